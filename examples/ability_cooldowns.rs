@@ -0,0 +1,101 @@
+//! Ability Cooldowns - Demonstrates button cooldown overlays
+//!
+//! Run with: cargo run --example ability_cooldowns --features button
+//!
+//! Click an ability (or press Q/W/E) to cast it. The button greys out, a dark
+//! overlay sweeps away and the remaining seconds count down until it can be
+//! used again.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use std::time::Duration;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, cast_abilities)
+        .run();
+}
+
+/// An ability button and its hotkey
+#[derive(Component, Clone)]
+struct Ability {
+    name: &'static str,
+    key: KeyCode,
+}
+
+#[derive(Component)]
+struct CastLog;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let abilities = [
+        ("Dash", KeyCode::KeyQ, 2, ButtonStyle::Secondary),
+        ("Fireball", KeyCode::KeyW, 5, ButtonStyle::Danger),
+        ("Heal", KeyCode::KeyE, 8, ButtonStyle::Success),
+    ];
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::End,
+                align_items: AlignItems::Center,
+                padding: UiRect::bottom(Val::Px(48.0)),
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|hud| {
+            hud.spawn((
+                Text::new("Press Q, W or E"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                CastLog,
+            ));
+
+            hud.spawn(Node {
+                column_gap: Val::Px(12.0),
+                ..default()
+            })
+            .with_children(|bar| {
+                for (name, key, secs, style) in abilities {
+                    ButtonBuilder::new(name)
+                        .style(style)
+                        .width(Val::Px(110.0))
+                        .height(Val::Px(56.0))
+                        .cooldown(Duration::from_secs(secs))
+                        .insert(Ability { name, key })
+                        .build(bar);
+                }
+            });
+        });
+}
+
+fn cast_abilities(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    abilities: Query<(Entity, &Ability, &ButtonCooldown, Option<&Interaction>)>,
+    mut triggers: MessageWriter<TriggerCooldown>,
+    mut log: Query<&mut Text, With<CastLog>>,
+) {
+    for (entity, ability, cooldown, interaction) in abilities.iter() {
+        let clicked = interaction == Some(&Interaction::Pressed);
+        if cooldown.is_active() || !(clicked || keyboard.just_pressed(ability.key)) {
+            continue;
+        }
+
+        triggers.write(TriggerCooldown { entity });
+        if let Ok(mut text) = log.single_mut() {
+            text.0 = format!("Cast {}!", ability.name);
+        }
+    }
+}
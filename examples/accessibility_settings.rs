@@ -0,0 +1,150 @@
+//! Accessibility Settings - Demonstrates the theme's reduce-motion and
+//! reduce-transparency flags
+//!
+//! Run with: cargo run --example accessibility_settings --features dialog,button
+//!
+//! Press T to toggle "reduce transparency" and M to toggle "reduce motion",
+//! then click "Open dialog" (or press Space). With reduce transparency on,
+//! the dialog backdrop is fully opaque and hides the colorful scene behind
+//! it; with reduce motion on, hover effects snap instead of animating.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (toggle_settings, open_dialog, close_on_ok, show_settings))
+        .run();
+}
+
+#[derive(Component, Clone)]
+struct OpenDialogButton;
+
+#[derive(Component)]
+struct SettingsText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(24.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            // A colorful strip that shows through see-through backdrops
+            parent
+                .spawn(Node {
+                    column_gap: Val::Px(12.0),
+                    ..default()
+                })
+                .with_children(|strip| {
+                    for color in [
+                        Color::srgb(0.9, 0.3, 0.3),
+                        Color::srgb(0.9, 0.7, 0.2),
+                        Color::srgb(0.3, 0.8, 0.4),
+                        Color::srgb(0.3, 0.5, 0.9),
+                    ] {
+                        strip.spawn((
+                            Node {
+                                width: Val::Px(80.0),
+                                height: Val::Px(80.0),
+                                ..default()
+                            },
+                            BackgroundColor(color),
+                            BorderRadius::all(Val::Px(8.0)),
+                        ));
+                    }
+                });
+
+            ButtonBuilder::new("Open dialog")
+                .style(ButtonStyle::Primary)
+                .insert(OpenDialogButton)
+                .build(parent);
+
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                SettingsText,
+            ));
+        });
+}
+
+fn toggle_settings(keyboard: Res<ButtonInput<KeyCode>>, mut theme: ResMut<UiTheme>) {
+    if keyboard.just_pressed(KeyCode::KeyT) {
+        theme.reduce_transparency = !theme.reduce_transparency;
+    }
+    if keyboard.just_pressed(KeyCode::KeyM) {
+        theme.reduce_motion = !theme.reduce_motion;
+    }
+}
+
+fn open_dialog(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    buttons: Query<&Interaction, (Changed<Interaction>, With<OpenDialogButton>)>,
+    dialogs: Query<(), With<DialogOverlay>>,
+    theme: Res<UiTheme>,
+) {
+    let clicked = buttons.iter().any(|interaction| *interaction == Interaction::Pressed);
+    if !(clicked || keyboard.just_pressed(KeyCode::Space)) || !dialogs.is_empty() {
+        return;
+    }
+
+    DialogBuilder::new(DialogType::Info)
+        .themed(&theme)
+        .title("Backdrop check")
+        .body(if theme.reduce_transparency {
+            "Reduce transparency is on: the backdrop is opaque."
+        } else {
+            "Reduce transparency is off: the scene shows through the backdrop."
+        })
+        .ok_button()
+        .build(&mut commands);
+}
+
+fn close_on_ok(
+    mut commands: Commands,
+    mut events: MessageReader<DialogButtonEvent>,
+    dialogs: Query<Entity, With<DialogOverlay>>,
+) {
+    for event in events.read() {
+        if event.button_marker != "ok" {
+            continue;
+        }
+
+        for dialog in dialogs.iter() {
+            commands.entity(dialog).despawn();
+        }
+    }
+}
+
+fn show_settings(theme: Res<UiTheme>, mut text: Query<&mut Text, With<SettingsText>>) {
+    if !theme.is_changed() {
+        return;
+    }
+
+    let on_off = |enabled: bool| if enabled { "on" } else { "off" };
+    if let Ok(mut text) = text.single_mut() {
+        text.0 = format!(
+            "[T] Reduce transparency: {}    [M] Reduce motion: {}",
+            on_off(theme.reduce_transparency),
+            on_off(theme.reduce_motion),
+        );
+    }
+}
@@ -0,0 +1,46 @@
+//! Animation Debug - Overlays live animation state on animated widgets
+//!
+//! Hover the buttons to watch their scale and brightness move towards the
+//! hover targets. Press F9 to toggle the overlay.
+//!
+//! Run with: cargo run --example animation_debug --features debug
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::traits::InteractiveBuilder;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands, mut debug: ResMut<AnimationDebug>) {
+    debug.enabled = true;
+
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(40.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            for (text, preset) in [
+                ("Subtle", AnimationPreset::Subtle),
+                ("Punchy", AnimationPreset::Punchy),
+                ("Playful", AnimationPreset::Playful),
+                ("Lift", AnimationPreset::Lift),
+            ] {
+                ButtonBuilder::new(text)
+                    .animation(preset)
+                    .build(parent);
+            }
+        });
+}
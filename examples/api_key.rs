@@ -0,0 +1,105 @@
+//! API Key - Demonstrates labels with a copy-to-clipboard button
+//!
+//! Run with: cargo run --example api_key --features label,toast
+//!
+//! Click "Copy" next to a key to put it on the clipboard; a "Copied!" toast
+//! confirms it. Press R to regenerate the secret key - the copy button always
+//! copies the label's current text.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::traits::UiBuilder;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, regenerate_secret)
+        .run();
+}
+
+#[derive(Component, Clone)]
+struct SecretKey;
+
+fn setup(mut commands: Commands, theme: Res<UiTheme>) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        padding: UiRect::all(Val::Px(32.0)),
+                        row_gap: Val::Px(12.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.12, 0.12, 0.15)),
+                    BorderRadius::all(Val::Px(8.0)),
+                ))
+                .with_children(|card| {
+                    LabelBuilder::new("API Keys")
+                        .size(LabelSize::Heading)
+                        .themed(&theme)
+                        .build(card);
+
+                    LabelBuilder::new("Publishable key")
+                        .size(LabelSize::Caption)
+                        .themed(&theme)
+                        .build(card);
+                    LabelBuilder::new("pk_live_51Hq8cR2eZvKYlo2C")
+                        .monospace()
+                        .themed(&theme)
+                        .with_copy_button(true)
+                        .build(card);
+
+                    LabelBuilder::new("Secret key (press R to regenerate)")
+                        .size(LabelSize::Caption)
+                        .themed(&theme)
+                        .build(card);
+                    LabelBuilder::new(secret_key(0))
+                        .monospace()
+                        .themed(&theme)
+                        .with_copy_button(true)
+                        .insert(SecretKey)
+                        .build(card);
+                });
+        });
+}
+
+/// A stand-in for a freshly issued secret
+fn secret_key(generation: u32) -> String {
+    format!("sk_live_{:08x}{:08x}", generation.wrapping_mul(2_654_435_761), 0x9e37_79b9u32 ^ generation)
+}
+
+fn regenerate_secret(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut generation: Local<u32>,
+    keys: Query<&Children, With<SecretKey>>,
+    mut labels: Query<&mut Text, Without<LabelCopyButton>>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyR) {
+        return;
+    }
+
+    *generation += 1;
+    // SecretKey sits on the row wrapping the label and its copy button
+    for children in keys.iter() {
+        for child in children.iter() {
+            if let Ok(mut text) = labels.get_mut(child) {
+                text.0 = secret_key(*generation);
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Branded Toasts - Demonstrates custom toast icons
+//!
+//! Run with: cargo run --example branded_toasts --features toast
+//!
+//! Press 1-4 for an info, success, warning or error toast with the built-in
+//! icons, and B for a success toast with a custom brand icon. Put a PNG at
+//! `assets/icons/brand.png` to see the image; without it the brand toast
+//! keeps the default success icon.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, show_toasts)
+        .run();
+}
+
+#[derive(Resource)]
+struct BrandIcon(Option<Handle<Image>>);
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn(Camera2d);
+
+    commands.insert_resource(BrandIcon(load_optional_image(&asset_server, "icons/brand.png")));
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("1-4: default icons    B: branded success toast"),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
+
+fn load_optional_image(asset_server: &AssetServer, path: &str) -> Option<Handle<Image>> {
+    std::path::Path::new("assets")
+        .join(path)
+        .exists()
+        .then(|| asset_server.load(path))
+}
+
+fn show_toasts(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    brand_icon: Res<BrandIcon>,
+    mut toasts: ResMut<ToastQueue>,
+) {
+    if keyboard.just_pressed(KeyCode::Digit1) {
+        ToastBuilder::info("A new version is available").show(&mut toasts);
+    }
+    if keyboard.just_pressed(KeyCode::Digit2) {
+        ToastBuilder::success("Settings saved").show(&mut toasts);
+    }
+    if keyboard.just_pressed(KeyCode::Digit3) {
+        ToastBuilder::warning("Low disk space").show(&mut toasts);
+    }
+    if keyboard.just_pressed(KeyCode::Digit4) {
+        ToastBuilder::error("Connection lost").show(&mut toasts);
+    }
+
+    if keyboard.just_pressed(KeyCode::KeyB) {
+        let mut toast = ToastBuilder::success("Your order is on its way")
+            .title("Thanks for shopping with Acme");
+        if let Some(icon) = &brand_icon.0 {
+            toast = toast.icon(icon.clone());
+        }
+        toast.show(&mut toasts);
+    }
+}
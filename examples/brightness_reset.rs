@@ -0,0 +1,62 @@
+//! Brightness Reset - Demonstrates sliders with a recommended default value
+//!
+//! Run with: cargo run --example brightness_reset --features slider
+//!
+//! Each slider has a tick on its track at the recommended value. Drag the
+//! sliders anywhere, then double-click a track to snap it back to its tick.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            SliderBuilder::new(0.0..100.0)
+                .label("Brightness")
+                .step(1.0)
+                .format(ValueFormat::Integer)
+                .value(80.0)
+                .default_marker(50.0)
+                .width(Val::Px(320.0))
+                .build(parent);
+
+            SliderBuilder::new(1.0..3.0)
+                .label("Gamma")
+                .step(0.1)
+                .value(2.6)
+                .default_marker(2.2)
+                .width(Val::Px(320.0))
+                .build(parent);
+
+            parent.spawn((
+                Text::new("Double-click a track to reset to the recommended value"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
@@ -1,6 +1,7 @@
 //! Demonstrates button selection states including:
 //! - Toggle buttons (checkbox-like)
 //! - Radio button groups (exclusive selection)
+//! - Segmented controls (joined button groups)
 //! - Active state (current tab/page indicators)
 //! - Custom selection colors
 //! - Different button styles with selection
@@ -43,6 +44,11 @@ fn setup(mut commands: Commands) {
     // This ensures it has no parent, avoiding B0004 warnings about missing GlobalTransform
     let radio_group = commands.spawn(ButtonGroupMembers::default()).id();
 
+    // Segmented group: members render joined as a single control
+    let range_group = commands
+        .spawn((ButtonGroupMembers::default(), SegmentedButtonGroup::default()))
+        .id();
+
     // Root container - using UiContainer to prevent B0004 warnings
     commands
         .spawn((
@@ -111,7 +117,23 @@ fn setup(mut commands: Commands) {
                     .build(section);
             });
 
-            // Section 3: Tab Bar (Active State)
+            // Section 3: Segmented Control (Joined Radio Group)
+            section_container(parent, "Segmented Control (Day / Week / Month)", |section| {
+                // Segments must sit flush against each other, so skip the section gap
+                section
+                    .spawn((UiContainer::row().build(), BackgroundColor(Color::NONE)))
+                    .with_children(|segments| {
+                        for (label, selected) in [("Day", false), ("Week", true), ("Month", false)] {
+                            ButtonBuilder::new(label)
+                                .in_group(range_group)
+                                .selected(selected)
+                                .style(ButtonStyle::Secondary)
+                                .build(segments);
+                        }
+                    });
+            });
+
+            // Section 4: Tab Bar (Active State)
             section_container(parent, "Tab Bar (Active State)", |section| {
                 ButtonBuilder::new("Home")
                     .selectable()
@@ -139,7 +161,7 @@ fn setup(mut commands: Commands) {
                     .build(section);
             });
 
-            // Section 4: Mixed States (Active + Selected)
+            // Section 5: Mixed States (Active + Selected)
             section_container(parent, "Mixed States & Custom Colors", |section| {
                 // Active button
                 ButtonBuilder::new("Active")
@@ -171,7 +193,7 @@ fn setup(mut commands: Commands) {
                     .build(section);
             });
 
-            // Section 5: Different Sizes
+            // Section 6: Different Sizes
             section_container(parent, "Selection with Different Sizes", |section| {
                 ButtonBuilder::new("Small")
                     .selectable()
@@ -201,7 +223,7 @@ fn setup(mut commands: Commands) {
 
             // Selection counter
             parent.spawn((
-                Text::new("Selected buttons: 3"),
+                Text::new("Selected buttons: 4"),
                 TextFont {
                     font_size: 20.0,
                     ..default()
@@ -0,0 +1,87 @@
+//! Cascading Dropdown - Demonstrates dropdowns with nested submenus
+//!
+//! Run with: cargo run --example cascading_dropdown --features dropdown
+//!
+//! Open the Insert dropdown and hover Shape: its submenu opens to the side
+//! with Rectangle and Circle, plus a Polygon row that cascades one level
+//! further. Move diagonally toward an open submenu and it stays open even as
+//! the pointer crosses other rows. Picking any option closes every level and
+//! shows the choice below.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, show_inserted)
+        .run();
+}
+
+#[derive(Component)]
+struct InsertMenu;
+
+#[derive(Component)]
+struct StatusText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::top(Val::Px(80.0)),
+                row_gap: Val::Px(24.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            DropdownBuilder::new(vec!["Image".into(), "Table".into()])
+                .placeholder("Insert")
+                .submenu("Shape", |shape| {
+                    shape
+                        .option("Rectangle")
+                        .option("Circle")
+                        .submenu("Polygon", |polygon| {
+                            polygon.option("Triangle").option("Hexagon");
+                        });
+                })
+                .submenu("Text", |text| {
+                    text.option("Heading").option("Paragraph");
+                })
+                .insert(InsertMenu)
+                .build(parent);
+
+            parent.spawn((
+                Text::new("Nothing inserted yet"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                StatusText,
+            ));
+        });
+}
+
+fn show_inserted(
+    menus: Query<&DropdownData, (With<InsertMenu>, Changed<DropdownData>)>,
+    mut status: Query<&mut Text, With<StatusText>>,
+) {
+    for data in menus.iter() {
+        let Some(value) = data.selected_value() else {
+            continue;
+        };
+
+        if let Ok(mut text) = status.single_mut() {
+            text.0 = format!("Inserted: {value}");
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! Character Limit - Demonstrates text input character counters
+//!
+//! Run with: cargo run --example character_limit --features text_input
+//!
+//! Each field shows how many of its characters are used. The counter turns
+//! red once the limit is reached and further typing is ignored. Emoji made
+//! of a single code point, like 🎉, count as one character.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::traits::UiBuilder;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                // Room for the counters below the single-line fields
+                row_gap: Val::Px(28.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            TextInputBuilder::new()
+                .with_placeholder("Username")
+                .with_max_length(16)
+                .show_counter()
+                .with_width(Val::Px(320.0))
+                .build(parent);
+
+            TextInputBuilder::new()
+                .with_placeholder("Status")
+                .with_value("Shipping today 🎉")
+                .with_max_length(40)
+                .show_counter()
+                .with_width(Val::Px(320.0))
+                .build(parent);
+
+            TextInputBuilder::new()
+                .with_placeholder("Bio")
+                .multiline(true)
+                .with_max_length(120)
+                .show_counter()
+                .with_width(Val::Px(320.0))
+                .with_height(Val::Px(120.0))
+                .build(parent);
+
+            parent.spawn((
+                Text::new("Counters turn red at the limit"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
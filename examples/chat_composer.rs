@@ -0,0 +1,88 @@
+//! Chat Composer - Demonstrates chat-style Enter handling in multiline inputs
+//!
+//! Run with: cargo run --example chat_composer --features text_input
+//!
+//! The composer at the bottom sends its message with Enter and starts a new
+//! line with Shift+Enter, like most chat apps. Sent messages appear in the
+//! log above, keeping their line breaks, and the composer clears for the
+//! next one.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::components::text_input::TextInputSubmitEvent;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, post_messages)
+        .run();
+}
+
+#[derive(Component)]
+struct ChatLog;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::End,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(24.0)),
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Node {
+                    width: Val::Px(420.0),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(8.0),
+                    ..default()
+                },
+                ChatLog,
+            ));
+
+            TextInputBuilder::new()
+                .multiline(true)
+                .submit_key(SubmitKey::Enter)
+                .with_placeholder("Message (Shift+Enter for a new line)")
+                .with_width(Val::Px(420.0))
+                .with_height(Val::Px(72.0))
+                .build(parent);
+        });
+}
+
+fn post_messages(
+    mut commands: Commands,
+    mut submits: MessageReader<TextInputSubmitEvent>,
+    log: Query<Entity, With<ChatLog>>,
+) {
+    let Ok(log) = log.single() else {
+        return;
+    };
+
+    for event in submits.read() {
+        let message = event.text.trim();
+        if message.is_empty() {
+            continue;
+        }
+
+        commands.entity(log).with_child((
+            Text::new(format!("You: {message}")),
+            TextFont {
+                font_size: 16.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.7, 0.7, 0.7)),
+        ));
+    }
+}
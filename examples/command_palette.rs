@@ -0,0 +1,114 @@
+//! Command Palette - Demonstrates text input autocomplete suggestions
+//!
+//! Run with: cargo run --example command_palette --features text_input
+//!
+//! Start typing a command (try "/t" or "se") and matching commands appear in
+//! a menu below the search box. Use Up/Down to highlight one, Tab or Enter
+//! to accept it, or click it. Press Enter again to run the command.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::components::text_input::TextInputSubmitEvent;
+use bevy_ui_builders::traits::UiBuilder;
+
+const COMMANDS: &[&str] = &[
+    "/help",
+    "/teleport",
+    "/time set day",
+    "/time set night",
+    "/toggle fog",
+    "/spawn enemy",
+    "/settings",
+    "/save",
+    "/quit",
+];
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, run_command)
+        .run();
+}
+
+#[derive(Component)]
+struct OutputText;
+
+/// Commands containing the typed text, prefix matches first
+fn suggest_commands(text: &str) -> Vec<String> {
+    let query = text.trim().trim_start_matches('/').to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let mut matches: Vec<&str> = COMMANDS.iter()
+        .copied()
+        .filter(|command| command.contains(query.as_str()))
+        .collect();
+    matches.sort_by_key(|command| !command[1..].starts_with(query.as_str()));
+    matches.into_iter().map(String::from).collect()
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::top(Val::Px(80.0)),
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Command Palette"),
+                TextFont {
+                    font_size: 28.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+
+            TextInputBuilder::new()
+                .with_placeholder("Type a command...")
+                .with_width(Val::Px(420.0))
+                .suggestions(suggest_commands)
+                .max_suggestions(6)
+                .retain_on_submit(false)
+                .build(parent);
+
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.6, 0.8, 0.6)),
+                OutputText,
+            ));
+        });
+}
+
+fn run_command(
+    mut submits: MessageReader<TextInputSubmitEvent>,
+    mut output: Query<&mut Text, With<OutputText>>,
+) {
+    for event in submits.read() {
+        let Ok(mut text) = output.single_mut() else {
+            continue;
+        };
+
+        text.0 = if COMMANDS.contains(&event.text.trim()) {
+            format!("Ran {}", event.text.trim())
+        } else {
+            format!("Unknown command: {}", event.text)
+        };
+    }
+}
@@ -0,0 +1,97 @@
+//! Console History - Demonstrates recalling submitted entries with Up/Down
+//!
+//! Run with: cargo run --example console_history --features text_input
+//!
+//! Type a command and press Enter; it is echoed to the log and the input
+//! clears. Press Up to bring back earlier commands and Down to step forward
+//! again - past the newest entry you get back whatever you had typed.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::components::text_input::TextInputSubmitEvent;
+
+/// Most log lines kept on screen
+const MAX_LOG_LINES: usize = 12;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, run_command)
+        .run();
+}
+
+#[derive(Component)]
+struct ConsoleLog;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::FlexEnd,
+                padding: UiRect::all(Val::Px(24.0)),
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.05, 0.05, 0.07)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Type \"help\" and press Enter"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.6, 0.9, 0.6)),
+                ConsoleLog,
+            ));
+
+            text_input()
+                .with_placeholder("> command")
+                .with_width(Val::Percent(100.0))
+                .retain_on_submit(false)
+                .with_history(true)
+                .build(parent);
+        });
+}
+
+fn run_command(
+    mut submits: MessageReader<TextInputSubmitEvent>,
+    mut log: Query<&mut Text, With<ConsoleLog>>,
+) {
+    for event in submits.read() {
+        let command = event.text.trim();
+        if command.is_empty() {
+            continue;
+        }
+
+        let reply = match command {
+            "help" => "Commands: help, god, noclip, give gold, clear".to_string(),
+            "god" => "God mode toggled".to_string(),
+            "noclip" => "Noclip toggled".to_string(),
+            "give gold" => "Added 1000 gold".to_string(),
+            other => format!("Unknown command: {}", other),
+        };
+
+        let Ok(mut text) = log.single_mut() else {
+            continue;
+        };
+
+        if command == "clear" {
+            text.0.clear();
+            continue;
+        }
+
+        let mut lines: Vec<&str> = text.0.lines().collect();
+        let entry = format!("> {}\n{}", command, reply);
+        lines.extend(entry.lines());
+        let start = lines.len().saturating_sub(MAX_LOG_LINES);
+        text.0 = lines[start..].join("\n");
+    }
+}
@@ -0,0 +1,111 @@
+//! Context Submenus - Demonstrates nested context menu submenus
+//!
+//! Run with: cargo run --example context_submenus --features context_menu
+//!
+//! Right-click the file area to open its menu. Hover "New" or "Sort by" for
+//! a moment and the submenu opens beside it. Move diagonally from "New"
+//! toward its submenu: the pointer crosses "Open" and "Rename" on the way,
+//! but the submenu stays open because you are heading into it. The chosen
+//! action is shown at the bottom.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, show_action)
+        .run();
+}
+
+#[derive(Component)]
+struct ActionText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let mut file_area = Entity::PLACEHOLDER;
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            file_area = parent
+                .spawn((
+                    Node {
+                        width: Val::Px(480.0),
+                        height: Val::Px(300.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.12, 0.12, 0.15)),
+                    BorderColor::all(Color::srgb(0.3, 0.3, 0.3)),
+                    BorderRadius::all(Val::Px(8.0)),
+                ))
+                .with_child((
+                    Text::new("Right-click anywhere in this area"),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.6, 0.6, 0.6)),
+                ))
+                .id();
+
+            parent.spawn((
+                Text::new("No action yet"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                ActionText,
+            ));
+        });
+
+    ContextMenuBuilder::new()
+        .submenu("New", |sub| {
+            sub.action("new_file", "File", Some("Ctrl+N"))
+                .action("new_folder", "Folder", None)
+                .separator()
+                .submenu("From template", |templates| {
+                    templates
+                        .action("template_readme", "README", None)
+                        .action("template_license", "LICENSE", None)
+                })
+        })
+        .action("open", "Open", Some("Enter"))
+        .action("rename", "Rename", Some("F2"))
+        .separator()
+        .submenu("Sort by", |sub| {
+            sub.action("sort_name", "Name", None)
+                .action("sort_date", "Date modified", None)
+                .action("sort_size", "Size", None)
+        })
+        .checkbox("show_hidden", "Show hidden files", false)
+        .build_for(&mut commands, file_area);
+}
+
+fn show_action(
+    mut actions: MessageReader<ContextMenuActionEvent>,
+    mut text: Query<&mut Text, With<ActionText>>,
+) {
+    for event in actions.read() {
+        if let Ok(mut text) = text.single_mut() {
+            text.0 = format!("Chose \"{}\"", event.id);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Corner Menu - Demonstrates animation transform origins
+//!
+//! Run with: cargo run --example corner_menu --features button
+//!
+//! Click "File" to open a menu that grows out of its top-left corner, right
+//! under the button, and shrinks back into it when closed. Without an origin
+//! the same animation would grow from the middle of the menu.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::animation::{EnterAnimating, ExitAnimating};
+use bevy_ui_builders::traits::UiBuilder;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, toggle_menu)
+        .run();
+}
+
+#[derive(Component, Clone)]
+struct FileButton;
+
+#[derive(Component)]
+struct MenuAnchor;
+
+#[derive(Component)]
+struct FileMenu;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(24.0)),
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        ..default()
+                    },
+                    MenuAnchor,
+                ))
+                .with_children(|anchor| {
+                    ButtonBuilder::new("File")
+                        .style(ButtonStyle::Secondary)
+                        .insert(FileButton)
+                        .build(anchor);
+                });
+
+            parent.spawn((
+                Text::new("Click File to open and close the menu"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
+
+fn toggle_menu(
+    mut commands: Commands,
+    clicked: Query<&Interaction, (With<FileButton>, Changed<Interaction>)>,
+    anchor: Query<Entity, With<MenuAnchor>>,
+    menus: Query<Entity, (With<FileMenu>, Without<ExitAnimating>)>,
+) {
+    if !clicked.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        return;
+    }
+
+    // Close an open menu with its exit animation
+    if let Ok(menu) = menus.single() {
+        commands.entity(menu).remove::<EnterAnimating>().insert(ExitAnimating);
+        return;
+    }
+
+    let Ok(anchor) = anchor.single() else {
+        return;
+    };
+    commands.entity(anchor).with_children(|anchor| {
+        anchor
+            .spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Percent(100.0),
+                    margin: UiRect::top(Val::Px(4.0)),
+                    width: Val::Px(180.0),
+                    padding: UiRect::all(Val::Px(6.0)),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(2.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.16, 0.16, 0.19)),
+                BorderRadius::all(Val::Px(6.0)),
+                Transform::default(),
+                UiAnimation::new()
+                    .with_enter(Transition::scale_in(0.3, 0.15))
+                    .with_exit(Transition::scale_out(0.3, 0.12))
+                    .with_origin(TransformOrigin::TopLeft),
+                EnterAnimating,
+                FileMenu,
+            ))
+            .with_children(|menu| {
+                for item in ["New", "Open...", "Save", "Save As...", "Quit"] {
+                    menu.spawn(Node {
+                        padding: UiRect::axes(Val::Px(10.0), Val::Px(6.0)),
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        row.spawn((
+                            Text::new(item),
+                            TextFont {
+                                font_size: 15.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                        ));
+                    });
+                }
+            });
+    });
+}
@@ -0,0 +1,76 @@
+//! Country Search - Demonstrates a searchable dropdown
+//!
+//! Run with: cargo run --example country_search --features dropdown,text_input
+//!
+//! Opening the menu focuses a search field at its top. Typing hides every
+//! country whose name doesn't contain the text (try "land"), and Enter picks
+//! the first match. Nothing is selected until you pick a country, and
+//! closing the menu brings the full list back.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+const COUNTRIES: [&str; 24] = [
+    "Argentina", "Australia", "Austria", "Belgium", "Brazil", "Canada", "Chile",
+    "Denmark", "Egypt", "Finland", "France", "Germany", "Greenland", "Iceland",
+    "Ireland", "Italy", "Japan", "Mexico", "Netherlands", "New Zealand", "Norway",
+    "Poland", "Spain", "Switzerland",
+];
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, show_selection)
+        .run();
+}
+
+#[derive(Component)]
+struct StatusText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                padding: UiRect::all(Val::Px(40.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            DropdownBuilder::new(COUNTRIES.iter().map(|name| name.to_string()).collect())
+                .placeholder("Choose a country")
+                .searchable()
+                .max_visible_items(8)
+                .width(Val::Px(260.0))
+                .build(parent);
+
+            parent.spawn((
+                Text::new("No country selected"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                StatusText,
+            ));
+        });
+}
+
+fn show_selection(
+    dropdowns: Query<&DropdownData, Changed<DropdownData>>,
+    mut status: Query<&mut Text, With<StatusText>>,
+) {
+    for data in dropdowns.iter() {
+        if let (Some(country), Ok(mut text)) = (data.selected_value(), status.single_mut()) {
+            text.0 = format!("Selected: {}", country);
+        }
+    }
+}
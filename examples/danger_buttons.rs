@@ -0,0 +1,104 @@
+//! Danger Buttons - Demonstrates tagging widgets with class names
+//!
+//! Run with: cargo run --example danger_buttons --features button
+//!
+//! The destructive buttons are tagged with the "danger" class as they are
+//! built. Press L to toggle safe mode, which finds every "danger" button
+//! through its `UiClass` and locks or unlocks them all at once - no marker
+//! component per group needed.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::traits::{Disabled, UiBuilder};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, toggle_safe_mode)
+        .run();
+}
+
+#[derive(Component)]
+struct StatusText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            ButtonBuilder::new("Save Game").width(Val::Px(220.0)).build(parent);
+            ButtonBuilder::new("Load Game").width(Val::Px(220.0)).build(parent);
+
+            ButtonBuilder::new("Delete Save")
+                .style(ButtonStyle::Danger)
+                .width(Val::Px(220.0))
+                .class("danger")
+                .build(parent);
+            ButtonBuilder::new("Reset Progress")
+                .style(ButtonStyle::Danger)
+                .width(Val::Px(220.0))
+                .class("danger")
+                .build(parent);
+            // Several names at once
+            ButtonBuilder::new("Quit Without Saving")
+                .style(ButtonStyle::Danger)
+                .width(Val::Px(220.0))
+                .class("danger exit")
+                .build(parent);
+
+            parent.spawn((
+                Text::new("Press L to lock the danger buttons"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                StatusText,
+            ));
+        });
+}
+
+/// Lock or unlock every button tagged "danger"
+fn toggle_safe_mode(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    classes: Query<(Entity, &UiClass)>,
+    mut status: Query<&mut Text, With<StatusText>>,
+    mut safe_mode: Local<bool>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyL) {
+        return;
+    }
+    *safe_mode = !*safe_mode;
+
+    let danger = entities_with_class("danger", &classes);
+    for &button in &danger {
+        if *safe_mode {
+            commands.entity(button).insert(Disabled);
+        } else {
+            commands.entity(button).remove::<Disabled>();
+        }
+    }
+
+    if let Ok(mut text) = status.single_mut() {
+        text.0 = if *safe_mode {
+            format!("Safe mode on: {} danger buttons locked (L to unlock)", danger.len())
+        } else {
+            "Press L to lock the danger buttons".to_string()
+        };
+    }
+}
@@ -0,0 +1,100 @@
+//! Danger Zone - Demonstrates resource bar thresholds and color zones
+//!
+//! Run with: cargo run --example danger_zone --features resource_bar
+//!
+//! The health bar is green while healthy, turns orange at half health and
+//! red in the danger zone below 25%. Tick marks show where each zone starts,
+//! plus one at 75%. Press D to take damage and H to heal.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::traits::UiBuilder;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (damage_and_heal, show_health).chain())
+        .run();
+}
+
+#[derive(Component, Clone)]
+struct PlayerHealth;
+
+#[derive(Component)]
+struct StatusText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            health_bar()
+                .fill_color(Color::srgb(0.3, 0.8, 0.3))
+                .background_color(Color::srgb(0.12, 0.2, 0.12))
+                .threshold(0.75)
+                .zone(0.5, Color::srgb(0.95, 0.6, 0.2))
+                .zone(0.25, Color::srgb(0.9, 0.15, 0.15))
+                .show_text(true)
+                .bar_width(Val::Px(360.0))
+                .insert(PlayerHealth)
+                .build(parent);
+
+            parent.spawn((
+                Text::new("D to take damage, H to heal"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                StatusText,
+            ));
+        });
+}
+
+fn damage_and_heal(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut health: Query<&mut ResourceBar, With<PlayerHealth>>,
+) {
+    let Ok(mut bar) = health.single_mut() else {
+        return;
+    };
+
+    if keyboard.just_pressed(KeyCode::KeyD) {
+        bar.value = (bar.value - 10.0).max(0.0);
+    }
+    if keyboard.just_pressed(KeyCode::KeyH) {
+        bar.value = (bar.value + 10.0).min(bar.max_value);
+    }
+}
+
+fn show_health(
+    health: Query<&ResourceBar, (With<PlayerHealth>, Changed<ResourceBar>)>,
+    mut status: Query<&mut Text, With<StatusText>>,
+) {
+    let Ok(bar) = health.single() else {
+        return;
+    };
+
+    let state = match bar.percentage() {
+        p if p <= 0.25 => "Danger!",
+        p if p <= 0.5 => "Wounded",
+        _ => "Healthy",
+    };
+    if let Ok(mut text) = status.single_mut() {
+        text.0 = format!("{:.0} HP - {}", bar.value, state);
+    }
+}
@@ -30,6 +30,7 @@ enum DemoDialogType {
     Success,
     Custom,
     NonDismissible,
+    Settings,
 }
 
 fn setup(mut commands: Commands) {
@@ -159,6 +160,13 @@ fn setup(mut commands: Commands) {
                                 ButtonStyle::Danger,
                                 DemoDialogType::NonDismissible,
                             );
+
+                            create_dialog_button(
+                                row,
+                                "Settings (Frosted)",
+                                ButtonStyle::Secondary,
+                                DemoDialogType::Settings,
+                            );
                         });
                 });
         });
@@ -199,8 +207,13 @@ fn button_system(
                     info!("Showing unsaved changes dialog");
                 }
                 DemoDialogType::Error => {
-                    // Using the preset with custom message
-                    presets::error(&mut commands, "Failed to load the requested file. Please check the file path and try again.");
+                    // Error dialog with the built-in red error icon above the title
+                    DialogBuilder::new(DialogType::Error)
+                        .default_icon()
+                        .title("Error")
+                        .body("Failed to load the requested file. Please check the file path and try again.")
+                        .ok_button()
+                        .build(&mut commands);
                     info!("Showing error dialog");
                 }
                 DemoDialogType::Info => {
@@ -247,6 +260,8 @@ fn button_system(
                 DemoDialogType::NonDismissible => {
                     // Non-dismissible dialog (can't click outside to close)
                     DialogBuilder::new(DialogType::Error)
+                        .default_icon()
+                        .icon_position(DialogIconPosition::Beside)
                         .title("Critical Error")
                         .body("A critical error has occurred. You must acknowledge this message before continuing.")
                         .danger_button("Acknowledge")
@@ -255,6 +270,17 @@ fn button_system(
                         .build(&mut commands);
                     info!("Showing non-dismissible dialog");
                 }
+                DemoDialogType::Settings => {
+                    // Frosted backdrop hides the busy scene behind the dialog
+                    DialogBuilder::new(DialogType::Custom)
+                        .title("Settings")
+                        .body("Audio, video and control settings would live here. The screen behind is frosted rather than just dimmed.")
+                        .confirm_button("Save")
+                        .cancel_button("Cancel")
+                        .blur_backdrop(true)
+                        .build(&mut commands);
+                    info!("Showing settings dialog");
+                }
             }
         }
     }
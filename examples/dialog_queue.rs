@@ -0,0 +1,115 @@
+//! Dialog Queue - Demonstrates showing queued dialogs one at a time
+//!
+//! Run with: cargo run --example dialog_queue --features dialog,button
+//!
+//! Click "Queue 3 dialogs" (or press Space) to enqueue three info dialogs.
+//! Only one is shown at a time; the next opens as soon as the current one is
+//! closed with OK or Escape.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (queue_dialogs, close_on_ok, show_queue_length))
+        .run();
+}
+
+#[derive(Component, Clone)]
+struct QueueButton;
+
+#[derive(Component)]
+struct QueueStatus;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            ButtonBuilder::new("Queue 3 dialogs")
+                .style(ButtonStyle::Primary)
+                .insert(QueueButton)
+                .build(parent);
+
+            parent.spawn((
+                Text::new("0 dialogs waiting"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                QueueStatus,
+            ));
+        });
+}
+
+fn queue_dialogs(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    buttons: Query<&Interaction, (Changed<Interaction>, With<QueueButton>)>,
+    mut queue: ResMut<DialogQueue>,
+) {
+    let clicked = buttons.iter().any(|interaction| *interaction == Interaction::Pressed);
+    if !clicked && !keyboard.just_pressed(KeyCode::Space) {
+        return;
+    }
+
+    let steps = [
+        ("Step 1 of 3", "Your save file was backed up."),
+        ("Step 2 of 3", "New achievements were unlocked."),
+        ("Step 3 of 3", "A game update is ready to install."),
+    ];
+
+    for (title, body) in steps {
+        queue.enqueue(
+            DialogBuilder::new(DialogType::Info)
+                .title(title)
+                .body(body)
+                .ok_button(),
+        );
+    }
+}
+
+/// OK closes the current dialog, which lets the queue open the next one
+fn close_on_ok(
+    mut commands: Commands,
+    mut events: MessageReader<DialogButtonEvent>,
+    dialogs: Query<Entity, With<DialogOverlay>>,
+) {
+    for event in events.read() {
+        if event.button_marker != "ok" {
+            continue;
+        }
+
+        for dialog in dialogs.iter() {
+            commands.entity(dialog).despawn();
+        }
+    }
+}
+
+fn show_queue_length(
+    queue: Res<DialogQueue>,
+    mut status: Query<&mut Text, With<QueueStatus>>,
+) {
+    if !queue.is_changed() {
+        return;
+    }
+
+    if let Ok(mut text) = status.single_mut() {
+        text.0 = format!("{} dialogs waiting", queue.len());
+    }
+}
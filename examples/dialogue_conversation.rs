@@ -0,0 +1,110 @@
+//! Dialogue Conversation - Demonstrates per-speaker name colors
+//!
+//! Run with: cargo run --example dialogue_conversation --features dialogue
+//!
+//! Three characters talk in turn, each with their name in their own color so
+//! it's clear at a glance who is speaking. Press Space to finish a line and N
+//! to move on to the next one.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::traits::UiBuilder;
+
+/// Who says what, in order
+const LINES: [(&str, &str); 5] = [
+    ("Mira", "You made it! I was starting to think the pass had swallowed you."),
+    ("Brandt", "The pass nearly did. Two wolves and a rockslide, in that order."),
+    ("Old Tobin", "Wolves in autumn? Something is driving them down from the peaks."),
+    ("Mira", "Then that's where we're going. Tobin, can you mark it on the map?"),
+    ("Old Tobin", "I can. But you won't like what's written next to it."),
+];
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .init_resource::<Conversation>()
+        .add_systems(Startup, setup)
+        .add_systems(Update, (advance, show_line).chain())
+        .run();
+}
+
+/// Index of the line being shown
+#[derive(Resource, Default)]
+struct Conversation(usize);
+
+#[derive(Component)]
+struct Root;
+
+#[derive(Component, Clone)]
+struct CurrentLine;
+
+fn speaker_color(speaker: &str) -> Color {
+    match speaker {
+        "Mira" => Color::srgb(0.95, 0.6, 0.8),
+        "Brandt" => Color::srgb(0.5, 0.75, 1.0),
+        _ => Color::srgb(0.9, 0.8, 0.4),
+    }
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                padding: UiRect::all(Val::Px(24.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+            Root,
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Text::new("Space finishes a line, N moves to the next"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
+
+fn advance(keyboard: Res<ButtonInput<KeyCode>>, mut conversation: ResMut<Conversation>) {
+    if keyboard.just_pressed(KeyCode::KeyN) {
+        conversation.0 = (conversation.0 + 1) % LINES.len();
+    }
+}
+
+/// Replace the dialogue box whenever the conversation moves on
+fn show_line(
+    mut commands: Commands,
+    conversation: Res<Conversation>,
+    root: Query<Entity, With<Root>>,
+    current: Query<Entity, With<CurrentLine>>,
+) {
+    if !conversation.is_changed() {
+        return;
+    }
+
+    for line in current.iter() {
+        commands.entity(line).despawn();
+    }
+
+    let Ok(root) = root.single() else {
+        return;
+    };
+    let (speaker, text) = LINES[conversation.0];
+
+    commands.entity(root).with_children(|parent| {
+        dialogue_box()
+            .speaker(speaker)
+            .speaker_color(speaker_color(speaker))
+            .text(text)
+            .insert(CurrentLine)
+            .build(parent);
+    });
+}
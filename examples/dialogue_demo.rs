@@ -0,0 +1,116 @@
+//! Dialogue Demo - Demonstrates dialogue boxes with locked choices
+//!
+//! Run with: cargo run --example dialogue_demo --features dialogue
+//!
+//! The gate keeper offers a choice that needs the Rusty Key. Until you pick
+//! it up (press K) the choice is greyed out, shows what it requires and
+//! can't be selected.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::traits::UiBuilder;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .init_resource::<Inventory>()
+        .add_systems(Startup, setup)
+        .add_systems(Update, (pick_up_key, handle_choices, rebuild_dialogue).chain())
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct Inventory {
+    has_rusty_key: bool,
+}
+
+#[derive(Component)]
+struct Root;
+
+#[derive(Component, Clone)]
+struct GateDialogue;
+
+#[derive(Component)]
+struct StatusText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                padding: UiRect::all(Val::Px(24.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+            Root,
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Text::new("Press K to pick up the Rusty Key"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                StatusText,
+            ));
+        });
+}
+
+fn pick_up_key(keyboard: Res<ButtonInput<KeyCode>>, mut inventory: ResMut<Inventory>) {
+    if keyboard.just_pressed(KeyCode::KeyK) && !inventory.has_rusty_key {
+        inventory.has_rusty_key = true;
+    }
+}
+
+/// Spawn the gate dialogue, and respawn it whenever the inventory changes
+fn rebuild_dialogue(
+    mut commands: Commands,
+    inventory: Res<Inventory>,
+    root: Query<Entity, With<Root>>,
+    dialogues: Query<Entity, With<GateDialogue>>,
+) {
+    if !inventory.is_changed() {
+        return;
+    }
+
+    for dialogue in dialogues.iter() {
+        commands.entity(dialogue).despawn();
+    }
+
+    let Ok(root) = root.single() else {
+        return;
+    };
+
+    commands.entity(root).with_children(|parent| {
+        dialogue_box()
+            .speaker("Gate Keeper")
+            .text("Halt! The north gate has been locked since the storm.")
+            .conditional_choice("unlock", "Unlock the gate", inventory.has_rusty_key, "Rusty Key")
+            .choice("ask", "Who has the key?")
+            .choice("leave", "Never mind.")
+            .insert(GateDialogue)
+            .build(parent);
+    });
+}
+
+fn handle_choices(
+    mut choices: MessageReader<DialogueChoiceEvent>,
+    mut status: Query<&mut Text, With<StatusText>>,
+) {
+    for event in choices.read() {
+        let message = match event.choice_id.as_str() {
+            "unlock" => "The gate creaks open.",
+            "ask" => "\"The blacksmith dropped it somewhere in the square.\"",
+            _ => "You walk away.",
+        };
+
+        if let Ok(mut text) = status.single_mut() {
+            text.0 = message.to_string();
+        }
+    }
+}
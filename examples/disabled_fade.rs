@@ -0,0 +1,103 @@
+//! Disabled Fade - Demonstrates animated enable/disable transitions
+//!
+//! Run with: cargo run --example disabled_fade --features form,text_input,button
+//!
+//! The "Create Account" button starts disabled: faded and gray. Fill in both
+//! required fields and it smoothly fades back to full color; clear one and
+//! it fades out again. While disabled, clicking it does nothing.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::components::form::{FormRoot, FormSubmitButton};
+use bevy_ui_builders::traits::Disabled;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (toggle_submit_buttons, show_submission))
+        .run();
+}
+
+#[derive(Component)]
+struct StatusText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.05, 0.05, 0.07)),
+        ))
+        .with_children(|parent| {
+            FormBuilder::new("signup")
+                .title("Sign Up")
+                .text_field("username", "Username")
+                .required()
+                .email_field("email", "Email")
+                .required()
+                .submit_text("Create Account")
+                .build(parent);
+
+            parent.spawn((
+                Text::new("Fill in both fields to enable the button"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                StatusText,
+            ));
+        });
+}
+
+/// Disable each form's submit button while the form is invalid
+///
+/// Adding or removing `Disabled` is all it takes; the animation plugin fades
+/// the button between its normal and disabled look.
+fn toggle_submit_buttons(
+    mut commands: Commands,
+    mut forms: Query<(Entity, &mut FormRoot), Changed<FormRoot>>,
+    buttons: Query<(Entity, &FormSubmitButton, Has<Disabled>)>,
+) {
+    for (form_entity, mut form) in forms.iter_mut() {
+        // Validating only updates the `is_valid` flag, which shouldn't
+        // re-trigger this system next frame
+        let is_valid = form.bypass_change_detection().validate();
+
+        for (button, submit, disabled) in buttons.iter() {
+            if submit.form_entity != form_entity || disabled != is_valid {
+                continue;
+            }
+
+            if is_valid {
+                commands.entity(button).remove::<Disabled>();
+            } else {
+                commands.entity(button).insert(Disabled);
+            }
+        }
+    }
+}
+
+fn show_submission(
+    mut submits: MessageReader<FormSubmitEvent>,
+    mut status: Query<&mut Text, With<StatusText>>,
+) {
+    for event in submits.read() {
+        let user = event.data.values.get("username").cloned().unwrap_or_default();
+
+        if let Ok(mut text) = status.single_mut() {
+            text.0 = format!("Created account for {}", user);
+        }
+    }
+}
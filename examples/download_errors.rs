@@ -0,0 +1,81 @@
+//! Download Errors - Demonstrates grouped toasts
+//!
+//! Run with: cargo run --example download_errors --features toast
+//!
+//! Press F to fail a batch of downloads. Their error toasts collapse into a
+//! single "N download errors" toast instead of stacking up; click "Show all"
+//! to list each one. Further failures join the group while it is on screen.
+//! Press S for an ungrouped success toast, which stacks as usual.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+const FILES: [&str; 6] = [
+    "terrain_chunk_04.bin",
+    "music_theme.ogg",
+    "npc_portraits.png",
+    "localization_de.json",
+    "shaders.pak",
+    "patch_notes.md",
+];
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .init_resource::<NextFile>()
+        .add_systems(Startup, setup)
+        .add_systems(Update, show_toasts)
+        .run();
+}
+
+/// Index of the next file to fail
+#[derive(Resource, Default)]
+struct NextFile(usize);
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("F: fail three downloads    S: success toast"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
+
+fn show_toasts(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut next_file: ResMut<NextFile>,
+    mut toasts: ResMut<ToastQueue>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyF) {
+        for _ in 0..3 {
+            let file = FILES[next_file.0 % FILES.len()];
+            next_file.0 += 1;
+
+            ToastBuilder::error(format!("{file} could not be downloaded"))
+                .group("download errors")
+                .show(&mut toasts);
+        }
+    }
+
+    if keyboard.just_pressed(KeyCode::KeyS) {
+        ToastBuilder::success("Library verified").show(&mut toasts);
+    }
+}
@@ -0,0 +1,217 @@
+//! Dropdown Demo - Demonstrates dropdown configurations
+//!
+//! Run with: cargo run --example dropdown_demo --features dropdown
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    // Camera
+    commands.spawn(Camera2d);
+
+    // Root node
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            padding: UiRect::all(Val::Px(40.0)),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(30.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            // Title
+            parent.spawn((
+                Text::new("Dropdown Demo"),
+                TextFont {
+                    font_size: 32.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+
+            // Color picker - the selected swatch is mirrored on the button
+            create_row(parent, "Accent color", |row| {
+                DropdownBuilder::new(vec![
+                    "Crimson".to_string(),
+                    "Emerald".to_string(),
+                    "Sapphire".to_string(),
+                    "Amber".to_string(),
+                ])
+                .placeholder("Pick a color")
+                .option_color(0, Color::srgb(0.86, 0.08, 0.24))
+                .option_color(1, Color::srgb(0.31, 0.78, 0.47))
+                .option_color(2, Color::srgb(0.06, 0.32, 0.73))
+                .option_color(3, Color::srgb(1.0, 0.75, 0.0))
+                .selected_index(Some(1))
+                .width(Val::Px(220.0))
+                .build(row);
+            });
+
+            // Icons alongside labels
+            create_row(parent, "Weather", |row| {
+                DropdownBuilder::new(vec![
+                    "Sunny".to_string(),
+                    "Cloudy".to_string(),
+                    "Rainy".to_string(),
+                ])
+                .option_icon(0, "*")
+                .option_icon(1, "~")
+                .option_icon(2, "/")
+                .width(Val::Px(220.0))
+                .build(row);
+            });
+
+            // Sold-out tiers are greyed out and can't be picked
+            create_row(parent, "Plan", |row| {
+                DropdownBuilder::new(vec![
+                    "Free".to_string(),
+                    "Pro".to_string(),
+                    "Team (sold out)".to_string(),
+                    "Enterprise (sold out)".to_string(),
+                ])
+                .placeholder("Choose a plan")
+                .option_disabled(2, true)
+                .option_disabled(3, true)
+                .width(Val::Px(220.0))
+                .build(row);
+            });
+
+            // Custom face rendering - large swatch only, label in caps
+            create_row(parent, "Team", |row| {
+                DropdownBuilder::new(vec![
+                    "Red".to_string(),
+                    "Blue".to_string(),
+                ])
+                .option_color(0, Color::srgb(0.9, 0.2, 0.2))
+                .option_color(1, Color::srgb(0.2, 0.4, 0.9))
+                .render_selected(render_team_face)
+                .width(Val::Px(220.0))
+                .build(row);
+            });
+
+            // A hundred levels, eight visible at a time; the selection is
+            // scrolled into view when the menu opens
+            create_row(parent, "Level", |row| {
+                DropdownBuilder::new((1..=100).map(|level| format!("Level {}", level)).collect())
+                    .selected_index(Some(41))
+                    .max_visible_items(8)
+                    .width(Val::Px(220.0))
+                    .build(row);
+            });
+
+            // A dropdown inside a short, clipping panel with a higher-z card
+            // below it. The menu opens in the popup layer, so neither the
+            // clip nor the card can cover it.
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        padding: UiRect::all(Val::Px(12.0)),
+                        row_gap: Val::Px(8.0),
+                        height: Val::Px(90.0),
+                        overflow: Overflow::clip(),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.15, 0.15, 0.2)),
+                    BorderRadius::all(Val::Px(6.0)),
+                ))
+                .with_children(|panel| {
+                    create_row(panel, "Difficulty", |row| {
+                        DropdownBuilder::new(vec![
+                            "Story".to_string(),
+                            "Normal".to_string(),
+                            "Hard".to_string(),
+                            "Nightmare".to_string(),
+                        ])
+                        .selected_index(Some(1))
+                        .width(Val::Px(220.0))
+                        .build(row);
+                    });
+                });
+
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(360.0),
+                        padding: UiRect::all(Val::Px(12.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.25, 0.18, 0.18)),
+                    BorderRadius::all(Val::Px(6.0)),
+                    ZIndex(10),
+                ))
+                .with_children(|card| {
+                    card.spawn((
+                        Text::new("This card sits above the panel, but not above open menus"),
+                        TextFont {
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                    ));
+                });
+        });
+}
+
+fn render_team_face(face: &mut ChildSpawnerCommands, label: &str, option: Option<&DropdownOption>) {
+    let color = option.and_then(|option| option.color).unwrap_or(Color::NONE);
+
+    face.spawn((
+        Node {
+            width: Val::Px(40.0),
+            height: Val::Px(16.0),
+            ..default()
+        },
+        BackgroundColor(color),
+        BorderRadius::all(Val::Px(8.0)),
+    ));
+
+    face.spawn((
+        Text::new(label.to_uppercase()),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+    ));
+}
+
+fn create_row(
+    parent: &mut ChildSpawnerCommands,
+    label: &str,
+    content: impl FnOnce(&mut ChildSpawnerCommands),
+) {
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(20.0),
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((
+                Node {
+                    width: Val::Px(120.0),
+                    ..default()
+                },
+                Text::new(label),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+            ));
+
+            content(row);
+        });
+}
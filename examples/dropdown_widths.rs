@@ -0,0 +1,89 @@
+//! Dropdown Widths - Demonstrates how dropdown menus size against their button
+//!
+//! Run with: cargo run --example dropdown_widths --features dropdown
+//!
+//! Each dropdown has a fixed-width button. The first menu matches the button,
+//! the second grows past it to fit a long option, and the third uses
+//! `menu_width` to open a wider menu than its narrow button.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                padding: UiRect::all(Val::Px(40.0)),
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::FlexStart,
+                column_gap: Val::Px(40.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            labeled(parent, "Matches the 220px button", |column| {
+                DropdownBuilder::new(vec!["Low".into(), "Medium".into(), "High".into()])
+                    .width(Val::Px(220.0))
+                    .build(column);
+            });
+
+            labeled(parent, "Grows past the 120px button", |column| {
+                DropdownBuilder::new(vec![
+                    "1280 x 720".into(),
+                    "1920 x 1080".into(),
+                    "3840 x 2160 (requires restart)".into(),
+                ])
+                .width(Val::Px(120.0))
+                .build(column);
+            });
+
+            labeled(parent, "menu_width(320px) on a 100px button", |column| {
+                DropdownBuilder::new(vec![
+                    "English".into(),
+                    "Deutsch".into(),
+                    "Portugues (Brasil)".into(),
+                ])
+                .width(Val::Px(100.0))
+                .menu_width(Val::Px(320.0))
+                .build(column);
+            });
+        });
+}
+
+fn labeled(
+    parent: &mut ChildSpawnerCommands,
+    label: &str,
+    content: impl FnOnce(&mut ChildSpawnerCommands),
+) {
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(8.0),
+            ..default()
+        })
+        .with_children(|column| {
+            column.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+
+            content(column);
+        });
+}
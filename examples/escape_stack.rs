@@ -0,0 +1,102 @@
+//! Escape Stack - Demonstrates Escape closing only the topmost popup
+//!
+//! Run with: cargo run --example escape_stack --features dialog,dropdown,button
+//!
+//! Press D to open a settings dialog, then open its difficulty dropdown.
+//! The first Escape closes just the dropdown, since it sits above the
+//! dialog; the second closes the dialog. The status line counts the open
+//! layers on the `EscapeStack`.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::components::dialog::DialogBody;
+use bevy_ui_builders::traits::LayoutBuilder;
+
+const DIFFICULTIES: [&str; 4] = ["Story", "Normal", "Hard", "Nightmare"];
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (open_dialog, fill_dialog, show_layers))
+        .run();
+}
+
+#[derive(Component)]
+struct StatusText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Press D to open the settings dialog"),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+
+            parent.spawn((
+                Text::new("Open layers: 0"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                StatusText,
+            ));
+        });
+}
+
+fn open_dialog(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    dialogs: Query<(), With<DialogOverlay>>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyD) && dialogs.is_empty() {
+        DialogBuilder::new(DialogType::Custom)
+            .title("Game Settings")
+            .body("Difficulty")
+            .cancel_button("Close")
+            .build(&mut commands);
+    }
+}
+
+/// Put the difficulty dropdown next to the dialog's body text
+fn fill_dialog(mut commands: Commands, bodies: Query<&ChildOf, Added<DialogBody>>) {
+    for row in bodies.iter() {
+        commands.entity(row.parent()).with_children(|row| {
+            DropdownBuilder::new(DIFFICULTIES.iter().map(|name| name.to_string()).collect())
+                .selected_index(Some(1))
+                .width(Val::Px(180.0))
+                .margin(UiRect::left(Val::Px(12.0)))
+                .build(row);
+        });
+    }
+}
+
+fn show_layers(escape: Res<EscapeStack>, mut status: Query<&mut Text, With<StatusText>>) {
+    if !escape.is_changed() {
+        return;
+    }
+
+    if let Ok(mut text) = status.single_mut() {
+        text.0 = format!("Open layers: {}", escape.len());
+    }
+}
@@ -0,0 +1,148 @@
+//! Favorite Toggle - Demonstrates custom checkbox icons
+//!
+//! Run with: cargo run --example favorite_toggle --features checkbox
+//!
+//! Each map in the list has a favorite toggle that shows a star instead of
+//! the usual "X" when checked. The star is drawn into an image at startup;
+//! a real game would load its own icon. Click a star to favorite a map.
+
+use std::f32::consts::{PI, TAU};
+
+use bevy::prelude::*;
+use bevy::asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy_ui_builders::*;
+use bevy_ui_builders::traits::UiBuilder;
+
+/// Width and height of the star image in pixels
+const STAR_SIZE: u32 = 64;
+
+const MAPS: [&str; 4] = ["Frozen Pass", "Sunken Temple", "Ember Foundry", "Whispering Dunes"];
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, show_favorites)
+        .run();
+}
+
+/// The map a favorite toggle's row is for
+#[derive(Component, Clone)]
+struct MapName(&'static str);
+
+#[derive(Component)]
+struct StatusText;
+
+fn setup(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    commands.spawn(Camera2d);
+    let star = images.add(star_image());
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(10.0),
+                    ..default()
+                })
+                .with_children(|list| {
+                    for map in MAPS {
+                        CheckboxBuilder::new()
+                            .check_icon(star.clone())
+                            .style(CheckboxStyle::Default)
+                            .size(24.0)
+                            .with_label(map)
+                            .insert(MapName(map))
+                            .build(list);
+                    }
+                });
+
+            parent.spawn((
+                Text::new("No favorites yet"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                StatusText,
+            ));
+        });
+}
+
+/// A white five-pointed star on a transparent background
+fn star_image() -> Image {
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: STAR_SIZE,
+            height: STAR_SIZE,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 0],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    );
+
+    let center = STAR_SIZE as f32 / 2.0;
+    let outer = center * 0.95;
+    let inner = outer * 0.45;
+    let data = image.data.as_mut().expect("new_fill creates image data");
+
+    for y in 0..STAR_SIZE {
+        for x in 0..STAR_SIZE {
+            let offset = Vec2::new(x as f32 + 0.5 - center, center - (y as f32 + 0.5));
+            // Angle from the top point, folded into one of the ten half-arms
+            let angle = (offset.x.atan2(offset.y) + TAU) % (TAU / 5.0);
+            let arm = (angle / (PI / 5.0) - 1.0).abs();
+            if offset.length() <= inner + (outer - inner) * arm {
+                let index = ((y * STAR_SIZE + x) * 4) as usize;
+                data[index..index + 4].copy_from_slice(&[255, 255, 255, 255]);
+            }
+        }
+    }
+
+    image
+}
+
+fn show_favorites(
+    toggles: Query<(&CheckboxState, &ChildOf)>,
+    changed: Query<(), Changed<CheckboxState>>,
+    rows: Query<&MapName>,
+    mut status: Query<&mut Text, With<StatusText>>,
+) {
+    if changed.is_empty() {
+        return;
+    }
+
+    // Each checkbox box sits in the row that names its map
+    let favorites: Vec<&str> = MAPS
+        .into_iter()
+        .filter(|map| {
+            toggles.iter().any(|(state, row)| {
+                state.is_checked() && rows.get(row.parent()).is_ok_and(|name| name.0 == *map)
+            })
+        })
+        .collect();
+
+    if let Ok(mut text) = status.single_mut() {
+        text.0 = if favorites.is_empty() {
+            "No favorites yet".to_string()
+        } else {
+            format!("Favorites: {}", favorites.join(", "))
+        };
+    }
+}
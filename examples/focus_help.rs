@@ -0,0 +1,128 @@
+//! Focus Help - Demonstrates reacting to text input focus changes
+//!
+//! Run with: cargo run --example focus_help --features text_input
+//!
+//! Each field has a formatting hint below it that only shows while the field
+//! is focused. Click a field or Tab between them and the hint follows; click
+//! the background and every hint hides again. The status line lists the focus
+//! events as they arrive.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::components::text_input::TextInputFocusEvent;
+use bevy_ui_builders::traits::UiBuilder;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, toggle_help)
+        .run();
+}
+
+/// Formatting hint for the text input it points at
+#[derive(Component)]
+struct HelpFor(Entity);
+
+#[derive(Component)]
+struct StatusText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            for (placeholder, help) in [
+                ("Birthday", "Use YYYY-MM-DD, e.g. 1990-04-23"),
+                ("Phone", "Digits only, with the country code first: 4915123456789"),
+                ("Username", "3-16 letters, digits or underscores"),
+            ] {
+                let input = TextInputBuilder::new()
+                    .with_placeholder(placeholder)
+                    .with_width(Val::Px(360.0))
+                    .build(parent);
+
+                parent
+                    .spawn((
+                        Node {
+                            display: Display::None,
+                            width: Val::Px(360.0),
+                            padding: UiRect::axes(Val::Px(10.0), Val::Px(6.0)),
+                            margin: UiRect::bottom(Val::Px(8.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.16, 0.2, 0.28)),
+                        BorderRadius::all(Val::Px(4.0)),
+                        HelpFor(input),
+                    ))
+                    .with_children(|panel| {
+                        panel.spawn((
+                            Text::new(help),
+                            TextFont {
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.8, 0.85, 0.95)),
+                        ));
+                    });
+            }
+
+            parent.spawn((
+                Text::new("Click a field to see its format"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                StatusText,
+            ));
+        });
+}
+
+fn toggle_help(
+    mut focus_changes: MessageReader<TextInputFocusEvent>,
+    mut help: Query<(&HelpFor, &mut Node)>,
+    mut status: Query<&mut Text, With<StatusText>>,
+) {
+    let mut log = Vec::new();
+
+    // Losses arrive first, so moving between fields hides the old hint
+    // before showing the new one
+    for event in focus_changes.read() {
+        let (display, name) = if event.focused {
+            (Display::Flex, "focus")
+        } else {
+            (Display::None, "blur")
+        };
+        set_help(&mut help, event.entity, display);
+        log.push(format!("{name} {}", event.entity));
+    }
+
+    if log.is_empty() {
+        return;
+    }
+    if let Ok(mut text) = status.single_mut() {
+        text.0 = log.join(", ");
+    }
+}
+
+fn set_help(help: &mut Query<(&HelpFor, &mut Node)>, input: Entity, display: Display) {
+    for (target, mut node) in help.iter_mut() {
+        if target.0 == input {
+            node.display = display;
+        }
+    }
+}
@@ -12,6 +12,7 @@ fn main() {
         .add_plugins(DefaultPlugins)
         .add_plugins(UiBuilderPlugin)
         .add_systems(Startup, setup)
+        .add_systems(Update, show_width_override)
         .run();
 }
 
@@ -110,7 +111,6 @@ fn create_registration_form(parent: &mut ChildSpawnerCommands) {
                 .password_field("password", "Password")
                 .required()
                 .validate(ValidationRule::MinLength(8))
-                .help_text("Must be at least 8 characters")
                 .password_field("confirm_password", "Confirm Password")
                 .required()
                 .dropdown_field("country", "Country", vec![
@@ -122,6 +122,8 @@ fn create_registration_form(parent: &mut ChildSpawnerCommands) {
                 ])
                 .checkbox_field("terms", "I agree to the terms and conditions")
                 .required()
+                .field_help("password", "At least 8 characters, including a number and a symbol")
+                .field_help_icon("email", "Only used to recover your account")
                 .submit_text("Register")
                 .cancel_text("Back")
                 .width(Val::Px(400.0))
@@ -163,5 +165,59 @@ fn create_settings_form(parent: &mut ChildSpawnerCommands) {
                 .layout(FormLayout::Vertical)
                 .width(Val::Px(350.0))
                 .build(container);
+
+            // Optional numeric setting: blank means "no override"
+            container.spawn((
+                Text::new("Override width (leave blank for auto)"),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+            ));
+
+            NumberInputBuilder::new()
+                .min(320.0)
+                .max(3840.0)
+                .allow_empty(true)
+                .with_placeholder("Auto")
+                .insert(WidthOverrideInput)
+                .build(container);
+
+            container.spawn((
+                Text::new("Window width: auto"),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.6, 0.6, 0.6)),
+                WidthOverrideStatus,
+            ));
         });
+}
+
+#[derive(Component, Clone)]
+struct WidthOverrideInput;
+
+#[derive(Component)]
+struct WidthOverrideStatus;
+
+fn show_width_override(
+    mut changes: MessageReader<NumberInputChangedEvent>,
+    inputs: Query<(), With<WidthOverrideInput>>,
+    mut status: Query<&mut Text, With<WidthOverrideStatus>>,
+) {
+    for change in changes.read() {
+        if !inputs.contains(change.entity) {
+            continue;
+        }
+
+        let summary = match change.value {
+            Some(width) => format!("Window width: {width:.0}px"),
+            None => "Window width: auto".to_string(),
+        };
+        for mut text in &mut status {
+            text.0 = summary.clone();
+        }
+    }
 }
\ No newline at end of file
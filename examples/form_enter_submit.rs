@@ -0,0 +1,75 @@
+//! Form Enter Submit - Demonstrates submitting a form with the Enter key
+//!
+//! Run with: cargo run --example form_enter_submit --features form,text_input,button
+//!
+//! Fill in both fields and press Enter in either of them (typically the
+//! password field) to submit, just like clicking "Sign In". The form only
+//! submits once both required fields have a value.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, show_submission)
+        .run();
+}
+
+#[derive(Component)]
+struct StatusText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.05, 0.05, 0.07)),
+        ))
+        .with_children(|parent| {
+            FormBuilder::new("login")
+                .title("Sign In")
+                .text_field("username", "Username")
+                .required()
+                .password_field("password", "Password")
+                .required()
+                .help_text("Press Enter to sign in")
+                .submit_text("Sign In")
+                .submit_on_enter(true)
+                .build(parent);
+
+            parent.spawn((
+                Text::new("Not signed in"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                StatusText,
+            ));
+        });
+}
+
+fn show_submission(
+    mut submits: MessageReader<FormSubmitEvent>,
+    mut status: Query<&mut Text, With<StatusText>>,
+) {
+    for event in submits.read() {
+        let user = event.data.values.get("username").cloned().unwrap_or_default();
+
+        if let Ok(mut text) = status.single_mut() {
+            text.0 = format!("Submitted \"{}\" as {}", event.form_id, user);
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! Form Inspector - Demonstrates layout debug outlines
+//!
+//! Run with: cargo run --example form_inspector --features form,button,text_input,checkbox,dropdown,debug
+//!
+//! A sign-up form with a couple of sections starts with its layout outlined:
+//! every container gets a border colored by how deeply it is nested, showing
+//! the rows, fields and button bar the form is built from. Press F10 to turn
+//! the outlines off and on, and +/- to change their width.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (adjust_outlines, show_status))
+        .run();
+}
+
+#[derive(Component)]
+struct StatusText;
+
+fn setup(mut commands: Commands, mut debug: ResMut<UiDebug>) {
+    commands.spawn(Camera2d);
+    debug.enabled = true;
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            FormBuilder::new("signup")
+                .title("Create Account")
+                .text_field("name", "Display Name")
+                .required()
+                .email_field("email", "Email Address")
+                .required()
+                .password_field("password", "Password")
+                .required()
+                .dropdown_field("region", "Region", vec![
+                    "Europe".to_string(),
+                    "North America".to_string(),
+                    "Asia".to_string(),
+                ])
+                .checkbox_field("newsletter", "Send me patch notes")
+                .field_help("password", "At least 8 characters")
+                .submit_text("Sign Up")
+                .cancel_text("Cancel")
+                .width(Val::Px(400.0))
+                .build(parent);
+
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                StatusText,
+            ));
+        });
+}
+
+fn adjust_outlines(keyboard: Res<ButtonInput<KeyCode>>, mut debug: ResMut<UiDebug>) {
+    if keyboard.any_just_pressed([KeyCode::Equal, KeyCode::NumpadAdd]) {
+        debug.outline_width = (debug.outline_width + 1.0).min(4.0);
+    }
+    if keyboard.any_just_pressed([KeyCode::Minus, KeyCode::NumpadSubtract]) {
+        debug.outline_width = (debug.outline_width - 1.0).max(1.0);
+    }
+}
+
+fn show_status(
+    debug: Res<UiDebug>,
+    outlined: Query<(), With<UiDebugOutline>>,
+    mut status: Query<&mut Text, With<StatusText>>,
+) {
+    let summary = if debug.enabled {
+        format!(
+            "Outlines on ({}px, {} containers) - F10 to hide",
+            debug.outline_width,
+            outlined.iter().count(),
+        )
+    } else {
+        "Outlines off - F10 to show".to_string()
+    };
+
+    if let Ok(mut text) = status.single_mut() {
+        if text.0 != summary {
+            text.0 = summary;
+        }
+    }
+}
@@ -0,0 +1,153 @@
+//! Format Toolbar - Demonstrates reacting to text input selections
+//!
+//! Run with: cargo run --example format_toolbar --features text_input,button
+//!
+//! Select some text in the note (drag with the mouse or use Shift+Arrow keys)
+//! and a format bar appears above it. Its buttons report what they would do
+//! with the selection. Clicking anywhere outside the note (including on the
+//! bar) clears the selection and hides the bar again.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::components::text_input::TextInputSelectionChangedEvent;
+use bevy_ui_builders::traits::UiBuilder;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .init_resource::<CurrentSelection>()
+        .add_systems(Startup, setup)
+        // Format clicks also clear the selection, so handle them after the
+        // selection update that hides the bar
+        .add_systems(Update, (track_selection, handle_format_buttons).chain())
+        .run();
+}
+
+/// The latest non-empty selection (kept after the selection is cleared)
+#[derive(Resource, Default)]
+struct CurrentSelection(String);
+
+#[derive(Component)]
+struct FormatBar;
+
+#[derive(Component, Clone, Copy)]
+enum FormatAction {
+    Bold,
+    Italic,
+    Find,
+}
+
+#[derive(Component)]
+struct StatusText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            // Hidden until something is selected
+            parent
+                .spawn((
+                    Node {
+                        display: Display::None,
+                        column_gap: Val::Px(6.0),
+                        padding: UiRect::all(Val::Px(6.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.18, 0.18, 0.22)),
+                    BorderRadius::all(Val::Px(6.0)),
+                    FormatBar,
+                ))
+                .with_children(|bar| {
+                    for (label, action) in [
+                        ("Bold", FormatAction::Bold),
+                        ("Italic", FormatAction::Italic),
+                        ("Find", FormatAction::Find),
+                    ] {
+                        ButtonBuilder::new(label)
+                            .style(ButtonStyle::Ghost)
+                            .size(ButtonSize::Small)
+                            .insert(action)
+                            .build(bar);
+                    }
+                });
+
+            TextInputBuilder::new()
+                .with_value("Select part of this sentence to format it.")
+                .with_width(Val::Px(420.0))
+                .build(parent);
+
+            parent.spawn((
+                Text::new("Nothing selected"),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.6, 0.6, 0.6)),
+                StatusText,
+            ));
+        });
+}
+
+fn track_selection(
+    mut selections: MessageReader<TextInputSelectionChangedEvent>,
+    mut current: ResMut<CurrentSelection>,
+    mut bars: Query<&mut Node, With<FormatBar>>,
+    mut status: Query<&mut Text, With<StatusText>>,
+) {
+    let Some(event) = selections.read().last() else {
+        return;
+    };
+
+    let has_selection = event.start != event.end;
+    if has_selection {
+        current.0 = event.selected_text.clone();
+    }
+
+    for mut bar in bars.iter_mut() {
+        bar.display = if has_selection { Display::Flex } else { Display::None };
+    }
+
+    if let Ok(mut text) = status.single_mut() {
+        text.0 = if has_selection {
+            format!("Selected chars {}..{}: \"{}\"", event.start, event.end, event.selected_text)
+        } else {
+            "Nothing selected".to_string()
+        };
+    }
+}
+
+fn handle_format_buttons(
+    buttons: Query<(&Interaction, &FormatAction), Changed<Interaction>>,
+    current: Res<CurrentSelection>,
+    mut status: Query<&mut Text, With<StatusText>>,
+) {
+    for (interaction, action) in buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let message = match action {
+            FormatAction::Bold => format!("Bold: **{}**", current.0),
+            FormatAction::Italic => format!("Italic: _{}_", current.0),
+            FormatAction::Find => format!("Finding \"{}\" in page", current.0),
+        };
+
+        if let Ok(mut text) = status.single_mut() {
+            text.0 = message;
+        }
+    }
+}
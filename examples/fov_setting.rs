@@ -0,0 +1,75 @@
+//! FOV Setting - Demonstrates a slider paired with a number input
+//!
+//! Run with: cargo run --example fov_setting --features slider,number_input
+//!
+//! Drag the Field of View slider and the number box follows. Type a precise
+//! value into the box and the slider moves to it; out-of-range values are
+//! clamped once you click away. The mouse sensitivity row uses a 0.05 step,
+//! so its box shows two decimals.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, show_settings)
+        .run();
+}
+
+#[derive(Component)]
+struct SummaryText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            slider_with_input(60.0..120.0, 1.0)
+                .label("Field of View")
+                .value(90.0)
+                .slider_width(Val::Px(260.0))
+                .build(parent);
+
+            slider_with_input(0.1..5.0, 0.05)
+                .label("Mouse Sensitivity")
+                .value(1.0)
+                .slider_width(Val::Px(260.0))
+                .build(parent);
+
+            parent.spawn((
+                Text::new("Move a slider or type a value"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                SummaryText,
+            ));
+        });
+}
+
+fn show_settings(
+    mut changes: MessageReader<SliderChanged>,
+    mut summary: Query<&mut Text, With<SummaryText>>,
+) {
+    for change in changes.read() {
+        if let Ok(mut text) = summary.single_mut() {
+            text.0 = format!("Last change: {:.2}", change.value);
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! Game HUD - Demonstrates resource bars
+//!
+//! Run with: cargo run --example game_hud --features resource_bar
+//!
+//! Health and mana run along the bottom of the screen while stamina sits in
+//! a vertical gauge on the side. Hold Space to sprint (drains stamina),
+//! press H to take damage and M to cast a spell. Press B to raise a shield;
+//! it can exceed max health, and the excess is drawn as a brighter overflow
+//! layer. Damage hits the shield first.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::traits::UiBuilder;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (drain_stamina, take_damage_and_cast, raise_shield))
+        .run();
+}
+
+#[derive(Component, Clone)]
+struct HealthBar;
+
+#[derive(Component, Clone)]
+struct ManaBar;
+
+#[derive(Component, Clone)]
+struct StaminaBar;
+
+#[derive(Component, Clone)]
+struct ShieldBar;
+
+/// Shield is measured against max health but may exceed it
+const MAX_SHIELD: f32 = 200.0;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                padding: UiRect::all(Val::Px(24.0)),
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::End,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|hud| {
+            // Bottom-left: horizontal health and mana
+            hud.spawn(Node {
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(8.0),
+                ..default()
+            })
+            .with_children(|bars| {
+                ResourceBarBuilder::new()
+                    .style(ResourceBarStyle::Shield)
+                    .max_value(100.0)
+                    .value(130.0)
+                    .allow_overflow(true)
+                    .show_damage_indicator(false)
+                    .show_text(true)
+                    .height(16.0)
+                    .bar_width(Val::Px(260.0))
+                    .insert(ShieldBar)
+                    .build(bars);
+
+                health_bar()
+                    .value(80.0)
+                    .show_text(true)
+                    .bar_width(Val::Px(260.0))
+                    .insert(HealthBar)
+                    .build(bars);
+
+                mana_bar()
+                    .value(60.0)
+                    .height(16.0)
+                    .bar_width(Val::Px(260.0))
+                    .insert(ManaBar)
+                    .build(bars);
+            });
+
+            // Right side: vertical stamina gauge filling bottom-to-top
+            stamina_bar()
+                .vertical(true)
+                .height(20.0)
+                .bar_width(Val::Px(200.0))
+                .insert(StaminaBar)
+                .build(hud);
+        });
+}
+
+/// Sprinting drains stamina, resting regenerates it
+fn drain_stamina(
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut bars: Query<&mut ResourceBar, With<StaminaBar>>,
+) {
+    let rate = if keyboard.pressed(KeyCode::Space) { -40.0 } else { 15.0 };
+
+    for mut bar in bars.iter_mut() {
+        bar.value = (bar.value + rate * time.delta_secs()).clamp(0.0, bar.max_value);
+    }
+}
+
+fn take_damage_and_cast(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut health: Query<&mut ResourceBar, (With<HealthBar>, Without<ManaBar>, Without<ShieldBar>)>,
+    mut mana: Query<&mut ResourceBar, (With<ManaBar>, Without<HealthBar>, Without<ShieldBar>)>,
+    mut shield: Query<&mut ResourceBar, (With<ShieldBar>, Without<HealthBar>, Without<ManaBar>)>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyH) {
+        // The shield soaks damage before health does
+        let mut damage = 15.0;
+        for mut bar in shield.iter_mut() {
+            let absorbed = damage.min(bar.value);
+            bar.value -= absorbed;
+            damage -= absorbed;
+        }
+
+        for mut bar in health.iter_mut() {
+            bar.value = (bar.value - damage).max(0.0);
+        }
+    }
+
+    if keyboard.just_pressed(KeyCode::KeyM) {
+        for mut bar in mana.iter_mut() {
+            bar.value = (bar.value - 20.0).max(0.0);
+        }
+    }
+}
+
+fn raise_shield(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut shield: Query<&mut ResourceBar, With<ShieldBar>>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyB) {
+        for mut bar in shield.iter_mut() {
+            bar.value = (bar.value + 30.0).min(MAX_SHIELD);
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! Gradient Header - Demonstrates panels with gradient backgrounds
+//!
+//! Run with: cargo run --example gradient_header --features panel,label
+//!
+//! The header uses an accent gradient built from the theme's primary colors,
+//! so it follows whatever theme is active. The two cards below use explicit
+//! gradients: a diagonal sunset and a vertical fade.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands, theme: Res<UiTheme>) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            BackgroundColor(theme.colors.surface.background),
+        ))
+        .with_children(|parent| {
+            PanelBuilder::new()
+                .accent_gradient(SemanticVariant::Primary, GradientDirection::ToRight)
+                .themed(&theme)
+                .width(Val::Percent(100.0))
+                .padding(UiRect::axes(Val::Px(32.0), Val::Px(24.0)))
+                .build_with_children(parent, |header| {
+                    LabelBuilder::new("Season 4: Frozen Frontier")
+                        .size(LabelSize::Heading)
+                        .build(header);
+                    LabelBuilder::new("New maps, new heroes, and a whole lot of snow")
+                        .size(LabelSize::Caption)
+                        .build(header);
+                });
+
+            parent
+                .spawn(Node {
+                    column_gap: Val::Px(16.0),
+                    padding: UiRect::all(Val::Px(24.0)),
+                    ..default()
+                })
+                .with_children(|row| {
+                    PanelBuilder::new()
+                        .background_gradient(
+                            Color::srgb(0.95, 0.45, 0.25),
+                            Color::srgb(0.45, 0.15, 0.5),
+                            GradientDirection::ToBottomRight,
+                        )
+                        .width(Val::Px(240.0))
+                        .height(Val::Px(140.0))
+                        .build_with_children(row, |card| {
+                            LabelBuilder::new("Battle Pass").build(card);
+                        });
+
+                    PanelBuilder::new()
+                        .background_gradient(
+                            Color::srgba(0.2, 0.6, 0.9, 0.9),
+                            Color::srgba(0.2, 0.6, 0.9, 0.0),
+                            GradientDirection::ToBottom,
+                        )
+                        .width(Val::Px(240.0))
+                        .height(Val::Px(140.0))
+                        .build_with_children(row, |card| {
+                            LabelBuilder::new("Store").build(card);
+                        });
+                });
+        });
+}
@@ -0,0 +1,86 @@
+//! Grouped Dropdown - Demonstrates dropdown option groups with headers
+//!
+//! Run with: cargo run --example grouped_dropdown --features dropdown,text_input
+//!
+//! The Equip dropdown sorts its items under Weapons, Armor and Potions
+//! headers. The arrow keys step over the headers, and the status line shows
+//! the index of the picked item in the flat option list. Type in the search
+//! field to filter: a header hides once none of its items match.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, show_equipped)
+        .run();
+}
+
+#[derive(Component)]
+struct EquipMenu;
+
+#[derive(Component)]
+struct StatusText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::top(Val::Px(80.0)),
+                row_gap: Val::Px(24.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            DropdownBuilder::new(Vec::new())
+                .placeholder("Equip")
+                .group("Weapons", |weapons| {
+                    weapons.option("Sword").option("Bow").option("Staff");
+                })
+                .group("Armor", |armor| {
+                    armor.option("Helmet").option("Shield");
+                })
+                .group("Potions", |potions| {
+                    potions.option("Healing Potion").option("Mana Potion");
+                })
+                .searchable()
+                .max_visible_items(6)
+                .insert(EquipMenu)
+                .build(parent);
+
+            parent.spawn((
+                Text::new("Nothing equipped yet"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                StatusText,
+            ));
+        });
+}
+
+fn show_equipped(
+    menus: Query<&DropdownData, (With<EquipMenu>, Changed<DropdownData>)>,
+    mut status: Query<&mut Text, With<StatusText>>,
+) {
+    for data in menus.iter() {
+        let (Some(index), Some(value)) = (data.selected_index, data.selected_value()) else {
+            continue;
+        };
+
+        if let Ok(mut text) = status.single_mut() {
+            text.0 = format!("Equipped: {value} (option {index})");
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! Hotbar - Demonstrates a quickslot bar selected with number keys
+//!
+//! Run with: cargo run --example hotbar --features inventory
+//!
+//! A 9-slot hotbar sits at the bottom of the screen. Press 1-9 (or click a
+//! slot) to select it; the selected slot is outlined.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::traits::UiBuilder;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, show_selected_slot)
+        .run();
+}
+
+#[derive(Component)]
+struct SelectionText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                padding: UiRect::all(Val::Px(24.0)),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::End,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|hud| {
+            hud.spawn((
+                Text::new("Slot 1 selected"),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                SelectionText,
+            ));
+
+            InventoryGridBuilder::hotbar(9)
+                .slot_size(Val::Px(56.0))
+                .build(hud);
+        });
+}
+
+fn show_selected_slot(
+    mut clicks: MessageReader<SlotClickEvent>,
+    mut text: Query<&mut Text, With<SelectionText>>,
+) {
+    for event in clicks.read() {
+        if let Ok(mut text) = text.single_mut() {
+            text.0 = format!("Slot {} selected", event.index + 1);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//! Hover State - Demonstrates polling widget interaction from game logic
+//!
+//! Run with: cargo run --example hover_state --features button
+//!
+//! Hover the Shop button and the shopkeeper greets you; press it and they
+//! start haggling. The gameplay system reads the button's
+//! `InteractionState` and the `is_hovered` helper instead of matching on
+//! `Interaction` itself.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (shopkeeper_dialogue, highlight_sign))
+        .run();
+}
+
+#[derive(Resource)]
+struct ShopButton(Entity);
+
+#[derive(Component)]
+struct ShopkeeperText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let mut shop_button = Entity::PLACEHOLDER;
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            shop_button = ButtonBuilder::new("Shop")
+                .style(ButtonStyle::Primary)
+                .build(parent);
+
+            parent.spawn((
+                Text::new("The shopkeeper is dozing"),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                ShopkeeperText,
+            ));
+        });
+
+    commands.insert_resource(ShopButton(shop_button));
+}
+
+/// React to hover and press edges from the readback component
+fn shopkeeper_dialogue(
+    shop: Res<ShopButton>,
+    states: Query<&InteractionState>,
+    mut dialogue: Query<&mut Text, With<ShopkeeperText>>,
+) {
+    let Ok(state) = states.get(shop.0) else {
+        return;
+    };
+    let Ok(mut text) = dialogue.single_mut() else {
+        return;
+    };
+
+    if state.just_pressed {
+        text.0 = "\"For you? Special price.\"".to_string();
+    } else if state.just_hovered {
+        text.0 = "\"Welcome, traveler!\"".to_string();
+    } else if state.just_unhovered {
+        text.0 = "The shopkeeper is dozing".to_string();
+    }
+}
+
+/// Poll the current hover state with the helper
+fn highlight_sign(
+    shop: Res<ShopButton>,
+    interactions: Query<&Interaction>,
+    mut dialogue: Query<&mut TextColor, With<ShopkeeperText>>,
+) {
+    let color = if is_hovered(shop.0, &interactions) {
+        Color::srgb(1.0, 0.85, 0.4)
+    } else {
+        Color::srgb(0.7, 0.7, 0.7)
+    };
+
+    if let Ok(mut text_color) = dialogue.single_mut() {
+        text_color.set_if_neq(TextColor(color));
+    }
+}
@@ -0,0 +1,106 @@
+//! Inbox Badge - Demonstrates badges attached to other widgets
+//!
+//! Run with: cargo run --example inbox_badge --features badge,button
+//!
+//! The Inbox button carries an unread-count badge. Press M to receive a
+//! message and the count goes up, showing "9+" past nine; click Inbox to read
+//! everything and the badge hides itself. The Quests button has a plain dot
+//! badge on its top-left corner.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (receive_messages, read_messages))
+        .run();
+}
+
+#[derive(Resource)]
+struct Inbox {
+    button: Entity,
+    badge: Entity,
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let mut inbox_button = Entity::PLACEHOLDER;
+    let mut quests_button = Entity::PLACEHOLDER;
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(24.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            inbox_button = ButtonBuilder::new("Inbox")
+                .style(ButtonStyle::Primary)
+                .build(parent);
+
+            quests_button = ButtonBuilder::new("Quests")
+                .style(ButtonStyle::Secondary)
+                .build(parent);
+
+            parent.spawn((
+                Text::new("M - receive a message, click Inbox to read them"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+
+    let badge = BadgeBuilder::count(3)
+        .max(9)
+        .attach_to(&mut commands, inbox_button);
+
+    BadgeBuilder::dot()
+        .corner(BadgeCorner::TopLeft)
+        .variant(SemanticVariant::Warning)
+        .attach_to(&mut commands, quests_button);
+
+    commands.insert_resource(Inbox {
+        button: inbox_button,
+        badge,
+    });
+}
+
+fn receive_messages(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    inbox: Res<Inbox>,
+    mut badges: Query<&mut Badge>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyM) {
+        return;
+    }
+
+    if let Ok(mut badge) = badges.get_mut(inbox.badge) {
+        badge.count = Some(badge.count.unwrap_or(0) + 1);
+    }
+}
+
+fn read_messages(
+    inbox: Res<Inbox>,
+    states: Query<&InteractionState>,
+    mut badges: Query<&mut Badge>,
+) {
+    if !states.get(inbox.button).is_ok_and(|state| state.just_pressed) {
+        return;
+    }
+
+    if let Ok(mut badge) = badges.get_mut(inbox.badge) {
+        badge.count = Some(0);
+    }
+}
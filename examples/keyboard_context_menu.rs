@@ -0,0 +1,112 @@
+//! Keyboard Context Menu - Demonstrates navigating nested context menus with the keyboard
+//!
+//! Run with: cargo run --example keyboard_context_menu --features context_menu
+//!
+//! Right-click the canvas to open its menu, then leave the mouse alone. Up and
+//! Down move through the items (skipping separators and the disabled entry),
+//! Right opens "Export" and steps into it, and Right again enters "Image" or
+//! "Document". Left backs out one level. Enter picks the highlighted format
+//! and Escape closes everything. Moving the mouse takes over from wherever
+//! the keyboard left off.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, show_action)
+        .run();
+}
+
+#[derive(Component)]
+struct ActionText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let mut canvas = Entity::PLACEHOLDER;
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            canvas = parent
+                .spawn((
+                    Node {
+                        width: Val::Px(480.0),
+                        height: Val::Px(300.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.12, 0.12, 0.15)),
+                    BorderColor::all(Color::srgb(0.3, 0.3, 0.3)),
+                    BorderRadius::all(Val::Px(8.0)),
+                ))
+                .with_child((
+                    Text::new("Right-click the canvas, then use the arrow keys"),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.6, 0.6, 0.6)),
+                ))
+                .id();
+
+            parent.spawn((
+                Text::new("No action yet"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                ActionText,
+            ));
+        });
+
+    ContextMenuBuilder::new()
+        .action("undo", "Undo", Some("Ctrl+Z"))
+        .action_disabled("redo", "Redo", Some("Ctrl+Y"))
+        .separator()
+        .submenu("Export", |sub| {
+            sub.submenu("Image", |image| {
+                image
+                    .action("export_png", "PNG", None)
+                    .action("export_jpg", "JPEG", None)
+                    .action("export_webp", "WebP", None)
+            })
+            .submenu("Document", |document| {
+                document
+                    .action("export_pdf", "PDF", None)
+                    .action("export_svg", "SVG", None)
+            })
+            .separator()
+            .action("export_clipboard", "Copy to clipboard", Some("Ctrl+Shift+C"))
+        })
+        .checkbox("show_grid", "Show grid", true)
+        .build_for(&mut commands, canvas);
+}
+
+fn show_action(
+    mut actions: MessageReader<ContextMenuActionEvent>,
+    mut text: Query<&mut Text, With<ActionText>>,
+) {
+    for event in actions.read() {
+        if let Ok(mut text) = text.single_mut() {
+            text.0 = format!("Chose \"{}\"", event.id);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Keyboard Dropdown - Demonstrates dropdown keyboard navigation
+//!
+//! Run with: cargo run --example keyboard_dropdown --features dropdown
+//!
+//! Open the menu, then use Up/Down to move the highlight (it wraps around),
+//! Enter to pick the highlighted country and Escape to close without
+//! changing anything. Type the start of a name to jump to it - "can" finds
+//! Canada, and pressing "s" repeatedly steps through the S countries. The
+//! disabled entry is skipped.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+const COUNTRIES: [&str; 14] = [
+    "Argentina", "Australia", "Brazil", "Cambodia", "Canada", "Chile", "Denmark",
+    "Egypt", "Finland", "Norway", "Portugal", "Spain", "Sweden", "Switzerland",
+];
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, show_selection)
+        .run();
+}
+
+#[derive(Component)]
+struct StatusText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                padding: UiRect::all(Val::Px(40.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            DropdownBuilder::new(COUNTRIES.iter().map(|name| name.to_string()).collect())
+                .placeholder("Choose a country")
+                .option_disabled(2, true)
+                .max_visible_items(6)
+                .width(Val::Px(260.0))
+                .build(parent);
+
+            parent.spawn((
+                Text::new("No country selected"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                StatusText,
+            ));
+        });
+}
+
+fn show_selection(
+    dropdowns: Query<&DropdownData, Changed<DropdownData>>,
+    mut status: Query<&mut Text, With<StatusText>>,
+) {
+    for data in dropdowns.iter() {
+        if let (Some(country), Ok(mut text)) = (data.selected_value(), status.single_mut()) {
+            text.0 = format!("Selected: {}", country);
+        }
+    }
+}
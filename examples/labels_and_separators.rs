@@ -1,6 +1,10 @@
 //! Labels and Separators Example - Demonstrates text styles and dividers
 //!
 //! Run with: cargo run --example labels_and_separators --features "label separator"
+//!
+//! The "Fonts per role" labels use a display font for headings and a
+//! monospace font for code when `assets/fonts/display.ttf` and
+//! `assets/fonts/mono.ttf` exist; otherwise they fall back to Bevy's default font.
 
 use bevy::prelude::*;
 use bevy_ui_builders::prelude::*;
@@ -14,10 +18,26 @@ fn main() {
         .run();
 }
 
-fn setup(mut commands: Commands) {
+/// Optional display font for headings (relative to `assets/`)
+const DISPLAY_FONT: &str = "fonts/display.ttf";
+/// Optional monospace font for code labels (relative to `assets/`)
+const MONO_FONT: &str = "fonts/mono.ttf";
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>, theme: Res<UiTheme>) {
     // Camera
     commands.spawn(Camera2d);
 
+    // Theme with per-role fonts (only roles whose font file is present)
+    let mut font_theme = theme.clone();
+    if let Some(display) = load_optional_font(&asset_server, DISPLAY_FONT) {
+        font_theme = font_theme
+            .with_font(FontRole::Display, display.clone())
+            .with_font(FontRole::Heading, display);
+    }
+    if let Some(mono) = load_optional_font(&asset_server, MONO_FONT) {
+        font_theme = font_theme.with_font(FontRole::Monospace, mono);
+    }
+
     // Root node
     commands
         .spawn(Node {
@@ -31,7 +51,7 @@ fn setup(mut commands: Commands) {
         })
         .with_children(|parent| {
             // Labels section
-            create_labels_section(parent);
+            create_labels_section(parent, &font_theme);
 
             // Vertical separator
             SeparatorBuilder::new()
@@ -44,7 +64,14 @@ fn setup(mut commands: Commands) {
         });
 }
 
-fn create_labels_section(parent: &mut ChildSpawnerCommands) {
+fn load_optional_font(asset_server: &AssetServer, path: &str) -> Option<Handle<Font>> {
+    std::path::Path::new("assets")
+        .join(path)
+        .exists()
+        .then(|| asset_server.load(path))
+}
+
+fn create_labels_section(parent: &mut ChildSpawnerCommands, font_theme: &UiTheme) {
     parent
         .spawn(Node {
             flex_direction: FlexDirection::Column,
@@ -106,6 +133,22 @@ fn create_labels_section(parent: &mut ChildSpawnerCommands) {
                 .size(LabelSize::Body)
                 .margin(UiRect::left(Val::Px(30.0)))
                 .build(column);
+
+            separator().margin(UiRect::vertical(Val::Px(10.0))).build(column);
+
+            // Fonts per role (picked via ThemeTypography::font_for)
+            heading("Fonts per role")
+                .themed(font_theme)
+                .build(column);
+
+            label("Body text keeps the body font")
+                .themed(font_theme)
+                .build(column);
+
+            label("let total = items.iter().sum::<u32>();")
+                .monospace()
+                .themed(font_theme)
+                .build(column);
         });
 }
 
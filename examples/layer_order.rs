@@ -0,0 +1,111 @@
+//! Layer Order - Demonstrates z-layers
+//!
+//! Run with: cargo run --example layer_order --features panel,label,toast
+//!
+//! A pinned objectives panel sits in the top-right corner, right where toasts
+//! appear. It starts one above `ZLayer::Toast`, so toasts slide in under it.
+//! Press T to show a toast and L to drop the panel to the base layer and back.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::traits::UiBuilder;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (show_toasts, toggle_layer))
+        .run();
+}
+
+/// The panel's layer while pinned above toasts
+fn pinned_layer() -> i32 {
+    ZLayer::Toast.z_index() + 1
+}
+
+#[derive(Component, Clone)]
+struct PinnedPanel;
+
+#[derive(Component)]
+struct StatusText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("T shows a toast, L changes the panel's layer"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                StatusText,
+            ));
+
+            // Pinned over the corner toasts appear in
+            parent
+                .spawn(Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(8.0),
+                    right: Val::Px(8.0),
+                    ..default()
+                })
+                .with_children(|corner| {
+                    PanelBuilder::new()
+                        .width(Val::Px(260.0))
+                        .padding(UiRect::all(Val::Px(12.0)))
+                        .z_layer(pinned_layer())
+                        .insert(PinnedPanel)
+                        .build_with_children(corner, |panel| {
+                            LabelBuilder::new("Objectives").size(LabelSize::Heading).build(panel);
+                            LabelBuilder::new("Hold the bridge until dawn").build(panel);
+                            LabelBuilder::new("Keep the captain alive").build(panel);
+                        });
+                });
+        });
+}
+
+fn show_toasts(keyboard: Res<ButtonInput<KeyCode>>, mut toasts: ResMut<ToastQueue>) {
+    if keyboard.just_pressed(KeyCode::KeyT) {
+        ToastBuilder::new("Reinforcements are on their way")
+            .title("Radio")
+            .show(&mut toasts);
+    }
+}
+
+fn toggle_layer(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut panel: Query<&mut GlobalZIndex, With<PinnedPanel>>,
+    mut status: Query<&mut Text, With<StatusText>>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyL) {
+        return;
+    }
+    let Ok(mut z) = panel.single_mut() else {
+        return;
+    };
+
+    let above = z.0 != pinned_layer();
+    z.0 = if above { pinned_layer() } else { ZLayer::Base.z_index() };
+
+    if let Ok(mut text) = status.single_mut() {
+        text.0 = if above {
+            "Panel is above toasts".to_string()
+        } else {
+            "Panel is on the base layer - toasts cover it".to_string()
+        };
+    }
+}
@@ -0,0 +1,129 @@
+//! Layer Panel - Demonstrates a drag-to-reorder list
+//!
+//! Run with: cargo run --example layer_panel --features reorderable_list
+//!
+//! Drag a layer up or down the panel; a line shows where it will land.
+//! Dropping it reorders the list, and the preview on the right redraws the
+//! colored layers in the new stacking order (top of the list is in front).
+//! Right-click while dragging to cancel.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .insert_resource(Layers(vec![
+            ("UI", Color::srgb(0.9, 0.9, 0.9)),
+            ("Characters", Color::srgb(0.9, 0.5, 0.2)),
+            ("Terrain", Color::srgb(0.3, 0.7, 0.3)),
+            ("Sky", Color::srgb(0.3, 0.5, 0.9)),
+        ]))
+        .add_systems(Startup, setup)
+        .add_systems(Update, apply_reorder)
+        .run();
+}
+
+/// Layer names and preview colors, front-most first (mirrors the list order)
+#[derive(Resource)]
+struct Layers(Vec<(&'static str, Color)>);
+
+/// A preview square; depth 0 is drawn at the back
+#[derive(Component)]
+struct PreviewSquare {
+    depth: usize,
+}
+
+fn setup(mut commands: Commands, layers: Res<Layers>, theme: Res<UiTheme>) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(48.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(12.0),
+                    ..default()
+                })
+                .with_children(|panel| {
+                    panel.spawn((
+                        Text::new("Layers"),
+                        TextFont {
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+
+                    ReorderableListBuilder::new()
+                        .themed(&theme)
+                        .items(layers.0.iter().map(|(name, _)| *name))
+                        .width(Val::Px(220.0))
+                        .build(panel);
+                });
+
+            // Preview squares, back-most first so later ones draw on top
+            let count = layers.0.len();
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(240.0),
+                        height: Val::Px(240.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.12, 0.12, 0.15)),
+                ))
+                .with_children(|preview| {
+                    for depth in 0..count {
+                        let offset = 24.0 + depth as f32 * 28.0;
+                        preview.spawn((
+                            Node {
+                                position_type: PositionType::Absolute,
+                                left: Val::Px(offset),
+                                top: Val::Px(offset),
+                                width: Val::Px(110.0),
+                                height: Val::Px(110.0),
+                                ..default()
+                            },
+                            BackgroundColor(layers.0[count - 1 - depth].1),
+                            BorderRadius::all(Val::Px(6.0)),
+                            PreviewSquare { depth },
+                        ));
+                    }
+                });
+        });
+}
+
+/// Keep the layer list in sync and recolor the preview
+fn apply_reorder(
+    mut events: MessageReader<ListReorderedEvent>,
+    mut layers: ResMut<Layers>,
+    mut squares: Query<(&PreviewSquare, &mut BackgroundColor)>,
+) {
+    for event in events.read() {
+        let layer = layers.0.remove(event.from);
+        info!("Moved {} from {} to {}", layer.0, event.from, event.to);
+        layers.0.insert(event.to, layer);
+    }
+
+    if !layers.is_changed() {
+        return;
+    }
+
+    let count = layers.0.len();
+    for (square, mut background) in squares.iter_mut() {
+        background.0 = layers.0[count - 1 - square.depth].1;
+    }
+}
@@ -0,0 +1,149 @@
+//! Login Form - Demonstrates floating labels on text inputs
+//!
+//! Run with: cargo run --example login_form --features text_input,button
+//!
+//! Each field's label rests inside the input while it is empty, and floats
+//! up onto the top border when the field is focused or filled. Press Enter
+//! in either field to "sign in". The "Forgot password?" link underlines on
+//! hover.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::components::text_input::{TextInputSubmitEvent, TextInputVisual};
+use bevy_ui_builders::traits::UiBuilder;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (mask_password_fields, handle_sign_in, handle_forgot_password))
+        .run();
+}
+
+#[derive(Component, Clone)]
+struct EmailField;
+
+#[derive(Component, Clone)]
+struct PasswordField;
+
+#[derive(Component)]
+struct StatusText;
+
+#[derive(Component, Clone)]
+struct ForgotPasswordLink;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        padding: UiRect::all(Val::Px(32.0)),
+                        // Extra gap leaves room for the floated labels
+                        row_gap: Val::Px(28.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.12, 0.12, 0.15)),
+                    BorderRadius::all(Val::Px(8.0)),
+                ))
+                .with_children(|card| {
+                    card.spawn((
+                        Text::new("Sign In"),
+                        TextFont {
+                            font_size: 28.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+
+                    TextInputBuilder::new()
+                        .floating_label("Email")
+                        .with_width(Val::Px(320.0))
+                        .with_height(Val::Px(48.0))
+                        .insert(EmailField)
+                        .build(card);
+
+                    TextInputBuilder::new()
+                        .floating_label("Password")
+                        .with_width(Val::Px(320.0))
+                        .with_height(Val::Px(48.0))
+                        .insert(PasswordField)
+                        .build(card);
+
+                    link_button("Forgot password?")
+                        .size(ButtonSize::Small)
+                        .insert(ForgotPasswordLink)
+                        .build(card);
+
+                    // Pre-filled field starts with its label already floated
+                    TextInputBuilder::new()
+                        .floating_label("Server")
+                        .with_value("eu-west.example.com")
+                        .with_width(Val::Px(320.0))
+                        .with_height(Val::Px(48.0))
+                        .build(card);
+
+                    card.spawn((
+                        Text::new("Press Enter to sign in"),
+                        TextFont {
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.6, 0.6, 0.6)),
+                        StatusText,
+                    ));
+                });
+        });
+}
+
+/// Hide password characters behind asterisks
+fn mask_password_fields(mut fields: Query<&mut TextInputVisual, Added<PasswordField>>) {
+    for mut visual in fields.iter_mut() {
+        visual.mask_char = Some('*');
+    }
+}
+
+fn handle_sign_in(
+    mut submits: MessageReader<TextInputSubmitEvent>,
+    fields: Query<(), Or<(With<EmailField>, With<PasswordField>)>>,
+    mut status: Query<&mut Text, With<StatusText>>,
+) {
+    for event in submits.read() {
+        if !fields.contains(event.entity) {
+            continue;
+        }
+
+        if let Ok(mut text) = status.single_mut() {
+            text.0 = "Signing in...".to_string();
+        }
+    }
+}
+
+fn handle_forgot_password(
+    links: Query<&Interaction, (Changed<Interaction>, With<ForgotPasswordLink>)>,
+    mut status: Query<&mut Text, With<StatusText>>,
+) {
+    for interaction in links.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        if let Ok(mut text) = status.single_mut() {
+            text.0 = "Password reset email sent".to_string();
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Long Text Selection - Demonstrates scrolling in inputs narrower than their text
+//!
+//! Run with: cargo run --example long_text_selection --features text_input
+//!
+//! Both fields hold more text than fits. Click into one and use the arrow
+//! keys or Home/End - the text scrolls to keep the cursor in view. Press the
+//! mouse inside the text and drag past the right or left edge: the field
+//! keeps scrolling while you hold the mouse there, faster the further out
+//! you drag, and the selection grows with it.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::components::text_input::TextInputSelectionChangedEvent;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, show_selection)
+        .run();
+}
+
+#[derive(Component)]
+struct SelectionText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            text_input()
+                .with_value("https://example.com/downloads/releases/2026/game-of-the-year-edition/patch-notes-and-changelog.html")
+                .with_width(Val::Px(320.0))
+                .build(parent);
+
+            text_input()
+                .with_value("The quick brown fox jumps over the lazy dog while the five boxing wizards jump quickly.")
+                .with_width(Val::Px(240.0))
+                .build(parent);
+
+            parent.spawn((
+                Text::new("Drag past an edge to select more than fits"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                SelectionText,
+            ));
+        });
+}
+
+fn show_selection(
+    mut events: MessageReader<TextInputSelectionChangedEvent>,
+    mut status: Query<&mut Text, With<SelectionText>>,
+) {
+    for event in events.read() {
+        if let Ok(mut text) = status.single_mut() {
+            text.0 = format!("Selected {} characters", event.selected_text.chars().count());
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Looping Animations - Repeating and yoyo transitions
+//!
+//! A download arrow bobs forever, a "new" badge pulses three times and then
+//! settles, and a bell rattles a few
+//! times. An odd number of yoyo cycles ends on a forward swing, so the bell
+//! comes to rest where it started.
+//!
+//! Run with: cargo run --example looping_animations
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::animation::EnterAnimating;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(60.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            // Bounce down and back up, forever
+            spawn_tile(
+                parent,
+                "v",
+                "Downloading",
+                Color::srgb(0.2, 0.5, 0.9),
+                Transition::slide_from_top(10.0, 0.45)
+                    .ease_in_out()
+                    .repeat_forever()
+                    .yoyo(true),
+            );
+
+            // Three pulses, then rest
+            spawn_tile(
+                parent,
+                "!",
+                "New",
+                Color::srgb(0.85, 0.3, 0.3),
+                Transition::pulse(1.15, 0.4).repeat(3),
+            );
+
+            // Rattle back and forth, ending at rest
+            spawn_tile(
+                parent,
+                "*",
+                "Ringing",
+                Color::srgb(0.9, 0.7, 0.2),
+                Transition::slide_from_left(6.0, 0.12).repeat(5).yoyo(true),
+            );
+        });
+}
+
+fn spawn_tile(
+    parent: &mut ChildSpawnerCommands,
+    glyph: &str,
+    caption: &str,
+    color: Color,
+    transition: Transition,
+) {
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(12.0),
+            ..default()
+        })
+        .with_children(|tile| {
+            tile.spawn((
+                Node {
+                    width: Val::Px(64.0),
+                    height: Val::Px(64.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(color),
+                BorderRadius::all(Val::Px(32.0)),
+                UiAnimation::new().with_enter(transition),
+                EnterAnimating,
+            ))
+            .with_children(|icon| {
+                icon.spawn((
+                    Text::new(glyph),
+                    TextFont {
+                        font_size: 32.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+            });
+
+            tile.spawn((
+                Text::new(caption),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+            ));
+        });
+}
@@ -0,0 +1,120 @@
+//! Loot Rarity - Demonstrates inventory slots colored by item rarity
+//!
+//! Run with: cargo run --example loot_rarity --features inventory
+//!
+//! The bag holds one item of each rarity tier, from a grey common rag to an
+//! orange legendary blade with a glowing border. Click an item to upgrade it
+//! to the next tier; legendary items wrap back around to common.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::traits::UiBuilder;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(PostStartup, fill_bag)
+        .add_systems(Update, upgrade_clicked_item)
+        .run();
+}
+
+/// Rarity tier of an item, kept next to its `InventoryItem`
+#[derive(Component)]
+struct Tier(ItemRarity);
+
+const LOOT: [(&str, ItemRarity); 5] = [
+    ("Rag", ItemRarity::Common),
+    ("Boots", ItemRarity::Uncommon),
+    ("Ring", ItemRarity::Rare),
+    ("Staff", ItemRarity::Epic),
+    ("Blade", ItemRarity::Legendary),
+];
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            InventoryGridBuilder::new(5, 2)
+                .slot_size(Val::Px(64.0))
+                .slot_spacing(Val::Px(8.0))
+                .build(parent);
+
+            parent.spawn((
+                Text::new("Click an item to upgrade its rarity"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
+
+/// Put one item of each tier into the first row of slots
+fn fill_bag(mut commands: Commands, mut slots: Query<(Entity, &mut InventorySlot)>) {
+    for (slot_entity, mut slot) in slots.iter_mut() {
+        let Some((name, rarity)) = LOOT.get(slot.index) else {
+            continue;
+        };
+
+        let item = commands
+            .spawn((
+                Text::new(*name),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                InventoryItem {
+                    slot: slot_entity,
+                    item_id: name.to_lowercase(),
+                    stack_size: 1,
+                    max_stack: 1,
+                    rarity: Some(rarity.color()),
+                },
+                Tier(*rarity),
+                ChildOf(slot_entity),
+            ))
+            .id();
+        slot.item = Some(item);
+    }
+}
+
+fn upgrade_clicked_item(
+    mut clicks: MessageReader<SlotClickEvent>,
+    slots: Query<&InventorySlot>,
+    mut items: Query<(&mut InventoryItem, &mut Tier)>,
+) {
+    for event in clicks.read() {
+        let Some(item) = slots.get(event.slot).ok().and_then(|slot| slot.item) else {
+            continue;
+        };
+        let Ok((mut item, mut tier)) = items.get_mut(item) else {
+            continue;
+        };
+
+        tier.0 = match tier.0 {
+            ItemRarity::Common => ItemRarity::Uncommon,
+            ItemRarity::Uncommon => ItemRarity::Rare,
+            ItemRarity::Rare => ItemRarity::Epic,
+            ItemRarity::Epic => ItemRarity::Legendary,
+            ItemRarity::Legendary => ItemRarity::Common,
+        };
+        item.rarity = Some(tier.0.color());
+    }
+}
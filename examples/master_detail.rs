@@ -0,0 +1,144 @@
+//! Master Detail - Demonstrates a resizable split pane editor layout
+//!
+//! Run with: cargo run --example master_detail --features split_pane,button
+//!
+//! A list of documents sits on the left and the selected document's details on
+//! the right. Drag the divider between them to resize the list (it stays
+//! between 160 and 400 pixels). The details pane is itself split vertically
+//! into an editor and a console.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+const DOCUMENTS: &[(&str, &str)] = &[
+    ("main.rs", "Entry point: builds the App and adds the game plugins."),
+    ("player.rs", "Player movement, input handling and the health component."),
+    ("enemy.rs", "Enemy spawning waves and simple chase AI."),
+    ("ui.rs", "HUD layout: health bar, score counter and pause menu."),
+];
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (select_document, show_pane_size))
+        .run();
+}
+
+#[derive(Component, Clone)]
+struct DocumentButton(usize);
+
+#[derive(Component)]
+struct DetailText;
+
+#[derive(Component)]
+struct ConsoleText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|root| {
+            SplitPaneBuilder::new(Orientation::Horizontal)
+                .ratio(0.25)
+                .min_first(160.0)
+                .max_first(400.0)
+                .min_second(240.0)
+                .build_with_panes(root, document_list, detail_view);
+        });
+}
+
+fn document_list(list: &mut ChildSpawnerCommands) {
+    list.spawn(Node {
+        width: Val::Percent(100.0),
+        flex_direction: FlexDirection::Column,
+        padding: UiRect::all(Val::Px(12.0)),
+        row_gap: Val::Px(6.0),
+        ..default()
+    })
+    .with_children(|column| {
+        for (index, (name, _)) in DOCUMENTS.iter().enumerate() {
+            ButtonBuilder::new(*name)
+                .style(ButtonStyle::Ghost)
+                .width(Val::Percent(100.0))
+                .insert(DocumentButton(index))
+                .build(column);
+        }
+    });
+}
+
+fn detail_view(detail: &mut ChildSpawnerCommands) {
+    SplitPaneBuilder::new(Orientation::Vertical)
+        .ratio(0.7)
+        .min_first(120.0)
+        .min_second(60.0)
+        .divider_style(SeparatorStyle::Thick)
+        .build_with_panes(
+            detail,
+            |editor| {
+                editor.spawn((
+                    Text::new(DOCUMENTS[0].1),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                    Node {
+                        margin: UiRect::all(Val::Px(16.0)),
+                        ..default()
+                    },
+                    DetailText,
+                ));
+            },
+            |console| {
+                console.spawn((
+                    Text::new("Drag a divider to resize"),
+                    TextFont {
+                        font_size: 13.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.6, 0.8, 0.6)),
+                    Node {
+                        margin: UiRect::all(Val::Px(12.0)),
+                        ..default()
+                    },
+                    ConsoleText,
+                ));
+            },
+        );
+}
+
+fn select_document(
+    buttons: Query<(&Interaction, &DocumentButton), Changed<Interaction>>,
+    mut detail: Query<&mut Text, With<DetailText>>,
+) {
+    for (interaction, document) in buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        if let Ok(mut text) = detail.single_mut() {
+            text.0 = DOCUMENTS[document.0].1.to_string();
+        }
+    }
+}
+
+fn show_pane_size(
+    mut resized: MessageReader<SplitResizedEvent>,
+    mut console: Query<&mut Text, With<ConsoleText>>,
+) {
+    if let Some(event) = resized.read().last() {
+        if let Ok(mut text) = console.single_mut() {
+            text.0 = format!("Pane resized to {:.0}px ({:.0}%)", event.first_size, event.ratio * 100.0);
+        }
+    }
+}
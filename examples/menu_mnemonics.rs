@@ -0,0 +1,144 @@
+//! Menu Mnemonics - Demonstrates underlined access keys
+//!
+//! Run with: cargo run --example menu_mnemonics --features button,label
+//!
+//! The menu bar items are written "&File", "&Edit", "&View" and "&Help" and
+//! built with `mnemonic_label`, so each shows its first letter underlined and
+//! Alt plus that letter clicks it, just like the mouse. The "&Mute" label below is bound to the toggle button
+//! next to it with `mnemonic_for`, so Alt+M flips the sound.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (open_menus, toggle_mute))
+        .run();
+}
+
+#[derive(Component)]
+struct MenuItem(&'static str);
+
+#[derive(Component)]
+struct MuteButton;
+
+#[derive(Component)]
+struct StatusText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            // Menu bar
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        padding: UiRect::all(Val::Px(4.0)),
+                        column_gap: Val::Px(4.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.14, 0.14, 0.17)),
+                ))
+                .with_children(|bar| {
+                    for (text, name) in [
+                        ("&File", "File"),
+                        ("&Edit", "Edit"),
+                        ("&View", "View"),
+                        ("&Help", "Help"),
+                    ] {
+                        ButtonBuilder::new(text)
+                            .style(ButtonStyle::Ghost)
+                            .size(ButtonSize::Small)
+                            .mnemonic_label()
+                            .insert(MenuItem(name))
+                            .build(bar);
+                    }
+                });
+
+            parent
+                .spawn(Node {
+                    flex_grow: 1.0,
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(16.0),
+                    ..default()
+                })
+                .with_children(|content| {
+                    content
+                        .spawn(Node {
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Px(12.0),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            let mute = ButtonBuilder::new("Off")
+                                .style(ButtonStyle::Secondary)
+                                .insert(MuteButton)
+                                .build(row);
+
+                            LabelBuilder::new("&Mute")
+                                .mnemonic_for(mute)
+                                .build(row);
+                        });
+
+                    content.spawn((
+                        Text::new("Hold Alt and press an underlined letter"),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                        StatusText,
+                    ));
+                });
+        });
+}
+
+fn open_menus(
+    items: Query<(&Interaction, &MenuItem), Changed<Interaction>>,
+    mut status: Query<&mut Text, With<StatusText>>,
+) {
+    for (interaction, item) in items.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        if let Ok(mut text) = status.single_mut() {
+            text.0 = format!("Opened the {} menu", item.0);
+        }
+    }
+}
+
+fn toggle_mute(
+    buttons: Query<(&Interaction, &Children), (Changed<Interaction>, With<MuteButton>)>,
+    mut texts: Query<&mut Text>,
+    mut muted: Local<bool>,
+) {
+    for (interaction, children) in buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        *muted = !*muted;
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                text.0 = if *muted { "On" } else { "Off" }.to_string();
+            }
+        }
+    }
+}
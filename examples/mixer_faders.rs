@@ -0,0 +1,76 @@
+//! Mixer Faders - Demonstrates vertical sliders
+//!
+//! Run with: cargo run --example mixer_faders --features slider
+//!
+//! A row of channel faders, each a vertical slider whose value grows
+//! upwards. Drag a fader or click anywhere on its track to set the level.
+//! The last one is inverted, so its value grows downwards.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(24.0),
+                    ..default()
+                })
+                .with_children(|mixer| {
+                    for (channel, level) in [("Drums", 0.0), ("Bass", -6.0), ("Keys", -12.0), ("Vocals", 3.0)] {
+                        SliderBuilder::new(-60.0..6.0)
+                            .orientation(Orientation::Vertical)
+                            .label(channel)
+                            .step(1.0)
+                            .format(ValueFormat::Integer)
+                            .value(level)
+                            .default_marker(0.0)
+                            .height(Val::Px(240.0))
+                            .build(mixer);
+                    }
+
+                    SliderBuilder::new(0.0..100.0)
+                        .orientation(Orientation::Vertical)
+                        .inverted(true)
+                        .label("Depth")
+                        .step(1.0)
+                        .format(ValueFormat::Integer)
+                        .value(25.0)
+                        .height(Val::Px(240.0))
+                        .build(mixer);
+                });
+
+            parent.spawn((
+                Text::new("Drag a fader up or down; double-click to reset to 0 dB"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
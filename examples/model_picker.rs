@@ -0,0 +1,86 @@
+//! Model Picker - Demonstrates two-line dropdown options with descriptions
+//!
+//! Run with: cargo run --example model_picker --features dropdown
+//!
+//! Each model is listed with its name and a short description under it,
+//! plus a color swatch for its speed tier. The button shows only the name.
+//! The second dropdown mixes in a plain option, which stays one line high.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+/// Name, description and swatch color of each model
+const MODELS: [(&str, &str, Color); 3] = [
+    ("Swift", "Fast and light, for quick everyday tasks", Color::srgb(0.3, 0.8, 0.4)),
+    ("Balanced", "A good mix of speed and quality", Color::srgb(0.95, 0.75, 0.2)),
+    ("Deep", "Slowest, for long and difficult problems", Color::srgb(0.85, 0.3, 0.3)),
+];
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, show_selection)
+        .run();
+}
+
+#[derive(Component)]
+struct StatusText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                padding: UiRect::all(Val::Px(40.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            let mut picker = DropdownBuilder::new(MODELS.iter().map(|(name, ..)| name.to_string()).collect())
+                .placeholder("Choose a model")
+                .width(Val::Px(260.0));
+            for (index, (_, description, color)) in MODELS.iter().enumerate() {
+                picker = picker
+                    .option_description(index, *description)
+                    .option_color(index, *color);
+            }
+            picker.build(parent);
+
+            // A plain option keeps its compact single-line row
+            DropdownBuilder::new(vec!["Automatic".into(), "Swift".into(), "Deep".into()])
+                .placeholder("Fallback model")
+                .option_description(1, MODELS[0].1)
+                .option_description(2, MODELS[2].1)
+                .width(Val::Px(260.0))
+                .build(parent);
+
+            parent.spawn((
+                Text::new("No model selected"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                StatusText,
+            ));
+        });
+}
+
+fn show_selection(
+    dropdowns: Query<&DropdownData, Changed<DropdownData>>,
+    mut status: Query<&mut Text, With<StatusText>>,
+) {
+    for data in dropdowns.iter() {
+        if let (Some(value), Ok(mut text)) = (data.selected_value(), status.single_mut()) {
+            text.0 = format!("Selected: {value}");
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! Notched Slider - Demonstrates stepped sliders with tick marks
+//!
+//! Run with: cargo run --example notched_slider --features slider
+//!
+//! Each slider snaps to whole steps and shows a notch at every one. The
+//! difficulty slider runs from 0 to 10 in steps of 3, so its last notch sits
+//! at 10 rather than 9. Click a track to focus it, then use the arrow keys
+//! to move one step at a time.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            SliderBuilder::new(1.0..8.0)
+                .label("Render distance (chunks)")
+                .step(1.0)
+                .format(ValueFormat::Integer)
+                .value(4.0)
+                .show_ticks()
+                .width(Val::Px(320.0))
+                .build(parent);
+
+            SliderBuilder::new(0.0..10.0)
+                .label("Difficulty")
+                .step(3.0)
+                .format(ValueFormat::Integer)
+                .value(3.0)
+                .show_ticks()
+                .width(Val::Px(320.0))
+                .build(parent);
+
+            parent.spawn((
+                Text::new("Click a track, then use the arrow keys to step"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
@@ -0,0 +1,85 @@
+//! Notes Editor - Demonstrates multiline text inputs
+//!
+//! Run with: cargo run --example notes_editor --features text_input
+//!
+//! The notes box wraps long lines at word boundaries and scrolls down as the
+//! text grows. Enter starts a new line, Up/Down move between the wrapped
+//! lines and Home/End jump to either end of one. Ctrl+Enter saves the note;
+//! the quest log below submits with Shift+Enter instead.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::components::text_input::TextInputSubmitEvent;
+use bevy_ui_builders::traits::UiBuilder;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, show_saved)
+        .run();
+}
+
+#[derive(Component)]
+struct StatusText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            TextInputBuilder::new()
+                .multiline(true)
+                .with_value(
+                    "Meet the blacksmith at dawn. She wants three iron ingots and the \
+                     wolf pelt from the northern woods before she'll repair the sword.",
+                )
+                .with_placeholder("Notes")
+                .with_width(Val::Px(360.0))
+                .with_height(Val::Px(120.0))
+                .build(parent);
+
+            TextInputBuilder::new()
+                .multiline(true)
+                .submit_key(SubmitKey::ShiftEnter)
+                .with_placeholder("Quest log (Shift+Enter to save)")
+                .with_width(Val::Px(360.0))
+                .with_height(Val::Px(80.0))
+                .build(parent);
+
+            parent.spawn((
+                Text::new("Ctrl+Enter saves the notes"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                StatusText,
+            ));
+        });
+}
+
+fn show_saved(
+    mut submits: MessageReader<TextInputSubmitEvent>,
+    mut status: Query<&mut Text, With<StatusText>>,
+) {
+    for event in submits.read() {
+        if let Ok(mut text) = status.single_mut() {
+            let lines = event.text.lines().count();
+            text.0 = format!("Saved {} characters on {} lines", event.text.chars().count(), lines);
+        }
+    }
+}
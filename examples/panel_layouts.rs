@@ -1,6 +1,7 @@
-//! Panel Layouts Example - Demonstrates different panel styles and nesting
+//! Panel Layouts Example - Demonstrates different panel styles, nesting, scrolling
+//! and showing/hiding a panel without rebuilding it
 //!
-//! Run with: cargo run --example panel_layouts --features panel
+//! Run with: cargo run --example panel_layouts --features panel,button
 
 use bevy::prelude::*;
 use bevy_ui_builders::prelude::*;
@@ -11,9 +12,16 @@ fn main() {
         .add_plugins(DefaultPlugins)
         .add_plugins(UiBuilderPlugin)
         .add_systems(Startup, setup)
+        .add_systems(Update, toggle_advanced_options)
         .run();
 }
 
+#[derive(Component, Clone)]
+struct AdvancedOptionsToggle;
+
+#[derive(Component, Clone)]
+struct AdvancedOptions;
+
 fn setup(mut commands: Commands) {
     // Camera
     commands.spawn(Camera2d);
@@ -137,18 +145,34 @@ fn setup(mut commands: Commands) {
                         });
                 });
 
-            // Nested panels example
+            // Scrollable sidebar + nested panels example
             parent
                 .spawn(Node {
                     width: Val::Px(900.0),
+                    column_gap: Val::Px(15.0),
                     ..default()
                 })
                 .with_children(|container| {
+                    // Sidebar with more options than fit - scrolls internally
+                    PanelBuilder::new()
+                        .style(PanelStyle::Bordered)
+                        .with_title("Options")
+                        .width(Val::Px(220.0))
+                        .row_gap(Val::Px(6.0))
+                        .scrollable(Val::Px(140.0))
+                        .build_with_children(container, |sidebar| {
+                            for i in 1..=20 {
+                                label(format!("Option {}", i))
+                                    .size(LabelSize::Body)
+                                    .build(sidebar);
+                            }
+                        });
+
                     PanelBuilder::new()
                         .style(PanelStyle::Card)
                         .with_title("Nested Panels Example")
                         .padding(UiRect::all(Val::Px(20.0)))
-                        .width(Val::Percent(100.0))
+                        .flex_grow(1.0)
                         .build_with_children(container, |outer_panel| {
                             label("This is the outer panel")
                                 .size(LabelSize::Body)
@@ -180,5 +204,38 @@ fn setup(mut commands: Commands) {
                                 });
                         });
                 });
+
+            // Collapsible advanced options - hidden, not despawned
+            ButtonBuilder::new("Advanced options")
+                .style(ButtonStyle::Secondary)
+                .insert(AdvancedOptionsToggle)
+                .build(parent);
+
+            PanelBuilder::new()
+                .style(PanelStyle::Bordered)
+                .width(Val::Px(400.0))
+                .padding(UiRect::all(Val::Px(15.0)))
+                .insert(AdvancedOptions)
+                .visible(false)
+                .build_with_children(parent, |panel| {
+                    label("Render scale: 100%").build(panel);
+                    label("Shadow quality: High").build(panel);
+                    label("Vertical sync: On").build(panel);
+                });
         });
+}
+
+/// Show or hide the advanced options panel each time the button is clicked
+fn toggle_advanced_options(
+    buttons: Query<&Interaction, (Changed<Interaction>, With<AdvancedOptionsToggle>)>,
+    panels: Query<(Entity, Has<UiHidden>), With<AdvancedOptions>>,
+    mut visibility: MessageWriter<SetVisible>,
+) {
+    if !buttons.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        return;
+    }
+
+    for (panel, hidden) in panels.iter() {
+        visibility.write(SetVisible::new(panel, hidden));
+    }
 }
\ No newline at end of file
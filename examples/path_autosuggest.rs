@@ -0,0 +1,96 @@
+//! Path Autosuggest - Demonstrates ghost suggestions in a text input
+//!
+//! Run with: cargo run --example path_autosuggest --features text_input
+//!
+//! Type the start of a path (try "a" or "assets/s") and the rest of the first
+//! matching directory appears greyed out after the cursor, like a shell's
+//! autosuggestion. Press Tab to fill it in, then keep typing to descend into
+//! its subdirectories. Shift+Tab still moves focus back as usual.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::components::text_input::{GhostSuggestion, TextInputChangeEvent};
+use bevy_ui_builders::traits::UiBuilder;
+
+const DIRECTORIES: &[&str] = &[
+    "assets/",
+    "assets/audio/",
+    "assets/audio/music/",
+    "assets/audio/sfx/",
+    "assets/fonts/",
+    "assets/shaders/",
+    "assets/sprites/",
+    "assets/sprites/characters/",
+    "assets/textures/",
+    "assets/textures/terrain/",
+    "saves/",
+    "screenshots/",
+];
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, suggest_directory)
+        .run();
+}
+
+#[derive(Component, Clone)]
+struct PathInput;
+
+/// First directory that continues the typed path, if any
+fn next_directory(text: &str) -> Option<&'static str> {
+    let typed = text.to_lowercase();
+    DIRECTORIES.iter()
+        .copied()
+        .find(|directory| directory.len() > typed.len() && directory.starts_with(typed.as_str()))
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            TextInputBuilder::new()
+                .with_placeholder("Path")
+                .ghost_suggestion(DIRECTORIES[0])
+                .with_width(Val::Px(360.0))
+                .insert(PathInput)
+                .build(parent);
+
+            parent.spawn((
+                Text::new("Tab completes the greyed-out directory"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
+
+/// Point the ghost at the next directory whenever the path changes
+fn suggest_directory(
+    mut changes: MessageReader<TextInputChangeEvent>,
+    mut inputs: Query<&mut GhostSuggestion, With<PathInput>>,
+) {
+    for change in changes.read() {
+        if let Ok(mut ghost) = inputs.get_mut(change.entity) {
+            let suggestion = next_directory(&change.text).unwrap_or_default();
+            ghost.set_if_neq(GhostSuggestion(suggestion.to_string()));
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! Placeholder Styles - Demonstrates per-input placeholder colors
+//!
+//! Run with: cargo run --example placeholder_styles --features text_input
+//!
+//! Required fields show their placeholder in a warm color and optional ones
+//! in a faint gray, so it's clear which still need filling in. Typing into a
+//! field switches it to the regular text color.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::traits::UiBuilder;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            for (placeholder, required) in [
+                ("Display name (required)", true),
+                ("Email (required)", true),
+                ("Website (optional)", false),
+                ("Bio (optional)", false),
+            ] {
+                let color = if required {
+                    Color::srgb(0.95, 0.65, 0.35)
+                } else {
+                    Color::srgba(0.6, 0.6, 0.6, 0.4)
+                };
+                TextInputBuilder::new()
+                    .with_placeholder(placeholder)
+                    .placeholder_color(color)
+                    .with_width(Val::Px(320.0))
+                    .build(parent);
+            }
+
+            parent.spawn((
+                Text::new("Orange placeholders mark required fields"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
@@ -0,0 +1,95 @@
+//! Price Filter - Demonstrates a two-handle range slider
+//!
+//! Run with: cargo run --example price_filter --features slider
+//!
+//! Drag either handle to narrow the price range; the list below only shows
+//! the items inside it. The handles can meet but never pass each other.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+/// Items for sale and their prices
+const ITEMS: [(&str, f32); 6] = [
+    ("Wooden sword", 15.0),
+    ("Leather boots", 40.0),
+    ("Iron shield", 85.0),
+    ("Travel cloak", 120.0),
+    ("Steel helm", 240.0),
+    ("Enchanted bow", 450.0),
+];
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, show_matches)
+        .run();
+}
+
+#[derive(Component)]
+struct ResultsText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            RangeSliderBuilder::new(0.0..500.0)
+                .label("Price")
+                .values(25.0, 250.0)
+                .step(5.0)
+                .format(ValueFormat::Custom(|value| format!("{}g", value as i32)))
+                .width(Val::Px(360.0))
+                .build(parent);
+
+            parent.spawn((
+                Text::new(matching(25.0, 250.0)),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                ResultsText,
+            ));
+        });
+}
+
+/// The items priced within `low..=high`, one per line
+fn matching(low: f32, high: f32) -> String {
+    let items: Vec<_> = ITEMS
+        .iter()
+        .filter(|(_, price)| (low..=high).contains(price))
+        .map(|(name, price)| format!("{} - {}g", name, price))
+        .collect();
+
+    if items.is_empty() {
+        "Nothing in this price range".to_string()
+    } else {
+        items.join("\n")
+    }
+}
+
+fn show_matches(
+    mut changes: MessageReader<RangeSliderChanged>,
+    mut results: Query<&mut Text, With<ResultsText>>,
+) {
+    let Some(change) = changes.read().last() else {
+        return;
+    };
+    if let Ok(mut text) = results.single_mut() {
+        text.0 = matching(change.low, change.high);
+    }
+}
@@ -0,0 +1,126 @@
+//! Profile Form - Demonstrates setting text input values from game code
+//!
+//! Run with: cargo run --example profile_form --features text_input,button
+//!
+//! "Load save" fills the form with saved data without sending change events;
+//! "Reset" clears it and does send them, so the status line counts the fields
+//! it changed. Either can be undone with Ctrl+Z in a focused field.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::components::text_input::TextInputChangeEvent;
+use bevy_ui_builders::traits::UiBuilder;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (load_or_reset, show_changes))
+        .run();
+}
+
+/// A profile field and the value the save file has for it
+#[derive(Component)]
+struct ProfileField {
+    saved: &'static str,
+}
+
+#[derive(Component, Clone)]
+struct LoadButton;
+
+#[derive(Component, Clone)]
+struct ResetButton;
+
+#[derive(Component)]
+struct StatusText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            for (placeholder, saved) in [
+                ("Character name", "Aldric the Bold"),
+                ("Home town", "Rivermoor"),
+                ("Motto", "Steel before silver"),
+            ] {
+                let field = TextInputBuilder::new()
+                    .with_placeholder(placeholder)
+                    .with_width(Val::Px(320.0))
+                    .build(parent);
+                parent.commands().entity(field).insert(ProfileField { saved });
+            }
+
+            parent
+                .spawn(Node {
+                    column_gap: Val::Px(12.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    ButtonBuilder::new("Load save")
+                        .style(ButtonStyle::Primary)
+                        .insert(LoadButton)
+                        .build(row);
+                    ButtonBuilder::new("Reset")
+                        .style(ButtonStyle::Secondary)
+                        .insert(ResetButton)
+                        .build(row);
+                });
+
+            parent.spawn((
+                Text::new("Load the save or type something, then reset"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                StatusText,
+            ));
+        });
+}
+
+fn load_or_reset(
+    load: Query<&Interaction, (Changed<Interaction>, With<LoadButton>)>,
+    reset: Query<&Interaction, (Changed<Interaction>, With<ResetButton>)>,
+    fields: Query<(Entity, &ProfileField)>,
+    mut set_value: MessageWriter<SetTextInputValue>,
+) {
+    let pressed = |interaction: &Interaction| *interaction == Interaction::Pressed;
+
+    if load.iter().any(pressed) {
+        for (entity, field) in fields.iter() {
+            set_value.write(SetTextInputValue::new(entity, field.saved).silent());
+        }
+    }
+    if reset.iter().any(pressed) {
+        for (entity, _) in fields.iter() {
+            set_value.write(SetTextInputValue::new(entity, ""));
+        }
+    }
+}
+
+fn show_changes(
+    mut changes: MessageReader<TextInputChangeEvent>,
+    mut status: Query<&mut Text, With<StatusText>>,
+) {
+    let count = changes.read().count();
+    if count == 0 {
+        return;
+    }
+    if let Ok(mut text) = status.single_mut() {
+        text.0 = format!("{count} change event(s) this frame");
+    }
+}
@@ -10,7 +10,7 @@ fn main() {
         .add_plugins(DefaultPlugins)
         .add_plugins(UiBuilderPlugin)
         .add_systems(Startup, setup)
-        .add_systems(Update, animate_progress)
+        .add_systems(Update, (animate_progress, restart_on_complete))
         .run();
 }
 
@@ -19,6 +19,12 @@ struct AnimatedProgress {
     speed: f32,
 }
 
+/// Text showing how many times the animated bar has finished loading
+#[derive(Component)]
+struct LoadStatus {
+    completed: u32,
+}
+
 fn setup(mut commands: Commands) {
     // Camera
     commands.spawn(Camera2d);
@@ -102,6 +108,16 @@ fn setup(mut commands: Commands) {
                             .build(section);
 
                         animated_bar = Some(bar);
+
+                        section.spawn((
+                            Text::new("Loading..."),
+                            TextFont {
+                                font_size: 12.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.6, 0.6, 0.6)),
+                            LoadStatus { completed: 0 },
+                        ));
                     });
 
                     // Multiple progress bars (stacked)
@@ -167,9 +183,27 @@ fn animate_progress(
     time: Res<Time>,
 ) {
     for (mut progress_bar, animated) in query.iter_mut().zip(animated_query.iter()) {
-        progress_bar.value += animated.speed * time.delta_secs();
-        if progress_bar.value > 1.0 {
-            progress_bar.value = 0.0;
+        // Fill up and hold at 100% - restart_on_complete resets the bar
+        progress_bar.value = (progress_bar.value + animated.speed * time.delta_secs()).min(1.0);
+    }
+}
+
+/// React to the loading bar filling up without polling its value
+fn restart_on_complete(
+    mut events: MessageReader<ProgressCompleteEvent>,
+    mut bars: Query<&mut ProgressBar, With<AnimatedProgress>>,
+    mut status: Query<(&mut Text, &mut LoadStatus)>,
+) {
+    for event in events.read() {
+        let Ok(mut bar) = bars.get_mut(event.entity) else {
+            continue;
+        };
+
+        for (mut text, mut load_status) in status.iter_mut() {
+            load_status.completed += 1;
+            **text = format!("Loaded {} time(s) - restarting", load_status.completed);
         }
+
+        bar.value = 0.0;
     }
-}
\ No newline at end of file
+}
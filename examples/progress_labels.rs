@@ -0,0 +1,58 @@
+//! Progress Labels - Demonstrates label placement on progress bars
+//!
+//! Run with: cargo run --example progress_labels --features progress
+//!
+//! The thin download bar shows its percentage outside, to the right of the
+//! bar. The tall install bar shows it inside, centered: the text is white
+//! over the dark track and turns black or white to contrast with the fill
+//! once the fill passes it. Both bars loop from 0% to 100%.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, advance_progress)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(32.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            ProgressBarBuilder::new(0.0)
+                .style(ProgressBarStyle::Thin)
+                .width(Val::Px(360.0))
+                .label_position(ProgressLabelPosition::Outside)
+                .build(parent);
+
+            ProgressBarBuilder::new(0.3)
+                .variant(SemanticVariant::Warning)
+                .width(Val::Px(360.0))
+                .height(Val::Px(22.0))
+                .label_position(ProgressLabelPosition::Inside)
+                .build(parent);
+        });
+}
+
+fn advance_progress(time: Res<Time>, mut bars: Query<&mut ProgressBar>) {
+    for mut bar in bars.iter_mut() {
+        bar.value = (bar.value + time.delta_secs() * 0.1) % 1.0;
+    }
+}
@@ -0,0 +1,64 @@
+//! Regex Filter - Demonstrates regex-filtered text inputs
+//!
+//! Run with: cargo run --example regex_filter --features text_input,regex
+//!
+//! Each field only accepts text its pattern allows, checked against the whole
+//! text an edit would produce. Try typing letters into the phone number, a
+//! second dash, or a fifth digit after the dash - they are dropped, wherever
+//! the cursor is. The patterns accept partial entries so the fields can be
+//! filled in one character at a time.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::traits::UiBuilder;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            for (placeholder, pattern) in [
+                // 555-0134
+                ("Phone (555-0134)", r"\d{0,3}(-\d{0,4})?"),
+                // Lowercase start, then letters, digits or underscores
+                ("Username", r"([a-z][a-z0-9_]{0,15})?"),
+                // Up to 3 letters, a space, up to 3 digits
+                ("Licence plate (ABC 123)", r"[A-Z]{0,3}( \d{0,3})?"),
+            ] {
+                TextInputBuilder::new()
+                    .with_placeholder(placeholder)
+                    .with_filter(InputFilter::regex(pattern))
+                    .with_width(Val::Px(320.0))
+                    .build(parent);
+            }
+
+            parent.spawn((
+                Text::new("Invalid characters are ignored"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
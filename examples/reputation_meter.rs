@@ -0,0 +1,108 @@
+//! Reputation Meter - Demonstrates a display-only slider
+//!
+//! Run with: cargo run --example reputation_meter --features slider
+//!
+//! The reputation bar uses slider styling but can't be dragged, clicked or
+//! nudged with the arrow keys - it only changes when the game says so.
+//! Press G to do a good deed and B to do a bad one.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (deeds, show_standing).chain())
+        .run();
+}
+
+/// The reputation meter
+#[derive(Resource)]
+struct Reputation(SliderRef);
+
+#[derive(Component)]
+struct StatusText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let mut reputation = None;
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            reputation = Some(
+                SliderBuilder::new(0.0..100.0)
+                    .label("Reputation")
+                    .value(50.0)
+                    .format(ValueFormat::Integer)
+                    .display_only(true)
+                    .width(Val::Px(320.0))
+                    .build_ref(parent),
+            );
+
+            parent.spawn((
+                Text::new("G for a good deed, B for a bad one"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                StatusText,
+            ));
+        });
+
+    if let Some(reputation) = reputation {
+        commands.insert_resource(Reputation(reputation));
+    }
+}
+
+fn deeds(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    reputation: Res<Reputation>,
+    sliders: Query<&Slider>,
+) {
+    let Some(current) = reputation.0.get_value(&sliders) else {
+        return;
+    };
+
+    if keyboard.just_pressed(KeyCode::KeyG) {
+        reputation.0.set_value(&mut commands, current + 10.0);
+    }
+    if keyboard.just_pressed(KeyCode::KeyB) {
+        reputation.0.set_value(&mut commands, current - 15.0);
+    }
+}
+
+fn show_standing(
+    sliders: Query<&Slider, Changed<Slider>>,
+    reputation: Res<Reputation>,
+    mut status: Query<&mut Text, With<StatusText>>,
+) {
+    let Ok(slider) = sliders.get(reputation.0.entity()) else {
+        return;
+    };
+
+    let standing = match slider.value {
+        v if v >= 80.0 => "Hero of the realm",
+        v if v >= 50.0 => "Respected",
+        v if v >= 20.0 => "Distrusted",
+        _ => "Outlaw",
+    };
+    if let Ok(mut text) = status.single_mut() {
+        text.0 = standing.to_string();
+    }
+}
@@ -0,0 +1,82 @@
+//! Responsive Dialog - Demonstrates dialogs that fit the window width
+//!
+//! Run with: cargo run --example responsive_dialog --features dialog,button
+//!
+//! The dialog takes 90% of the window width, up to 480 pixels. Drag the
+//! window narrower and the dialog shrinks with it; widen it again and the
+//! dialog stops growing at 480 pixels. Press OK to close it and Space to
+//! open it again.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, (setup, open_dialog))
+        .add_systems(Update, (reopen_dialog, close_on_ok))
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::FlexEnd,
+                padding: UiRect::bottom(Val::Px(24.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Resize the window - Space reopens the dialog"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
+
+fn open_dialog(mut commands: Commands) {
+    DialogBuilder::new(DialogType::Info)
+        .title("Update available")
+        .body("Version 1.4 adds cloud saves and controller remapping. It will be installed the next time you launch the game.")
+        .responsive_width(90.0, 480.0)
+        .ok_button()
+        .build(&mut commands);
+}
+
+fn reopen_dialog(
+    commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    dialogs: Query<(), With<DialogOverlay>>,
+) {
+    if keyboard.just_pressed(KeyCode::Space) && dialogs.is_empty() {
+        open_dialog(commands);
+    }
+}
+
+fn close_on_ok(
+    mut commands: Commands,
+    mut events: MessageReader<DialogButtonEvent>,
+    dialogs: Query<Entity, With<DialogOverlay>>,
+) {
+    for event in events.read() {
+        if event.button_marker != "ok" {
+            continue;
+        }
+
+        for dialog in dialogs.iter() {
+            commands.entity(dialog).despawn();
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//! RTS Minimap - Demonstrates minimap fog of war
+//!
+//! Run with: cargo run --example rts_minimap --features minimap
+//!
+//! The minimap covers a 2000x2000 world that starts hidden under fog. Move
+//! the scout (the green dot) with WASD or the arrow keys and the fog clears
+//! around every place it has been. Hold Shift to scout a wider area.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::traits::UiBuilder;
+
+/// Half the width of the square world
+const WORLD_HALF_SIZE: f32 = 1000.0;
+
+/// Scout speed in world units per second
+const SCOUT_SPEED: f32 = 400.0;
+
+/// Minimap size in pixels
+const MINIMAP_SIZE: f32 = 280.0;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .init_resource::<Scout>()
+        .add_systems(Startup, setup)
+        .add_systems(Update, (move_scout, reveal_around_scout, show_scout).chain())
+        .run();
+}
+
+/// World position of the scout
+#[derive(Resource, Default)]
+struct Scout(Vec2);
+
+#[derive(Component)]
+struct ScoutDot;
+
+#[derive(Component)]
+struct StatusText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(Node {
+                    width: Val::Px(MINIMAP_SIZE),
+                    height: Val::Px(MINIMAP_SIZE),
+                    ..default()
+                })
+                .with_children(|frame| {
+                    MinimapBuilder::new()
+                        .size(MINIMAP_SIZE)
+                        .shape(MinimapShape::Square)
+                        .show_border(false)
+                        .background_color(Color::srgb(0.22, 0.35, 0.2))
+                        .world_bounds(Rect::from_center_size(Vec2::ZERO, Vec2::splat(WORLD_HALF_SIZE * 2.0)))
+                        .fog_of_war(true)
+                        .build(frame);
+
+                    // Drawn over the fog so the scout is always visible
+                    frame.spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            width: Val::Px(8.0),
+                            height: Val::Px(8.0),
+                            margin: UiRect::all(Val::Px(-4.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.2, 0.8, 0.2)),
+                        BorderRadius::all(Val::Percent(50.0)),
+                        ScoutDot,
+                    ));
+                });
+
+            parent.spawn((
+                Text::new("WASD to scout"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                StatusText,
+            ));
+        });
+}
+
+fn move_scout(keyboard: Res<ButtonInput<KeyCode>>, time: Res<Time>, mut scout: ResMut<Scout>) {
+    let mut direction = Vec2::ZERO;
+    if keyboard.any_pressed([KeyCode::KeyW, KeyCode::ArrowUp]) {
+        direction.y += 1.0;
+    }
+    if keyboard.any_pressed([KeyCode::KeyS, KeyCode::ArrowDown]) {
+        direction.y -= 1.0;
+    }
+    if keyboard.any_pressed([KeyCode::KeyA, KeyCode::ArrowLeft]) {
+        direction.x -= 1.0;
+    }
+    if keyboard.any_pressed([KeyCode::KeyD, KeyCode::ArrowRight]) {
+        direction.x += 1.0;
+    }
+
+    let step = direction.normalize_or_zero() * SCOUT_SPEED * time.delta_secs();
+    scout.0 = (scout.0 + step).clamp(Vec2::splat(-WORLD_HALF_SIZE), Vec2::splat(WORLD_HALF_SIZE));
+}
+
+fn reveal_around_scout(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    scout: Res<Scout>,
+    mut reveals: MessageWriter<MinimapRevealEvent>,
+) {
+    let wide = keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    reveals.write(MinimapRevealEvent {
+        world_pos: scout.0,
+        radius: if wide { 250.0 } else { 120.0 },
+    });
+}
+
+fn show_scout(
+    scout: Res<Scout>,
+    mut dot: Query<&mut Node, With<ScoutDot>>,
+    mut status: Query<&mut Text, With<StatusText>>,
+) {
+    if !scout.is_changed() {
+        return;
+    }
+
+    // World +Y is the top of the minimap
+    let fraction = (scout.0 + WORLD_HALF_SIZE) / (WORLD_HALF_SIZE * 2.0);
+    if let Ok(mut node) = dot.single_mut() {
+        node.left = Val::Percent(fraction.x * 100.0);
+        node.top = Val::Percent((1.0 - fraction.y) * 100.0);
+    }
+    if let Ok(mut text) = status.single_mut() {
+        text.0 = format!("Scout at ({:.0}, {:.0})", scout.0.x, scout.0.y);
+    }
+}
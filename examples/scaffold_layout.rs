@@ -0,0 +1,51 @@
+//! Scaffold Layout - Fixed header and footer around a scrolling body
+//!
+//! Run with: cargo run --example scaffold_layout --features "button label"
+
+use bevy::prelude::*;
+use bevy_ui_builders::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands, theme: Res<UiTheme>) {
+    // Camera
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            scaffold()
+                .themed(&theme)
+                // Toolbar header
+                .header(|header| {
+                    heading("Documents").build(header);
+                    spacer().build(header);
+                    ghost_button("New").size(ButtonSize::Small).build(header);
+                    ghost_button("Import").size(ButtonSize::Small).build(header);
+                })
+                // Scrolling content
+                .body(|body| {
+                    for i in 1..=60 {
+                        label(format!("Document {} - last edited {} days ago", i, i % 14))
+                            .build(body);
+                    }
+                })
+                // Button footer
+                .footer(|footer| {
+                    spacer().build(footer);
+                    secondary_button("Cancel").build(footer);
+                    primary_button("Save").build(footer);
+                })
+                .build(parent);
+        });
+}
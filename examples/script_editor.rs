@@ -0,0 +1,75 @@
+//! Script Editor - Demonstrates multiple carets in a multiline input
+//!
+//! Run with: cargo run --example script_editor --features text_input
+//!
+//! Click into the script, then Alt+Click at the end of a few lines to add
+//! carets there (Alt+Click a caret again to remove it). Whatever you type,
+//! delete or Enter goes in at every caret, and Ctrl+Z undoes it everywhere
+//! at once. Any other key, or a plain click, goes back to a single cursor.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::components::text_input::ExtraCarets;
+use bevy_ui_builders::traits::UiBuilder;
+
+const SCRIPT: &str = "spawn goblin\nspawn goblin\nspawn troll\nwait 5\nspawn dragon";
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, show_caret_count)
+        .run();
+}
+
+#[derive(Component)]
+struct StatusText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            TextInputBuilder::new()
+                .with_value(SCRIPT)
+                .multiline(true)
+                .with_width(Val::Px(420.0))
+                .with_height(Val::Px(180.0))
+                .build(parent);
+
+            parent.spawn((
+                Text::new("Alt+Click to add carets"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                StatusText,
+            ));
+        });
+}
+
+fn show_caret_count(
+    carets: Query<&ExtraCarets, Changed<ExtraCarets>>,
+    mut status: Query<&mut Text, With<StatusText>>,
+) {
+    let Ok(carets) = carets.single() else {
+        return;
+    };
+    if let Ok(mut text) = status.single_mut() {
+        text.0 = format!("{} caret(s) - Alt+Click to add more", carets.positions.len() + 1);
+    }
+}
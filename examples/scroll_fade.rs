@@ -0,0 +1,75 @@
+//! Scroll Fade - Edge fades hint at more scrollable content
+//!
+//! The quest log fades out at the bottom while more entries are below, and
+//! at the top once you scroll down. Scroll all the way to the end and the
+//! bottom fade disappears.
+//!
+//! Run with: cargo run --example scroll_fade
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+const PANEL_BACKGROUND: Color = Color::srgb(0.12, 0.12, 0.15);
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.05, 0.05, 0.07)),
+        ))
+        .with_children(|root| {
+            // The fades are laid out against this frame, which the scroll view fills
+            root.spawn(Node {
+                width: Val::Px(360.0),
+                height: Val::Px(320.0),
+                ..default()
+            })
+            .with_children(|frame| {
+                ScrollViewBuilder::new()
+                    .height(Val::Percent(100.0))
+                    .padding(UiRect::all(Val::Px(12.0)))
+                    .gap(Val::Px(8.0))
+                    .background_color(PANEL_BACKGROUND)
+                    .fade_edges(true)
+                    .fade_size(32.0)
+                    .build_with_children(frame, |list| {
+                        for index in 1..=20 {
+                            list.spawn((
+                                Node {
+                                    padding: UiRect::all(Val::Px(10.0)),
+                                    ..default()
+                                },
+                                BackgroundColor(Color::srgb(0.18, 0.18, 0.22)),
+                                BorderRadius::all(Val::Px(4.0)),
+                            ))
+                            .with_children(|entry| {
+                                entry.spawn((
+                                    Text::new(format!("Quest {}: Gather {} herbs", index, index * 3)),
+                                    TextFont {
+                                        font_size: 16.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                                ));
+                            });
+                        }
+                    });
+            });
+        });
+}
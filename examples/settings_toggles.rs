@@ -0,0 +1,76 @@
+//! Settings Toggles - Demonstrates checkboxes with a title and description
+//!
+//! Run with: cargo run --example settings_toggles --features checkbox
+//!
+//! Each setting is a settings-style row: a bold title with a dimmer line
+//! explaining what it does. Click anywhere in a row (not just the box) to
+//! toggle it.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, log_changes)
+        .run();
+}
+
+fn setup(mut commands: Commands, theme: Res<UiTheme>) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(420.0),
+                        flex_direction: FlexDirection::Column,
+                        padding: UiRect::all(Val::Px(24.0)),
+                        row_gap: Val::Px(18.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.12, 0.12, 0.15)),
+                    BorderRadius::all(Val::Px(8.0)),
+                ))
+                .with_children(|card| {
+                    CheckboxBuilder::new()
+                        .themed(&theme)
+                        .checked(true)
+                        .with_description("Auto-save", "Save your progress every five minutes")
+                        .build(card);
+
+                    CheckboxBuilder::new()
+                        .themed(&theme)
+                        .with_description("Subtitles", "Show captions for all spoken dialogue")
+                        .build(card);
+
+                    CheckboxBuilder::new()
+                        .themed(&theme)
+                        .style(CheckboxStyle::Danger)
+                        .with_description(
+                            "Permadeath",
+                            "Your save is deleted when your character dies",
+                        )
+                        .build(card);
+                });
+        });
+}
+
+fn log_changes(checkboxes: Query<&CheckboxState, Changed<CheckboxState>>) {
+    for state in checkboxes.iter() {
+        info!("Setting toggled: {}", state.is_checked());
+    }
+}
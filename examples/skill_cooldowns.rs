@@ -0,0 +1,97 @@
+//! Skill Cooldowns - Demonstrates circular progress rings
+//!
+//! Run with: cargo run --example skill_cooldowns --features progress
+//!
+//! Press 1, 2 or 3 to cast a skill. Its ring empties and refills clockwise
+//! while the skill recovers; casting again before it is full does nothing.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::traits::UiBuilder;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (cast_skills, recover_skills))
+        .run();
+}
+
+/// A skill slot and its cooldown ring
+#[derive(Component, Clone)]
+struct Skill {
+    key: KeyCode,
+    cooldown_secs: f32,
+}
+
+const SKILLS: [(&str, KeyCode, f32, SemanticVariant); 3] = [
+    ("Dash", KeyCode::Digit1, 2.0, SemanticVariant::Primary),
+    ("Heal", KeyCode::Digit2, 6.0, SemanticVariant::Success),
+    ("Meteor", KeyCode::Digit3, 12.0, SemanticVariant::Danger),
+];
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::End,
+                padding: UiRect::bottom(Val::Px(48.0)),
+                column_gap: Val::Px(32.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|hud| {
+            for (index, (name, key, cooldown_secs, variant)) in SKILLS.into_iter().enumerate() {
+                hud.spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(8.0),
+                    ..default()
+                })
+                .with_children(|slot| {
+                    ProgressBarBuilder::new(1.0)
+                        .style(ProgressBarStyle::Circular { thickness: 6.0 })
+                        .width(Val::Px(64.0))
+                        .variant(variant)
+                        .with_label()
+                        .insert(Skill { key, cooldown_secs })
+                        .build(slot);
+
+                    slot.spawn((
+                        Text::new(format!("[{}] {}", index + 1, name)),
+                        TextFont {
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                    ));
+                });
+            }
+        });
+}
+
+fn cast_skills(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut skills: Query<(&Skill, &mut ProgressBar)>,
+) {
+    for (skill, mut bar) in skills.iter_mut() {
+        if keyboard.just_pressed(skill.key) && bar.value >= 1.0 {
+            bar.value = 0.0;
+        }
+    }
+}
+
+fn recover_skills(time: Res<Time>, mut skills: Query<(&Skill, &mut ProgressBar)>) {
+    for (skill, mut bar) in skills.iter_mut() {
+        if bar.value < 1.0 {
+            bar.value = (bar.value + time.delta_secs() / skill.cooldown_secs).min(1.0);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Slider Announcements - Demonstrates the accessible value text of sliders
+//!
+//! Run with: cargo run --example slider_announcements --features slider
+//!
+//! Every slider keeps an `AccessibleValue` string describing its current
+//! value the way a screen reader would say it. Drag either slider and the
+//! line at the bottom shows the text a screen-reader bridge would announce,
+//! e.g. "Volume, 60 percent".
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, announce)
+        .run();
+}
+
+#[derive(Component)]
+struct AnnouncementText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            SliderBuilder::new(0.0..1.0)
+                .label("Volume")
+                .step(0.01)
+                .value(0.6)
+                .format(ValueFormat::Percentage)
+                .width(Val::Px(320.0))
+                .build(parent);
+
+            SliderBuilder::new(60.0..120.0)
+                .label("Field of view")
+                .step(1.0)
+                .value(90.0)
+                .format(ValueFormat::Integer)
+                .width(Val::Px(320.0))
+                .build(parent);
+
+            parent.spawn((
+                Text::new("Drag a slider to hear it announced"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                AnnouncementText,
+            ));
+        });
+}
+
+/// Stand-in for a screen-reader bridge: show the latest changed value text
+fn announce(
+    values: Query<&AccessibleValue, Changed<AccessibleValue>>,
+    mut text: Query<&mut Text, With<AnnouncementText>>,
+) {
+    let Some(value) = values.iter().last() else {
+        return;
+    };
+    if let Ok(mut text) = text.single_mut() {
+        text.0 = format!("Screen reader: \"{}\"", value.0);
+    }
+}
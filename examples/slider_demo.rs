@@ -10,7 +10,7 @@ fn main() {
         .add_plugins(DefaultPlugins)
         .add_plugins(UiBuilderPlugin)
         .add_systems(Startup, setup)
-        .add_systems(Update, update_slider_values)
+        .add_systems(Update, (update_slider_values, update_balance_readout))
         .run();
 }
 
@@ -113,9 +113,26 @@ fn setup(mut commands: Commands) {
                             .build(row);
                     });
 
+                    // Bandwidth allocation with a band reserved for the system
+                    create_slider_row(container, "Bandwidth", |row| {
+                        SliderBuilder::new(0.0..100.0)
+                            .value(40.0)
+                            .step(5.0)
+                            .locked_range(85.0..100.0)
+                            .format(ValueFormat::Custom(|v| format!("{:.0} Mbps", v)))
+                            .width(Val::Px(300.0))
+                            .label("Allocation (85+ reserved)")
+                            .with_buttons()
+                            .with_preview(true)
+                            .build(row);
+                    });
+
                     // Multiple sliders for RGB color
                     create_color_picker_section(container);
 
+                    // 2D balance/pan pad
+                    create_balance_section(container);
+
                     // Slider without preview
                     create_slider_row(container, "Hidden Value", |row| {
                         SliderBuilder::new(0.0..100.0)
@@ -244,6 +261,53 @@ fn create_color_picker_section(parent: &mut ChildSpawnerCommands) {
         });
 }
 
+#[derive(Component)]
+struct BalanceReadout;
+
+fn create_balance_section(parent: &mut ChildSpawnerCommands) {
+    create_slider_row(parent, "Balance / Pan (2D pad)", |row| {
+        row.spawn(Node {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(16.0),
+            ..default()
+        })
+        .with_children(|pad_row| {
+            // x = left/right pan, y = front/back balance
+            Pad2DBuilder::new()
+                .value(0.5, 0.5)
+                .size(180.0)
+                .build(pad_row);
+
+            pad_row.spawn((
+                Text::new(format_balance(0.5, 0.5)),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                BalanceReadout,
+            ));
+        });
+    });
+}
+
+/// Show the pad position as pan (-100 left .. 100 right) and front/back balance
+fn format_balance(x: f32, y: f32) -> String {
+    format!("Pan: {:+.0}\nFront/Back: {:+.0}", (x - 0.5) * 200.0, (y - 0.5) * 200.0)
+}
+
+fn update_balance_readout(
+    mut changes: MessageReader<Pad2DChanged>,
+    mut readouts: Query<&mut Text, With<BalanceReadout>>,
+) {
+    if let Some(change) = changes.read().last() {
+        for mut text in &mut readouts {
+            **text = format_balance(change.x, change.y);
+        }
+    }
+}
+
 // System to monitor slider value changes
 fn update_slider_values(
     slider_query: Query<&Slider, Changed<Slider>>,
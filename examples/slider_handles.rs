@@ -0,0 +1,115 @@
+//! Slider Handles - Demonstrates driving a slider from game logic
+//!
+//! Run with: cargo run --example slider_handles --features slider
+//!
+//! The volume slider is built with `build_ref`, and its `SliderRef` is kept in
+//! a resource. Press R to reset it to 50%, or Up/Down to nudge it; the
+//! readout below reads the value back through the same handle.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (control_volume, show_volume))
+        .run();
+}
+
+#[derive(Resource)]
+struct VolumeSlider(SliderRef);
+
+#[derive(Component)]
+struct VolumeText;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let mut volume = None;
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            volume = Some(
+                SliderBuilder::new(0.0..1.0)
+                    .value(0.8)
+                    .step(0.05)
+                    .format(ValueFormat::Percentage)
+                    .label("Volume")
+                    .width(Val::Px(300.0))
+                    .build_ref(parent),
+            );
+
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                VolumeText,
+            ));
+
+            parent.spawn((
+                Text::new("R: reset to 50%   Up/Down: nudge"),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.5, 0.5, 0.5)),
+            ));
+        });
+
+    if let Some(volume) = volume {
+        commands.insert_resource(VolumeSlider(volume));
+    }
+}
+
+fn control_volume(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    volume: Res<VolumeSlider>,
+    sliders: Query<&Slider>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyR) {
+        volume.0.set_value(&mut commands, 0.5);
+    }
+
+    let nudge = if keyboard.just_pressed(KeyCode::ArrowUp) {
+        0.1
+    } else if keyboard.just_pressed(KeyCode::ArrowDown) {
+        -0.1
+    } else {
+        return;
+    };
+
+    if let Some(current) = volume.0.get_value(&sliders) {
+        volume.0.set_value(&mut commands, current + nudge);
+    }
+}
+
+fn show_volume(
+    volume: Res<VolumeSlider>,
+    sliders: Query<&Slider, Changed<Slider>>,
+    mut text: Query<&mut Text, With<VolumeText>>,
+) {
+    let Some(value) = volume.0.get_value(&sliders) else {
+        return;
+    };
+
+    if let Ok(mut text) = text.single_mut() {
+        text.0 = format!("Game volume is {:.0}%", value * 100.0);
+    }
+}
@@ -0,0 +1,84 @@
+//! Stacked Dialog Buttons - Demonstrates dialog buttons that stack on narrow windows
+//!
+//! Run with: cargo run --example stacked_dialog_buttons --features dialog,button
+//!
+//! The dialog has three buttons with long labels. While the window is at
+//! least 640 pixels wide they sit side by side; make it narrower and they
+//! stack into a full-width column, then widen it again to put them back in a
+//! row. Press a button to close the dialog and Space to open it again.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, (setup, open_dialog))
+        .add_systems(Update, (reopen_dialog, close_on_button))
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::FlexEnd,
+                padding: UiRect::bottom(Val::Px(24.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Resize the window - Space reopens the dialog"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
+
+fn open_dialog(mut commands: Commands) {
+    DialogBuilder::new(DialogType::Warning)
+        .title("Unsaved changes")
+        .body("You have unsaved changes to \"Chapter 3\". What would you like to do before closing?")
+        .responsive_width(90.0, 600.0)
+        .save_button("Save and close")
+        .discard_button("Close without saving")
+        .cancel_button("Keep editing")
+        .stack_buttons_below(Val::Px(640.0))
+        .build(&mut commands);
+}
+
+fn reopen_dialog(
+    commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    dialogs: Query<(), With<DialogOverlay>>,
+) {
+    if keyboard.just_pressed(KeyCode::Space) && dialogs.is_empty() {
+        open_dialog(commands);
+    }
+}
+
+fn close_on_button(
+    mut commands: Commands,
+    mut events: MessageReader<DialogButtonEvent>,
+    dialogs: Query<Entity, With<DialogOverlay>>,
+) {
+    if events.read().count() == 0 {
+        return;
+    }
+
+    // Cancel buttons already close their dialog
+    for dialog in dialogs.iter() {
+        commands.entity(dialog).try_despawn();
+    }
+}
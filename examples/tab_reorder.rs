@@ -0,0 +1,99 @@
+//! Tab Reorder - Demonstrates dragging tabs into a new order
+//!
+//! Run with: cargo run --example tab_reorder --features tabs
+//!
+//! Drag an editor tab left or right; a line shows where it will land. Drop
+//! it to move the tab (its content and the active tab follow), or
+//! right-click mid-drag to cancel. The file order below the tabs is kept in
+//! sync from `TabReorderedEvent`.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::traits::UiBuilder;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .insert_resource(OpenFiles(vec!["main.rs", "lib.rs", "ui.rs", "Cargo.toml"]))
+        .add_systems(Startup, setup)
+        .add_systems(Update, apply_reorder)
+        .run();
+}
+
+/// Open files, in tab order
+#[derive(Resource)]
+struct OpenFiles(Vec<&'static str>);
+
+#[derive(Component)]
+struct OrderText;
+
+fn setup(mut commands: Commands, files: Res<OpenFiles>) {
+    commands.spawn(Camera2d);
+
+    let files = files.0.clone();
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(24.0)),
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            let mut builder = TabViewBuilder::new()
+                .style(TabStyle::Boxed)
+                .tab(files[0], file_contents(files[0]));
+            for &file in &files[1..] {
+                builder = builder.tab(file, file_contents(file));
+            }
+            builder.reorderable(true).build(parent);
+
+            parent.spawn((
+                Text::new(format_order(&files)),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                OrderText,
+            ));
+        });
+}
+
+fn file_contents(file: &'static str) -> impl FnOnce(&mut ChildSpawnerCommands) {
+    move |content| {
+        content.spawn((
+            Text::new(format!("// {file}\n\nfn main() {{}}")),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.8, 0.85, 0.8)),
+        ));
+    }
+}
+
+fn format_order(files: &[&str]) -> String {
+    format!("Tab order: {}", files.join(", "))
+}
+
+fn apply_reorder(
+    mut reordered: MessageReader<TabReorderedEvent>,
+    mut files: ResMut<OpenFiles>,
+    mut text: Query<&mut Text, With<OrderText>>,
+) {
+    for event in reordered.read() {
+        let file = files.0.remove(event.from);
+        files.0.insert(event.to, file);
+
+        if let Ok(mut text) = text.single_mut() {
+            text.0 = format_order(&files.0);
+        }
+    }
+}
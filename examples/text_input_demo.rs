@@ -16,6 +16,7 @@
 use bevy::prelude::*;
 use bevy_ui_builders::*;
 use bevy_ui_builders::traits::UiBuilder;
+use bevy_ui_builders::components::text_input::CursorStyle;
 
 fn main() {
     App::new()
@@ -116,13 +117,13 @@ fn setup(mut commands: Commands) {
                                     .build(section);
                             });
 
-                            // Password input (would need mask_char support)
+                            // Password input - shows * per character, Ctrl+C is ignored
                             create_input_section(container, "Password Field", |section| {
                                 TextInputBuilder::new()
                                     .with_placeholder("Enter password...")
+                                    .password()
                                     .insert(PasswordInput)
                                     .build(section);
-                                // Note: mask_char would be set in TextInputVisual
                             });
 
                             // Numeric only input
@@ -253,6 +254,25 @@ fn setup(mut commands: Commands) {
                                     .inactive()
                                     .build(section);
                             });
+
+                            // Retro terminal - solid green block cursor with a slow blink
+                            create_input_section(container, "Retro Terminal", |section| {
+                                TextInputBuilder::new()
+                                    .with_value("C:\\>")
+                                    .cursor_style(CursorStyle::Block)
+                                    .cursor_color(Color::srgb(0.2, 1.0, 0.3))
+                                    .cursor_blink_rate(0.5)
+                                    .build(section);
+                            });
+
+                            // Thick line cursor that doesn't blink
+                            create_input_section(container, "Bold Cursor", |section| {
+                                TextInputBuilder::new()
+                                    .with_placeholder("Solid 4px cursor")
+                                    .cursor_width(4.0)
+                                    .cursor_blink_rate(0.0)
+                                    .build(section);
+                            });
                         });
                 });
 
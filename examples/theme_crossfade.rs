@@ -0,0 +1,121 @@
+//! Theme Crossfade - Demonstrates animated theme switching
+//!
+//! Run with: cargo run --example theme_crossfade --features button
+//!
+//! Click "Toggle dark mode" (or press D) to switch between the dark and light
+//! themes. Backgrounds, borders and text fade to their new colors instead of
+//! swapping instantly. Press M to toggle reduce motion and compare with an
+//! instant swap.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, toggle_theme)
+        .run();
+}
+
+#[derive(Component, Clone)]
+struct ToggleButton;
+
+fn setup(mut commands: Commands, theme: Res<UiTheme>) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(theme.colors.surface.background),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        padding: UiRect::all(Val::Px(32.0)),
+                        row_gap: Val::Px(16.0),
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BackgroundColor(theme.colors.surface.primary),
+                    BorderColor::all(theme.colors.border.default),
+                    BorderRadius::all(Val::Px(8.0)),
+                ))
+                .with_children(|card| {
+                    card.spawn((
+                        Text::new("Appearance"),
+                        TextFont {
+                            font_size: 28.0,
+                            ..default()
+                        },
+                        TextColor(theme.colors.text.title),
+                    ));
+
+                    card.spawn((
+                        Text::new("Theme changes fade smoothly between palettes."),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(theme.colors.text.secondary),
+                    ));
+
+                    card.spawn(Node {
+                        column_gap: Val::Px(12.0),
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        ButtonBuilder::new("Save")
+                            .style(ButtonStyle::Primary)
+                            .themed(&theme)
+                            .build(row);
+                        ButtonBuilder::new("Cancel")
+                            .style(ButtonStyle::Secondary)
+                            .themed(&theme)
+                            .build(row);
+                        ButtonBuilder::new("Delete")
+                            .style(ButtonStyle::Danger)
+                            .themed(&theme)
+                            .build(row);
+                    });
+
+                    ButtonBuilder::new("Toggle dark mode")
+                        .style(ButtonStyle::Ghost)
+                        .themed(&theme)
+                        .insert(ToggleButton)
+                        .build(card);
+                });
+        });
+}
+
+fn toggle_theme(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    buttons: Query<&Interaction, (Changed<Interaction>, With<ToggleButton>)>,
+    mut theme: ResMut<UiTheme>,
+    mut dark: Local<Option<bool>>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyM) {
+        theme.reduce_motion = !theme.reduce_motion;
+        info!("Reduce motion: {}", theme.reduce_motion);
+    }
+
+    let clicked = buttons.iter().any(|interaction| *interaction == Interaction::Pressed);
+    if !clicked && !keyboard.just_pressed(KeyCode::KeyD) {
+        return;
+    }
+
+    let is_dark = dark.get_or_insert(true);
+    *is_dark = !*is_dark;
+
+    let next = if *is_dark { UiTheme::dark() } else { UiTheme::light() };
+    *theme = next.with_reduce_motion(theme.reduce_motion);
+}
@@ -0,0 +1,114 @@
+//! Throttled Slider - Demonstrates rate-limiting slider change messages
+//!
+//! Run with: cargo run --example throttled_slider --features slider
+//!
+//! Both sliders drive a deliberately slow "preview render". Drag them and
+//! compare the render counters: the top slider re-renders on every change,
+//! while the throttled one renders at most ten times a second and once more
+//! with the final value when you let go.
+
+use std::time::Duration;
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, render_previews)
+        .run();
+}
+
+/// Which slider a readout belongs to
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+enum Preview {
+    Every,
+    Throttled,
+}
+
+/// How many times a preview has rendered
+#[derive(Component, Default)]
+struct RenderStats {
+    renders: u32,
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(24.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            let every = SliderBuilder::new(0.0..100.0)
+                .label("Unthrottled")
+                .width(Val::Px(360.0))
+                .build(parent);
+            parent.commands().entity(every).insert(Preview::Every);
+            spawn_readout(parent, Preview::Every);
+
+            let throttled = SliderBuilder::new(0.0..100.0)
+                .label("Throttled (100 ms)")
+                .width(Val::Px(360.0))
+                .on_change_throttle(Duration::from_millis(100))
+                .build(parent);
+            parent.commands().entity(throttled).insert(Preview::Throttled);
+            spawn_readout(parent, Preview::Throttled);
+        });
+}
+
+fn spawn_readout(parent: &mut ChildSpawnerCommands, preview: Preview) {
+    parent.spawn((
+        Text::new("0 renders"),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.7, 0.7, 0.7)),
+        preview,
+        RenderStats::default(),
+    ));
+}
+
+/// Stand-in for an expensive preview render
+fn expensive_render(value: f32) -> f32 {
+    (0..200_000).fold(value, |acc, i| (acc + i as f32).sqrt())
+}
+
+fn render_previews(
+    mut changes: MessageReader<SliderChanged>,
+    parents: Query<&ChildOf>,
+    previews: Query<&Preview, Without<RenderStats>>,
+    mut readouts: Query<(&Preview, &mut RenderStats, &mut Text)>,
+) {
+    for change in changes.read() {
+        // The message names the slider track; the marker is on its container
+        let Some(preview) = parents.get(change.entity).ok()
+            .and_then(|child_of| previews.get(child_of.parent()).ok())
+        else {
+            continue;
+        };
+
+        let result = expensive_render(change.value);
+
+        for (readout, mut stats, mut text) in readouts.iter_mut() {
+            if readout == preview {
+                stats.renders += 1;
+                text.0 = format!(
+                    "{} renders (last value {:.1}, result {:.2})",
+                    stats.renders, change.value, result
+                );
+            }
+        }
+    }
+}
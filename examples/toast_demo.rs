@@ -0,0 +1,66 @@
+//! Toast Demo - Demonstrates toast notifications and their transitions
+//!
+//! Press 1-4 to show info, success, warning and error toasts.
+//!
+//! Run with: cargo run --example toast_demo --features toast
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, show_toasts_on_keypress)
+        .run();
+}
+
+fn setup(mut commands: Commands, mut settings: ResMut<ToastSettings>) {
+    // Camera
+    commands.spawn(Camera2d);
+
+    // Toasts scale in from the bottom-right corner and shrink away
+    settings.position = ToastPosition::BottomRight;
+    settings.enter_transition = Transition::scale_in(0.6, 0.25).spring();
+    settings.exit_transition = Transition::scale_out(0.6, 0.2);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Press 1-4 to show toasts"),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+fn show_toasts_on_keypress(keyboard: Res<ButtonInput<KeyCode>>, mut toasts: ResMut<ToastQueue>) {
+    if keyboard.just_pressed(KeyCode::Digit1) {
+        ToastBuilder::new("Autosave is enabled").show(&mut toasts);
+    }
+    if keyboard.just_pressed(KeyCode::Digit2) {
+        show_success(&mut toasts, "Profile saved");
+    }
+    if keyboard.just_pressed(KeyCode::Digit3) {
+        ToastBuilder::warning("Disk space is running low")
+            .title("Storage")
+            .show(&mut toasts);
+    }
+    if keyboard.just_pressed(KeyCode::Digit4) {
+        ToastBuilder::error("Upload failed")
+            .title("Network Error")
+            .action("Retry")
+            .show(&mut toasts);
+    }
+}
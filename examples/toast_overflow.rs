@@ -0,0 +1,65 @@
+//! Toast Overflow - Demonstrates limiting how many toasts show at once
+//!
+//! Run with: cargo run --example toast_overflow --features toast
+//!
+//! Press Space to fire off a burst of five toasts. Only three are on screen
+//! at a time; the rest wait behind a "+N more" line and slide in as the
+//! shown ones time out or are dismissed, each getting its full duration.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, fire_burst)
+        .run();
+}
+
+fn setup(mut commands: Commands, mut settings: ResMut<ToastSettings>) {
+    commands.spawn(Camera2d);
+
+    settings.max_visible = 3;
+    settings.overflow_indicator = true;
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Space: five toasts at once"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
+
+fn fire_burst(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut toasts: ResMut<ToastQueue>,
+    mut fired: Local<usize>,
+) {
+    if !keyboard.just_pressed(KeyCode::Space) {
+        return;
+    }
+
+    for _ in 0..5 {
+        *fired += 1;
+        ToastBuilder::info(format!("Achievement unlocked #{}", *fired))
+            .duration_secs(3)
+            .show(&mut toasts);
+    }
+}
@@ -0,0 +1,66 @@
+//! Toast Progress - Demonstrates remaining-time strips on toasts
+//!
+//! Run with: cargo run --example toast_progress --features toast
+//!
+//! Every toast carries a thin strip along its bottom edge that empties as
+//! its time runs out. Press 1 for a short success toast, 2 for a longer
+//! error toast, and G a few times for grouped toasts: expanding the group
+//! holds its strip, and each new toast in the group refills it.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, show_toasts)
+        .run();
+}
+
+fn setup(mut commands: Commands, mut settings: ResMut<ToastSettings>) {
+    commands.spawn(Camera2d);
+
+    settings.show_progress = true;
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("1: short toast    2: long toast    G: grouped toast"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
+
+fn show_toasts(keyboard: Res<ButtonInput<KeyCode>>, mut toasts: ResMut<ToastQueue>) {
+    if keyboard.just_pressed(KeyCode::Digit1) {
+        ToastBuilder::success("Game saved").duration_secs(3).show(&mut toasts);
+    }
+    if keyboard.just_pressed(KeyCode::Digit2) {
+        ToastBuilder::error("Lost connection to the server")
+            .title("Network Error")
+            .duration_secs(10)
+            .show(&mut toasts);
+    }
+    if keyboard.just_pressed(KeyCode::KeyG) {
+        ToastBuilder::warning("A player left the lobby")
+            .group("players left")
+            .duration_secs(5)
+            .show(&mut toasts);
+    }
+}
@@ -0,0 +1,71 @@
+//! UI Sounds - Demonstrates interaction audio via one resource
+//!
+//! Run with: cargo run --example ui_sounds --features button,checkbox,audio
+//!
+//! Drop `hover.ogg`, `click.ogg` and `toggle.ogg` into `assets/sounds/` and
+//! every button and checkbox below picks them up from the single `UiSounds`
+//! resource. Missing files are simply skipped.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use bevy_ui_builders::traits::UiBuilder;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn(Camera2d);
+
+    // One resource wires sounds to every widget
+    commands.insert_resource(UiSounds {
+        hover: load_optional_sound(&asset_server, "sounds/hover.ogg"),
+        click: load_optional_sound(&asset_server, "sounds/click.ogg"),
+        toggle: load_optional_sound(&asset_server, "sounds/toggle.ogg"),
+        volume: 0.6,
+    });
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(20.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            ButtonBuilder::new("Play")
+                .style(ButtonStyle::Primary)
+                .build(parent);
+
+            ButtonBuilder::new("Settings")
+                .style(ButtonStyle::Secondary)
+                .build(parent);
+
+            CheckboxBuilder::new()
+                .with_label("Fullscreen")
+                .build(parent);
+
+            // Per-widget opt-out
+            ButtonBuilder::new("Silent button")
+                .style(ButtonStyle::Ghost)
+                .insert(NoUiSounds)
+                .build(parent);
+        });
+}
+
+fn load_optional_sound(asset_server: &AssetServer, path: &str) -> Option<Handle<AudioSource>> {
+    std::path::Path::new("assets")
+        .join(path)
+        .exists()
+        .then(|| asset_server.load(path))
+}
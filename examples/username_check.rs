@@ -0,0 +1,97 @@
+//! Username Check - Demonstrates async form validation
+//!
+//! Run with: cargo run --example username_check --features form,text_input,button
+//!
+//! Stop typing for a moment and the username is "sent to the server": a
+//! spinner shows beside the label until the answer arrives a second later,
+//! then a check or cross mark. "admin", "root" and "player1" are taken.
+
+use bevy::prelude::*;
+use bevy_ui_builders::*;
+use std::time::Duration;
+
+/// Usernames the pretend server rejects
+const TAKEN: [&str; 3] = ["admin", "root", "player1"];
+
+/// How long the pretend server takes to answer
+const LATENCY_SECS: f32 = 1.0;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UiBuilderPlugin)
+        .init_resource::<Server>()
+        .add_systems(Startup, setup)
+        .add_systems(Update, (receive_requests, answer_requests).chain())
+        .run();
+}
+
+/// Checks in flight: the request and how long until the answer
+#[derive(Resource, Default)]
+struct Server(Vec<(ValidationRequestEvent, Timer)>);
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.1)),
+        ))
+        .with_children(|parent| {
+            FormBuilder::new("signup")
+                .title("Create Account")
+                .text_field("username", "Username")
+                .required()
+                .async_validate(Duration::from_millis(400))
+                .password_field("password", "Password")
+                .required()
+                .submit_text("Sign Up")
+                .build(parent);
+
+            parent.spawn((
+                Text::new("Taken: admin, root, player1"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
+
+fn receive_requests(mut requests: MessageReader<ValidationRequestEvent>, mut server: ResMut<Server>) {
+    for request in requests.read() {
+        server.0.push((
+            request.clone(),
+            Timer::from_seconds(LATENCY_SECS, TimerMode::Once),
+        ));
+    }
+}
+
+fn answer_requests(
+    time: Res<Time>,
+    mut server: ResMut<Server>,
+    mut results: MessageWriter<ValidationResolvedEvent>,
+) {
+    server.0.retain_mut(|(request, timer)| {
+        if !timer.tick(time.delta()).is_finished() {
+            return true;
+        }
+
+        if TAKEN.contains(&request.value.trim().to_lowercase().as_str()) {
+            results.write(ValidationResolvedEvent::invalid(request, "Username is taken"));
+        } else {
+            results.write(ValidationResolvedEvent::valid(request));
+        }
+        false
+    });
+}
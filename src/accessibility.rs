@@ -0,0 +1,12 @@
+//! Accessibility components shared by widgets
+//!
+//! Screen-reader bridges read these to describe widgets to the user.
+
+use bevy::prelude::*;
+
+/// Name a screen reader announces for a widget, e.g. "Volume"
+///
+/// Builders with a visible label, like `SliderBuilder::label()`, add it;
+/// insert it yourself on widgets without one.
+#[derive(Component, Clone, Debug, PartialEq, Eq)]
+pub struct AccessibleName(pub String);
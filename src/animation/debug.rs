@@ -0,0 +1,187 @@
+//! Animation debug overlay (requires the `debug` feature).
+//!
+//! When [`AnimationDebug::enabled`] is set, every entity with a [`UiAnimation`]
+//! gets a small text label drawn just below it showing its category, the
+//! current → target values of each animated property, and the progress of any
+//! running enter/exit transition. Handy for tuning hover scales and speeds.
+//!
+//! ```ignore
+//! fn setup(mut debug: ResMut<AnimationDebug>) {
+//!     debug.enabled = true;
+//! }
+//! ```
+
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+use super::types::{AnimationCategory, UiAnimation};
+use crate::utils::geometry::node_logical_rect;
+
+/// Z-index for debug labels - above every other UI layer
+const DEBUG_Z_INDEX: i32 = 10_000;
+
+/// Controls the animation debug overlay
+#[derive(Resource, Debug, Clone)]
+pub struct AnimationDebug {
+    /// Whether the overlay is currently shown
+    pub enabled: bool,
+    /// Key that toggles the overlay (None to disable the shortcut)
+    pub toggle_key: Option<KeyCode>,
+    /// Font size of the overlay labels
+    pub font_size: f32,
+    /// Text color of the overlay labels
+    pub text_color: Color,
+    /// Background color of the overlay labels
+    pub background_color: Color,
+}
+
+impl Default for AnimationDebug {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            toggle_key: Some(KeyCode::F9),
+            font_size: 10.0,
+            text_color: Color::srgb(0.4, 1.0, 0.6),
+            background_color: Color::srgba(0.0, 0.0, 0.0, 0.75),
+        }
+    }
+}
+
+/// Overlay label showing the animation state of `target`
+#[derive(Component, Debug)]
+pub struct AnimationDebugLabel {
+    /// The animated entity this label describes
+    pub target: Entity,
+}
+
+/// Toggle the overlay with the configured key
+pub fn toggle_animation_debug(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut debug: ResMut<AnimationDebug>,
+) {
+    if let Some(key) = debug.toggle_key {
+        if keyboard.just_pressed(key) {
+            debug.enabled = !debug.enabled;
+        }
+    }
+}
+
+/// Spawn labels for newly animated entities and despawn stale ones
+pub fn sync_animation_debug_labels(
+    mut commands: Commands,
+    debug: Res<AnimationDebug>,
+    animated: Query<Entity, With<UiAnimation>>,
+    labels: Query<(Entity, &AnimationDebugLabel)>,
+) {
+    if !debug.enabled {
+        for (label, _) in labels.iter() {
+            commands.entity(label).despawn();
+        }
+        return;
+    }
+
+    let mut labelled = HashSet::new();
+    for (label, debug_label) in labels.iter() {
+        if animated.contains(debug_label.target) {
+            labelled.insert(debug_label.target);
+        } else {
+            commands.entity(label).despawn();
+        }
+    }
+
+    for target in animated.iter().filter(|entity| !labelled.contains(entity)) {
+        commands.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                padding: UiRect::axes(Val::Px(3.0), Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(debug.background_color),
+            GlobalZIndex(DEBUG_Z_INDEX),
+            Pickable::IGNORE, // Must not steal hover from the widget being inspected
+            Text::default(),
+            TextFont {
+                font_size: debug.font_size,
+                ..default()
+            },
+            TextColor(debug.text_color),
+            AnimationDebugLabel { target },
+        ));
+    }
+}
+
+/// Position labels under their targets and refresh the displayed values
+pub fn update_animation_debug_labels(
+    debug: Res<AnimationDebug>,
+    animated: Query<(&UiAnimation, Option<&AnimationCategory>, &GlobalTransform, &ComputedNode)>,
+    mut labels: Query<(&AnimationDebugLabel, &mut Node, &mut Text)>,
+) {
+    if !debug.enabled {
+        return;
+    }
+
+    for (label, mut node, mut text) in labels.iter_mut() {
+        let Ok((animation, category, transform, computed)) = animated.get(label.target) else {
+            continue;
+        };
+
+        let rect = node_logical_rect(computed, transform);
+        node.left = Val::Px(rect.min.x);
+        node.top = Val::Px(rect.max.y + 2.0);
+
+        let summary = describe_animation(animation, category);
+        if text.0 != summary {
+            text.0 = summary;
+        }
+    }
+}
+
+/// Build the overlay text for one animation
+fn describe_animation(animation: &UiAnimation, category: Option<&AnimationCategory>) -> String {
+    let state = &animation.state;
+    let category = category.map_or_else(|| "Custom".to_string(), |category| format!("{:?}", category));
+    let status = if state.is_animating { "animating" } else { "idle" };
+
+    let mut lines = vec![
+        format!("{} ({})", category, status),
+        format!(
+            "scale {:.3} -> {:.3}  bright {:.3} -> {:.3}",
+            state.current_scale, state.target_scale,
+            state.current_brightness, state.target_brightness,
+        ),
+        format!(
+            "opacity {:.2} -> {:.2}  blend {:.2} -> {:.2}",
+            state.current_opacity, state.target_opacity,
+            state.current_color_blend, state.target_color_blend,
+        ),
+    ];
+
+    if state.target_translation != Vec2::ZERO || state.current_translation != Vec2::ZERO {
+        lines.push(format!(
+            "offset ({:.1}, {:.1}) -> ({:.1}, {:.1})",
+            state.current_translation.x, state.current_translation.y,
+            state.target_translation.x, state.target_translation.y,
+        ));
+    }
+
+    for (name, mount) in [("enter", &animation.enter), ("exit", &animation.exit)] {
+        if let Some(mount) = mount.as_ref().filter(|mount| mount.playing) {
+            lines.push(format!("{} {:.0}%", name, mount.progress * 100.0));
+        }
+    }
+
+    lines.join("\n")
+}
+
+define_plugin!(AnimationDebugPlugin {
+    custom_init: |app: &mut App| {
+        app.init_resource::<AnimationDebug>();
+    },
+    update: [
+        toggle_animation_debug,
+        sync_animation_debug_labels,
+        update_animation_debug_labels,
+    ]
+});
@@ -6,6 +6,8 @@
 //! - **Enter/Exit Transitions**: Fade, slide, scale animations for mounting/unmounting
 //! - **Easing Functions**: Full library of easing curves (ease-out, bounce, elastic, etc.)
 //! - **Animation Presets**: Ready-to-use configurations (Subtle, Punchy, Playful)
+//! - **Theme Crossfades**: Themed colors fade to the new palette when `UiTheme` changes
+//! - **Debug Overlay**: Live animation state labels via `AnimationDebug` (`debug` feature)
 //!
 //! # Quick Start
 //!
@@ -39,6 +41,8 @@
 //! - Auto-add system that automatically applies animations to interactive elements
 //! - Systems that run each frame to interpolate towards targets
 
+#[cfg(feature = "debug")]
+mod debug;
 mod easing;
 mod effects;
 mod plugin;
@@ -48,21 +52,25 @@ mod transitions;
 mod types;
 
 // Re-export all public types
+#[cfg(feature = "debug")]
+pub use debug::{AnimationDebug, AnimationDebugLabel, AnimationDebugPlugin};
 pub use easing::Easing;
 pub use effects::{HoverEffect, HoverEffects, PressEffect};
 pub use plugin::AnimationPlugin;
 pub use systems::lerp_color;
+#[cfg(test)]
+pub(crate) use systems::start_theme_transitions;
 pub use transitions::{Direction, Transition};
 pub use types::{
     AnimationCategory, AnimationOriginals, AnimationPreset, AnimationState, AnimationTarget,
     DisableAutoAnimation, EnterAnimating, ExitAnimating, InteractionAnimation, MountAnimation,
-    UiAnimation,
+    ThemeTransition, TransformOrigin, UiAnimation,
 };
 
 /// Prelude module for convenient animation imports
 pub mod prelude {
     pub use super::{
         AnimationCategory, AnimationPreset, Direction, DisableAutoAnimation, Easing, HoverEffect,
-        HoverEffects, Transition, UiAnimation,
+        HoverEffects, Transition, TransformOrigin, UiAnimation,
     };
 }
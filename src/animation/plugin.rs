@@ -3,9 +3,10 @@
 use bevy_plugin_builder::define_plugin;
 
 use super::systems::{
-    apply_animation_colors, apply_animation_transforms, auto_add_animation,
+    apply_animation_colors, apply_animation_transforms, apply_transform_origins, auto_add_animation,
     init_animation_originals, process_enter_animations, process_exit_animations,
-    tick_animation_states, update_interaction_animations,
+    start_theme_transitions, tick_animation_states, tick_theme_transitions,
+    update_disabled_animations, update_interaction_animations,
 };
 
 define_plugin!(AnimationPlugin {
@@ -19,15 +20,24 @@ define_plugin!(AnimationPlugin {
         // Update targets based on interaction changes
         update_interaction_animations,
 
+        // Fade into and out of the disabled look
+        update_disabled_animations,
+
         // Tick animation states towards targets
         tick_animation_states,
 
-        // Apply animation to visuals
-        apply_animation_transforms,
-        apply_animation_colors,
+        // Crossfade colors after a theme change, then apply animation to visuals
+        (start_theme_transitions, tick_theme_transitions, apply_animation_colors).chain(),
 
-        // Process mount/unmount animations
-        process_enter_animations,
-        process_exit_animations,
+        // Process mount/unmount animations on top of the interaction transforms
+        apply_animation_transforms,
+        process_enter_animations.after(apply_animation_transforms),
+        process_exit_animations.after(apply_animation_transforms),
+
+        // Hold scaled elements in place at their transform origin
+        apply_transform_origins
+            .after(init_animation_originals)
+            .after(process_enter_animations)
+            .after(process_exit_animations),
     ]
 });
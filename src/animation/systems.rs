@@ -5,15 +5,19 @@ use bevy::prelude::*;
 use super::easing::Easing;
 use super::transitions::Transition;
 use super::types::{
-    AnimationCategory, AnimationOriginals, DisableAutoAnimation, EnterAnimating, ExitAnimating,
-    UiAnimation,
+    AnimationCategory, AnimationOriginals, AnimationState, AnimationTarget, DisableAutoAnimation,
+    EnterAnimating, ExitAnimating, ThemeTransition, TransformOrigin, UiAnimation,
 };
-use crate::theme::UiTheme;
+use crate::theme::{ThemeColors, UiTheme};
+use crate::traits::Disabled;
+#[cfg(feature = "button")]
+use crate::components::button::{ButtonSelectionColors, ButtonStateColors};
 
 /// System to automatically add UiAnimation to entities with Interaction + AnimationCategory.
 ///
 /// This runs before other animation systems and applies per-category defaults from the theme.
-/// Entities with `DisableAutoAnimation` are skipped.
+/// Entities with `DisableAutoAnimation` are skipped, as is everything while the theme has
+/// `reduce_motion` set.
 pub fn auto_add_animation(
     mut commands: Commands,
     theme: Res<UiTheme>,
@@ -26,6 +30,10 @@ pub fn auto_add_animation(
         ),
     >,
 ) {
+    if theme.reduce_motion {
+        return;
+    }
+
     for (entity, category) in &query {
         let defaults = theme.animation.get_category_defaults(*category);
         let animation = UiAnimation::from_category_defaults(defaults);
@@ -33,9 +41,26 @@ pub fn auto_add_animation(
     }
 }
 
+/// Point an animation state at a target (unset values return to normal)
+fn set_targets(state: &mut AnimationState, target: &AnimationTarget) {
+    state.target_scale = target.scale.unwrap_or(1.0);
+    state.target_brightness = target.brightness.unwrap_or(1.0);
+    state.target_opacity = target.opacity.unwrap_or(1.0);
+    state.target_saturation = target.saturation.unwrap_or(1.0);
+    state.target_color_blend = if target.color_target.is_some() {
+        1.0
+    } else {
+        0.0
+    };
+    state.target_translation = target.translation.unwrap_or(Vec2::ZERO);
+    state.is_animating = true;
+}
+
 /// System to handle interaction-based animation targets
+///
+/// Disabled elements keep their disabled look whatever the pointer does.
 pub fn update_interaction_animations(
-    mut query: Query<(&Interaction, &mut UiAnimation), Changed<Interaction>>,
+    mut query: Query<(&Interaction, &mut UiAnimation), (Changed<Interaction>, Without<Disabled>)>,
 ) {
     for (interaction, mut animation) in &mut query {
         // Clone the target to avoid borrow issues
@@ -45,22 +70,57 @@ pub fn update_interaction_animations(
             Interaction::Pressed => animation.interaction.pressed.clone(),
         };
 
-        // Set animation targets from interaction state
-        animation.state.target_scale = target.scale.unwrap_or(1.0);
-        animation.state.target_brightness = target.brightness.unwrap_or(1.0);
-        animation.state.target_opacity = target.opacity.unwrap_or(1.0);
-        animation.state.target_color_blend = if target.color_target.is_some() {
-            1.0
-        } else {
-            0.0
+        set_targets(&mut animation.state, &target);
+    }
+}
+
+/// System to fade elements into and out of their disabled look
+///
+/// Adding [`Disabled`] animates towards `interaction.disabled` (half opacity,
+/// grayscale by default); removing it animates back to the state matching
+/// the current `Interaction`. Elements that are disabled when their animation
+/// is first added start in the disabled look instead of fading into it.
+pub fn update_disabled_animations(
+    mut removed: RemovedComponents<Disabled>,
+    mut disabled: Query<
+        &mut UiAnimation,
+        (With<Disabled>, Or<(Added<Disabled>, Added<UiAnimation>)>),
+    >,
+    mut enabled: Query<(&mut UiAnimation, Option<&Interaction>), Without<Disabled>>,
+) {
+    for mut animation in &mut disabled {
+        let target = animation.interaction.disabled.clone();
+        let spawned_disabled = animation.is_added();
+
+        set_targets(&mut animation.state, &target);
+        if spawned_disabled {
+            animation.state.snap_to_target();
+        }
+    }
+
+    for entity in removed.read() {
+        let Ok((mut animation, interaction)) = enabled.get_mut(entity) else {
+            continue;
+        };
+
+        let target = match interaction {
+            Some(Interaction::Hovered) => animation.interaction.hover.clone(),
+            Some(Interaction::Pressed) => animation.interaction.pressed.clone(),
+            Some(Interaction::None) | None => animation.interaction.idle.clone(),
         };
-        animation.state.target_translation = target.translation.unwrap_or(bevy::prelude::Vec2::ZERO);
-        animation.state.is_animating = true;
+
+        set_targets(&mut animation.state, &target);
     }
 }
 
 /// System to tick animation states towards their targets
-pub fn tick_animation_states(mut query: Query<&mut UiAnimation>, time: Res<Time>) {
+///
+/// With the theme's `reduce_motion` set, states jump straight to their targets.
+pub fn tick_animation_states(
+    mut query: Query<&mut UiAnimation>,
+    time: Res<Time>,
+    theme: Res<UiTheme>,
+) {
     let delta = time.delta_secs();
 
     for mut animation in &mut query {
@@ -68,7 +128,11 @@ pub fn tick_animation_states(mut query: Query<&mut UiAnimation>, time: Res<Time>
             continue;
         }
 
-        let speed = animation.state.animation_speed * delta;
+        let speed = if theme.reduce_motion {
+            1.0
+        } else {
+            animation.state.animation_speed * delta
+        };
         let state = &mut animation.state;
 
         // Animate scale
@@ -95,6 +159,14 @@ pub fn tick_animation_states(mut query: Query<&mut UiAnimation>, time: Res<Time>
             state.current_opacity = state.target_opacity;
         }
 
+        // Animate saturation
+        let saturation_diff = state.target_saturation - state.current_saturation;
+        if saturation_diff.abs() > 0.001 {
+            state.current_saturation += saturation_diff * speed;
+        } else {
+            state.current_saturation = state.target_saturation;
+        }
+
         // Animate color blend
         let blend_diff = state.target_color_blend - state.current_color_blend;
         if blend_diff.abs() > 0.001 {
@@ -139,12 +211,28 @@ pub fn apply_animation_transforms(
             .unwrap_or(transform.translation);
 
         // Apply translation offset (convert Vec2 to Vec3)
-        transform.translation = base_translation
-            + Vec3::new(
-                state.current_translation.x,
-                state.current_translation.y,
-                0.0,
-            );
+        transform.translation = base_translation + state.current_translation.extend(0.0);
+    }
+}
+
+/// Keep each animation's [`TransformOrigin`] in place while it is scaled
+///
+/// Runs after the interaction and mount animations have set the scale, and
+/// rebuilds the translation from the originals so the offset never piles up
+/// from one frame to the next.
+pub fn apply_transform_origins(
+    mut query: Query<(&UiAnimation, &AnimationOriginals, &ComputedNode, &mut Transform)>,
+) {
+    for (animation, originals, computed, mut transform) in &mut query {
+        if animation.origin == TransformOrigin::Center {
+            continue;
+        }
+
+        let state = &animation.state;
+        let size = computed.size() * computed.inverse_scale_factor();
+        transform.translation = originals.translation
+            + (state.current_translation + state.mount_translation).extend(0.0)
+            + animation.origin.offset(size, transform.scale, transform.rotation);
     }
 }
 
@@ -160,16 +248,22 @@ pub fn apply_animation_colors(
     for (animation, mut bg_color, border_color, originals) in &mut query {
         let state = &animation.state;
 
-        // Apply brightness to background
+        // Apply brightness, saturation and opacity to background
         if let Some(original_bg) = originals.and_then(|o| o.background) {
-            bg_color.0 = apply_brightness(original_bg, state.current_brightness);
+            let color = apply_brightness(original_bg, state.current_brightness);
+            bg_color.0 = apply_saturation(color, state.current_saturation)
+                .with_alpha(original_bg.alpha() * state.current_opacity);
         }
 
-        // Apply brightness to border if present
+        // Apply brightness, saturation and opacity to border if present
         if let (Some(mut border), Some(original_border)) =
             (border_color, originals.and_then(|o| o.border))
         {
-            *border = BorderColor::all(apply_brightness(original_border, state.current_brightness));
+            let color = apply_brightness(original_border, state.current_brightness);
+            *border = BorderColor::all(
+                apply_saturation(color, state.current_saturation)
+                    .with_alpha(original_border.alpha() * state.current_opacity),
+            );
         }
     }
 }
@@ -198,10 +292,13 @@ pub fn init_animation_originals(
 }
 
 /// System to process enter animations
+///
+/// With the theme's `reduce_motion` set, entities appear in their final state at once.
 pub fn process_enter_animations(
     mut commands: Commands,
     mut query: Query<(Entity, &mut UiAnimation, &mut Transform), With<EnterAnimating>>,
     time: Res<Time>,
+    theme: Res<UiTheme>,
 ) {
     let delta = time.delta_secs();
 
@@ -211,20 +308,23 @@ pub fn process_enter_animations(
                 mount_anim.play();
             }
 
-            let duration = mount_anim.transition.duration();
-            if duration > 0.0 {
-                mount_anim.progress += delta / duration;
-            } else {
-                mount_anim.progress = 1.0;
-            }
+            mount_anim.advance(delta);
 
             // Apply transition effects
-            apply_mount_transition(&mount_anim.transition, mount_anim.progress, &mut transform);
+            let progress = if theme.reduce_motion { 1.0 } else { mount_anim.cycle_progress() };
+            let mut shift = Vec2::ZERO;
+            let opacity = apply_mount_transition(&mount_anim.transition, progress, &mut transform, &mut shift);
 
-            if mount_anim.is_complete() {
+            if theme.reduce_motion || mount_anim.is_complete() {
                 mount_anim.playing = false;
+                shift = Vec2::ZERO;
                 commands.entity(entity).remove::<EnterAnimating>();
             }
+
+            transform.translation += shift.extend(0.0);
+            animation.state.mount_translation = shift;
+            animation.state.current_opacity = opacity;
+            animation.state.target_opacity = opacity;
         } else {
             commands.entity(entity).remove::<EnterAnimating>();
         }
@@ -232,65 +332,223 @@ pub fn process_enter_animations(
 }
 
 /// System to process exit animations
+///
+/// With the theme's `reduce_motion` set, exiting entities are despawned at once.
 pub fn process_exit_animations(
     mut commands: Commands,
     mut query: Query<(Entity, &mut UiAnimation, &mut Transform), With<ExitAnimating>>,
     time: Res<Time>,
+    theme: Res<UiTheme>,
 ) {
     let delta = time.delta_secs();
 
     for (entity, mut animation, mut transform) in &mut query {
+        if theme.reduce_motion && animation.exit.is_some() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
         if let Some(ref mut mount_anim) = animation.exit {
             if !mount_anim.playing {
                 mount_anim.play_reverse();
             }
 
-            let duration = mount_anim.transition.duration();
-            if duration > 0.0 {
-                mount_anim.progress -= delta / duration;
-            } else {
-                mount_anim.progress = 0.0;
-            }
+            mount_anim.advance(delta);
 
-            // Apply transition effects (in reverse)
-            apply_mount_transition(&mount_anim.transition, mount_anim.progress, &mut transform);
+            // Enter-style transitions play in reverse; exit-style ones
+            // (fade_out, scale_out, ...) already describe leaving, so play forward
+            let progress = if mount_anim.transition.is_exit() {
+                1.0 - mount_anim.cycle_progress()
+            } else {
+                mount_anim.cycle_progress()
+            };
+            let mut shift = Vec2::ZERO;
+            let opacity = apply_mount_transition(&mount_anim.transition, progress, &mut transform, &mut shift);
 
             if mount_anim.is_complete() {
                 // Despawn entity after exit animation completes
                 commands.entity(entity).despawn();
             }
+
+            transform.translation += shift.extend(0.0);
+            animation.state.mount_translation = shift;
+            animation.state.current_opacity = opacity;
+            animation.state.target_opacity = opacity;
         } else {
             commands.entity(entity).remove::<ExitAnimating>();
         }
     }
 }
 
-/// Apply mount transition effects to a transform
-fn apply_mount_transition(transition: &Transition, progress: f32, transform: &mut Transform) {
+/// System to crossfade UI colors when the theme palette changes
+///
+/// Every background, border and text color that plays a role in the old
+/// palette fades to the same role in the new one over the theme's `slow`
+/// duration (at once with `reduce_motion`). Colors outside the palette, such
+/// as custom `.color(...)` overrides, are left alone. The hover and pressed
+/// colors buttons animate towards move to the new palette right away.
+pub fn start_theme_transitions(
+    mut commands: Commands,
+    theme: Res<UiTheme>,
+    mut previous: Local<Option<ThemeColors>>,
+    query: Query<
+        (
+            Entity,
+            Option<&BackgroundColor>,
+            Option<&BorderColor>,
+            Option<&TextColor>,
+            Option<&AnimationOriginals>,
+            Option<&ThemeTransition>,
+            Option<&ChildOf>,
+        ),
+        Or<(With<BackgroundColor>, With<BorderColor>, With<TextColor>)>,
+    >,
+    backgrounds: Query<(&BackgroundColor, Option<&AnimationOriginals>)>,
+    #[cfg(feature = "button")]
+    mut button_colors: Query<(Option<&mut ButtonStateColors>, Option<&mut ButtonSelectionColors>)>,
+) {
+    if !theme.is_changed() {
+        return;
+    }
+    let Some(old) = previous.replace(theme.colors.clone()) else {
+        return;
+    };
+    let new = &theme.colors;
+    if old.palette() == new.palette() {
+        return;
+    }
+
+    let duration = if theme.reduce_motion {
+        0.0
+    } else {
+        theme.animation.durations.slow
+    };
+
+    #[cfg(feature = "button")]
+    for (state_colors, selection_colors) in &mut button_colors {
+        if let Some(mut state_colors) = state_colors {
+            state_colors.remap(&old, new);
+        }
+        if let Some(mut selection_colors) = selection_colors {
+            selection_colors.remap(&old, new);
+        }
+    }
+
+    for (entity, background, border, text, originals, running, child_of) in &query {
+        // Animated entities keep their un-hovered colors in the originals
+        let current_background = originals
+            .and_then(|o| o.background)
+            .or(background.map(|b| b.0));
+        let current_border = originals
+            .and_then(|o| o.border)
+            .or(border.map(|b| b.top));
+        let current_text = text.map(|t| t.0);
+
+        // A crossfade still running from an earlier change is measured from
+        // its destination, which is a color of the old palette
+        let rest_background = running.and_then(|r| r.background).map(|(_, to)| to).or(current_background);
+        let rest_border = running.and_then(|r| r.border).map(|(_, to)| to).or(current_border);
+        let rest_text = running.and_then(|r| r.text).map(|(_, to)| to).or(current_text);
+
+        let parent_background = child_of
+            .and_then(|child_of| backgrounds.get(child_of.parent()).ok())
+            .map(|(bg, originals)| originals.and_then(|o| o.background).unwrap_or(bg.0));
+
+        let transition = ThemeTransition {
+            background: current_background
+                .zip(rest_background.and_then(|c| old.remap(new, c))),
+            border: current_border
+                .zip(rest_border.and_then(|c| old.remap(new, c))),
+            text: current_text
+                .zip(rest_text.and_then(|c| old.remap_text(new, c, parent_background))),
+            elapsed: 0.0,
+            duration,
+        };
+
+        if transition.background.is_some() || transition.border.is_some() || transition.text.is_some() {
+            commands.entity(entity).insert(transition);
+        }
+    }
+}
+
+/// System to advance theme crossfades and write the blended colors
+///
+/// Runs before `apply_animation_colors`, which re-applies hover effects on top
+/// of the blended originals.
+pub fn tick_theme_transitions(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(
+        Entity,
+        &mut ThemeTransition,
+        Option<&mut BackgroundColor>,
+        Option<&mut BorderColor>,
+        Option<&mut TextColor>,
+        Option<&mut AnimationOriginals>,
+    )>,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut transition, background, border, text, mut originals) in &mut query {
+        transition.elapsed += delta;
+        let t = Easing::EaseInOut.apply(transition.progress());
+
+        if let (Some((from, to)), Some(mut background)) = (transition.background, background) {
+            let color = lerp_color(from, to, t);
+            background.0 = color;
+            if let Some(originals) = originals.as_mut() {
+                originals.background = Some(color);
+            }
+        }
+
+        if let (Some((from, to)), Some(mut border)) = (transition.border, border) {
+            let color = lerp_color(from, to, t);
+            *border = BorderColor::all(color);
+            if let Some(originals) = originals.as_mut() {
+                originals.border = Some(color);
+            }
+        }
+
+        if let (Some((from, to)), Some(mut text)) = (transition.text, text) {
+            text.0 = lerp_color(from, to, t);
+        }
+
+        if transition.progress() >= 1.0 {
+            commands.entity(entity).remove::<ThemeTransition>();
+        }
+    }
+}
+
+/// Apply mount transition effects to a transform, returning the opacity
+/// (applied to colors by `apply_animation_colors`)
+///
+/// Slides and shakes go into `shift` rather than the transform; the callers
+/// add it on top of the translation and remember it for the transform origin.
+fn apply_mount_transition(
+    transition: &Transition,
+    progress: f32,
+    transform: &mut Transform,
+    shift: &mut Vec2,
+) -> f32 {
     let easing = transition.easing().unwrap_or(Easing::EaseOut);
     let t = easing.apply(progress);
 
     match transition {
-        Transition::FadeIn { .. } | Transition::FadeOut { .. } => {
-            // Opacity is handled separately
-        }
+        Transition::FadeIn { .. } => return t,
+        Transition::FadeOut { .. } => return 1.0 - t,
         Transition::SlideIn {
             direction,
             distance,
             ..
         } => {
-            let offset = direction.as_vec2(*distance) * (1.0 - t);
-            transform.translation.x += offset.x;
-            transform.translation.y += offset.y;
+            *shift = direction.as_vec2(*distance) * (1.0 - t);
         }
         Transition::SlideOut {
             direction,
             distance,
             ..
         } => {
-            let offset = direction.as_vec2(*distance) * t;
-            transform.translation.x += offset.x;
-            transform.translation.y += offset.y;
+            *shift = direction.as_vec2(*distance) * t;
         }
         Transition::ScaleIn { from, .. } => {
             let scale = *from + (1.0 - *from) * t;
@@ -305,13 +563,13 @@ fn apply_mount_transition(transition: &Transition, progress: f32, transform: &mu
             distance,
             ..
         } => {
-            let offset = direction.as_vec2(*distance) * (1.0 - t);
-            transform.translation.x += offset.x;
-            transform.translation.y += offset.y;
+            *shift = direction.as_vec2(*distance) * (1.0 - t);
+            return t;
         }
         Transition::FadeScale { scale, .. } => {
             let s = *scale + (1.0 - *scale) * t;
             transform.scale = Vec3::splat(s);
+            return t;
         }
         Transition::Bounce { intensity, .. } => {
             // Bounce effect using elastic easing
@@ -322,14 +580,19 @@ fn apply_mount_transition(transition: &Transition, progress: f32, transform: &mu
             // Shake effect
             let shake_amount = *intensity * (1.0 - t);
             let phase = progress * 20.0 * std::f32::consts::PI;
-            transform.translation.x += shake_amount * phase.sin();
+            *shift = Vec2::new(shake_amount * phase.sin(), 0.0);
         }
         Transition::Pulse { scale, .. } => {
             let pulse_t = (progress * std::f32::consts::PI).sin();
             transform.scale = Vec3::splat(1.0 + (*scale - 1.0) * pulse_t);
         }
+        Transition::Repeat { transition, .. } => {
+            return apply_mount_transition(transition, progress, transform, shift);
+        }
         Transition::None => {}
     }
+
+    1.0
 }
 
 /// Helper function to apply brightness to a color
@@ -343,6 +606,23 @@ fn apply_brightness(color: Color, brightness: f32) -> Color {
     })
 }
 
+/// Helper function to blend a color towards its grayscale luminance
+/// (1.0 = unchanged, 0.0 = fully gray)
+fn apply_saturation(color: Color, saturation: f32) -> Color {
+    if saturation >= 1.0 {
+        return color;
+    }
+
+    let rgba = color.to_linear();
+    let gray = rgba.red * 0.2126 + rgba.green * 0.7152 + rgba.blue * 0.0722;
+    Color::LinearRgba(LinearRgba {
+        red: gray + (rgba.red - gray) * saturation,
+        green: gray + (rgba.green - gray) * saturation,
+        blue: gray + (rgba.blue - gray) * saturation,
+        alpha: rgba.alpha,
+    })
+}
+
 /// Helper function to lerp between colors
 pub fn lerp_color(from: Color, to: Color, t: f32) -> Color {
     let from_linear = from.to_linear();
@@ -355,3 +635,37 @@ pub fn lerp_color(from: Color, to: Color, t: f32) -> Color {
         alpha: from_linear.alpha + (to_linear.alpha - from_linear.alpha) * t,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transform_origin_offset_does_not_pile_up() {
+        let mut app = App::new();
+        app.add_systems(Update, (apply_animation_transforms, apply_transform_origins).chain());
+
+        let mut animation = UiAnimation::new().with_origin(TransformOrigin::TopLeft);
+        animation.state.current_scale = 0.5;
+        let entity = app.world_mut().spawn((
+            animation,
+            AnimationOriginals {
+                translation: Vec3::new(10.0, 20.0, 0.0),
+                ..default()
+            },
+            ComputedNode {
+                size: Vec2::new(100.0, 50.0),
+                inverse_scale_factor: 1.0,
+                ..default()
+            },
+            Transform::default(),
+        )).id();
+
+        // The top-left corner stays put however many frames the scale holds
+        for _ in 0..3 {
+            app.update();
+            let transform = app.world().get::<Transform>(entity).unwrap();
+            assert_eq!(transform.translation, Vec3::new(-15.0, 7.5, 0.0));
+        }
+    }
+}
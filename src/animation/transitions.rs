@@ -14,6 +14,17 @@ use super::easing::Easing;
 ///     .exit_animation(Transition::fade_out(0.2))
 ///     .build(commands);
 /// ```
+///
+/// Any transition can loop with [`repeat`](Self::repeat) /
+/// [`repeat_forever`](Self::repeat_forever), and ping-pong with
+/// [`yoyo`](Self::yoyo):
+///
+/// ```ignore
+/// // Bob up and down for as long as the element lives
+/// UiAnimation::new().with_enter(
+///     Transition::slide_from_top(8.0, 0.5).ease_in_out().repeat_forever().yoyo(true),
+/// )
+/// ```
 #[derive(Clone, Debug)]
 pub enum Transition {
     /// Fade in from transparent
@@ -122,6 +133,16 @@ pub enum Transition {
         duration: f32,
     },
 
+    /// Play another transition several times
+    Repeat {
+        /// The transition to repeat
+        transition: Box<Transition>,
+        /// Number of cycles to play (`None` loops forever)
+        count: Option<u32>,
+        /// Play every other cycle backwards
+        yoyo: bool,
+    },
+
     /// No transition (instant)
     None,
 }
@@ -245,6 +266,46 @@ impl Transition {
 
     // ========== Modifiers ==========
 
+    /// Play this transition `count` times in total
+    pub fn repeat(self, count: u32) -> Self {
+        self.with_repeat(Some(count.max(1)))
+    }
+
+    /// Loop this transition until the element is despawned
+    ///
+    /// Exit transitions play a looping transition's cycle once, so the
+    /// element still despawns.
+    pub fn repeat_forever(self) -> Self {
+        self.with_repeat(None)
+    }
+
+    /// Play every other cycle backwards, so the transition ping-pongs
+    ///
+    /// On a transition that doesn't repeat yet, this plays it there and back once.
+    pub fn yoyo(self, yoyo: bool) -> Self {
+        match self {
+            Self::Repeat { transition, count, .. } => Self::Repeat { transition, count, yoyo },
+            other if yoyo => Self::Repeat {
+                transition: Box::new(other),
+                count: Some(2),
+                yoyo,
+            },
+            other => other,
+        }
+    }
+
+    fn with_repeat(self, count: Option<u32>) -> Self {
+        match self {
+            Self::None => Self::None,
+            Self::Repeat { transition, yoyo, .. } => Self::Repeat { transition, count, yoyo },
+            other => Self::Repeat {
+                transition: Box::new(other),
+                count,
+                yoyo: false,
+            },
+        }
+    }
+
     /// Set the easing curve for this transition
     pub fn ease(self, easing: Easing) -> Self {
         match self {
@@ -298,6 +359,11 @@ impl Transition {
                 duration,
                 easing,
             },
+            Self::Repeat { transition, count, yoyo } => Self::Repeat {
+                transition: Box::new(transition.ease(easing)),
+                count,
+                yoyo,
+            },
             other => other, // Bounce, Shake, Pulse, None don't have easing
         }
     }
@@ -324,7 +390,7 @@ impl Transition {
 
     // ========== Properties ==========
 
-    /// Get the duration of this transition
+    /// Get the duration of this transition (of one cycle, for repeating transitions)
     pub fn duration(&self) -> f32 {
         match self {
             Self::FadeIn { duration, .. } => *duration,
@@ -338,10 +404,42 @@ impl Transition {
             Self::Bounce { duration, .. } => *duration,
             Self::Shake { duration, .. } => *duration,
             Self::Pulse { duration, .. } => *duration,
+            Self::Repeat { transition, .. } => transition.duration(),
             Self::None => 0.0,
         }
     }
 
+    /// Number of cycles this transition plays (`None` loops forever)
+    pub fn cycles(&self) -> Option<u32> {
+        match self {
+            Self::Repeat { count, .. } => *count,
+            _ => Some(1),
+        }
+    }
+
+    /// The transition played each cycle (the transition itself unless it repeats)
+    pub fn base(&self) -> &Transition {
+        match self {
+            Self::Repeat { transition, .. } => transition.base(),
+            other => other,
+        }
+    }
+
+    /// Map progress within `cycle` (counting from 0) to the progress to display,
+    /// running odd cycles backwards for yoyo transitions
+    pub fn cycle_progress(&self, cycle: u32, progress: f32) -> f32 {
+        match self {
+            Self::Repeat { yoyo: true, .. } if cycle % 2 == 1 => 1.0 - progress,
+            _ => progress,
+        }
+    }
+
+    /// Whether this transition describes leaving (fade/slide/scale out)
+    /// rather than arriving
+    pub fn is_exit(&self) -> bool {
+        matches!(self.base(), Self::FadeOut { .. } | Self::SlideOut { .. } | Self::ScaleOut { .. })
+    }
+
     /// Get the easing curve if applicable
     pub fn easing(&self) -> Option<Easing> {
         match self {
@@ -353,6 +451,7 @@ impl Transition {
             Self::ScaleOut { easing, .. } => Some(*easing),
             Self::FadeSlide { easing, .. } => Some(*easing),
             Self::FadeScale { easing, .. } => Some(*easing),
+            Self::Repeat { transition, .. } => transition.easing(),
             _ => None,
         }
     }
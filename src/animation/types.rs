@@ -33,6 +33,8 @@ pub struct UiAnimation {
     pub enter: Option<MountAnimation>,
     /// Exit animation (when despawned/hidden)
     pub exit: Option<MountAnimation>,
+    /// Point that scale animations grow from and shrink towards
+    pub origin: TransformOrigin,
 }
 
 impl Default for UiAnimation {
@@ -42,6 +44,7 @@ impl Default for UiAnimation {
             interaction: InteractionAnimation::default(),
             enter: None,
             exit: None,
+            origin: TransformOrigin::Center,
         }
     }
 }
@@ -90,6 +93,21 @@ impl UiAnimation {
         self
     }
 
+    /// Scale from `origin` instead of the center
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // A menu that grows out of its top-left corner, where it is anchored
+    /// UiAnimation::new()
+    ///     .with_enter(Transition::scale_in(0.6, 0.15))
+    ///     .with_origin(TransformOrigin::TopLeft)
+    /// ```
+    pub fn with_origin(mut self, origin: TransformOrigin) -> Self {
+        self.origin = origin;
+        self
+    }
+
     /// Apply a preset animation configuration
     pub fn with_preset(mut self, preset: AnimationPreset) -> Self {
         match preset {
@@ -186,6 +204,62 @@ impl UiAnimation {
     }
 }
 
+/// Point on a node that scale animations grow from and shrink towards
+///
+/// Bevy scales UI nodes about their center; any other origin is kept in
+/// place by offsetting the node's translation.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum TransformOrigin {
+    /// The middle of the node (Bevy's own behavior)
+    #[default]
+    Center,
+    /// Top-left corner
+    TopLeft,
+    /// Middle of the top edge
+    Top,
+    /// Top-right corner
+    TopRight,
+    /// Middle of the left edge
+    Left,
+    /// Middle of the right edge
+    Right,
+    /// Bottom-left corner
+    BottomLeft,
+    /// Middle of the bottom edge
+    Bottom,
+    /// Bottom-right corner
+    BottomRight,
+    /// Fractions of the node's size from its top-left corner
+    /// (`Vec2::ZERO` is the top-left, `Vec2::ONE` the bottom-right)
+    Custom(Vec2),
+}
+
+impl TransformOrigin {
+    /// The origin as fractions of the node's size from its top-left corner
+    pub fn fraction(self) -> Vec2 {
+        match self {
+            Self::Center => Vec2::splat(0.5),
+            Self::TopLeft => Vec2::new(0.0, 0.0),
+            Self::Top => Vec2::new(0.5, 0.0),
+            Self::TopRight => Vec2::new(1.0, 0.0),
+            Self::Left => Vec2::new(0.0, 0.5),
+            Self::Right => Vec2::new(1.0, 0.5),
+            Self::BottomLeft => Vec2::new(0.0, 1.0),
+            Self::Bottom => Vec2::new(0.5, 1.0),
+            Self::BottomRight => Vec2::new(1.0, 1.0),
+            Self::Custom(fraction) => fraction,
+        }
+    }
+
+    /// Translation that keeps this point of a node of `size` (logical
+    /// pixels) still while it is scaled and rotated about its center
+    pub fn offset(self, size: Vec2, scale: Vec3, rotation: Quat) -> Vec3 {
+        // UI y grows downwards, like the fractions
+        let pivot = ((self.fraction() - Vec2::splat(0.5)) * size).extend(0.0);
+        pivot - rotation * (scale * pivot)
+    }
+}
+
 /// Current state of an animation
 #[derive(Clone, Debug)]
 pub struct AnimationState {
@@ -201,6 +275,10 @@ pub struct AnimationState {
     pub current_opacity: f32,
     /// Target opacity
     pub target_opacity: f32,
+    /// Current color saturation (1.0 = original colors, 0.0 = grayscale)
+    pub current_saturation: f32,
+    /// Target saturation
+    pub target_saturation: f32,
     /// Current color blend factor (0.0 = original, 1.0 = target color)
     pub current_color_blend: f32,
     /// Target color blend
@@ -209,6 +287,8 @@ pub struct AnimationState {
     pub current_translation: Vec2,
     /// Target translation
     pub target_translation: Vec2,
+    /// Offset from the running enter or exit transition (slides and shakes)
+    pub mount_translation: Vec2,
     /// Animation speed multiplier
     pub animation_speed: f32,
     /// Whether the element is currently animating
@@ -224,10 +304,13 @@ impl Default for AnimationState {
             target_brightness: 1.0,
             current_opacity: 1.0,
             target_opacity: 1.0,
+            current_saturation: 1.0,
+            target_saturation: 1.0,
             current_color_blend: 0.0,
             target_color_blend: 0.0,
             current_translation: Vec2::ZERO,
             target_translation: Vec2::ZERO,
+            mount_translation: Vec2::ZERO,
             animation_speed: 10.0,
             is_animating: false,
         }
@@ -240,6 +323,7 @@ impl AnimationState {
         (self.current_scale - self.target_scale).abs() < 0.001
             && (self.current_brightness - self.target_brightness).abs() < 0.001
             && (self.current_opacity - self.target_opacity).abs() < 0.001
+            && (self.current_saturation - self.target_saturation).abs() < 0.001
             && (self.current_color_blend - self.target_color_blend).abs() < 0.001
             && self.current_translation.distance(self.target_translation) < 0.1
     }
@@ -249,13 +333,24 @@ impl AnimationState {
         self.target_scale = 1.0;
         self.target_brightness = 1.0;
         self.target_opacity = 1.0;
+        self.target_saturation = 1.0;
         self.target_color_blend = 0.0;
         self.target_translation = Vec2::ZERO;
     }
+
+    /// Jump to the targets without animating
+    pub fn snap_to_target(&mut self) {
+        self.current_scale = self.target_scale;
+        self.current_brightness = self.target_brightness;
+        self.current_opacity = self.target_opacity;
+        self.current_saturation = self.target_saturation;
+        self.current_color_blend = self.target_color_blend;
+        self.current_translation = self.target_translation;
+    }
 }
 
 /// Animation configuration for interaction states
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 pub struct InteractionAnimation {
     /// Animation when idle (not hovered)
     pub idle: AnimationTarget,
@@ -265,10 +360,28 @@ pub struct InteractionAnimation {
     pub pressed: AnimationTarget,
     /// Animation when focused
     pub focused: AnimationTarget,
-    /// Animation when disabled
+    /// Animation while the element is [`Disabled`](crate::traits::Disabled)
+    ///
+    /// Defaults to half opacity and grayscale.
     pub disabled: AnimationTarget,
 }
 
+impl Default for InteractionAnimation {
+    fn default() -> Self {
+        Self {
+            idle: AnimationTarget::default(),
+            hover: AnimationTarget::default(),
+            pressed: AnimationTarget::default(),
+            focused: AnimationTarget::default(),
+            disabled: AnimationTarget {
+                opacity: Some(0.5),
+                saturation: Some(0.0),
+                ..default()
+            },
+        }
+    }
+}
+
 /// Target values for an animation state
 #[derive(Clone, Debug, Default)]
 pub struct AnimationTarget {
@@ -278,6 +391,8 @@ pub struct AnimationTarget {
     pub brightness: Option<f32>,
     /// Opacity (None = no change)
     pub opacity: Option<f32>,
+    /// Color saturation, 0.0 for grayscale (None = no change)
+    pub saturation: Option<f32>,
     /// Target color to blend towards (None = no change)
     pub color_target: Option<Color>,
     /// Border color override
@@ -294,6 +409,7 @@ impl AnimationTarget {
         self.scale.is_some()
             || self.brightness.is_some()
             || self.opacity.is_some()
+            || self.saturation.is_some()
             || self.color_target.is_some()
             || self.border_color.is_some()
             || self.translation.is_some()
@@ -305,8 +421,10 @@ impl AnimationTarget {
 pub struct MountAnimation {
     /// The transition to use
     pub transition: Transition,
-    /// Current progress (0.0 to 1.0)
+    /// Current progress within the current cycle (0.0 to 1.0)
     pub progress: f32,
+    /// Cycles completed so far (repeating transitions)
+    pub cycle: u32,
     /// Whether the animation is playing
     pub playing: bool,
     /// Whether this is a reverse (exit) animation
@@ -319,6 +437,7 @@ impl MountAnimation {
         Self {
             transition,
             progress: 0.0,
+            cycle: 0,
             playing: false,
             reverse: false,
         }
@@ -327,6 +446,7 @@ impl MountAnimation {
     /// Start playing the animation
     pub fn play(&mut self) {
         self.progress = 0.0;
+        self.cycle = 0;
         self.playing = true;
         self.reverse = false;
     }
@@ -334,10 +454,50 @@ impl MountAnimation {
     /// Start playing in reverse (for exit)
     pub fn play_reverse(&mut self) {
         self.progress = 1.0;
+        self.cycle = 0;
         self.playing = true;
         self.reverse = true;
     }
 
+    /// Advance by `delta` seconds, wrapping into the next cycle of a
+    /// repeating transition
+    pub fn advance(&mut self, delta: f32) {
+        let duration = self.transition.duration();
+        if duration <= 0.0 {
+            self.progress = if self.reverse { 0.0 } else { 1.0 };
+            return;
+        }
+
+        let step = delta / duration;
+        if self.reverse {
+            self.progress -= step;
+            while self.progress <= 0.0 && self.has_next_cycle() {
+                self.progress += 1.0;
+                self.cycle += 1;
+            }
+        } else {
+            self.progress += step;
+            while self.progress >= 1.0 && self.has_next_cycle() {
+                self.progress -= 1.0;
+                self.cycle += 1;
+            }
+        }
+    }
+
+    /// Progress to display for the current cycle (reversed on yoyo back-swings)
+    pub fn cycle_progress(&self) -> f32 {
+        self.transition.cycle_progress(self.cycle, self.progress.clamp(0.0, 1.0))
+    }
+
+    /// Whether another cycle follows the current one
+    fn has_next_cycle(&self) -> bool {
+        match self.transition.cycles() {
+            Some(count) => self.cycle + 1 < count,
+            // Looping exits would never despawn the element
+            None => !self.reverse,
+        }
+    }
+
     /// Check if complete
     pub fn is_complete(&self) -> bool {
         if self.reverse {
@@ -431,3 +591,83 @@ pub enum AnimationCategory {
 /// ```
 #[derive(Component, Default)]
 pub struct DisableAutoAnimation;
+
+/// Crossfade of an entity's colors to a new theme palette.
+///
+/// Inserted by the animation plugin when the [`UiTheme`](crate::theme::UiTheme)
+/// palette changes, and removed once the new colors are reached. Each pair is
+/// the `(from, to)` color of that channel.
+#[derive(Component, Clone, Debug)]
+pub struct ThemeTransition {
+    /// Background color crossfade
+    pub background: Option<(Color, Color)>,
+    /// Border color crossfade
+    pub border: Option<(Color, Color)>,
+    /// Text color crossfade
+    pub text: Option<(Color, Color)>,
+    /// Seconds since the crossfade started
+    pub elapsed: f32,
+    /// Length of the crossfade in seconds
+    pub duration: f32,
+}
+
+impl ThemeTransition {
+    /// Linear progress of the crossfade (0.0 to 1.0)
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            1.0
+        } else {
+            (self.elapsed / self.duration).min(1.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_origin_stays_in_place_while_scaling() {
+        let size = Vec2::new(100.0, 50.0);
+        let scale = Vec3::splat(0.5);
+
+        // Scaling about the center moves the top-left corner inwards to
+        // (-25, -12.5); the offset moves it back to (-50, -25)
+        let offset = TransformOrigin::TopLeft.offset(size, scale, Quat::IDENTITY);
+        assert_eq!(offset, Vec3::new(-25.0, -12.5, 0.0));
+        assert_eq!(Vec3::new(-25.0, -12.5, 0.0) + offset, Vec3::new(-50.0, -25.0, 0.0));
+
+        assert_eq!(TransformOrigin::Center.offset(size, scale, Quat::IDENTITY), Vec3::ZERO);
+        assert_eq!(TransformOrigin::TopLeft.offset(size, Vec3::ONE, Quat::IDENTITY), Vec3::ZERO);
+    }
+
+    #[test]
+    fn test_repeat_counts_cycles() {
+        let mut mount = MountAnimation::from_transition(Transition::fade_in(1.0).repeat(3));
+        mount.play();
+
+        mount.advance(2.5);
+        assert_eq!(mount.cycle, 2);
+        assert!(!mount.is_complete());
+
+        mount.advance(1.0);
+        assert_eq!(mount.cycle, 2);
+        assert!(mount.is_complete());
+    }
+
+    #[test]
+    fn test_yoyo_reverses_odd_cycles() {
+        let mut mount = MountAnimation::from_transition(Transition::fade_in(1.0).repeat_forever().yoyo(true));
+        mount.play();
+
+        mount.advance(0.25);
+        assert!((mount.cycle_progress() - 0.25).abs() < 0.001);
+
+        mount.advance(1.0);
+        assert_eq!(mount.cycle, 1);
+        assert!((mount.cycle_progress() - 0.75).abs() < 0.001);
+
+        mount.advance(100.0);
+        assert!(!mount.is_complete());
+    }
+}
@@ -0,0 +1,155 @@
+//! Interaction sounds (requires the `audio` feature).
+//!
+//! Insert a [`UiSounds`] resource and every button plays its hover and click
+//! sounds, while checkboxes and selectable buttons play the toggle sound when
+//! their state flips. Leave a handle as `None` to stay silent for that event.
+//!
+//! ```ignore
+//! fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+//!     commands.insert_resource(UiSounds {
+//!         click: Some(asset_server.load("sounds/click.ogg")),
+//!         ..default()
+//!     });
+//! }
+//! ```
+//!
+//! Opt a widget (or a whole subtree) out by inserting [`NoUiSounds`]:
+//!
+//! ```ignore
+//! ButtonBuilder::new("Silent").insert(NoUiSounds).build(parent);
+//! ```
+
+use std::collections::HashSet;
+
+use bevy::audio::Volume;
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+/// Sounds played for UI interactions
+#[derive(Resource, Debug, Clone)]
+pub struct UiSounds {
+    /// Played when the pointer enters a button
+    pub hover: Option<Handle<AudioSource>>,
+    /// Played when a button is pressed
+    pub click: Option<Handle<AudioSource>>,
+    /// Played when a checkbox or selectable button changes state
+    pub toggle: Option<Handle<AudioSource>>,
+    /// Linear volume applied to every UI sound
+    pub volume: f32,
+}
+
+impl Default for UiSounds {
+    fn default() -> Self {
+        Self {
+            hover: None,
+            click: None,
+            toggle: None,
+            volume: 1.0,
+        }
+    }
+}
+
+/// Silences UI sounds for this entity and all of its descendants
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct NoUiSounds;
+
+/// Whether `entity` or one of its ancestors opted out of UI sounds
+fn is_muted(entity: Entity, parents: &Query<&ChildOf>, muted: &Query<(), With<NoUiSounds>>) -> bool {
+    std::iter::once(entity)
+        .chain(parents.iter_ancestors(entity))
+        .any(|entity| muted.contains(entity))
+}
+
+/// Spawn a one-shot audio player that despawns when finished
+fn play(commands: &mut Commands, sound: &Option<Handle<AudioSource>>, volume: f32) {
+    if let Some(sound) = sound {
+        commands.spawn((
+            AudioPlayer::new(sound.clone()),
+            PlaybackSettings::DESPAWN.with_volume(Volume::Linear(volume)),
+        ));
+    }
+}
+
+/// Play hover and click sounds for buttons
+pub fn play_ui_interaction_sounds(
+    mut commands: Commands,
+    sounds: Res<UiSounds>,
+    buttons: Query<(Entity, &Interaction), (Changed<Interaction>, With<Button>)>,
+    parents: Query<&ChildOf>,
+    muted: Query<(), With<NoUiSounds>>,
+    #[cfg(feature = "button")]
+    selectable: Query<(), With<crate::components::button::SelectableButton>>,
+    mut removed: RemovedComponents<Interaction>,
+    mut hovered: Local<HashSet<Entity>>,
+) {
+    for entity in removed.read() {
+        hovered.remove(&entity);
+    }
+
+    for (entity, interaction) in buttons.iter() {
+        match interaction {
+            Interaction::None => {
+                hovered.remove(&entity);
+            }
+            // Returning from Pressed to Hovered is not a new hover
+            Interaction::Hovered => {
+                if hovered.insert(entity) && !is_muted(entity, &parents, &muted) {
+                    play(&mut commands, &sounds.hover, sounds.volume);
+                }
+            }
+            Interaction::Pressed => {
+                hovered.insert(entity);
+
+                // Selectable buttons play the toggle sound instead
+                #[cfg(feature = "button")]
+                if selectable.contains(entity) {
+                    continue;
+                }
+
+                if !is_muted(entity, &parents, &muted) {
+                    play(&mut commands, &sounds.click, sounds.volume);
+                }
+            }
+        }
+    }
+}
+
+/// Play the toggle sound when checkboxes or selectable buttons change state
+pub fn play_ui_toggle_sounds(
+    mut commands: Commands,
+    sounds: Res<UiSounds>,
+    parents: Query<&ChildOf>,
+    muted: Query<(), With<NoUiSounds>>,
+    #[cfg(feature = "checkbox")]
+    checkboxes: Query<(Entity, Ref<crate::components::checkbox::CheckboxState>)>,
+    #[cfg(feature = "button")]
+    mut selections: MessageReader<crate::components::button::SelectionChanged>,
+) {
+    #[allow(unused_mut)]
+    let mut toggled: Vec<Entity> = Vec::new();
+
+    #[cfg(feature = "checkbox")]
+    toggled.extend(
+        checkboxes.iter()
+            .filter(|(_, state)| state.is_changed() && !state.is_added())
+            .map(|(entity, _)| entity),
+    );
+
+    #[cfg(feature = "button")]
+    toggled.extend(selections.read().map(|event| event.entity));
+
+    // One sound per frame is enough, even when a radio group flips two entries
+    if toggled.iter().any(|&entity| !is_muted(entity, &parents, &muted)) {
+        play(&mut commands, &sounds.toggle, sounds.volume);
+    }
+}
+
+define_plugin!(UiSoundsPlugin {
+    custom_init: |app: &mut App| {
+        app.init_resource::<UiSounds>();
+    },
+    update: [
+        play_ui_interaction_sounds,
+        play_ui_toggle_sounds,
+    ]
+});
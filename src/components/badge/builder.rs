@@ -0,0 +1,315 @@
+//! BadgeBuilder implementation
+
+use bevy::prelude::*;
+use crate::relationships::BadgeOf;
+use crate::styles::dimensions;
+use crate::theme::{UiTheme, SemanticVariant};
+use crate::traits::{UiBuilder, LayoutBuilder, BuilderBase};
+use super::types::*;
+
+/// Font size of the number in a count badge
+const COUNT_FONT_SIZE: f32 = 11.0;
+
+/// Horizontal padding around the number in a count badge
+const COUNT_PADDING: f32 = 5.0;
+
+/// Resolved badge colors (either from theme or defaults)
+#[derive(Clone)]
+struct BadgeColors {
+    background: Color,
+    text: Color,
+}
+
+impl BadgeColors {
+    fn from_theme(theme: &UiTheme, variant: SemanticVariant) -> Self {
+        let colors = variant.color_scale(theme).resolve();
+        Self {
+            background: colors.background,
+            text: colors.text,
+        }
+    }
+}
+
+/// Builder for a count or dot badge on the corner of another widget
+///
+/// Works on any UI entity - buttons, tabs, inventory slots - so badges look
+/// and behave the same everywhere. The badge is spawned as a child of the
+/// target and follows it around; despawning the target despawns the badge.
+///
+/// # Example
+/// ```ignore
+/// let badge = BadgeBuilder::count(3)
+///     .max(99)
+///     .attach_to(&mut commands, inbox_button);
+///
+/// // Later: update the number
+/// badges.get_mut(badge)?.count = Some(4);
+/// ```
+pub struct BadgeBuilder {
+    count: Option<u32>,
+    max: u32,
+    corner: BadgeCorner,
+    show_zero: bool,
+    variant: SemanticVariant,
+    // Theme from .themed(), resolved against the variant in build()
+    theme: Option<UiTheme>,
+    custom_background: Option<Color>,
+    custom_text_color: Option<Color>,
+    base: BuilderBase,
+}
+
+impl BadgeBuilder {
+    fn new(count: Option<u32>) -> Self {
+        let mut base = BuilderBase::new();
+        base.node.position_type = PositionType::Absolute;
+        base.node.justify_content = JustifyContent::Center;
+        base.node.align_items = AlignItems::Center;
+        match count {
+            Some(_) => {
+                base.node.min_width = Val::Px(COUNT_HEIGHT);
+                base.node.height = Val::Px(COUNT_HEIGHT);
+                base.node.padding = UiRect::horizontal(Val::Px(COUNT_PADDING));
+            }
+            None => {
+                base.node.width = Val::Px(DOT_SIZE);
+                base.node.height = Val::Px(DOT_SIZE);
+            }
+        }
+
+        Self {
+            count,
+            max: 99,
+            corner: BadgeCorner::default(),
+            show_zero: false,
+            variant: SemanticVariant::Danger,
+            theme: None,
+            custom_background: None,
+            custom_text_color: None,
+            base,
+        }
+    }
+
+    /// Create a badge showing a number
+    pub fn count(count: u32) -> Self {
+        Self::new(Some(count))
+    }
+
+    /// Create a plain dot badge, for "something new" without a number
+    pub fn dot() -> Self {
+        Self::new(None)
+    }
+
+    /// Apply theme colors to this builder.
+    ///
+    /// Call this method to use theme-aware styling. If not called,
+    /// sensible defaults (matching the dark theme) will be used.
+    pub fn themed(mut self, theme: &UiTheme) -> Self {
+        self.theme = Some(theme.clone());
+        self
+    }
+
+    /// Set the semantic variant (defaults to `Danger`)
+    pub fn variant(mut self, variant: SemanticVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Set the largest number shown; higher counts show as "max+"
+    pub fn max(mut self, max: u32) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Set the corner of the target the badge sits on
+    pub fn corner(mut self, corner: BadgeCorner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Keep the badge visible when its count is zero
+    ///
+    /// By default a count badge hides itself at zero.
+    pub fn show_zero(mut self, show_zero: bool) -> Self {
+        self.show_zero = show_zero;
+        self
+    }
+
+    /// Set a custom background color
+    pub fn color(mut self, color: Color) -> Self {
+        self.custom_background = Some(color);
+        self
+    }
+
+    /// Set a custom text color
+    pub fn text_color(mut self, color: Color) -> Self {
+        self.custom_text_color = Some(color);
+        self
+    }
+
+    /// Resolve colors (custom > themed > default)
+    fn resolve_colors(&self) -> BadgeColors {
+        let colors = match &self.theme {
+            Some(theme) => BadgeColors::from_theme(theme, self.variant),
+            None => BadgeColors::from_theme(&UiTheme::default(), self.variant),
+        };
+
+        BadgeColors {
+            background: self.custom_background.unwrap_or(colors.background),
+            text: self.custom_text_color.unwrap_or(colors.text),
+        }
+    }
+
+    /// Attach the badge to `target` and return the badge entity
+    pub fn attach_to(self, commands: &mut Commands, target: Entity) -> Entity {
+        let colors = self.resolve_colors();
+        let badge = Badge {
+            count: self.count,
+            max: self.max,
+            corner: self.corner,
+            show_zero: self.show_zero,
+        };
+        let label = badge.label();
+        let visibility = if badge.is_visible() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+
+        // Start at the size's best guess; anchor_badges corrects it once
+        // the badge has been laid out
+        let mut node = self.base.node;
+        let half = match node.height {
+            Val::Px(height) => -height / 2.0,
+            _ => 0.0,
+        };
+        let (top, right, bottom, left) = corner_insets(self.corner, Val::Px(half), Val::Px(half));
+        node.top = top;
+        node.right = right;
+        node.bottom = bottom;
+        node.left = left;
+
+        let mut entity = match self.base.entity {
+            Some(id) => commands.entity(id),
+            None => commands.spawn_empty(),
+        };
+        entity.insert((
+            node,
+            BackgroundColor(colors.background),
+            BorderRadius::all(Val::Px(dimensions::BORDER_RADIUS_ROUND)),
+            badge,
+            visibility,
+            ZIndex(1),
+            Pickable::IGNORE,
+            BadgeOf(target),
+            ChildOf(target),
+        ));
+
+        if let Some(label) = label {
+            entity.with_children(|badge| {
+                badge.spawn((
+                    Text::new(label),
+                    TextFont {
+                        font_size: COUNT_FONT_SIZE,
+                        ..default()
+                    },
+                    TextColor(colors.text),
+                    Pickable::IGNORE,
+                    BadgeText,
+                ));
+            });
+        }
+
+        let id = entity.id();
+        for hook in self.base.hooks {
+            hook(&mut commands.entity(id));
+        }
+
+        id
+    }
+
+    /// Build the badge on `parent` (proxy to UiBuilder::build)
+    pub fn build(self, parent: &mut ChildSpawnerCommands) -> Entity {
+        UiBuilder::build(self, parent)
+    }
+}
+
+impl UiBuilder for BadgeBuilder {
+    /// Attach the badge to the entity whose children are being spawned
+    fn build(self, parent: &mut ChildSpawnerCommands) -> Entity {
+        let target = parent.target_entity();
+        self.attach_to(&mut parent.commands(), target)
+    }
+
+    fn insert(mut self, bundle: impl Bundle + Clone) -> Self {
+        self.base.hooks.push(Box::new(move |cmds| {
+            cmds.insert(bundle.clone());
+        }));
+        self
+    }
+
+    fn id(mut self, id: Entity) -> Self {
+        self.base.entity = Some(id);
+        self
+    }
+}
+
+impl LayoutBuilder for BadgeBuilder {
+    fn node(mut self, node: Node) -> Self {
+        self.base.node = node;
+        self
+    }
+
+    fn margin(mut self, margin: UiRect) -> Self {
+        self.base.node.margin = margin;
+        self
+    }
+
+    fn padding(mut self, padding: UiRect) -> Self {
+        self.base.node.padding = padding;
+        self
+    }
+
+    fn width(mut self, width: Val) -> Self {
+        self.base.node.width = width;
+        self
+    }
+
+    fn height(mut self, height: Val) -> Self {
+        self.base.node.height = height;
+        self
+    }
+}
+
+/// Position insets (top, right, bottom, left) that put a badge's corner
+/// offset at `corner`
+pub(crate) fn corner_insets(corner: BadgeCorner, x: Val, y: Val) -> (Val, Val, Val, Val) {
+    match corner {
+        BadgeCorner::TopRight => (y, x, Val::Auto, Val::Auto),
+        BadgeCorner::TopLeft => (y, Val::Auto, Val::Auto, x),
+        BadgeCorner::BottomRight => (Val::Auto, x, y, Val::Auto),
+        BadgeCorner::BottomLeft => (Val::Auto, Val::Auto, y, x),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn variant_and_theme_resolve_in_any_order() {
+        let theme = UiTheme::default().with_success(Color::srgb(0.1, 0.8, 0.3));
+        let expected = BadgeColors::from_theme(&theme, SemanticVariant::Success).background;
+
+        let variant_first = BadgeBuilder::count(1)
+            .variant(SemanticVariant::Success)
+            .themed(&theme)
+            .resolve_colors();
+        let theme_first = BadgeBuilder::count(1)
+            .themed(&theme)
+            .variant(SemanticVariant::Success)
+            .resolve_colors();
+
+        assert_eq!(variant_first.background, expected);
+        assert_eq!(theme_first.background, expected);
+    }
+}
@@ -0,0 +1,35 @@
+//! Badge component
+//!
+//! Provides count and dot badges that sit on the corner of any widget.
+//!
+//! # Features
+//!
+//! - Count badges that cap at a maximum ("99+")
+//! - Dot badges for "something new" without a number
+//! - Any corner of any UI entity - buttons, tabs, inventory slots
+//! - Hidden automatically when the count drops to zero
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use bevy_ui_builders::prelude::*;
+//!
+//! // Unread count on an inbox button
+//! BadgeBuilder::count(unread)
+//!     .attach_to(&mut commands, inbox_button);
+//!
+//! // Stack size on an inventory slot
+//! BadgeBuilder::count(stack)
+//!     .corner(BadgeCorner::BottomRight)
+//!     .variant(SemanticVariant::Secondary)
+//!     .attach_to(&mut commands, slot);
+//! ```
+
+mod builder;
+mod plugin;
+mod systems;
+mod types;
+
+pub use builder::BadgeBuilder;
+pub use plugin::BadgePlugin;
+pub use types::{Badge, BadgeCorner, BadgeText};
@@ -0,0 +1,12 @@
+//! Badge plugin
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+use super::systems::*;
+
+define_plugin!(BadgePlugin {
+    update: [
+        update_badges,
+        anchor_badges,
+    ]
+});
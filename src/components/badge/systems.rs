@@ -0,0 +1,61 @@
+//! Badge systems
+
+use bevy::prelude::*;
+use super::builder::corner_insets;
+use super::types::*;
+
+/// Refresh badge text and visibility when a badge's count changes
+pub fn update_badges(
+    mut badges: Query<(&Badge, &mut Visibility, Option<&Children>), Changed<Badge>>,
+    mut texts: Query<&mut Text, With<BadgeText>>,
+) {
+    for (badge, mut visibility, children) in badges.iter_mut() {
+        let target = if badge.is_visible() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        visibility.set_if_neq(target);
+
+        let Some(label) = badge.label() else {
+            continue;
+        };
+        for child in children.into_iter().flatten() {
+            if let Ok(mut text) = texts.get_mut(*child) {
+                if text.0 != label {
+                    text.0 = label.clone();
+                }
+            }
+        }
+    }
+}
+
+/// Keep each badge centered on its target's corner as its size changes
+///
+/// A count badge grows with its number, so the offset is recomputed from the
+/// laid-out size rather than fixed at spawn time.
+pub fn anchor_badges(
+    mut badges: Query<
+        (&Badge, &ComputedNode, &mut Node),
+        Or<(Changed<ComputedNode>, Changed<Badge>)>,
+    >,
+) {
+    for (badge, computed, mut node) in badges.iter_mut() {
+        let size = computed.size() * computed.inverse_scale_factor();
+        if size == Vec2::ZERO {
+            continue;
+        }
+
+        let (top, right, bottom, left) = corner_insets(
+            badge.corner,
+            Val::Px(-size.x / 2.0),
+            Val::Px(-size.y / 2.0),
+        );
+        if node.top != top || node.right != right || node.bottom != bottom || node.left != left {
+            node.top = top;
+            node.right = right;
+            node.bottom = bottom;
+            node.left = left;
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! Badge types and components
+
+use bevy::prelude::*;
+
+/// Diameter of a dot badge
+pub(crate) const DOT_SIZE: f32 = 10.0;
+
+/// Height (and minimum width) of a count badge
+pub(crate) const COUNT_HEIGHT: f32 = 18.0;
+
+/// Corner of the target widget a badge is centered on
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BadgeCorner {
+    /// Top-right corner (the usual spot for notification counts)
+    #[default]
+    TopRight,
+    /// Top-left corner
+    TopLeft,
+    /// Bottom-right corner (stack sizes on inventory slots)
+    BottomRight,
+    /// Bottom-left corner
+    BottomLeft,
+}
+
+/// A small count or dot badge attached to another widget
+///
+/// The badge is a child of its target with a [`BadgeOf`](crate::relationships::BadgeOf)
+/// relationship, and is kept centered on the target's [`BadgeCorner`]. Change
+/// `count` to update the number shown.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct Badge {
+    /// Number shown, or `None` for a plain dot
+    pub count: Option<u32>,
+    /// Largest number shown before switching to "max+"
+    pub max: u32,
+    /// Corner of the target the badge sits on
+    pub corner: BadgeCorner,
+    /// Keep the badge visible when `count` is zero
+    pub show_zero: bool,
+}
+
+impl Badge {
+    /// Text shown in the badge, or `None` for a dot
+    pub fn label(&self) -> Option<String> {
+        self.count.map(|count| {
+            if count > self.max {
+                format!("{}+", self.max)
+            } else {
+                count.to_string()
+            }
+        })
+    }
+
+    /// Whether the badge should currently be shown
+    pub fn is_visible(&self) -> bool {
+        self.show_zero || self.count != Some(0)
+    }
+}
+
+/// Marker for the text inside a count badge
+#[derive(Component)]
+pub struct BadgeText;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn badge(count: Option<u32>) -> Badge {
+        Badge {
+            count,
+            max: 99,
+            corner: BadgeCorner::TopRight,
+            show_zero: false,
+        }
+    }
+
+    #[test]
+    fn label_caps_at_max() {
+        assert_eq!(badge(Some(7)).label().as_deref(), Some("7"));
+        assert_eq!(badge(Some(99)).label().as_deref(), Some("99"));
+        assert_eq!(badge(Some(150)).label().as_deref(), Some("99+"));
+        assert_eq!(badge(None).label(), None);
+    }
+
+    #[test]
+    fn zero_count_hides_unless_requested() {
+        assert!(!badge(Some(0)).is_visible());
+        assert!(badge(None).is_visible());
+        assert!(Badge { show_zero: true, ..badge(Some(0)) }.is_visible());
+    }
+}
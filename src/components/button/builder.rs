@@ -1,12 +1,14 @@
 //! ButtonBuilder implementation
 
 use bevy::prelude::*;
+use std::time::Duration;
 use crate::animation::{AnimationCategory, DisableAutoAnimation};
 use crate::styles::{dimensions, ButtonStyle, ButtonSize};
 use crate::theme::UiTheme;
-use crate::traits::{InteractiveConfig, UiBuilder, LayoutBuilder, BuilderBase};
-use crate::relationships::{InButtonGroup, ButtonGroupMembers};
-use super::types::{StyledButton, StateColorSet, SelectableButton, Selected, Active, ButtonSelectionColors, ButtonStateColors};
+use crate::traits::{Disabled, InteractiveConfig, UiBuilder, LayoutBuilder, BuilderBase};
+use crate::relationships::InButtonGroup;
+use crate::systems::mnemonic::{parse_mnemonic, with_mnemonic_spans, Mnemonic};
+use super::types::{StyledButton, StateColorSet, SelectableButton, Selected, Active, ButtonSelectionColors, ButtonStateColors, LinkButton, ButtonCooldown, ButtonCooldownOverlay};
 
 /// Resolved button colors from theme
 #[derive(Clone)]
@@ -29,6 +31,8 @@ pub struct ButtonBuilder {
     is_active: bool,
     button_group: Option<Entity>,
     custom_selection_colors: Option<(StateColorSet, StateColorSet)>, // (selected, active)
+    cooldown: Option<Duration>,
+    mnemonic: bool,
     // Theme-resolved colors (set via .themed())
     themed_colors: Option<ResolvedButtonColors>,
     base: BuilderBase,
@@ -50,6 +54,8 @@ impl ButtonBuilder {
             is_active: false,
             button_group: None,
             custom_selection_colors: None,
+            cooldown: None,
+            mnemonic: false,
             themed_colors: None,
             base: BuilderBase::new(),
             interactive: InteractiveConfig::new(),
@@ -117,6 +123,9 @@ impl ButtonBuilder {
     }
 
     /// Set whether the button is enabled
+    ///
+    /// A disabled button is marked [`Disabled`] and drawn faded out. Remove
+    /// the marker at runtime to fade it back in.
     pub fn enabled(mut self, enabled: bool) -> Self {
         self.interactive.disabled = !enabled;
         self
@@ -170,6 +179,41 @@ impl ButtonBuilder {
         self
     }
 
+    /// Give the button a cooldown
+    ///
+    /// Send a [`TriggerCooldown`](super::TriggerCooldown) for the button (for
+    /// example when its ability is used) to start it: the button is disabled,
+    /// a dark overlay sweeps away as time passes, and the remaining seconds
+    /// are shown on top until it is ready again.
+    ///
+    /// # Example
+    /// ```ignore
+    /// ButtonBuilder::new("Fireball")
+    ///     .cooldown(Duration::from_secs(5))
+    ///     .build(parent);
+    /// ```
+    pub fn cooldown(mut self, duration: Duration) -> Self {
+        self.cooldown = Some(duration);
+        self
+    }
+
+    /// Read an access key from the button text
+    ///
+    /// An `&` before a letter or digit makes it the button's access key:
+    /// `"&Save"` underlines the S and Alt+S presses the button. Use `&&` for
+    /// a literal ampersand. Without this, `&` in button text is shown as-is.
+    ///
+    /// # Example
+    /// ```ignore
+    /// ButtonBuilder::new("&Save")
+    ///     .mnemonic_label()
+    ///     .build(parent);
+    /// ```
+    pub fn mnemonic_label(mut self) -> Self {
+        self.mnemonic = true;
+        self
+    }
+
     /// Build the button entity (proxy to UiBuilder::build)
     pub fn build(self, parent: &mut ChildSpawnerCommands) -> Entity {
         UiBuilder::build(self, parent)
@@ -181,13 +225,20 @@ impl UiBuilder for ButtonBuilder {
         // Resolve colors (themed > default)
         let colors = self.resolve_colors();
         let (padding, font_size, height) = self.size.dimensions();
+        // Links are bare text: no box, padding or fixed height
+        let is_link = self.style == ButtonStyle::Link;
 
         let button_width = self.base.node.width; // Default is Auto
-        let button_height = if matches!(self.base.node.height, Val::Auto) {
+        let button_height = if matches!(self.base.node.height, Val::Auto) && !is_link {
             Val::Px(height)
         } else {
             self.base.node.height
         };
+        let (border, padding) = if is_link {
+            (UiRect::ZERO, UiRect::ZERO)
+        } else {
+            (UiRect::all(Val::Px(dimensions::BORDER_WIDTH_MEDIUM)), padding)
+        };
 
         let mut button = parent.spawn((
             Button,
@@ -197,7 +248,7 @@ impl UiBuilder for ButtonBuilder {
                 margin: self.base.node.margin,
                 justify_content: JustifyContent::Center,
                 align_items: AlignItems::Center,
-                border: UiRect::all(Val::Px(dimensions::BORDER_WIDTH_MEDIUM)),
+                border,
                 padding,
                 ..default()
             },
@@ -209,6 +260,11 @@ impl UiBuilder for ButtonBuilder {
             AnimationCategory::Button, // For auto-animation
         ));
 
+        // Links signal hover with an underline instead of scaling
+        if is_link {
+            button.insert((LinkButton, DisableAutoAnimation));
+        }
+
         // Handle animation configuration
         if self.interactive.disable_animation {
             button.insert(DisableAutoAnimation);
@@ -216,6 +272,9 @@ impl UiBuilder for ButtonBuilder {
         if let Some(custom_anim) = self.interactive.build_animation() {
             button.insert(custom_anim);
         }
+        if self.interactive.disabled {
+            button.insert(Disabled);
+        }
 
         let button_entity = button.id();
 
@@ -245,6 +304,23 @@ impl UiBuilder for ButtonBuilder {
             None
         };
 
+        // With `mnemonic_label`, "&Save" draws "Save" with an underlined S
+        // and binds Alt+S
+        let (text_before, mnemonic_spans) = if self.mnemonic {
+            let mnemonic = parse_mnemonic(&self.text);
+            if let Some(key) = mnemonic.key() {
+                button.insert(Mnemonic(key));
+            }
+            mnemonic.spans()
+        } else {
+            (self.text.clone(), None)
+        };
+        let text_font = TextFont {
+            font_size,
+            ..default()
+        };
+
+        let mut cooldown = None;
         button.with_children(|button| {
             if let Some(icon) = self.icon {
                 // Icon + Text layout
@@ -270,29 +346,87 @@ impl UiBuilder for ButtonBuilder {
                     ));
 
                     // Text
-                    container.spawn((
-                        Text::new(&self.text),
-                        TextFont {
-                            font_size,
-                            ..default()
-                        },
+                    let mut text = container.spawn((
+                        Text::new(text_before),
+                        text_font.clone(),
                         TextColor(colors.text),
                         Pickable::IGNORE, // Don't block button interaction
                     ));
+                    with_mnemonic_spans(&mut text, mnemonic_spans, &text_font, TextColor(colors.text));
                 });
             } else {
                 // Just text
-                button.spawn((
-                    Text::new(&self.text),
-                    TextFont {
-                        font_size,
-                        ..default()
-                    },
+                let mut text = button.spawn((
+                    Text::new(text_before),
+                    text_font.clone(),
                     TextColor(colors.text),
                     Pickable::IGNORE, // Don't block button interaction
                 ));
+                with_mnemonic_spans(&mut text, mnemonic_spans, &text_font, TextColor(colors.text));
+            }
+
+            // Cooldown overlay, hidden until a cooldown is triggered
+            if let Some(duration) = self.cooldown {
+                let mut sweep = Entity::PLACEHOLDER;
+                let mut label = Entity::PLACEHOLDER;
+                let overlay = button
+                    .spawn((
+                        Node {
+                            display: Display::None,
+                            position_type: PositionType::Absolute,
+                            width: Val::Percent(100.0),
+                            height: Val::Percent(100.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        Pickable::IGNORE,
+                    ))
+                    .with_children(|overlay| {
+                        sweep = overlay
+                            .spawn((
+                                Node {
+                                    position_type: PositionType::Absolute,
+                                    left: Val::Px(0.0),
+                                    right: Val::Px(0.0),
+                                    bottom: Val::Px(0.0),
+                                    height: Val::Percent(100.0),
+                                    ..default()
+                                },
+                                BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+                                BorderRadius::all(Val::Px(dimensions::BORDER_RADIUS_MEDIUM)),
+                                Pickable::IGNORE,
+                                ButtonCooldownOverlay,
+                            ))
+                            .id();
+                        label = overlay
+                            .spawn((
+                                Text::new(""),
+                                TextFont {
+                                    font_size,
+                                    ..default()
+                                },
+                                TextColor(Color::WHITE),
+                                Pickable::IGNORE,
+                            ))
+                            .id();
+                    })
+                    .id();
+
+                cooldown = Some(ButtonCooldown {
+                    duration,
+                    timer: None,
+                    disabled_button: false,
+                    overlay,
+                    sweep,
+                    label,
+                });
             }
         });
+
+        if let Some(cooldown) = cooldown {
+            parent.commands().entity(button_entity).insert(cooldown);
+        }
         
         // Apply hooks
         for hook in self.base.hooks {
@@ -333,14 +467,8 @@ impl UiBuilder for ButtonBuilder {
 
             // Handle button group membership
             if let Some(group_entity) = button_group {
+                // The relationship adds this button to the group's members
                 entity_cmds.insert(InButtonGroup(group_entity));
-
-                // Add this button to the group's members
-                let entity_to_add = button_entity;
-                cmds.entity(group_entity).entry::<ButtonGroupMembers>()
-                    .and_modify(move |mut members| {
-                        members.push(entity_to_add);
-                    });
             }
         }
 
@@ -417,5 +545,10 @@ pub fn ghost_button(text: impl Into<String>) -> ButtonBuilder {
     ButtonBuilder::new(text).style(ButtonStyle::Ghost)
 }
 
+/// Convenience function for creating a link-style button
+pub fn link_button(text: impl Into<String>) -> ButtonBuilder {
+    ButtonBuilder::new(text).style(ButtonStyle::Link)
+}
+
 // Implement InteractiveBuilder trait for ButtonBuilder
 crate::impl_interactive_builder!(ButtonBuilder);
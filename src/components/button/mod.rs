@@ -15,19 +15,26 @@ pub use builder::{
     success_button,
     danger_button,
     ghost_button,
+    link_button,
 };
 
 pub use plugin::ButtonPlugin;
 
 pub use types::{
     StyledButton,
+    LinkButton,
     SelectableButton,
     Selected,
     Active,
     ButtonSelectionColors,
     StateColorSet,
     SelectionChanged,
+    SegmentedButtonGroup,
+    ButtonCooldown,
+    ButtonCooldownOverlay,
+    TriggerCooldown,
 };
+pub(crate) use types::ButtonStateColors;
 
 // Re-export styles for convenience
 pub use crate::styles::{ButtonStyle, ButtonSize};
\ No newline at end of file
@@ -9,14 +9,19 @@ use super::systems::{
     enforce_exclusive_button_groups,
     update_selection_appearance,
     apply_selection_colors_immediately,
+    style_segmented_button_groups,
+    update_link_underlines,
+    start_button_cooldowns,
+    tick_button_cooldowns,
 };
-use super::types::SelectionChanged;
+use super::types::{SelectionChanged, TriggerCooldown};
 
 // Plugin that adds button interaction systems
 define_plugin!(ButtonPlugin {
     custom_init: |app: &mut App| {
         // Register selection changed message
-        app.add_message::<SelectionChanged>();
+        app.add_message::<SelectionChanged>()
+           .add_message::<TriggerCooldown>();
     },
     update: [
         // Selection state management - CHAINED to ensure commands are applied!
@@ -31,5 +36,14 @@ define_plugin!(ButtonPlugin {
 
         // Animation and interaction (UiAnimation handles hover/press effects)
         (handle_button_interaction, animate_button_transitions).chain(),
+
+        // Segmented control joining for marked button groups
+        style_segmented_button_groups,
+
+        // Hover underline for link-style buttons
+        update_link_underlines,
+
+        // Cooldown overlays
+        (start_button_cooldowns, tick_button_cooldowns).chain(),
     ]
 });
\ No newline at end of file
@@ -1,8 +1,12 @@
 //! Button interaction systems
 
 use bevy::prelude::*;
-use super::types::{StyledButton, ButtonStateColors, ButtonAnimationState, SelectableButton, Selected, Active, ButtonSelectionColors, SelectionChanged};
+use bevy::text::Underline;
+use super::types::{StyledButton, LinkButton, ButtonStateColors, ButtonAnimationState, SelectableButton, Selected, Active, ButtonSelectionColors, SelectionChanged, SegmentedButtonGroup, ButtonCooldown, TriggerCooldown};
 use crate::relationships::{InButtonGroup, ButtonGroupMembers};
+use crate::styles::dimensions;
+use crate::theme::UiTheme;
+use crate::traits::Disabled;
 
 /// Comprehensive button interaction system that handles all hover/pressed states
 /// This system sets the target values for the animation system to interpolate
@@ -34,6 +38,8 @@ pub fn handle_button_interaction(
 }
 
 /// System to smoothly animate button transitions
+///
+/// With the theme's `reduce_motion` set, buttons jump straight to their targets.
 pub fn animate_button_transitions(
     mut query: Query<(
         &mut ButtonAnimationState,
@@ -43,21 +49,29 @@ pub fn animate_button_transitions(
         &mut BorderColor,
     ), With<StyledButton>>,
     time: Res<Time>,
+    theme: Option<Res<UiTheme>>,
 ) {
     let delta = time.delta_secs();
+    let reduce_motion = theme.is_some_and(|theme| theme.reduce_motion);
 
     for (mut animation, mut transform, state_colors, mut bg_color, mut border_color) in &mut query {
+        let step = if reduce_motion {
+            1.0
+        } else {
+            animation.animation_speed * delta
+        };
+
         // Smoothly interpolate scale
         let scale_diff = animation.target_scale - animation.current_scale;
         if scale_diff.abs() > 0.001 {
-            animation.current_scale += scale_diff * animation.animation_speed * delta;
+            animation.current_scale += scale_diff * step;
             transform.scale = Vec3::splat(animation.current_scale);
         }
 
         // Smoothly interpolate color blend
         let color_diff = animation.target_color_blend - animation.current_color_blend;
         if color_diff.abs() > 0.001 {
-            animation.current_color_blend += color_diff * animation.animation_speed * delta;
+            animation.current_color_blend += color_diff * step;
 
             // Blend colors based on current blend value
             let t = animation.current_color_blend;
@@ -85,6 +99,102 @@ pub fn animate_button_transitions(
     }
 }
 
+/// Underline link button text while the link is hovered or pressed
+pub fn update_link_underlines(
+    mut commands: Commands,
+    links: Query<(Entity, &Interaction), (Changed<Interaction>, With<LinkButton>)>,
+    children: Query<&Children>,
+    texts: Query<(), With<Text>>,
+) {
+    for (link, interaction) in links.iter() {
+        let underline = *interaction != Interaction::None;
+
+        for text in children.iter_descendants(link).filter(|&entity| texts.contains(entity)) {
+            if underline {
+                commands.entity(text).insert(Underline);
+            } else {
+                commands.entity(text).remove::<Underline>();
+            }
+        }
+    }
+}
+
+/// Start cooldowns requested through [`TriggerCooldown`]
+///
+/// The button's `Interaction` is removed for the duration so it can't be
+/// hovered or clicked, and it is marked [`Disabled`].
+pub fn start_button_cooldowns(
+    mut commands: Commands,
+    mut triggers: MessageReader<TriggerCooldown>,
+    mut buttons: Query<(&mut ButtonCooldown, Has<Disabled>)>,
+    mut nodes: Query<&mut Node>,
+) {
+    for trigger in triggers.read() {
+        let Ok((mut cooldown, disabled)) = buttons.get_mut(trigger.entity) else {
+            continue;
+        };
+
+        cooldown.timer = Some(Timer::new(cooldown.duration, TimerMode::Once));
+        if let Ok(mut overlay) = nodes.get_mut(cooldown.overlay) {
+            overlay.display = Display::Flex;
+        }
+
+        // Leave buttons the app disabled itself for the app to re-enable
+        if !disabled {
+            cooldown.disabled_button = true;
+            commands.entity(trigger.entity)
+                .remove::<Interaction>()
+                .insert(Disabled);
+        }
+    }
+}
+
+/// Sweep cooldown overlays and re-enable buttons once their cooldown elapses
+pub fn tick_button_cooldowns(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut buttons: Query<(Entity, &mut ButtonCooldown)>,
+    mut nodes: Query<&mut Node>,
+    mut texts: Query<&mut Text>,
+) {
+    for (entity, mut cooldown) in buttons.iter_mut() {
+        let Some(timer) = cooldown.timer.as_mut() else {
+            continue;
+        };
+        timer.tick(time.delta());
+
+        if timer.is_finished() {
+            cooldown.timer = None;
+            if let Ok(mut overlay) = nodes.get_mut(cooldown.overlay) {
+                overlay.display = Display::None;
+            }
+            if std::mem::take(&mut cooldown.disabled_button) {
+                commands.entity(entity)
+                    .insert(Interaction::default())
+                    .remove::<Disabled>();
+            }
+            continue;
+        }
+
+        if let Ok(mut sweep) = nodes.get_mut(cooldown.sweep) {
+            sweep.height = Val::Percent(cooldown.remaining_fraction() * 100.0);
+        }
+
+        // Whole seconds, with one decimal for the final second
+        let remaining = cooldown.remaining_secs();
+        let label = if remaining < 1.0 {
+            format!("{:.1}", remaining)
+        } else {
+            format!("{}", remaining.ceil() as u32)
+        };
+        if let Ok(mut text) = texts.get_mut(cooldown.label) {
+            if text.0 != label {
+                text.0 = label;
+            }
+        }
+    }
+}
+
 /// Helper function to lerp between two colors
 fn lerp_color(from: Color, to: Color, t: f32) -> Color {
     let from_linear = from.to_linear();
@@ -249,4 +359,103 @@ pub fn apply_selection_colors_immediately(
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// Join the members of segmented button groups into a single control
+///
+/// Inner corners are squared off and every segment after the first is pulled
+/// back by one border width so neighbours share a single border line.
+pub fn style_segmented_button_groups(
+    groups: Query<
+        (&SegmentedButtonGroup, &ButtonGroupMembers),
+        Or<(Changed<ButtonGroupMembers>, Changed<SegmentedButtonGroup>)>,
+    >,
+    mut buttons: Query<(&mut Node, &mut BorderRadius), With<StyledButton>>,
+) {
+    let radius = Val::Px(dimensions::BORDER_RADIUS_MEDIUM);
+    let overlap = Val::Px(-dimensions::BORDER_WIDTH_MEDIUM);
+
+    for (segmented, members) in &groups {
+        let segments: Vec<Entity> = members.iter()
+            .copied()
+            .filter(|&member| buttons.contains(member))
+            .collect();
+
+        let last = segments.len().saturating_sub(1);
+        for (index, &segment) in segments.iter().enumerate() {
+            let Ok((mut node, mut border_radius)) = buttons.get_mut(segment) else {
+                continue;
+            };
+
+            let first_corner = if index == 0 { radius } else { Val::ZERO };
+            let last_corner = if index == last { radius } else { Val::ZERO };
+            let shared_edge = if index == 0 { Val::ZERO } else { overlap };
+
+            if segmented.vertical {
+                *border_radius = BorderRadius::new(first_corner, first_corner, last_corner, last_corner);
+                node.margin.top = shared_edge;
+            } else {
+                *border_radius = BorderRadius::new(first_corner, last_corner, last_corner, first_corner);
+                node.margin.left = shared_edge;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::start_theme_transitions;
+
+    #[test]
+    fn hovering_after_a_theme_change_uses_the_new_palette() {
+        let dark = UiTheme::dark();
+        let light = UiTheme::light().with_reduce_motion(true);
+        let danger = &dark.colors.danger;
+
+        let mut app = App::new();
+        app.insert_resource(dark.clone())
+            .init_resource::<Time>()
+            .add_systems(Update, (
+                start_theme_transitions,
+                handle_button_interaction,
+                animate_button_transitions,
+            ).chain());
+
+        let button = app.world_mut().spawn((
+            StyledButton,
+            Interaction::None,
+            ButtonAnimationState {
+                current_scale: 1.0,
+                target_scale: 1.0,
+                current_color_blend: 0.0,
+                target_color_blend: 0.0,
+                animation_speed: 10.0,
+            },
+            ButtonStateColors {
+                normal_bg: danger.base,
+                hover_bg: danger.hover,
+                pressed_bg: danger.pressed,
+                normal_border: danger.base,
+                hover_border: danger.hover,
+                pressed_border: danger.pressed,
+            },
+            BackgroundColor(danger.base),
+            BorderColor::all(danger.base),
+            Transform::default(),
+        )).id();
+        app.update();
+
+        *app.world_mut().resource_mut::<UiTheme>() = light.clone();
+        app.update();
+
+        *app.world_mut().get_mut::<Interaction>(button).unwrap() = Interaction::Hovered;
+        app.update();
+
+        let background = app.world().get::<BackgroundColor>(button).unwrap().0.to_linear();
+        let expected = light.colors.danger.hover.to_linear();
+        assert!((background.red - expected.red).abs() < 1e-4);
+        assert!((background.green - expected.green).abs() < 1e-4);
+        assert!((background.blue - expected.blue).abs() < 1e-4);
+    }
+}
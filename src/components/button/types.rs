@@ -1,11 +1,17 @@
 //! Button component types and markers
 
 use bevy::prelude::*;
+use crate::theme::ThemeColors;
 
 /// Marker component for styled buttons
 #[derive(Component)]
 pub struct StyledButton;
 
+/// Marker for [`ButtonStyle::Link`](crate::styles::ButtonStyle::Link) buttons,
+/// whose text is underlined while hovered or pressed
+#[derive(Component)]
+pub struct LinkButton;
+
 /// Component storing button-specific state colors
 /// This is kept for compatibility with existing button code
 /// but new code should use HoverColors from systems::hover
@@ -19,6 +25,19 @@ pub struct ButtonStateColors {
     pub pressed_border: Color,
 }
 
+impl ButtonStateColors {
+    /// Move every color that plays a role in palette `from` to the same role
+    /// in `to` (see [`ThemeColors::remap`])
+    pub(crate) fn remap(&mut self, from: &ThemeColors, to: &ThemeColors) {
+        for color in [
+            &mut self.normal_bg, &mut self.hover_bg, &mut self.pressed_bg,
+            &mut self.normal_border, &mut self.hover_border, &mut self.pressed_border,
+        ] {
+            remap_color(color, from, to);
+        }
+    }
+}
+
 /// Component for button-specific animation state
 /// This is kept for compatibility with existing button code
 /// but new code should use HoverAnimationState from systems::hover
@@ -80,6 +99,21 @@ pub struct ButtonSelectionColors {
     pub active: StateColorSet,
 }
 
+impl ButtonSelectionColors {
+    /// Move every color that plays a role in palette `from` to the same role
+    /// in `to` (see [`ThemeColors::remap`])
+    pub(crate) fn remap(&mut self, from: &ThemeColors, to: &ThemeColors) {
+        for set in [&mut self.normal, &mut self.selected, &mut self.active] {
+            for color in [
+                &mut set.normal_bg, &mut set.hover_bg, &mut set.pressed_bg,
+                &mut set.normal_border, &mut set.hover_border, &mut set.pressed_border,
+            ] {
+                remap_color(color, from, to);
+            }
+        }
+    }
+}
+
 /// Color set for a specific state (normal/selected/active).
 /// Contains colors for all interaction states (normal/hover/pressed).
 #[derive(Clone, Debug)]
@@ -135,10 +169,89 @@ pub struct SelectionChanged {
     pub selected: bool,
 }
 
+/// Marks a button group entity whose members render as one segmented control.
+///
+/// Members are joined into a single strip: neighbouring buttons share a
+/// border and only the outer corners of the strip stay rounded. Lay the
+/// members out next to each other with no gap between them.
+///
+/// # Example
+/// ```ignore
+/// let group = commands.spawn((ButtonGroupMembers::default(), SegmentedButtonGroup::default())).id();
+/// ```
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct SegmentedButtonGroup {
+    /// Segments are stacked top-to-bottom instead of left-to-right
+    pub vertical: bool,
+}
+
+// ============================================================================
+// Cooldown Components
+// ============================================================================
+
+/// Cooldown configuration and state for a button built with
+/// [`ButtonBuilder::cooldown`](super::ButtonBuilder::cooldown).
+///
+/// While cooling down the button is [`Disabled`](crate::traits::Disabled),
+/// ignores clicks, and shows a shrinking overlay with the remaining seconds.
+/// A button the app had already disabled stays disabled afterwards.
+#[derive(Component, Debug, Clone)]
+pub struct ButtonCooldown {
+    /// How long the button stays disabled after a [`TriggerCooldown`]
+    pub duration: std::time::Duration,
+    pub(crate) timer: Option<Timer>,
+    /// Whether the running cooldown disabled the button (and so re-enables it)
+    pub(crate) disabled_button: bool,
+    /// Full-size container shown only while cooling down
+    pub(crate) overlay: Entity,
+    /// Dark fill that shrinks as the cooldown elapses
+    pub(crate) sweep: Entity,
+    /// Remaining-seconds text
+    pub(crate) label: Entity,
+}
+
+impl ButtonCooldown {
+    /// Whether the cooldown is currently running
+    pub fn is_active(&self) -> bool {
+        self.timer.is_some()
+    }
+
+    /// Seconds left before the button is usable again (0.0 when ready)
+    pub fn remaining_secs(&self) -> f32 {
+        self.timer.as_ref().map_or(0.0, |timer| timer.remaining_secs())
+    }
+
+    /// Fraction of the cooldown still left (1.0 just triggered, 0.0 when ready)
+    pub fn remaining_fraction(&self) -> f32 {
+        self.timer.as_ref().map_or(0.0, |timer| 1.0 - timer.fraction())
+    }
+}
+
+/// Marker for the dark fill swept over a cooling-down button
+#[derive(Component)]
+pub struct ButtonCooldownOverlay;
+
+/// Message to start a button's cooldown
+///
+/// Has no effect on buttons without [`ButtonCooldown`]. Triggering a button
+/// that is already cooling down restarts its cooldown.
+#[derive(Message, Clone, Debug)]
+pub struct TriggerCooldown {
+    /// The button entity
+    pub entity: Entity,
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
+/// Replace `color` with its role in palette `to`, if it has one in `from`
+fn remap_color(color: &mut Color, from: &ThemeColors, to: &ThemeColors) {
+    if let Some(remapped) = from.remap(to, *color) {
+        *color = remapped;
+    }
+}
+
 /// Adjust brightness of a color by a multiplier
 fn adjust_brightness(color: Color, multiplier: f32) -> Color {
     let linear = color.to_linear();
@@ -3,8 +3,9 @@
 use bevy::prelude::*;
 use super::types::*;
 use crate::animation::AnimationCategory;
+use crate::relationships::InButtonGroup;
 use crate::styles::dimensions;
-use crate::theme::UiTheme;
+use crate::theme::{FontRole, ThemeFonts, UiTheme};
 use crate::traits::{UiBuilder, LayoutBuilder, BuilderBase};
 
 /// Resolved checkbox colors
@@ -14,6 +15,7 @@ struct ResolvedCheckboxColors {
     unchecked: Color,
     border: Color,
     label: Color,
+    description: Color,
 }
 
 /// Builder for creating styled checkboxes
@@ -35,9 +37,14 @@ pub struct CheckboxBuilder {
     checked: bool,
     style: CheckboxStyle,
     label: Option<String>,
+    description: Option<String>,
     size: f32,
+    group: Option<Entity>,
+    radio: bool,
+    icon: CheckIcon,
     // Theme-resolved values (set via .themed())
     themed_colors: Option<ResolvedCheckboxColors>,
+    themed_fonts: Option<ThemeFonts>,
     base: BuilderBase,
 }
 
@@ -54,8 +61,13 @@ impl CheckboxBuilder {
             checked: false,
             style: CheckboxStyle::Primary,
             label: None,
+            description: None,
             size: 20.0,
+            group: None,
+            radio: false,
+            icon: CheckIcon::default(),
             themed_colors: None,
+            themed_fonts: None,
             base,
         }
     }
@@ -82,7 +94,9 @@ impl CheckboxBuilder {
             unchecked: self.style.unchecked_color_from_theme(theme),
             border: self.style.border_color_from_theme(theme),
             label: self.style.label_color_from_theme(theme),
+            description: self.style.description_color_from_theme(theme),
         });
+        self.themed_fonts = Some(theme.typography.fonts.clone());
         self
     }
 
@@ -104,6 +118,27 @@ impl CheckboxBuilder {
         self
     }
 
+    /// Show a title with a dimmer description below it, settings-row style
+    ///
+    /// Clicking anywhere in the row (box, title or description) toggles the
+    /// checkbox.
+    ///
+    /// # Example
+    /// ```ignore
+    /// CheckboxBuilder::new()
+    ///     .with_description("Auto-save", "Save your progress every five minutes")
+    ///     .build(parent);
+    /// ```
+    pub fn with_description(
+        mut self,
+        title: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        self.label = Some(title.into());
+        self.description = Some(description.into());
+        self
+    }
+
     /// Set whether the label appears on the right (default) or left of the checkbox
     pub fn label_on_right(mut self, on_right: bool) -> Self {
         self.base.node.flex_direction = if on_right {
@@ -120,6 +155,52 @@ impl CheckboxBuilder {
         self
     }
 
+    /// Show an image instead of the "X" when checked
+    ///
+    /// The image is scaled to fit the box, so a star makes a "favorite"
+    /// toggle and a padlock a "locked" one.
+    ///
+    /// # Example
+    /// ```ignore
+    /// CheckboxBuilder::new()
+    ///     .check_icon(asset_server.load("icons/star.png"))
+    ///     .with_label("Favorite")
+    ///     .build(parent);
+    /// ```
+    pub fn check_icon(mut self, icon: Handle<Image>) -> Self {
+        self.icon = CheckIcon::Image(icon);
+        self
+    }
+
+    /// Show a text glyph instead of the "X" when checked
+    ///
+    /// The glyph must exist in the text font; Bevy's default font only
+    /// covers ASCII.
+    pub fn check_glyph(mut self, glyph: impl Into<String>) -> Self {
+        self.icon = CheckIcon::Glyph(glyph.into());
+        self
+    }
+
+    /// Add this checkbox to a group for keyboard navigation.
+    ///
+    /// Grouped checkboxes share the button group relationship: once one has
+    /// focus (by clicking it, or Tab while nothing else has focus), the arrow
+    /// keys move between group members and Space toggles the focused one.
+    pub fn in_group(mut self, group_entity: Entity) -> Self {
+        self.group = Some(group_entity);
+        self
+    }
+
+    /// Make this checkbox a radio option within a group.
+    ///
+    /// Only one radio option in a group can be checked at a time, and arrow
+    /// keys move the selection as well as focus.
+    pub fn radio(mut self, group_entity: Entity) -> Self {
+        self.group = Some(group_entity);
+        self.radio = true;
+        self
+    }
+
     /// Build the checkbox entity (proxy to UiBuilder::build)
     pub fn build(self, parent: &mut ChildSpawnerCommands) -> Entity {
         UiBuilder::build(self, parent)
@@ -132,6 +213,7 @@ impl CheckboxBuilder {
             unchecked: self.style.default_unchecked_color(),
             border: self.style.default_border_color(),
             label: self.style.default_label_color(),
+            description: self.style.default_description_color(),
         })
     }
 }
@@ -153,10 +235,19 @@ impl UiBuilder for CheckboxBuilder {
         let label = self.label.clone();
         let size = self.size;
         let style = self.style;
+        let group = self.group;
+        let radio = self.radio;
+        let icon = self.icon;
+        let description = self.description;
+        let has_description = description.is_some();
+        let title_font = self.themed_fonts
+            .map(|fonts| fonts.font_for(FontRole::Heading))
+            .unwrap_or_default();
+        let mut checkbox_entity = Entity::PLACEHOLDER;
 
         // Spawn the checkbox box itself
         container.with_children(|container| {
-            let _checkbox_entity = container.spawn((
+            checkbox_entity = container.spawn((
                 Node {
                     width: Val::Px(size),
                     height: Val::Px(size),
@@ -175,13 +266,14 @@ impl UiBuilder for CheckboxBuilder {
                 Checkbox,
                 state,
                 CheckboxStyleComponent(style),
+                CheckboxIcon(icon.clone()),
                 Interaction::default(),
                 Transform::default(),
                 AnimationCategory::Button,
             )).with_children(|checkbox_box| {
-                // Checkmark icon (ASCII X for maximum compatibility)
-                checkbox_box.spawn((
-                    Text::new("X"),
+                // Checkmark icon, shown while checked
+                let icon_size = icon.checkmark_size();
+                let mut checkmark = checkbox_box.spawn((
                     TextFont {
                         font_size: size * 0.6,
                         ..default()
@@ -193,14 +285,70 @@ impl UiBuilder for CheckboxBuilder {
                         } else {
                             Display::None
                         },
+                        width: icon_size,
+                        height: icon_size,
                         ..default()
                     },
                     CheckboxCheckmark,
                 ));
+                icon.apply_to(&mut checkmark);
+
+                // Focus ring, shown while the checkbox has keyboard focus
+                if group.is_some() {
+                    checkbox_box.spawn((
+                        Node {
+                            display: Display::None,
+                            position_type: PositionType::Absolute,
+                            left: Val::Px(-6.0),
+                            right: Val::Px(-6.0),
+                            top: Val::Px(-6.0),
+                            bottom: Val::Px(-6.0),
+                            border: UiRect::all(Val::Px(2.0)),
+                            ..default()
+                        },
+                        BorderColor::all(Color::NONE),
+                        BorderRadius::all(Val::Px(8.0)),
+                        Pickable::IGNORE,
+                        CheckboxFocusRing,
+                    ));
+                }
             }).id();
 
-            // Add label if provided
-            if let Some(label_text) = label {
+            // Group membership drives keyboard navigation (and radio exclusivity)
+            if let Some(group_entity) = group {
+                let mut checkbox = container.commands().entity(checkbox_entity);
+                checkbox.insert(InButtonGroup(group_entity));
+                if radio {
+                    checkbox.insert(RadioCheckbox);
+                }
+            }
+
+            // Title with a description below it, or a plain label
+            if let Some(description) = description {
+                container.spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(2.0),
+                    ..default()
+                }).with_children(|text| {
+                    text.spawn((
+                        Text::new(label.unwrap_or_default()),
+                        TextFont {
+                            font: title_font,
+                            font_size: dimensions::FONT_SIZE_LARGE,
+                            ..default()
+                        },
+                        TextColor(colors.label),
+                    ));
+                    text.spawn((
+                        Text::new(description),
+                        TextFont {
+                            font_size: dimensions::FONT_SIZE_SMALL,
+                            ..default()
+                        },
+                        TextColor(colors.description),
+                    ));
+                });
+            } else if let Some(label_text) = label {
                 container.spawn((
                     Text::new(label_text),
                     TextFont {
@@ -216,6 +364,14 @@ impl UiBuilder for CheckboxBuilder {
             }
         });
 
+        // Settings rows toggle from anywhere in the row
+        if has_description {
+            parent.commands().entity(container_entity).insert((
+                CheckboxRow { checkbox: checkbox_entity },
+                Interaction::default(),
+            ));
+        }
+
         // Apply hooks
         for hook in self.base.hooks {
             hook(&mut parent.commands().entity(container_entity));
@@ -8,4 +8,7 @@ mod types;
 // Public exports
 pub use builder::CheckboxBuilder;
 pub use plugin::CheckboxPlugin;
-pub use types::{Checkbox, CheckboxState, CheckboxStyle, CheckboxStyleComponent, CheckboxCheckmark};
+pub use types::{
+    Checkbox, CheckboxState, CheckboxStyle, CheckboxStyleComponent, CheckboxCheckmark,
+    CheckIcon, CheckboxIcon, RadioCheckbox, CheckboxFocused, CheckboxFocusRing, CheckboxRow,
+};
@@ -8,6 +8,9 @@ use super::systems::*;
 define_plugin!(CheckboxPlugin {
     update: [
         handle_checkbox_toggle,
+        handle_checkbox_row_clicks.after(handle_checkbox_toggle),
+        handle_checkbox_group_navigation,
         update_checkbox_visuals,
+        update_checkbox_icons,
     ]
 });
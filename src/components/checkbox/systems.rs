@@ -2,15 +2,166 @@
 
 use bevy::prelude::*;
 use super::types::*;
+use crate::relationships::{InButtonGroup, ButtonGroupMembers};
+use crate::theme::UiTheme;
 
 /// Handle checkbox clicks to toggle state
+///
+/// Clicking a grouped checkbox also gives it keyboard focus; clicking
+/// anywhere else clears checkbox focus.
+#[allow(clippy::too_many_arguments)]
 pub fn handle_checkbox_toggle(
-    mut checkboxes: Query<(&Interaction, &mut CheckboxState), (With<Checkbox>, Changed<Interaction>)>,
+    mut commands: Commands,
+    clicked: Query<(Entity, &Interaction, Option<&InButtonGroup>), (With<Checkbox>, Changed<Interaction>)>,
+    grouped: Query<&Interaction, (With<Checkbox>, With<InButtonGroup>)>,
+    focused: Query<Entity, With<CheckboxFocused>>,
+    radios: Query<(), With<RadioCheckbox>>,
+    groups: Query<&ButtonGroupMembers>,
+    mut states: Query<&mut CheckboxState>,
+    mut rings: FocusRings,
     mouse_button: Res<ButtonInput<MouseButton>>,
+    theme: Option<Res<UiTheme>>,
 ) {
-    for (interaction, mut state) in checkboxes.iter_mut() {
-        if *interaction == Interaction::Pressed && mouse_button.just_pressed(MouseButton::Left) {
-            state.toggle();
+    if !mouse_button.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    for (entity, interaction, in_group) in clicked.iter() {
+        if *interaction == Interaction::Pressed {
+            activate_checkbox(entity, in_group, &mut states, &radios, &groups);
+
+            if in_group.is_some() {
+                focus_checkbox(&mut commands, &mut rings, focused.iter(), entity, focus_color(theme.as_deref()));
+            }
+        }
+    }
+
+    // Click landed outside every grouped checkbox - drop keyboard focus
+    if !grouped.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        for entity in focused.iter() {
+            unfocus_checkbox(&mut commands, &mut rings, entity);
+        }
+    }
+}
+
+/// Toggle a row's checkbox when its title or description is clicked
+///
+/// Clicks on the box itself are left to `handle_checkbox_toggle`.
+pub fn handle_checkbox_row_clicks(
+    rows: Query<(&CheckboxRow, &Interaction), Changed<Interaction>>,
+    boxes: Query<(&Interaction, Option<&InButtonGroup>), With<Checkbox>>,
+    radios: Query<(), With<RadioCheckbox>>,
+    groups: Query<&ButtonGroupMembers>,
+    mut states: Query<&mut CheckboxState>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+) {
+    if !mouse_button.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    for (row, interaction) in rows.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok((box_interaction, in_group)) = boxes.get(row.checkbox) else {
+            continue;
+        };
+        if *box_interaction != Interaction::Pressed {
+            activate_checkbox(row.checkbox, in_group, &mut states, &radios, &groups);
+        }
+    }
+}
+
+/// Keyboard navigation within checkbox and radio groups
+///
+/// While a grouped checkbox has focus, the arrow keys move focus between the
+/// members of its group (wrapping at either end), Space toggles the focused
+/// checkbox, and Tab / Shift+Tab jump to the next / previous group. In radio
+/// groups, moving focus also moves the selection, matching native controls.
+///
+/// While nothing has focus (no grouped checkbox and no text input), Tab
+/// enters the first group and Shift+Tab the last one.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_checkbox_group_navigation(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    focused: Query<(Entity, &InButtonGroup), With<CheckboxFocused>>,
+    checkboxes: Query<&InButtonGroup, With<Checkbox>>,
+    groups: Query<&ButtonGroupMembers>,
+    radios: Query<(), With<RadioCheckbox>>,
+    mut states: Query<&mut CheckboxState>,
+    mut rings: FocusRings,
+    theme: Option<Res<UiTheme>>,
+    #[cfg(feature = "text_input")]
+    text_buffers: Query<&crate::components::text_input::TextBuffer>,
+) {
+    let shift = keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight);
+    let color = focus_color(theme.as_deref());
+
+    let Ok((current, in_group)) = focused.single() else {
+        #[cfg(feature = "text_input")]
+        if text_buffers.iter().any(|buffer| buffer.is_focused) {
+            return;
+        }
+
+        // Tab into the first (Shift+Tab: last) group, on its checked member
+        if keyboard.just_pressed(KeyCode::Tab) {
+            let group_entities = checkbox_groups(&checkboxes);
+            let entry = if shift { group_entities.last() } else { group_entities.first() };
+            if let Some(target) = entry.and_then(|&group| group_entry(group, &groups, &checkboxes, &states)) {
+                focus_checkbox(&mut commands, &mut rings, [], target, color);
+            }
+        }
+        return;
+    };
+    let Some(members) = group_checkboxes(in_group.0, &groups, &checkboxes) else {
+        return;
+    };
+    let Some(index) = members.iter().position(|&entity| entity == current) else {
+        return;
+    };
+
+    if keyboard.just_pressed(KeyCode::Space) {
+        activate_checkbox(current, Some(in_group), &mut states, &radios, &groups);
+        return;
+    }
+
+    let step = if keyboard.any_just_pressed([KeyCode::ArrowDown, KeyCode::ArrowRight]) {
+        Some(1)
+    } else if keyboard.any_just_pressed([KeyCode::ArrowUp, KeyCode::ArrowLeft]) {
+        Some(-1)
+    } else {
+        None
+    };
+
+    if let Some(step) = step {
+        let next_index = (index as isize + step).rem_euclid(members.len() as isize) as usize;
+        let next = members[next_index];
+        focus_checkbox(&mut commands, &mut rings, [current], next, color);
+
+        if radios.contains(next) {
+            activate_checkbox(next, Some(in_group), &mut states, &radios, &groups);
+        }
+        return;
+    }
+
+    if keyboard.just_pressed(KeyCode::Tab) {
+        let group_entities = checkbox_groups(&checkboxes);
+        if group_entities.len() < 2 {
+            return;
+        }
+
+        let group_index = group_entities.iter()
+            .position(|&group| group == in_group.0)
+            .unwrap_or(0);
+        let next_group = if shift {
+            group_entities[(group_index + group_entities.len() - 1) % group_entities.len()]
+        } else {
+            group_entities[(group_index + 1) % group_entities.len()]
+        };
+
+        if let Some(target) = group_entry(next_group, &groups, &checkboxes, &states) {
+            focus_checkbox(&mut commands, &mut rings, [current], target, color);
         }
     }
 }
@@ -40,3 +191,147 @@ pub fn update_checkbox_visuals(
         }
     }
 }
+
+/// Show each checkbox's `CheckboxIcon` in its checkmark when it's added or
+/// replaced
+pub fn update_checkbox_icons(
+    mut commands: Commands,
+    checkboxes: Query<(&CheckboxIcon, &Children), (With<Checkbox>, Changed<CheckboxIcon>)>,
+    mut checkmarks: Query<&mut Node, With<CheckboxCheckmark>>,
+) {
+    for (icon, children) in checkboxes.iter() {
+        for child in children.iter() {
+            let Ok(mut node) = checkmarks.get_mut(child) else {
+                continue;
+            };
+            let size = icon.0.checkmark_size();
+            node.width = size;
+            node.height = size;
+            icon.0.apply_to(&mut commands.entity(child));
+        }
+    }
+}
+
+/// Toggle a checkbox, or select it exclusively if it's a radio option
+fn activate_checkbox(
+    entity: Entity,
+    in_group: Option<&InButtonGroup>,
+    states: &mut Query<&mut CheckboxState>,
+    radios: &Query<(), With<RadioCheckbox>>,
+    groups: &Query<&ButtonGroupMembers>,
+) {
+    let radio_members = in_group
+        .filter(|_| radios.contains(entity))
+        .and_then(|group| groups.get(group.0).ok());
+
+    if let Some(members) = radio_members {
+        for &member in members.iter() {
+            if let Ok(mut state) = states.get_mut(member) {
+                // set_if_neq keeps untouched members from re-running visual updates
+                state.set_if_neq(if member == entity {
+                    CheckboxState::Checked
+                } else {
+                    CheckboxState::Unchecked
+                });
+            }
+        }
+    } else if let Ok(mut state) = states.get_mut(entity) {
+        state.toggle();
+    }
+}
+
+/// Focus rings of grouped checkboxes, found through the checkbox's children
+#[derive(bevy::ecs::system::SystemParam)]
+pub struct FocusRings<'w, 's> {
+    children: Query<'w, 's, &'static Children, With<Checkbox>>,
+    rings: Query<'w, 's, (&'static mut Node, &'static mut BorderColor), With<CheckboxFocusRing>>,
+}
+
+impl FocusRings<'_, '_> {
+    /// Show the ring around `checkbox` in `color`, or hide it with `None`
+    fn show(&mut self, checkbox: Entity, color: Option<Color>) {
+        let Ok(children) = self.children.get(checkbox) else {
+            return;
+        };
+        for child in children.iter() {
+            if let Ok((mut node, mut border)) = self.rings.get_mut(child) {
+                let display = if color.is_some() { Display::Flex } else { Display::None };
+                if node.display != display {
+                    node.display = display;
+                }
+                if let Some(color) = color {
+                    *border = BorderColor::all(color);
+                }
+            }
+        }
+    }
+}
+
+/// Move keyboard focus to `target`, showing its focus ring
+fn focus_checkbox(
+    commands: &mut Commands,
+    rings: &mut FocusRings,
+    previous: impl IntoIterator<Item = Entity>,
+    target: Entity,
+    color: Color,
+) {
+    for entity in previous {
+        if entity != target {
+            unfocus_checkbox(commands, rings, entity);
+        }
+    }
+
+    commands.entity(target).insert(CheckboxFocused);
+    rings.show(target, Some(color));
+}
+
+/// Take keyboard focus away from `entity`, hiding its focus ring
+fn unfocus_checkbox(commands: &mut Commands, rings: &mut FocusRings, entity: Entity) {
+    commands.entity(entity).remove::<CheckboxFocused>();
+    rings.show(entity, None);
+}
+
+/// Every group that contains at least one checkbox, in a stable order
+fn checkbox_groups(checkboxes: &Query<&InButtonGroup, With<Checkbox>>) -> Vec<Entity> {
+    let mut group_entities: Vec<Entity> = checkboxes.iter().map(|group| group.0).collect();
+    group_entities.sort();
+    group_entities.dedup();
+    group_entities
+}
+
+/// Where focus lands when entering a group: its checked member (radio
+/// convention), else the first one
+fn group_entry(
+    group: Entity,
+    groups: &Query<&ButtonGroupMembers>,
+    checkboxes: &Query<&InButtonGroup, With<Checkbox>>,
+    states: &Query<&mut CheckboxState>,
+) -> Option<Entity> {
+    let members = group_checkboxes(group, groups, checkboxes)?;
+    members.iter()
+        .copied()
+        .find(|&entity| states.get(entity).is_ok_and(|state| state.is_checked()))
+        .or_else(|| members.first().copied())
+}
+
+/// Checkbox members of a group, in the order they joined it
+fn group_checkboxes(
+    group: Entity,
+    groups: &Query<&ButtonGroupMembers>,
+    checkboxes: &Query<&InButtonGroup, With<Checkbox>>,
+) -> Option<Vec<Entity>> {
+    let members: Vec<Entity> = groups.get(group).ok()?
+        .iter()
+        .copied()
+        .filter(|&entity| checkboxes.contains(entity))
+        .collect();
+
+    (!members.is_empty()).then_some(members)
+}
+
+/// Focus ring color (theme focus border, or the dark theme default)
+fn focus_color(theme: Option<&UiTheme>) -> Color {
+    theme
+        .map(|theme| theme.colors.border.focus)
+        .unwrap_or(defaults::BORDER_FOCUS)
+}
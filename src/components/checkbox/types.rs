@@ -92,6 +92,11 @@ impl CheckboxStyle {
     pub fn label_color_from_theme(&self, theme: &UiTheme) -> Color {
         theme.colors.text.primary
     }
+
+    /// Get the description text color from theme
+    pub fn description_color_from_theme(&self, theme: &UiTheme) -> Color {
+        theme.colors.text.secondary
+    }
 }
 
 // Default colors (dark theme) for when no theme is provided
@@ -104,7 +109,9 @@ pub(crate) mod defaults {
     pub const DANGER: Color = Color::srgb(0.86, 0.25, 0.25);
     pub const BACKGROUND: Color = Color::srgb(0.08, 0.08, 0.1);
     pub const BORDER: Color = Color::srgb(0.3, 0.3, 0.3);
+    pub const BORDER_FOCUS: Color = Color::srgb(0.25, 0.46, 0.86);
     pub const TEXT_PRIMARY: Color = Color::srgb(0.95, 0.95, 0.95);
+    pub const TEXT_SECONDARY: Color = Color::srgb(0.7, 0.7, 0.7);
 }
 
 impl CheckboxStyle {
@@ -135,6 +142,11 @@ impl CheckboxStyle {
         defaults::TEXT_PRIMARY
     }
 
+    /// Get the default description text color (no theme)
+    pub(crate) fn default_description_color(&self) -> Color {
+        defaults::TEXT_SECONDARY
+    }
+
     /// Get the checked color (uses default, for backwards compatibility with systems)
     pub fn checked_color(&self) -> Color {
         self.default_checked_color()
@@ -158,3 +170,77 @@ pub struct CheckboxStyleComponent(pub CheckboxStyle);
 /// Marker component for the checkmark icon inside a checkbox
 #[derive(Component, Debug, Clone, Copy)]
 pub struct CheckboxCheckmark;
+
+/// What a checked checkbox shows in its box
+#[derive(Debug, Clone, PartialEq)]
+pub enum CheckIcon {
+    /// Text glyph, white on the checked color (must exist in the font)
+    Glyph(String),
+    /// Image scaled to fit the box, e.g. a star for a "favorite" toggle
+    Image(Handle<Image>),
+}
+
+impl Default for CheckIcon {
+    fn default() -> Self {
+        // ASCII X for maximum font compatibility
+        Self::Glyph("X".to_string())
+    }
+}
+
+impl CheckIcon {
+    /// Size of the checkmark node showing this icon
+    pub(crate) fn checkmark_size(&self) -> Val {
+        match self {
+            Self::Glyph(_) => Val::Auto,
+            Self::Image(_) => Val::Percent(70.0),
+        }
+    }
+
+    /// Make `checkmark` show this icon, replacing whichever it showed before
+    pub(crate) fn apply_to(&self, checkmark: &mut EntityCommands) {
+        match self {
+            Self::Glyph(glyph) => {
+                checkmark.remove::<ImageNode>().insert(Text::new(glyph.clone()));
+            }
+            Self::Image(image) => {
+                checkmark.remove::<Text>().insert(ImageNode::new(image.clone()));
+            }
+        }
+    }
+}
+
+/// The icon a checkbox shows when checked
+///
+/// Lives on the checkbox box; replacing it swaps the icon of a built
+/// checkbox.
+#[derive(Component, Debug, Clone, PartialEq, Default)]
+pub struct CheckboxIcon(pub CheckIcon);
+
+/// Makes a whole checkbox row clickable
+///
+/// Added to the container of checkboxes built with
+/// `CheckboxBuilder::with_description`, so clicking the title or
+/// description toggles the checkbox too.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct CheckboxRow {
+    /// The checkbox box this row toggles
+    pub checkbox: Entity,
+}
+
+/// Marks a grouped checkbox as a radio option.
+///
+/// Checking a radio option unchecks every other member of its group, and
+/// arrow-key navigation moves the selection along with focus.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct RadioCheckbox;
+
+/// Marker for the grouped checkbox that currently has keyboard focus
+#[derive(Component, Debug, Clone, Copy)]
+pub struct CheckboxFocused;
+
+/// Ring drawn around a grouped checkbox while it has keyboard focus
+///
+/// A child of the checkbox box, hidden until the box gains focus, so the
+/// box's own `Outline` is left to the app.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct CheckboxFocusRing;
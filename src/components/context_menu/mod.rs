@@ -8,6 +8,7 @@
 //! - Checkbox items
 //! - Separators
 //! - Nested submenus
+//! - Keyboard navigation: arrows move through items and submenus, Enter activates
 //! - Event-based action handling
 //!
 //! # Examples
@@ -15,6 +15,8 @@ define_plugin!(ContextMenuPlugin {
     update: [
         detect_context_menu_trigger,
         handle_menu_item_hover,
+        handle_menu_keyboard.after(handle_menu_item_hover),
+        update_submenus.after(handle_menu_keyboard),
         handle_menu_item_click,
         close_menu_on_outside_click,
         close_menu_on_escape,
@@ -1,9 +1,12 @@
 //! Context menu systems
 
 use bevy::prelude::*;
-use bevy::window::PrimaryWindow;
+use bevy::window::{CursorMoved, PrimaryWindow};
 use crate::styles::dimensions;
+use crate::systems::escape::{EscapeLayer, EscapeStack};
+use crate::systems::popup::{Popup, SubmenuHover};
 use crate::theme::UiTheme;
+use crate::utils::geometry::node_logical_rect;
 use super::types::*;
 
 /// System to detect right-clicks and open context menus
@@ -14,7 +17,7 @@ pub fn detect_context_menu_trigger(
     mut open_menu: ResMut<OpenContextMenu>,
     mut commands: Commands,
     settings: Res<ContextMenuSettings>,
-    existing_menus: Query<Entity, With<ContextMenu>>,
+    existing_menus: Query<Entity, (With<ContextMenu>, Without<SubmenuContainer>)>,
     theme: Option<Res<UiTheme>>,
 ) {
     // Only handle right-click
@@ -37,23 +40,14 @@ pub fn detect_context_menu_trigger(
 
     // Check if cursor is over any trigger
     for (entity, trigger, transform, computed) in trigger_query.iter() {
-        let pos = transform.translation().truncate();
-        let size = computed.size();
-
-        // Check if cursor is within bounds
-        let min = pos;
-        let max = pos + size;
-
-        if cursor_pos.x >= min.x && cursor_pos.x <= max.x
-            && cursor_pos.y >= min.y && cursor_pos.y <= max.y
-        {
+        if node_logical_rect(computed, transform).contains(cursor_pos) {
             // Close any existing menu
             for menu_entity in existing_menus.iter() {
                 commands.entity(menu_entity).despawn();
             }
 
             // Spawn new context menu
-            spawn_context_menu(
+            let menu = spawn_context_menu(
                 &mut commands,
                 &settings,
                 entity,
@@ -62,8 +56,13 @@ pub fn detect_context_menu_trigger(
                 &colors,
             );
 
-            open_menu.menu = Some(entity);
-            open_menu.trigger = Some(entity);
+            *open_menu = OpenContextMenu {
+                menu: Some(menu),
+                trigger: Some(entity),
+                active: Some(menu),
+                highlighted: None,
+                keyboard: false,
+            };
 
             return;
         }
@@ -78,8 +77,8 @@ fn spawn_context_menu(
     items: &[MenuItem],
     position: Vec2,
     colors: &ContextMenuColors,
-) {
-    commands
+) -> Entity {
+    let mut menu = commands
         .spawn((
             Node {
                 position_type: PositionType::Absolute,
@@ -94,16 +93,72 @@ fn spawn_context_menu(
             BorderColor::all(colors.border),
             BorderRadius::all(Val::Px(6.0)),
             GlobalZIndex(settings.z_index),
+            EscapeLayer::new(settings.z_index),
+            Popup,
             ContextMenu {
                 trigger,
                 position,
             },
-        ))
-        .with_children(|menu| {
+            SubmenuHover::default(),
+        ));
+
+    let menu_entity = menu.id();
+    menu.with_children(|menu| {
+        for (index, item) in items.iter().enumerate() {
+            spawn_menu_item(menu, item, index, menu_entity, colors);
+        }
+    });
+
+    menu_entity
+}
+
+/// Spawn a submenu beside its item in the parent menu
+fn spawn_submenu(
+    commands: &mut Commands,
+    settings: &ContextMenuSettings,
+    item: Entity,
+    parent: &ContextMenuItem,
+    items: &[MenuItem],
+    trigger: Entity,
+    colors: &ContextMenuColors,
+) -> Entity {
+    let mut submenu = Entity::PLACEHOLDER;
+    commands.entity(item).with_children(|row| {
+        let mut menu = row.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(100.0),
+                top: Val::Px(-4.0),
+                margin: UiRect::left(Val::Px(settings.submenu_offset)),
+                flex_direction: FlexDirection::Column,
+                min_width: Val::Px(180.0),
+                padding: UiRect::all(Val::Px(4.0)),
+                ..default()
+            },
+            BackgroundColor(colors.background),
+            BorderColor::all(colors.border),
+            BorderRadius::all(Val::Px(6.0)),
+            GlobalZIndex(settings.z_index + 1),
+            ContextMenu {
+                trigger,
+                position: Vec2::ZERO,
+            },
+            SubmenuContainer {
+                parent_menu: parent.menu,
+                trigger_index: parent.index,
+            },
+            SubmenuHover::default(),
+        ));
+
+        let menu_entity = menu.id();
+        menu.with_children(|menu| {
             for (index, item) in items.iter().enumerate() {
-                spawn_menu_item(menu, item, index, trigger, colors);
+                spawn_menu_item(menu, item, index, menu_entity, colors);
             }
         });
+        submenu = menu_entity;
+    });
+    submenu
 }
 
 /// Spawn a single menu item
@@ -329,35 +384,320 @@ pub fn handle_menu_item_click(
             continue;
         };
 
-        match &menu_item.item {
-            MenuItem::Action { id, disabled, .. } => {
-                if !disabled {
-                    action_events.write(ContextMenuActionEvent {
-                        id: id.clone(),
-                        trigger: menu.trigger,
-                    });
+        // Submenus open on hover (see `update_submenus`)
+        if activate_item(&menu_item.item, menu.trigger, &mut action_events, &mut checkbox_events) {
+            close_all_menus(&mut commands, &mut open_menu);
+        }
+    }
+}
+
+/// Send the event for an activated item
+///
+/// Returns whether the menu should close. Submenus, separators and disabled
+/// actions do nothing.
+fn activate_item(
+    item: &MenuItem,
+    trigger: Entity,
+    action_events: &mut MessageWriter<ContextMenuActionEvent>,
+    checkbox_events: &mut MessageWriter<ContextMenuCheckboxEvent>,
+) -> bool {
+    match item {
+        MenuItem::Action { id, disabled: false, .. } => {
+            action_events.write(ContextMenuActionEvent {
+                id: id.clone(),
+                trigger,
+            });
+            true
+        }
+
+        MenuItem::Checkbox { id, checked, .. } => {
+            checkbox_events.write(ContextMenuCheckboxEvent {
+                id: id.clone(),
+                checked: !checked,
+                trigger,
+            });
+            true
+        }
+
+        _ => false,
+    }
+}
 
-                    // Close menu
-                    close_all_menus(&mut commands, &mut open_menu);
+/// System to navigate open context menus with the keyboard
+///
+/// Up/Down move the highlight within the active menu, Right opens the
+/// highlighted submenu and moves into it, and Left closes the submenu and
+/// returns to its item in the parent menu. Enter or Space activates the
+/// highlighted item (or enters its submenu). Moving the pointer hands control
+/// back to it, and the keyboard carries on from the item it last hovered.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_menu_keyboard(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut cursor_moved: MessageReader<CursorMoved>,
+    settings: Res<ContextMenuSettings>,
+    theme: Option<Res<UiTheme>>,
+    mut open_menu: ResMut<OpenContextMenu>,
+    mut menus: Query<(&ContextMenu, &mut SubmenuHover, Option<&SubmenuContainer>)>,
+    mut items: Query<(Entity, &ContextMenuItem, &Interaction, &mut BackgroundColor)>,
+    mut action_events: MessageWriter<ContextMenuActionEvent>,
+    mut checkbox_events: MessageWriter<ContextMenuCheckboxEvent>,
+) {
+    let pointer_moved = cursor_moved.read().count() > 0;
+    let Some(mut active) = open_menu.active else {
+        return;
+    };
+
+    // The pointer may have closed the submenu the keyboard was in
+    if !menus.contains(active) {
+        let Some(root) = open_menu.menu else { return };
+        active = root;
+        open_menu.active = Some(root);
+        open_menu.highlighted = None;
+    }
+
+    if pointer_moved {
+        if open_menu.keyboard {
+            open_menu.keyboard = false;
+
+            // Drop the keyboard highlight unless the pointer is on it
+            for (_, item, interaction, mut background) in items.iter_mut() {
+                if item.menu == active
+                    && Some(item.index) == open_menu.highlighted
+                    && *interaction == Interaction::None
+                {
+                    background.0 = Color::NONE;
                 }
             }
+        }
 
-            MenuItem::Checkbox { id, checked, .. } => {
-                checkbox_events.write(ContextMenuCheckboxEvent {
-                    id: id.clone(),
-                    checked: !checked,
-                    trigger: menu.trigger,
-                });
+        if let Some((_, item, _, _)) = items.iter().find(|(_, _, interaction, _)| **interaction != Interaction::None) {
+            active = item.menu;
+            open_menu.active = Some(item.menu);
+            open_menu.highlighted = Some(item.index);
+        }
+    }
 
-                // Close menu
-                close_all_menus(&mut commands, &mut open_menu);
+    let colors = if let Some(ref theme) = theme {
+        ContextMenuColors::from_theme(theme)
+    } else {
+        ContextMenuColors::default_colors()
+    };
+
+    let up = keyboard.just_pressed(KeyCode::ArrowUp);
+    let down = keyboard.just_pressed(KeyCode::ArrowDown);
+    let right = keyboard.just_pressed(KeyCode::ArrowRight);
+    let left = keyboard.just_pressed(KeyCode::ArrowLeft);
+    let activate = keyboard.any_just_pressed([KeyCode::Enter, KeyCode::NumpadEnter, KeyCode::Space]);
+
+    if up || down || right || left || activate {
+        open_menu.keyboard = true;
+
+        let mut entries: Vec<(Entity, ContextMenuItem)> = items.iter()
+            .filter(|(_, item, _, _)| item.menu == active)
+            .map(|(entity, item, _, _)| (entity, item.clone()))
+            .collect();
+        entries.sort_by_key(|(_, item)| item.index);
+        let highlighted = entries.iter().find(|(_, item)| Some(item.index) == open_menu.highlighted);
+
+        if up || down {
+            let selectable: Vec<usize> = entries.iter()
+                .filter(|(_, item)| item.item.is_selectable())
+                .map(|(_, item)| item.index)
+                .collect();
+            open_menu.highlighted = step_highlight(&selectable, open_menu.highlighted, down);
+
+            // A submenu the pointer opened closes once the keyboard moves on
+            if let Ok((_, mut hover, _)) = menus.get_mut(active) {
+                if let Some((_, submenu)) = hover.open.take() {
+                    commands.entity(submenu).despawn();
+                }
             }
+        } else if left {
+            if let Ok((_, _, Some(container))) = menus.get(active) {
+                let (parent, index) = (container.parent_menu, container.trigger_index);
+                if let Ok((_, mut hover, _)) = menus.get_mut(parent) {
+                    if let Some((_, submenu)) = hover.open.take() {
+                        commands.entity(submenu).despawn();
+                    }
+                }
+                open_menu.active = Some(parent);
+                open_menu.highlighted = Some(index);
+            }
+        } else if let Some((entity, item)) = highlighted {
+            match &item.item {
+                MenuItem::Submenu { items: submenu_items, .. } => {
+                    if let Ok((menu, mut hover, _)) = menus.get_mut(active) {
+                        let submenu = match hover.open {
+                            Some((open_item, submenu)) if open_item == *entity => submenu,
+                            open => {
+                                if let Some((_, submenu)) = open {
+                                    commands.entity(submenu).despawn();
+                                }
+                                let submenu = spawn_submenu(
+                                    &mut commands,
+                                    &settings,
+                                    *entity,
+                                    item,
+                                    submenu_items,
+                                    menu.trigger,
+                                    &colors,
+                                );
+                                hover.open = Some((*entity, submenu));
+                                submenu
+                            }
+                        };
+
+                        open_menu.active = Some(submenu);
+                        open_menu.highlighted = submenu_items.iter().position(MenuItem::is_selectable);
+                    }
+                }
+                other if activate => {
+                    let trigger = menus.get(active).map(|(menu, _, _)| menu.trigger);
+                    if let Ok(trigger) = trigger {
+                        if activate_item(other, trigger, &mut action_events, &mut checkbox_events) {
+                            close_all_menus(&mut commands, &mut open_menu);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
 
-            MenuItem::Submenu { .. } => {
-                // TODO: Open submenu on hover/click
+    // Paint the keyboard highlight, including in a submenu spawned last frame
+    if open_menu.keyboard {
+        if let Some(active) = open_menu.active {
+            for (_, item, _, mut background) in items.iter_mut() {
+                if item.menu != active {
+                    continue;
+                }
+                let color = if Some(item.index) == open_menu.highlighted {
+                    colors.hover
+                } else {
+                    Color::NONE
+                };
+                background.set_if_neq(BackgroundColor(color));
             }
+        }
+    }
+}
+
+/// Move the highlight to the next (or previous) selectable index, wrapping
+/// around
+///
+/// With nothing highlighted, Down starts at the first item and Up at the last.
+fn step_highlight(selectable: &[usize], current: Option<usize>, forward: bool) -> Option<usize> {
+    let position = current.and_then(|current| selectable.iter().position(|index| *index == current));
+    let count = selectable.len();
+    if count == 0 {
+        return None;
+    }
+
+    let next = match (position, forward) {
+        (None, true) => 0,
+        (None, false) => count - 1,
+        (Some(position), true) => (position + 1) % count,
+        (Some(position), false) => (position + count - 1) % count,
+    };
+    Some(selectable[next])
+}
 
-            MenuItem::Separator => {}
+/// System to open and close submenus as the pointer moves through a menu
+///
+/// Hovering a submenu item opens its submenu after
+/// `ContextMenuSettings::submenu_open_delay`. An open submenu stays up while
+/// the pointer is inside it or travelling toward it across other items, and
+/// otherwise closes `submenu_close_delay` after the pointer moves on.
+pub fn update_submenus(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<ContextMenuSettings>,
+    theme: Option<Res<UiTheme>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut menus: Query<(Entity, &ContextMenu, &mut SubmenuHover)>,
+    items: Query<(Entity, &ContextMenuItem, &Interaction)>,
+    bounds: Query<(&GlobalTransform, &ComputedNode)>,
+    mut backgrounds: Query<&mut BackgroundColor, With<ContextMenuItem>>,
+    open_menu: Res<OpenContextMenu>,
+) {
+    let colors = if let Some(ref theme) = theme {
+        ContextMenuColors::from_theme(theme)
+    } else {
+        ContextMenuColors::default_colors()
+    };
+    let cursor = window_query.single().ok().and_then(|window| window.cursor_position());
+    let delta = time.delta_secs();
+
+    // A menu whose own submenu is open still has the pointer somewhere below it
+    let menus_with_open_submenu: Vec<Entity> = menus.iter()
+        .filter(|(_, _, hover)| hover.open.is_some())
+        .map(|(entity, _, _)| entity)
+        .collect();
+
+    for (menu_entity, menu, mut hover) in menus.iter_mut() {
+        let hovered = items.iter()
+            .find(|(_, item, interaction)| item.menu == menu_entity && **interaction != Interaction::None)
+            .map(|(entity, item, _)| (entity, item));
+        let hovered_entity = hovered.map(|(entity, _)| entity);
+
+        let open_rect = hover.open
+            .and_then(|(_, submenu)| bounds.get(submenu).ok())
+            .map(|(transform, computed)| node_logical_rect(computed, transform));
+        let in_submenu = hover.open.is_some_and(|(_, submenu)| menus_with_open_submenu.contains(&submenu))
+            || open_rect.zip(cursor).is_some_and(|(rect, cursor)| rect.contains(cursor));
+
+        // While the keyboard drives the menu, a resting pointer doesn't
+        // open or close submenus under it
+        let switch = if open_menu.keyboard {
+            None
+        } else {
+            hover.update(
+                hovered.map(|(entity, item)| (entity, matches!(item.item, MenuItem::Submenu { .. }))),
+                in_submenu,
+                open_rect,
+                cursor,
+                delta,
+                settings.submenu_open_delay,
+                settings.submenu_close_delay,
+            )
+        };
+
+        if let Some(switch) = switch {
+            if let Some((item, submenu)) = switch.close {
+                if let Ok(mut entity_commands) = commands.get_entity(submenu) {
+                    entity_commands.despawn();
+                }
+                if hovered_entity != Some(item) {
+                    if let Ok(mut background) = backgrounds.get_mut(item) {
+                        background.0 = Color::NONE;
+                    }
+                }
+            }
+
+            if let Some((item, menu_item)) = hovered.filter(|(item, _)| switch.open == Some(*item)) {
+                if let MenuItem::Submenu { items: submenu_items, .. } = &menu_item.item {
+                    let submenu = spawn_submenu(
+                        &mut commands,
+                        &settings,
+                        item,
+                        menu_item,
+                        submenu_items,
+                        menu.trigger,
+                        &colors,
+                    );
+                    hover.open = Some((item, submenu));
+                }
+            }
+        }
+
+        // Keep the item of an open submenu highlighted after the pointer leaves it
+        if let Some((item, _)) = hover.open {
+            if hovered_entity != Some(item) {
+                if let Ok(mut background) = backgrounds.get_mut(item) {
+                    background.set_if_neq(BackgroundColor(colors.hover));
+                }
+            }
         }
     }
 }
@@ -380,14 +720,9 @@ pub fn close_menu_on_outside_click(
         return;
     };
 
-    // Check if click is outside all menus
+    // Check if click is outside all menus (submenus included)
     for (_entity, transform, computed) in menu_query.iter() {
-        let pos = transform.translation().truncate();
-        let size = computed.size();
-
-        if cursor_pos.x >= pos.x && cursor_pos.x <= pos.x + size.x
-            && cursor_pos.y >= pos.y && cursor_pos.y <= pos.y + size.y
-        {
+        if node_logical_rect(computed, transform).contains(cursor_pos) {
             // Click is inside a menu, don't close
             return;
         }
@@ -397,13 +732,14 @@ pub fn close_menu_on_outside_click(
     close_all_menus(&mut commands, &mut open_menu);
 }
 
-/// System to close context menu on Escape key
+/// System to close context menu on Escape key, when it is the topmost
+/// [`EscapeStack`] layer
 pub fn close_menu_on_escape(
     mut commands: Commands,
-    keyboard: Res<ButtonInput<KeyCode>>,
+    escape: Res<EscapeStack>,
     mut open_menu: ResMut<OpenContextMenu>,
 ) {
-    if keyboard.just_pressed(KeyCode::Escape) {
+    if open_menu.menu.is_some_and(|menu| escape.closes(menu)) {
         close_all_menus(&mut commands, &mut open_menu);
     }
 }
@@ -415,5 +751,22 @@ fn close_all_menus(commands: &mut Commands, open_menu: &mut OpenContextMenu) {
             entity_commands.despawn();
         }
     }
-    open_menu.trigger = None;
+    *open_menu = OpenContextMenu::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_highlight_skips_and_wraps() {
+        // Index 1 is a separator
+        let selectable = [0, 2, 3];
+        assert_eq!(step_highlight(&selectable, None, true), Some(0));
+        assert_eq!(step_highlight(&selectable, None, false), Some(3));
+        assert_eq!(step_highlight(&selectable, Some(0), true), Some(2));
+        assert_eq!(step_highlight(&selectable, Some(3), true), Some(0));
+        assert_eq!(step_highlight(&selectable, Some(0), false), Some(3));
+        assert_eq!(step_highlight(&[], Some(0), true), None);
+    }
 }
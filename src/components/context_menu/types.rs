@@ -1,6 +1,7 @@
 //! Context menu types and components
 
 use bevy::prelude::*;
+use crate::dimensions::ZLayer;
 
 use crate::theme::UiTheme;
 
@@ -97,6 +98,13 @@ impl MenuItem {
             other => other,
         }
     }
+
+    /// Whether keyboard navigation can land on this item
+    ///
+    /// Separators and disabled actions are skipped.
+    pub(crate) fn is_selectable(&self) -> bool {
+        !matches!(self, Self::Separator | Self::Action { disabled: true, .. })
+    }
 }
 
 /// Component marking a context menu trigger
@@ -164,14 +172,21 @@ pub struct ContextMenuSettings {
     pub submenu_offset: f32,
     /// Animation duration
     pub animation_duration: f32,
+    /// Seconds a submenu item must be hovered before its submenu opens
+    pub submenu_open_delay: f32,
+    /// Seconds an open submenu stays up after the pointer moves to another
+    /// item without heading toward the submenu
+    pub submenu_close_delay: f32,
 }
 
 impl Default for ContextMenuSettings {
     fn default() -> Self {
         Self {
-            z_index: 1500,
+            z_index: ZLayer::Dropdown.z_index(),
             submenu_offset: -4.0,
             animation_duration: 0.1,
+            submenu_open_delay: 0.2,
+            submenu_close_delay: 0.3,
         }
     }
 }
@@ -183,6 +198,14 @@ pub struct OpenContextMenu {
     pub menu: Option<Entity>,
     /// The trigger entity
     pub trigger: Option<Entity>,
+    /// The menu the arrow keys navigate: the root menu, or the submenu the
+    /// keyboard (or pointer) last entered
+    pub active: Option<Entity>,
+    /// Index of the highlighted item in the active menu
+    pub highlighted: Option<usize>,
+    /// Whether the keyboard is driving the menu; moving the pointer hands
+    /// control back to it
+    pub keyboard: bool,
 }
 
 // Default colors (dark theme) for when no theme is provided
@@ -5,12 +5,24 @@ use crate::components::button::{ButtonBuilder, ButtonSize};
 use crate::styles::{dimensions, ButtonStyle};
 use crate::theme::UiTheme;
 use crate::relationships::BelongsToDialog;
+use crate::systems::escape::EscapeLayer;
 use crate::traits::{UiBuilder, LayoutBuilder, BuilderBase};
 use super::types::*;
 use std::collections::HashMap;
 use std::cell::RefCell;
 use std::rc::Rc;
 
+/// Default edge length of the header icon
+const DEFAULT_ICON_SIZE: f32 = 40.0;
+
+/// Where the header icon comes from
+enum DialogIconSource {
+    /// User-supplied image
+    Image(Handle<Image>),
+    /// Glyph badge chosen from the dialog type
+    TypeDefault,
+}
+
 /// Builder for creating dialogs
 pub struct DialogBuilder {
     title: String,
@@ -18,7 +30,14 @@ pub struct DialogBuilder {
     dialog_type: DialogType,
     buttons: Vec<DialogButton>,
     dismissible: bool,
+    blur_backdrop: bool,
     z_index: i32,
+    icon: Option<DialogIconSource>,
+    icon_position: DialogIconPosition,
+    icon_size: f32,
+    icon_tint: Option<Color>,
+    responsive_width: Option<DialogResponsiveWidth>,
+    stack_buttons_below: Option<Val>,
     // Theme-resolved colors (set via .themed())
     themed_colors: Option<DialogColors>,
     base: BuilderBase,
@@ -41,7 +60,14 @@ impl DialogBuilder {
             dialog_type,
             buttons: Vec::new(),
             dismissible: true,
+            blur_backdrop: false,
             z_index: dimensions::Z_INDEX_MODAL,
+            icon: None,
+            icon_position: DialogIconPosition::default(),
+            icon_size: DEFAULT_ICON_SIZE,
+            icon_tint: None,
+            responsive_width: None,
+            stack_buttons_below: None,
             themed_colors: None,
             base,
         }
@@ -92,6 +118,48 @@ impl DialogBuilder {
         self
     }
 
+    /// Size the dialog to `percent` of the window width, never wider than `max_px`
+    ///
+    /// The width is updated whenever the window is resized, so the dialog
+    /// shrinks to fit small windows and stops growing on large ones.
+    ///
+    /// # Example
+    /// ```ignore
+    /// DialogBuilder::new(DialogType::Info)
+    ///     .title("Update available")
+    ///     .responsive_width(90.0, 480.0)
+    ///     .ok_button()
+    ///     .build(&mut commands);
+    /// ```
+    pub fn responsive_width(mut self, percent: f32, max_px: f32) -> Self {
+        self.responsive_width = Some(DialogResponsiveWidth {
+            percent,
+            max: max_px,
+        });
+        self
+    }
+
+    /// Stack the buttons vertically when the window is narrower than `threshold`
+    ///
+    /// Keeps long button labels from overflowing on small screens. The
+    /// stacked buttons stretch to the dialog's width, and switch back to a
+    /// row when the window is widened again.
+    ///
+    /// # Example
+    /// ```ignore
+    /// DialogBuilder::new(DialogType::Warning)
+    ///     .title("Unsaved changes")
+    ///     .save_button("Save and quit")
+    ///     .discard_button("Quit without saving")
+    ///     .cancel_button("Keep editing")
+    ///     .stack_buttons_below(Val::Px(600.0))
+    ///     .build(&mut commands);
+    /// ```
+    pub fn stack_buttons_below(mut self, threshold: Val) -> Self {
+        self.stack_buttons_below = Some(threshold);
+        self
+    }
+
     /// Set minimum width
     pub fn min_width(mut self, min_width: Val) -> Self {
         self.base.node.min_width = min_width;
@@ -128,12 +196,67 @@ impl DialogBuilder {
         self
     }
 
-    /// Set the z-index for layering
+    /// Frost the content behind the dialog instead of just dimming it
+    ///
+    /// Bevy's UI has no backdrop blur yet, so this uses a dense frosted
+    /// overlay tint and lifts the dialog off it with a soft drop shadow.
+    pub fn blur_backdrop(mut self, blur: bool) -> Self {
+        self.blur_backdrop = blur;
+        self
+    }
+
+    /// Set the global z-index for layering (defaults to `Z_INDEX_MODAL`)
+    ///
+    /// On the same scale as the `dimensions::Z_INDEX_*` constants, so popups
+    /// opened from the dialog (at `Z_INDEX_POPUP`) draw above it. Also sets
+    /// where the dialog sits on the Escape stack.
     pub fn z_index(mut self, z_index: i32) -> Self {
         self.z_index = z_index;
         self
     }
 
+    /// Show an image icon in the dialog header
+    ///
+    /// # Example
+    /// ```ignore
+    /// DialogBuilder::new(DialogType::Warning)
+    ///     .icon(asset_server.load("icons/warning.png"))
+    ///     .title("Low Disk Space")
+    ///     .ok_button()
+    ///     .build(&mut commands);
+    /// ```
+    pub fn icon(mut self, image: Handle<Image>) -> Self {
+        self.icon = Some(DialogIconSource::Image(image));
+        self
+    }
+
+    /// Show the built-in icon for this dialog's type
+    ///
+    /// Renders a colored badge with a glyph (a red "x" for errors, an amber
+    /// "!" for warnings, ...). Has no effect on [`DialogType::Custom`] dialogs.
+    pub fn default_icon(mut self) -> Self {
+        self.icon = Some(DialogIconSource::TypeDefault);
+        self
+    }
+
+    /// Place the icon above the title (default) or beside it
+    pub fn icon_position(mut self, position: DialogIconPosition) -> Self {
+        self.icon_position = position;
+        self
+    }
+
+    /// Set the icon size in pixels (default: 40)
+    pub fn icon_size(mut self, size: f32) -> Self {
+        self.icon_size = size;
+        self
+    }
+
+    /// Tint image icons, or recolor the default icon badge
+    pub fn icon_color(mut self, color: Color) -> Self {
+        self.icon_tint = Some(color);
+        self
+    }
+
     /// Add a confirm button
     pub fn confirm_button(mut self, text: impl Into<String>) -> Self {
         self.buttons.push(DialogButton {
@@ -293,12 +416,17 @@ impl DialogBuilder {
                     align_items: AlignItems::Center,
                     ..default()
                 },
-                BackgroundColor(colors.overlay),
+                BackgroundColor(if self.blur_backdrop {
+                    colors.overlay_frosted
+                } else {
+                    colors.overlay
+                }),
                 DialogOverlay {
                     dialog_type: self.dialog_type,
                     dismissible: self.dismissible,
                 },
-                ZIndex(self.z_index),
+                GlobalZIndex(self.z_index),
+                EscapeLayer::new(self.z_index),
             ))
             .id();
 
@@ -349,12 +477,56 @@ impl DialogBuilder {
                 DialogContainer {
                     dialog_type: self.dialog_type,
                 },
-                ZIndex(self.z_index + 50),
                 BelongsToDialog(overlay_entity),  // Relationship to dialog overlay
             ))
             .id();
 
+        if let Some(responsive) = self.responsive_width {
+            commands.entity(container_entity).insert(responsive);
+        }
+
+        if self.blur_backdrop {
+            commands.entity(container_entity).insert(BoxShadow::new(
+                colors.shadow,
+                Val::Px(0.0),
+                Val::Px(8.0),
+                Val::Px(0.0),
+                Val::Px(32.0),
+            ));
+        }
+
+        // Header icon settings, pulled out so the closure below doesn't borrow self
+        let icon = self.icon.take();
+        let stack_buttons_below = self.stack_buttons_below;
+        let (dialog_type, icon_size, icon_tint) = (self.dialog_type, self.icon_size, self.icon_tint);
+        let has_icon = match &icon {
+            Some(DialogIconSource::TypeDefault) => self.dialog_type.default_icon_glyph().is_some(),
+            Some(DialogIconSource::Image(_)) => true,
+            None => false,
+        };
+        // Without a title there is nothing to sit beside, so fall back to above
+        let icon_beside = has_icon
+            && self.icon_position == DialogIconPosition::Beside
+            && !self.title.is_empty();
+
         commands.entity(container_entity).with_children(|parent| {
+            // Icon (above the title)
+            if has_icon && !icon_beside {
+                parent
+                    .spawn((
+                        Node {
+                            width: Val::Percent(100.0),
+                            margin: UiRect::bottom(Val::Px(dimensions::SPACING_MEDIUM)),
+                            justify_content: JustifyContent::Center,
+                            ..default()
+                        },
+                        BackgroundColor(Color::NONE),
+                    ))
+                    .with_children(|icon_parent| {
+                        spawn_dialog_icon(icon_parent, icon.as_ref(), dialog_type, icon_size, icon_tint, &colors);
+                    });
+            }
+
             // Title
             if !self.title.is_empty() {
                 parent
@@ -364,11 +536,16 @@ impl DialogBuilder {
                             margin: UiRect::bottom(Val::Px(dimensions::SPACING_LARGE)),
                             justify_content: JustifyContent::Center,
                             align_items: AlignItems::Center,
+                            column_gap: Val::Px(dimensions::SPACING_MEDIUM),
                             ..default()
                         },
                         BackgroundColor(Color::NONE),
                     ))
                     .with_children(|title_parent| {
+                        if icon_beside {
+                            spawn_dialog_icon(title_parent, icon.as_ref(), dialog_type, icon_size, icon_tint, &colors);
+                        }
+
                         title_parent.spawn((
                             Text::new(self.title.clone()),
                             TextFont {
@@ -409,59 +586,64 @@ impl DialogBuilder {
 
             // Buttons
             if !self.buttons.is_empty() {
-                parent
-                    .spawn((
-                        Node {
-                            width: Val::Percent(100.0),
-                            flex_direction: FlexDirection::Row,
-                            justify_content: JustifyContent::Center,
-                            column_gap: Val::Px(dimensions::SPACING_MEDIUM),
-                            ..default()
-                        },
-                        BackgroundColor(Color::NONE),
-                        DialogButtonRow,
-                    ))
-                    .with_children(|button_row| {
-                        for button in self.buttons {
-                            let button_entity = ButtonBuilder::new(button.text)
-                                .style(button.style)
-                                .size(ButtonSize::Medium)
-                                .build(button_row);
-
-                            // Track button entity if needed
-                            if return_buttons {
-                                button_entities_clone.borrow_mut().insert(button.marker.clone(), button_entity);
-                            }
+                let mut row = parent.spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Row,
+                        justify_content: JustifyContent::Center,
+                        column_gap: Val::Px(dimensions::SPACING_MEDIUM),
+                        row_gap: Val::Px(dimensions::SPACING_SMALL),
+                        ..default()
+                    },
+                    BackgroundColor(Color::NONE),
+                    DialogButtonRow,
+                ));
+
+                if let Some(threshold) = stack_buttons_below {
+                    row.insert(DialogButtonStacking { threshold });
+                }
+
+                row.with_children(|button_row| {
+                    for button in self.buttons {
+                        let button_entity = ButtonBuilder::new(button.text)
+                            .style(button.style)
+                            .size(ButtonSize::Medium)
+                            .build(button_row);
+
+                        // Track button entity if needed
+                        if return_buttons {
+                            button_entities_clone.borrow_mut().insert(button.marker.clone(), button_entity);
+                        }
 
-                            // Add standard marker based on type
-                            match &button.marker {
-                                DialogButtonMarker::Confirm => {
-                                    button_row.commands().entity(button_entity).insert(ConfirmButton);
-                                }
-                                DialogButtonMarker::Cancel => {
-                                    button_row.commands().entity(button_entity).insert(CancelButton);
-                                }
-                                DialogButtonMarker::Save => {
-                                    button_row.commands().entity(button_entity).insert(SaveButton);
-                                }
-                                DialogButtonMarker::Discard => {
-                                    button_row.commands().entity(button_entity).insert(DiscardButton);
-                                }
-                                DialogButtonMarker::Ok => {
-                                    button_row.commands().entity(button_entity).insert(OkButton);
-                                }
-                                DialogButtonMarker::Yes => {
-                                    button_row.commands().entity(button_entity).insert(YesButton);
-                                }
-                                DialogButtonMarker::No => {
-                                    button_row.commands().entity(button_entity).insert(NoButton);
-                                }
-                                DialogButtonMarker::Custom(_) => {
-                                    // Custom markers can be added by the caller using build_with_buttons()
-                                }
+                        // Add standard marker based on type
+                        match &button.marker {
+                            DialogButtonMarker::Confirm => {
+                                button_row.commands().entity(button_entity).insert(ConfirmButton);
+                            }
+                            DialogButtonMarker::Cancel => {
+                                button_row.commands().entity(button_entity).insert(CancelButton);
+                            }
+                            DialogButtonMarker::Save => {
+                                button_row.commands().entity(button_entity).insert(SaveButton);
+                            }
+                            DialogButtonMarker::Discard => {
+                                button_row.commands().entity(button_entity).insert(DiscardButton);
+                            }
+                            DialogButtonMarker::Ok => {
+                                button_row.commands().entity(button_entity).insert(OkButton);
+                            }
+                            DialogButtonMarker::Yes => {
+                                button_row.commands().entity(button_entity).insert(YesButton);
+                            }
+                            DialogButtonMarker::No => {
+                                button_row.commands().entity(button_entity).insert(NoButton);
+                            }
+                            DialogButtonMarker::Custom(_) => {
+                                // Custom markers can be added by the caller using build_with_buttons()
                             }
                         }
-                    });
+                    }
+                });
             }
         });
 
@@ -483,6 +665,71 @@ impl DialogBuilder {
     }
 }
 
+/// Spawn the header icon (image or type badge) into `parent`
+fn spawn_dialog_icon(
+    parent: &mut ChildSpawnerCommands,
+    icon: Option<&DialogIconSource>,
+    dialog_type: DialogType,
+    icon_size: f32,
+    icon_tint: Option<Color>,
+    colors: &DialogColors,
+) {
+    let size = Val::Px(icon_size);
+
+    match icon {
+        Some(DialogIconSource::Image(image)) => {
+            let mut image_node = ImageNode::new(image.clone());
+            if let Some(tint) = icon_tint {
+                image_node.color = tint;
+            }
+
+            parent.spawn((
+                Node {
+                    width: size,
+                    height: size,
+                    flex_shrink: 0.0,
+                    ..default()
+                },
+                image_node,
+                DialogIcon,
+            ));
+        }
+        Some(DialogIconSource::TypeDefault) => {
+            let Some(glyph) = dialog_type.default_icon_glyph() else {
+                return;
+            };
+            let badge_color = icon_tint
+                .unwrap_or_else(|| colors.icon_color_for(dialog_type));
+
+            parent
+                .spawn((
+                    Node {
+                        width: size,
+                        height: size,
+                        flex_shrink: 0.0,
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(badge_color),
+                    BorderRadius::all(Val::Percent(50.0)),
+                    DialogIcon,
+                ))
+                .with_children(|badge| {
+                    badge.spawn((
+                        Text::new(glyph),
+                        TextFont {
+                            font_size: icon_size * 0.6,
+                            ..default()
+                        },
+                        TextColor(colors.text_on_accent),
+                    ));
+                });
+        }
+        None => {}
+    }
+}
+
 impl UiBuilder for DialogBuilder {
     fn build(self, parent: &mut ChildSpawnerCommands) -> Entity {
         // Dialogs are usually spawned as root entities (overlays)
@@ -12,11 +12,16 @@ pub use plugin::DialogPlugin;
 pub use systems::DialogButtonEvent;
 pub use types::{
     DialogOverlay,
+    DialogQueue,
     DialogType,
     DialogContainer,
+    DialogResponsiveWidth,
     DialogTitle,
+    DialogIcon,
+    DialogIconPosition,
     DialogBody,
     DialogButtonRow,
+    DialogButtonStacking,
     DialogButton,
     DialogButtonMarker,
     // Dialog type markers
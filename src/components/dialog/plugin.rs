@@ -1,16 +1,24 @@
 //! Dialog plugin
 #![allow(missing_docs)]
 
+use bevy::prelude::*;
 use bevy_plugin_builder::define_plugin;
 use super::systems::*;
+use super::types::DialogQueue;
 
 // Plugin that adds dialog interaction systems
 define_plugin!(DialogPlugin {
     messages: [DialogButtonEvent],
+    custom_init: |app: &mut App| {
+        app.init_resource::<DialogQueue>();
+    },
     update: [
         handle_dialog_escape,
         handle_dialog_overlay_click,
         handle_cancel_button,
-        emit_dialog_button_events
+        emit_dialog_button_events,
+        show_queued_dialogs,
+        update_responsive_dialog_widths,
+        update_dialog_button_stacking
     ]
 });
\ No newline at end of file
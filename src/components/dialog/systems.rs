@@ -1,19 +1,23 @@
 //! Dialog interaction systems
 
 use bevy::prelude::*;
-use super::types::{DialogOverlay, CancelButton};
+use bevy::window::{PrimaryWindow, WindowResized};
+use crate::systems::escape::EscapeStack;
+use super::types::{DialogOverlay, DialogQueue, CancelButton, DialogResponsiveWidth, DialogButtonStacking};
 
 /// System to handle ESC key for dismissible dialogs
+///
+/// Only the topmost dialog closes, and only once no dropdown or other
+/// popup is open above it (see [`EscapeStack`]). A non-dismissible dialog
+/// on top swallows the press.
 pub fn handle_dialog_escape(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    escape: Res<EscapeStack>,
     mut commands: Commands,
     overlay_query: Query<(Entity, &DialogOverlay)>,
 ) {
-    if keyboard.just_pressed(KeyCode::Escape) {
-        for (entity, overlay) in &overlay_query {
-            if overlay.dismissible {
-                commands.entity(entity).despawn();
-            }
+    for (entity, overlay) in &overlay_query {
+        if overlay.dismissible && escape.closes(entity) {
+            commands.entity(entity).despawn();
         }
     }
 }
@@ -46,6 +50,21 @@ pub fn handle_cancel_button(
     }
 }
 
+/// System to open the next queued dialog once no dialog is showing
+pub fn show_queued_dialogs(
+    mut commands: Commands,
+    mut queue: ResMut<DialogQueue>,
+    overlay_query: Query<(), With<DialogOverlay>>,
+) {
+    if queue.is_empty() || !overlay_query.is_empty() {
+        return;
+    }
+
+    if let Some(dialog) = queue.pop() {
+        dialog.build(&mut commands);
+    }
+}
+
 /// Event fired when a dialog button is clicked
 #[derive(Message)]
 pub struct DialogButtonEvent {
@@ -132,4 +151,60 @@ pub fn emit_dialog_button_events(
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// Fit responsive dialogs to the window when they open and when it is resized
+pub fn update_responsive_dialog_widths(
+    mut resized: MessageReader<WindowResized>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut dialogs: Query<(Ref<DialogResponsiveWidth>, &mut Node)>,
+) {
+    let window_resized = resized.read().count() > 0;
+    let Ok(window) = windows.single() else {
+        return;
+    };
+
+    for (responsive, mut node) in &mut dialogs {
+        if !window_resized && !responsive.is_changed() {
+            continue;
+        }
+
+        let width = Val::Px(responsive.width_for(window.width()));
+        if node.width != width {
+            node.width = width;
+        }
+    }
+}
+
+/// Stack dialog buttons vertically in narrow windows, and unstack them when it widens
+pub fn update_dialog_button_stacking(
+    mut resized: MessageReader<WindowResized>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Option<Res<UiScale>>,
+    mut rows: Query<(Ref<DialogButtonStacking>, &mut Node)>,
+) {
+    // Px thresholds grow and shrink with the UI scale
+    let rescaled = ui_scale.as_ref().is_some_and(|scale| scale.is_changed());
+    let window_resized = resized.read().count() > 0 || rescaled;
+    let ui_scale = ui_scale.map_or(1.0, |scale| scale.0);
+    let Ok(window) = windows.single() else {
+        return;
+    };
+
+    for (stacking, mut node) in &mut rows {
+        if !window_resized && !stacking.is_changed() {
+            continue;
+        }
+
+        // Stacked buttons stretch to the dialog's width
+        let (direction, align) = if stacking.stacks_at(window.size(), ui_scale) {
+            (FlexDirection::Column, AlignItems::Stretch)
+        } else {
+            (FlexDirection::Row, AlignItems::Default)
+        };
+        if node.flex_direction != direction || node.align_items != align {
+            node.flex_direction = direction;
+            node.align_items = align;
+        }
+    }
+}
@@ -1,8 +1,12 @@
 //! Dialog component types and markers
 
 use bevy::prelude::*;
+use bevy::color::Alpha;
+
+use std::collections::VecDeque;
 
 use crate::theme::UiTheme;
+use super::builder::DialogBuilder;
 
 /// Component for dialog overlays
 #[derive(Component, Debug, Clone)]
@@ -13,6 +17,49 @@ pub struct DialogOverlay {
     pub dismissible: bool,
 }
 
+/// Dialogs waiting to be shown one at a time
+///
+/// Queued dialogs open in order, each only once no other dialog is open, so
+/// several notifications raised together never stack on top of each other.
+///
+/// ```ignore
+/// fn report(mut queue: ResMut<DialogQueue>) {
+///     queue.enqueue(DialogBuilder::new(DialogType::Info).title("Saved").ok_button());
+///     queue.enqueue(DialogBuilder::new(DialogType::Warning).title("Low disk space").ok_button());
+/// }
+/// ```
+#[derive(Resource, Default)]
+pub struct DialogQueue {
+    pending: VecDeque<DialogBuilder>,
+}
+
+impl DialogQueue {
+    /// Add a dialog to the back of the queue
+    pub fn enqueue(&mut self, dialog: DialogBuilder) {
+        self.pending.push_back(dialog);
+    }
+
+    /// Number of dialogs still waiting to be shown
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Whether no dialogs are waiting
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Drop every dialog that hasn't been shown yet
+    pub fn clear(&mut self) {
+        self.pending.clear();
+    }
+
+    /// Take the next dialog to show
+    pub(crate) fn pop(&mut self) -> Option<DialogBuilder> {
+        self.pending.pop_front()
+    }
+}
+
 /// Types of dialogs
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DialogType {
@@ -34,6 +81,30 @@ pub enum DialogType {
     Custom,
 }
 
+impl DialogType {
+    /// ASCII glyph used for this type's default header icon (None for custom dialogs)
+    pub fn default_icon_glyph(&self) -> Option<&'static str> {
+        match self {
+            DialogType::Error => Some("x"),
+            DialogType::Warning | DialogType::UnsavedChanges => Some("!"),
+            DialogType::Info | DialogType::Resolution => Some("i"),
+            DialogType::Success => Some("v"),
+            DialogType::ExitConfirmation => Some("?"),
+            DialogType::Custom => None,
+        }
+    }
+}
+
+/// Where the header icon sits relative to the title
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DialogIconPosition {
+    /// Centered above the title (default)
+    #[default]
+    Above,
+    /// Left of the title, on the same row
+    Beside,
+}
+
 /// Component for dialog containers
 #[derive(Component, Debug)]
 pub struct DialogContainer {
@@ -41,10 +112,69 @@ pub struct DialogContainer {
     pub dialog_type: DialogType,
 }
 
+/// Sizes a dialog to a share of the window width, up to a maximum
+///
+/// Added by [`DialogBuilder::responsive_width`]; the width follows the
+/// window as it is resized.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct DialogResponsiveWidth {
+    /// Share of the window width, in percent
+    pub percent: f32,
+    /// Largest width in logical pixels
+    pub max: f32,
+}
+
+impl DialogResponsiveWidth {
+    /// Dialog width for a window `window_width` logical pixels wide
+    pub fn width_for(&self, window_width: f32) -> f32 {
+        (window_width * self.percent / 100.0).min(self.max)
+    }
+}
+
+/// Stacks a dialog's buttons vertically when the window gets narrow
+///
+/// Added to the [`DialogButtonRow`] by [`DialogBuilder::stack_buttons_below`];
+/// the row switches between a row and a full-width column as the window is
+/// resized.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct DialogButtonStacking {
+    /// Window width below which the buttons stack
+    ///
+    /// `Px` is UI pixels, scaled by [`UiScale`] like any other size, and the
+    /// viewport units are shares of the window; `Auto` and `Percent` never
+    /// stack.
+    pub threshold: Val,
+}
+
+impl DialogButtonStacking {
+    /// The threshold in logical pixels, for a window `window_size` logical
+    /// pixels big and a UI scale of `ui_scale`
+    pub fn threshold_px(&self, window_size: Vec2, ui_scale: f32) -> Option<f32> {
+        match self.threshold {
+            Val::Px(px) => Some(px * ui_scale),
+            Val::Vw(percent) => Some(window_size.x * percent / 100.0),
+            Val::Vh(percent) => Some(window_size.y * percent / 100.0),
+            Val::VMin(percent) => Some(window_size.min_element() * percent / 100.0),
+            Val::VMax(percent) => Some(window_size.max_element() * percent / 100.0),
+            Val::Auto | Val::Percent(_) => None,
+        }
+    }
+
+    /// Whether the buttons stack in a window `window_size` logical pixels big
+    pub fn stacks_at(&self, window_size: Vec2, ui_scale: f32) -> bool {
+        self.threshold_px(window_size, ui_scale)
+            .is_some_and(|threshold| window_size.x < threshold)
+    }
+}
+
 /// Component for dialog title text
 #[derive(Component)]
 pub struct DialogTitle;
 
+/// Component for the dialog header icon
+#[derive(Component)]
+pub struct DialogIcon;
+
 /// Component for dialog body text
 #[derive(Component)]
 pub struct DialogBody;
@@ -145,10 +275,17 @@ pub(crate) mod defaults {
     use bevy::prelude::Color;
 
     pub const OVERLAY_BACKDROP: Color = Color::srgba(0.0, 0.0, 0.0, 0.6);
+    pub const OVERLAY_FROSTED: Color = Color::srgba(0.22, 0.24, 0.28, 0.8);
+    pub const SHADOW: Color = Color::srgba(0.0, 0.0, 0.0, 0.5);
     pub const BACKGROUND: Color = Color::srgb(0.12, 0.12, 0.14);
     pub const BORDER: Color = Color::srgb(0.3, 0.3, 0.3);
     pub const TEXT_PRIMARY: Color = Color::srgb(0.95, 0.95, 0.95);
     pub const TEXT_SECONDARY: Color = Color::srgb(0.7, 0.7, 0.7);
+    pub const INFO: Color = Color::srgb(0.25, 0.46, 0.86);
+    pub const SUCCESS: Color = Color::srgb(0.25, 0.76, 0.25);
+    pub const WARNING: Color = Color::srgb(0.96, 0.76, 0.05);
+    pub const DANGER: Color = Color::srgb(0.86, 0.25, 0.25);
+    pub const TEXT_ON_ACCENT: Color = Color::WHITE;
 }
 
 /// Resolved dialog colors from theme
@@ -156,6 +293,10 @@ pub(crate) mod defaults {
 pub struct DialogColors {
     /// Overlay backdrop color
     pub overlay: Color,
+    /// Frosted overlay color used with `blur_backdrop`
+    pub overlay_frosted: Color,
+    /// Drop shadow under the container on frosted backdrops
+    pub shadow: Color,
     /// Container background color
     pub background: Color,
     /// Border color
@@ -164,17 +305,34 @@ pub struct DialogColors {
     pub text_title: Color,
     /// Body text color
     pub text_body: Color,
+    /// Icon badge color for info-like dialogs
+    pub info: Color,
+    /// Icon badge color for success dialogs
+    pub success: Color,
+    /// Icon badge color for warning dialogs
+    pub warning: Color,
+    /// Icon badge color for error dialogs
+    pub danger: Color,
+    /// Glyph color on icon badges
+    pub text_on_accent: Color,
 }
 
 impl DialogColors {
     /// Resolve colors from theme
     pub fn from_theme(theme: &UiTheme) -> Self {
         Self {
-            overlay: theme.colors.overlay,
+            overlay: theme.backdrop_color(theme.colors.overlay),
+            overlay_frosted: theme.backdrop_color(theme.colors.surface.tertiary.with_alpha(0.8)),
+            shadow: theme.colors.surface.dark.with_alpha(0.5),
             background: theme.colors.surface.secondary,
             border: theme.colors.border.default,
             text_title: theme.colors.text.primary,
             text_body: theme.colors.text.secondary,
+            info: theme.colors.primary.base,
+            success: theme.colors.success.base,
+            warning: theme.colors.warning.base,
+            danger: theme.colors.danger.base,
+            text_on_accent: theme.colors.primary.on_color,
         }
     }
 
@@ -182,10 +340,54 @@ impl DialogColors {
     pub fn default_colors() -> Self {
         Self {
             overlay: defaults::OVERLAY_BACKDROP,
+            overlay_frosted: defaults::OVERLAY_FROSTED,
+            shadow: defaults::SHADOW,
             background: defaults::BACKGROUND,
             border: defaults::BORDER,
             text_title: defaults::TEXT_PRIMARY,
             text_body: defaults::TEXT_SECONDARY,
+            info: defaults::INFO,
+            success: defaults::SUCCESS,
+            warning: defaults::WARNING,
+            danger: defaults::DANGER,
+            text_on_accent: defaults::TEXT_ON_ACCENT,
         }
     }
-}
\ No newline at end of file
+
+    /// Icon badge color for a dialog type
+    pub fn icon_color_for(&self, dialog_type: DialogType) -> Color {
+        match dialog_type {
+            DialogType::Error => self.danger,
+            DialogType::Warning | DialogType::UnsavedChanges => self.warning,
+            DialogType::Success => self.success,
+            _ => self.info,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stacking_threshold_is_compared_in_logical_pixels() {
+        let window = Vec2::new(800.0, 600.0);
+
+        let px = DialogButtonStacking { threshold: Val::Px(600.0) };
+        assert!(!px.stacks_at(window, 1.0));
+        // At 2x UI scale 600 UI pixels take up 1200 logical pixels
+        assert!(px.stacks_at(window, 2.0));
+
+        // 150% of the window height is 900 logical pixels
+        let vh = DialogButtonStacking { threshold: Val::Vh(150.0) };
+        assert_eq!(vh.threshold_px(window, 2.0), Some(900.0));
+        assert!(vh.stacks_at(window, 1.0));
+
+        let vmin = DialogButtonStacking { threshold: Val::VMin(100.0) };
+        assert_eq!(vmin.threshold_px(window, 1.0), Some(600.0));
+        assert!(!vmin.stacks_at(window, 1.0));
+
+        let percent = DialogButtonStacking { threshold: Val::Percent(200.0) };
+        assert!(!percent.stacks_at(window, 1.0));
+    }
+}
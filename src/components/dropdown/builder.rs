@@ -3,11 +3,16 @@
 use bevy::prelude::*;
 use super::types::*;
 use crate::animation::AnimationCategory;
+use crate::components::scroll_view::{ScrollView, ScrollConfig, ScrollbarVisibility};
 use crate::styles::dimensions;
 use crate::theme::UiTheme;
 use crate::relationships::BelongsToDropdown;
+use crate::systems::popup::{self, PopupAnchor, PopupWidth, SubmenuHover};
 use crate::traits::{UiBuilder, LayoutBuilder, BuilderBase};
 
+#[cfg(feature = "text_input")]
+use crate::components::text_input::TextInputBuilder;
+
 /// Builder for creating dropdown select components
 ///
 /// # Examples
@@ -22,10 +27,50 @@ use crate::traits::{UiBuilder, LayoutBuilder, BuilderBase};
 ///         .build(parent);
 /// }
 /// ```
+///
+/// Options can carry an icon and a color swatch, which are shown both in the
+/// menu and on the button face when selected:
+///
+/// ```ignore
+/// DropdownBuilder::new(vec!["Red".into(), "Green".into()])
+///     .option_color(0, Color::srgb(0.9, 0.2, 0.2))
+///     .option_color(1, Color::srgb(0.2, 0.8, 0.3))
+///     .build(parent);
+/// ```
+///
+/// A description turns an option into a two-line row in the menu, with the
+/// description in smaller text under the label:
+///
+/// ```ignore
+/// DropdownBuilder::new(vec!["Fast".into(), "Accurate".into()])
+///     .option_description(0, "Quick answers for simple tasks")
+///     .option_description(1, "Slower, for hard problems")
+///     .build(parent);
+/// ```
+///
+/// Groups put related options under a header:
+///
+/// ```ignore
+/// DropdownBuilder::new(Vec::new())
+///     .group("Fruit", |fruit| {
+///         fruit.option("Apple").option("Cherry");
+///     })
+///     .group("Vegetables", |vegetables| {
+///         vegetables.option("Carrot");
+///     })
+///     .build(parent);
+/// ```
 pub struct DropdownBuilder {
     options: Vec<String>,
+    option_details: Vec<DropdownOption>,
+    entries: Vec<MenuEntry>,
+    face_renderer: Option<DropdownFaceRenderFn>,
     selected_index: Option<usize>,
     placeholder: String,
+    max_visible_items: Option<usize>,
+    menu_width: Option<Val>,
+    #[cfg(feature = "text_input")]
+    search_placeholder: Option<String>,
     // Theme-resolved colors (set via .themed())
     themed_colors: Option<DropdownColors>,
     base: BuilderBase,
@@ -39,10 +84,20 @@ impl DropdownBuilder {
         base.node.flex_direction = FlexDirection::Column;
         base.node.position_type = PositionType::Relative;
 
+        let option_details = (0..options.len()).map(DropdownOption::new).collect();
+        let entries = (0..options.len()).map(MenuEntry::Option).collect();
+
         Self {
             options,
+            option_details,
+            entries,
+            face_renderer: None,
             selected_index: None,
             placeholder: "Select an option".to_string(),
+            max_visible_items: None,
+            menu_width: None,
+            #[cfg(feature = "text_input")]
+            search_placeholder: None,
             themed_colors: None,
             base,
         }
@@ -86,12 +141,171 @@ impl DropdownBuilder {
         self
     }
 
+    /// Show an icon (emoji or symbol) before the option at `index`
+    pub fn option_icon(mut self, index: usize, icon: impl Into<String>) -> Self {
+        if let Some(option) = self.option_details.get_mut(index) {
+            option.icon = Some(icon.into());
+        }
+        self
+    }
+
+    /// Show a color swatch before the option at `index`
+    pub fn option_color(mut self, index: usize, color: Color) -> Self {
+        if let Some(option) = self.option_details.get_mut(index) {
+            option.color = Some(color);
+        }
+        self
+    }
+
+    /// Show a description under the option at `index` in the menu
+    ///
+    /// Options with a description take two lines; the others stay compact.
+    /// The button face shows only the label.
+    pub fn option_description(mut self, index: usize, description: impl Into<String>) -> Self {
+        if let Some(option) = self.option_details.get_mut(index) {
+            option.description = Some(description.into());
+        }
+        self
+    }
+
+    /// Disable the option at `index`, greying it out and making it unselectable
+    pub fn option_disabled(mut self, index: usize, disabled: bool) -> Self {
+        if let Some(option) = self.option_details.get_mut(index) {
+            option.disabled = disabled;
+        }
+        self
+    }
+
+    /// Add a row that opens a nested submenu on hover
+    ///
+    /// The submenu's options are appended to the dropdown's options, so
+    /// their indices continue after the options added so far and selecting
+    /// one works like selecting any other option. Submenus can nest.
+    ///
+    /// # Example
+    /// ```ignore
+    /// DropdownBuilder::new(vec!["Image".into()])
+    ///     .placeholder("Insert")
+    ///     .submenu("Shape", |shape| {
+    ///         shape.option("Rectangle").option("Circle");
+    ///     })
+    ///     .build(parent);
+    /// ```
+    pub fn submenu(mut self, label: impl Into<String>, build: impl FnOnce(&mut DropdownSubmenuBuilder)) -> Self {
+        let entries = DropdownSubmenuBuilder::collect(&mut self.options, build);
+        self.push_nested(MenuEntry::Submenu {
+            label: label.into(),
+            entries,
+        })
+    }
+
+    /// Add a group of options under a non-selectable header
+    ///
+    /// The header is drawn in muted text and skipped by keyboard navigation.
+    /// Like a submenu's, the group's options are appended to the dropdown's
+    /// options: their indices continue after the options added so far, so
+    /// [`DropdownData::selected_index`] and
+    /// [`DropdownData::selected_value`] refer to the flat list as usual.
+    ///
+    /// In a [searchable](Self::searchable) menu only options are matched,
+    /// never header text; a header stays visible while any option in its
+    /// group (or the group's submenus) matches, and hides with the last one.
+    ///
+    /// # Example
+    /// ```ignore
+    /// // Options 0-1 are weapons, 2-3 armor
+    /// DropdownBuilder::new(Vec::new())
+    ///     .placeholder("Equip")
+    ///     .group("Weapons", |weapons| {
+    ///         weapons.option("Sword").option("Bow");
+    ///     })
+    ///     .group("Armor", |armor| {
+    ///         armor.option("Helmet").option("Shield");
+    ///     })
+    ///     .build(parent);
+    /// ```
+    pub fn group(mut self, label: impl Into<String>, build: impl FnOnce(&mut DropdownSubmenuBuilder)) -> Self {
+        let entries = DropdownSubmenuBuilder::collect(&mut self.options, build);
+        self.push_nested(MenuEntry::Group {
+            label: label.into(),
+            entries,
+        })
+    }
+
+    /// Add a submenu or group whose options were just appended to `options`
+    fn push_nested(mut self, entry: MenuEntry) -> Self {
+        self.entries.push(entry);
+
+        let start = self.option_details.len();
+        self.option_details.extend((start..self.options.len()).map(DropdownOption::new));
+        self
+    }
+
+    /// Show at most `count` options at once and scroll the rest
+    ///
+    /// Opening the menu scrolls the selected option into view. Without a
+    /// limit the menu is capped at 200 pixels.
+    pub fn max_visible_items(mut self, count: usize) -> Self {
+        self.max_visible_items = Some(count.max(1));
+        self
+    }
+
+    /// Give the menu a fixed width instead of following the button
+    ///
+    /// By default the menu is at least as wide as the dropdown button,
+    /// measured when it opens, and grows to fit longer options.
+    pub fn menu_width(mut self, width: Val) -> Self {
+        self.menu_width = Some(width);
+        self
+    }
+
+    /// Put a search field at the top of the menu that filters the options
+    ///
+    /// The field takes focus when the menu opens. Options whose label
+    /// doesn't contain the typed text (ignoring case) are hidden, and the
+    /// keyboard highlight jumps to the first match, so typing and pressing
+    /// Enter picks it. Closing the menu clears the search.
+    ///
+    /// [Group](Self::group) headers are never matched themselves; each
+    /// stays visible while at least one option in its group matches.
+    ///
+    /// # Example
+    /// ```ignore
+    /// DropdownBuilder::new(countries)
+    ///     .placeholder("Country")
+    ///     .searchable()
+    ///     .max_visible_items(8)
+    ///     .build(parent);
+    /// ```
+    #[cfg(feature = "text_input")]
+    pub fn searchable(mut self) -> Self {
+        self.search_placeholder = Some("Search...".to_string());
+        self
+    }
+
+    /// Render the button face with a custom function instead of the
+    /// default `[swatch] [icon] label` layout
+    pub fn render_selected(mut self, render: DropdownFaceRenderFn) -> Self {
+        self.face_renderer = Some(render);
+        self
+    }
+
     /// Set the width of the dropdown
     pub fn width(mut self, width: Val) -> Self {
         self.base.node.width = width;
         self
     }
 
+    /// Row limit for the menu, sized to the rows shown before it scrolls
+    fn menu_limit(&self) -> Option<DropdownMenuLimit> {
+        self.max_visible_items.map(|visible_items| DropdownMenuLimit {
+            visible_items,
+            viewport_height: MenuEntry::visible_height(&self.entries, visible_items, |row| {
+                fixed_row_height(row, &self.option_details)
+            }),
+        })
+    }
+
     /// Build the dropdown and spawn it (proxy to UiBuilder::build)
     pub fn build(self, parent: &mut ChildSpawnerCommands) -> Entity {
         UiBuilder::build(self, parent)
@@ -110,6 +324,20 @@ impl UiBuilder for DropdownBuilder {
         };
 
         let display_text = data.display_text().to_string();
+        let limit = self.menu_limit();
+        let menu_max_height = match limit {
+            // Row heights plus the menu's top and bottom border
+            Some(limit) => limit.viewport_height + 4.0,
+            None => 200.0,
+        };
+        #[cfg(feature = "text_input")]
+        let menu_max_height = menu_max_height + if self.search_placeholder.is_some() { SEARCH_ROW_HEIGHT } else { 0.0 };
+        let selected_option = self.selected_index
+            .and_then(|index| self.option_details.get(index));
+        let face = DropdownButtonContent {
+            text_color: colors.text_primary,
+            render: self.face_renderer,
+        };
 
         // Temporary variable to hold dropdown entity ID
         let mut dropdown_entity = Entity::PLACEHOLDER;
@@ -120,10 +348,11 @@ impl UiBuilder for DropdownBuilder {
             Dropdown,
             DropdownState::Closed,
             data,
+            DropdownNavigation::default(),
         )).with_children(|dropdown| {
             dropdown_entity = dropdown.target_entity();
             // Dropdown button
-            dropdown.spawn((
+            let button_id = dropdown.spawn((
                 Node {
                     width: Val::Percent(100.0),
                     height: Val::Px(dimensions::INPUT_HEIGHT),
@@ -142,15 +371,20 @@ impl UiBuilder for DropdownBuilder {
                 Transform::default(),
                 AnimationCategory::Button,
             )).with_children(|button| {
-                // Selected value text
+                // Selected value (rebuilt by update_dropdown_button_face)
                 button.spawn((
-                    Text::new(display_text),
-                    TextFont {
-                        font_size: dimensions::FONT_SIZE_NORMAL,
+                    Node {
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(dimensions::SPACING_SMALL),
+                        flex_grow: 1.0,
                         ..default()
                     },
-                    TextColor(colors.text_primary),
-                ));
+                    face,
+                    BelongsToDropdown(dropdown_entity),
+                )).with_children(|content| {
+                    face.render(content, &display_text, selected_option);
+                });
 
                 // Down arrow indicator (ASCII for maximum compatibility)
                 button.spawn((
@@ -161,59 +395,61 @@ impl UiBuilder for DropdownBuilder {
                     },
                     TextColor(colors.text_secondary),
                 ));
-            });
+            }).id();
 
-            // Dropdown menu (initially hidden)
-            let _menu_id = dropdown.spawn((
-                Node {
-                    width: Val::Percent(100.0),
-                    max_height: Val::Px(200.0),
-                    position_type: PositionType::Absolute,
-                    top: Val::Px(dimensions::INPUT_HEIGHT + 4.0),
-                    left: Val::Px(0.0),
-                    flex_direction: FlexDirection::Column,
-                    display: Display::None, // Hidden by default
-                    overflow: Overflow::scroll_y(),
-                    border: UiRect::all(Val::Px(2.0)),
-                    ..default()
-                },
+            // Dropdown menu (initially hidden). It moves to the popup layer so
+            // clipping or higher-z siblings around the dropdown can't hide it.
+            let mut menu_node = popup::menu_node(Val::Px(menu_max_height));
+            let anchor = match self.menu_width {
+                Some(width) => {
+                    menu_node.width = width;
+                    PopupAnchor::below(button_id).with_width(PopupWidth::Free)
+                }
+                None => PopupAnchor::below(button_id).with_width(PopupWidth::AtLeast),
+            };
+            let mut menu = dropdown.spawn((
+                menu_node,
                 BackgroundColor(colors.menu_background),
                 BorderColor::all(colors.border),
                 BorderRadius::all(Val::Px(dimensions::BORDER_RADIUS_SMALL)),
-                GlobalZIndex(dimensions::Z_INDEX_MODAL), // Use GlobalZIndex to appear above ALL UI elements
+                GlobalZIndex(dimensions::Z_INDEX_POPUP), // Above dialogs, below tooltips
+                anchor,
                 DropdownMenu,
                 BelongsToDropdown(dropdown_entity),
-            )).with_children(|menu| {
-                // Spawn options
-                for (index, option) in self.options.iter().enumerate() {
-                    menu.spawn((
-                        Node {
-                            width: Val::Percent(100.0),
-                            padding: UiRect::all(Val::Px(dimensions::PADDING_SMALL)),
-                            ..default()
-                        },
-                        BackgroundColor(if Some(index) == self.selected_index {
-                            colors.selected_highlight
-                        } else {
-                            Color::NONE
-                        }),
-                        DropdownOption { index },
-                        Interaction::default(),
-                        Transform::default(),
-                        AnimationCategory::Button,
-                        BelongsToDropdown(dropdown_entity),
-                    )).with_children(|option_container| {
-                        option_container.spawn((
-                            Text::new(option.clone()),
-                            TextFont {
-                                font_size: dimensions::FONT_SIZE_NORMAL,
-                                ..default()
-                            },
-                            TextColor(colors.text_primary),
-                        ));
-                    });
+                SubmenuHover::default(),
+                // Wheel and PageUp/PageDown scrolling while hovered
+                ScrollView,
+                ScrollPosition::default(),
+                ScrollConfig {
+                    auto_scroll_to_focus: false,
+                    scrollbar_visibility: ScrollbarVisibility::Never,
+                    enable_drag_scroll: false,
+                    enable_kinetic_scroll: false,
+                    ..default()
+                },
+                Interaction::default(),
+            ));
+            if let Some(limit) = limit {
+                menu.insert(limit);
+            }
+
+            let rows = MenuRows {
+                options: &self.options,
+                details: &self.option_details,
+                selected_index: self.selected_index,
+                colors: &colors,
+                fixed_heights: limit.is_some(),
+                dropdown: dropdown_entity,
+            };
+            menu.with_children(|menu| {
+                #[cfg(feature = "text_input")]
+                if let Some(placeholder) = &self.search_placeholder {
+                    let field = spawn_search_field(menu, placeholder, dropdown_entity);
+                    menu.commands().entity(dropdown_entity).insert(DropdownSearch { field });
                 }
-            }).id();
+
+                rows.spawn(menu, &self.entries, 1);
+            });
         }).id();
 
         // Apply hooks
@@ -237,6 +473,241 @@ impl UiBuilder for DropdownBuilder {
     }
 }
 
+/// Spawn the search field row at the top of a searchable menu
+#[cfg(feature = "text_input")]
+fn spawn_search_field(menu: &mut ChildSpawnerCommands, placeholder: &str, dropdown: Entity) -> Entity {
+    let mut field = Entity::PLACEHOLDER;
+    menu.spawn(Node {
+        width: Val::Percent(100.0),
+        height: Val::Px(SEARCH_ROW_HEIGHT),
+        flex_shrink: 0.0,
+        padding: UiRect::all(Val::Px((SEARCH_ROW_HEIGHT - dimensions::INPUT_HEIGHT) / 2.0)),
+        ..default()
+    }).with_children(|row| {
+        field = TextInputBuilder::new()
+            .with_placeholder(placeholder)
+            .with_width(Val::Percent(100.0))
+            .build(row);
+        row.commands().entity(field).insert(BelongsToDropdown(dropdown));
+    });
+    field
+}
+
+/// Collects the rows of a dropdown submenu or group
+///
+/// Passed to the closure given to [`DropdownBuilder::submenu`] or
+/// [`DropdownBuilder::group`].
+pub struct DropdownSubmenuBuilder<'a> {
+    options: &'a mut Vec<String>,
+    entries: Vec<MenuEntry>,
+}
+
+impl DropdownSubmenuBuilder<'_> {
+    /// Run `build` on an empty submenu or group whose options are appended
+    /// to `options`, returning its rows
+    fn collect(options: &mut Vec<String>, build: impl FnOnce(&mut DropdownSubmenuBuilder)) -> Vec<MenuEntry> {
+        let mut rows = DropdownSubmenuBuilder {
+            options,
+            entries: Vec::new(),
+        };
+        build(&mut rows);
+        rows.entries
+    }
+
+    /// Add an option to the submenu
+    pub fn option(&mut self, label: impl Into<String>) -> &mut Self {
+        self.entries.push(MenuEntry::Option(self.options.len()));
+        self.options.push(label.into());
+        self
+    }
+
+    /// Add a row that opens a further submenu
+    pub fn submenu(&mut self, label: impl Into<String>, build: impl FnOnce(&mut DropdownSubmenuBuilder)) -> &mut Self {
+        let entries = Self::collect(self.options, build);
+        self.entries.push(MenuEntry::Submenu {
+            label: label.into(),
+            entries,
+        });
+        self
+    }
+
+    /// Add a group of rows under a header
+    pub fn group(&mut self, label: impl Into<String>, build: impl FnOnce(&mut DropdownSubmenuBuilder)) -> &mut Self {
+        let entries = Self::collect(self.options, build);
+        self.entries.push(MenuEntry::Group {
+            label: label.into(),
+            entries,
+        });
+        self
+    }
+}
+
+/// Spawns the rows of a dropdown menu and its submenus
+struct MenuRows<'a> {
+    options: &'a [String],
+    details: &'a [DropdownOption],
+    selected_index: Option<usize>,
+    colors: &'a DropdownColors,
+    /// Whether rows get fixed heights (in a height-limited menu) instead
+    /// of fitting their content
+    fixed_heights: bool,
+    dropdown: Entity,
+}
+
+impl MenuRows<'_> {
+    /// `px` tall in a height-limited menu, otherwise fitting the content
+    fn height(&self, px: f32) -> Val {
+        if self.fixed_heights {
+            Val::Px(px)
+        } else {
+            Val::Auto
+        }
+    }
+
+    /// Spawn `entries` into `menu`; `depth` stacks nested submenus above
+    /// their parents
+    fn spawn(&self, menu: &mut ChildSpawnerCommands, entries: &[MenuEntry], depth: i32) {
+        for entry in entries {
+            match entry {
+                MenuEntry::Option(index) => self.spawn_option(menu, *index),
+                MenuEntry::Submenu { label, entries } => self.spawn_submenu(menu, label, entries, depth),
+                MenuEntry::Group { label, entries } => {
+                    self.spawn_group_header(menu, label, MenuEntry::option_indices(entries));
+                    self.spawn(menu, entries, depth);
+                }
+            }
+        }
+    }
+
+    fn spawn_option(&self, menu: &mut ChildSpawnerCommands, index: usize) {
+        let (Some(option), Some(details)) = (self.options.get(index), self.details.get(index)) else {
+            return;
+        };
+        let (text_color, description_color) = if details.disabled {
+            (self.colors.text_disabled, self.colors.text_disabled)
+        } else {
+            (self.colors.text_primary, self.colors.text_secondary)
+        };
+
+        let mut option_entity = menu.spawn((
+            popup::menu_item_node(self.height(option_row_height(details))),
+            BackgroundColor(if Some(index) == self.selected_index {
+                self.colors.selected_highlight
+            } else {
+                Color::NONE
+            }),
+            details.clone(),
+            Interaction::default(),
+            Transform::default(),
+            BelongsToDropdown(self.dropdown),
+        ));
+        option_entity.with_children(|option_container| {
+            spawn_menu_option_content(option_container, option, Some(details), text_color, description_color);
+        });
+
+        // Disabled options don't react to hover
+        if !details.disabled {
+            option_entity.insert(AnimationCategory::Button);
+        }
+    }
+
+    fn spawn_group_header(&self, menu: &mut ChildSpawnerCommands, label: &str, options: Vec<usize>) {
+        let mut header_node = popup::menu_item_node(self.height(GROUP_HEADER_HEIGHT));
+        header_node.align_items = AlignItems::End;
+
+        menu.spawn((
+            header_node,
+            BackgroundColor(Color::NONE),
+            DropdownGroupHeader { options },
+            BelongsToDropdown(self.dropdown),
+        )).with_child((
+            Text::new(label),
+            TextFont {
+                font_size: dimensions::FONT_SIZE_SMALL,
+                ..default()
+            },
+            TextColor(self.colors.text_muted),
+        ));
+    }
+
+    fn spawn_submenu(&self, menu: &mut ChildSpawnerCommands, label: &str, entries: &[MenuEntry], depth: i32) {
+        let mut row_node = popup::menu_item_node(self.height(dimensions::INPUT_HEIGHT));
+        row_node.justify_content = JustifyContent::SpaceBetween;
+
+        let row = menu.spawn((
+            row_node,
+            BackgroundColor(Color::NONE),
+            Interaction::default(),
+            BelongsToDropdown(self.dropdown),
+        )).with_children(|row| {
+            row.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: dimensions::FONT_SIZE_NORMAL,
+                    ..default()
+                },
+                TextColor(self.colors.text_primary),
+            ));
+
+            // Right arrow indicator (ASCII for maximum compatibility)
+            row.spawn((
+                Text::new(">"),
+                TextFont {
+                    font_size: dimensions::FONT_SIZE_SMALL,
+                    ..default()
+                },
+                TextColor(self.colors.text_secondary),
+            ));
+        }).id();
+
+        // The panel moves to the popup layer with the menu, beside its row
+        let mut panel_node = popup::menu_node(Val::Px(SUBMENU_MAX_HEIGHT));
+        panel_node.min_width = Val::Px(SUBMENU_MIN_WIDTH);
+        let submenu = menu.spawn((
+            panel_node,
+            BackgroundColor(self.colors.menu_background),
+            BorderColor::all(self.colors.border),
+            BorderRadius::all(Val::Px(dimensions::BORDER_RADIUS_SMALL)),
+            GlobalZIndex(dimensions::Z_INDEX_POPUP + depth),
+            PopupAnchor::beside(row),
+            DropdownSubmenu { item: row },
+            SubmenuHover::default(),
+            BelongsToDropdown(self.dropdown),
+            Interaction::default(),
+        )).with_children(|panel| self.spawn(panel, entries, depth + 1)).id();
+
+        menu.commands().entity(row).insert(DropdownSubmenuItem { submenu });
+    }
+}
+
+/// Height of a menu row in a height-limited menu: options with a
+/// description line are taller, group headers shorter
+fn fixed_row_height(row: &MenuEntry, details: &[DropdownOption]) -> f32 {
+    match row {
+        MenuEntry::Option(index) => details.get(*index).map_or(dimensions::INPUT_HEIGHT, option_row_height),
+        MenuEntry::Submenu { .. } => dimensions::INPUT_HEIGHT,
+        MenuEntry::Group { .. } => GROUP_HEADER_HEIGHT,
+    }
+}
+
+/// Fixed height of an option row: two lines when it has a description
+fn option_row_height(option: &DropdownOption) -> f32 {
+    if option.description.is_some() {
+        RICH_ITEM_HEIGHT
+    } else {
+        dimensions::INPUT_HEIGHT
+    }
+}
+
+/// Height of fixed-height rows for options with a description line
+const RICH_ITEM_HEIGHT: f32 = 52.0;
+
+/// Largest height of a submenu panel before it scrolls
+const SUBMENU_MAX_HEIGHT: f32 = 200.0;
+
+/// Narrowest a submenu panel gets
+const SUBMENU_MIN_WIDTH: f32 = 140.0;
+
 impl LayoutBuilder for DropdownBuilder {
     fn node(mut self, node: Node) -> Self {
         self.base.node = node;
@@ -263,3 +734,26 @@ impl LayoutBuilder for DropdownBuilder {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_options_with_a_description_get_tall_rows() {
+        let builder = DropdownBuilder::new(vec![
+            "Fast".into(),
+            "Accurate".into(),
+            "Balanced".into(),
+        ])
+            .option_description(1, "Slower, for hard problems")
+            .max_visible_items(3);
+
+        let limit = builder.menu_limit().unwrap();
+        assert_eq!(limit.viewport_height, 2.0 * dimensions::INPUT_HEIGHT + RICH_ITEM_HEIGHT);
+
+        // Without descriptions every row stays single-line
+        let plain = DropdownBuilder::new(vec!["Fast".into(), "Accurate".into()]).max_visible_items(2);
+        assert_eq!(plain.menu_limit().unwrap().viewport_height, 2.0 * dimensions::INPUT_HEIGHT);
+    }
+}
@@ -6,6 +6,9 @@ mod systems;
 mod types;
 
 // Public exports
-pub use builder::DropdownBuilder;
+pub use builder::{DropdownBuilder, DropdownSubmenuBuilder};
 pub use plugin::DropdownPlugin;
-pub use types::{Dropdown, DropdownButton, DropdownMenu, DropdownOption, DropdownState, DropdownData};
+pub use types::{
+    Dropdown, DropdownButton, DropdownMenu, DropdownMenuLimit, DropdownOption, DropdownState, DropdownData, DropdownNavigation, DropdownSearch,
+    DropdownButtonContent, DropdownFaceRenderFn, DropdownSubmenu, DropdownSubmenuItem, DropdownGroupHeader,
+};
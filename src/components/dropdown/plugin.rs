@@ -2,16 +2,35 @@
 //! Dropdown plugin
 #![allow(missing_docs)]
 
+use bevy::prelude::*;
 use bevy_plugin_builder::define_plugin;
 use super::systems::*;
 
 /// Plugin for dropdown functionality
 define_plugin!(DropdownPlugin {
+    custom_init: |app: &mut App| {
+        // Searchable menus: focus the field on open, then filter the rows
+        // before the keyboard reads them
+        #[cfg(feature = "text_input")]
+        app.add_systems(Update, (
+            sync_dropdown_search_fields
+                .after(handle_dropdown_button_clicks)
+                .after(crate::components::text_input::native_input::handle_click_outside),
+            filter_dropdown_options
+                .after(sync_dropdown_search_fields)
+                .before(handle_dropdown_keyboard),
+        ));
+    },
     update: [
         handle_dropdown_button_clicks,
         handle_dropdown_option_clicks,
+        handle_dropdown_keyboard.after(handle_dropdown_button_clicks),
+        update_dropdown_button_face,
         close_dropdown_on_outside_click,
         update_dropdown_selection_highlights,
         update_dropdown_option_hover,
+        scroll_dropdown_selection_into_view,
+        update_dropdown_submenus,
+        sync_dropdown_escape_layers,
     ]
 });
@@ -1,14 +1,66 @@
 //! Systems for dropdown interaction
 
 use bevy::prelude::*;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::window::PrimaryWindow;
 use super::types::*;
 use crate::relationships::BelongsToDropdown;
+use crate::styles::dimensions;
+use crate::systems::escape::{EscapeLayer, EscapeStack};
+use crate::systems::popup::SubmenuHover;
+use crate::utils::geometry::node_logical_rect;
+
+#[cfg(feature = "text_input")]
+use crate::components::text_input::native_input::{
+    focus_input, unfocus_input, CursorVisual, SelectionState, SetTextInputValue, TextBuffer,
+};
+
+/// Seconds a submenu row must be hovered before its submenu opens
+const SUBMENU_OPEN_DELAY: f32 = 0.2;
+
+/// Seconds an open submenu stays up after the pointer moves to another row
+/// without heading toward it
+const SUBMENU_CLOSE_DELAY: f32 = 0.3;
+
+/// Background of a hovered row, and of a row whose submenu is open
+const ROW_HOVER: Color = Color::srgba(0.4, 0.6, 0.9, 0.4);
+
+/// Menu panels of a dropdown: the menu itself and any submenus
+type MenuPanels<'w, 's> = Query<
+    'w,
+    's,
+    (&'static mut Node, &'static BelongsToDropdown, Has<DropdownSubmenu>),
+    Or<(With<DropdownMenu>, With<DropdownSubmenu>)>,
+>;
+
+/// Show or hide the menu belonging to `dropdown`
+///
+/// Menus live in the popup layer, so they are found through
+/// [`BelongsToDropdown`] rather than the dropdown's children. Opening shows
+/// only the top-level menu; closing hides its submenus too.
+fn set_menu_display(
+    menus: &mut MenuPanels,
+    dropdown: Entity,
+    state: DropdownState,
+) {
+    for (mut menu_node, belongs_to, is_submenu) in menus.iter_mut() {
+        if belongs_to.0 != dropdown {
+            continue;
+        }
+
+        match state {
+            DropdownState::Open if !is_submenu => menu_node.display = Display::Flex,
+            DropdownState::Open => {}
+            DropdownState::Closed => menu_node.display = Display::None,
+        }
+    }
+}
 
 /// Handle dropdown button clicks to toggle menu
 pub fn handle_dropdown_button_clicks(
-    mut dropdowns: Query<(&mut DropdownState, &Children), With<Dropdown>>,
+    mut dropdowns: Query<&mut DropdownState, With<Dropdown>>,
     buttons: Query<(Entity, &Interaction), (With<DropdownButton>, Changed<Interaction>)>,
-    mut menus: Query<&mut Node, With<DropdownMenu>>,
+    mut menus: MenuPanels,
     mouse_button: Res<ButtonInput<MouseButton>>,
     parents: Query<&ChildOf>,
 ) {
@@ -16,7 +68,7 @@ pub fn handle_dropdown_button_clicks(
         if *interaction == Interaction::Pressed && mouse_button.just_pressed(MouseButton::Left) {
             // Find the dropdown this button belongs to
             if let Ok(child_of) = parents.get(button_entity) {
-                if let Ok((mut state, children)) = dropdowns.get_mut(child_of.parent()) {
+                if let Ok(mut state) = dropdowns.get_mut(child_of.parent()) {
                     // Toggle state
                     *state = match *state {
                         DropdownState::Closed => DropdownState::Open,
@@ -24,14 +76,7 @@ pub fn handle_dropdown_button_clicks(
                     };
 
                     // Update menu visibility
-                    for child in children.iter() {
-                        if let Ok(mut menu_node) = menus.get_mut(child) {
-                            menu_node.display = match *state {
-                                DropdownState::Open => Display::Flex,
-                                DropdownState::Closed => Display::None,
-                            };
-                        }
-                    }
+                    set_menu_display(&mut menus, child_of.parent(), *state);
                 }
             }
         }
@@ -40,89 +85,282 @@ pub fn handle_dropdown_button_clicks(
 
 /// Handle dropdown option selection
 pub fn handle_dropdown_option_clicks(
-    mut dropdowns: Query<(&mut DropdownData, &mut DropdownState, &Children), With<Dropdown>>,
-    options: Query<(&DropdownOption, &Interaction, &ChildOf), Changed<Interaction>>,
-    buttons: Query<&Children, With<DropdownButton>>,
-    mut texts: Query<&mut Text>,
-    mut menus: Query<&mut Node, With<DropdownMenu>>,
+    mut dropdowns: Query<(&mut DropdownData, &mut DropdownState), With<Dropdown>>,
+    options: Query<(&DropdownOption, &Interaction, &BelongsToDropdown), Changed<Interaction>>,
+    mut menus: MenuPanels,
     mouse_button: Res<ButtonInput<MouseButton>>,
-    parents: Query<&ChildOf>,
 ) {
-    for (option, interaction, option_child_of) in options.iter() {
+    for (option, interaction, belongs_to) in options.iter() {
+        if option.disabled {
+            continue;
+        }
+
         if *interaction == Interaction::Pressed && mouse_button.just_pressed(MouseButton::Left) {
-            // Find the dropdown this option belongs to
-            // Option -> Menu -> Dropdown
-            if let Ok(menu_child_of) = parents.get(option_child_of.parent()) {
-                if let Ok((mut data, mut state, dropdown_children)) = dropdowns.get_mut(menu_child_of.parent()) {
-                    // Update selected index
-                    data.selected_index = Some(option.index);
-                    let new_text = data.display_text().to_string();
-
-                    // Find the button and update its text (first Text child)
-                    for child in dropdown_children.iter() {
-                        if let Ok(button_children) = buttons.get(child) {
-                            // First child of button is the selected value text
-                            if let Some(first_text_entity) = button_children.iter().next() {
-                                if let Ok(mut text) = texts.get_mut(first_text_entity) {
-                                    **text = new_text;
-                                }
-                            }
-                            break;
-                        }
-                    }
-
-                    // Close the menu
-                    *state = DropdownState::Closed;
-                    for child in dropdown_children.iter() {
-                        if let Ok(mut menu_node) = menus.get_mut(child) {
-                            menu_node.display = Display::None;
-                        }
-                    }
+            if let Ok((mut data, mut state)) = dropdowns.get_mut(belongs_to.0) {
+                // Update selected index (the button face follows via update_dropdown_button_face)
+                data.selected_index = Some(option.index);
+
+                // Close the menu
+                *state = DropdownState::Closed;
+                set_menu_display(&mut menus, belongs_to.0, DropdownState::Closed);
+            }
+        }
+    }
+}
+
+/// Drive open menus from the keyboard (see [`DropdownNavigation`])
+///
+/// Opening a menu highlights the current selection. Up/Down move the
+/// highlight, Enter selects it, Escape closes without changing anything
+/// (when the menu is the topmost [`EscapeStack`] layer), and typed letters
+/// jump to a matching option.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_dropdown_keyboard(
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    escape: Res<EscapeStack>,
+    mut key_events: MessageReader<KeyboardInput>,
+    mut dropdowns: Query<
+        (Entity, &mut DropdownState, &mut DropdownData, &mut DropdownNavigation, Has<DropdownSearch>),
+        With<Dropdown>,
+    >,
+    menu_rows: Query<(&BelongsToDropdown, &Children), With<DropdownMenu>>,
+    options: Query<(&DropdownOption, &Node)>,
+    mut menus: MenuPanels,
+) {
+    let letters: Vec<char> = key_events.read()
+        .filter(|event| event.state.is_pressed())
+        .filter_map(|event| match &event.logical_key {
+            Key::Character(text) => Some(text.chars().filter(|letter| letter.is_alphanumeric()).collect::<Vec<_>>()),
+            _ => None,
+        })
+        .flatten()
+        .collect();
+
+    for (entity, mut state, mut data, mut navigation, searchable) in dropdowns.iter_mut() {
+        if *state != DropdownState::Open {
+            if navigation.highlighted.is_some() {
+                navigation.highlighted = None;
+            }
+            continue;
+        }
+        if state.is_changed() {
+            navigation.reset(data.selected_index);
+        }
+        navigation.bypass_change_detection().tick(time.delta_secs());
+
+        // Enabled top-level options not hidden by a search, in menu order
+        let selectable: Vec<usize> = menu_rows.iter()
+            .filter(|(belongs_to, _)| belongs_to.0 == entity)
+            .flat_map(|(_, children)| children.iter())
+            .filter_map(|child| options.get(child).ok())
+            .filter(|(option, node)| !option.disabled && node.display != Display::None)
+            .map(|(option, _)| option.index)
+            .collect();
+
+        if escape.closes(entity) {
+            *state = DropdownState::Closed;
+        } else if keyboard.just_pressed(KeyCode::Enter) {
+            if let Some(index) = navigation.highlighted {
+                data.selected_index = Some(index);
+            }
+            *state = DropdownState::Closed;
+        } else if keyboard.just_pressed(KeyCode::ArrowDown) {
+            navigation.move_highlight(&selectable, true);
+        } else if keyboard.just_pressed(KeyCode::ArrowUp) {
+            navigation.move_highlight(&selectable, false);
+        } else if !searchable {
+            // Letters go to the search field instead, when there is one
+            for &letter in &letters {
+                navigation.type_ahead(letter, &data.options, &selectable);
+            }
+        }
+
+        if *state == DropdownState::Closed {
+            set_menu_display(&mut menus, entity, DropdownState::Closed);
+        }
+    }
+}
+
+/// Put open menus on the [`EscapeStack`], and take closed ones off
+pub fn sync_dropdown_escape_layers(
+    mut commands: Commands,
+    dropdowns: Query<(Entity, &DropdownState), (With<Dropdown>, Changed<DropdownState>)>,
+) {
+    for (entity, state) in dropdowns.iter() {
+        if *state == DropdownState::Open {
+            commands.entity(entity).try_insert(EscapeLayer::new(dimensions::Z_INDEX_POPUP));
+        } else {
+            commands.entity(entity).try_remove::<EscapeLayer>();
+        }
+    }
+}
+
+/// Focus a searchable menu's search field when it opens, and clear it
+/// (bringing every option back) when it closes
+#[cfg(feature = "text_input")]
+pub fn sync_dropdown_search_fields(
+    dropdowns: Query<(&DropdownState, &DropdownSearch), Changed<DropdownState>>,
+    mut fields: Query<(Entity, &mut TextBuffer, &mut SelectionState, &mut CursorVisual)>,
+    mut set_values: MessageWriter<SetTextInputValue>,
+) {
+    for (state, search) in dropdowns.iter() {
+        if *state == DropdownState::Open {
+            // Focus the field as a click would, taking focus from other inputs
+            for (entity, mut buffer, mut selection, mut cursor_visual) in fields.iter_mut() {
+                if entity == search.field {
+                    focus_input(&mut buffer, &mut cursor_visual);
+                } else if buffer.is_focused {
+                    unfocus_input(&mut buffer, &mut selection, &mut cursor_visual);
                 }
             }
+            continue;
+        }
+
+        let Ok((_, mut buffer, mut selection, mut cursor_visual)) = fields.get_mut(search.field) else {
+            continue;
+        };
+        unfocus_input(&mut buffer, &mut selection, &mut cursor_visual);
+        if !buffer.content.is_empty() {
+            set_values.write(SetTextInputValue::new(search.field, "").silent());
+        }
+    }
+}
+
+/// Hide the options of searchable dropdowns that don't match the search
+///
+/// Rows are hidden rather than despawned, so nothing about them (such as
+/// the selection) is lost. A submenu row stays visible while anything in
+/// its submenu matches. While a search is typed, the keyboard highlight
+/// moves to the first visible option.
+#[cfg(feature = "text_input")]
+pub fn filter_dropdown_options(
+    mut dropdowns: Query<(Entity, &DropdownState, &DropdownData, &DropdownSearch, &mut DropdownNavigation)>,
+    fields: Query<Ref<TextBuffer>>,
+    mut rows: Query<(
+        &mut Node,
+        &BelongsToDropdown,
+        Option<&DropdownOption>,
+        Option<&DropdownSubmenuItem>,
+        Option<&DropdownGroupHeader>,
+    )>,
+    menus: Query<(&BelongsToDropdown, &Children), With<DropdownMenu>>,
+    children: Query<&Children>,
+    options: Query<&DropdownOption>,
+) {
+    for (entity, state, data, search, mut navigation) in dropdowns.iter_mut() {
+        let Ok(field) = fields.get(search.field) else {
+            continue;
+        };
+        if !field.is_changed() {
+            continue;
+        }
+        let query = field.content.as_str();
+
+        for (mut node, belongs_to, option, submenu_item, header) in rows.iter_mut() {
+            if belongs_to.0 != entity {
+                continue;
+            }
+            let visible = if let Some(option) = option {
+                data.matches(option.index, query)
+            } else if let Some(item) = submenu_item {
+                children.iter_descendants(item.submenu)
+                    .filter_map(|row| options.get(row).ok())
+                    .any(|option| data.matches(option.index, query))
+            } else if let Some(header) = header {
+                // A header stays while anything in its group matches
+                header.options.iter().any(|index| data.matches(*index, query))
+            } else {
+                continue;
+            };
+            let display = if visible { Display::Flex } else { Display::None };
+            if node.display != display {
+                node.display = display;
+            }
+        }
+
+        if *state != DropdownState::Open || query.trim().is_empty() {
+            continue;
+        }
+        let first_match = menus.iter()
+            .filter(|(belongs_to, _)| belongs_to.0 == entity)
+            .flat_map(|(_, rows)| rows.iter())
+            .filter_map(|row| options.get(row).ok())
+            .find(|option| !option.disabled && data.matches(option.index, query))
+            .map(|option| option.index);
+        if navigation.highlighted != first_match {
+            navigation.highlighted = first_match;
+        }
+    }
+}
+
+/// Rebuild the button face when the selection changes
+///
+/// Mirrors the selected option's icon, color swatch and label (or a custom
+/// renderer's output) onto the [`DropdownButton`]. Also picks up selections
+/// made by writing to [`DropdownData`] directly.
+pub fn update_dropdown_button_face(
+    mut commands: Commands,
+    dropdowns: Query<(Entity, Ref<DropdownData>), Changed<DropdownData>>,
+    options: Query<(&DropdownOption, &BelongsToDropdown)>,
+    faces: Query<(Entity, &DropdownButtonContent, &BelongsToDropdown)>,
+) {
+    for (dropdown_entity, data) in dropdowns.iter() {
+        // The builder already rendered the initial face
+        if data.is_added() {
+            continue;
+        }
+
+        let label = data.display_text().to_string();
+        let selected = data.selected_index.and_then(|index| {
+            options.iter()
+                .find(|(option, belongs_to)| belongs_to.0 == dropdown_entity && option.index == index)
+                .map(|(option, _)| option.clone())
+        });
+
+        for (face_entity, face, belongs_to) in faces.iter() {
+            if belongs_to.0 != dropdown_entity {
+                continue;
+            }
+
+            let face = *face;
+            let label = label.clone();
+            let selected = selected.clone();
+            commands.entity(face_entity)
+                .despawn_related::<Children>()
+                .with_children(|content| face.render(content, &label, selected.as_ref()));
         }
     }
 }
 
-/// Update all option backgrounds when dropdown selection changes
+/// Update all option backgrounds when the selection or keyboard highlight changes
 pub fn update_dropdown_selection_highlights(
-    changed_dropdowns: Query<(Entity, &DropdownData), Changed<DropdownData>>,
-    mut options: Query<(&DropdownOption, &mut BackgroundColor, &Interaction, &ChildOf), With<DropdownOption>>,
-    parents: Query<&ChildOf>,
+    changed_dropdowns: Query<
+        (Entity, &DropdownData, Option<&DropdownNavigation>),
+        Or<(Changed<DropdownData>, Changed<DropdownNavigation>)>,
+    >,
+    mut options: Query<(&DropdownOption, &mut BackgroundColor, &Interaction, &BelongsToDropdown)>,
 ) {
-    for (dropdown_entity, data) in changed_dropdowns.iter() {
+    for (dropdown_entity, data, navigation) in changed_dropdowns.iter() {
         // Update all options belonging to this dropdown
-        for (option, mut bg_color, interaction, option_child_of) in options.iter_mut() {
-            // Check if this option belongs to the changed dropdown
-            if let Ok(menu_child_of) = parents.get(option_child_of.parent()) {
-                if menu_child_of.parent() == dropdown_entity {
-                    let is_selected = Some(option.index) == data.selected_index;
-
-                    // Update background based on interaction state and selection
-                    *bg_color = match interaction {
-                        Interaction::Hovered => BackgroundColor(Color::srgba(0.4, 0.6, 0.9, 0.4)),
-                        Interaction::Pressed => BackgroundColor(Color::srgba(0.3, 0.5, 0.8, 0.5)),
-                        Interaction::None => {
-                            if is_selected {
-                                BackgroundColor(Color::srgba(0.3, 0.5, 0.8, 0.3))
-                            } else {
-                                BackgroundColor(Color::NONE)
-                            }
-                        }
-                    };
-                }
+        for (option, mut bg_color, interaction, belongs_to) in options.iter_mut() {
+            if belongs_to.0 != dropdown_entity {
+                continue;
             }
+
+            let highlighted = navigation.is_some_and(|navigation| navigation.highlighted == Some(option.index));
+            *bg_color = BackgroundColor(option_background(option, interaction, data, highlighted));
         }
     }
 }
 
 /// Close dropdown when clicking outside
 pub fn close_dropdown_on_outside_click(
-    mut dropdowns: Query<(Entity, &mut DropdownState, &Children), With<Dropdown>>,
+    mut dropdowns: Query<(Entity, &mut DropdownState), With<Dropdown>>,
     pressed_entities: Query<(Entity, &Interaction), Changed<Interaction>>,
     buttons: Query<&ChildOf, With<DropdownButton>>,
     dropdown_parts: Query<&BelongsToDropdown>,
-    mut menus: Query<&mut Node, With<DropdownMenu>>,
+    mut menus: MenuPanels,
     mouse_button: Res<ButtonInput<MouseButton>>,
 ) {
     // Only process on left click
@@ -148,7 +386,7 @@ pub fn close_dropdown_on_outside_click(
     }
 
     // Check each open dropdown
-    for (dropdown_entity, mut state, children) in dropdowns.iter_mut() {
+    for (dropdown_entity, mut state) in dropdowns.iter_mut() {
         // Only process if dropdown is open
         if *state != DropdownState::Open {
             continue;
@@ -178,10 +416,72 @@ pub fn close_dropdown_on_outside_click(
         // If clicked outside, close the dropdown
         if !clicked_inside {
             *state = DropdownState::Closed;
-            for child in children.iter() {
-                if let Ok(mut menu_node) = menus.get_mut(child) {
-                    menu_node.display = Display::None;
+            set_menu_display(&mut menus, dropdown_entity, DropdownState::Closed);
+        }
+    }
+}
+
+/// Scroll the selected option into view when a height-limited menu opens,
+/// and keep the keyboard highlight in view as it moves
+///
+/// Options inside submenus are left alone.
+pub fn scroll_dropdown_selection_into_view(
+    dropdowns: Query<
+        (Entity, &DropdownState, &DropdownData, Option<&DropdownNavigation>, Has<DropdownSearch>),
+        Or<(Changed<DropdownState>, Changed<DropdownData>, Changed<DropdownNavigation>)>,
+    >,
+    mut menus: Query<(&DropdownMenuLimit, &BelongsToDropdown, &Children, &mut ScrollPosition), With<DropdownMenu>>,
+    rows: Query<
+        (Option<&DropdownOption>, &Node),
+        Or<(With<DropdownOption>, With<DropdownSubmenuItem>, With<DropdownGroupHeader>)>,
+    >,
+) {
+    for (dropdown_entity, state, data, navigation, searchable) in dropdowns.iter() {
+        if *state != DropdownState::Open {
+            continue;
+        }
+        let highlighted = navigation.and_then(|navigation| navigation.highlighted);
+        let Some(index) = highlighted.or(data.selected_index) else {
+            continue;
+        };
+
+        for (limit, belongs_to, children, mut position) in menus.iter_mut() {
+            if belongs_to.0 != dropdown_entity {
+                continue;
+            }
+            // Height of the visible rows above the option. Rows in a limited
+            // menu have fixed heights, which differ between group headers,
+            // single-line options and options with a description
+            let mut above = 0.0;
+            let mut item_height = None;
+            for (option, node) in children.iter().filter_map(|child| rows.get(child).ok()) {
+                if node.display == Display::None {
+                    continue;
                 }
+                let height = match node.height {
+                    Val::Px(height) => height,
+                    _ => 0.0,
+                };
+                if option.is_some_and(|option| option.index == index) {
+                    item_height = Some(height);
+                    break;
+                }
+                above += height;
+            }
+            let Some(item_height) = item_height else {
+                continue;
+            };
+
+            // Rows start below the search field of a searchable menu
+            let rows_top = if searchable { SEARCH_ROW_HEIGHT } else { 0.0 };
+            let item_top = rows_top + above;
+            let item_bottom = item_top + item_height;
+            let viewport = rows_top + limit.viewport_height;
+
+            if item_top < position.0.y {
+                position.0.y = item_top;
+            } else if item_bottom > position.0.y + viewport {
+                position.0.y = item_bottom - viewport;
             }
         }
     }
@@ -189,28 +489,164 @@ pub fn close_dropdown_on_outside_click(
 
 /// Update dropdown option hover effects
 pub fn update_dropdown_option_hover(
-    mut options: Query<(&Interaction, &DropdownOption, &mut BackgroundColor, &ChildOf), (With<DropdownOption>, Changed<Interaction>)>,
-    dropdowns: Query<&DropdownData>,
-    parents: Query<&ChildOf>,
+    mut options: Query<(&Interaction, &DropdownOption, &mut BackgroundColor, &BelongsToDropdown), Changed<Interaction>>,
+    dropdowns: Query<(&DropdownData, Option<&DropdownNavigation>)>,
 ) {
-    for (interaction, option, mut bg_color, option_child_of) in options.iter_mut() {
+    for (interaction, option, mut bg_color, belongs_to) in options.iter_mut() {
         // Find the dropdown to check if this option is selected
-        if let Ok(menu_child_of) = parents.get(option_child_of.parent()) {
-            if let Ok(data) = dropdowns.get(menu_child_of.parent()) {
-                let is_selected = Some(option.index) == data.selected_index;
-
-                *bg_color = match interaction {
-                    Interaction::Hovered => BackgroundColor(Color::srgba(0.4, 0.6, 0.9, 0.4)),
-                    Interaction::Pressed => BackgroundColor(Color::srgba(0.3, 0.5, 0.8, 0.5)),
-                    Interaction::None => {
-                        if is_selected {
-                            BackgroundColor(Color::srgba(0.3, 0.5, 0.8, 0.3))
-                        } else {
-                            BackgroundColor(Color::NONE)
-                        }
-                    }
-                };
+        if let Ok((data, navigation)) = dropdowns.get(belongs_to.0) {
+            let highlighted = navigation.is_some_and(|navigation| navigation.highlighted == Some(option.index));
+            *bg_color = BackgroundColor(option_background(option, interaction, data, highlighted));
+        }
+    }
+}
+
+/// Background of an option row for its interaction state, selection and
+/// keyboard highlight
+fn option_background(
+    option: &DropdownOption,
+    interaction: &Interaction,
+    data: &DropdownData,
+    highlighted: bool,
+) -> Color {
+    // Disabled options never show hover/press feedback
+    let interaction = if option.disabled { &Interaction::None } else { interaction };
+
+    match interaction {
+        Interaction::Hovered => ROW_HOVER,
+        Interaction::Pressed => Color::srgba(0.3, 0.5, 0.8, 0.5),
+        Interaction::None if highlighted => ROW_HOVER,
+        Interaction::None if Some(option.index) == data.selected_index => Color::srgba(0.3, 0.5, 0.8, 0.3),
+        Interaction::None => Color::NONE,
+    }
+}
+
+/// Open and close dropdown submenus as the pointer moves through the menu
+///
+/// Works like context menu submenus: hovering a submenu row opens its panel
+/// after a short delay, and an open panel stays up while the pointer is
+/// inside it or heading toward it across other rows. Panels of a closed
+/// menu forget their open submenu, so the menu reopens collapsed.
+pub fn update_dropdown_submenus(
+    time: Res<Time>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut panels: Query<
+        (Entity, &mut Node, &mut SubmenuHover, Option<&Children>),
+        Or<(With<DropdownMenu>, With<DropdownSubmenu>)>,
+    >,
+    items: Query<(&Interaction, Option<&DropdownSubmenuItem>)>,
+    bounds: Query<(&GlobalTransform, &ComputedNode)>,
+    mut submenu_rows: Query<(&DropdownSubmenuItem, &Interaction, &mut BackgroundColor)>,
+) {
+    let cursor = window_query.single().ok().and_then(|window| window.cursor_position());
+    let delta = time.delta_secs();
+
+    // A panel whose own submenu is open still has the pointer somewhere below it
+    let panels_with_open_submenu: Vec<Entity> = panels.iter()
+        .filter(|(_, node, hover, _)| node.display != Display::None && hover.open.is_some())
+        .map(|(entity, ..)| entity)
+        .collect();
+
+    let mut display_changes = Vec::new();
+    for (_, node, mut hover, children) in panels.iter_mut() {
+        if node.display == Display::None {
+            if let Some((_, submenu)) = hover.open {
+                display_changes.push((submenu, Display::None));
+                *hover = SubmenuHover::default();
+            }
+            continue;
+        }
+
+        let hovered = children.into_iter().flatten().find_map(|child| {
+            let (interaction, submenu_item) = items.get(*child).ok()?;
+            (*interaction != Interaction::None).then_some((*child, submenu_item.map(|item| item.submenu)))
+        });
+
+        let open_rect = hover.open
+            .and_then(|(_, submenu)| bounds.get(submenu).ok())
+            .map(|(transform, computed)| node_logical_rect(computed, transform));
+        let in_submenu = hover.open.is_some_and(|(_, submenu)| panels_with_open_submenu.contains(&submenu))
+            || open_rect.zip(cursor).is_some_and(|(rect, cursor)| rect.contains(cursor));
+
+        let switch = hover.update(
+            hovered.map(|(entity, submenu)| (entity, submenu.is_some())),
+            in_submenu,
+            open_rect,
+            cursor,
+            delta,
+            SUBMENU_OPEN_DELAY,
+            SUBMENU_CLOSE_DELAY,
+        );
+
+        if let Some(switch) = switch {
+            if let Some((_, submenu)) = switch.close {
+                display_changes.push((submenu, Display::None));
+            }
+            if let Some((item, Some(submenu))) = hovered.filter(|(item, _)| switch.open == Some(*item)) {
+                display_changes.push((submenu, Display::Flex));
+                hover.open = Some((item, submenu));
             }
         }
     }
+
+    for (panel, display) in display_changes {
+        if let Ok((_, mut node, _, _)) = panels.get_mut(panel) {
+            node.display = display;
+        }
+    }
+
+    // Keep the row of an open submenu highlighted after the pointer leaves it
+    for (row, interaction, mut background) in submenu_rows.iter_mut() {
+        let open = panels.get(row.submenu).is_ok_and(|(_, node, _, _)| node.display != Display::None);
+        let color = if open || *interaction != Interaction::None { ROW_HOVER } else { Color::NONE };
+        background.set_if_neq(BackgroundColor(color));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scrolling_accounts_for_two_line_rows() {
+        let mut app = App::new();
+        app.add_systems(Update, scroll_dropdown_selection_into_view);
+
+        let dropdown = app.world_mut().spawn((
+            DropdownState::Open,
+            DropdownData {
+                options: vec!["Fast".into(), "Accurate".into(), "Balanced".into(), "Legacy".into()],
+                selected_index: Some(3),
+                placeholder: String::new(),
+            },
+        )).id();
+
+        // Options 1 and 3 have a description and are taller
+        let heights = [36.0, 52.0, 36.0, 52.0];
+        let menu = app.world_mut().spawn((
+            DropdownMenu,
+            DropdownMenuLimit {
+                visible_items: 2,
+                viewport_height: 36.0 + 52.0,
+            },
+            BelongsToDropdown(dropdown),
+            ScrollPosition::default(),
+        )).with_children(|menu| {
+            for (index, height) in heights.into_iter().enumerate() {
+                menu.spawn((
+                    DropdownOption::new(index),
+                    Node {
+                        height: Val::Px(height),
+                        ..default()
+                    },
+                ));
+            }
+        }).id();
+
+        app.update();
+
+        // The last option's bottom edge lines up with the viewport's
+        let position = app.world().get::<ScrollPosition>(menu).unwrap();
+        assert_eq!(position.0.y, 36.0 + 52.0 + 36.0 + 52.0 - (36.0 + 52.0));
+    }
 }
@@ -16,11 +16,265 @@ pub struct DropdownButton;
 #[derive(Component, Debug, Clone, Copy)]
 pub struct DropdownMenu;
 
-/// Marker component for individual dropdown options
+/// Row in a dropdown menu that opens a nested submenu on hover
 #[derive(Component, Debug, Clone, Copy)]
+pub struct DropdownSubmenuItem {
+    /// The submenu panel this row opens
+    pub submenu: Entity,
+}
+
+/// Panel of a dropdown submenu, shown beside its [`DropdownSubmenuItem`]
+///
+/// Hidden until its row has been hovered for a moment, and hidden again
+/// with the rest of the menu when the dropdown closes.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DropdownSubmenu {
+    /// The row that opens this submenu
+    pub item: Entity,
+}
+
+/// Non-selectable header row above a group of options in a dropdown menu
+///
+/// Added by [`DropdownBuilder::group`](super::DropdownBuilder::group).
+/// Headers are skipped by keyboard navigation and type-ahead. In a
+/// searchable menu a header stays visible while any of its options match.
+#[derive(Component, Debug, Clone)]
+pub struct DropdownGroupHeader {
+    /// Indices of the options in the group, including those in its submenus
+    pub options: Vec<usize>,
+}
+
+/// Height of a group header row in a menu with fixed-height rows
+pub(crate) const GROUP_HEADER_HEIGHT: f32 = 28.0;
+
+/// One row of a dropdown menu: an option, a submenu of further rows, or a
+/// group of rows under a header
+#[derive(Debug, Clone)]
+pub(crate) enum MenuEntry {
+    /// The option at this index in [`DropdownData::options`]
+    Option(usize),
+    /// A nested submenu
+    Submenu {
+        /// Text of the row that opens it
+        label: String,
+        /// Rows of the submenu
+        entries: Vec<MenuEntry>,
+    },
+    /// A header followed by its rows, inline in the same menu
+    Group {
+        /// Text of the header
+        label: String,
+        /// Rows of the group
+        entries: Vec<MenuEntry>,
+    },
+}
+
+impl MenuEntry {
+    /// Indices of every option in `entries`, nested ones included
+    pub(crate) fn option_indices(entries: &[MenuEntry]) -> Vec<usize> {
+        entries.iter()
+            .flat_map(|entry| match entry {
+                MenuEntry::Option(index) => vec![*index],
+                MenuEntry::Submenu { entries, .. } | MenuEntry::Group { entries, .. } => {
+                    Self::option_indices(entries)
+                }
+            })
+            .collect()
+    }
+
+    /// The rows a menu shows inline, top to bottom: options, submenu rows,
+    /// and each group (standing for its header row) followed by its rows
+    pub(crate) fn inline_rows(entries: &[MenuEntry]) -> Vec<&MenuEntry> {
+        entries.iter()
+            .flat_map(|entry| match entry {
+                MenuEntry::Group { entries, .. } => {
+                    std::iter::once(entry).chain(Self::inline_rows(entries)).collect()
+                }
+                _ => vec![entry],
+            })
+            .collect()
+    }
+
+    /// Height of a menu's rows down to its `visible_items`-th option or
+    /// submenu row, counting the group headers in between
+    pub(crate) fn visible_height(
+        entries: &[MenuEntry],
+        visible_items: usize,
+        row_height: impl Fn(&MenuEntry) -> f32,
+    ) -> f32 {
+        let mut height = 0.0;
+        let mut items = 0;
+        for row in Self::inline_rows(entries) {
+            if items == visible_items {
+                break;
+            }
+            if !matches!(row, MenuEntry::Group { .. }) {
+                items += 1;
+            }
+            height += row_height(row);
+        }
+        height
+    }
+}
+
+/// Caps a dropdown menu at a number of visible options; the rest scroll
+///
+/// Added by [`DropdownBuilder::max_visible_items`](super::DropdownBuilder::max_visible_items).
+/// Rows get a fixed height so the selection can be scrolled into view:
+/// single-line options stay compact, options with a description are two
+/// lines tall.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DropdownMenuLimit {
+    /// Number of options visible at once
+    pub visible_items: usize,
+    /// Height of the rows shown before the menu scrolls, including any
+    /// group headers among them
+    pub viewport_height: f32,
+}
+
+/// Component for individual dropdown options
+#[derive(Component, Debug, Clone, Default)]
 pub struct DropdownOption {
     /// Index of this option in the dropdown
     pub index: usize,
+    /// Optional icon (emoji or symbol) shown before the label
+    pub icon: Option<String>,
+    /// Optional color swatch shown before the label
+    pub color: Option<Color>,
+    /// Optional second line under the label in the menu
+    pub description: Option<String>,
+    /// Disabled options are greyed out and cannot be selected
+    pub disabled: bool,
+}
+
+impl DropdownOption {
+    /// Create a plain option at the given index
+    pub fn new(index: usize) -> Self {
+        Self {
+            index,
+            ..default()
+        }
+    }
+}
+
+/// Function that renders the selected option onto the dropdown button.
+///
+/// Receives the face container to spawn into, the label to show, and the
+/// selected option (`None` while the placeholder is shown).
+pub type DropdownFaceRenderFn = fn(&mut ChildSpawnerCommands, &str, Option<&DropdownOption>);
+
+/// Container inside the [`DropdownButton`] showing the current selection.
+///
+/// Its children are rebuilt whenever the selection changes, so the button
+/// always mirrors the selected option's icon, swatch and label.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DropdownButtonContent {
+    /// Text color used by the default rendering
+    pub text_color: Color,
+    /// Custom renderer replacing the default icon + swatch + label layout
+    pub render: Option<DropdownFaceRenderFn>,
+}
+
+impl DropdownButtonContent {
+    /// Spawn the face for `label` / `option` into `face`
+    pub fn render(&self, face: &mut ChildSpawnerCommands, label: &str, option: Option<&DropdownOption>) {
+        match self.render {
+            Some(render) => render(face, label, option),
+            None => spawn_option_content(face, label, option, self.text_color),
+        }
+    }
+}
+
+/// Default option layout: `[swatch] [icon] label`
+pub(crate) fn spawn_option_content(
+    parent: &mut ChildSpawnerCommands,
+    label: &str,
+    option: Option<&DropdownOption>,
+    text_color: Color,
+) {
+    spawn_option_decorations(parent, option, text_color);
+    parent.spawn(option_label(label, text_color));
+}
+
+/// Menu row layout: as on the button face, but an option with a description
+/// shows it on a second, smaller line under the label
+pub(crate) fn spawn_menu_option_content(
+    parent: &mut ChildSpawnerCommands,
+    label: &str,
+    option: Option<&DropdownOption>,
+    text_color: Color,
+    description_color: Color,
+) {
+    use crate::styles::dimensions;
+
+    let Some(description) = option.and_then(|option| option.description.clone()) else {
+        spawn_option_content(parent, label, option, text_color);
+        return;
+    };
+
+    spawn_option_decorations(parent, option, text_color);
+    parent.spawn(Node {
+        flex_direction: FlexDirection::Column,
+        row_gap: Val::Px(2.0),
+        ..default()
+    }).with_children(|lines| {
+        lines.spawn(option_label(label, text_color));
+        lines.spawn((
+            Text::new(description),
+            TextFont {
+                font_size: dimensions::FONT_SIZE_SMALL,
+                ..default()
+            },
+            TextColor(description_color),
+        ));
+    });
+}
+
+/// The label text of an option
+fn option_label(label: &str, text_color: Color) -> impl Bundle {
+    use crate::styles::dimensions;
+
+    (
+        Text::new(label),
+        TextFont {
+            font_size: dimensions::FONT_SIZE_NORMAL,
+            ..default()
+        },
+        TextColor(text_color),
+    )
+}
+
+/// The swatch and icon shown before an option's label
+fn spawn_option_decorations(
+    parent: &mut ChildSpawnerCommands,
+    option: Option<&DropdownOption>,
+    text_color: Color,
+) {
+    use crate::styles::dimensions;
+
+    if let Some(color) = option.and_then(|option| option.color) {
+        parent.spawn((
+            Node {
+                width: Val::Px(14.0),
+                height: Val::Px(14.0),
+                flex_shrink: 0.0,
+                ..default()
+            },
+            BackgroundColor(color),
+            BorderRadius::all(Val::Px(3.0)),
+        ));
+    }
+
+    if let Some(icon) = option.and_then(|option| option.icon.clone()) {
+        parent.spawn((
+            Text::new(icon),
+            TextFont {
+                font_size: dimensions::FONT_SIZE_NORMAL,
+                ..default()
+            },
+            TextColor(text_color),
+        ));
+    }
 }
 
 /// State of the dropdown (open or closed)
@@ -61,6 +315,118 @@ impl DropdownData {
     pub fn display_text(&self) -> &str {
         self.selected_value().unwrap_or(&self.placeholder)
     }
+
+    /// Whether the option at `index` matches a search `query`
+    ///
+    /// Matches labels containing the query, ignoring case and surrounding
+    /// whitespace; an empty query matches everything.
+    pub fn matches(&self, index: usize, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        self.options.get(index).is_some_and(|label| label.to_lowercase().contains(&query))
+    }
+}
+
+/// Links a searchable dropdown to the search field at the top of its menu
+///
+/// Added by [`DropdownBuilder::searchable`](super::DropdownBuilder::searchable).
+/// Typing in the field hides the options that don't
+/// [match](DropdownData::matches); closing the menu clears the field and
+/// brings them all back. The selection only changes when an option is
+/// picked.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DropdownSearch {
+    /// The search field's text input
+    pub field: Entity,
+}
+
+/// Height of the row holding a searchable menu's search field
+pub(crate) const SEARCH_ROW_HEIGHT: f32 = crate::styles::dimensions::INPUT_HEIGHT + 8.0;
+
+/// Keyboard state of a dropdown's open menu
+///
+/// While the menu is open, Up/Down move the highlight (wrapping around),
+/// Enter selects the highlighted option and Escape closes the menu without
+/// changing the selection. Typing letters jumps to the first option whose
+/// label starts with them; the typed prefix resets after a short pause.
+/// Disabled options and options inside submenus are skipped.
+#[derive(Component, Debug, Clone, Default)]
+pub struct DropdownNavigation {
+    /// Option highlighted with the keyboard (an index into
+    /// [`DropdownData::options`])
+    pub highlighted: Option<usize>,
+    /// Letters typed since the last pause
+    pub(crate) typed: String,
+    /// Seconds since the last typed letter
+    pub(crate) idle: f32,
+}
+
+impl DropdownNavigation {
+    /// Seconds without typing after which type-ahead starts a new prefix
+    pub const TYPE_AHEAD_RESET: f32 = 0.8;
+
+    /// Move the highlight to the next (or previous) of `selectable`, given
+    /// in menu order, wrapping around at either end
+    pub fn move_highlight(&mut self, selectable: &[usize], forward: bool) {
+        if selectable.is_empty() {
+            return;
+        }
+
+        let count = selectable.len();
+        let current = self.highlighted.and_then(|index| selectable.iter().position(|&option| option == index));
+        let next = match (current, forward) {
+            (Some(position), true) => (position + 1) % count,
+            (Some(position), false) => (position + count - 1) % count,
+            (None, true) => 0,
+            (None, false) => count - 1,
+        };
+        self.highlighted = Some(selectable[next]);
+    }
+
+    /// Add a typed letter and highlight the first of `selectable` whose
+    /// label in `options` starts with the letters typed so far
+    ///
+    /// Matching ignores case. Pressing the same letter repeatedly cycles
+    /// through the options starting with it.
+    pub fn type_ahead(&mut self, letter: char, options: &[String], selectable: &[usize]) {
+        self.idle = 0.0;
+        self.typed.extend(letter.to_lowercase());
+
+        let mut letters = self.typed.chars();
+        let first = letters.next();
+        let repeated = letters.all(|letter| Some(letter) == first);
+        let prefix = if repeated { &self.typed[..first.map_or(0, char::len_utf8)] } else { self.typed.as_str() };
+
+        // A new or repeated letter looks past the current option; a longer
+        // prefix may still match it
+        let current = self.highlighted.and_then(|index| selectable.iter().position(|&option| option == index));
+        let start = match current {
+            Some(position) if repeated => position + 1,
+            Some(position) => position,
+            None => 0,
+        };
+
+        let count = selectable.len();
+        self.highlighted = (0..count)
+            .map(|offset| selectable[(start + offset) % count])
+            .find(|&index| {
+                options.get(index).is_some_and(|label| label.to_lowercase().starts_with(prefix))
+            })
+            .or(self.highlighted);
+    }
+
+    /// Advance the type-ahead timer, forgetting the typed prefix after a pause
+    pub(crate) fn tick(&mut self, delta: f32) {
+        self.idle += delta;
+        if self.idle >= Self::TYPE_AHEAD_RESET {
+            self.typed.clear();
+        }
+    }
+
+    /// Start over when the menu opens, highlighting `selected`
+    pub(crate) fn reset(&mut self, selected: Option<usize>) {
+        self.highlighted = selected;
+        self.typed.clear();
+    }
 }
 
 // Default colors (dark theme) for when no theme is provided
@@ -73,6 +439,8 @@ pub(crate) mod defaults {
     pub const TEXT_PRIMARY: Color = Color::srgb(0.95, 0.95, 0.95);
     pub const TEXT_SECONDARY: Color = Color::srgb(0.7, 0.7, 0.7);
     pub const SELECTED_HIGHLIGHT: Color = Color::srgba(0.3, 0.5, 0.8, 0.3);
+    pub const TEXT_DISABLED: Color = Color::srgb(0.4, 0.4, 0.4);
+    pub const TEXT_MUTED: Color = Color::srgb(0.5, 0.5, 0.5);
 }
 
 /// Resolved dropdown colors from theme
@@ -90,6 +458,10 @@ pub struct DropdownColors {
     pub text_secondary: Color,
     /// Selected option highlight
     pub selected_highlight: Color,
+    /// Text color of disabled options
+    pub text_disabled: Color,
+    /// Text color of group headers
+    pub text_muted: Color,
 }
 
 impl DropdownColors {
@@ -103,6 +475,8 @@ impl DropdownColors {
             text_primary: theme.colors.text.primary,
             text_secondary: theme.colors.text.secondary,
             selected_highlight: theme.colors.primary.base.with_alpha(0.3),
+            text_disabled: theme.colors.text.disabled,
+            text_muted: theme.colors.text.muted,
         }
     }
 
@@ -115,6 +489,132 @@ impl DropdownColors {
             text_primary: defaults::TEXT_PRIMARY,
             text_secondary: defaults::TEXT_SECONDARY,
             selected_highlight: defaults::SELECTED_HIGHLIGHT,
+            text_disabled: defaults::TEXT_DISABLED,
+            text_muted: defaults::TEXT_MUTED,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options() -> Vec<String> {
+        ["Apple", "Banana", "Blueberry", "Cherry", "Date"].map(String::from).to_vec()
+    }
+
+    #[test]
+    fn search_matches_labels_ignoring_case() {
+        let data = DropdownData {
+            options: options(),
+            selected_index: Some(3),
+            placeholder: String::new(),
+        };
+
+        let matching: Vec<usize> = (0..5).filter(|&index| data.matches(index, " eRR ")).collect();
+        assert_eq!(matching, vec![2, 3]);
+        assert!((0..5).all(|index| data.matches(index, "")));
+        assert!(!data.matches(7, ""));
+    }
+
+    #[test]
+    fn highlight_wraps_around_and_skips_unselectable() {
+        // "Cherry" (3) is disabled
+        let selectable = [0, 1, 2, 4];
+        let mut navigation = DropdownNavigation::default();
+
+        navigation.move_highlight(&selectable, true);
+        assert_eq!(navigation.highlighted, Some(0));
+        navigation.move_highlight(&selectable, false);
+        assert_eq!(navigation.highlighted, Some(4));
+        navigation.move_highlight(&selectable, true);
+        assert_eq!(navigation.highlighted, Some(0));
+
+        navigation.highlighted = Some(2);
+        navigation.move_highlight(&selectable, true);
+        assert_eq!(navigation.highlighted, Some(4));
+    }
+
+    #[test]
+    fn type_ahead_matches_typed_prefix() {
+        let options = options();
+        let selectable = [0, 1, 2, 3, 4];
+        let mut navigation = DropdownNavigation::default();
+
+        navigation.type_ahead('b', &options, &selectable);
+        assert_eq!(navigation.highlighted, Some(1));
+        navigation.type_ahead('L', &options, &selectable);
+        assert_eq!(navigation.highlighted, Some(2));
+
+        // After a pause the next letter starts a new prefix
+        navigation.tick(DropdownNavigation::TYPE_AHEAD_RESET);
+        navigation.type_ahead('c', &options, &selectable);
+        assert_eq!(navigation.highlighted, Some(3));
+
+        // No match keeps the current highlight
+        navigation.type_ahead('x', &options, &selectable);
+        assert_eq!(navigation.highlighted, Some(3));
+    }
+
+    #[test]
+    fn repeating_a_letter_cycles_through_its_options() {
+        let options = options();
+        let selectable = [0, 1, 2, 3, 4];
+        let mut navigation = DropdownNavigation::default();
+
+        navigation.type_ahead('b', &options, &selectable);
+        navigation.type_ahead('b', &options, &selectable);
+        assert_eq!(navigation.highlighted, Some(2));
+        navigation.type_ahead('b', &options, &selectable);
+        assert_eq!(navigation.highlighted, Some(1));
+    }
+
+    #[test]
+    fn group_collects_nested_option_indices() {
+        let entries = vec![
+            MenuEntry::Option(0),
+            MenuEntry::Group {
+                label: "Fruit".into(),
+                entries: vec![
+                    MenuEntry::Option(1),
+                    MenuEntry::Submenu {
+                        label: "Berries".into(),
+                        entries: vec![MenuEntry::Option(2), MenuEntry::Option(3)],
+                    },
+                ],
+            },
+        ];
+
+        assert_eq!(MenuEntry::option_indices(&entries), vec![0, 1, 2, 3]);
+        assert_eq!(MenuEntry::option_indices(&entries[1..]), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn visible_height_counts_group_headers() {
+        let entries = vec![
+            MenuEntry::Group {
+                label: "Weapons".into(),
+                entries: vec![MenuEntry::Option(0), MenuEntry::Option(1)],
+            },
+            MenuEntry::Group {
+                label: "Armor".into(),
+                entries: vec![MenuEntry::Option(2), MenuEntry::Option(3)],
+            },
+        ];
+        let row_height = |row: &MenuEntry| match row {
+            MenuEntry::Group { .. } => GROUP_HEADER_HEIGHT,
+            _ => 30.0,
+        };
+
+        // Three options plus both headers above them
+        assert_eq!(
+            MenuEntry::visible_height(&entries, 3, row_height),
+            3.0 * 30.0 + 2.0 * GROUP_HEADER_HEIGHT,
+        );
+        // A short menu only counts the rows it has
+        assert_eq!(
+            MenuEntry::visible_height(&entries, 10, row_height),
+            4.0 * 30.0 + 2.0 * GROUP_HEADER_HEIGHT,
+        );
+    }
+}
@@ -2,11 +2,12 @@
 
 use bevy::prelude::*;
 use std::collections::HashMap;
+use std::time::Duration;
 use crate::styles::dimensions;
-use crate::traits::{UiBuilder, LayoutBuilder, BuilderBase};
+use crate::traits::{UiBuilder, LayoutBuilder, BuilderBase, HelpTextBuilder};
 use super::types::{
     FormField, FieldType, ValidationRule, FormLayout,
-    FormRoot, FormSubmitButton
+    FormRoot, FormSubmitButton, FormSubmitOnEnter
 };
 use super::field::spawn_form_field;
 
@@ -25,6 +26,7 @@ pub struct FormBuilder {
     submit_text: String,
     cancel_text: Option<String>,
     layout: FormLayout,
+    submit_on_enter: bool,
     base: BuilderBase,
 }
 
@@ -44,6 +46,7 @@ impl FormBuilder {
             submit_text: "Submit".to_string(),
             cancel_text: None,
             layout: FormLayout::Vertical,
+            submit_on_enter: false,
             base,
         }
     }
@@ -63,8 +66,11 @@ impl FormBuilder {
             validations: Vec::new(),
             placeholder: None,
             help_text: None,
+            help_tooltip: None,
+            error_text: None,
             disabled: false,
             default_value: None,
+            async_validation: None,
         });
         self
     }
@@ -78,8 +84,11 @@ impl FormBuilder {
             validations: Vec::new(),
             placeholder: None,
             help_text: None,
+            help_tooltip: None,
+            error_text: None,
             disabled: false,
             default_value: None,
+            async_validation: None,
         });
         self
     }
@@ -93,8 +102,11 @@ impl FormBuilder {
             validations: vec![ValidationRule::Email],
             placeholder: Some("email@example.com".to_string()),
             help_text: None,
+            help_tooltip: None,
+            error_text: None,
             disabled: false,
             default_value: None,
+            async_validation: None,
         };
         self.fields.push(field);
         self
@@ -115,8 +127,11 @@ impl FormBuilder {
             validations: Vec::new(),
             placeholder: None,
             help_text: None,
+            help_tooltip: None,
+            error_text: None,
             disabled: false,
             default_value: None,
+            async_validation: None,
         });
         self
     }
@@ -136,8 +151,11 @@ impl FormBuilder {
             validations: Vec::new(),
             placeholder: None,
             help_text: None,
+            help_tooltip: None,
+            error_text: None,
             disabled: false,
             default_value: Some(min.to_string()),
+            async_validation: None,
         });
         self
     }
@@ -156,8 +174,11 @@ impl FormBuilder {
             validations: Vec::new(),
             placeholder: Some("Select an option".to_string()),
             help_text: None,
+            help_tooltip: None,
+            error_text: None,
             disabled: false,
             default_value: None,
+            async_validation: None,
         });
         self
     }
@@ -171,8 +192,11 @@ impl FormBuilder {
             validations: Vec::new(),
             placeholder: None,
             help_text: None,
+            help_tooltip: None,
+            error_text: None,
             disabled: false,
             default_value: Some("false".to_string()),
+            async_validation: None,
         });
         self
     }
@@ -193,6 +217,30 @@ impl FormBuilder {
         self
     }
 
+    /// Check the last field's value asynchronously, e.g. against a server
+    ///
+    /// Once the value has been unchanged for `debounce`, a
+    /// [`ValidationRequestEvent`](crate::validation::ValidationRequestEvent)
+    /// is sent for the field's input and a spinner shows beside its label.
+    /// Answer with a
+    /// [`ValidationResolvedEvent`](crate::validation::ValidationResolvedEvent)
+    /// to swap the spinner for a check or cross mark. Text, password and
+    /// email fields only.
+    ///
+    /// # Example
+    /// ```ignore
+    /// FormBuilder::new("signup")
+    ///     .text_field("username", "Username")
+    ///     .async_validate(Duration::from_millis(400))
+    ///     .build(parent);
+    /// ```
+    pub fn async_validate(mut self, debounce: Duration) -> Self {
+        if let Some(field) = self.fields.last_mut() {
+            field.async_validation = Some(debounce);
+        }
+        self
+    }
+
     /// Add placeholder to the last field
     pub fn placeholder(mut self, text: impl Into<String>) -> Self {
         if let Some(field) = self.fields.last_mut() {
@@ -209,6 +257,39 @@ impl FormBuilder {
         self
     }
 
+    /// Add error text to the last field
+    pub fn error_text(mut self, text: impl Into<String>) -> Self {
+        if let Some(field) = self.fields.last_mut() {
+            field.error_text = Some(text.into());
+        }
+        self
+    }
+
+    /// Add help text below the field named `id`
+    pub fn field_help(mut self, id: &str, text: impl Into<String>) -> Self {
+        if let Some(field) = self.field_mut(id) {
+            field.help_text = Some(text.into());
+        }
+        self
+    }
+
+    /// Add a "?" icon beside the label of the field named `id`, showing `text`
+    /// in a tooltip
+    ///
+    /// Falls back to inline help text when the field has no separate label
+    /// (checkboxes) or the `tooltip` feature is disabled.
+    pub fn field_help_icon(mut self, id: &str, text: impl Into<String>) -> Self {
+        if let Some(field) = self.field_mut(id) {
+            field.help_tooltip = Some(text.into());
+        }
+        self
+    }
+
+    /// Find a field by name
+    fn field_mut(&mut self, id: &str) -> Option<&mut FormField> {
+        self.fields.iter_mut().find(|field| field.name == id)
+    }
+
     /// Set submit button text
     pub fn submit_text(mut self, text: impl Into<String>) -> Self {
         self.submit_text = text.into();
@@ -227,6 +308,15 @@ impl FormBuilder {
         self
     }
 
+    /// Submit the form when Enter is pressed in any single-line field
+    ///
+    /// The form is only submitted if every field passes validation.
+    /// Multiline fields still insert a new line on Enter.
+    pub fn submit_on_enter(mut self, enabled: bool) -> Self {
+        self.submit_on_enter = enabled;
+        self
+    }
+
     /// Set form width
     pub fn width(mut self, width: Val) -> Self {
         self.base.node.width = width;
@@ -256,6 +346,10 @@ impl UiBuilder for FormBuilder {
             ))
             .id();
 
+        if self.submit_on_enter {
+            parent.commands().entity(form_entity).insert(FormSubmitOnEnter);
+        }
+
         let form_entity_copy = form_entity;
 
         parent.commands().entity(form_entity).with_children(|form| {
@@ -277,7 +371,7 @@ impl UiBuilder for FormBuilder {
 
             // Add fields
             for field in &self.fields {
-                spawn_form_field(form, field);
+                spawn_form_field(form, field, form_entity_copy);
             }
 
             // Add buttons
@@ -329,6 +423,16 @@ impl UiBuilder for FormBuilder {
     }
 }
 
+impl HelpTextBuilder for FormBuilder {
+    fn help_text(self, text: impl Into<String>) -> Self {
+        FormBuilder::help_text(self, text)
+    }
+
+    fn error_text(self, text: impl Into<String>) -> Self {
+        FormBuilder::error_text(self, text)
+    }
+}
+
 impl LayoutBuilder for FormBuilder {
     fn node(mut self, node: Node) -> Self {
         self.base.node = node;
@@ -2,13 +2,16 @@
 
 use bevy::prelude::*;
 use crate::styles::dimensions;
-use super::types::{FormField, FieldType, FormFieldMarker};
+use super::types::{FormField, FieldType};
+use crate::validation::{spawn_validation_indicator, AsyncValidated, ValidationState};
 
 /// Default colors for form fields (dark theme fallback)
 mod defaults {
     use bevy::prelude::Color;
     pub const TEXT_PRIMARY: Color = Color::srgb(0.95, 0.95, 0.95);
     pub const TEXT_SECONDARY: Color = Color::srgb(0.7, 0.7, 0.7);
+    pub const TEXT_ERROR: Color = Color::srgb(0.9, 0.3, 0.3);
+    pub const HELP_ICON_BACKGROUND: Color = Color::srgb(0.25, 0.25, 0.3);
 }
 
 #[cfg(feature = "text_input")]
@@ -26,8 +29,18 @@ use crate::components::number_input::NumberInputBuilder;
 #[cfg(feature = "dropdown")]
 use crate::components::dropdown::DropdownBuilder;
 
-/// Helper function to spawn a form field
-pub fn spawn_form_field(parent: &mut ChildSpawnerCommands, field: &FormField) {
+#[cfg(feature = "tooltip")]
+use crate::components::tooltip::TooltipBuilder;
+
+#[cfg(any(feature = "text_input", feature = "slider", feature = "checkbox", feature = "dropdown"))]
+use crate::relationships::BelongsToForm;
+#[cfg(any(feature = "text_input", feature = "slider", feature = "checkbox", feature = "dropdown"))]
+use crate::traits::UiBuilder;
+#[cfg(any(feature = "text_input", feature = "slider", feature = "checkbox", feature = "dropdown"))]
+use super::types::FormFieldMarker;
+
+/// Helper function to spawn a form field belonging to `form`
+pub fn spawn_form_field(parent: &mut ChildSpawnerCommands, field: &FormField, form: Entity) {
     parent
         .spawn((
             Node {
@@ -40,23 +53,45 @@ pub fn spawn_form_field(parent: &mut ChildSpawnerCommands, field: &FormField) {
         ))
         .with_children(|field_container| {
             // Label (only show for non-checkbox fields, checkbox has its own label)
-            if !matches!(field.field_type, FieldType::Checkbox) {
-                field_container.spawn((
-                    Text::new(&field.label),
-                    TextFont {
-                        font_size: dimensions::FONT_SIZE_MEDIUM,
+            let has_label = !matches!(field.field_type, FieldType::Checkbox);
+            let help_icon = if has_label && cfg!(feature = "tooltip") {
+                field.help_tooltip.as_ref()
+            } else {
+                None
+            };
+
+            let label_row = has_label.then(|| {
+                field_container
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(dimensions::SPACING_SMALL),
                         ..default()
-                    },
-                    TextColor(defaults::TEXT_PRIMARY),
-                ));
-            }
+                    })
+                    .with_children(|label_row| {
+                        label_row.spawn((
+                            Text::new(&field.label),
+                            TextFont {
+                                font_size: dimensions::FONT_SIZE_MEDIUM,
+                                ..default()
+                            },
+                            TextColor(defaults::TEXT_PRIMARY),
+                        ));
+
+                        if let Some(help) = help_icon {
+                            spawn_help_icon(label_row, help);
+                        }
+                    })
+                    .id()
+            });
 
-            // Field input based on type
-            match &field.field_type {
+            // Field input based on type; text fields can validate asynchronously
+            let async_input = match &field.field_type {
                 #[cfg(feature = "text_input")]
                 FieldType::Text => {
                     let mut builder = TextInputBuilder::new()
-                        .with_width(Val::Percent(100.0));
+                        .with_width(Val::Percent(100.0))
+                        .insert(field_components(field, form));
 
                     if let Some(placeholder) = &field.placeholder {
                         builder = builder.with_placeholder(placeholder);
@@ -66,54 +101,42 @@ pub fn spawn_form_field(parent: &mut ChildSpawnerCommands, field: &FormField) {
                         builder = builder.with_value(default_value);
                     }
 
-                    let entity = builder.build(field_container);
-
-                    field_container.commands().entity(entity).insert(FormFieldMarker {
-                        field_name: field.name.clone(),
-                        field_type: field.field_type.clone(),
-                    });
+                    Some(builder.build(field_container))
                 }
 
                 #[cfg(feature = "text_input")]
                 FieldType::Password => {
                     let mut builder = TextInputBuilder::new()
-                        .with_width(Val::Percent(100.0));
-                    // TODO: Add password masking visualization
+                        .with_width(Val::Percent(100.0))
+                        .password()
+                        .insert(field_components(field, form));
 
                     if let Some(placeholder) = &field.placeholder {
                         builder = builder.with_placeholder(placeholder);
                     }
 
-                    let entity = builder.build(field_container);
-
-                    field_container.commands().entity(entity).insert(FormFieldMarker {
-                        field_name: field.name.clone(),
-                        field_type: field.field_type.clone(),
-                    });
+                    Some(builder.build(field_container))
                 }
 
                 #[cfg(feature = "text_input")]
                 FieldType::Email => {
                     // Use regex pattern for email validation if desired
                     let mut builder = TextInputBuilder::new()
-                        .with_width(Val::Percent(100.0));
+                        .with_width(Val::Percent(100.0))
+                        .insert(field_components(field, form));
 
                     if let Some(placeholder) = &field.placeholder {
                         builder = builder.with_placeholder(placeholder);
                     }
 
-                    let entity = builder.build(field_container);
-
-                    field_container.commands().entity(entity).insert(FormFieldMarker {
-                        field_name: field.name.clone(),
-                        field_type: field.field_type.clone(),
-                    });
+                    Some(builder.build(field_container))
                 }
 
                 #[cfg(feature = "number_input")]
                 FieldType::Number { min, max } => {
                     let mut builder = NumberInputBuilder::new()
-                        .width(Val::Percent(100.0));
+                        .width(Val::Percent(100.0))
+                        .insert(field_components(field, form));
 
                     if let Some(min_val) = min {
                         builder = builder.min(*min_val);
@@ -133,12 +156,9 @@ pub fn spawn_form_field(parent: &mut ChildSpawnerCommands, field: &FormField) {
                         }
                     }
 
-                    let entity = builder.build(field_container);
+                    builder.build(field_container);
 
-                    field_container.commands().entity(entity).insert(FormFieldMarker {
-                        field_name: field.name.clone(),
-                        field_type: field.field_type.clone(),
-                    });
+                    None
                 }
 
                 #[cfg(feature = "slider")]
@@ -150,18 +170,16 @@ pub fn spawn_form_field(parent: &mut ChildSpawnerCommands, field: &FormField) {
 
                     let mut builder = SliderBuilder::new(*min..*max)
                         .value(default_value)
-                        .width(Val::Percent(100.0));
+                        .width(Val::Percent(100.0))
+                        .insert(field_components(field, form));
 
                     if let Some(step_val) = step {
                         builder = builder.step(*step_val);
                     }
 
-                    let entity = builder.build(field_container);
+                    builder.build(field_container);
 
-                    field_container.commands().entity(entity).insert(FormFieldMarker {
-                        field_name: field.name.clone(),
-                        field_type: field.field_type.clone(),
-                    });
+                    None
                 }
 
                 #[cfg(feature = "checkbox")]
@@ -171,21 +189,20 @@ pub fn spawn_form_field(parent: &mut ChildSpawnerCommands, field: &FormField) {
                         .map(|s| s == "true" || s == "1")
                         .unwrap_or(false);
 
-                    let entity = CheckboxBuilder::new()
+                    CheckboxBuilder::new()
                         .checked(checked)
                         .with_label(&field.label)
+                        .insert(field_components(field, form))
                         .build(field_container);
 
-                    field_container.commands().entity(entity).insert(FormFieldMarker {
-                        field_name: field.name.clone(),
-                        field_type: field.field_type.clone(),
-                    });
+                    None
                 }
 
                 #[cfg(feature = "dropdown")]
                 FieldType::Dropdown { options } => {
                     let mut builder = DropdownBuilder::new(options.clone())
-                        .width(Val::Percent(100.0));
+                        .width(Val::Percent(100.0))
+                        .insert(field_components(field, form));
 
                     if let Some(placeholder) = &field.placeholder {
                         builder = builder.placeholder(placeholder);
@@ -198,12 +215,9 @@ pub fn spawn_form_field(parent: &mut ChildSpawnerCommands, field: &FormField) {
                         }
                     }
 
-                    let entity = builder.build(field_container);
+                    builder.build(field_container);
 
-                    field_container.commands().entity(entity).insert(FormFieldMarker {
-                        field_name: field.name.clone(),
-                        field_type: field.field_type.clone(),
-                    });
+                    None
                 }
 
                 #[cfg(not(feature = "dropdown"))]
@@ -217,6 +231,8 @@ pub fn spawn_form_field(parent: &mut ChildSpawnerCommands, field: &FormField) {
                         },
                         TextColor(defaults::TEXT_SECONDARY),
                     ));
+
+                    None
                 }
 
                 _ => {
@@ -229,11 +245,19 @@ pub fn spawn_form_field(parent: &mut ChildSpawnerCommands, field: &FormField) {
                         },
                         TextColor(defaults::TEXT_SECONDARY),
                     ));
+
+                    None
                 }
+            };
+
+            if let Some(input) = async_input {
+                attach_async_validation(field_container, field, input, label_row);
             }
 
-            // Help text if provided
-            if let Some(help_text) = &field.help_text {
+            // Help text if provided (tooltip help without an icon is shown inline too)
+            let inline_help = field.help_text.iter()
+                .chain(field.help_tooltip.iter().filter(|_| help_icon.is_none()));
+            for help_text in inline_help {
                 field_container.spawn((
                     Text::new(help_text),
                     TextFont {
@@ -243,5 +267,87 @@ pub fn spawn_form_field(parent: &mut ChildSpawnerCommands, field: &FormField) {
                     TextColor(defaults::TEXT_SECONDARY),
                 ));
             }
+
+            // Error text if provided
+            if let Some(error_text) = &field.error_text {
+                field_container.spawn((
+                    Text::new(error_text),
+                    TextFont {
+                        font_size: dimensions::FONT_SIZE_SMALL,
+                        ..default()
+                    },
+                    TextColor(defaults::TEXT_ERROR),
+                ));
+            }
+        });
+}
+
+/// Components that tie a field's input to its form, added through the
+/// input builder's hooks
+#[cfg(any(feature = "text_input", feature = "slider", feature = "checkbox", feature = "dropdown"))]
+fn field_components(field: &FormField, form: Entity) -> impl Bundle + Clone {
+    (
+        FormFieldMarker {
+            field_name: field.name.clone(),
+            field_type: field.field_type.clone(),
+        },
+        BelongsToForm(form),
+    )
+}
+
+/// Spawn a small "?" badge that shows `help` in a tooltip on hover
+#[cfg_attr(not(feature = "tooltip"), allow(unused_variables))]
+fn spawn_help_icon(parent: &mut ChildSpawnerCommands, help: &str) {
+    let size = dimensions::FONT_SIZE_SMALL + 4.0;
+
+    let mut icon = parent.spawn((
+        Node {
+            width: Val::Px(size),
+            height: Val::Px(size),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(defaults::HELP_ICON_BACKGROUND),
+        BorderRadius::all(Val::Px(size / 2.0)),
+        Interaction::default(), // Required for tooltip hover detection
+    ));
+
+    icon.with_children(|icon| {
+        icon.spawn((
+            Text::new("?"),
+            TextFont {
+                font_size: dimensions::FONT_SIZE_SMALL,
+                ..default()
+            },
+            TextColor(defaults::TEXT_SECONDARY),
+        ));
+    });
+
+    #[cfg(feature = "tooltip")]
+    icon.insert(TooltipBuilder::new(help).into_components());
+}
+
+/// Make `input` validate asynchronously if the field asks for it, with the
+/// pending/result indicator beside the field's label
+fn attach_async_validation(
+    field_container: &mut ChildSpawnerCommands,
+    field: &FormField,
+    input: Entity,
+    label_row: Option<Entity>,
+) {
+    let Some(debounce) = field.async_validation else {
+        return;
+    };
+
+    let mut commands = field_container.commands();
+    commands
+        .entity(input)
+        .insert((AsyncValidated::new(debounce), ValidationState::default()));
+
+    if let Some(label_row) = label_row {
+        commands.entity(label_row).with_children(|row| {
+            spawn_validation_indicator(row, input);
         });
-}
\ No newline at end of file
+    }
+}
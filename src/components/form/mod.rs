@@ -3,10 +3,13 @@
 // Private submodules
 mod builder;
 mod field;
+mod plugin;
+mod systems;
 mod types;
 
 // Public exports
 pub use builder::FormBuilder;
+pub use plugin::FormPlugin;
 pub use types::{
     FieldType,
     ValidationRule,
@@ -15,6 +18,7 @@ pub use types::{
     FormRoot,
     FormFieldMarker,
     FormSubmitButton,
+    FormSubmitOnEnter,
     FormSubmitEvent,
     FormLayout,
 };
\ No newline at end of file
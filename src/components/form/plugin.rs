@@ -0,0 +1,31 @@
+//! Form plugin
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+use super::types::FormSubmitEvent;
+use super::systems::*;
+
+// Plugin that tracks form values and sends submit events
+define_plugin!(FormPlugin {
+    messages: [FormSubmitEvent],
+    custom_init: |app: &mut App| {
+        // Field values are mirrored into FormRoot before a submit reads them
+        #[cfg(feature = "text_input")]
+        app.add_systems(Update, (
+            sync_text_field_values,
+            handle_form_enter_submit,
+        ).chain().before(handle_form_submit_buttons));
+
+        #[cfg(feature = "slider")]
+        app.add_systems(Update, sync_slider_field_values.before(handle_form_submit_buttons));
+
+        #[cfg(feature = "checkbox")]
+        app.add_systems(Update, sync_checkbox_field_values.before(handle_form_submit_buttons));
+
+        #[cfg(feature = "dropdown")]
+        app.add_systems(Update, sync_dropdown_field_values.before(handle_form_submit_buttons));
+    },
+    update: [
+        handle_form_submit_buttons,
+    ]
+});
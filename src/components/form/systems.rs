@@ -0,0 +1,161 @@
+//! Form value tracking and submission systems
+
+use bevy::prelude::*;
+use crate::relationships::BelongsToForm;
+use crate::traits::Disabled;
+use crate::validation::ValidationState;
+use super::types::{FormRoot, FormFieldMarker, FormSubmitButton, FormSubmitEvent, FormData};
+
+#[cfg(feature = "text_input")]
+use crate::components::text_input::{TextBuffer, TextInputSubmitEvent};
+#[cfg(feature = "text_input")]
+use super::types::FormSubmitOnEnter;
+
+#[cfg(feature = "slider")]
+use crate::components::slider::Slider;
+
+#[cfg(feature = "checkbox")]
+use crate::components::checkbox::CheckboxState;
+
+#[cfg(feature = "dropdown")]
+use crate::components::dropdown::DropdownData;
+
+/// Store a field's value on its form, skipping unchanged values
+fn store_value(
+    forms: &mut Query<&mut FormRoot>,
+    field: &FormFieldMarker,
+    form: &BelongsToForm,
+    value: String,
+) {
+    let Ok(mut root) = forms.get_mut(form.0) else {
+        return;
+    };
+
+    if root.values.get(&field.field_name) != Some(&value) {
+        root.values.insert(field.field_name.clone(), value);
+    }
+}
+
+/// Mirror text, password, email and number fields into their form
+#[cfg(feature = "text_input")]
+pub fn sync_text_field_values(
+    fields: Query<(&FormFieldMarker, &BelongsToForm, &TextBuffer), Changed<TextBuffer>>,
+    mut forms: Query<&mut FormRoot>,
+) {
+    for (field, form, buffer) in fields.iter() {
+        store_value(&mut forms, field, form, buffer.content.clone());
+    }
+}
+
+/// Mirror slider fields into their form (the slider is the field's track child)
+#[cfg(feature = "slider")]
+pub fn sync_slider_field_values(
+    sliders: Query<(&Slider, &ChildOf), Changed<Slider>>,
+    fields: Query<(&FormFieldMarker, &BelongsToForm)>,
+    mut forms: Query<&mut FormRoot>,
+) {
+    for (slider, child_of) in sliders.iter() {
+        if let Ok((field, form)) = fields.get(child_of.parent()) {
+            store_value(&mut forms, field, form, slider.value.to_string());
+        }
+    }
+}
+
+/// Mirror checkbox fields into their form as "true" / "false"
+#[cfg(feature = "checkbox")]
+pub fn sync_checkbox_field_values(
+    checkboxes: Query<(&CheckboxState, &ChildOf), Changed<CheckboxState>>,
+    fields: Query<(&FormFieldMarker, &BelongsToForm)>,
+    mut forms: Query<&mut FormRoot>,
+) {
+    for (state, child_of) in checkboxes.iter() {
+        if let Ok((field, form)) = fields.get(child_of.parent()) {
+            store_value(&mut forms, field, form, state.is_checked().to_string());
+        }
+    }
+}
+
+/// Mirror dropdown fields into their form as the selected index
+#[cfg(feature = "dropdown")]
+pub fn sync_dropdown_field_values(
+    fields: Query<(&FormFieldMarker, &BelongsToForm, &DropdownData), Changed<DropdownData>>,
+    mut forms: Query<&mut FormRoot>,
+) {
+    for (field, form, data) in fields.iter() {
+        let value = data.selected_index.map(|index| index.to_string()).unwrap_or_default();
+        store_value(&mut forms, field, form, value);
+    }
+}
+
+/// Validate a form and send `FormSubmitEvent` if it passes
+fn submit_form(
+    form_entity: Entity,
+    forms: &mut Query<&mut FormRoot>,
+    submit_events: &mut MessageWriter<FormSubmitEvent>,
+) {
+    let Ok(mut form) = forms.get_mut(form_entity) else {
+        return;
+    };
+
+    if form.validate() {
+        submit_events.write(FormSubmitEvent {
+            form_id: form.id.clone(),
+            data: FormData {
+                values: form.values.clone(),
+            },
+        });
+    }
+}
+
+/// Whether one of the form's async checks is still running or has failed
+fn async_checks_block(form_entity: Entity, checks: &Query<(&BelongsToForm, &ValidationState)>) -> bool {
+    checks.iter()
+        .any(|(form, state)| form.0 == form_entity && (state.is_pending() || !state.is_valid))
+}
+
+/// Submit forms whose submit button was clicked
+///
+/// A [`Disabled`] submit button doesn't submit, and neither does a form
+/// waiting on (or failing) an async field check.
+pub fn handle_form_submit_buttons(
+    buttons: Query<(&Interaction, &FormSubmitButton), (Changed<Interaction>, Without<Disabled>)>,
+    mut forms: Query<&mut FormRoot>,
+    mut submit_events: MessageWriter<FormSubmitEvent>,
+    checks: Query<(&BelongsToForm, &ValidationState)>,
+) {
+    for (interaction, button) in buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        if async_checks_block(button.form_entity, &checks) {
+            continue;
+        }
+
+        submit_form(button.form_entity, &mut forms, &mut submit_events);
+    }
+}
+
+/// Submit `submit_on_enter` forms when Enter is pressed in one of their fields
+///
+/// Multiline inputs never send `TextInputSubmitEvent`, so Enter there still
+/// inserts a new line.
+#[cfg(feature = "text_input")]
+pub fn handle_form_enter_submit(
+    mut text_submits: MessageReader<TextInputSubmitEvent>,
+    fields: Query<&BelongsToForm, With<FormFieldMarker>>,
+    enter_forms: Query<(), With<FormSubmitOnEnter>>,
+    mut forms: Query<&mut FormRoot>,
+    mut submit_events: MessageWriter<FormSubmitEvent>,
+    checks: Query<(&BelongsToForm, &ValidationState)>,
+) {
+    for event in text_submits.read() {
+        let Ok(form) = fields.get(event.entity) else {
+            continue;
+        };
+
+        if enter_forms.contains(form.0) && !async_checks_block(form.0, &checks) {
+            submit_form(form.0, &mut forms, &mut submit_events);
+        }
+    }
+}
@@ -2,6 +2,7 @@
 
 use bevy::prelude::*;
 use std::collections::HashMap;
+use std::time::Duration;
 
 /// Field types available in forms
 #[derive(Debug, Clone)]
@@ -149,10 +150,17 @@ pub struct FormField {
     pub placeholder: Option<String>,
     /// Help text shown below field
     pub help_text: Option<String>,
+    /// Help shown in a tooltip on a "?" icon beside the label
+    pub help_tooltip: Option<String>,
+    /// Error message shown below field
+    pub error_text: Option<String>,
     /// Whether field is disabled
     pub disabled: bool,
     /// Default value
     pub default_value: Option<String>,
+    /// Debounce before an async check of the value is requested
+    /// (text fields only; see [`AsyncValidated`](crate::validation::AsyncValidated))
+    pub async_validation: Option<Duration>,
 }
 
 /// Form submission result
@@ -175,8 +183,28 @@ pub struct FormRoot {
     pub values: HashMap<String, String>,
 }
 
+impl FormRoot {
+    /// Run every field's validation rules against its current value,
+    /// updating `is_valid`
+    pub fn validate(&mut self) -> bool {
+        let is_valid = self.fields.iter().all(|field| {
+            let value = self.values.get(&field.name).map_or("", String::as_str);
+            field.validations.iter().all(|rule| rule.validate(value).is_ok())
+        });
+        self.is_valid = is_valid;
+        is_valid
+    }
+}
+
+/// Submit the form when Enter is pressed in any of its single-line fields
+///
+/// Added by `FormBuilder::submit_on_enter(true)`. Multiline fields keep
+/// Enter for new lines.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct FormSubmitOnEnter;
+
 /// Component marking a form field
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct FormFieldMarker {
     /// Field name
     pub field_name: String,
@@ -223,4 +251,42 @@ pub enum ValidationTrigger {
     OnChange,
     /// Validate only on submit
     OnSubmit,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn required_field(name: &str) -> FormField {
+        FormField {
+            name: name.to_string(),
+            label: name.to_string(),
+            field_type: FieldType::Text,
+            validations: vec![ValidationRule::Required],
+            placeholder: None,
+            help_text: None,
+            help_tooltip: None,
+            error_text: None,
+            disabled: false,
+            default_value: None,
+            async_validation: None,
+        }
+    }
+
+    #[test]
+    fn validate_checks_current_values() {
+        let mut form = FormRoot {
+            id: "login".to_string(),
+            fields: vec![required_field("user"), required_field("password")],
+            is_valid: false,
+            values: HashMap::new(),
+        };
+
+        form.values.insert("user".to_string(), "ada".to_string());
+        assert!(!form.validate());
+
+        form.values.insert("password".to_string(), "hunter2".to_string());
+        assert!(form.validate());
+        assert!(form.is_valid);
+    }
+}
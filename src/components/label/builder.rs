@@ -2,8 +2,9 @@
 
 use bevy::prelude::*;
 
-use super::types::{Label as UiLabel, LabelSize};
-use crate::theme::{SemanticVariant, UiTheme};
+use super::types::{Label as UiLabel, LabelCopyButton, LabelSize};
+use crate::systems::mnemonic::{parse_mnemonic, with_mnemonic_spans, Mnemonic};
+use crate::theme::{FontRole, SemanticVariant, ThemeFonts, UiTheme};
 use crate::traits::{UiBuilder, LayoutBuilder, BuilderBase};
 
 // Default colors (dark theme) for when no theme is provided
@@ -27,6 +28,7 @@ mod defaults {
 
 /// Resolved colors and sizes for a label (either from theme or defaults)
 struct ResolvedLabelStyle {
+    font: Handle<Font>,
     font_size: f32,
     text_color: Color,
 }
@@ -38,10 +40,15 @@ pub struct LabelBuilder {
     variant: Option<SemanticVariant>,
     font_size: Option<f32>,
     text_color: Option<Color>,
+    font: Option<Handle<Font>>,
+    font_role: Option<FontRole>,
     text_align: JustifyContent,
+    copy_button: bool,
+    mnemonic_target: Option<Entity>,
     // Theme-resolved values (set via .themed())
     themed_font_size: Option<f32>,
     themed_text_color: Option<Color>,
+    themed_fonts: Option<ThemeFonts>,
     base: BuilderBase,
 }
 
@@ -54,9 +61,14 @@ impl LabelBuilder {
             variant: None,
             font_size: None,
             text_color: None,
+            font: None,
+            font_role: None,
             text_align: JustifyContent::Start,
+            copy_button: false,
+            mnemonic_target: None,
             themed_font_size: None,
             themed_text_color: None,
+            themed_fonts: None,
             base: BuilderBase::new(),
         }
     }
@@ -81,6 +93,7 @@ impl LabelBuilder {
         // Pre-resolve theme values
         self.themed_font_size = Some(self.size.font_size(theme));
         self.themed_text_color = Some(self.resolve_text_color_from_theme(theme));
+        self.themed_fonts = Some(theme.typography.fonts.clone());
         self
     }
 
@@ -139,12 +152,68 @@ impl LabelBuilder {
         self
     }
 
+    /// Pick the theme font for a role instead of the one implied by the size
+    ///
+    /// Only takes effect on themed labels; see [`ThemeTypography::font_for`](crate::ThemeTypography::font_for).
+    pub fn font_role(mut self, role: FontRole) -> Self {
+        self.font_role = Some(role);
+        self
+    }
+
+    /// Use the theme's monospace font (for code, numbers, ...)
+    pub fn monospace(self) -> Self {
+        self.font_role(FontRole::Monospace)
+    }
+
+    /// Override the font directly
+    pub fn font(mut self, font: Handle<Font>) -> Self {
+        self.font = Some(font);
+        self
+    }
+
     /// Set text alignment
     pub fn text_align(mut self, align: JustifyContent) -> Self {
         self.text_align = align;
         self
     }
 
+    /// Show a small "Copy" button next to the label
+    ///
+    /// Clicking it copies the label's current text to the clipboard and
+    /// briefly confirms with "Copied!" (as a toast when the `toast` feature
+    /// is enabled). The label and button are wrapped in a row, which `build`
+    /// returns and which gets the builder's `insert()` components (and so
+    /// `visible()` and `class()`); the label is the row's first child.
+    ///
+    /// # Example
+    /// ```ignore
+    /// LabelBuilder::new("sk-live-4f9a...")
+    ///     .monospace()
+    ///     .with_copy_button(true)
+    ///     .build(parent);
+    /// ```
+    pub fn with_copy_button(mut self, enabled: bool) -> Self {
+        self.copy_button = enabled;
+        self
+    }
+
+    /// Bind the label's access key to `target`
+    ///
+    /// An `&` before a letter or digit in the label text is underlined, and
+    /// Alt plus that key presses `target` - typically the button or control
+    /// the label describes. Without this, `&` in label text is shown as-is.
+    ///
+    /// # Example
+    /// ```ignore
+    /// LabelBuilder::new("&Name:")
+    ///     .mnemonic_for(name_button)
+    ///     .build(parent);
+    /// ```
+    pub fn mnemonic_for(mut self, target: Entity) -> Self {
+        self.mnemonic_target = Some(target);
+        self
+    }
+
     /// Resolve final styles (priority: override > themed > default)
     fn resolve_styles(&self) -> ResolvedLabelStyle {
         // Font size priority: direct override > themed > default
@@ -157,7 +226,13 @@ impl LabelBuilder {
             .or(self.themed_text_color)
             .unwrap_or_else(|| self.default_text_color());
 
-        ResolvedLabelStyle { font_size, text_color }
+        // Font priority: direct override > themed role font > Bevy default
+        let role = self.font_role.unwrap_or_else(|| self.size.font_role());
+        let font = self.font.clone()
+            .or_else(|| self.themed_fonts.as_ref().map(|fonts| fonts.font_for(role)))
+            .unwrap_or_default();
+
+        ResolvedLabelStyle { font, font_size, text_color }
     }
 
     /// Get default font size (no theme)
@@ -202,27 +277,88 @@ impl UiBuilder for LabelBuilder {
     fn build(mut self, parent: &mut ChildSpawnerCommands) -> Entity {
         let styles = self.resolve_styles();
 
-        let entity = parent
-            .spawn((
-                Text::new(self.text.clone()),
-                TextFont {
-                    font_size: styles.font_size,
-                    ..default()
-                },
-                TextColor(styles.text_color),
-                Node {
+        let text_font = TextFont {
+            font: styles.font.clone(),
+            font_size: styles.font_size,
+            ..default()
+        };
+
+        // Only labels bound to a target treat `&` as an access key marker
+        let (text, mnemonic_spans) = match self.mnemonic_target {
+            Some(target) => {
+                let mnemonic = parse_mnemonic(&self.text);
+                if let Some(key) = mnemonic.key() {
+                    parent.commands().entity(target).insert(Mnemonic(key));
+                }
+                mnemonic.spans()
+            }
+            None => (self.text.clone(), None),
+        };
+
+        let label = (
+            Text::new(text),
+            text_font.clone(),
+            TextColor(styles.text_color),
+            UiLabel {
+                size: self.size,
+                variant: self.variant,
+            },
+        );
+
+        let (root, entity) = if self.copy_button {
+            let mut entity = Entity::PLACEHOLDER;
+            let row = parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(8.0),
                     margin: self.base.node.margin,
                     ..default()
-                },
-                UiLabel {
-                    size: self.size,
-                    variant: self.variant,
-                },
-            ))
-            .id();
-
-        self.base.apply(entity, &mut parent.commands());
-        entity
+                })
+                .with_children(|row| {
+                    entity = row.spawn(label).id();
+                    row.spawn((
+                        Button,
+                        Text::new("Copy"),
+                        TextFont {
+                            font: styles.font,
+                            font_size: (styles.font_size * 0.75).max(10.0),
+                            ..default()
+                        },
+                        TextColor(styles.text_color),
+                        Node {
+                            padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+                            ..default()
+                        },
+                        BackgroundColor(styles.text_color.with_alpha(0.1)),
+                        BorderRadius::all(Val::Px(4.0)),
+                        LabelCopyButton::new(entity),
+                    ));
+                })
+                .id();
+            (row, entity)
+        } else {
+            let entity = parent
+                .spawn((
+                    label,
+                    Node {
+                        margin: self.base.node.margin,
+                        ..default()
+                    },
+                ))
+                .id();
+            (entity, entity)
+        };
+
+        with_mnemonic_spans(
+            &mut parent.commands().entity(entity),
+            mnemonic_spans,
+            &text_font,
+            TextColor(styles.text_color),
+        );
+
+        self.base.apply(root, &mut parent.commands());
+        root
     }
 
     fn insert(mut self, bundle: impl Bundle + Clone) -> Self {
@@ -2,6 +2,8 @@
 
 // Private submodules - no implementation here!
 mod builder;
+mod plugin;
+mod systems;
 mod types;
 
 // Public exports only
@@ -9,7 +11,8 @@ pub use builder::{
     LabelBuilder, label, heading, title, secondary_text,
     error_label, success_label, warning_label,
 };
-pub use types::{Label, LabelSize};
+pub use plugin::LabelPlugin;
+pub use types::{Label, LabelCopyButton, LabelSize};
 
 // Deprecated re-export for backwards compatibility
 #[allow(deprecated)]
@@ -0,0 +1,13 @@
+//! Plugin for label functionality
+#![allow(missing_docs)]
+
+use bevy_plugin_builder::define_plugin;
+use super::systems::*;
+
+/// Plugin for label copy buttons
+define_plugin!(LabelPlugin {
+    update: [
+        handle_label_copy_buttons,
+        update_label_copy_buttons,
+    ]
+});
@@ -0,0 +1,86 @@
+//! Systems for label copy buttons
+
+use bevy::prelude::*;
+
+use super::types::LabelCopyButton;
+use crate::utils::clipboard::copy_to_clipboard;
+
+#[cfg(feature = "toast")]
+use crate::components::toast::{ToastBuilder, ToastQueue};
+
+/// Button text shown while idle
+const COPY_TEXT: &str = "Copy";
+/// Button text shown after a successful copy
+const COPIED_TEXT: &str = "Copied!";
+/// How long the "Copied!" confirmation lasts
+const FEEDBACK_SECS: f32 = 1.5;
+
+/// Copy the label text to the clipboard when its copy button is clicked
+pub fn handle_label_copy_buttons(
+    mut buttons: Query<(&Interaction, &mut LabelCopyButton, &mut Text), Changed<Interaction>>,
+    labels: Query<&Text, Without<LabelCopyButton>>,
+    spans: Query<&TextSpan>,
+    children: Query<&Children>,
+    #[cfg(feature = "toast")] mut toasts: Option<ResMut<ToastQueue>>,
+) {
+    for (interaction, mut button, mut button_text) in buttons.iter_mut() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Ok(label) = labels.get(button.label) else {
+            continue;
+        };
+        // Labels with an access key keep the rest of their text in spans
+        let mut text = label.0.clone();
+        for span in children.iter_descendants(button.label) {
+            if let Ok(span) = spans.get(span) {
+                text.push_str(&span.0);
+            }
+        }
+        copy_to_clipboard(&text);
+
+        #[cfg(feature = "toast")]
+        if let Some(toasts) = toasts.as_deref_mut() {
+            // Repeated clicks while the confirmation is showing don't stack toasts
+            if button.feedback.is_none() {
+                ToastBuilder::success(COPIED_TEXT)
+                    .duration_ms((FEEDBACK_SECS * 1000.0) as u64)
+                    .show(toasts);
+            }
+            button.feedback = Some(Timer::from_seconds(FEEDBACK_SECS, TimerMode::Once));
+            continue;
+        }
+
+        button.feedback = Some(Timer::from_seconds(FEEDBACK_SECS, TimerMode::Once));
+        button_text.0 = COPIED_TEXT.to_string();
+    }
+}
+
+/// Tint copy buttons on hover and restore their text once the confirmation expires
+pub fn update_label_copy_buttons(
+    time: Res<Time>,
+    mut buttons: Query<(&Interaction, &mut LabelCopyButton, &mut Text, &TextColor, &mut BackgroundColor)>,
+) {
+    for (interaction, mut button, mut text, text_color, mut background) in buttons.iter_mut() {
+        let alpha = match interaction {
+            Interaction::Pressed => 0.3,
+            Interaction::Hovered => 0.2,
+            Interaction::None => 0.1,
+        };
+        let tint = text_color.0.with_alpha(alpha);
+        if background.0 != tint {
+            background.0 = tint;
+        }
+
+        let Some(timer) = button.feedback.as_mut() else {
+            continue;
+        };
+        if timer.tick(time.delta()).just_finished() {
+            button.feedback = None;
+            if text.0 != COPY_TEXT {
+                text.0 = COPY_TEXT.to_string();
+            }
+        }
+    }
+}
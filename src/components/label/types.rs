@@ -2,7 +2,7 @@
 
 use bevy::prelude::*;
 
-use crate::theme::{SemanticVariant, UiTheme};
+use crate::theme::{FontRole, SemanticVariant, UiTheme};
 
 /// Component for text labels
 #[derive(Component, Debug)]
@@ -13,6 +13,24 @@ pub struct Label {
     pub variant: Option<SemanticVariant>,
 }
 
+/// Copy button placed next to a label built with `with_copy_button(true)`
+///
+/// Clicking it copies the label's text to the system clipboard.
+#[derive(Component, Debug)]
+pub struct LabelCopyButton {
+    /// The label whose text is copied
+    pub label: Entity,
+    /// Counts down while the button shows its "Copied!" confirmation
+    pub(crate) feedback: Option<Timer>,
+}
+
+impl LabelCopyButton {
+    /// Create a copy button for a label
+    pub fn new(label: Entity) -> Self {
+        Self { label, feedback: None }
+    }
+}
+
 /// Label size/typography variants (controls font size only)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum LabelSize {
@@ -41,6 +59,15 @@ impl LabelSize {
         }
     }
 
+    /// Get the font role used for this label size
+    pub fn font_role(&self) -> FontRole {
+        match self {
+            LabelSize::Title => FontRole::Display,
+            LabelSize::Heading => FontRole::Heading,
+            LabelSize::Body | LabelSize::Caption | LabelSize::Small => FontRole::Body,
+        }
+    }
+
     /// Get the default text color for this label size from theme
     /// (used when no semantic variant is specified)
     pub fn default_text_color(&self, theme: &UiTheme) -> Color {
@@ -2,6 +2,7 @@
 //!
 //! This module contains all the UI component builders organized by type:
 //!
+//! - **badge** - Count and dot badges on any widget
 //! - **button** - Interactive buttons with variants and sizes
 //! - **checkbox** - Toggle checkboxes
 //! - **context_menu** - Right-click context menus
@@ -12,9 +13,11 @@
 //! - **number_input** - Numeric input fields
 //! - **panel** - Container panels
 //! - **progress** - Progress bars
+//! - **reorderable_list** - Drag-to-reorder lists
 //! - **scroll_view** - Scrollable containers
 //! - **separator** - Visual dividers
 //! - **slider** - Value sliders
+//! - **split_pane** - Resizable two-pane containers
 //! - **tabs** - Tabbed interfaces
 //! - **text_input** - Text input fields
 //! - **toast** - Toast notifications
@@ -30,6 +33,9 @@
 //!     .build(parent);
 //! ```
 
+#[cfg(feature = "badge")]
+pub mod badge;
+
 #[cfg(feature = "button")]
 pub mod button;
 
@@ -60,6 +66,9 @@ pub mod panel;
 #[cfg(feature = "progress")]
 pub mod progress;
 
+#[cfg(feature = "reorderable_list")]
+pub mod reorderable_list;
+
 // scroll_view is always available (core functionality)
 pub mod scroll_view;
 
@@ -69,6 +78,9 @@ pub mod separator;
 #[cfg(feature = "slider")]
 pub mod slider;
 
+#[cfg(feature = "split_pane")]
+pub mod split_pane;
+
 #[cfg(feature = "tabs")]
 pub mod tabs;
 
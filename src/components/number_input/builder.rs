@@ -4,6 +4,7 @@ use bevy::prelude::*;
 use super::types::*;
 use crate::components::text_input::{TextInputBuilder, InputFilter};
 use crate::traits::{UiBuilder, LayoutBuilder, BuilderBase};
+use crate::validation::{Validated, ValidationState};
 
 /// Builder for creating number input fields with validation
 ///
@@ -25,6 +26,7 @@ pub struct NumberInputBuilder {
     max: Option<f32>,
     step: f32,
     default_value: Option<f32>,
+    allow_empty: bool,
     placeholder: Option<String>,
     base: BuilderBase,
 }
@@ -40,6 +42,7 @@ impl NumberInputBuilder {
             max: None,
             step: 1.0,
             default_value: None,
+            allow_empty: false,
             placeholder: None,
             base,
         }
@@ -69,6 +72,17 @@ impl NumberInputBuilder {
         self
     }
 
+    /// Let a blank field mean "no value"
+    ///
+    /// When enabled, clearing the field emits
+    /// [`NumberInputChangedEvent`] with `value: None` and skips range
+    /// validation, which suits optional settings. When disabled (the default),
+    /// a blank field reads as 0 clamped to the range.
+    pub fn allow_empty(mut self, allow: bool) -> Self {
+        self.allow_empty = allow;
+        self
+    }
+
     /// Set the width of the input field
     pub fn width(mut self, width: Val) -> Self {
         self.base.node.width = width;
@@ -93,6 +107,7 @@ impl UiBuilder for NumberInputBuilder {
             min: self.min,
             max: self.max,
             step: self.step,
+            allow_empty: self.allow_empty,
         };
 
         // Clamp default value to range if provided
@@ -128,18 +143,22 @@ impl UiBuilder for NumberInputBuilder {
             text_input = text_input.with_value(&value_str);
         }
 
+        // Build the text input
+        let entity = text_input.build(parent);
+
         // Add automatic validation for range if min or max is specified
         if self.min.is_some() || self.max.is_some() {
             let min = self.min.unwrap_or(f32::MIN);
             let max = self.max.unwrap_or(f32::MAX);
-            text_input = text_input.with_validation(vec![
+            let mut validated = Validated::new(vec![
                 crate::ValidationRule::Range { min, max }
             ]);
+            if self.allow_empty {
+                validated = validated.allow_empty();
+            }
+            parent.commands().entity(entity).insert((validated, ValidationState::default()));
         }
 
-        // Build the text input
-        let entity = text_input.build(parent);
-
         // Add NumberInput specific components
         parent.commands().entity(entity).insert((
             NumberInput,
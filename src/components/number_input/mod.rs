@@ -1,8 +1,11 @@
 //! NumberInputBuilder for creating validated number input fields
 
 mod builder;
+mod plugin;
+mod systems;
 mod types;
 
 // Public exports
 pub use builder::NumberInputBuilder;
-pub use types::{NumberInput, NumberInputConfig};
+pub use plugin::NumberInputPlugin;
+pub use types::{NumberInput, NumberInputConfig, NumberInputChangedEvent};
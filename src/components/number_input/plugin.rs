@@ -0,0 +1,13 @@
+//! Number input plugin
+#![allow(missing_docs)]
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+use super::systems::emit_number_input_changes;
+use super::types::NumberInputChangedEvent;
+
+// Plugin that turns number input text changes into parsed values
+define_plugin!(NumberInputPlugin {
+    messages: [NumberInputChangedEvent],
+    update: [emit_number_input_changes]
+});
@@ -0,0 +1,25 @@
+//! Number input systems
+
+use bevy::prelude::*;
+use crate::components::text_input::TextInputChangeEvent;
+use super::types::*;
+
+/// Re-emit text changes on number inputs as parsed values
+pub fn emit_number_input_changes(
+    mut text_changes: MessageReader<TextInputChangeEvent>,
+    inputs: Query<&NumberInputConfig, With<NumberInput>>,
+    mut changes: MessageWriter<NumberInputChangedEvent>,
+) {
+    for event in text_changes.read() {
+        let Ok(config) = inputs.get(event.entity) else {
+            continue;
+        };
+
+        if let Some(value) = config.parse_text(&event.text) {
+            changes.write(NumberInputChangedEvent {
+                entity: event.entity,
+                value,
+            });
+        }
+    }
+}
@@ -15,6 +15,8 @@ pub struct NumberInputConfig {
     pub max: Option<f32>,
     /// Step size for increment/decrement (future: +/- buttons)
     pub step: f32,
+    /// An empty field means "no value" (`None`) instead of coercing to 0 / `min`
+    pub allow_empty: bool,
 }
 
 impl Default for NumberInputConfig {
@@ -23,6 +25,7 @@ impl Default for NumberInputConfig {
             min: None,
             max: None,
             step: 1.0,
+            allow_empty: false,
         }
     }
 }
@@ -40,6 +43,29 @@ impl NumberInputConfig {
         result
     }
 
+    /// Interpret the field text as a value
+    ///
+    /// - Empty text is `Some(None)` when `allow_empty` is set, otherwise it
+    ///   coerces to 0 clamped to the range (so `min` when `min > 0`).
+    /// - Text that isn't a finite number yet (e.g. a lone `-` or `.`) is `None`.
+    /// - Anything else is `Some(Some(value))`, unclamped; range errors are
+    ///   reported by validation instead.
+    pub fn parse_text(&self, text: &str) -> Option<Option<f64>> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Some(if self.allow_empty {
+                None
+            } else {
+                Some(self.clamp_value(0.0) as f64)
+            });
+        }
+
+        text.parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite())
+            .map(Some)
+    }
+
     /// Validate if a value is within the configured range
     pub fn is_valid(&self, value: f32) -> bool {
         if let Some(min) = self.min {
@@ -55,3 +81,14 @@ impl NumberInputConfig {
         true
     }
 }
+
+/// Message emitted when a number input's text parses to a new value
+///
+/// Partial input that isn't a number yet (a lone `-`, say) emits nothing.
+#[derive(Message, Debug, Clone)]
+pub struct NumberInputChangedEvent {
+    /// The number input entity
+    pub entity: Entity,
+    /// The new value; `None` when the field is blank and `allow_empty` is set
+    pub value: Option<f64>,
+}
@@ -1,9 +1,11 @@
 //! PanelBuilder implementation
 
 use bevy::prelude::*;
+use bevy::ui::{BackgroundGradient, ColorStop, LinearGradient};
 use crate::{dimensions, components::label::{LabelBuilder, LabelSize}};
-use crate::theme::UiTheme;
+use crate::theme::{SemanticVariant, UiTheme};
 use crate::traits::{UiBuilder, LayoutBuilder, BuilderBase};
+use crate::components::scroll_view::ScrollViewBuilder;
 use super::types::*;
 
 /// Builder for creating panels with consistent styling
@@ -13,8 +15,15 @@ pub struct PanelBuilder {
     title: Option<String>,
     custom_border: Option<UiRect>,
     border_color: Option<Color>,
+    scroll_max_height: Option<Val>,
+    blur_backdrop: bool,
+    gradient: Option<(Color, Color, GradientDirection)>,
+    accent_gradient: Option<(SemanticVariant, GradientDirection)>,
     // Theme-resolved values (set via .themed())
     themed_background: Option<Color>,
+    // Theme kept to resolve the accent gradient in build()
+    themed_accents: Option<UiTheme>,
+    themed_frosted: Option<Color>,
     themed_border_color: Option<Color>,
     base: BuilderBase,
 }
@@ -36,7 +45,13 @@ impl PanelBuilder {
             title: None,
             custom_border: None,
             border_color: None,
+            scroll_max_height: None,
+            blur_backdrop: false,
+            gradient: None,
+            accent_gradient: None,
             themed_background: None,
+            themed_accents: None,
+            themed_frosted: None,
             themed_border_color: None,
             base,
         }
@@ -60,7 +75,9 @@ impl PanelBuilder {
     /// ```
     pub fn themed(mut self, theme: &UiTheme) -> Self {
         self.themed_background = Some(self.style.background_color_from_theme(theme));
+        self.themed_frosted = Some(PanelStyle::frosted_color_from_theme(theme));
         self.themed_border_color = Some(self.style.border_color_from_theme(theme));
+        self.themed_accents = Some(theme.clone());
         self
     }
 
@@ -112,9 +129,14 @@ impl PanelBuilder {
         self
     }
 
-    /// Make this panel vertically scrollable
-    pub fn scrollable(mut self) -> Self {
-        self.base.node.overflow = Overflow::scroll_y();
+    /// Wrap the panel's content in a vertical [`ScrollView`](crate::ScrollView)
+    ///
+    /// Children added through [`build_with_children`](Self::build_with_children)
+    /// are spawned inside the scroll view, which grows with its content up to
+    /// `max_height` and scrolls beyond that. The title (if any) stays pinned
+    /// above the scrolling area.
+    pub fn scrollable(mut self, max_height: Val) -> Self {
+        self.scroll_max_height = Some(max_height);
         self
     }
 
@@ -172,12 +194,54 @@ impl PanelBuilder {
         self
     }
 
+    /// Frost the content behind the panel instead of just dimming it
+    ///
+    /// Meant for [`PanelStyle::FullScreen`] overlays such as drawers and
+    /// pause screens. Bevy's UI has no backdrop blur yet, so this swaps the
+    /// background for a dense frosted tint. A custom background still wins.
+    pub fn blur_backdrop(mut self, blur: bool) -> Self {
+        self.blur_backdrop = blur;
+        self
+    }
+
     /// Set a custom background color
     pub fn custom_background(mut self, color: Color) -> Self {
         self.custom_background = Some(color);
         self
     }
 
+    /// Paint the background with a linear gradient from `from` to `to`
+    ///
+    /// Meant for hero panels and headers. Takes precedence over the style's
+    /// background color; a custom background still shows through
+    /// translucent stops.
+    ///
+    /// # Example
+    /// ```ignore
+    /// PanelBuilder::new()
+    ///     .background_gradient(
+    ///         Color::srgb(0.3, 0.2, 0.6),
+    ///         Color::srgb(0.1, 0.4, 0.7),
+    ///         GradientDirection::ToRight,
+    ///     )
+    ///     .build(parent);
+    /// ```
+    pub fn background_gradient(mut self, from: Color, to: Color, direction: GradientDirection) -> Self {
+        self.gradient = Some((from, to, direction));
+        self
+    }
+
+    /// Paint the background with a gradient in the theme's `variant` colors
+    ///
+    /// The stops come from the theme passed to [`themed`](Self::themed), or
+    /// the default theme otherwise, running from the
+    /// variant's lighter hover shade to its darker pressed shade. An explicit
+    /// [`background_gradient`](Self::background_gradient) wins.
+    pub fn accent_gradient(mut self, variant: SemanticVariant, direction: GradientDirection) -> Self {
+        self.accent_gradient = Some((variant, direction));
+        self
+    }
+
     /// Set a title for the panel
     pub fn with_title(mut self, title: impl Into<String>) -> Self {
         self.title = Some(title.into());
@@ -240,18 +304,45 @@ impl PanelBuilder {
     where
         F: FnOnce(&mut ChildSpawnerCommands),
     {
-        let entity = self.build(parent);
-        parent.commands().entity(entity).with_children(children);
+        let (entity, content) = self.spawn(parent);
+        parent.commands().entity(content).with_children(children);
         entity
     }
-}
 
-impl UiBuilder for PanelBuilder {
-    fn build(mut self, parent: &mut ChildSpawnerCommands) -> Entity {
+    /// Resolve the background gradient (explicit > themed accent > default-theme accent)
+    fn resolve_gradient(&self) -> Option<(Color, Color, GradientDirection)> {
+        self.gradient.or_else(|| {
+            self.accent_gradient.map(|(variant, direction)| {
+                let (from, to) = match &self.themed_accents {
+                    Some(theme) => accent_gradient_stops(variant, theme),
+                    None => accent_gradient_stops(variant, &UiTheme::default()),
+                };
+                (from, to, direction)
+            })
+        })
+    }
+
+    /// Spawn the panel, returning the panel entity and the entity its
+    /// content should be spawned into (the scroll view when scrollable)
+    fn spawn(mut self, parent: &mut ChildSpawnerCommands) -> (Entity, Entity) {
         // Color priority: custom override > themed > default
-        let background_color = self.custom_background
-            .or(self.themed_background)
-            .unwrap_or_else(|| self.style.default_background_color());
+        let background_color = if self.blur_backdrop {
+            self.custom_background
+                .or(self.themed_frosted)
+                .unwrap_or_else(PanelStyle::default_frosted_color)
+        } else {
+            self.custom_background
+                .or(self.themed_background)
+                .unwrap_or_else(|| self.style.default_background_color())
+        };
+
+        let gradient = self.resolve_gradient();
+
+        // The gradient replaces the style background unless one was set explicitly
+        let background_color = match (gradient, self.custom_background) {
+            (Some(_), None) => Color::NONE,
+            _ => background_color,
+        };
 
         let border = self.custom_border
             .unwrap_or_else(|| UiRect::all(self.style.border_width()));
@@ -264,6 +355,8 @@ impl UiBuilder for PanelBuilder {
             .unwrap_or_else(|| self.style.default_border_color());
 
         let title = self.title.clone();
+        let scroll_max_height = self.scroll_max_height;
+        let row_gap = self.base.node.row_gap;
 
         let mut panel_entity = parent.spawn((
             self.base.node,
@@ -272,6 +365,13 @@ impl UiBuilder for PanelBuilder {
             Panel { style: self.style },
         ));
 
+        if let Some((from, to, direction)) = gradient {
+            panel_entity.insert(BackgroundGradient::from(LinearGradient::new(
+                direction.angle(),
+                vec![ColorStop::auto(from), ColorStop::auto(to)],
+            )));
+        }
+
         // Add title if provided
         if let Some(title_text) = title {
             panel_entity.with_children(|parent| {
@@ -284,12 +384,31 @@ impl UiBuilder for PanelBuilder {
 
         let entity = panel_entity.id();
 
+        // Content goes in a scroll view below the title when scrollable
+        let mut content = entity;
+        if let Some(max_height) = scroll_max_height {
+            parent.commands().entity(entity).with_children(|panel| {
+                content = ScrollViewBuilder::new()
+                    .width(Val::Percent(100.0))
+                    .max_height(max_height)
+                    .padding(UiRect::ZERO)
+                    .gap(row_gap)
+                    .build(panel);
+            });
+        }
+
         // Apply hooks
         for hook in self.base.hooks {
             hook(&mut parent.commands().entity(entity));
         }
 
-        entity
+        (entity, content)
+    }
+}
+
+impl UiBuilder for PanelBuilder {
+    fn build(self, parent: &mut ChildSpawnerCommands) -> Entity {
+        self.spawn(parent).0
     }
 
     fn insert(mut self, bundle: impl Bundle + Clone) -> Self {
@@ -335,4 +454,28 @@ impl LayoutBuilder for PanelBuilder {
 /// Convenience function to create a panel builder
 pub fn panel() -> PanelBuilder {
     PanelBuilder::new()
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accent_gradient_and_theme_resolve_in_any_order() {
+        let theme = UiTheme::default().with_success(Color::srgb(0.1, 0.8, 0.3));
+        let (from, to) = accent_gradient_stops(SemanticVariant::Success, &theme);
+        let expected = Some((from, to, GradientDirection::ToBottom));
+
+        let accent_first = PanelBuilder::new()
+            .accent_gradient(SemanticVariant::Success, GradientDirection::ToBottom)
+            .themed(&theme)
+            .resolve_gradient();
+        let theme_first = PanelBuilder::new()
+            .themed(&theme)
+            .accent_gradient(SemanticVariant::Success, GradientDirection::ToBottom)
+            .resolve_gradient();
+
+        assert_eq!(accent_first, expected);
+        assert_eq!(theme_first, expected);
+    }
+}
@@ -6,4 +6,4 @@ mod types;
 
 // Public exports only
 pub use builder::{PanelBuilder, panel};
-pub use types::{Panel, PanelStyle};
\ No newline at end of file
+pub use types::{Panel, PanelStyle, GradientDirection};
\ No newline at end of file
@@ -1,8 +1,9 @@
 //! Panel component types and markers
 
 use bevy::prelude::*;
+use bevy::ui::LinearGradient;
 
-use crate::theme::UiTheme;
+use crate::theme::{SemanticVariant, UiTheme};
 
 /// Component for panels/containers
 #[derive(Component, Debug)]
@@ -43,11 +44,17 @@ impl PanelStyle {
             PanelStyle::Dark => theme.colors.surface.dark,
             PanelStyle::Light => theme.colors.surface.light,
             PanelStyle::Bordered => theme.colors.surface.primary,
-            PanelStyle::FullScreen => theme.colors.overlay,
+            PanelStyle::FullScreen => theme.backdrop_color(theme.colors.overlay),
             PanelStyle::Card => theme.colors.surface.secondary,
         }
     }
 
+    /// Get the frosted backdrop color from theme (see `PanelBuilder::blur_backdrop`)
+    pub fn frosted_color_from_theme(theme: &UiTheme) -> Color {
+        use bevy::color::Alpha;
+        theme.backdrop_color(theme.colors.surface.tertiary.with_alpha(0.8))
+    }
+
     /// Get the border color from theme
     pub fn border_color_from_theme(&self, theme: &UiTheme) -> Color {
         match self {
@@ -67,6 +74,50 @@ impl PanelStyle {
     }
 }
 
+/// Direction a panel's background gradient runs in
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum GradientDirection {
+    /// Left edge to right edge
+    #[default]
+    ToRight,
+    /// Right edge to left edge
+    ToLeft,
+    /// Top edge to bottom edge
+    ToBottom,
+    /// Bottom edge to top edge
+    ToTop,
+    /// Top-left corner to bottom-right corner
+    ToBottomRight,
+    /// Bottom-left corner to top-right corner
+    ToTopRight,
+    /// Any angle in degrees, clockwise from pointing up (CSS convention)
+    Degrees(f32),
+}
+
+impl GradientDirection {
+    /// The gradient angle in radians
+    pub fn angle(&self) -> f32 {
+        match self {
+            GradientDirection::ToRight => LinearGradient::TO_RIGHT,
+            GradientDirection::ToLeft => LinearGradient::TO_LEFT,
+            GradientDirection::ToBottom => LinearGradient::TO_BOTTOM,
+            GradientDirection::ToTop => LinearGradient::TO_TOP,
+            GradientDirection::ToBottomRight => LinearGradient::TO_BOTTOM_RIGHT,
+            GradientDirection::ToTopRight => LinearGradient::TO_TOP_RIGHT,
+            GradientDirection::Degrees(degrees) => degrees.to_radians(),
+        }
+    }
+}
+
+/// Gradient stops for an accent gradient in `variant`'s colors
+///
+/// Runs from the variant's hover shade to its pressed shade, a lighter to
+/// darker take on the base color.
+pub(crate) fn accent_gradient_stops(variant: SemanticVariant, theme: &UiTheme) -> (Color, Color) {
+    let colors = variant.color_scale(theme).resolve();
+    (colors.background_hover, colors.background_pressed)
+}
+
 // Default colors (dark theme) for when no theme is provided
 pub(crate) mod defaults {
     use bevy::prelude::Color;
@@ -75,6 +126,7 @@ pub(crate) mod defaults {
     pub const BACKGROUND_ELEVATED: Color = Color::srgb(0.2, 0.2, 0.2);
     pub const BACKGROUND_DARK: Color = Color::srgb(0.1, 0.1, 0.1);
     pub const BACKGROUND_OVERLAY: Color = Color::srgba(0.0, 0.0, 0.0, 0.8);
+    pub const BACKGROUND_FROSTED: Color = Color::srgba(0.22, 0.24, 0.28, 0.8);
     pub const BORDER_DEFAULT: Color = Color::srgb(0.3, 0.3, 0.3);
     pub const BORDER_SUBTLE: Color = Color::srgba(0.2, 0.2, 0.2, 0.3);
 }
@@ -94,6 +146,11 @@ impl PanelStyle {
         }
     }
 
+    /// Get the default frosted backdrop color (no theme)
+    pub(crate) fn default_frosted_color() -> Color {
+        defaults::BACKGROUND_FROSTED
+    }
+
     /// Get the default border color (no theme)
     pub(crate) fn default_border_color(&self) -> Color {
         match self {
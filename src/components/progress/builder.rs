@@ -15,6 +15,7 @@ pub struct ProgressBarBuilder {
     track_color: Option<Color>,
     fill_color: Option<Color>,
     show_label: bool,
+    label_position: ProgressLabelPosition,
     custom_label: Option<String>,
     animated: bool,
     // Theme-resolved values (set via .themed())
@@ -39,6 +40,7 @@ impl ProgressBarBuilder {
             track_color: None,
             fill_color: None,
             show_label: false,
+            label_position: ProgressLabelPosition::default(),
             custom_label: None,
             animated: false,
             themed_track_color: None,
@@ -120,12 +122,34 @@ impl ProgressBarBuilder {
         self
     }
 
-    /// Show a percentage label
+    /// Show a percentage label (below the bar unless
+    /// [`label_position`](Self::label_position) says otherwise)
     pub fn with_label(mut self) -> Self {
         self.show_label = true;
         self
     }
 
+    /// Set where the label is drawn, turning it on unless `None`
+    ///
+    /// `Below` (the default) centers the label under the bar, and `Outside`
+    /// puts it to the right of the bar. `Inside` centers it in the bar, in black or white to contrast with the
+    /// fill or track behind it; give the bar room for the text with
+    /// [`height`](Self::height). Circular bars always draw the label in the
+    /// middle of the ring.
+    ///
+    /// # Example
+    /// ```ignore
+    /// ProgressBarBuilder::new(0.4)
+    ///     .height(Val::Px(20.0))
+    ///     .label_position(ProgressLabelPosition::Inside)
+    ///     .build(parent);
+    /// ```
+    pub fn label_position(mut self, position: ProgressLabelPosition) -> Self {
+        self.label_position = position;
+        self.show_label = position != ProgressLabelPosition::None;
+        self
+    }
+
     /// Show a custom label text instead of percentage
     pub fn with_label_text(mut self, text: impl Into<String>) -> Self {
         self.custom_label = Some(text.into());
@@ -159,13 +183,86 @@ impl UiBuilder for ProgressBarBuilder {
         let label_color = self.themed_label_color
             .unwrap_or_else(|| self.style.default_label_color());
 
-        // Ensure container properties
-        self.base.node.flex_direction = FlexDirection::Column;
+        let show_label = self.show_label && self.label_position != ProgressLabelPosition::None;
+        let below = show_label && self.label_position == ProgressLabelPosition::Below;
+        let outside = show_label && self.label_position == ProgressLabelPosition::Outside;
+        let inside = show_label && self.label_position == ProgressLabelPosition::Inside;
+
+        // Ensure container properties; an outside label sits beside the track
+        if outside {
+            self.base.node.flex_direction = FlexDirection::Row;
+            self.base.node.align_items = AlignItems::Center;
+            self.base.node.column_gap = Val::Px(dimensions::SPACING_SMALL);
+        } else {
+            self.base.node.flex_direction = FlexDirection::Column;
+        }
 
-        let show_label = self.show_label;
         let custom_label = self.custom_label.clone();
         let value = self.value;
 
+        if let ProgressBarStyle::Circular { thickness } = self.style {
+            let diameter = match self.base.node.width {
+                Val::Px(px) => px,
+                _ => DEFAULT_RING_DIAMETER,
+            };
+            self.base.node.width = Val::Px(diameter);
+            self.base.node.height = Val::Px(diameter);
+
+            let label_text = show_label.then(|| {
+                custom_label.unwrap_or_else(|| format!("{}%", (value * 100.0) as i32))
+            });
+
+            let entity = parent
+                .spawn((
+                    self.base.node,
+                    BackgroundColor(Color::NONE),
+                ))
+                .with_children(|container| {
+                    spawn_ring(container, diameter, thickness, value, fill_color, track_color);
+
+                    if let Some(label_text) = label_text {
+                        container
+                            .spawn(Node {
+                                position_type: PositionType::Absolute,
+                                width: Val::Percent(100.0),
+                                height: Val::Percent(100.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            })
+                            .with_children(|label_container| {
+                                label_container.spawn((
+                                    Text::new(label_text),
+                                    TextFont {
+                                        font_size: dimensions::FONT_SIZE_SMALL,
+                                        ..default()
+                                    },
+                                    TextColor(label_color),
+                                    ProgressBarLabel,
+                                ));
+                            });
+                    }
+                })
+                .insert(ProgressBar {
+                    value: self.value,
+                    style: self.style,
+                    animated: self.animated,
+                })
+                .id();
+
+            for hook in self.base.hooks {
+                hook(&mut parent.commands().entity(entity));
+            }
+
+            return entity;
+        }
+
+        let label_text = show_label.then(|| {
+            custom_label.unwrap_or_else(|| format!("{}%", (value * 100.0) as i32))
+        });
+        let inside_label = label_text.clone().filter(|_| inside);
+        let outer_label = label_text.filter(|_| below || outside);
+
         let entity = parent
             .spawn((
                 self.base.node,
@@ -176,7 +273,8 @@ impl UiBuilder for ProgressBarBuilder {
                 container
                     .spawn((
                         Node {
-                            width: Val::Percent(100.0),
+                            width: if outside { Val::Auto } else { Val::Percent(100.0) },
+                            flex_grow: if outside { 1.0 } else { 0.0 },
                             height: track_height,
                             position_type: PositionType::Relative,
                             overflow: Overflow::clip(),
@@ -201,20 +299,57 @@ impl UiBuilder for ProgressBarBuilder {
                             BorderRadius::all(Val::Px(2.0)),
                             ProgressBarFill,
                         ));
+
+                        // Label centered over the fill
+                        if let Some(label_text) = inside_label {
+                            let contrast = ProgressLabelContrast {
+                                fill: fill_color,
+                                track: track_color,
+                            };
+
+                            track
+                                .spawn(Node {
+                                    position_type: PositionType::Absolute,
+                                    width: Val::Percent(100.0),
+                                    height: Val::Percent(100.0),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                })
+                                .with_children(|label_container| {
+                                    label_container.spawn((
+                                        Text::new(label_text),
+                                        TextFont {
+                                            font_size: dimensions::FONT_SIZE_SMALL,
+                                            ..default()
+                                        },
+                                        TextColor(contrast.color_for(value)),
+                                        contrast,
+                                        ProgressBarLabel,
+                                    ));
+                                });
+                        }
                     });
 
-                // Optional label
-                if show_label {
-                    let label_text = custom_label
-                        .unwrap_or_else(|| format!("{}%", (value * 100.0) as i32));
+                // Label below or beside the track
+                if let Some(label_text) = outer_label {
+                    let label_node = if outside {
+                        Node {
+                            min_width: Val::Px(OUTSIDE_LABEL_MIN_WIDTH),
+                            justify_content: JustifyContent::FlexEnd,
+                            ..default()
+                        }
+                    } else {
+                        Node {
+                            margin: UiRect::top(Val::Px(4.0)),
+                            justify_content: JustifyContent::Center,
+                            ..default()
+                        }
+                    };
 
                     container
                         .spawn((
-                            Node {
-                                margin: UiRect::top(Val::Px(4.0)),
-                                justify_content: JustifyContent::Center,
-                                ..default()
-                            },
+                            label_node,
                             BackgroundColor(Color::NONE),
                         ))
                         .with_children(|label_container| {
@@ -285,6 +420,63 @@ impl LayoutBuilder for ProgressBarBuilder {
     }
 }
 
+/// Room kept for a label beside the bar, so the bar doesn't jump as the
+/// percentage gains digits
+const OUTSIDE_LABEL_MIN_WIDTH: f32 = 36.0;
+
+/// Diameter of a circular progress ring when no pixel width is set
+const DEFAULT_RING_DIAMETER: f32 = 48.0;
+
+/// Spawn the overlapping round segments that make up a circular progress ring
+fn spawn_ring(
+    parent: &mut ChildSpawnerCommands,
+    diameter: f32,
+    thickness: f32,
+    value: f32,
+    fill_color: Color,
+    track_color: Color,
+) {
+    let thickness = thickness.clamp(1.0, diameter / 2.0);
+    let radius = (diameter - thickness) / 2.0;
+    // Space segments at half their width so neighbours overlap into a smooth stroke
+    let circumference = std::f32::consts::TAU * radius;
+    let count = ((circumference / (thickness * 0.5)).ceil() as usize).clamp(12, 180);
+
+    parent
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            ProgressBarTrack,
+        ))
+        .with_children(|ring| {
+            for index in 0..count {
+                // Clockwise from 12 o'clock (UI y axis points down)
+                let angle = std::f32::consts::TAU * (index as f32 + 0.5) / count as f32
+                    - std::f32::consts::FRAC_PI_2;
+                let center = diameter / 2.0;
+                let segment = ProgressRingSegment { index, count, fill_color, track_color };
+
+                ring.spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(center + radius * angle.cos() - thickness / 2.0),
+                        top: Val::Px(center + radius * angle.sin() - thickness / 2.0),
+                        width: Val::Px(thickness),
+                        height: Val::Px(thickness),
+                        ..default()
+                    },
+                    BackgroundColor(segment.color_for(value)),
+                    BorderRadius::all(Val::Percent(50.0)),
+                    segment,
+                ));
+            }
+        });
+}
+
 /// Convenience function to create a progress bar builder
 pub fn progress(value: f32) -> ProgressBarBuilder {
     ProgressBarBuilder::new(value)
@@ -15,5 +15,10 @@ pub use types::{
     ProgressBarStyle,
     ProgressBarFill,
     ProgressBarTrack,
+    ProgressRingSegment,
     ProgressBarLabel,
+    ProgressLabelPosition,
+    ProgressLabelContrast,
+    ProgressBarCompleted,
+    ProgressCompleteEvent,
 };
\ No newline at end of file
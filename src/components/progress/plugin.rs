@@ -3,8 +3,10 @@
 
 use bevy_plugin_builder::define_plugin;
 use super::systems::*;
+use super::types::ProgressCompleteEvent;
 
 /// Plugin to add progress bar systems
 define_plugin!(ProgressBarPlugin {
+    messages: [ProgressCompleteEvent],
     update: [update_progress_bars, force_update_progress_bars]
 });
\ No newline at end of file
@@ -4,15 +4,31 @@ use bevy::prelude::*;
 use super::types::*;
 
 /// System to update progress bar fills when value changes
+///
+/// Also fires [`ProgressCompleteEvent`] when a bar crosses into 100%. Bars
+/// spawned already full are marked complete without firing.
 pub fn update_progress_bars(
-    mut bars: Query<(Entity, &ProgressBar), Changed<ProgressBar>>,
+    mut commands: Commands,
+    mut bars: Query<(Entity, Ref<ProgressBar>, Has<ProgressBarCompleted>), Changed<ProgressBar>>,
     children_query: Query<&Children>,
     mut fills: Query<&mut Node, With<ProgressBarFill>>,
-    mut labels: Query<&mut Text, With<ProgressBarLabel>>,
+    mut labels: Query<(&mut Text, &mut TextColor, Option<&ProgressLabelContrast>), With<ProgressBarLabel>>,
+    mut segments: Query<(&ProgressRingSegment, &mut BackgroundColor)>,
+    mut complete_events: MessageWriter<ProgressCompleteEvent>,
 ) {
-    for (entity, bar) in &mut bars {
+    for (entity, bar, completed) in &mut bars {
         // Use the recursive helper to find and update fills/labels
-        find_and_update_fill(entity, bar.value, &children_query, &mut fills, &mut labels);
+        find_and_update_fill(entity, bar.value, &children_query, &mut fills, &mut labels, &mut segments);
+
+        let full = bar.value >= 1.0;
+        if full && !completed {
+            if !bar.is_added() {
+                complete_events.write(ProgressCompleteEvent { entity });
+            }
+            commands.entity(entity).insert(ProgressBarCompleted);
+        } else if !full && completed {
+            commands.entity(entity).remove::<ProgressBarCompleted>();
+        }
     }
 }
 
@@ -22,7 +38,8 @@ fn find_and_update_fill(
     value: f32,
     children_query: &Query<&Children>,
     fills: &mut Query<&mut Node, With<ProgressBarFill>>,
-    labels: &mut Query<&mut Text, With<ProgressBarLabel>>,
+    labels: &mut Query<(&mut Text, &mut TextColor, Option<&ProgressLabelContrast>), With<ProgressBarLabel>>,
+    segments: &mut Query<(&ProgressRingSegment, &mut BackgroundColor)>,
 ) {
     // Try to update this entity if it's a fill
     if let Ok(mut fill_node) = fills.get_mut(entity) {
@@ -33,17 +50,33 @@ fn find_and_update_fill(
     }
 
     // Try to update this entity if it's a label
-    if let Ok(mut label_text) = labels.get_mut(entity) {
+    if let Ok((mut label_text, mut label_color, contrast)) = labels.get_mut(entity) {
         let new_text = format!("{}%", (value * 100.0) as i32);
         if **label_text != new_text {
             **label_text = new_text;
         }
+
+        // Labels inside the bar keep contrasting with what is behind them
+        if let Some(contrast) = contrast {
+            let new_color = contrast.color_for(value);
+            if label_color.0 != new_color {
+                label_color.0 = new_color;
+            }
+        }
+    }
+
+    // Try to update this entity if it's a ring segment (sets the arc sweep)
+    if let Ok((segment, mut color)) = segments.get_mut(entity) {
+        let new_color = segment.color_for(value);
+        if color.0 != new_color {
+            color.0 = new_color;
+        }
     }
 
     // Recursively check children
     if let Ok(children) = children_query.get(entity) {
         for child in children.iter() {
-            find_and_update_fill(child, value, children_query, fills, labels);
+            find_and_update_fill(child, value, children_query, fills, labels, segments);
         }
     }
 }
@@ -53,10 +86,11 @@ pub fn force_update_progress_bars(
     bars: Query<(Entity, &ProgressBar)>,
     children_query: Query<&Children>,
     mut fills: Query<&mut Node, With<ProgressBarFill>>,
-    mut labels: Query<&mut Text, With<ProgressBarLabel>>,
+    mut labels: Query<(&mut Text, &mut TextColor, Option<&ProgressLabelContrast>), With<ProgressBarLabel>>,
+    mut segments: Query<(&ProgressRingSegment, &mut BackgroundColor)>,
 ) {
     for (entity, bar) in bars.iter() {
         // Recursively search for fill and label components in the hierarchy
-        find_and_update_fill(entity, bar.value, &children_query, &mut fills, &mut labels);
+        find_and_update_fill(entity, bar.value, &children_query, &mut fills, &mut labels, &mut segments);
     }
 }
\ No newline at end of file
@@ -2,7 +2,7 @@
 
 use bevy::prelude::*;
 
-use crate::theme::{UiTheme, SemanticVariant};
+use crate::theme::{contrast_color, UiTheme, SemanticVariant};
 
 /// Component for progress bars
 #[derive(Component, Debug)]
@@ -16,7 +16,7 @@ pub struct ProgressBar {
 }
 
 /// Progress bar style variants (controls size/height)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum ProgressBarStyle {
     /// Standard progress bar (default)
     #[default]
@@ -27,6 +27,14 @@ pub enum ProgressBarStyle {
     Thick,
     /// Segmented appearance
     Segmented,
+    /// Radial ring that fills clockwise from the top
+    ///
+    /// The ring's diameter comes from the builder's `width` (48px unless set
+    /// in pixels); `thickness` is the ring's stroke width in pixels.
+    Circular {
+        /// Stroke width of the ring in pixels
+        thickness: f32,
+    },
 }
 
 impl ProgressBarStyle {
@@ -35,6 +43,7 @@ impl ProgressBarStyle {
         match self {
             ProgressBarStyle::Thin => 4.0,
             ProgressBarStyle::Thick => 12.0,
+            ProgressBarStyle::Circular { thickness } => *thickness,
             _ => 8.0,
         }
     }
@@ -106,6 +115,109 @@ pub struct ProgressBarFill;
 #[derive(Component)]
 pub struct ProgressBarTrack;
 
+/// One segment of a circular progress ring
+///
+/// The ring is drawn as overlapping round segments laid out clockwise from
+/// the top; each is tinted from `track_color` to `fill_color` by how much of
+/// its arc the current value covers.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ProgressRingSegment {
+    /// Position of this segment along the ring
+    pub index: usize,
+    /// Total number of segments in the ring
+    pub count: usize,
+    /// Color of the filled arc
+    pub fill_color: Color,
+    /// Color of the unfilled arc
+    pub track_color: Color,
+}
+
+impl ProgressRingSegment {
+    /// Color of this segment for a progress value (0.0 to 1.0)
+    pub fn color_for(&self, value: f32) -> Color {
+        let coverage = (value.clamp(0.0, 1.0) * self.count as f32 - self.index as f32).clamp(0.0, 1.0);
+        self.track_color.mix(&self.fill_color, coverage)
+    }
+}
+
+/// Marker for progress bars that have reached 100%.
+///
+/// Inserted when [`ProgressCompleteEvent`] fires and removed when the value
+/// drops back below 100%, so the event fires once per crossing.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ProgressBarCompleted;
+
+/// Message emitted when a progress bar's value reaches 100%
+#[derive(Message, Clone, Debug)]
+pub struct ProgressCompleteEvent {
+    /// The progress bar entity
+    pub entity: Entity,
+}
+
 /// Marker component for the progress bar label
 #[derive(Component)]
-pub struct ProgressBarLabel;
\ No newline at end of file
+pub struct ProgressBarLabel;
+
+/// Where a progress bar's percentage label is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProgressLabelPosition {
+    /// Centered below the bar (default)
+    #[default]
+    Below,
+    /// Beside the bar, to its right
+    Outside,
+    /// Centered inside the bar, in black or white depending on what is
+    /// behind it; needs a bar tall enough for the text
+    Inside,
+    /// No label
+    None,
+}
+
+/// Colors behind a label drawn inside a progress bar
+///
+/// The label's text color is switched to contrast with the fill once the fill
+/// reaches the middle of the bar, and with the track before that.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ProgressLabelContrast {
+    /// Color of the filled portion
+    pub fill: Color,
+    /// Color of the unfilled track
+    pub track: Color,
+}
+
+impl ProgressLabelContrast {
+    /// Text color for a progress value (0.0 to 1.0)
+    pub fn color_for(&self, value: f32) -> Color {
+        if value >= 0.5 {
+            contrast_color(self.fill)
+        } else {
+            contrast_color(self.track)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inside_label_contrasts_with_what_is_behind_its_center() {
+        let contrast = ProgressLabelContrast {
+            fill: Color::WHITE,
+            track: Color::BLACK,
+        };
+
+        // Below half the label sits on the dark track
+        assert_eq!(contrast.color_for(0.0), contrast_color(Color::BLACK));
+        assert_eq!(contrast.color_for(0.49), contrast_color(Color::BLACK));
+        // From half on, the light fill is behind it
+        assert_eq!(contrast.color_for(0.5), contrast_color(Color::WHITE));
+        assert_eq!(contrast.color_for(1.0), contrast_color(Color::WHITE));
+        assert_ne!(contrast.color_for(0.0), contrast.color_for(1.0));
+    }
+
+    #[test]
+    fn labels_sit_below_the_bar_by_default() {
+        assert_eq!(ProgressLabelPosition::default(), ProgressLabelPosition::Below);
+    }
+}
@@ -0,0 +1,213 @@
+//! ReorderableListBuilder implementation
+
+use bevy::prelude::*;
+use crate::styles::dimensions;
+use crate::theme::UiTheme;
+use crate::traits::{UiBuilder, LayoutBuilder, BuilderBase};
+use super::types::*;
+
+/// Builder for lists whose items can be dragged into a new order
+///
+/// Dragging an item shows a line where it will land; dropping it moves the
+/// item and emits a [`ListReorderedEvent`].
+///
+/// # Example
+/// ```ignore
+/// ReorderableListBuilder::new()
+///     .items(["Background", "Terrain", "Characters", "UI"])
+///     .width(Val::Px(240.0))
+///     .build(parent);
+/// ```
+pub struct ReorderableListBuilder {
+    items: Vec<String>,
+    item_height: f32,
+    gap: f32,
+    themed_colors: Option<ReorderableListColors>,
+    base: BuilderBase,
+}
+
+impl ReorderableListBuilder {
+    /// Create a new, empty reorderable list builder
+    pub fn new() -> Self {
+        let mut base = BuilderBase::new();
+        base.node.width = Val::Px(240.0);
+
+        Self {
+            items: Vec::new(),
+            item_height: 36.0,
+            gap: 4.0,
+            themed_colors: None,
+            base,
+        }
+    }
+
+    /// Apply theme colors to this builder.
+    ///
+    /// Call this method to use theme-aware styling. If not called,
+    /// sensible defaults (matching the dark theme) will be used.
+    pub fn themed(mut self, theme: &UiTheme) -> Self {
+        self.themed_colors = Some(ReorderableListColors::from_theme(theme));
+        self
+    }
+
+    /// Resolve colors (themed > default)
+    fn resolve_colors(&self) -> ReorderableListColors {
+        self.themed_colors.clone()
+            .unwrap_or_else(ReorderableListColors::default_colors)
+    }
+
+    /// Add an item
+    pub fn item(mut self, label: impl Into<String>) -> Self {
+        self.items.push(label.into());
+        self
+    }
+
+    /// Add several items
+    pub fn items<I, S>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.items.extend(labels.into_iter().map(Into::into));
+        self
+    }
+
+    /// Set the width of the list
+    pub fn width(mut self, width: Val) -> Self {
+        self.base.node.width = width;
+        self
+    }
+
+    /// Set the height of each item in pixels
+    pub fn item_height(mut self, height: f32) -> Self {
+        self.item_height = height;
+        self
+    }
+
+    /// Set the vertical gap between items in pixels
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Build the list (proxy to UiBuilder::build)
+    pub fn build(self, parent: &mut ChildSpawnerCommands) -> Entity {
+        UiBuilder::build(self, parent)
+    }
+}
+
+impl Default for ReorderableListBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UiBuilder for ReorderableListBuilder {
+    fn build(mut self, parent: &mut ChildSpawnerCommands) -> Entity {
+        let colors = self.resolve_colors();
+
+        self.base.node.flex_direction = FlexDirection::Column;
+        self.base.node.row_gap = Val::Px(self.gap);
+
+        let mut list = parent.spawn((
+            self.base.node,
+            ReorderableList { colors: colors.clone() },
+        ));
+        let list_entity = list.id();
+
+        list.with_children(|list| {
+            // The indicator stays the first child so item `i` is child `i + 1`
+            list.spawn((
+                Node {
+                    display: Display::None,
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(0.0),
+                    right: Val::Px(0.0),
+                    height: Val::Px(2.0),
+                    ..default()
+                },
+                BackgroundColor(colors.indicator),
+                ZIndex(1),
+                Pickable::IGNORE,
+                ListDropIndicator,
+            ));
+
+            for label in &self.items {
+                list.spawn((
+                    Button,
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Px(self.item_height),
+                        padding: UiRect::horizontal(Val::Px(dimensions::PADDING_MEDIUM)),
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(colors.item_background),
+                    BorderRadius::all(Val::Px(dimensions::BORDER_RADIUS_SMALL)),
+                    ReorderableItem { list: list_entity },
+                ))
+                .with_children(|item| {
+                    item.spawn((
+                        Text::new(label.clone()),
+                        TextFont {
+                            font_size: dimensions::FONT_SIZE_MEDIUM,
+                            ..default()
+                        },
+                        TextColor(colors.text),
+                        Pickable::IGNORE,
+                    ));
+                });
+            }
+        });
+
+        for hook in self.base.hooks {
+            hook(&mut parent.commands().entity(list_entity));
+        }
+
+        list_entity
+    }
+
+    fn insert(mut self, bundle: impl Bundle + Clone) -> Self {
+        self.base.hooks.push(Box::new(move |cmds| {
+            cmds.insert(bundle.clone());
+        }));
+        self
+    }
+
+    fn id(mut self, id: Entity) -> Self {
+        self.base.entity = Some(id);
+        self
+    }
+}
+
+impl LayoutBuilder for ReorderableListBuilder {
+    fn node(mut self, node: Node) -> Self {
+        self.base.node = node;
+        self
+    }
+
+    fn margin(mut self, margin: UiRect) -> Self {
+        self.base.node.margin = margin;
+        self
+    }
+
+    fn padding(mut self, padding: UiRect) -> Self {
+        self.base.node.padding = padding;
+        self
+    }
+
+    fn width(mut self, width: Val) -> Self {
+        self.base.node.width = width;
+        self
+    }
+
+    fn height(mut self, height: Val) -> Self {
+        self.base.node.height = height;
+        self
+    }
+}
+
+/// Convenience function to create a reorderable list builder
+pub fn reorderable_list() -> ReorderableListBuilder {
+    ReorderableListBuilder::new()
+}
@@ -0,0 +1,39 @@
+//! Reorderable list component
+//!
+//! Provides vertical lists whose items can be dragged into a new order.
+//!
+//! # Features
+//!
+//! - Press and drag an item to move it
+//! - A drop-indicator line shows where the item will land
+//! - `ListReorderedEvent { from, to }` after each move
+//! - Right-click cancels a drag
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use bevy_ui_builders::prelude::*;
+//!
+//! ReorderableListBuilder::new()
+//!     .items(["Background", "Terrain", "Characters", "UI"])
+//!     .build(parent);
+//!
+//! fn sync_layers(mut events: MessageReader<ListReorderedEvent>, mut layers: ResMut<Layers>) {
+//!     for event in events.read() {
+//!         let layer = layers.0.remove(event.from);
+//!         layers.0.insert(event.to, layer);
+//!     }
+//! }
+//! ```
+
+mod builder;
+mod plugin;
+mod systems;
+mod types;
+
+pub use builder::{ReorderableListBuilder, reorderable_list};
+pub use plugin::ReorderableListPlugin;
+pub use types::{
+    ReorderableList, ReorderableItem, ListDropIndicator, ListReorderedEvent,
+    ReorderDrag, ReorderDragState, ReorderableListColors, reorder_target,
+};
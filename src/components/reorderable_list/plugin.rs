@@ -0,0 +1,21 @@
+//! Reorderable list plugin
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+use super::types::{ListReorderedEvent, ReorderDragState};
+use super::systems::*;
+
+define_plugin!(ReorderableListPlugin {
+    custom_init: |app: &mut App| {
+        app.insert_resource(ReorderDragState::default())
+           .add_message::<ListReorderedEvent>();
+    },
+    update: [
+        (
+            start_item_drag,
+            update_drop_indicator,
+            finish_item_drag,
+            update_item_visuals,
+        ).chain(),
+    ]
+});
@@ -0,0 +1,173 @@
+//! Reorderable list drag and drop systems
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use crate::utils::geometry::node_logical_rect;
+use super::types::*;
+
+/// Items of a list, in display order
+fn list_items(
+    list: Entity,
+    children: &Query<&Children>,
+    items: &Query<(&GlobalTransform, &ComputedNode), With<ReorderableItem>>,
+) -> Vec<Entity> {
+    children.get(list)
+        .map(|children| children.iter().filter(|child| items.contains(*child)).collect())
+        .unwrap_or_default()
+}
+
+/// Start dragging an item when it is pressed
+pub fn start_item_drag(
+    pressed: Query<(Entity, &ReorderableItem, &Interaction), Changed<Interaction>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    children: Query<&Children>,
+    items: Query<(&GlobalTransform, &ComputedNode), With<ReorderableItem>>,
+    mut drag_state: ResMut<ReorderDragState>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) || drag_state.dragging.is_some() {
+        return;
+    }
+
+    for (entity, item, interaction) in pressed.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Some(from) = list_items(item.list, &children, &items).iter().position(|&e| e == entity) else {
+            continue;
+        };
+
+        drag_state.dragging = Some(ReorderDrag {
+            list: item.list,
+            item: entity,
+            from,
+            drop_slot: from,
+        });
+        break;
+    }
+}
+
+/// Track the gap under the cursor and draw the drop indicator there
+pub fn update_drop_indicator(
+    mut drag_state: ResMut<ReorderDragState>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    children: Query<&Children>,
+    items: Query<(&GlobalTransform, &ComputedNode), With<ReorderableItem>>,
+    lists: Query<(&GlobalTransform, &ComputedNode), With<ReorderableList>>,
+    mut indicators: Query<(&ChildOf, &mut Node), With<ListDropIndicator>>,
+) {
+    let cursor = windows.single().ok().and_then(|window| window.cursor_position());
+
+    for (child_of, mut indicator) in indicators.iter_mut() {
+        let list = child_of.parent();
+        let drag = drag_state.dragging.as_mut().filter(|drag| drag.list == list);
+
+        let (Some(drag), Some(cursor)) = (drag, cursor) else {
+            if indicator.display != Display::None {
+                indicator.display = Display::None;
+            }
+            continue;
+        };
+
+        let bounds: Vec<(f32, f32)> = list_items(list, &children, &items)
+            .into_iter()
+            .filter_map(|item| items.get(item).ok())
+            .map(|(transform, computed)| {
+                let rect = node_logical_rect(computed, transform);
+                (rect.min.y, rect.max.y)
+            })
+            .collect();
+        let Some((&(first_top, _), &(_, last_bottom))) = bounds.first().zip(bounds.last()) else {
+            continue;
+        };
+
+        // Gap `i` sits above item `i`; past the last item's middle is the end
+        drag.drop_slot = bounds.iter()
+            .position(|(top, bottom)| cursor.y < (top + bottom) / 2.0)
+            .unwrap_or(bounds.len());
+
+        if reorder_target(drag.from, drag.drop_slot).is_none() {
+            if indicator.display != Display::None {
+                indicator.display = Display::None;
+            }
+            continue;
+        }
+
+        // Center the line in the gap between neighbouring items
+        let line_y = match drag.drop_slot {
+            0 => first_top - 2.0,
+            slot if slot == bounds.len() => last_bottom + 2.0,
+            slot => (bounds[slot - 1].1 + bounds[slot].0) / 2.0,
+        };
+        let Ok((list_transform, list_computed)) = lists.get(list) else {
+            continue;
+        };
+        let list_top = node_logical_rect(list_computed, list_transform).min.y;
+
+        let top = Val::Px(line_y - list_top - 1.0);
+        if indicator.top != top {
+            indicator.top = top;
+        }
+        if indicator.display != Display::Flex {
+            indicator.display = Display::Flex;
+        }
+    }
+}
+
+/// Drop the dragged item into its new position (right-click cancels the drag)
+pub fn finish_item_drag(
+    mut commands: Commands,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut drag_state: ResMut<ReorderDragState>,
+    mut reordered: MessageWriter<ListReorderedEvent>,
+) {
+    if mouse.just_pressed(MouseButton::Right) {
+        drag_state.dragging = None;
+        return;
+    }
+
+    if !mouse.just_released(MouseButton::Left) {
+        return;
+    }
+
+    let Some(drag) = drag_state.dragging.take() else {
+        return;
+    };
+    let Some(to) = reorder_target(drag.from, drag.drop_slot) else {
+        return;
+    };
+
+    // Moves the existing child. The drop indicator is child 0, so item `to` is child `to + 1`
+    commands.entity(drag.list).insert_children(to + 1, &[drag.item]);
+
+    reordered.write(ListReorderedEvent {
+        list: drag.list,
+        item: drag.item,
+        from: drag.from,
+        to,
+    });
+}
+
+/// Tint items on hover and highlight the one being dragged
+pub fn update_item_visuals(
+    drag_state: Res<ReorderDragState>,
+    lists: Query<&ReorderableList>,
+    mut items: Query<(Entity, &ReorderableItem, &Interaction, &mut BackgroundColor)>,
+) {
+    let dragged = drag_state.dragging.as_ref().map(|drag| drag.item);
+
+    for (entity, item, interaction, mut background) in items.iter_mut() {
+        let Ok(list) = lists.get(item.list) else {
+            continue;
+        };
+
+        let color = if dragged == Some(entity) {
+            list.colors.item_dragging
+        } else if *interaction != Interaction::None && dragged.is_none() {
+            list.colors.item_hover
+        } else {
+            list.colors.item_background
+        };
+        background.set_if_neq(BackgroundColor(color));
+    }
+}
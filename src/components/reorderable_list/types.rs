@@ -0,0 +1,114 @@
+//! Reorderable list types and components
+
+use bevy::prelude::*;
+
+use crate::theme::UiTheme;
+
+pub use crate::utils::reorder::reorder_target;
+
+/// Component marking a reorderable list container
+///
+/// Items are the container's children with [`ReorderableItem`], in child order.
+#[derive(Component, Clone, Debug)]
+pub struct ReorderableList {
+    /// Colors used for item hover/drag feedback and the drop indicator
+    pub colors: ReorderableListColors,
+}
+
+/// Component marking a draggable item in a reorderable list
+#[derive(Component, Clone, Debug)]
+pub struct ReorderableItem {
+    /// The list this item belongs to
+    pub list: Entity,
+}
+
+/// Marker for the line showing where a dragged item will be dropped
+#[derive(Component)]
+pub struct ListDropIndicator;
+
+/// Message emitted when an item is dropped at a new position
+///
+/// Indices are item positions before the move (`from`) and after it (`to`),
+/// so applying `vec.remove(from)` then `vec.insert(to, item)` keeps a
+/// parallel `Vec` in sync with the list.
+#[derive(Message, Clone, Debug)]
+pub struct ListReorderedEvent {
+    /// The list entity
+    pub list: Entity,
+    /// The dragged item entity
+    pub item: Entity,
+    /// Index the item was dragged from
+    pub from: usize,
+    /// Index the item now sits at
+    pub to: usize,
+}
+
+/// An in-progress item drag
+#[derive(Clone, Debug)]
+pub struct ReorderDrag {
+    /// The list being reordered
+    pub list: Entity,
+    /// The dragged item entity
+    pub item: Entity,
+    /// Index the item was picked up from
+    pub from: usize,
+    /// Gap position (0..=item count) the item would be dropped into
+    pub drop_slot: usize,
+}
+
+/// Resource tracking the item currently being dragged
+#[derive(Resource, Default, Debug)]
+pub struct ReorderDragState {
+    /// The active drag, if any
+    pub dragging: Option<ReorderDrag>,
+}
+
+/// Default colors (dark theme) for when no theme is provided
+pub(crate) mod defaults {
+    use bevy::prelude::Color;
+
+    pub const ITEM_BACKGROUND: Color = Color::srgb(0.15, 0.15, 0.18);
+    pub const ITEM_HOVER: Color = Color::srgb(0.2, 0.2, 0.24);
+    pub const ITEM_DRAGGING: Color = Color::srgba(0.25, 0.46, 0.86, 0.35);
+    pub const TEXT: Color = Color::srgb(0.95, 0.95, 0.95);
+    pub const INDICATOR: Color = Color::srgb(0.25, 0.46, 0.86);
+}
+
+/// Resolved reorderable list colors
+#[derive(Clone, Debug)]
+pub struct ReorderableListColors {
+    /// Item background
+    pub item_background: Color,
+    /// Item background while hovered
+    pub item_hover: Color,
+    /// Background of the item being dragged
+    pub item_dragging: Color,
+    /// Item text color
+    pub text: Color,
+    /// Drop indicator line color
+    pub indicator: Color,
+}
+
+impl ReorderableListColors {
+    /// Resolve colors from theme
+    pub fn from_theme(theme: &UiTheme) -> Self {
+        Self {
+            item_background: theme.colors.surface.secondary,
+            item_hover: theme.colors.surface.tertiary,
+            item_dragging: theme.colors.primary.base.with_alpha(0.35),
+            text: theme.colors.text.primary,
+            indicator: theme.colors.primary.base,
+        }
+    }
+
+    /// Default colors (no theme)
+    pub fn default_colors() -> Self {
+        Self {
+            item_background: defaults::ITEM_BACKGROUND,
+            item_hover: defaults::ITEM_HOVER,
+            item_dragging: defaults::ITEM_DRAGGING,
+            text: defaults::TEXT,
+            indicator: defaults::INDICATOR,
+        }
+    }
+}
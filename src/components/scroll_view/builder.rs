@@ -1,6 +1,8 @@
 //! ScrollView builder for creating scrollable containers with dynamic sizing
 
 use bevy::prelude::*;
+use bevy::color::Alpha;
+use bevy::ui::{BackgroundGradient, ColorStop, LinearGradient};
 use super::types::*;
 use crate::theme::UiTheme;
 use crate::traits::{UiBuilder, LayoutBuilder, BuilderBase};
@@ -165,6 +167,23 @@ impl ScrollViewBuilder {
         self
     }
 
+    /// Fade content out at the edges that have more content beyond them
+    ///
+    /// The fade blends into the scroll view's background color, or the theme's
+    /// app background when the scroll view is transparent. Like the scrollbar,
+    /// the fades are laid out against the scroll view's parent, so the scroll
+    /// view should fill it.
+    pub fn fade_edges(mut self, enabled: bool) -> Self {
+        self.config.fade_edges = enabled;
+        self
+    }
+
+    /// Set the length of the edge fades in pixels
+    pub fn fade_size(mut self, size: f32) -> Self {
+        self.config.fade_size = size;
+        self
+    }
+
     /// Show/hide scroll indicators (deprecated - use scrollbar_visibility instead)
     pub fn show_indicators(mut self, show: bool) -> Self {
         self.config.scrollbar_visibility = if show {
@@ -208,10 +227,17 @@ impl ScrollViewBuilder {
         let enable_kinetic = self.config.enable_kinetic_scroll;
         let scrollbar_width = self.config.scrollbar_width;
         let _min_thumb_length = self.config.min_thumb_length;
+        let fade_edges = self.config.fade_edges;
+        let fade_size = self.config.fade_size;
 
         // Resolve scrollbar colors: themed > default
         let scrollbar_colors = self.themed_scrollbar_colors
             .unwrap_or_else(ScrollbarColors::default_colors);
+        let fade_color = if self.background_color.alpha() > 0.0 {
+            self.background_color
+        } else {
+            scrollbar_colors.fade
+        };
 
         // Update base.node with calculated properties
         self.base.node.flex_direction = flex_direction;
@@ -272,6 +298,24 @@ impl ScrollViewBuilder {
             });
         }
 
+        // Add edge fades for each scrollable axis
+        if fade_edges {
+            let edges: &[IndicatorPosition] = match self.direction {
+                ScrollDirection::Vertical => &[IndicatorPosition::Top, IndicatorPosition::Bottom],
+                ScrollDirection::Horizontal => &[IndicatorPosition::Left, IndicatorPosition::Right],
+                ScrollDirection::Both => &[
+                    IndicatorPosition::Top,
+                    IndicatorPosition::Bottom,
+                    IndicatorPosition::Left,
+                    IndicatorPosition::Right,
+                ],
+            };
+
+            for &position in edges {
+                spawn_edge_fade(parent, container, position, fade_size, fade_color);
+            }
+        }
+
         // Apply hooks
         for hook in self.base.hooks {
             hook(&mut parent.commands().entity(container));
@@ -281,6 +325,52 @@ impl ScrollViewBuilder {
     }
 }
 
+/// Spawn a gradient overlay fading from `color` at `position` into the content
+fn spawn_edge_fade(
+    parent: &mut ChildSpawnerCommands,
+    container: Entity,
+    position: IndicatorPosition,
+    size: f32,
+    color: Color,
+) {
+    let (node, angle) = match position {
+        IndicatorPosition::Top => (
+            Node { top: Val::ZERO, left: Val::ZERO, right: Val::ZERO, height: Val::Px(size), ..default() },
+            LinearGradient::TO_BOTTOM,
+        ),
+        IndicatorPosition::Bottom => (
+            Node { bottom: Val::ZERO, left: Val::ZERO, right: Val::ZERO, height: Val::Px(size), ..default() },
+            LinearGradient::TO_TOP,
+        ),
+        IndicatorPosition::Left => (
+            Node { left: Val::ZERO, top: Val::ZERO, bottom: Val::ZERO, width: Val::Px(size), ..default() },
+            LinearGradient::TO_RIGHT,
+        ),
+        IndicatorPosition::Right => (
+            Node { right: Val::ZERO, top: Val::ZERO, bottom: Val::ZERO, width: Val::Px(size), ..default() },
+            LinearGradient::TO_LEFT,
+        ),
+    };
+
+    parent.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            ..node
+        },
+        BackgroundGradient::from(LinearGradient::new(
+            angle,
+            vec![ColorStop::auto(color), ColorStop::auto(color.with_alpha(0.0))],
+        )),
+        Visibility::Hidden, // Shown by update_scroll_fade_edges once content overflows
+        Pickable::IGNORE, // Wheel and clicks go through to the content
+        ScrollIndicator {
+            position,
+            visible: false,
+            scroll_container: container,
+        },
+    ));
+}
+
 impl UiBuilder for ScrollViewBuilder {
     fn build(self, parent: &mut ChildSpawnerCommands) -> Entity {
         self.build_with_children(parent, |_| {})
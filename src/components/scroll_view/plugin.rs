@@ -17,5 +17,6 @@ define_plugin!(ScrollViewPlugin {
         auto_scroll_to_focused_input,      // Auto-scroll to focused text inputs
         clamp_scroll_bounds,               // Clamp scroll position to valid range
         update_scrollbar_thumb_position,   // Update thumb position/size based on scroll (AFTER all scroll updates)
+        update_scroll_fade_edges,          // Show edge fades where more content is scrollable
     ]
 });
@@ -5,6 +5,7 @@ use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
 use bevy::ui::ComputedNode;
 use super::types::*;
 use super::builder::ScrollbarThumb;
+use crate::utils::geometry::node_logical_rect;
 
 /// Handle mouse wheel scrolling for scrollable containers
 pub fn handle_mouse_wheel_scroll(
@@ -427,3 +428,38 @@ pub fn clamp_scroll_bounds(
         position.0.y = position.0.y.clamp(0.0, max_offset_y);
     }
 }
+
+/// Show edge fades only where there is more content beyond the edge
+pub fn update_scroll_fade_edges(
+    mut indicator_query: Query<(&mut ScrollIndicator, &mut Visibility)>,
+    scroll_query: Query<(&ScrollPosition, &GlobalTransform, &ComputedNode, &Children), With<ScrollView>>,
+    child_query: Query<(&GlobalTransform, &ComputedNode), Without<ScrollView>>,
+) {
+    for (mut indicator, mut visibility) in indicator_query.iter_mut() {
+        let Ok((position, transform, computed, children)) = scroll_query.get(indicator.scroll_container) else {
+            continue;
+        };
+
+        // Far edge of the content, relative to the viewport's top-left corner
+        // (child transforms already include the scroll offset)
+        let viewport = node_logical_rect(computed, transform);
+        let viewport_size = viewport.size();
+        let content_max = children.iter()
+            .filter_map(|child| child_query.get(child).ok())
+            .fold(Vec2::ZERO, |max, (child_transform, child_computed)| {
+                max.max(node_logical_rect(child_computed, child_transform).max - viewport.min)
+            });
+
+        let visible = match indicator.position {
+            IndicatorPosition::Top => position.0.y > 0.5,
+            IndicatorPosition::Left => position.0.x > 0.5,
+            IndicatorPosition::Bottom => content_max.y > viewport_size.y + 0.5,
+            IndicatorPosition::Right => content_max.x > viewport_size.x + 0.5,
+        };
+
+        if indicator.visible != visible {
+            indicator.visible = visible;
+            *visibility = if visible { Visibility::Inherited } else { Visibility::Hidden };
+        }
+    }
+}
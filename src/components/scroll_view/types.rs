@@ -14,6 +14,8 @@ pub struct ScrollbarColors {
     pub track: Color,
     /// Thumb (draggable) color
     pub thumb: Color,
+    /// Edge fade color, used when the scroll view has no background of its own
+    pub fade: Color,
 }
 
 impl ScrollbarColors {
@@ -23,6 +25,7 @@ impl ScrollbarColors {
         Self {
             track: theme.colors.border.light,
             thumb: theme.colors.border.default.with_alpha(0.5),
+            fade: theme.colors.surface.background,
         }
     }
 
@@ -31,6 +34,7 @@ impl ScrollbarColors {
         Self {
             track: Color::srgba(1.0, 1.0, 1.0, 0.1),
             thumb: Color::srgba(1.0, 1.0, 1.0, 0.3),
+            fade: Color::srgb(0.05, 0.05, 0.07),
         }
     }
 }
@@ -52,6 +56,10 @@ pub struct ScrollConfig {
     pub min_thumb_length: f32,
     /// Scrollbar width in pixels
     pub scrollbar_width: f32,
+    /// Fade content out at edges with more content beyond them
+    pub fade_edges: bool,
+    /// Length of the edge fade in pixels
+    pub fade_size: f32,
 }
 
 impl Default for ScrollConfig {
@@ -64,6 +72,8 @@ impl Default for ScrollConfig {
             scroll_sensitivity: 1.0,
             min_thumb_length: 8.0,
             scrollbar_width: 8.0,
+            fade_edges: false,
+            fade_size: 24.0,
         }
     }
 }
@@ -135,12 +145,17 @@ pub struct KineticScrollState {
 pub struct DragScrollTarget;
 
 /// Visual indicator for scroll availability
+///
+/// Spawned as a gradient overlay at each scrollable edge when
+/// [`ScrollConfig::fade_edges`] is enabled.
 #[derive(Component, Debug, Clone)]
 pub struct ScrollIndicator {
     /// Position of the indicator
     pub position: IndicatorPosition,
     /// Whether the indicator is currently visible
     pub visible: bool,
+    /// Target scroll container entity
+    pub scroll_container: Entity,
 }
 
 /// Position of scroll indicators
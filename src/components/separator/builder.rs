@@ -89,13 +89,7 @@ impl SeparatorBuilder {
     fn resolve_color(&self) -> Color {
         self.color
             .or(self.themed_color)
-            .unwrap_or_else(|| {
-                if self.style == SeparatorStyle::Invisible {
-                    Color::NONE
-                } else {
-                    DEFAULT_BORDER_COLOR
-                }
-            })
+            .unwrap_or_else(|| self.style.default_color())
     }
 }
 
@@ -58,6 +58,14 @@ impl SeparatorStyle {
             _ => theme.colors.border.default,
         }
     }
+
+    /// Get the default color (no theme)
+    pub(crate) fn default_color(&self) -> Color {
+        match self {
+            SeparatorStyle::Invisible => Color::NONE,
+            _ => DEFAULT_BORDER_COLOR,
+        }
+    }
 }
 
 // Default color for when no theme is provided
@@ -1,25 +1,51 @@
 //! SliderBuilder implementation
 
+use std::time::Duration;
 use bevy::prelude::*;
 use bevy::ui::RelativeCursorPosition;
+use crate::accessibility::AccessibleName;
 use crate::animation::AnimationCategory;
 use crate::components::button::{ButtonBuilder, ButtonSize, ButtonStyle};
+use crate::components::separator::Orientation;
 use crate::styles::dimensions;
 use crate::theme::{UiTheme, SemanticVariant};
 use crate::traits::{UiBuilder, LayoutBuilder, BuilderBase};
 use super::types::*;
 
+/// Width of the tick drawn at a default value
+const DEFAULT_MARKER_WIDTH: f32 = 2.0;
+
+/// How far the default-value tick sticks out above and below the track
+const DEFAULT_MARKER_OVERHANG: f32 = 4.0;
+
+/// Width of the notches drawn at each step by `show_ticks`
+const TICK_WIDTH: f32 = 1.0;
+
+/// Above this many steps the notches would blur together, so none are drawn
+const MAX_TICKS: usize = 100;
+
+/// Length of the track when no width (or height, when vertical) is given
+const DEFAULT_TRACK_LENGTH: f32 = 200.0;
+
 /// Builder for creating sliders
 pub struct SliderBuilder {
     value: f32,
     min: f32,
     max: f32,
     step: Option<f32>,
+    locked: Vec<std::ops::Range<f32>>,
+    default_value: Option<f32>,
+    show_ticks: bool,
     format: ValueFormat,
     variant: SemanticVariant,
     with_preview: bool,
     with_buttons: bool,
     label: Option<String>,
+    binding: Option<Box<dyn FnOnce(&mut EntityCommands)>>,
+    change_throttle: Option<Duration>,
+    orientation: Orientation,
+    inverted: bool,
+    display_only: bool,
     // Theme-resolved colors (set via .themed())
     themed_colors: Option<SliderColors>,
     base: BuilderBase,
@@ -29,7 +55,6 @@ impl SliderBuilder {
     /// Create a new slider builder with range
     pub fn new(range: std::ops::Range<f32>) -> Self {
         let mut base = BuilderBase::new();
-        base.node.width = Val::Px(200.0);
         base.node.flex_direction = FlexDirection::Column;
         base.node.row_gap = Val::Px(dimensions::SPACING_SMALL);
         base.node.margin = UiRect::bottom(Val::Px(dimensions::SPACING_MEDIUM));
@@ -39,11 +64,19 @@ impl SliderBuilder {
             min: range.start,
             max: range.end,
             step: None,
+            locked: Vec::new(),
+            default_value: None,
+            show_ticks: false,
             format: ValueFormat::Decimal(1),
             variant: SemanticVariant::Primary,
             with_preview: true,
             with_buttons: false,
             label: None,
+            binding: None,
+            change_throttle: None,
+            orientation: Orientation::Horizontal,
+            inverted: false,
+            display_only: false,
             themed_colors: None,
             base,
         }
@@ -88,12 +121,117 @@ impl SliderBuilder {
         self
     }
 
+    /// Lock a range of values the handle cannot be dragged into.
+    ///
+    /// The range is drawn on the track in a distinct color. Dragging stops
+    /// at its edge and the +/- buttons step over it. Call repeatedly to
+    /// lock several ranges.
+    ///
+    /// # Example
+    /// ```ignore
+    /// // 0-100 Mbps, with 90-100 reserved for the system
+    /// SliderBuilder::new(0.0..100.0)
+    ///     .locked_range(90.0..100.0)
+    ///     .build(parent);
+    /// ```
+    pub fn locked_range(mut self, range: std::ops::Range<f32>) -> Self {
+        let start = range.start.clamp(self.min, self.max);
+        let end = range.end.clamp(self.min, self.max);
+        if start < end {
+            self.locked.push(start..end);
+        }
+        self
+    }
+
+    /// Mark a recommended value on the track
+    ///
+    /// Draws a small tick at `value`, and double-clicking the track resets
+    /// the slider to it. Handy for settings such as brightness or gamma.
+    ///
+    /// # Example
+    /// ```ignore
+    /// SliderBuilder::new(0.0..100.0)
+    ///     .label("Brightness")
+    ///     .default_marker(50.0)
+    ///     .build(parent);
+    /// ```
+    pub fn default_marker(mut self, value: f32) -> Self {
+        self.default_value = Some(value.clamp(self.min, self.max));
+        self
+    }
+
+    /// Lay the slider out horizontally (default) or vertically
+    ///
+    /// Vertical sliders run top-to-bottom with the value growing upwards,
+    /// and are 200px tall unless given a `.height()` (horizontal ones are
+    /// 200px wide unless given a `.width()`).
+    ///
+    /// # Example
+    /// ```ignore
+    /// SliderBuilder::new(-60.0..6.0)
+    ///     .orientation(Orientation::Vertical)
+    ///     .label("Master")
+    ///     .build(parent);
+    /// ```
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Grow the value the other way: leftwards, or downwards when vertical
+    pub fn inverted(mut self, inverted: bool) -> Self {
+        self.inverted = inverted;
+        self
+    }
+
+    /// Show the value without accepting input, like a meter or gauge
+    ///
+    /// The slider ignores dragging, clicks and the arrow keys, and gets no
+    /// +/- buttons or handle hover effect. Its value can still be set from
+    /// code, e.g. with [`SliderRef::set_value`].
+    ///
+    /// # Example
+    /// ```ignore
+    /// let reputation = SliderBuilder::new(0.0..100.0)
+    ///     .label("Reputation")
+    ///     .display_only(true)
+    ///     .build_ref(parent);
+    /// ```
+    pub fn display_only(mut self, display_only: bool) -> Self {
+        self.display_only = display_only;
+        self
+    }
+
+    /// Draw a notch on the track at every step
+    ///
+    /// Needs a [`step`](Self::step); the last notch is always at the
+    /// maximum, even when the range isn't a whole number of steps. Ranges
+    /// with more than 100 steps get no notches.
+    ///
+    /// # Example
+    /// ```ignore
+    /// SliderBuilder::new(0.0..10.0)
+    ///     .step(1.0)
+    ///     .show_ticks()
+    ///     .build(parent);
+    /// ```
+    pub fn show_ticks(mut self) -> Self {
+        self.show_ticks = true;
+        self
+    }
+
     /// Set the width
     pub fn width(mut self, width: Val) -> Self {
         self.base.node.width = width;
         self
     }
 
+    /// Set the height (mostly useful for vertical sliders)
+    pub fn height(mut self, height: Val) -> Self {
+        self.base.node.height = height;
+        self
+    }
+
     /// Set the value format
     pub fn format(mut self, format: ValueFormat) -> Self {
         self.format = format;
@@ -123,11 +261,53 @@ impl SliderBuilder {
         self
     }
 
+    /// Bind the slider to an `f32` field of resource `R` (two-way).
+    ///
+    /// Moving the slider updates the resource, and external changes to the
+    /// resource move the slider. Requires `SliderBindingPlugin::<R>`.
+    ///
+    /// # Example
+    /// ```ignore
+    /// app.add_plugins(SliderBindingPlugin::<AudioSettings>::default());
+    ///
+    /// SliderBuilder::new(0.0..1.0)
+    ///     .label("Volume")
+    ///     .bind::<AudioSettings>(|settings| &mut settings.volume)
+    ///     .build(parent);
+    /// ```
+    pub fn bind<R: Resource>(mut self, accessor: fn(&mut R) -> &mut f32) -> Self {
+        self.binding = Some(Box::new(move |cmds| {
+            cmds.insert(SliderBinding::new(accessor));
+        }));
+        self
+    }
+
+    /// Send [`SliderChanged`] at most once per `interval` while dragging
+    ///
+    /// Useful when each change triggers expensive work such as a live
+    /// preview. The final value is always sent when the drag ends.
+    ///
+    /// # Example
+    /// ```ignore
+    /// SliderBuilder::new(0.0..1.0)
+    ///     .on_change_throttle(Duration::from_millis(100))
+    ///     .build(parent);
+    /// ```
+    pub fn on_change_throttle(mut self, interval: Duration) -> Self {
+        self.change_throttle = Some(interval);
+        self
+    }
+
     /// Build the slider (proxy to UiBuilder::build)
     pub fn build(self, parent: &mut ChildSpawnerCommands) -> Entity {
         UiBuilder::build(self, parent)
     }
 
+    /// Build the slider and return a typed [`SliderRef`] for updating it later
+    pub fn build_ref(self, parent: &mut ChildSpawnerCommands) -> SliderRef {
+        SliderRef(UiBuilder::build(self, parent))
+    }
+
     /// Resolve colors (themed > default)
     fn resolve_colors(&self) -> SliderColors {
         self.themed_colors.clone()
@@ -140,12 +320,27 @@ impl UiBuilder for SliderBuilder {
         // Resolve colors (themed > default)
         let colors = self.resolve_colors();
 
-        let container = parent.spawn((
-            self.base.node,
-            BackgroundColor(Color::NONE),
-        )).id();
+        // Size along the track, applied here so that `.width()`/`.height()`
+        // win whichever side of `.orientation()` they are called on
+        let mut node = self.base.node;
+        match self.orientation {
+            Orientation::Horizontal => {
+                if node.width == Val::Auto {
+                    node.width = Val::Px(DEFAULT_TRACK_LENGTH);
+                }
+            }
+            Orientation::Vertical => {
+                if node.height == Val::Auto {
+                    node.height = Val::Px(DEFAULT_TRACK_LENGTH);
+                }
+                if node.align_items == AlignItems::Default {
+                    node.align_items = AlignItems::Center;
+                }
+            }
+        }
+
+        let container = spawn_container(parent, self.base.entity, node);
 
-        let mut value_text_id = None;
         let label = self.label.clone();
         let with_preview = self.with_preview;
         let format = self.format.clone();
@@ -153,66 +348,37 @@ impl UiBuilder for SliderBuilder {
         let min = self.min;
         let max = self.max;
         let step = self.step;
-        let with_buttons = self.with_buttons;
+        let locked = self.locked;
+        let default_value = self.default_value;
+        let show_ticks = self.show_ticks;
+        let display_only = self.display_only;
+        let with_buttons = self.with_buttons && !display_only;
+        let binding = self.binding;
+        let change_throttle = self.change_throttle;
+        let config = SliderConfig {
+            show_value: with_preview,
+            value_format: format.clone(),
+            track_height: dimensions::SLIDER_TRACK_HEIGHT,
+            handle_size: dimensions::SLIDER_HANDLE_SIZE,
+            track_color: colors.track,
+            fill_color: colors.fill,
+            handle_color: colors.handle,
+            orientation: self.orientation,
+            inverted: self.inverted,
+        };
 
         parent.commands().entity(container).with_children(|container| {
-            // Label and value row
-            if label.is_some() || with_preview {
-                container.spawn((
-                    Node {
-                        width: Val::Percent(100.0),
-                        flex_direction: FlexDirection::Row,
-                        justify_content: JustifyContent::SpaceBetween,
-                        ..default()
-                    },
-                    BackgroundColor(Color::NONE),
-                )).with_children(|row| {
-                    // Label
-                    if let Some(label_text) = label.clone() {
-                        row.spawn((
-                            Text::new(label_text),
-                            TextFont {
-                                font_size: dimensions::FONT_SIZE_MEDIUM,
-                                ..default()
-                            },
-                            TextColor(colors.text_label),
-                            SliderLabel,
-                        ));
-                    }
-
-                    // Value text
-                    if with_preview {
-                        let entity = row.spawn((
-                            Text::new(format.format(value)),
-                            TextFont {
-                                font_size: dimensions::FONT_SIZE_MEDIUM,
-                                ..default()
-                            },
-                            TextColor(colors.text_value),
-                            SliderValueText,
-                        )).id();
-                        value_text_id = Some(entity);
-                    }
-                });
-            }
+            let value_text_id = spawn_label_row(
+                container,
+                label.clone(),
+                with_preview.then(|| format.format(value)),
+                &colors,
+            );
 
             // Slider track and handle
             let mut slider_entity = container.spawn((
                 Button,
-                Node {
-                    width: Val::Percent(100.0),
-                    height: Val::Px(dimensions::SLIDER_TRACK_HEIGHT + dimensions::SLIDER_HANDLE_SIZE),
-                    padding: UiRect {
-                        left: Val::Px(dimensions::SLIDER_HANDLE_SIZE / 2.0),  // Half handle width
-                        right: Val::Px(dimensions::SLIDER_HANDLE_SIZE / 2.0), // Half handle width
-                        top: Val::Px((dimensions::SLIDER_HANDLE_SIZE - dimensions::SLIDER_TRACK_HEIGHT) / 2.0),
-                        bottom: Val::Px((dimensions::SLIDER_HANDLE_SIZE - dimensions::SLIDER_TRACK_HEIGHT) / 2.0),
-                    },
-                    justify_content: JustifyContent::Start,
-                    align_items: AlignItems::Center,
-                    position_type: PositionType::Relative,
-                    ..default()
-                },
+                track_container_node(&config),
                 BackgroundColor(Color::NONE),
                 Interaction::default(),
                 RelativeCursorPosition::default(),
@@ -224,66 +390,103 @@ impl UiBuilder for SliderBuilder {
 
             let mut slider = Slider::new(min, max, value);
             slider.step = step;
+            slider.locked = locked;
+            slider.value = slider.snap(slider.value);
             slider.value_text_entity = value_text_id;
 
-            slider_entity.insert(slider.clone());
-            slider_entity.insert(SliderConfig {
-                show_value: with_preview,
-                value_format: format.clone(),
-                track_height: dimensions::SLIDER_TRACK_HEIGHT,
-                handle_size: dimensions::SLIDER_HANDLE_SIZE,
-                track_color: colors.track,
-                fill_color: colors.fill,
-                handle_color: colors.handle,
-            });
+            slider_entity.insert((slider.clone(), AccessibleValue::default()));
+            if let Some(name) = label.clone() {
+                slider_entity.insert(AccessibleName(name));
+            }
+            slider_entity.insert(config.clone());
+
+            if let Some(interval) = change_throttle {
+                slider_entity.insert(SliderThrottle::new(interval));
+            }
+
+            if let Some(value) = default_value {
+                slider_entity.insert(SliderDefault { value });
+            }
+
+            if display_only {
+                slider_entity.insert(SliderDisplayOnly);
+            }
+
+            if let Some(binding) = binding {
+                binding(&mut slider_entity);
+            }
 
             slider_entity.with_children(|track| {
-                // Track background
-                track.spawn((
-                    Node {
-                        width: Val::Percent(100.0),
-                        height: Val::Px(dimensions::SLIDER_TRACK_HEIGHT),
-                        position_type: PositionType::Absolute,
-                        ..default()
-                    },
-                    BackgroundColor(colors.track),
-                    BorderRadius::all(Val::Px(dimensions::SLIDER_TRACK_HEIGHT / 2.0)),
-                ));
+                spawn_track_background(track, &config, &colors);
+
+                // Locked regions
+                let range = (max - min).max(f32::EPSILON);
+                for region in &slider.locked {
+                    let mut node = config.track_node(
+                        Val::Percent((region.end - region.start) / range * 100.0),
+                        Val::Px(dimensions::SLIDER_TRACK_HEIGHT),
+                    );
+                    config.place_along(&mut node, (region.start - min) / range * 100.0);
+                    track.spawn((
+                        node,
+                        BackgroundColor(colors.locked),
+                        BorderRadius::all(Val::Px(dimensions::SLIDER_TRACK_HEIGHT / 2.0)),
+                        SliderLockedRegion,
+                    ));
+                }
 
                 // Filled portion
                 let fill_width = slider.normalized() * 100.0;
                 track.spawn((
-                    Node {
-                        width: Val::Percent(fill_width),
-                        height: Val::Px(dimensions::SLIDER_TRACK_HEIGHT),
-                        position_type: PositionType::Absolute,
-                        ..default()
-                    },
+                    config.track_node(Val::Percent(fill_width), Val::Px(dimensions::SLIDER_TRACK_HEIGHT)),
                     BackgroundColor(colors.fill),
                     BorderRadius::all(Val::Px(dimensions::SLIDER_TRACK_HEIGHT / 2.0)),
                     SliderFill,
                 ));
 
+                // Step notches, lined up with the handle's center at each stop
+                let stops = if show_ticks { slider.stops() } else { Vec::new() };
+                if stops.len() <= MAX_TICKS {
+                    for stop in stops {
+                        let mut node = config.track_node(
+                            Val::Px(TICK_WIDTH),
+                            Val::Px(dimensions::SLIDER_TRACK_HEIGHT),
+                        );
+                        config.place_along(&mut node, (stop - min) / range * 100.0);
+                        node.margin = config.start_margin((dimensions::SLIDER_HANDLE_SIZE - TICK_WIDTH) / 2.0);
+                        track.spawn((
+                            node,
+                            BackgroundColor(colors.marker),
+                            SliderTick,
+                        ));
+                    }
+                }
+
+                // Recommended value tick, drawn over the fill but under the
+                // handle and lined up with the handle's center at that value
+                if let Some(value) = default_value {
+                    let mut node = config.track_node(
+                        Val::Px(DEFAULT_MARKER_WIDTH),
+                        Val::Px(dimensions::SLIDER_TRACK_HEIGHT + DEFAULT_MARKER_OVERHANG * 2.0),
+                    );
+                    config.place_along(&mut node, (value - min) / range * 100.0);
+                    node.margin = config.start_margin((dimensions::SLIDER_HANDLE_SIZE - DEFAULT_MARKER_WIDTH) / 2.0);
+                    track.spawn((
+                        node,
+                        BackgroundColor(colors.marker),
+                        BorderRadius::all(Val::Px(DEFAULT_MARKER_WIDTH / 2.0)),
+                        SliderDefaultMarker,
+                    ));
+                }
+
                 // Handle
-                let handle_offset = slider.normalized() * 100.0;
-                track.spawn((
-                    Node {
-                        width: Val::Px(dimensions::SLIDER_HANDLE_SIZE),
-                        height: Val::Px(dimensions::SLIDER_HANDLE_SIZE),
-                        position_type: PositionType::Absolute,
-                        left: Val::Percent(handle_offset),
-                        top: Val::Px(0.0),
-                        border: UiRect::all(Val::Px(dimensions::BORDER_WIDTH_MEDIUM)),
-                        ..default()
-                    },
-                    BackgroundColor(colors.handle),
-                    BorderColor::all(colors.handle_border),
-                    BorderRadius::all(Val::Px(dimensions::SLIDER_HANDLE_SIZE / 2.0)),
-                    SliderHandle,
-                    Transform::default(), // Required for scale animations
-                    AnimationCategory::Slider, // Auto-animation with slider defaults (1.15 scale)
-                    Interaction::default(),
-                ));
+                let mut handle = spawn_handle(track, &config, slider.normalized() * 100.0, &colors);
+                if !display_only {
+                    handle.insert((
+                        AnimationCategory::Slider, // Auto-animation with slider defaults (1.15 scale)
+                        Interaction::default(),
+                    ));
+                }
             });
 
             // Add increment/decrement buttons if requested
@@ -376,6 +579,140 @@ impl LayoutBuilder for SliderBuilder {
     }
 }
 
+/// Spawn a slider's outer container, on the builder's `id()` entity if set
+pub(super) fn spawn_container(parent: &mut ChildSpawnerCommands, id: Option<Entity>, node: Node) -> Entity {
+    let bundle = (node, BackgroundColor(Color::NONE));
+    match id {
+        Some(id) => {
+            let parent_entity = parent.target_entity();
+            parent.commands().entity(id).insert((bundle, ChildOf(parent_entity)));
+            id
+        }
+        None => parent.spawn(bundle).id(),
+    }
+}
+
+/// Spawn the label and value preview row above a slider's track
+///
+/// Returns the value text entity when `preview` holds its initial text.
+pub(super) fn spawn_label_row(
+    container: &mut ChildSpawnerCommands,
+    label: Option<String>,
+    preview: Option<String>,
+    colors: &SliderColors,
+) -> Option<Entity> {
+    if label.is_none() && preview.is_none() {
+        return None;
+    }
+
+    let mut value_text = None;
+    container.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Row,
+            justify_content: JustifyContent::SpaceBetween,
+            ..default()
+        },
+        BackgroundColor(Color::NONE),
+    )).with_children(|row| {
+        // Label
+        if let Some(label_text) = label {
+            row.spawn((
+                Text::new(label_text),
+                TextFont {
+                    font_size: dimensions::FONT_SIZE_MEDIUM,
+                    ..default()
+                },
+                TextColor(colors.text_label),
+                SliderLabel,
+            ));
+        }
+
+        // Value text
+        if let Some(text) = preview {
+            value_text = Some(row.spawn((
+                Text::new(text),
+                TextFont {
+                    font_size: dimensions::FONT_SIZE_MEDIUM,
+                    ..default()
+                },
+                TextColor(colors.text_value),
+                SliderValueText,
+            )).id());
+        }
+    });
+    value_text
+}
+
+/// Node of the clickable track area holding a slider's track and handles
+pub(super) fn track_container_node(config: &SliderConfig) -> Node {
+    let horizontal = config.orientation == Orientation::Horizontal;
+    let thickness = Val::Px(dimensions::SLIDER_TRACK_HEIGHT + dimensions::SLIDER_HANDLE_SIZE);
+    let end_padding = Val::Px(dimensions::SLIDER_HANDLE_SIZE / 2.0); // Half handle width
+    let side_padding = Val::Px((dimensions::SLIDER_HANDLE_SIZE - dimensions::SLIDER_TRACK_HEIGHT) / 2.0);
+
+    Node {
+        width: if horizontal { Val::Percent(100.0) } else { thickness },
+        height: if horizontal { thickness } else { Val::Auto },
+        flex_grow: if horizontal { 0.0 } else { 1.0 },
+        flex_direction: if horizontal { FlexDirection::Row } else { FlexDirection::Column },
+        padding: if horizontal {
+            UiRect::axes(end_padding, side_padding)
+        } else {
+            UiRect::axes(side_padding, end_padding)
+        },
+        // The fill grows from the minimum end of the track
+        justify_content: if horizontal != config.inverted {
+            JustifyContent::Start
+        } else {
+            JustifyContent::End
+        },
+        align_items: AlignItems::Center,
+        position_type: PositionType::Relative,
+        ..default()
+    }
+}
+
+/// Spawn the full-length track background
+pub(super) fn spawn_track_background(track: &mut ChildSpawnerCommands, config: &SliderConfig, colors: &SliderColors) {
+    track.spawn((
+        config.track_node(Val::Percent(100.0), Val::Px(dimensions::SLIDER_TRACK_HEIGHT)),
+        BackgroundColor(colors.track),
+        BorderRadius::all(Val::Px(dimensions::SLIDER_TRACK_HEIGHT / 2.0)),
+    ));
+}
+
+/// Spawn a handle `percent` of the way along the track
+pub(super) fn spawn_handle<'a>(
+    track: &'a mut ChildSpawnerCommands,
+    config: &SliderConfig,
+    percent: f32,
+    colors: &SliderColors,
+) -> EntityCommands<'a> {
+    let mut node = Node {
+        width: Val::Px(dimensions::SLIDER_HANDLE_SIZE),
+        height: Val::Px(dimensions::SLIDER_HANDLE_SIZE),
+        position_type: PositionType::Absolute,
+        border: UiRect::all(Val::Px(dimensions::BORDER_WIDTH_MEDIUM)),
+        ..default()
+    };
+    config.place_along(&mut node, percent);
+    if config.orientation == Orientation::Horizontal {
+        node.top = Val::Px(0.0);
+    } else {
+        node.left = Val::Px(0.0);
+    }
+
+    track.spawn((
+        node,
+        BackgroundColor(colors.handle),
+        BorderColor::all(colors.handle_border),
+        BorderRadius::all(Val::Px(dimensions::SLIDER_HANDLE_SIZE / 2.0)),
+        SliderHandle,
+        Transform::default(), // Required for scale animations
+    ))
+}
+
 /// Convenience function to create a slider with a range
 pub fn slider(min: f32, max: f32) -> SliderBuilder {
     SliderBuilder::new(min..max)
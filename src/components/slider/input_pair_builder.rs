@@ -0,0 +1,170 @@
+//! SliderWithInputBuilder implementation
+
+use bevy::prelude::*;
+use crate::components::number_input::NumberInputBuilder;
+use crate::styles::dimensions;
+use crate::theme::UiTheme;
+use crate::traits::{UiBuilder, LayoutBuilder, BuilderBase};
+use super::builder::SliderBuilder;
+use super::types::*;
+
+/// Default width of the number box next to the slider
+const DEFAULT_INPUT_WIDTH: f32 = 80.0;
+
+/// Builder for a slider with a number input beside it, kept in sync
+///
+/// Dragging the slider updates the number; typing a number moves the slider
+/// (clamped to the range and snapped to the step). The common settings-row
+/// pattern for values that need both quick and precise adjustment.
+///
+/// # Example
+/// ```ignore
+/// slider_with_input(60.0..120.0, 1.0)
+///     .label("Field of View")
+///     .value(90.0)
+///     .build(parent);
+/// ```
+pub struct SliderWithInputBuilder {
+    slider: SliderBuilder,
+    input: NumberInputBuilder,
+    step: f32,
+    base: BuilderBase,
+}
+
+impl SliderWithInputBuilder {
+    /// Create a slider and number input over `range`, moving in `step` increments
+    pub fn new(range: std::ops::Range<f32>, step: f32) -> Self {
+        let mut base = BuilderBase::new();
+        base.node.flex_direction = FlexDirection::Row;
+        base.node.align_items = AlignItems::FlexEnd;
+        base.node.column_gap = Val::Px(dimensions::SPACING_MEDIUM);
+        base.node.margin = UiRect::bottom(Val::Px(dimensions::SPACING_MEDIUM));
+
+        let input = NumberInputBuilder::new()
+            .min(range.start)
+            .max(range.end)
+            .step(step)
+            .default_value(range.start)
+            .width(Val::Px(DEFAULT_INPUT_WIDTH));
+        let slider = SliderBuilder::new(range)
+            .step(step)
+            .with_preview(false) // The input shows the value
+            .margin(UiRect::ZERO);
+
+        Self {
+            slider,
+            input,
+            step,
+            base,
+        }
+    }
+
+    /// Apply theme colors to the slider
+    pub fn themed(mut self, theme: &UiTheme) -> Self {
+        self.slider = self.slider.themed(theme);
+        self
+    }
+
+    /// Set the initial value
+    pub fn value(mut self, value: f32) -> Self {
+        self.slider = self.slider.value(value);
+        self.input = self.input.default_value(value);
+        self
+    }
+
+    /// Add a label above the slider
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.slider = self.slider.label(label);
+        self
+    }
+
+    /// Set the width of the slider
+    pub fn slider_width(mut self, width: Val) -> Self {
+        self.slider = self.slider.width(width);
+        self
+    }
+
+    /// Set the width of the number input
+    pub fn input_width(mut self, width: Val) -> Self {
+        self.input = self.input.width(width);
+        self
+    }
+
+    /// Build the row and spawn it (proxy to UiBuilder::build)
+    pub fn build(self, parent: &mut ChildSpawnerCommands) -> Entity {
+        UiBuilder::build(self, parent)
+    }
+}
+
+impl UiBuilder for SliderWithInputBuilder {
+    fn build(self, parent: &mut ChildSpawnerCommands) -> Entity {
+        let Self { slider, input, step, base } = self;
+
+        let mut slider_entity = Entity::PLACEHOLDER;
+        let mut input_entity = Entity::PLACEHOLDER;
+        let row = parent.spawn((
+            base.node,
+            BackgroundColor(Color::NONE),
+        )).with_children(|row| {
+            slider_entity = slider.build(row);
+            input_entity = input.build(row);
+        }).id();
+
+        parent.commands().entity(row).insert(SliderInputPair {
+            slider: slider_entity,
+            input: input_entity,
+            decimals: step_decimals(step),
+        });
+
+        // Apply hooks
+        for hook in base.hooks {
+            hook(&mut parent.commands().entity(row));
+        }
+
+        row
+    }
+
+    fn insert(mut self, bundle: impl Bundle + Clone) -> Self {
+        self.base.hooks.push(Box::new(move |cmds| {
+            cmds.insert(bundle.clone());
+        }));
+        self
+    }
+
+    fn id(mut self, id: Entity) -> Self {
+        self.base.entity = Some(id);
+        self
+    }
+}
+
+impl LayoutBuilder for SliderWithInputBuilder {
+    fn node(mut self, node: Node) -> Self {
+        self.base.node = node;
+        self
+    }
+
+    fn margin(mut self, margin: UiRect) -> Self {
+        self.base.node.margin = margin;
+        self
+    }
+
+    fn padding(mut self, padding: UiRect) -> Self {
+        self.base.node.padding = padding;
+        self
+    }
+
+    fn width(mut self, width: Val) -> Self {
+        self.base.node.width = width;
+        self
+    }
+
+    fn height(mut self, height: Val) -> Self {
+        self.base.node.height = height;
+        self
+    }
+}
+
+/// Convenience function to create a slider paired with a number input
+pub fn slider_with_input(range: std::ops::Range<f32>, step: f32) -> SliderWithInputBuilder {
+    SliderWithInputBuilder::new(range, step)
+}
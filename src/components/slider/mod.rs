@@ -2,21 +2,50 @@
 
 // Private submodules - no implementation here!
 mod builder;
+#[cfg(feature = "number_input")]
+mod input_pair_builder;
+mod pad_builder;
 mod plugin;
+mod range_builder;
 mod systems;
 mod types;
 
 // Public exports only
 pub use builder::{SliderBuilder, slider, percentage_slider, normalized_slider};
-pub use plugin::SliderPlugin;
+#[cfg(feature = "number_input")]
+pub use input_pair_builder::{SliderWithInputBuilder, slider_with_input};
+pub use pad_builder::{Pad2DBuilder, pad_2d};
+pub use plugin::{SliderPlugin, SliderBindingPlugin};
+pub use range_builder::{RangeSliderBuilder, range_slider};
 pub use types::{
     Slider,
+    SliderRef,
+    SliderChanged,
+    RangeSlider,
+    RangeHandle,
+    RangeSliderChanged,
+    SliderThrottle,
     SliderHandle,
     SliderTrack,
     SliderFill,
+    SliderLockedRegion,
+    SliderDefault,
+    SliderDefaultMarker,
+    SliderTick,
+    SliderFocused,
+    SliderDisplayOnly,
     SliderValueText,
     SliderLabel,
+    AccessibleValue,
     SliderButtonAction,
     SliderConfig,
+    SliderBinding,
     ValueFormat,
-};
\ No newline at end of file
+    Pad2D,
+    Pad2DArea,
+    Pad2DHandle,
+    Pad2DCrosshair,
+    Pad2DChanged,
+};
+#[cfg(feature = "number_input")]
+pub use types::SliderInputPair;
@@ -0,0 +1,243 @@
+//! Pad2DBuilder implementation
+
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+use crate::animation::AnimationCategory;
+use crate::styles::dimensions;
+use crate::theme::{UiTheme, SemanticVariant};
+use crate::traits::{UiBuilder, LayoutBuilder, BuilderBase};
+use super::types::*;
+
+/// Default edge length of a pad
+const DEFAULT_PAD_SIZE: f32 = 160.0;
+
+/// Builder for two-axis control surfaces (XY pads)
+///
+/// Dragging the handle anywhere inside the square emits [`Pad2DChanged`]
+/// with both axes normalized to 0.0..=1.0. Handy for color saturation/value
+/// areas, joystick simulation or position pickers.
+pub struct Pad2DBuilder {
+    x: f32,
+    y: f32,
+    crosshair: bool,
+    variant: SemanticVariant,
+    // Theme-resolved colors (set via .themed())
+    themed_colors: Option<SliderColors>,
+    base: BuilderBase,
+}
+
+impl Pad2DBuilder {
+    /// Create a new pad builder with the handle centered
+    pub fn new() -> Self {
+        let mut base = BuilderBase::new();
+        base.node.width = Val::Px(DEFAULT_PAD_SIZE);
+        base.node.height = Val::Px(DEFAULT_PAD_SIZE);
+
+        Self {
+            x: 0.5,
+            y: 0.5,
+            crosshair: true,
+            variant: SemanticVariant::Primary,
+            themed_colors: None,
+            base,
+        }
+    }
+
+    /// Apply theme colors to this builder.
+    ///
+    /// Call this method to use theme-aware styling. If not called,
+    /// sensible defaults (matching the dark theme) will be used.
+    pub fn themed(mut self, theme: &UiTheme) -> Self {
+        self.themed_colors = Some(SliderColors::from_theme(theme, self.variant));
+        self
+    }
+
+    /// Set the semantic variant (handle and crosshair color)
+    pub fn variant(mut self, variant: SemanticVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Set the initial normalized position (y = 0.0 is the bottom edge)
+    pub fn value(mut self, x: f32, y: f32) -> Self {
+        self.x = x;
+        self.y = y;
+        self
+    }
+
+    /// Set the width and height of the pad in pixels
+    pub fn size(mut self, size: f32) -> Self {
+        self.base.node.width = Val::Px(size);
+        self.base.node.height = Val::Px(size);
+        self
+    }
+
+    /// Show or hide the crosshair lines through the handle (default: shown)
+    pub fn crosshair(mut self, show: bool) -> Self {
+        self.crosshair = show;
+        self
+    }
+
+    /// Build the pad entity (proxy to UiBuilder::build)
+    pub fn build(self, parent: &mut ChildSpawnerCommands) -> Entity {
+        UiBuilder::build(self, parent)
+    }
+
+    /// Resolve colors (themed > default)
+    fn resolve_colors(&self) -> SliderColors {
+        self.themed_colors.clone()
+            .unwrap_or_else(|| SliderColors::default_colors(self.variant))
+    }
+}
+
+impl Default for Pad2DBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UiBuilder for Pad2DBuilder {
+    fn build(self, parent: &mut ChildSpawnerCommands) -> Entity {
+        // Resolve colors (themed > default)
+        let colors = self.resolve_colors();
+        let pad = Pad2D::new(self.x, self.y);
+        let handle_size = dimensions::SLIDER_HANDLE_SIZE;
+        let left = Val::Percent(pad.x * 100.0);
+        let top = Val::Percent((1.0 - pad.y) * 100.0);
+
+        let mut node = self.base.node;
+        node.position_type = PositionType::Relative;
+        node.border = UiRect::all(Val::Px(dimensions::BORDER_WIDTH_THIN));
+
+        let entity = parent.spawn((
+            Button,
+            node,
+            BackgroundColor(colors.track),
+            BorderColor::all(colors.handle_border),
+            BorderRadius::all(Val::Px(dimensions::BORDER_RADIUS_MEDIUM)),
+            Interaction::default(),
+            pad,
+        )).with_children(|pad_node| {
+            // Inset by half a handle so the handle stays fully inside the pad
+            pad_node.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(handle_size / 2.0),
+                    right: Val::Px(handle_size / 2.0),
+                    top: Val::Px(handle_size / 2.0),
+                    bottom: Val::Px(handle_size / 2.0),
+                    ..default()
+                },
+                BackgroundColor(Color::NONE),
+                RelativeCursorPosition::default(),
+                Pad2DArea,
+            )).with_children(|area| {
+                if self.crosshair {
+                    area.spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Px(0.0),
+                            width: Val::Percent(100.0),
+                            height: Val::Px(1.0),
+                            top,
+                            ..default()
+                        },
+                        BackgroundColor(colors.fill),
+                        Pickable::IGNORE,
+                        Pad2DCrosshair { horizontal: true },
+                    ));
+
+                    area.spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            top: Val::Px(0.0),
+                            height: Val::Percent(100.0),
+                            width: Val::Px(1.0),
+                            left,
+                            ..default()
+                        },
+                        BackgroundColor(colors.fill),
+                        Pickable::IGNORE,
+                        Pad2DCrosshair { horizontal: false },
+                    ));
+                }
+
+                // Handle, centered on the value via negative margins
+                area.spawn((
+                    Node {
+                        width: Val::Px(handle_size),
+                        height: Val::Px(handle_size),
+                        position_type: PositionType::Absolute,
+                        left,
+                        top,
+                        margin: UiRect {
+                            left: Val::Px(-handle_size / 2.0),
+                            top: Val::Px(-handle_size / 2.0),
+                            ..default()
+                        },
+                        border: UiRect::all(Val::Px(dimensions::BORDER_WIDTH_MEDIUM)),
+                        ..default()
+                    },
+                    BackgroundColor(colors.handle),
+                    BorderColor::all(colors.handle_border),
+                    BorderRadius::all(Val::Px(handle_size / 2.0)),
+                    Pad2DHandle,
+                    Transform::default(), // Required for scale animations
+                    AnimationCategory::Slider, // Same hover feel as slider handles
+                    Interaction::default(),
+                ));
+            });
+        }).id();
+
+        // Apply hooks
+        for hook in self.base.hooks {
+            hook(&mut parent.commands().entity(entity));
+        }
+
+        entity
+    }
+
+    fn insert(mut self, bundle: impl Bundle + Clone) -> Self {
+        self.base.hooks.push(Box::new(move |cmds| {
+            cmds.insert(bundle.clone());
+        }));
+        self
+    }
+
+    fn id(mut self, id: Entity) -> Self {
+        self.base.entity = Some(id);
+        self
+    }
+}
+
+impl LayoutBuilder for Pad2DBuilder {
+    fn node(mut self, node: Node) -> Self {
+        self.base.node = node;
+        self
+    }
+
+    fn margin(mut self, margin: UiRect) -> Self {
+        self.base.node.margin = margin;
+        self
+    }
+
+    fn padding(mut self, padding: UiRect) -> Self {
+        self.base.node.padding = padding;
+        self
+    }
+
+    fn width(mut self, width: Val) -> Self {
+        self.base.node.width = width;
+        self
+    }
+
+    fn height(mut self, height: Val) -> Self {
+        self.base.node.height = height;
+        self
+    }
+}
+
+/// Convenience function to create a pad builder
+pub fn pad_2d() -> Pad2DBuilder {
+    Pad2DBuilder::new()
+}
@@ -1,18 +1,79 @@
 //! Slider plugin
 #![allow(missing_docs)]
 
+use std::marker::PhantomData;
+use bevy::prelude::*;
 use bevy_plugin_builder::define_plugin;
 use super::systems::{
     handle_slider_interaction,
+    update_slider_focus,
+    handle_slider_keyboard,
     update_slider_visuals,
-    handle_slider_button_clicks
+    handle_slider_button_clicks,
+    emit_slider_changes,
+    update_accessible_values,
+    sync_slider_bindings,
+    handle_pad_interaction,
+    update_pad_visuals,
+    handle_range_slider_interaction,
+    update_range_slider_visuals,
+    emit_range_slider_changes,
 };
+use super::types::{Pad2DChanged, RangeSliderChanged, SliderChanged};
+#[cfg(feature = "number_input")]
+use super::systems::{apply_paired_input_values, sync_paired_inputs};
 
 // Plugin that provides slider systems
 define_plugin!(SliderPlugin {
+    messages: [Pad2DChanged, RangeSliderChanged, SliderChanged],
+    custom_init: |app: &mut App| {
+        // Sliders paired with a number input: typed values move the slider
+        // before it is drawn, and the input shows the value after dragging
+        #[cfg(feature = "number_input")]
+        app.add_systems(Update, (
+            apply_paired_input_values.before(update_slider_visuals),
+            sync_paired_inputs
+                .after(handle_slider_interaction)
+                .after(handle_slider_keyboard)
+                .after(handle_slider_button_clicks),
+        ));
+    },
     update: [
         handle_slider_interaction,
+        update_slider_focus,
+        handle_slider_keyboard,
         update_slider_visuals,
-        handle_slider_button_clicks
+        handle_slider_button_clicks,
+        emit_slider_changes
+            .after(handle_slider_interaction)
+            .after(handle_slider_button_clicks)
+            .after(handle_slider_keyboard),
+        update_accessible_values
+            .after(handle_slider_interaction)
+            .after(handle_slider_button_clicks)
+            .after(handle_slider_keyboard),
+        (handle_pad_interaction, update_pad_visuals).chain(),
+        (handle_range_slider_interaction, update_range_slider_visuals, emit_range_slider_changes).chain()
     ]
-});
\ No newline at end of file
+});
+
+/// Plugin that syncs sliders bound with `SliderBuilder::bind::<R>` to resource `R`.
+///
+/// Add one instance per bound resource type:
+///
+/// ```ignore
+/// app.add_plugins(SliderBindingPlugin::<AudioSettings>::default());
+/// ```
+pub struct SliderBindingPlugin<R: Resource>(PhantomData<R>);
+
+impl<R: Resource> Default for SliderBindingPlugin<R> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<R: Resource> Plugin for SliderBindingPlugin<R> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, sync_slider_bindings::<R>);
+    }
+}
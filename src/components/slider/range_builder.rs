@@ -0,0 +1,242 @@
+//! RangeSliderBuilder implementation
+
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+use crate::animation::AnimationCategory;
+use crate::styles::dimensions;
+use crate::theme::{UiTheme, SemanticVariant};
+use crate::traits::{UiBuilder, LayoutBuilder, BuilderBase};
+use super::builder::{spawn_container, spawn_handle, spawn_label_row, spawn_track_background, track_container_node};
+use super::types::*;
+
+/// Builder for two-handle sliders selecting a span of a range
+///
+/// Dragging either handle emits [`RangeSliderChanged`] with both bounds;
+/// the fill spans between the handles, which never cross. Handy for price
+/// ranges and other min-max filters.
+pub struct RangeSliderBuilder {
+    low: f32,
+    high: f32,
+    min: f32,
+    max: f32,
+    step: Option<f32>,
+    format: ValueFormat,
+    variant: SemanticVariant,
+    with_preview: bool,
+    label: Option<String>,
+    // Theme-resolved colors (set via .themed())
+    themed_colors: Option<SliderColors>,
+    base: BuilderBase,
+}
+
+impl RangeSliderBuilder {
+    /// Create a new range slider builder spanning the whole range
+    pub fn new(range: std::ops::Range<f32>) -> Self {
+        let mut base = BuilderBase::new();
+        base.node.width = Val::Px(200.0);
+        base.node.flex_direction = FlexDirection::Column;
+        base.node.row_gap = Val::Px(dimensions::SPACING_SMALL);
+        base.node.margin = UiRect::bottom(Val::Px(dimensions::SPACING_MEDIUM));
+
+        Self {
+            low: range.start,
+            high: range.end,
+            min: range.start,
+            max: range.end,
+            step: None,
+            format: ValueFormat::Decimal(1),
+            variant: SemanticVariant::Primary,
+            with_preview: true,
+            label: None,
+            themed_colors: None,
+            base,
+        }
+    }
+
+    /// Apply theme colors to this builder.
+    ///
+    /// Call this method to use theme-aware styling. If not called,
+    /// sensible defaults (matching the dark theme) will be used.
+    pub fn themed(mut self, theme: &UiTheme) -> Self {
+        self.themed_colors = Some(SliderColors::from_theme(theme, self.variant));
+        self
+    }
+
+    /// Set the semantic variant (controls colors)
+    pub fn variant(mut self, variant: SemanticVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Set the initial lower and upper values
+    pub fn values(mut self, low: f32, high: f32) -> Self {
+        self.low = low;
+        self.high = high;
+        self
+    }
+
+    /// Set the step size for snapping
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Set the width
+    pub fn width(mut self, width: Val) -> Self {
+        self.base.node.width = width;
+        self
+    }
+
+    /// Set the format both values are shown in
+    pub fn format(mut self, format: ValueFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Show/hide the value preview ("low - high")
+    pub fn with_preview(mut self, show: bool) -> Self {
+        self.with_preview = show;
+        self
+    }
+
+    /// Add a label
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Build the range slider (proxy to UiBuilder::build)
+    pub fn build(self, parent: &mut ChildSpawnerCommands) -> Entity {
+        UiBuilder::build(self, parent)
+    }
+
+    /// Resolve colors (themed > default)
+    fn resolve_colors(&self) -> SliderColors {
+        self.themed_colors.clone()
+            .unwrap_or_else(|| SliderColors::default_colors(self.variant))
+    }
+}
+
+impl UiBuilder for RangeSliderBuilder {
+    fn build(self, parent: &mut ChildSpawnerCommands) -> Entity {
+        // Resolve colors (themed > default)
+        let colors = self.resolve_colors();
+
+        let container = spawn_container(parent, self.base.entity, self.base.node);
+
+        let mut slider = RangeSlider::new(self.min, self.max, self.low, self.high);
+        slider.step = self.step;
+        slider.low = slider.snap(slider.low);
+        slider.high = slider.snap(slider.high).max(slider.low);
+
+        let label = self.label;
+        let with_preview = self.with_preview;
+        let config = SliderConfig {
+            show_value: with_preview,
+            value_format: self.format,
+            track_height: dimensions::SLIDER_TRACK_HEIGHT,
+            handle_size: dimensions::SLIDER_HANDLE_SIZE,
+            track_color: colors.track,
+            fill_color: colors.fill,
+            handle_color: colors.handle,
+            ..default()
+        };
+
+        parent.commands().entity(container).with_children(|container| {
+            slider.value_text_entity = spawn_label_row(
+                container,
+                label,
+                with_preview.then(|| slider.format(&config.value_format)),
+                &colors,
+            );
+
+            // Track, fill and both handles
+            container.spawn((
+                Button,
+                track_container_node(&config),
+                BackgroundColor(Color::NONE),
+                Interaction::default(),
+                RelativeCursorPosition::default(),
+                slider.clone(),
+                config.clone(),
+            )).with_children(|track| {
+                spawn_track_background(track, &config, &colors);
+
+                // Fill between the handles' centers
+                let low = slider.normalized(slider.low) * 100.0;
+                let high = slider.normalized(slider.high) * 100.0;
+                let mut fill_node = config.track_node(
+                    Val::Percent(high - low),
+                    Val::Px(dimensions::SLIDER_TRACK_HEIGHT),
+                );
+                config.place_along(&mut fill_node, low);
+                fill_node.margin = config.start_margin(dimensions::SLIDER_HANDLE_SIZE / 2.0);
+                track.spawn((
+                    fill_node,
+                    BackgroundColor(colors.fill),
+                    SliderFill,
+                ));
+
+                for handle in [RangeHandle::Low, RangeHandle::High] {
+                    let percent = slider.normalized(slider.value(handle)) * 100.0;
+                    spawn_handle(track, &config, percent, &colors).insert((
+                        handle,
+                        AnimationCategory::Slider,
+                        Interaction::default(),
+                    ));
+                }
+            });
+        });
+
+        // Apply hooks
+        for hook in self.base.hooks {
+            hook(&mut parent.commands().entity(container));
+        }
+
+        container
+    }
+
+    fn insert(mut self, bundle: impl Bundle + Clone) -> Self {
+        self.base.hooks.push(Box::new(move |cmds| {
+            cmds.insert(bundle.clone());
+        }));
+        self
+    }
+
+    fn id(mut self, id: Entity) -> Self {
+        self.base.entity = Some(id);
+        self
+    }
+}
+
+impl LayoutBuilder for RangeSliderBuilder {
+    fn node(mut self, node: Node) -> Self {
+        self.base.node = node;
+        self
+    }
+
+    fn margin(mut self, margin: UiRect) -> Self {
+        self.base.node.margin = margin;
+        self
+    }
+
+    fn padding(mut self, padding: UiRect) -> Self {
+        self.base.node.padding = padding;
+        self
+    }
+
+    fn width(mut self, width: Val) -> Self {
+        self.base.node.width = width;
+        self
+    }
+
+    fn height(mut self, height: Val) -> Self {
+        self.base.node.height = height;
+        self
+    }
+}
+
+/// Convenience function to create a range slider over `min..max`
+pub fn range_slider(min: f32, max: f32) -> RangeSliderBuilder {
+    RangeSliderBuilder::new(min..max)
+}
@@ -1,49 +1,248 @@
 //! Slider interaction systems
 
+use std::collections::HashMap;
 use bevy::prelude::*;
 use bevy::ui::RelativeCursorPosition;
+use crate::accessibility::AccessibleName;
 use super::types::*;
 
+#[cfg(feature = "number_input")]
+use crate::components::number_input::NumberInputChangedEvent;
+#[cfg(feature = "number_input")]
+use crate::components::text_input::TextBuffer;
+
+/// Longest gap between two clicks on a track that counts as a double-click
+const DOUBLE_CLICK_SECS: f64 = 0.4;
+
 /// Handle slider dragging interaction
+///
+/// Double-clicking the track of a slider with a [`SliderDefault`] resets it
+/// to that value instead of starting a drag.
 pub fn handle_slider_interaction(
     mut sliders: Query<
         (
             Entity,
             &Interaction,
             &mut Slider,
-            &Node,
+            &SliderConfig,
             &RelativeCursorPosition,
             &Children,
+            Option<&SliderDefault>,
         ),
-        With<SliderTrack>,
+        (With<SliderTrack>, Without<SliderDisplayOnly>),
     >,
     mouse_button: Res<ButtonInput<MouseButton>>,
+    time: Res<Time>,
     mut dragged_slider: Local<Option<Entity>>,
+    mut last_click: Local<Option<(Entity, f64)>>,
 ) {
     // If mouse was released, stop dragging
     if !mouse_button.pressed(MouseButton::Left) {
         *dragged_slider = None;
     }
 
-    for (entity, interaction, mut slider, _node, cursor_pos, _children) in &mut sliders {
-        // Start dragging on press
+    let now = time.elapsed_secs_f64();
+
+    for (entity, interaction, mut slider, config, cursor_pos, _children, default) in &mut sliders {
+        // A click anywhere on the track jumps straight to the nearest value
+        // (or step), then keeps dragging while the button is held
         if *interaction == Interaction::Pressed
-            && mouse_button.pressed(MouseButton::Left)
+            && mouse_button.just_pressed(MouseButton::Left)
             && dragged_slider.is_none()
         {
+            let double_click = last_click
+                .is_some_and(|(clicked, at)| clicked == entity && now - at <= DOUBLE_CLICK_SECS);
+
+            if let (true, Some(default)) = (double_click, default) {
+                slider.set_value(default.value);
+                *last_click = None;
+                continue;
+            }
+            *last_click = Some((entity, now));
+
+            if let Some(fraction) = cursor_fraction(cursor_pos) {
+                slider.set_normalized(config.track_fraction(fraction));
+            }
             *dragged_slider = Some(entity);
+            continue;
         }
 
         if *dragged_slider == Some(entity) {
-            if let Some(cursor_pos) = cursor_pos.normalized {
-                // Bevy 0.17: Convert center-based [-0.5, 0.5] to corner-based [0.0, 1.0]
-                let normalized_x = (cursor_pos.x + 0.5).clamp(0.0, 1.0);
-                slider.set_normalized(normalized_x);
+            if let Some(fraction) = cursor_fraction(cursor_pos) {
+                slider.set_normalized(config.track_fraction(fraction));
             }
         }
     }
 }
 
+/// Drag the handles of range sliders
+///
+/// A press grabs the handle nearest the cursor and jumps it there. When the
+/// handles overlap, the first move picks which one follows the cursor.
+pub fn handle_range_slider_interaction(
+    mut sliders: Query<(Entity, &Interaction, &mut RangeSlider, &SliderConfig, &RelativeCursorPosition)>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    mut dragged: Local<Option<(Entity, Option<RangeHandle>)>>,
+) {
+    if !mouse_button.pressed(MouseButton::Left) {
+        *dragged = None;
+    }
+
+    for (entity, interaction, mut slider, config, cursor_pos) in &mut sliders {
+        let pressed = *interaction == Interaction::Pressed
+            && mouse_button.just_pressed(MouseButton::Left)
+            && dragged.is_none();
+        if !pressed && dragged.is_none_or(|(dragged, _)| dragged != entity) {
+            continue;
+        }
+        let Some(fraction) = cursor_fraction(cursor_pos) else {
+            continue;
+        };
+
+        let normalized = config.track_fraction(fraction);
+        let target = slider.snap(slider.min + (slider.max - slider.min) * normalized);
+        let handle = match *dragged {
+            Some((_, Some(handle))) => Some(handle),
+            _ => slider.handle_near(target),
+        };
+        *dragged = Some((entity, handle));
+
+        if let Some(handle) = handle {
+            if slider.value(handle) != target {
+                slider.set_normalized(handle, normalized);
+            }
+        }
+    }
+}
+
+/// Give keyboard focus to the slider whose track was just pressed
+pub fn update_slider_focus(
+    mut commands: Commands,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    tracks: Query<(Entity, &Interaction), (With<SliderTrack>, Without<SliderDisplayOnly>)>,
+    focused: Query<Entity, With<SliderFocused>>,
+) {
+    if !mouse_button.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    let pressed = tracks.iter()
+        .find(|(_, interaction)| **interaction == Interaction::Pressed)
+        .map(|(entity, _)| entity);
+
+    for entity in focused.iter() {
+        if Some(entity) != pressed {
+            commands.entity(entity).remove::<SliderFocused>();
+        }
+    }
+    if let Some(entity) = pressed {
+        commands.entity(entity).insert(SliderFocused);
+    }
+}
+
+/// Nudge the focused slider with the arrow keys
+///
+/// Arrows raise or lower the value by one step (or 1% of the range without
+/// a step), the same as the +/- buttons. Which arrows raise it follows the
+/// slider's orientation and direction (see [`SliderConfig::arrow_direction`]).
+pub fn handle_slider_keyboard(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut sliders: Query<(&mut Slider, &SliderConfig), (With<SliderFocused>, Without<SliderDisplayOnly>)>,
+) {
+    let arrows = [KeyCode::ArrowRight, KeyCode::ArrowUp, KeyCode::ArrowLeft, KeyCode::ArrowDown];
+    let Some(key) = arrows.into_iter().find(|key| keyboard.just_pressed(*key)) else {
+        return;
+    };
+
+    for (mut slider, config) in &mut sliders {
+        let Some(direction) = config.arrow_direction(key) else {
+            continue;
+        };
+        let delta = slider.step.unwrap_or((slider.max - slider.min) / 100.0);
+        slider.step_by(direction * delta);
+    }
+}
+
+/// Handle pad dragging on both axes
+pub fn handle_pad_interaction(
+    mut pads: Query<(Entity, &Interaction, &mut Pad2D, &Children)>,
+    areas: Query<&RelativeCursorPosition, With<Pad2DArea>>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    mut dragged_pad: Local<Option<Entity>>,
+    mut changes: MessageWriter<Pad2DChanged>,
+) {
+    // If mouse was released, stop dragging
+    if !mouse_button.pressed(MouseButton::Left) {
+        *dragged_pad = None;
+    }
+
+    for (entity, interaction, mut pad, children) in &mut pads {
+        // Start dragging on press
+        if *interaction == Interaction::Pressed
+            && mouse_button.pressed(MouseButton::Left)
+            && dragged_pad.is_none()
+        {
+            *dragged_pad = Some(entity);
+        }
+
+        if *dragged_pad != Some(entity) {
+            continue;
+        }
+
+        // The handle travels within the inner area, so measure the cursor there
+        let fraction = children.iter()
+            .find_map(|child| areas.get(child).ok())
+            .and_then(cursor_fraction);
+
+        if let Some(fraction) = fraction {
+            // UI y grows downwards; pad y grows upwards
+            let value = Pad2D::new(fraction.x, 1.0 - fraction.y);
+            if pad.set_if_neq(value) {
+                changes.write(Pad2DChanged {
+                    entity,
+                    x: value.x,
+                    y: value.y,
+                });
+            }
+        }
+    }
+}
+
+/// Move pad handles and crosshairs when the value changes
+pub fn update_pad_visuals(
+    pads: Query<(Entity, &Pad2D), Changed<Pad2D>>,
+    children: Query<&Children>,
+    mut handles: Query<&mut Node, (With<Pad2DHandle>, Without<Pad2DCrosshair>)>,
+    mut crosshairs: Query<(&mut Node, &Pad2DCrosshair), Without<Pad2DHandle>>,
+) {
+    for (entity, pad) in &pads {
+        let left = Val::Percent(pad.x * 100.0);
+        let top = Val::Percent((1.0 - pad.y) * 100.0);
+
+        for descendant in children.iter_descendants(entity) {
+            if let Ok(mut handle_node) = handles.get_mut(descendant) {
+                handle_node.left = left;
+                handle_node.top = top;
+            }
+
+            if let Ok((mut line_node, crosshair)) = crosshairs.get_mut(descendant) {
+                if crosshair.horizontal {
+                    line_node.top = top;
+                } else {
+                    line_node.left = left;
+                }
+            }
+        }
+    }
+}
+
+/// Cursor position within a node as corner-based fractions, clamped to 0.0..=1.0
+fn cursor_fraction(cursor: &RelativeCursorPosition) -> Option<Vec2> {
+    // Bevy 0.17: Convert center-based [-0.5, 0.5] to corner-based [0.0, 1.0]
+    cursor.normalized
+        .map(|position| (position + Vec2::splat(0.5)).clamp(Vec2::ZERO, Vec2::ONE))
+}
+
 /// Update slider visuals when value changes
 pub fn update_slider_visuals(
     sliders: Query<(&Slider, &SliderConfig, &Children), Changed<Slider>>,
@@ -57,12 +256,12 @@ pub fn update_slider_visuals(
         for child in children.iter() {
             if let Ok(mut fill_node) = fills.get_mut(child) {
                 // Clean percentage - padding on container handles alignment
-                fill_node.width = Val::Percent(normalized_percent);
+                config.set_length(&mut fill_node, Val::Percent(normalized_percent));
             }
 
             if let Ok(mut handle_node) = handles.get_mut(child) {
                 // Handle position matches fill exactly - no clamping needed
-                handle_node.left = Val::Percent(normalized_percent);
+                config.place_along(&mut handle_node, normalized_percent);
             }
         }
 
@@ -74,24 +273,348 @@ pub fn update_slider_visuals(
     }
 }
 
+/// Move range slider handles, fill and value text when either value changes
+pub fn update_range_slider_visuals(
+    sliders: Query<(&RangeSlider, &SliderConfig, &Children), Changed<RangeSlider>>,
+    mut fills: Query<&mut Node, (With<SliderFill>, Without<RangeHandle>)>,
+    mut handles: Query<(&mut Node, &RangeHandle), Without<SliderFill>>,
+    mut value_texts: Query<&mut Text>,
+) {
+    for (slider, config, children) in &sliders {
+        let low = slider.normalized(slider.low) * 100.0;
+        let high = slider.normalized(slider.high) * 100.0;
+
+        for child in children.iter() {
+            if let Ok(mut fill_node) = fills.get_mut(child) {
+                config.place_along(&mut fill_node, low);
+                config.set_length(&mut fill_node, Val::Percent(high - low));
+            }
+
+            if let Ok((mut handle_node, handle)) = handles.get_mut(child) {
+                config.place_along(&mut handle_node, slider.normalized(slider.value(*handle)) * 100.0);
+            }
+        }
+
+        if let Some(value_text_entity) = slider.value_text_entity {
+            if let Ok(mut text) = value_texts.get_mut(value_text_entity) {
+                **text = slider.format(&config.value_format);
+            }
+        }
+    }
+}
+
+/// Rebuild each slider's [`AccessibleValue`] when its value or name changes
+pub fn update_accessible_values(
+    mut sliders: Query<
+        (&Slider, &SliderConfig, Option<&AccessibleName>, &mut AccessibleValue),
+        Or<(Changed<Slider>, Changed<AccessibleName>)>,
+    >,
+) {
+    for (slider, config, name, mut accessible) in &mut sliders {
+        let formatted = config.value_format.format(slider.value);
+        accessible.set_if_neq(AccessibleValue::describe(name.map(|name| name.0.as_str()), &formatted));
+    }
+}
+
 /// Handle clicks on slider increment/decrement buttons
 pub fn handle_slider_button_clicks(
     button_query: Query<(&Interaction, &SliderButtonAction), (Changed<Interaction>, With<Button>)>,
-    mut slider_query: Query<&mut Slider>,
+    mut slider_query: Query<&mut Slider, Without<SliderDisplayOnly>>,
 ) {
     for (interaction, action) in &button_query {
         if *interaction == Interaction::Pressed {
             if let Ok(mut slider) = slider_query.get_mut(action.slider_entity) {
-                let new_value = (slider.value + action.delta).clamp(slider.min, slider.max);
+                slider.step_by(action.delta);
+            }
+        }
+    }
+}
 
-                // Apply step if configured
-                if let Some(step) = slider.step {
-                    let steps = ((new_value - slider.min) / step).round();
-                    slider.value = slider.min + steps * step;
-                } else {
-                    slider.value = new_value;
-                }
+/// Send `SliderChanged` when a slider's value changes
+///
+/// Sliders with a [`SliderThrottle`] hold back changes while the mouse
+/// button is down, sending at most one per interval and the final value
+/// on release.
+pub fn emit_slider_changes(
+    time: Res<Time>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    mut sliders: Query<(Entity, &Slider, Option<&mut SliderThrottle>)>,
+    mut removed: RemovedComponents<Slider>,
+    mut reported: Local<HashMap<Entity, f32>>,
+    mut changes: MessageWriter<SliderChanged>,
+) {
+    for entity in removed.read() {
+        reported.remove(&entity);
+    }
+
+    let dragging = mouse_button.pressed(MouseButton::Left);
+
+    for (entity, slider, throttle) in &mut sliders {
+        // New sliders start from their initial value without a message
+        let last = *reported.entry(entity).or_insert(slider.value);
+        let changed = last != slider.value;
+
+        let send = match throttle {
+            Some(mut throttle) => throttle.tick(time.delta(), changed, dragging),
+            None => changed,
+        };
+        if send {
+            reported.insert(entity, slider.value);
+            changes.write(SliderChanged {
+                entity,
+                value: slider.value,
+            });
+        }
+    }
+}
+
+/// Send [`RangeSliderChanged`] when either handle of a range slider moves
+pub fn emit_range_slider_changes(
+    sliders: Query<(Entity, Ref<RangeSlider>)>,
+    mut changes: MessageWriter<RangeSliderChanged>,
+) {
+    for (entity, slider) in &sliders {
+        if slider.is_changed() && !slider.is_added() {
+            changes.write(RangeSliderChanged {
+                entity,
+                low: slider.low,
+                high: slider.high,
+            });
+        }
+    }
+}
+
+/// Keep sliders bound to resource `R` in sync in both directions.
+///
+/// When the slider is first spawned it takes the resource's value. After
+/// that, a slider change is written to the resource, and an external
+/// resource change moves the slider (clamped to its range). Values are only
+/// written when they differ, so the two sides never ping-pong.
+pub fn sync_slider_bindings<R: Resource>(
+    mut sliders: Query<(&mut Slider, &SliderBinding<R>)>,
+    resource: Option<ResMut<R>>,
+) {
+    let Some(mut resource) = resource else {
+        return;
+    };
+    let resource_changed = resource.is_changed();
+
+    for (mut slider, binding) in &mut sliders {
+        // Read without flagging the resource as changed
+        let bound = *binding.field(resource.bypass_change_detection());
+        if (bound - slider.value).abs() <= f32::EPSILON {
+            continue;
+        }
+
+        if slider.is_changed() && !slider.is_added() {
+            *binding.field(&mut resource) = slider.value;
+        } else if resource_changed || slider.is_added() {
+            slider.set_value(bound);
+        }
+    }
+}
+
+/// Find the slider track (the entity carrying [`Slider`]) inside a slider container
+#[cfg(feature = "number_input")]
+fn find_slider_track(
+    container: Entity,
+    children: &Query<&Children>,
+    is_slider: impl Fn(Entity) -> bool,
+) -> Option<Entity> {
+    children.get(container).ok()?.iter().find(|&child| is_slider(child))
+}
+
+/// Move paired sliders to the values typed into their number inputs
+///
+/// Values are clamped to the slider's range and snapped to its step; text
+/// that isn't a number yet is ignored.
+#[cfg(feature = "number_input")]
+pub fn apply_paired_input_values(
+    mut number_changes: MessageReader<NumberInputChangedEvent>,
+    pairs: Query<&SliderInputPair>,
+    children: Query<&Children>,
+    mut sliders: Query<&mut Slider>,
+) {
+    for event in number_changes.read() {
+        let Some(value) = event.value else {
+            continue;
+        };
+        let Some(pair) = pairs.iter().find(|pair| pair.input == event.entity) else {
+            continue;
+        };
+        let Some(track) = find_slider_track(pair.slider, &children, |entity| sliders.contains(entity)) else {
+            continue;
+        };
+
+        if let Ok(mut slider) = sliders.get_mut(track) {
+            let mut target = slider.clone();
+            target.set_value(value as f32);
+            if target.value != slider.value {
+                slider.value = target.value;
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// Show paired sliders' values in their number inputs
+///
+/// Inputs being edited are left alone, so typing isn't interrupted; once
+/// focus leaves, the input settles on the slider's (clamped, snapped) value.
+#[cfg(feature = "number_input")]
+pub fn sync_paired_inputs(
+    pairs: Query<&SliderInputPair>,
+    children: Query<&Children>,
+    sliders: Query<&Slider>,
+    mut inputs: Query<&mut TextBuffer>,
+) {
+    for pair in pairs.iter() {
+        let Some(slider) = find_slider_track(pair.slider, &children, |entity| sliders.contains(entity))
+            .and_then(|track| sliders.get(track).ok())
+        else {
+            continue;
+        };
+        let Ok(mut buffer) = inputs.get_mut(pair.input) else {
+            continue;
+        };
+        if buffer.is_focused {
+            continue;
+        }
+
+        let text = pair.format(slider.value);
+        if buffer.content != text {
+            buffer.cursor_pos = text.chars().count();
+            buffer.content = text;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::separator::Orientation;
+
+    fn slider_config(orientation: Orientation, inverted: bool) -> SliderConfig {
+        SliderConfig {
+            orientation,
+            inverted,
+            ..default()
+        }
+    }
+
+    fn press_arrow(config: SliderConfig, key: KeyCode) -> f32 {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .add_systems(Update, handle_slider_keyboard);
+
+        let mut slider = Slider::new(0.0, 10.0, 5.0);
+        slider.step = Some(1.0);
+        let entity = app.world_mut().spawn((slider, config, SliderFocused)).id();
+
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(key);
+        app.update();
+        app.world().get::<Slider>(entity).unwrap().value
+    }
+
+    fn drag_to(config: SliderConfig, cursor: Vec2) -> f32 {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<Time>()
+            .add_systems(Update, handle_slider_interaction);
+
+        let mut slider = Slider::new(0.0, 10.0, 5.0);
+        slider.step = Some(1.0);
+        let entity = app.world_mut().spawn((
+            slider,
+            config,
+            SliderTrack,
+            Interaction::Pressed,
+            // Center-based, as Bevy reports it
+            RelativeCursorPosition {
+                normalized: Some(cursor - Vec2::splat(0.5)),
+                ..default()
+            },
+        )).with_child(Node::default()).id();
+
+        app.world_mut().resource_mut::<ButtonInput<MouseButton>>().press(MouseButton::Left);
+        app.update();
+        app.world().get::<Slider>(entity).unwrap().value
+    }
+
+    #[test]
+    fn arrow_keys_follow_the_track_direction() {
+        let horizontal = slider_config(Orientation::Horizontal, false);
+        assert_eq!(press_arrow(horizontal.clone(), KeyCode::ArrowRight), 6.0);
+        assert_eq!(press_arrow(horizontal, KeyCode::ArrowLeft), 4.0);
+
+        let inverted = slider_config(Orientation::Horizontal, true);
+        assert_eq!(press_arrow(inverted.clone(), KeyCode::ArrowLeft), 6.0);
+        assert_eq!(press_arrow(inverted, KeyCode::ArrowRight), 4.0);
+
+        let vertical = slider_config(Orientation::Vertical, false);
+        assert_eq!(press_arrow(vertical.clone(), KeyCode::ArrowUp), 6.0);
+        assert_eq!(press_arrow(vertical, KeyCode::ArrowDown), 4.0);
+
+        let vertical_inverted = slider_config(Orientation::Vertical, true);
+        assert_eq!(press_arrow(vertical_inverted.clone(), KeyCode::ArrowDown), 6.0);
+        assert_eq!(press_arrow(vertical_inverted.clone(), KeyCode::ArrowUp), 4.0);
+        // Across the track Right still raises the value
+        assert_eq!(press_arrow(vertical_inverted, KeyCode::ArrowRight), 6.0);
+    }
+
+    #[test]
+    fn pressing_the_track_follows_the_track_direction() {
+        // Near the top of a vertical track
+        let top = Vec2::new(0.5, 0.1);
+        assert_eq!(drag_to(slider_config(Orientation::Vertical, false), top), 9.0);
+        assert_eq!(drag_to(slider_config(Orientation::Vertical, true), top), 1.0);
+
+        // Near the left end of a horizontal track
+        let left = Vec2::new(0.1, 0.5);
+        assert_eq!(drag_to(slider_config(Orientation::Horizontal, false), left), 1.0);
+        assert_eq!(drag_to(slider_config(Orientation::Horizontal, true), left), 9.0);
+    }
+
+    #[test]
+    fn clicking_the_track_jumps_to_the_value_under_the_cursor() {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<Time>()
+            .add_systems(Update, handle_slider_interaction);
+
+        let mut slider = Slider::new(0.0, 100.0, 50.0);
+        slider.step = Some(1.0);
+        let entity = app.world_mut().spawn((
+            slider,
+            SliderConfig::default(),
+            SliderTrack,
+            Interaction::Pressed,
+            RelativeCursorPosition {
+                normalized: Some(Vec2::new(0.3, 0.0)),
+                ..default()
+            },
+        )).with_child(Node::default()).id();
+        let value = |app: &App| app.world().get::<Slider>(entity).unwrap().value;
+        let move_cursor = |app: &mut App, x: f32| {
+            app.world_mut().get_mut::<RelativeCursorPosition>(entity).unwrap().normalized =
+                Some(Vec2::new(x - 0.5, 0.0));
+        };
+
+        // The click itself moves the value, far from the old one
+        app.world_mut().resource_mut::<ButtonInput<MouseButton>>().press(MouseButton::Left);
+        app.update();
+        assert_eq!(value(&app), 80.0);
+
+        // Holding the button keeps dragging from there
+        app.world_mut().resource_mut::<ButtonInput<MouseButton>>().clear();
+        move_cursor(&mut app, 0.25);
+        app.update();
+        assert_eq!(value(&app), 25.0);
+
+        // After release, moving the cursor leaves the value alone
+        app.world_mut().resource_mut::<ButtonInput<MouseButton>>().release(MouseButton::Left);
+        *app.world_mut().get_mut::<Interaction>(entity).unwrap() = Interaction::Hovered;
+        move_cursor(&mut app, 0.9);
+        app.update();
+        assert_eq!(value(&app), 25.0);
+    }
+}
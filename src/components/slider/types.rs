@@ -2,7 +2,9 @@
 
 use bevy::prelude::*;
 use bevy::color::Alpha;
+use bevy::ecs::query::QueryFilter;
 
+use crate::components::separator::Orientation;
 use crate::theme::{UiTheme, SemanticVariant};
 
 /// Main slider component with configuration and state
@@ -12,6 +14,12 @@ pub struct Slider {
     pub min: f32,
     pub max: f32,
     pub step: Option<f32>,
+    /// Ranges the value can never be inside.
+    ///
+    /// Dragging stops at the near edge of a locked region, while stepping
+    /// (the +/- buttons and arrow keys) skips over it to the far edge. Values
+    /// set directly move to the nearest edge.
+    pub locked: Vec<std::ops::Range<f32>>,
     /// Entity ID of the associated value text display (if any)
     pub value_text_entity: Option<Entity>,
 }
@@ -23,6 +31,7 @@ impl Slider {
             min,
             max,
             step: None,
+            locked: Vec::new(),
             value_text_entity: None,
         }
     }
@@ -36,17 +45,328 @@ impl Slider {
     }
 
     /// Set value from normalized (0.0 to 1.0)
+    ///
+    /// Like a drag, the move stops at the edge of any locked region in the way.
     pub fn set_normalized(&mut self, normalized: f32) {
         let normalized = normalized.clamp(0.0, 1.0);
-        self.value = self.min + (self.max - self.min) * normalized;
+        let target = self.snap(self.min + (self.max - self.min) * normalized);
+        self.value = self.stop_at_locked(target);
+    }
+
+    /// Set the value directly, clamped to the range, snapped to the step
+    /// and kept out of locked regions
+    pub fn set_value(&mut self, value: f32) {
+        self.value = self.snap(value);
+    }
+
+    /// Clamp `value` to the range, round it to the nearest step and move it
+    /// out of any locked region it lands in (to the nearest edge).
+    ///
+    /// The stops are `min`, `min + step`, `min + 2 * step`, ... and always
+    /// `max`, even when the range isn't a whole number of steps.
+    pub fn snap(&self, value: f32) -> f32 {
+        let value = value.clamp(self.min, self.max);
+        let Some(step) = self.positive_step() else {
+            return self.nearest_unlocked(value);
+        };
+
+        let snapped = (self.min + ((value - self.min) / step).round() * step).min(self.max);
+        let snapped = if self.max - value < (value - snapped).abs() {
+            self.max
+        } else {
+            snapped
+        };
+        self.nearest_unlocked(snapped)
+    }
+
+    /// Every value the slider can snap to, from `min` to `max` (empty
+    /// without a step)
+    pub fn stops(&self) -> Vec<f32> {
+        let Some(step) = self.positive_step() else {
+            return Vec::new();
+        };
+        let last = self.last_stop(step);
+        (0..=last)
+            .map(|index| if index == last { self.max } else { self.min + index as f32 * step })
+            .collect()
+    }
+
+    /// Move the value by `delta`, snapping to the step and skipping over
+    /// locked regions
+    ///
+    /// With a step, the value moves by whole stops (at least one), so an
+    /// off-grid `max` is reached on the way up and left on the way down.
+    pub fn step_by(&mut self, delta: f32) {
+        let mut value = match self.positive_step() {
+            Some(step) => {
+                let last = self.last_stop(step);
+                let current = if self.value >= self.max {
+                    last
+                } else {
+                    ((self.value - self.min) / step).round() as i64
+                };
+                let moves = (delta / step).round() as i64;
+                let moves = if moves == 0 { delta.signum() as i64 } else { moves };
+
+                let index = (current + moves).clamp(0, last);
+                if index == last { self.max } else { self.min + index as f32 * step }
+            }
+            None => (self.value + delta).clamp(self.min, self.max),
+        };
+
+        // Skip to the far side, across touching regions too
+        while let Some(region) = self.locked_region_at(value) {
+            value = if delta > 0.0 { region.end } else { region.start };
+        }
+
+        self.value = self.nearest_unlocked(value.clamp(self.min, self.max));
+    }
+
+    /// The step, if it is usable (positive and finite)
+    fn positive_step(&self) -> Option<f32> {
+        self.step.filter(|step| *step > 0.0 && step.is_finite())
+    }
+
+    /// Index of the stop at `max`
+    fn last_stop(&self, step: f32) -> i64 {
+        // Tolerate float error so an exact multiple doesn't gain an extra stop
+        (((self.max - self.min) / step) - 1e-4).ceil().max(0.0) as i64
+    }
+
+    /// Whether `value` lies strictly inside a locked region (edges are free)
+    pub fn is_locked(&self, value: f32) -> bool {
+        self.locked_region_at(value).is_some()
+    }
+
+    /// The locked region `value` lies strictly inside, if any
+    fn locked_region_at(&self, value: f32) -> Option<std::ops::Range<f32>> {
+        self.locked.iter()
+            .find(|region| value > region.start && value < region.end)
+            .cloned()
+    }
+
+    /// `value`, or the nearest edge of the locked region it lies in
+    ///
+    /// Edges outside the range are skipped, so a region covering `min` or
+    /// `max` sends values to its other edge.
+    fn nearest_unlocked(&self, value: f32) -> f32 {
+        let Some(region) = self.locked_region_at(value) else {
+            return value;
+        };
+        let start_usable = region.start >= self.min;
+        let end_usable = region.end <= self.max;
+        match (start_usable, end_usable) {
+            (true, true) if value - region.start <= region.end - value => region.start,
+            (true, true) => region.end,
+            (true, false) => region.start,
+            (false, true) => region.end,
+            (false, false) => value,
+        }
+    }
+
+    /// Clamp a move from the current value to `target` at the first locked
+    /// region in the way
+    fn stop_at_locked(&self, target: f32) -> f32 {
+        let from = self.value;
+        self.locked.iter().fold(target, |target, region| {
+            if target > from && region.start >= from && region.start < target {
+                region.start
+            } else if target < from && region.end <= from && region.end > target {
+                region.end
+            } else {
+                target
+            }
+        })
+    }
+}
+
+/// Which handle of a [`RangeSlider`] something refers to
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RangeHandle {
+    /// The handle setting the lower bound
+    Low,
+    /// The handle setting the upper bound
+    High,
+}
+
+/// Two-handle slider selecting a `low..=high` span of its range, such as a
+/// price filter
+///
+/// The handles never cross: moving one past the other stops it where the
+/// other handle is.
+#[derive(Component, Clone, Debug)]
+pub struct RangeSlider {
+    pub low: f32,
+    pub high: f32,
+    pub min: f32,
+    pub max: f32,
+    pub step: Option<f32>,
+    /// Entity ID of the associated value text display (if any)
+    pub value_text_entity: Option<Entity>,
+}
+
+impl RangeSlider {
+    pub fn new(min: f32, max: f32, low: f32, high: f32) -> Self {
+        let low = low.clamp(min, max);
+        Self {
+            low,
+            high: high.clamp(low, max),
+            min,
+            max,
+            step: None,
+            value_text_entity: None,
+        }
+    }
+
+    /// Value of one of the handles
+    pub fn value(&self, handle: RangeHandle) -> f32 {
+        match handle {
+            RangeHandle::Low => self.low,
+            RangeHandle::High => self.high,
+        }
+    }
+
+    /// Move one handle to `value`, snapped to the step and stopped at the
+    /// other handle
+    pub fn set_value(&mut self, handle: RangeHandle, value: f32) {
+        let value = self.snap(value);
+        match handle {
+            RangeHandle::Low => self.low = value.min(self.high),
+            RangeHandle::High => self.high = value.max(self.low),
+        }
+    }
+
+    /// Move one handle to a 0.0 to 1.0 position along the range
+    pub fn set_normalized(&mut self, handle: RangeHandle, normalized: f32) {
+        let value = self.min + (self.max - self.min) * normalized.clamp(0.0, 1.0);
+        self.set_value(handle, value);
+    }
 
-        // Apply step if configured
-        if let Some(step) = self.step {
-            let steps = ((self.value - self.min) / step).round();
-            self.value = self.min + steps * step;
+    /// Position of `value` along the range (0.0 to 1.0)
+    pub fn normalized(&self, value: f32) -> f32 {
+        if self.max == self.min {
+            return 0.0;
+        }
+        ((value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+    }
+
+    /// The handle a press at `value` should grab, or `None` while both
+    /// handles sit on `value` and either could move
+    pub fn handle_near(&self, value: f32) -> Option<RangeHandle> {
+        if value < self.low {
+            Some(RangeHandle::Low)
+        } else if value > self.high {
+            Some(RangeHandle::High)
+        } else if self.low == self.high {
+            None
+        } else if value - self.low <= self.high - value {
+            Some(RangeHandle::Low)
+        } else {
+            Some(RangeHandle::High)
+        }
+    }
+
+    /// Both values formatted for display, e.g. "20 - 80"
+    pub fn format(&self, format: &ValueFormat) -> String {
+        format!("{} - {}", format.format(self.low), format.format(self.high))
+    }
+
+    /// Clamp `value` to the range and round it to the nearest step (the
+    /// same stops as a [`Slider`] with this range and step)
+    pub fn snap(&self, value: f32) -> f32 {
+        let mut slider = Slider::new(self.min, self.max, self.min);
+        slider.step = self.step;
+        slider.snap(value)
+    }
+}
+
+/// Typed reference to a built slider, for reading and updating it from game logic
+///
+/// Returned by [`SliderBuilder::build_ref`](super::SliderBuilder::build_ref), or
+/// wrap any slider entity with `SliderRef(entity)`.
+///
+/// # Example
+/// ```ignore
+/// #[derive(Resource)]
+/// struct VolumeSlider(SliderRef);
+///
+/// fn reset_volume(mut commands: Commands, volume: Res<VolumeSlider>) {
+///     volume.0.set_value(&mut commands, 0.5);
+/// }
+///
+/// fn read_volume(volume: Res<VolumeSlider>, sliders: Query<&Slider>) {
+///     let value = volume.0.get_value(&sliders);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SliderRef(pub Entity);
+
+impl SliderRef {
+    /// The slider entity
+    pub fn entity(&self) -> Entity {
+        self.0
+    }
+
+    /// Set the slider's value (clamped and snapped to its step) once commands apply
+    pub fn set_value(&self, commands: &mut Commands, value: f32) {
+        self.modify(commands, move |slider| slider.set_value(value));
+    }
+
+    /// Set the slider's value from a 0.0 to 1.0 position along its range
+    pub fn set_normalized(&self, commands: &mut Commands, normalized: f32) {
+        self.modify(commands, move |slider| {
+            let value = slider.min + (slider.max - slider.min) * normalized.clamp(0.0, 1.0);
+            slider.set_value(value);
+        });
+    }
+
+    /// Read the slider's current value (`None` if the slider no longer exists)
+    pub fn get_value<F: QueryFilter>(&self, sliders: &Query<&Slider, F>) -> Option<f32> {
+        sliders.get(self.0).ok().map(|slider| slider.value)
+    }
+
+    /// Read the slider's current 0.0 to 1.0 position along its range
+    pub fn get_normalized<F: QueryFilter>(&self, sliders: &Query<&Slider, F>) -> Option<f32> {
+        sliders.get(self.0).ok().map(Slider::normalized)
+    }
+
+    /// Queue a change to the slider component (ignored if the slider was despawned)
+    fn modify(&self, commands: &mut Commands, change: impl FnOnce(&mut Slider) + Send + 'static) {
+        if let Ok(mut entity) = commands.get_entity(self.0) {
+            entity.queue(move |mut entity: EntityWorldMut| {
+                if let Some(mut slider) = entity.get_mut::<Slider>() {
+                    change(&mut slider);
+                }
+            });
         }
+    }
+}
 
-        self.value = self.value.clamp(self.min, self.max);
+impl From<SliderRef> for Entity {
+    fn from(slider: SliderRef) -> Self {
+        slider.0
+    }
+}
+
+/// Two-way binding between a slider and an `f32` field of resource `R`.
+///
+/// Added by [`SliderBuilder::bind`](super::SliderBuilder::bind) and kept in
+/// sync by [`SliderBindingPlugin`](super::SliderBindingPlugin). Moving the
+/// slider writes the field; changing the resource moves the slider.
+#[derive(Component)]
+pub struct SliderBinding<R: Resource> {
+    accessor: fn(&mut R) -> &mut f32,
+}
+
+impl<R: Resource> SliderBinding<R> {
+    /// Create a binding from a field accessor, e.g. `|settings| &mut settings.volume`
+    pub fn new(accessor: fn(&mut R) -> &mut f32) -> Self {
+        Self { accessor }
+    }
+
+    /// Get a mutable reference to the bound field
+    pub fn field<'a>(&self, resource: &'a mut R) -> &'a mut f32 {
+        (self.accessor)(resource)
     }
 }
 
@@ -65,6 +385,44 @@ pub struct SliderButtonAction {
 #[derive(Component)]
 pub struct SliderTrack;
 
+/// Track segment drawn over a locked range of the slider
+#[derive(Component)]
+pub struct SliderLockedRegion;
+
+/// Recommended value a slider resets to when its track is double-clicked
+///
+/// Added to the track by [`SliderBuilder::default_marker`](super::SliderBuilder::default_marker).
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct SliderDefault {
+    /// The value to reset to
+    pub value: f32,
+}
+
+/// Tick drawn on the track at a slider's [`SliderDefault`] value
+#[derive(Component)]
+pub struct SliderDefaultMarker;
+
+/// Notch drawn on the track at each step, added by
+/// [`SliderBuilder::show_ticks`](super::SliderBuilder::show_ticks)
+#[derive(Component)]
+pub struct SliderTick;
+
+/// Marker for the slider track that currently has keyboard focus
+///
+/// Pressing a track focuses it; the arrow keys then nudge its value by one
+/// step. Clicking anywhere else drops focus.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SliderFocused;
+
+/// Marker for sliders that only display their value, like a meter
+///
+/// Added by [`SliderBuilder::display_only`](super::SliderBuilder::display_only).
+/// Dragging, clicking, the arrow keys and the +/- buttons are all ignored,
+/// but the value can still be changed from code (for example through a
+/// [`SliderRef`]) and the slider redraws as usual.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SliderDisplayOnly;
+
 /// Component for the filled portion of the slider
 #[derive(Component)]
 pub struct SliderFill;
@@ -77,6 +435,33 @@ pub struct SliderValueText;
 #[derive(Component)]
 pub struct SliderLabel;
 
+/// Text a screen reader announces for a slider's current value, e.g.
+/// "Volume, 60 percent"
+///
+/// Every slider gets one, rebuilt from its
+/// [`AccessibleName`](crate::accessibility::AccessibleName) and formatted
+/// value whenever either changes, so a screen-reader bridge can watch it with
+/// `Changed<AccessibleValue>`.
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq)]
+pub struct AccessibleValue(pub String);
+
+impl AccessibleValue {
+    /// Spoken form of a formatted value, prefixed by the name if there is one
+    ///
+    /// A trailing `%` is read out as "percent".
+    pub fn describe(name: Option<&str>, formatted: &str) -> Self {
+        let value = match formatted.strip_suffix('%') {
+            Some(number) => format!("{} percent", number),
+            None => formatted.to_string(),
+        };
+
+        Self(match name {
+            Some(name) => format!("{}, {}", name, value),
+            None => value,
+        })
+    }
+}
+
 /// Configuration for how the value is displayed
 #[derive(Clone, Debug)]
 pub enum ValueFormat {
@@ -102,6 +487,154 @@ impl ValueFormat {
     }
 }
 
+/// Two-axis control surface (XY pad)
+///
+/// Both axes are normalized: `x` runs 0.0 (left) to 1.0 (right) and `y`
+/// runs 0.0 (bottom) to 1.0 (top).
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct Pad2D {
+    /// Horizontal position (0.0 = left, 1.0 = right)
+    pub x: f32,
+    /// Vertical position (0.0 = bottom, 1.0 = top)
+    pub y: f32,
+}
+
+impl Pad2D {
+    /// Create a pad position, clamping both axes to 0.0..=1.0
+    pub fn new(x: f32, y: f32) -> Self {
+        Self {
+            x: x.clamp(0.0, 1.0),
+            y: y.clamp(0.0, 1.0),
+        }
+    }
+}
+
+/// Inner area of a pad that the handle moves within
+#[derive(Component)]
+pub struct Pad2DArea;
+
+/// Marker for the draggable handle of a pad
+#[derive(Component)]
+pub struct Pad2DHandle;
+
+/// Crosshair line through the pad handle
+#[derive(Component)]
+pub struct Pad2DCrosshair {
+    /// True for the line that follows `y` (spans the pad horizontally)
+    pub horizontal: bool,
+}
+
+/// Message emitted when a slider's value changes
+///
+/// Sliders with a [`SliderThrottle`] send it at most once per interval while
+/// dragged, plus a final message with the settled value.
+#[derive(Message, Clone, Copy, Debug)]
+pub struct SliderChanged {
+    /// The slider entity (carries the [`Slider`] component)
+    pub entity: Entity,
+    /// The new value
+    pub value: f32,
+}
+
+/// Message emitted when either handle of a [`RangeSlider`] moves
+#[derive(Message, Clone, Copy, Debug)]
+pub struct RangeSliderChanged {
+    /// The range slider entity (carries the [`RangeSlider`] component)
+    pub entity: Entity,
+    /// The new lower bound
+    pub low: f32,
+    /// The new upper bound
+    pub high: f32,
+}
+
+/// Rate limit for a slider's [`SliderChanged`] messages
+///
+/// Added by [`SliderBuilder::on_change_throttle`](super::SliderBuilder::on_change_throttle).
+#[derive(Component, Clone, Debug)]
+pub struct SliderThrottle {
+    /// Minimum time between messages while dragging
+    pub interval: std::time::Duration,
+    /// Time since the last message
+    pub(crate) elapsed: std::time::Duration,
+    /// A change is waiting to be sent
+    pub(crate) pending: bool,
+}
+
+impl SliderThrottle {
+    /// Send at most one message per `interval` while dragging
+    pub fn new(interval: std::time::Duration) -> Self {
+        Self {
+            interval,
+            // The first change goes out immediately
+            elapsed: interval,
+            pending: false,
+        }
+    }
+
+    /// Advance the timer and decide whether a message should be sent now
+    ///
+    /// Pending changes are flushed once the interval has passed, or right
+    /// away when the drag has ended.
+    pub(crate) fn tick(&mut self, delta: std::time::Duration, changed: bool, dragging: bool) -> bool {
+        self.elapsed = self.elapsed.saturating_add(delta);
+        self.pending |= changed;
+
+        if self.pending && (self.elapsed >= self.interval || !dragging) {
+            self.elapsed = std::time::Duration::ZERO;
+            self.pending = false;
+            return true;
+        }
+        false
+    }
+}
+
+/// Keeps a slider and a number input showing the same value
+///
+/// Added to the row spawned by
+/// [`SliderWithInputBuilder`](super::SliderWithInputBuilder). Moving the
+/// slider rewrites the input; typing a number moves the slider.
+#[cfg(feature = "number_input")]
+#[derive(Component, Clone, Copy, Debug)]
+pub struct SliderInputPair {
+    /// The slider container (as returned by `SliderBuilder::build`)
+    pub slider: Entity,
+    /// The number input
+    pub input: Entity,
+    /// Decimal places shown in the input
+    pub decimals: usize,
+}
+
+#[cfg(feature = "number_input")]
+impl SliderInputPair {
+    /// Format a slider value for the input
+    pub fn format(&self, value: f32) -> String {
+        format!("{:.*}", self.decimals, value)
+    }
+}
+
+/// Decimal places needed to show every multiple of `step` exactly
+/// (at most 6)
+#[cfg(feature = "number_input")]
+pub(crate) fn step_decimals(step: f32) -> usize {
+    (0..6)
+        .find(|&decimals| {
+            let scaled = step * 10f32.powi(decimals as i32);
+            (scaled - scaled.round()).abs() < 1e-3
+        })
+        .unwrap_or(6)
+}
+
+/// Message emitted while the user drags a pad handle
+#[derive(Message, Clone, Copy, Debug)]
+pub struct Pad2DChanged {
+    /// The pad entity (carries the [`Pad2D`] component)
+    pub entity: Entity,
+    /// Normalized horizontal position (0.0 = left, 1.0 = right)
+    pub x: f32,
+    /// Normalized vertical position (0.0 = bottom, 1.0 = top)
+    pub y: f32,
+}
+
 /// Configuration for slider appearance
 #[derive(Component, Clone, Debug)]
 pub struct SliderConfig {
@@ -112,6 +645,87 @@ pub struct SliderConfig {
     pub track_color: Color,
     pub fill_color: Color,
     pub handle_color: Color,
+    /// Direction the track runs in
+    pub orientation: Orientation,
+    /// Whether the value grows leftwards (horizontal) or downwards (vertical)
+    pub inverted: bool,
+}
+
+impl SliderConfig {
+    /// Normalized value (0.0..=1.0) under a cursor at `cursor`, given as
+    /// corner-based fractions of the track
+    pub fn track_fraction(&self, cursor: Vec2) -> f32 {
+        let fraction = match self.orientation {
+            Orientation::Horizontal => cursor.x,
+            // UI y grows downwards; vertical sliders grow upwards
+            Orientation::Vertical => 1.0 - cursor.y,
+        };
+        if self.inverted { 1.0 - fraction } else { fraction }
+    }
+
+    /// Value change (1.0 or -1.0) asked for by an arrow key, if any
+    ///
+    /// Arrows along the track move the handle the way they point, so Left
+    /// (or Down, when vertical) raises an inverted slider. Arrows across the
+    /// track keep Up and Right raising the value.
+    pub fn arrow_direction(&self, key: KeyCode) -> Option<f32> {
+        let (along, across) = match self.orientation {
+            Orientation::Horizontal => ((KeyCode::ArrowRight, KeyCode::ArrowLeft), (KeyCode::ArrowUp, KeyCode::ArrowDown)),
+            Orientation::Vertical => ((KeyCode::ArrowUp, KeyCode::ArrowDown), (KeyCode::ArrowRight, KeyCode::ArrowLeft)),
+        };
+        let (raise, lower) = if self.inverted { (along.1, along.0) } else { along };
+
+        if key == raise || key == across.0 {
+            Some(1.0)
+        } else if key == lower || key == across.1 {
+            Some(-1.0)
+        } else {
+            None
+        }
+    }
+
+    /// Place `node` `percent` of the way along the track from the minimum end
+    pub(crate) fn place_along(&self, node: &mut Node, percent: f32) {
+        let offset = Val::Percent(percent);
+        match (self.orientation, self.inverted) {
+            (Orientation::Horizontal, false) => node.left = offset,
+            (Orientation::Horizontal, true) => node.right = offset,
+            (Orientation::Vertical, false) => node.bottom = offset,
+            (Orientation::Vertical, true) => node.top = offset,
+        }
+    }
+
+    /// Set how far `node` extends along the track
+    pub(crate) fn set_length(&self, node: &mut Node, length: Val) {
+        match self.orientation {
+            Orientation::Horizontal => node.width = length,
+            Orientation::Vertical => node.height = length,
+        }
+    }
+
+    /// A node `length` along the track and `thickness` across it
+    pub(crate) fn track_node(&self, length: Val, thickness: Val) -> Node {
+        let (width, height) = match self.orientation {
+            Orientation::Horizontal => (length, thickness),
+            Orientation::Vertical => (thickness, length),
+        };
+        Node {
+            width,
+            height,
+            position_type: PositionType::Absolute,
+            ..default()
+        }
+    }
+
+    /// Margin of `px` on the track's minimum end
+    pub(crate) fn start_margin(&self, px: f32) -> UiRect {
+        match (self.orientation, self.inverted) {
+            (Orientation::Horizontal, false) => UiRect::left(Val::Px(px)),
+            (Orientation::Horizontal, true) => UiRect::right(Val::Px(px)),
+            (Orientation::Vertical, false) => UiRect::bottom(Val::Px(px)),
+            (Orientation::Vertical, true) => UiRect::top(Val::Px(px)),
+        }
+    }
 }
 
 // Default colors (dark theme) for when no theme is provided
@@ -123,6 +737,7 @@ pub(crate) mod defaults {
     pub const PRIMARY: Color = Color::srgb(0.25, 0.46, 0.86);
     pub fn fill_color() -> Color { PRIMARY.with_alpha(0.3) }
     pub const HANDLE_COLOR: Color = PRIMARY;
+    pub fn locked_color() -> Color { Color::srgb(0.86, 0.25, 0.25).with_alpha(0.35) }
     pub const HANDLE_HOVER: Color = Color::srgb(0.35, 0.56, 0.96);
     pub const TEXT_PRIMARY: Color = Color::srgb(0.95, 0.95, 0.95);
     pub const TEXT_SECONDARY: Color = Color::srgb(0.7, 0.7, 0.7);
@@ -140,6 +755,8 @@ impl Default for SliderConfig {
             track_color: defaults::TRACK_COLOR,
             fill_color: defaults::fill_color(),
             handle_color: defaults::HANDLE_COLOR,
+            orientation: Orientation::Horizontal,
+            inverted: false,
         }
     }
 }
@@ -153,6 +770,8 @@ pub struct SliderColors {
     pub handle_hover: Color,
     pub handle_border: Color,
     pub handle_border_hover: Color,
+    pub locked: Color,
+    pub marker: Color,
     pub text_label: Color,
     pub text_value: Color,
 }
@@ -184,6 +803,8 @@ impl SliderColors {
             handle_hover: hover_color,
             handle_border: theme.colors.border.light,
             handle_border_hover: theme.colors.border.focus,
+            locked: theme.colors.danger.base.with_alpha(0.35),
+            marker: theme.colors.text.secondary,
             text_label: theme.colors.text.secondary,
             text_value: theme.colors.text.primary,
         }
@@ -206,8 +827,242 @@ impl SliderColors {
             handle_hover: hover_color,
             handle_border: defaults::BORDER_LIGHT,
             handle_border_hover: base_color,
+            locked: defaults::locked_color(),
+            marker: defaults::TEXT_SECONDARY,
             text_label: defaults::TEXT_SECONDARY,
             text_value: defaults::TEXT_PRIMARY,
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "number_input")]
+    #[test]
+    fn step_decimals_match_step_precision() {
+        assert_eq!(step_decimals(1.0), 0);
+        assert_eq!(step_decimals(5.0), 0);
+        assert_eq!(step_decimals(0.5), 1);
+        assert_eq!(step_decimals(0.1), 1);
+        assert_eq!(step_decimals(0.25), 2);
+        assert_eq!(step_decimals(0.001), 3);
+    }
+
+    #[test]
+    fn accessible_value_reads_percent_aloud() {
+        assert_eq!(AccessibleValue::describe(Some("Volume"), "60%").0, "Volume, 60 percent");
+        assert_eq!(AccessibleValue::describe(None, "0.5").0, "0.5");
+    }
+
+    #[test]
+    fn click_snaps_to_nearest_step() {
+        let mut slider = Slider::new(0.0, 10.0, 0.0);
+        slider.step = Some(2.0);
+
+        // 75% of the track is 7.5, nearest step is 8
+        slider.set_normalized(0.75);
+        assert_eq!(slider.value, 8.0);
+
+        slider.set_normalized(0.3);
+        assert_eq!(slider.value, 4.0);
+    }
+
+    #[test]
+    fn click_without_step_is_continuous() {
+        let mut slider = Slider::new(0.0, 100.0, 50.0);
+        slider.set_normalized(0.75);
+        assert_eq!(slider.value, 75.0);
+    }
+
+    #[test]
+    fn click_stays_in_range() {
+        let mut slider = Slider::new(0.0, 10.0, 0.0);
+        slider.step = Some(4.0);
+
+        // Nearest step (12) lies beyond max
+        slider.set_normalized(1.0);
+        assert_eq!(slider.value, 10.0);
+    }
+
+    #[test]
+    fn set_value_clamps_and_snaps() {
+        let mut slider = Slider::new(0.0, 1.0, 0.0);
+        slider.step = Some(0.25);
+
+        slider.set_value(0.4);
+        assert_eq!(slider.value, 0.5);
+
+        slider.set_value(3.0);
+        assert_eq!(slider.value, 1.0);
+    }
+
+    #[test]
+    fn drag_stops_at_locked_region() {
+        let mut slider = Slider::new(0.0, 100.0, 20.0);
+        slider.locked.push(60.0..80.0);
+
+        // Dragging into (or past) the region stops at its near edge
+        slider.set_normalized(0.7);
+        assert_eq!(slider.value, 60.0);
+        slider.set_normalized(0.9);
+        assert_eq!(slider.value, 60.0);
+
+        // Coming from above stops at the far edge
+        slider.value = 95.0;
+        slider.set_normalized(0.1);
+        assert_eq!(slider.value, 80.0);
+    }
+
+    #[test]
+    fn set_value_moves_out_of_locked_region() {
+        let mut slider = Slider::new(0.0, 100.0, 20.0);
+        slider.locked.push(60.0..80.0);
+
+        // Programmatic values go to the nearest edge
+        slider.set_value(65.0);
+        assert_eq!(slider.value, 60.0);
+        slider.set_value(75.0);
+        assert_eq!(slider.value, 80.0);
+
+        // Values past the region are untouched
+        slider.set_value(90.0);
+        assert_eq!(slider.value, 90.0);
+        assert!(!slider.is_locked(slider.snap(70.0)));
+    }
+
+    #[test]
+    fn stepped_values_never_land_in_locked_region() {
+        let mut slider = Slider::new(0.0, 100.0, 50.0);
+        slider.step = Some(10.0);
+        slider.locked.push(55.0..65.0);
+        slider.locked.push(65.0..72.0);
+
+        // Snapping to 60 would be locked; 55 is the nearer edge
+        slider.set_value(58.0);
+        assert_eq!(slider.value, 55.0);
+
+        // Stepping up from 55 lands on 70, inside the second region
+        slider.step_by(10.0);
+        assert_eq!(slider.value, 72.0);
+        assert!(!slider.is_locked(slider.value));
+    }
+
+    #[test]
+    fn step_skips_over_locked_region() {
+        let mut slider = Slider::new(0.0, 100.0, 60.0);
+        slider.step = Some(5.0);
+        slider.locked.push(60.0..80.0);
+
+        slider.step_by(5.0);
+        assert_eq!(slider.value, 80.0);
+
+        slider.step_by(-5.0);
+        assert_eq!(slider.value, 60.0);
+        assert!(!slider.is_locked(slider.value));
+    }
+
+    #[test]
+    fn throttle_limits_drag_updates_and_flushes_on_release() {
+        use std::time::Duration;
+
+        let frame = Duration::from_millis(16);
+        let mut throttle = SliderThrottle::new(Duration::from_millis(100));
+
+        // Leading change is sent, the next few frames are held back
+        assert!(throttle.tick(frame, true, true));
+        assert!(!throttle.tick(frame, true, true));
+        assert!(!throttle.tick(frame, true, true));
+
+        // Releasing sends the held-back value
+        assert!(throttle.tick(frame, false, false));
+        assert!(!throttle.tick(frame, false, false));
+    }
+
+    #[test]
+    fn dragging_vertical_handle_to_top_yields_max() {
+        let config = SliderConfig {
+            orientation: Orientation::Vertical,
+            ..default()
+        };
+        let mut slider = Slider::new(0.0, 10.0, 5.0);
+
+        // The top of the track is y = 0 in UI space
+        slider.set_normalized(config.track_fraction(Vec2::new(0.5, 0.0)));
+        assert_eq!(slider.value, 10.0);
+
+        slider.set_normalized(config.track_fraction(Vec2::new(0.5, 1.0)));
+        assert_eq!(slider.value, 0.0);
+
+        // Inverted sliders grow downwards instead
+        let inverted = SliderConfig { inverted: true, ..config };
+        slider.set_normalized(inverted.track_fraction(Vec2::new(0.5, 0.0)));
+        assert_eq!(slider.value, 0.0);
+    }
+
+    #[test]
+    fn arrow_steps_move_whole_stops() {
+        let mut slider = Slider::new(0.0, 1.0, 0.5);
+        slider.step = Some(0.25);
+
+        // A nudge smaller than the step still moves one stop
+        slider.step_by(0.01);
+        assert_eq!(slider.value, 0.75);
+
+        slider.step_by(-0.5);
+        assert_eq!(slider.value, 0.25);
+    }
+
+    #[test]
+    fn max_is_reachable_when_range_isnt_a_multiple_of_step() {
+        let mut slider = Slider::new(0.0, 10.0, 0.0);
+        slider.step = Some(3.0);
+        assert_eq!(slider.stops(), vec![0.0, 3.0, 6.0, 9.0, 10.0]);
+
+        // Dragging to the end of the track lands on max, not the last step
+        slider.set_normalized(1.0);
+        assert_eq!(slider.value, 10.0);
+        slider.set_normalized(0.0);
+        assert_eq!(slider.value, 0.0);
+
+        // Arrow keys and buttons walk every stop, max included
+        slider.set_value(9.0);
+        slider.step_by(3.0);
+        assert_eq!(slider.value, 10.0);
+        slider.step_by(3.0);
+        assert_eq!(slider.value, 10.0);
+        slider.step_by(-3.0);
+        assert_eq!(slider.value, 9.0);
+    }
+
+    #[test]
+    fn range_handles_stop_at_each_other() {
+        let mut range = RangeSlider::new(0.0, 100.0, 20.0, 80.0);
+
+        // Dragging the low handle past the high one stops at the crossover
+        range.set_value(RangeHandle::Low, 95.0);
+        assert_eq!((range.low, range.high), (80.0, 80.0));
+
+        // ...and the high handle can't be pulled below the low one
+        range.set_normalized(RangeHandle::High, 0.1);
+        assert_eq!((range.low, range.high), (80.0, 80.0));
+
+        // Once they meet, a press decides nothing until the drag picks a side
+        assert_eq!(range.handle_near(80.0), None);
+        assert_eq!(range.handle_near(30.0), Some(RangeHandle::Low));
+        assert_eq!(range.handle_near(90.0), Some(RangeHandle::High));
+    }
+
+    #[test]
+    fn range_handles_snap_to_step() {
+        let mut range = RangeSlider::new(0.0, 10.0, 0.0, 10.0);
+        range.step = Some(3.0);
+
+        range.set_value(RangeHandle::Low, 4.0);
+        range.set_value(RangeHandle::High, 9.7);
+        assert_eq!((range.low, range.high), (3.0, 10.0));
+        assert_eq!(range.handle_near(5.0), Some(RangeHandle::Low));
+        assert_eq!(range.format(&ValueFormat::Integer), "3 - 10");
+    }
+}
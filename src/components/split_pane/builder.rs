@@ -0,0 +1,331 @@
+//! SplitPaneBuilder implementation
+
+use bevy::prelude::*;
+use crate::components::separator::{Orientation, SeparatorBuilder, SeparatorStyle};
+use crate::theme::UiTheme;
+use crate::traits::{UiBuilder, LayoutBuilder, BuilderBase};
+use super::types::*;
+
+/// Resolved divider colors (either from theme or defaults)
+struct DividerColors {
+    line: Color,
+    hover: Color,
+}
+
+/// Builder for two panes separated by a draggable divider
+///
+/// The divider is drawn as a [`SeparatorBuilder`] line inside a wider grab
+/// area; dragging it resizes the panes within their min/max constraints and
+/// emits a [`SplitResizedEvent`].
+///
+/// # Example
+/// ```ignore
+/// SplitPaneBuilder::new(Orientation::Horizontal)
+///     .ratio(0.3)
+///     .min_first(160.0)
+///     .min_second(240.0)
+///     .build_with_panes(
+///         parent,
+///         |list| { /* master list */ },
+///         |detail| { /* detail view */ },
+///     );
+/// ```
+pub struct SplitPaneBuilder {
+    orientation: Orientation,
+    ratio: f32,
+    min_first: f32,
+    max_first: Option<f32>,
+    min_second: f32,
+    divider_size: f32,
+    divider_style: SeparatorStyle,
+    // Theme from .themed(), resolved against the divider style in build()
+    theme: Option<UiTheme>,
+    base: BuilderBase,
+}
+
+impl SplitPaneBuilder {
+    /// Create a split pane; `Horizontal` places the panes side by side,
+    /// `Vertical` stacks them
+    pub fn new(orientation: Orientation) -> Self {
+        let mut base = BuilderBase::new();
+        base.node.width = Val::Percent(100.0);
+        base.node.height = Val::Percent(100.0);
+
+        Self {
+            orientation,
+            ratio: 0.5,
+            min_first: 0.0,
+            max_first: None,
+            min_second: 0.0,
+            divider_size: 8.0,
+            divider_style: SeparatorStyle::Solid,
+            theme: None,
+            base,
+        }
+    }
+
+    /// Apply theme colors to this builder.
+    ///
+    /// Call this method to use theme-aware styling. If not called,
+    /// sensible defaults (matching the dark theme) will be used.
+    pub fn themed(mut self, theme: &UiTheme) -> Self {
+        self.theme = Some(theme.clone());
+        self
+    }
+
+    /// Resolve colors (themed > default)
+    fn resolve_colors(&self) -> DividerColors {
+        match &self.theme {
+            Some(theme) => DividerColors {
+                line: self.divider_style.color_from_theme(theme),
+                hover: theme.colors.primary.base,
+            },
+            None => DividerColors {
+                line: self.divider_style.default_color(),
+                hover: defaults::DIVIDER_HOVER,
+            },
+        }
+    }
+
+    /// Set the initial share of the space given to the first pane (0.0 to 1.0)
+    pub fn ratio(mut self, ratio: f32) -> Self {
+        self.ratio = ratio.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the smallest size of the first pane in pixels
+    pub fn min_first(mut self, size: f32) -> Self {
+        self.min_first = size.max(0.0);
+        self
+    }
+
+    /// Set the largest size of the first pane in pixels
+    pub fn max_first(mut self, size: f32) -> Self {
+        self.max_first = Some(size.max(0.0));
+        self
+    }
+
+    /// Set the smallest size of the second pane in pixels
+    pub fn min_second(mut self, size: f32) -> Self {
+        self.min_second = size.max(0.0);
+        self
+    }
+
+    /// Set the thickness of the divider's grab area in pixels (default 8)
+    pub fn divider_size(mut self, size: f32) -> Self {
+        self.divider_size = size.max(1.0);
+        self
+    }
+
+    /// Set the separator style used for the divider line
+    pub fn divider_style(mut self, style: SeparatorStyle) -> Self {
+        self.divider_style = style;
+        self
+    }
+
+    /// Set the width of the container
+    pub fn width(mut self, width: Val) -> Self {
+        self.base.node.width = width;
+        self
+    }
+
+    /// Set the height of the container
+    pub fn height(mut self, height: Val) -> Self {
+        self.base.node.height = height;
+        self
+    }
+
+    /// Build the split pane with empty panes (proxy to UiBuilder::build)
+    pub fn build(self, parent: &mut ChildSpawnerCommands) -> Entity {
+        UiBuilder::build(self, parent)
+    }
+
+    /// Build the split pane and fill both panes
+    pub fn build_with_panes(
+        mut self,
+        parent: &mut ChildSpawnerCommands,
+        first: impl FnOnce(&mut ChildSpawnerCommands),
+        second: impl FnOnce(&mut ChildSpawnerCommands),
+    ) -> Entity {
+        let colors = self.resolve_colors();
+        let horizontal = self.orientation == Orientation::Horizontal;
+
+        self.base.node.flex_direction = if horizontal {
+            FlexDirection::Row
+        } else {
+            FlexDirection::Column
+        };
+
+        let mut container = parent.spawn(self.base.node);
+        let split = container.id();
+
+        let axis_px = |size: f32| if horizontal { (Val::Px(size), Val::Auto) } else { (Val::Auto, Val::Px(size)) };
+        let (min_first_w, min_first_h) = axis_px(self.min_first);
+        let (max_first_w, max_first_h) = self.max_first.map(axis_px).unwrap_or((Val::Auto, Val::Auto));
+        let (min_second_w, min_second_h) = axis_px(self.min_second);
+
+        let mut first_pane = Entity::PLACEHOLDER;
+        let mut second_pane = Entity::PLACEHOLDER;
+        container.with_children(|panes| {
+            // Both panes grow from zero in proportion to the ratio, which splits
+            // exactly the space left over by the divider
+            first_pane = panes
+                .spawn((
+                    Node {
+                        flex_grow: self.ratio,
+                        flex_basis: Val::Px(0.0),
+                        min_width: min_first_w,
+                        min_height: min_first_h,
+                        max_width: max_first_w,
+                        max_height: max_first_h,
+                        overflow: Overflow::clip(),
+                        ..default()
+                    },
+                    SplitPaneFirst,
+                ))
+                .with_children(first)
+                .id();
+
+            let mut line = Entity::PLACEHOLDER;
+            panes
+                .spawn((
+                    Button,
+                    Node {
+                        width: if horizontal { Val::Px(self.divider_size) } else { Val::Percent(100.0) },
+                        height: if horizontal { Val::Percent(100.0) } else { Val::Px(self.divider_size) },
+                        flex_shrink: 0.0,
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(Color::NONE),
+                ))
+                .with_children(|divider| {
+                    let line_orientation = if horizontal {
+                        Orientation::Vertical
+                    } else {
+                        Orientation::Horizontal
+                    };
+                    line = SeparatorBuilder::new()
+                        .orientation(line_orientation)
+                        .style(self.divider_style)
+                        .color(colors.line)
+                        .margin(UiRect::ZERO)
+                        .insert(Pickable::IGNORE)
+                        .build(divider);
+                })
+                .insert(SplitDivider {
+                    split,
+                    color: colors.line,
+                    hover_color: colors.hover,
+                    line,
+                    dragging: false,
+                });
+
+            second_pane = panes
+                .spawn((
+                    Node {
+                        flex_grow: 1.0 - self.ratio,
+                        flex_basis: Val::Px(0.0),
+                        min_width: min_second_w,
+                        min_height: min_second_h,
+                        overflow: Overflow::clip(),
+                        ..default()
+                    },
+                    SplitPaneSecond,
+                ))
+                .with_children(second)
+                .id();
+        });
+
+        container.insert(SplitPane {
+            orientation: self.orientation,
+            ratio: self.ratio,
+            min_first: self.min_first,
+            max_first: self.max_first,
+            min_second: self.min_second,
+            divider_size: self.divider_size,
+            first: first_pane,
+            second: second_pane,
+        });
+
+        for hook in self.base.hooks {
+            hook(&mut parent.commands().entity(split));
+        }
+
+        split
+    }
+}
+
+impl UiBuilder for SplitPaneBuilder {
+    fn build(self, parent: &mut ChildSpawnerCommands) -> Entity {
+        self.build_with_panes(parent, |_| {}, |_| {})
+    }
+
+    fn insert(mut self, bundle: impl Bundle + Clone) -> Self {
+        self.base.hooks.push(Box::new(move |cmds| {
+            cmds.insert(bundle.clone());
+        }));
+        self
+    }
+
+    fn id(mut self, id: Entity) -> Self {
+        self.base.entity = Some(id);
+        self
+    }
+}
+
+impl LayoutBuilder for SplitPaneBuilder {
+    fn node(mut self, node: Node) -> Self {
+        self.base.node = node;
+        self
+    }
+
+    fn margin(mut self, margin: UiRect) -> Self {
+        self.base.node.margin = margin;
+        self
+    }
+
+    fn padding(mut self, padding: UiRect) -> Self {
+        self.base.node.padding = padding;
+        self
+    }
+
+    fn width(mut self, width: Val) -> Self {
+        self.base.node.width = width;
+        self
+    }
+
+    fn height(mut self, height: Val) -> Self {
+        self.base.node.height = height;
+        self
+    }
+}
+
+/// Convenience function to create a side-by-side split pane
+pub fn split_pane() -> SplitPaneBuilder {
+    SplitPaneBuilder::new(Orientation::Horizontal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn divider_style_and_theme_resolve_in_any_order() {
+        let theme = UiTheme::default();
+
+        let style_first = SplitPaneBuilder::new(Orientation::Horizontal)
+            .divider_style(SeparatorStyle::Invisible)
+            .themed(&theme)
+            .resolve_colors();
+        let theme_first = SplitPaneBuilder::new(Orientation::Horizontal)
+            .themed(&theme)
+            .divider_style(SeparatorStyle::Invisible)
+            .resolve_colors();
+
+        assert_eq!(style_first.line, Color::NONE);
+        assert_eq!(theme_first.line, Color::NONE);
+        assert_eq!(theme_first.hover, theme.colors.primary.base);
+    }
+}
@@ -0,0 +1,35 @@
+//! Split pane component
+//!
+//! Provides two panes separated by a draggable divider.
+//!
+//! # Features
+//!
+//! - Side-by-side (`Horizontal`) or stacked (`Vertical`) panes
+//! - Drag the divider to resize, within min/max pane sizes
+//! - Divider line drawn with separator styling
+//! - `SplitResizedEvent` while the divider is dragged
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use bevy_ui_builders::prelude::*;
+//!
+//! SplitPaneBuilder::new(Orientation::Horizontal)
+//!     .ratio(0.3)
+//!     .min_first(160.0)
+//!     .min_second(240.0)
+//!     .build_with_panes(
+//!         parent,
+//!         |list| { /* master list */ },
+//!         |detail| { /* detail view */ },
+//!     );
+//! ```
+
+mod builder;
+mod plugin;
+mod systems;
+mod types;
+
+pub use builder::{SplitPaneBuilder, split_pane};
+pub use plugin::SplitPanePlugin;
+pub use types::{SplitPane, SplitDivider, SplitPaneFirst, SplitPaneSecond, SplitResizedEvent};
@@ -0,0 +1,16 @@
+//! Split pane plugin
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+use super::types::SplitResizedEvent;
+use super::systems::*;
+
+define_plugin!(SplitPanePlugin {
+    messages: [SplitResizedEvent],
+    update: [
+        (
+            drag_split_dividers,
+            update_divider_visuals,
+        ).chain(),
+    ]
+});
@@ -0,0 +1,91 @@
+//! Split pane divider drag systems
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use crate::components::separator::Orientation;
+use crate::utils::geometry::node_logical_rect;
+use super::types::*;
+
+/// Resize the panes while a divider is dragged
+pub fn drag_split_dividers(
+    mouse: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut dividers: Query<(&mut SplitDivider, &Interaction)>,
+    mut splits: Query<(&mut SplitPane, &GlobalTransform, &ComputedNode)>,
+    mut panes: Query<&mut Node, Or<(With<SplitPaneFirst>, With<SplitPaneSecond>)>>,
+    mut resized: MessageWriter<SplitResizedEvent>,
+) {
+    let cursor = windows.single().ok().and_then(|window| window.cursor_position());
+
+    for (mut divider, interaction) in dividers.iter_mut() {
+        if mouse.just_pressed(MouseButton::Left) && *interaction == Interaction::Pressed {
+            divider.dragging = true;
+        }
+        if !mouse.pressed(MouseButton::Left) {
+            if divider.dragging {
+                divider.dragging = false;
+            }
+            continue;
+        }
+        if !divider.dragging {
+            continue;
+        }
+
+        let Some(cursor) = cursor else {
+            continue;
+        };
+        let Ok((mut split, transform, computed)) = splits.get_mut(divider.split) else {
+            continue;
+        };
+
+        let rect = node_logical_rect(computed, transform);
+        let (offset, length) = match split.orientation {
+            Orientation::Horizontal => (cursor.x - rect.min.x, rect.width()),
+            Orientation::Vertical => (cursor.y - rect.min.y, rect.height()),
+        };
+
+        let available = (length - split.divider_size).max(0.0);
+        if available <= 0.0 {
+            continue;
+        }
+
+        // Keep the cursor on the middle of the divider
+        let first_size = split.clamp_first(offset - split.divider_size / 2.0, available);
+        let ratio = first_size / available;
+        if (ratio - split.ratio).abs() < f32::EPSILON {
+            continue;
+        }
+
+        split.ratio = ratio;
+        if let Ok(mut node) = panes.get_mut(split.first) {
+            node.flex_grow = ratio;
+        }
+        if let Ok(mut node) = panes.get_mut(split.second) {
+            node.flex_grow = 1.0 - ratio;
+        }
+
+        resized.write(SplitResizedEvent {
+            split: divider.split,
+            ratio,
+            first_size,
+        });
+    }
+}
+
+/// Highlight the divider line while it is hovered or dragged
+pub fn update_divider_visuals(
+    dividers: Query<(&SplitDivider, &Interaction)>,
+    mut lines: Query<&mut BackgroundColor>,
+) {
+    for (divider, interaction) in dividers.iter() {
+        let color = if divider.dragging || *interaction != Interaction::None {
+            divider.hover_color
+        } else {
+            divider.color
+        };
+
+        if let Ok(mut background) = lines.get_mut(divider.line) {
+            background.set_if_neq(BackgroundColor(color));
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Split pane types and components
+
+use bevy::prelude::*;
+
+use crate::components::separator::Orientation;
+
+/// Component for a split pane container
+///
+/// The first pane takes `ratio` of the space not used by the divider and the
+/// second pane takes the rest, so the split keeps its proportions when the
+/// container is resized.
+#[derive(Component, Debug, Clone)]
+pub struct SplitPane {
+    /// `Horizontal` places the panes side by side, `Vertical` stacks them
+    pub orientation: Orientation,
+    /// Share of the available space given to the first pane (0.0 to 1.0)
+    pub ratio: f32,
+    /// Smallest size of the first pane in pixels
+    pub min_first: f32,
+    /// Largest size of the first pane in pixels, if limited
+    pub max_first: Option<f32>,
+    /// Smallest size of the second pane in pixels
+    pub min_second: f32,
+    /// Thickness of the draggable divider in pixels
+    pub divider_size: f32,
+    pub(crate) first: Entity,
+    pub(crate) second: Entity,
+}
+
+impl SplitPane {
+    /// Clamp a first-pane size (pixels) to the pane constraints
+    ///
+    /// `available` is the space shared by both panes (container minus divider).
+    /// When the constraints can't all be met, the first pane's minimum wins.
+    pub fn clamp_first(&self, size: f32, available: f32) -> f32 {
+        let mut max = (available - self.min_second).max(0.0);
+        if let Some(max_first) = self.max_first {
+            max = max.min(max_first);
+        }
+        size.min(max).max(self.min_first.min(available.max(0.0)))
+    }
+}
+
+/// Component for the draggable divider between the panes
+#[derive(Component, Debug, Clone)]
+pub struct SplitDivider {
+    /// The split pane this divider resizes
+    pub split: Entity,
+    /// Idle color of the divider line
+    pub color: Color,
+    /// Divider line color while hovered or dragged
+    pub hover_color: Color,
+    pub(crate) line: Entity,
+    pub(crate) dragging: bool,
+}
+
+/// Marker for the first (left or top) pane
+#[derive(Component)]
+pub struct SplitPaneFirst;
+
+/// Marker for the second (right or bottom) pane
+#[derive(Component)]
+pub struct SplitPaneSecond;
+
+/// Message emitted while a divider is dragged
+#[derive(Message, Clone, Debug)]
+pub struct SplitResizedEvent {
+    /// The split pane entity
+    pub split: Entity,
+    /// New share of the space given to the first pane
+    pub ratio: f32,
+    /// New size of the first pane in pixels
+    pub first_size: f32,
+}
+
+/// Default colors (dark theme) for when no theme is provided
+pub(crate) mod defaults {
+    use bevy::prelude::Color;
+
+    pub const DIVIDER_HOVER: Color = Color::srgb(0.25, 0.46, 0.86);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(min_first: f32, max_first: Option<f32>, min_second: f32) -> SplitPane {
+        SplitPane {
+            orientation: Orientation::Horizontal,
+            ratio: 0.5,
+            min_first,
+            max_first,
+            min_second,
+            divider_size: 8.0,
+            first: Entity::PLACEHOLDER,
+            second: Entity::PLACEHOLDER,
+        }
+    }
+
+    #[test]
+    fn clamp_first_respects_both_panes() {
+        let pane = split(100.0, Some(400.0), 150.0);
+        assert_eq!(pane.clamp_first(50.0, 600.0), 100.0);
+        assert_eq!(pane.clamp_first(300.0, 600.0), 300.0);
+        assert_eq!(pane.clamp_first(500.0, 600.0), 400.0);
+        // Second pane minimum caps the first pane below its own maximum
+        assert_eq!(pane.clamp_first(500.0, 500.0), 350.0);
+    }
+
+    #[test]
+    fn clamp_first_prefers_first_minimum_when_cramped() {
+        let pane = split(200.0, None, 200.0);
+        assert_eq!(pane.clamp_first(250.0, 300.0), 200.0);
+    }
+}
@@ -42,6 +42,7 @@ pub struct TabViewBuilder<S> {
     style: TabStyle,
     position: TabPosition,
     active_tab: usize,
+    reorderable: bool,
     // Theme-resolved colors (set via .themed())
     themed_colors: Option<TabColors>,
     base: BuilderBase,
@@ -62,6 +63,7 @@ impl TabViewBuilder<NoTabs> {
             style: TabStyle::default(),
             position: TabPosition::default(),
             active_tab: 0,
+            reorderable: false,
             themed_colors: None,
             base: BuilderBase::new(),
             _state: PhantomData,
@@ -94,6 +96,7 @@ impl TabViewBuilder<NoTabs> {
             style: self.style,
             position: self.position,
             active_tab: self.active_tab,
+            reorderable: self.reorderable,
             themed_colors: self.themed_colors,
             base: self.base,
             _state: PhantomData,
@@ -117,6 +120,7 @@ impl TabViewBuilder<NoTabs> {
             style: self.style,
             position: self.position,
             active_tab: self.active_tab,
+            reorderable: self.reorderable,
             themed_colors: self.themed_colors,
             base: self.base,
             _state: PhantomData,
@@ -196,6 +200,15 @@ impl TabViewBuilder<HasTabs> {
         self
     }
 
+    /// Let tabs be dragged into a new order, sending `TabReorderedEvent`
+    ///
+    /// A line shows where the dragged tab will land. Right-click cancels
+    /// the drag.
+    pub fn reorderable(mut self, enabled: bool) -> Self {
+        self.reorderable = enabled;
+        self
+    }
+
     /// Resolve colors (themed > default)
     fn resolve_colors(&self) -> TabColors {
         self.themed_colors.clone()
@@ -211,6 +224,7 @@ impl UiBuilder for TabViewBuilder<HasTabs> {
         let tab_count = self.tabs.len();
         let active_tab = self.active_tab.min(tab_count.saturating_sub(1));
         let style = self.style;
+        let reorderable = self.reorderable;
 
         // Determine layout direction based on tab position
         let (container_direction, tabs_direction) = match self.position {
@@ -253,6 +267,23 @@ impl UiBuilder for TabViewBuilder<HasTabs> {
                     BackgroundColor(colors.background),
                 ))
                 .with_children(|tabs_container| {
+                    // Drop indicator for drag-to-reorder (kept as child 0)
+                    if reorderable {
+                        let horizontal = tabs_direction == FlexDirection::Row;
+                        tabs_container.spawn((
+                            Node {
+                                position_type: PositionType::Absolute,
+                                display: Display::None,
+                                width: if horizontal { Val::Px(2.0) } else { Val::Percent(100.0) },
+                                height: if horizontal { Val::Percent(100.0) } else { Val::Px(2.0) },
+                                ..default()
+                            },
+                            BackgroundColor(colors.active_background),
+                            ZIndex(2),
+                            TabDropIndicator,
+                        ));
+                    }
+
                     for (index, tab_config) in tab_configs.iter().enumerate() {
                         let is_active = index == active_tab;
                         let (bg_color, text_color) = if is_active {
@@ -362,6 +393,10 @@ impl UiBuilder for TabViewBuilder<HasTabs> {
                 });
         });
 
+        if reorderable {
+            parent.commands().entity(tab_view_entity).insert(ReorderableTabs);
+        }
+
         self.base.apply(tab_view_entity, &mut parent.commands());
         tab_view_entity
     }
@@ -49,5 +49,5 @@ pub use builder::{TabViewBuilder, NoTabs, HasTabs, tabs};
 pub use plugin::TabsPlugin;
 pub use types::{
     TabView, TabButton, TabContent, TabPosition, TabStyle,
-    TabSelectedEvent, TabConfig,
+    TabSelectedEvent, TabReorderedEvent, TabConfig, ReorderableTabs, TabDropIndicator,
 };
@@ -2,17 +2,20 @@
 
 use bevy::prelude::*;
 use bevy_plugin_builder::define_plugin;
-use super::types::TabSelectedEvent;
+use super::types::{TabSelectedEvent, TabReorderedEvent, TabDragState};
 use super::systems::*;
 
 define_plugin!(TabsPlugin {
     custom_init: |app: &mut App| {
-        app.add_message::<TabSelectedEvent>();
+        app.add_message::<TabSelectedEvent>()
+           .add_message::<TabReorderedEvent>()
+           .init_resource::<TabDragState>();
     },
     update: [
         handle_tab_clicks,
         update_tab_button_visuals,
         update_tab_content_visibility,
         handle_tab_hover,
+        (start_tab_drag, update_tab_drag, finish_tab_drag).chain(),
     ]
 });
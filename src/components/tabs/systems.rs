@@ -1,7 +1,10 @@
 //! Tab view systems
 
 use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
 use crate::theme::UiTheme;
+use crate::utils::geometry::node_logical_rect;
+use crate::utils::reorder::{reorder_target, reordered_index};
 use super::types::*;
 
 /// Default colors for tabs (dark theme fallback)
@@ -161,3 +164,197 @@ pub fn handle_tab_hover(
         };
     }
 }
+
+/// Cursor travel (logical pixels) before a pressed tab starts dragging
+const TAB_DRAG_THRESHOLD: f32 = 4.0;
+
+/// Start tracking a tab when it is pressed in a reorderable tab view
+pub fn start_tab_drag(
+    pressed: Query<(Entity, &TabButton, &Interaction), Changed<Interaction>>,
+    reorderable: Query<(), With<ReorderableTabs>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut drag_state: ResMut<TabDragState>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) || drag_state.dragging.is_some() {
+        return;
+    }
+    let Some(cursor) = windows.single().ok().and_then(|window| window.cursor_position()) else {
+        return;
+    };
+
+    for (entity, tab_button, interaction) in pressed.iter() {
+        if *interaction != Interaction::Pressed || !reorderable.contains(tab_button.tab_view) {
+            continue;
+        }
+
+        drag_state.dragging = Some(TabDrag {
+            tab_view: tab_button.tab_view,
+            button: entity,
+            from: tab_button.index,
+            drop_slot: tab_button.index,
+            grab: cursor,
+            moved: false,
+        });
+        break;
+    }
+}
+
+/// Move the dragged tab with the cursor and draw the drop indicator
+pub fn update_tab_drag(
+    mut commands: Commands,
+    mut drag_state: ResMut<TabDragState>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    tabs: Query<(&TabButton, &GlobalTransform, &ComputedNode, &ChildOf)>,
+    containers: Query<(&Node, &GlobalTransform, &ComputedNode, &Children), (Without<TabButton>, Without<TabDropIndicator>)>,
+    mut tab_nodes: Query<&mut Node, (With<TabButton>, Without<TabDropIndicator>)>,
+    mut indicators: Query<(&ChildOf, &mut Node), (With<TabDropIndicator>, Without<TabButton>)>,
+) {
+    let cursor = windows.single().ok().and_then(|window| window.cursor_position());
+    let drag = drag_state.dragging.as_mut();
+
+    // The container holding the dragged tab, once the drag is under way
+    let active = drag.zip(cursor).and_then(|(drag, cursor)| {
+        if !drag.moved && cursor.distance(drag.grab) < TAB_DRAG_THRESHOLD {
+            return None;
+        }
+        if !drag.moved {
+            drag.moved = true;
+            commands.entity(drag.button).insert(ZIndex(1));
+        }
+        let (_, _, _, child_of) = tabs.get(drag.button).ok()?;
+        Some((drag, cursor, child_of.parent()))
+    });
+
+    let Some((drag, cursor, container)) = active else {
+        for (_, mut indicator) in indicators.iter_mut() {
+            if indicator.display != Display::None {
+                indicator.display = Display::None;
+            }
+        }
+        return;
+    };
+    let Ok((container_node, container_transform, container_computed, children)) = containers.get(container) else {
+        return;
+    };
+
+    let horizontal = matches!(container_node.flex_direction, FlexDirection::Row | FlexDirection::RowReverse);
+    let axis = |v: Vec2| if horizontal { v.x } else { v.y };
+    let offset = axis(cursor - drag.grab);
+
+    // Slide the dragged tab along with the cursor
+    if let Ok(mut node) = tab_nodes.get_mut(drag.button) {
+        let shift = Val::Px(offset);
+        let current = if horizontal { &mut node.left } else { &mut node.top };
+        if *current != shift {
+            *current = shift;
+        }
+    }
+
+    let bounds: Vec<(f32, f32)> = children.iter()
+        .filter_map(|child| tabs.get(child).ok().map(|tab| (child, tab)))
+        .filter(|(_, (tab, ..))| tab.tab_view == drag.tab_view)
+        .map(|(child, (_, transform, computed, _))| {
+            let rect = node_logical_rect(computed, transform);
+            // Measure the dragged tab at its resting place
+            let shift = if child == drag.button { offset } else { 0.0 };
+            (axis(rect.min) - shift, axis(rect.max) - shift)
+        })
+        .collect();
+    let Some((&(first_start, _), &(_, last_end))) = bounds.first().zip(bounds.last()) else {
+        return;
+    };
+
+    // Gap `i` sits before tab `i`; past the last tab's middle is the end
+    let cursor_axis = axis(cursor);
+    drag.drop_slot = bounds.iter()
+        .position(|(start, end)| cursor_axis < (start + end) / 2.0)
+        .unwrap_or(bounds.len());
+
+    let container_start = axis(node_logical_rect(container_computed, container_transform).min);
+    let line = match drag.drop_slot {
+        0 => first_start - 2.0,
+        slot if slot == bounds.len() => last_end + 2.0,
+        slot => (bounds[slot - 1].1 + bounds[slot].0) / 2.0,
+    };
+    let show = reorder_target(drag.from, drag.drop_slot).is_some();
+
+    for (child_of, mut indicator) in indicators.iter_mut() {
+        if child_of.parent() != container {
+            continue;
+        }
+
+        let display = if show { Display::Flex } else { Display::None };
+        if indicator.display != display {
+            indicator.display = display;
+        }
+
+        let position = Val::Px(line - container_start - 1.0);
+        let current = if horizontal { &mut indicator.left } else { &mut indicator.top };
+        if *current != position {
+            *current = position;
+        }
+    }
+}
+
+/// Drop the dragged tab into its new position (right-click cancels the drag)
+pub fn finish_tab_drag(
+    mut commands: Commands,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut drag_state: ResMut<TabDragState>,
+    mut tab_views: Query<&mut TabView>,
+    mut tab_buttons: Query<(&mut TabButton, &mut Node, &ChildOf)>,
+    mut tab_contents: Query<&mut TabContent>,
+    mut reordered: MessageWriter<TabReorderedEvent>,
+) {
+    let cancelled = mouse.just_pressed(MouseButton::Right);
+    if !cancelled && !mouse.just_released(MouseButton::Left) {
+        return;
+    }
+    let Some(drag) = drag_state.dragging.take() else {
+        return;
+    };
+
+    // Put the dragged tab back in the flow
+    let Ok((_, mut node, child_of)) = tab_buttons.get_mut(drag.button) else {
+        return;
+    };
+    node.left = Val::Auto;
+    node.top = Val::Auto;
+    let container = child_of.parent();
+    commands.entity(drag.button).remove::<ZIndex>();
+
+    if cancelled || !drag.moved {
+        return;
+    }
+    let Some(to) = reorder_target(drag.from, drag.drop_slot) else {
+        return;
+    };
+
+    // Moves the existing child. The drop indicator is child 0, so tab `to` is child `to + 1`
+    commands.entity(container).insert_children(to + 1, &[drag.button]);
+
+    // Keep button and content indices in display order
+    for (mut tab_button, ..) in tab_buttons.iter_mut() {
+        if tab_button.tab_view == drag.tab_view {
+            tab_button.index = reordered_index(tab_button.index, drag.from, to);
+        }
+    }
+    for mut tab_content in tab_contents.iter_mut() {
+        if tab_content.tab_view == drag.tab_view {
+            tab_content.index = reordered_index(tab_content.index, drag.from, to);
+        }
+    }
+    if let Ok(mut tab_view) = tab_views.get_mut(drag.tab_view) {
+        let active_tab = reordered_index(tab_view.active_tab, drag.from, to);
+        if tab_view.active_tab != active_tab {
+            tab_view.active_tab = active_tab;
+        }
+    }
+
+    reordered.write(TabReorderedEvent {
+        tab_view: drag.tab_view,
+        from: drag.from,
+        to,
+    });
+}
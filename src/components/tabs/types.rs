@@ -70,6 +70,56 @@ pub struct TabSelectedEvent {
     pub previous_index: usize,
 }
 
+/// Message emitted when a tab is dragged to a new position
+///
+/// Indices follow the same convention as `ListReorderedEvent`: apply
+/// `vec.remove(from)` then `vec.insert(to, tab)` to keep a parallel `Vec` in
+/// sync. Tab button and content indices (and the active tab) are updated to
+/// the new order before this is sent.
+#[derive(Message, Clone, Debug)]
+pub struct TabReorderedEvent {
+    /// The tab view entity
+    pub tab_view: Entity,
+    /// Index the tab was dragged from
+    pub from: usize,
+    /// Index the tab now sits at
+    pub to: usize,
+}
+
+/// Marks a tab view whose tabs can be dragged into a new order
+///
+/// Added by `TabViewBuilder::reorderable(true)`.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct ReorderableTabs;
+
+/// Marker for the line showing where a dragged tab will be dropped
+#[derive(Component)]
+pub struct TabDropIndicator;
+
+/// An in-progress tab drag
+#[derive(Clone, Debug)]
+pub(crate) struct TabDrag {
+    /// The tab view being reordered
+    pub tab_view: Entity,
+    /// The dragged tab button
+    pub button: Entity,
+    /// Index the tab was picked up from
+    pub from: usize,
+    /// Gap position (0..=tab count) the tab would be dropped into
+    pub drop_slot: usize,
+    /// Cursor position when the tab was pressed
+    pub grab: Vec2,
+    /// Whether the cursor has moved far enough to count as a drag
+    pub moved: bool,
+}
+
+/// Resource tracking the tab currently being dragged
+#[derive(Resource, Default, Debug)]
+pub(crate) struct TabDragState {
+    /// The active drag, if any
+    pub dragging: Option<TabDrag>,
+}
+
 /// Configuration for a single tab
 #[derive(Clone, Debug)]
 pub struct TabConfig {
@@ -3,29 +3,56 @@
 use bevy::prelude::*;
 use crate::animation::AnimationCategory;
 use crate::components::button::{ButtonBuilder, ButtonSize};
-use crate::styles::ButtonStyle;
-use crate::theme::UiTheme;
+use crate::relationships::TextInputPart;
+use crate::styles::{ButtonStyle, dimensions};
+use crate::systems::popup::{self, PopupAnchor};
+use crate::theme::{FontRole, TextInputComponentStyle, ThemeFonts, UiTheme};
 use crate::traits::{UiBuilder, LayoutBuilder, BuilderBase};
 use super::types::*;
 use super::native_input::{
-    NativeTextInput, TextBuffer, TextInputVisual,
-    TextInputSettings, TabBehavior,
+    NativeTextInput, TextBuffer, TextInputVisual, CursorVisual, CursorStyle,
+    TextInputSettings, TabBehavior, SubmitKey, InputHistory, DEFAULT_UNDO_DEPTH, DEFAULT_UNDO_COALESCE_WINDOW,
+    DEFAULT_CURSOR_BLINK_RATE,
+    DEFAULT_CURSOR_WIDTH,
+    DEFAULT_MASK_CHAR,
 };
 
 /// Builder for creating text inputs with managed focus
 pub struct TextInputBuilder {
     value: String,
     placeholder: Option<String>,
+    placeholder_color: Option<Color>,
+    placeholder_font: Option<Handle<Font>>,
+    placeholder_italic: bool,
+    floating_label: Option<String>,
     font_size: f32,
+    font: Option<Handle<Font>>,
+    font_role: FontRole,
     padding: UiRect,
     focus_type: TextInputFocus,
     inactive: bool,
     retain_on_submit: bool,
     filter: Option<TextInputFilter>,
     show_clear_button: bool,
+    show_counter: bool,
+    max_undo_depth: usize,
+    undo_coalesce_window: f32,
+    cursor_style: CursorStyle,
+    cursor_color: Option<Color>,
+    cursor_blink_rate: Option<f32>,
+    cursor_width: Option<f32>,
     validation_rules: Option<Vec<crate::ValidationRule>>,
-    // Theme-resolved colors (set via .themed())
+    suggestions: Option<SuggestionProvider>,
+    max_suggestions: usize,
+    ghost_suggestion: Option<String>,
+    history: bool,
+    mask_char: Option<char>,
+    multiline: bool,
+    submit_key: SubmitKey,
+    // Theme-resolved colors, fonts and cursor (set via .themed())
     themed_colors: Option<TextInputColors>,
+    themed_fonts: Option<ThemeFonts>,
+    themed_style: Option<TextInputComponentStyle>,
     base: BuilderBase,
 }
 
@@ -40,15 +67,37 @@ impl TextInputBuilder {
         Self {
             value: String::new(),
             placeholder: None,
+            placeholder_color: None,
+            placeholder_font: None,
+            placeholder_italic: false,
+            floating_label: None,
             font_size: 16.0,
+            font: None,
+            font_role: FontRole::Body,
             padding: UiRect::all(Val::Px(10.0)),
             focus_type: TextInputFocus::Independent,
             inactive: false,
             retain_on_submit: true,
             filter: None,
             show_clear_button: false,
+            show_counter: false,
+            max_undo_depth: DEFAULT_UNDO_DEPTH,
+            undo_coalesce_window: DEFAULT_UNDO_COALESCE_WINDOW,
+            cursor_style: CursorStyle::Line,
+            cursor_color: None,
+            cursor_blink_rate: None,
+            cursor_width: None,
             validation_rules: None,
+            suggestions: None,
+            max_suggestions: 8,
+            ghost_suggestion: None,
+            history: false,
+            mask_char: None,
+            multiline: false,
+            submit_key: SubmitKey::default(),
             themed_colors: None,
+            themed_fonts: None,
+            themed_style: None,
             base,
         }
     }
@@ -71,9 +120,25 @@ impl TextInputBuilder {
     /// ```
     pub fn themed(mut self, theme: &UiTheme) -> Self {
         self.themed_colors = Some(TextInputColors::from_theme(theme));
+        self.themed_fonts = Some(theme.typography.fonts.clone());
+        self.themed_style = Some(theme.components.text_input.clone());
         self
     }
 
+    /// Resolve the cursor blink rate (explicit > themed > default)
+    fn resolve_cursor_blink_rate(&self) -> f32 {
+        self.cursor_blink_rate
+            .or(self.themed_style.as_ref().map(|style| style.cursor_blink_rate))
+            .unwrap_or(DEFAULT_CURSOR_BLINK_RATE)
+    }
+
+    /// Resolve the cursor width (explicit > themed > default)
+    fn resolve_cursor_width(&self) -> f32 {
+        self.cursor_width
+            .or(self.themed_style.as_ref().map(|style| style.cursor_width))
+            .unwrap_or(DEFAULT_CURSOR_WIDTH)
+    }
+
     /// Resolve colors (themed > default)
     fn resolve_colors(&self) -> TextInputColors {
         self.themed_colors.clone()
@@ -92,12 +157,98 @@ impl TextInputBuilder {
         self
     }
 
+    /// Set the placeholder color, overriding the theme's
+    pub fn placeholder_color(mut self, color: Color) -> Self {
+        self.placeholder_color = Some(color);
+        self
+    }
+
+    /// Show the placeholder in the theme's italic font
+    ///
+    /// Bevy can't slant text itself, so this needs `FontRole::Italic` set in
+    /// the theme's fonts; otherwise the placeholder stays upright.
+    pub fn placeholder_italic(mut self, italic: bool) -> Self {
+        self.placeholder_italic = italic;
+        self
+    }
+
+    /// Override the placeholder font directly
+    pub fn placeholder_font(mut self, font: Handle<Font>) -> Self {
+        self.placeholder_font = Some(font);
+        self
+    }
+
+    /// Mask the input for passwords, showing `*` for each character
+    ///
+    /// Shorthand for `.mask_char('*')`.
+    pub fn password(self) -> Self {
+        self.mask_char(DEFAULT_MASK_CHAR)
+    }
+
+    /// Show `mask` in place of each character of the input
+    ///
+    /// Only the display is masked: `TextBuffer.content` and change/submit
+    /// events carry the real text. Masked inputs don't copy or cut to the
+    /// clipboard, but pasting into them still works.
+    pub fn mask_char(mut self, mask: char) -> Self {
+        self.mask_char = Some(mask);
+        self
+    }
+
+    /// Add a Material-style floating label
+    ///
+    /// The label sits inside the field like a placeholder while the input is
+    /// empty and unfocused, then animates up to a smaller label straddling the
+    /// top border once the input is focused or filled. Replaces any placeholder.
+    ///
+    /// # Example
+    /// ```ignore
+    /// TextInputBuilder::new()
+    ///     .floating_label("Email")
+    ///     .build(parent);
+    /// ```
+    pub fn floating_label(mut self, label: impl Into<String>) -> Self {
+        self.floating_label = Some(label.into());
+        self
+    }
+
     /// Set the font size
     pub fn with_font_size(mut self, size: f32) -> Self {
         self.font_size = size;
         self
     }
 
+    /// Pick the theme font for a role (default: body)
+    ///
+    /// Only takes effect on themed inputs; e.g. `FontRole::Monospace` for code
+    /// or hex entry fields.
+    pub fn font_role(mut self, role: FontRole) -> Self {
+        self.font_role = role;
+        self
+    }
+
+    /// Override the font directly
+    pub fn with_font(mut self, font: Handle<Font>) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Resolve font (override > themed role font > Bevy default)
+    fn resolve_font(&self) -> Handle<Font> {
+        self.font.clone()
+            .or_else(|| self.themed_fonts.as_ref().map(|fonts| fonts.font_for(self.font_role)))
+            .unwrap_or_default()
+    }
+
+    /// Resolve placeholder font (override > themed italic font > input font)
+    fn resolve_placeholder_font(&self) -> Option<Handle<Font>> {
+        self.placeholder_font.clone().or_else(|| {
+            self.themed_fonts.as_ref()
+                .filter(|_| self.placeholder_italic)
+                .and_then(|fonts| fonts.italic.clone())
+        })
+    }
+
     /// Set the width of the input
     pub fn with_width(mut self, width: Val) -> Self {
         self.base.node.width = width;
@@ -134,6 +285,25 @@ impl TextInputBuilder {
         self
     }
 
+    /// Accept several lines of text
+    ///
+    /// The text wraps at word boundaries and scrolls vertically to keep the
+    /// cursor in view. Enter inserts a newline; the submit key (Ctrl+Enter
+    /// by default) submits. Give the input a height that fits a few lines.
+    pub fn multiline(mut self, multiline: bool) -> Self {
+        self.multiline = multiline;
+        self
+    }
+
+    /// Set which Enter press submits a multiline input
+    ///
+    /// [`SubmitKey::Enter`] gives chat-style input: Enter sends and
+    /// Shift+Enter starts a new line.
+    pub fn submit_key(mut self, key: SubmitKey) -> Self {
+        self.submit_key = key;
+        self
+    }
+
     /// Set whether to retain text on submit
     pub fn retain_on_submit(mut self, retain: bool) -> Self {
         self.retain_on_submit = retain;
@@ -234,12 +404,176 @@ impl TextInputBuilder {
         self
     }
 
+    /// Show a small character counter, e.g. "12/20" with `.with_max_length(20)`
+    ///
+    /// The counter sits below the field's right edge (inside the bottom-right
+    /// corner for multiline inputs) and turns the theme's danger color once
+    /// the limit is reached. It counts chars, like `max_length` does, so an
+    /// emoji made of a single code point counts as one; composed ones such
+    /// as flags count each code point. Without a max length only the count
+    /// is shown.
+    pub fn show_counter(mut self) -> Self {
+        self.show_counter = true;
+        self
+    }
+
+    /// Set the maximum number of undo steps kept for this input.
+    ///
+    /// Older steps are dropped once the limit is reached, bounding memory
+    /// use during long editing sessions. Defaults to 100.
+    pub fn max_undo_depth(mut self, depth: usize) -> Self {
+        self.max_undo_depth = depth;
+        self
+    }
+
+    /// Set how long a pause, in seconds, ends an undo step while typing.
+    ///
+    /// Characters typed in a row merge into one undo step until the pause,
+    /// a cursor move or the space after a word. Defaults to 0.5; use 0.0 to
+    /// undo one character at a time.
+    pub fn undo_coalesce_window(mut self, seconds: f32) -> Self {
+        self.undo_coalesce_window = seconds;
+        self
+    }
+
+    /// Set the cursor style (line, block or underline)
+    pub fn cursor_style(mut self, style: CursorStyle) -> Self {
+        self.cursor_style = style;
+        self
+    }
+
+    /// Set the cursor color (default: white)
+    pub fn cursor_color(mut self, color: Color) -> Self {
+        self.cursor_color = Some(color);
+        self
+    }
+
+    /// Set how many times per second the cursor blinks (0.0 for a solid cursor)
+    ///
+    /// Each blink is one visible and one hidden phase, so 2.0 shows the
+    /// cursor for a quarter second at a time. Defaults to the theme's
+    /// `components.text_input.cursor_blink_rate` when themed, else 1.0.
+    pub fn cursor_blink_rate(mut self, rate: f32) -> Self {
+        self.cursor_blink_rate = Some(rate);
+        self
+    }
+
+    /// Set the width in pixels of a [`CursorStyle::Line`] cursor
+    ///
+    /// Defaults to the theme's `components.text_input.cursor_width` when
+    /// themed, else 2.0.
+    pub fn cursor_width(mut self, width: f32) -> Self {
+        self.cursor_width = Some(width);
+        self
+    }
+
     /// Add validation rules to this text input
     pub fn with_validation(mut self, rules: Vec<crate::ValidationRule>) -> Self {
         self.validation_rules = Some(rules);
         self
     }
 
+    /// Offer autocomplete suggestions in a popup menu below the field
+    ///
+    /// `provider` is called with the current text whenever it changes and
+    /// returns the candidates to list. Up/Down move the highlight, Tab
+    /// accepts the highlighted (or first) candidate, Enter accepts the
+    /// highlighted one, and Escape closes the menu. Clicking a candidate
+    /// accepts it as well.
+    ///
+    /// # Example
+    /// ```ignore
+    /// fn complete_command(text: &str) -> Vec<String> {
+    ///     ["/help", "/kick", "/quit"].iter()
+    ///         .filter(|command| command.starts_with(text))
+    ///         .map(|command| command.to_string())
+    ///         .collect()
+    /// }
+    ///
+    /// TextInputBuilder::new()
+    ///     .suggestions(complete_command)
+    ///     .build(parent);
+    /// ```
+    pub fn suggestions(mut self, provider: SuggestionProvider) -> Self {
+        self.suggestions = Some(provider);
+        self
+    }
+
+    /// Set how many suggestions are listed at once (default: 8)
+    pub fn max_suggestions(mut self, count: usize) -> Self {
+        self.max_suggestions = count.max(1);
+        self
+    }
+
+    /// Show a greyed-out completion after the typed text, filled in by Tab
+    ///
+    /// Shown while the focused input's text is the start of `text` (ignoring
+    /// case) and the cursor is at the end; Shift+Tab still moves to the
+    /// previous field. Update the input's [`GhostSuggestion`] to follow what
+    /// is being typed, such as the rest of a matching file name.
+    pub fn ghost_suggestion(mut self, text: impl Into<String>) -> Self {
+        self.ghost_suggestion = Some(text.into());
+        self
+    }
+
+    /// Recall previously submitted entries with the Up and Down arrows
+    ///
+    /// For command consoles and search boxes. Each submit (Enter) records
+    /// the text in the input's [`InputHistory`]; Up brings back older
+    /// entries and Down returns to newer ones and finally to the text that
+    /// was being typed. Pair with `.retain_on_submit(false)` so the field
+    /// clears after each command.
+    ///
+    /// # Example
+    /// ```ignore
+    /// TextInputBuilder::new()
+    ///     .with_placeholder("Enter a command...")
+    ///     .retain_on_submit(false)
+    ///     .with_history(true)
+    ///     .build(parent);
+    /// ```
+    pub fn with_history(mut self, enabled: bool) -> Self {
+        self.history = enabled;
+        self
+    }
+
+    /// Floating label child bundle, if one was requested
+    fn floating_label_bundle(&self, colors: &TextInputColors) -> Option<impl Bundle> {
+        let text = self.floating_label.clone()?;
+        let filled = !self.value.is_empty();
+        let label = FloatingLabel {
+            progress: if filled { 1.0 } else { 0.0 },
+            duration: 0.15,
+            rest_font_size: self.font_size,
+            float_font_size: (self.font_size * 0.75).round(),
+            rest_color: colors.placeholder,
+            focus_color: colors.border_focus,
+            background: colors.background,
+        };
+
+        Some((
+            Node {
+                position_type: PositionType::Absolute,
+                left: self.padding.left,
+                // Horizontal padding keeps the border gap wider than the text
+                margin: UiRect::left(Val::Px(-4.0)),
+                padding: UiRect::horizontal(Val::Px(4.0)),
+                ..default()
+            },
+            Text::new(text),
+            TextFont {
+                font: self.resolve_font(),
+                font_size: if filled { label.float_font_size } else { label.rest_font_size },
+                ..default()
+            },
+            TextColor(label.rest_color),
+            BackgroundColor(Color::NONE),
+            BorderRadius::all(Val::Px(2.0)),
+            Pickable::IGNORE, // Clicks go through to the input
+            label,
+        ))
+    }
+
     /// Build and spawn the text input entity (proxy to UiBuilder::build)
     pub fn build(self, parent: &mut ChildSpawnerCommands) -> Entity {
         UiBuilder::build(self, parent)
@@ -250,9 +584,21 @@ impl UiBuilder for TextInputBuilder {
     fn build(self, parent: &mut ChildSpawnerCommands) -> Entity {
         // Resolve colors (themed > default)
         let colors = self.resolve_colors();
+        let cursor_blink_rate = self.resolve_cursor_blink_rate();
+        let cursor_width = self.resolve_cursor_width();
+        let font = self.resolve_font();
+        let placeholder_font = self.resolve_placeholder_font();
+        let placeholder_color = self.placeholder_color.unwrap_or(colors.placeholder);
+        let floating_label = self.floating_label_bundle(&colors);
+        // The floating label stands in for the placeholder while resting
+        let placeholder = if self.floating_label.is_some() {
+            String::new()
+        } else {
+            self.placeholder.clone().unwrap_or_default()
+        };
 
         // If we need a clear button, create a container
-        let entity = if self.show_clear_button {
+        let (entity, input_entity) = if self.show_clear_button {
             // Container uses base.node properties
             let mut container_node = self.base.node.clone();
             container_node.flex_direction = FlexDirection::Row;
@@ -283,8 +629,6 @@ impl UiBuilder for TextInputBuilder {
                             padding: self.padding,
                             border: UiRect::all(Val::Px(2.0)),
                             justify_content: JustifyContent::Start,
-                            align_items: AlignItems::Center,
-                            overflow: Overflow::visible(),  // Prevent cursor clipping
                             ..default()
                         },
                         BackgroundColor(colors.background),
@@ -299,22 +643,34 @@ impl UiBuilder for TextInputBuilder {
                         },
                         TextInputVisual {
                             font: TextFont {
+                                font: font.clone(),
                                 font_size: self.font_size,
                                 ..default()
                             },
                             text_color: colors.text,
-                            placeholder: self.placeholder.clone().unwrap_or_default(),
-                            placeholder_color: colors.placeholder,
-                            cursor_color: Color::WHITE,  // White cursor for maximum visibility
+                            placeholder: placeholder.clone(),
+                            placeholder_color,
+                            placeholder_font: placeholder_font.clone(),
+                            cursor_color: self.cursor_color.unwrap_or(Color::WHITE),  // White cursor for maximum visibility
                             selection_color: colors.selection,
-                            mask_char: None,
+                            mask_char: self.mask_char,
                         },
                         TextInputSettings {
-                            multiline: false,
+                            multiline: self.multiline,
                             max_length: self.filter.as_ref().and_then(|f| f.max_length),
                             retain_on_submit: self.retain_on_submit,
                             read_only: self.inactive,
                             tab_behavior: TabBehavior::NextField,
+                            max_undo_depth: self.max_undo_depth,
+                            undo_coalesce_window: self.undo_coalesce_window,
+                            cursor_blink_rate,
+                            cursor_width,
+                            submit_key: self.submit_key,
+                        },
+                        CursorVisual {
+                            visible: false,
+                            style: self.cursor_style,
+                            ..default()
                         },
                         // Focus management
                         self.focus_type.clone(),
@@ -337,6 +693,10 @@ impl UiBuilder for TextInputBuilder {
                         ));
                     }
 
+                    if let Some(label) = floating_label {
+                        entity_commands.with_child(label);
+                    }
+
                     text_input_id = Some(entity_commands.id());
 
                     // Add clear button
@@ -354,7 +714,7 @@ impl UiBuilder for TextInputBuilder {
                     }
                 });
 
-            container_id
+            (container_id, text_input_id.unwrap_or(container_id))
         } else {
             // No clear button, build normally
             // Merge base.node with input specific props
@@ -362,8 +722,6 @@ impl UiBuilder for TextInputBuilder {
             input_node.padding = self.padding;
             input_node.border = UiRect::all(Val::Px(2.0));
             input_node.justify_content = JustifyContent::Start;
-            input_node.align_items = AlignItems::Center;
-            input_node.overflow = Overflow::visible();
 
             let mut entity_commands = parent.spawn((
                 input_node,
@@ -379,22 +737,34 @@ impl UiBuilder for TextInputBuilder {
                 },
                 TextInputVisual {
                     font: TextFont {
+                        font: font.clone(),
                         font_size: self.font_size,
                         ..default()
                     },
                     text_color: colors.text,
-                    placeholder: self.placeholder.clone().unwrap_or_default(),
-                    placeholder_color: colors.placeholder,
-                    cursor_color: Color::WHITE,  // White cursor for maximum visibility
+                    placeholder: placeholder.clone(),
+                    placeholder_color,
+                    placeholder_font: placeholder_font.clone(),
+                    cursor_color: self.cursor_color.unwrap_or(Color::WHITE),  // White cursor for maximum visibility
                     selection_color: colors.selection,
-                    mask_char: None,
+                    mask_char: self.mask_char,
                 },
                 TextInputSettings {
-                    multiline: false,
+                    multiline: self.multiline,
                     max_length: self.filter.as_ref().and_then(|f| f.max_length),
                     retain_on_submit: self.retain_on_submit,
                     read_only: self.inactive,
                     tab_behavior: TabBehavior::NextField,
+                    max_undo_depth: self.max_undo_depth,
+                    undo_coalesce_window: self.undo_coalesce_window,
+                    cursor_blink_rate,
+                    cursor_width,
+                    submit_key: self.submit_key,
+                },
+                CursorVisual {
+                    visible: false,
+                    style: self.cursor_style,
+                    ..default()
                 },
                 // Focus management
                 self.focus_type.clone(),
@@ -417,9 +787,85 @@ impl UiBuilder for TextInputBuilder {
                 ));
             }
 
-            entity_commands.id()
+            if let Some(label) = floating_label {
+                entity_commands.with_child(label);
+            }
+
+            let id = entity_commands.id();
+            (id, id)
         };
+
+        // Character counter, kept up to date by update_character_counters
+        if self.show_counter {
+            let max_length = self.filter.as_ref().and_then(|f| f.max_length);
+            let (text, at_limit) = CharacterCounter::label(&self.value, max_length);
+            let placement = if self.multiline {
+                Node {
+                    position_type: PositionType::Absolute,
+                    right: self.padding.right,
+                    bottom: Val::Px(2.0),
+                    ..default()
+                }
+            } else {
+                Node {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(0.0),
+                    top: Val::Percent(100.0),
+                    margin: UiRect::top(Val::Px(4.0)),
+                    ..default()
+                }
+            };
+
+            let counter = parent.commands().spawn((
+                placement,
+                Text::new(text),
+                TextFont {
+                    font: font.clone(),
+                    font_size: (self.font_size * 0.75).round(),
+                    ..default()
+                },
+                TextColor(if at_limit { colors.danger } else { colors.placeholder }),
+                Pickable::IGNORE,
+                CharacterCounter {
+                    color: colors.placeholder,
+                    limit_color: colors.danger,
+                },
+                TextInputPart(input_entity),
+            )).id();
+            parent.commands().entity(input_entity).add_child(counter);
+        }
+
+        // Suggestion menu (initially hidden), hanging below the whole field
+        if let Some(provider) = self.suggestions {
+            let menu = parent.commands().spawn((
+                popup::menu_node(Val::Auto),
+                BackgroundColor(colors.background),
+                BorderColor::all(colors.border),
+                BorderRadius::all(Val::Px(5.0)),
+                GlobalZIndex(dimensions::Z_INDEX_POPUP),
+                PopupAnchor::below(entity),
+                SuggestionMenu { input: input_entity },
+                TextInputPart(input_entity),
+            )).id();
+
+            parent.commands().entity(input_entity).insert(TextInputSuggestions {
+                provider,
+                max_items: self.max_suggestions,
+                candidates: Vec::new(),
+                highlighted: None,
+                menu,
+                query: None,
+            });
+        }
         
+        if let Some(text) = self.ghost_suggestion {
+            parent.commands().entity(input_entity).insert(GhostSuggestion(text));
+        }
+
+        if self.history {
+            parent.commands().entity(input_entity).insert(InputHistory::default());
+        }
+
         // Apply hooks
         for hook in self.base.hooks {
             hook(&mut parent.commands().entity(entity));
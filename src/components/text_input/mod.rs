@@ -9,12 +9,28 @@ pub mod native_input;
 // Public exports only
 pub use builder::{TextInputBuilder, text_input};
 pub use plugin::TextInputPlugin;
-pub use systems::handle_clear_button_clicks;
+pub use systems::{
+    handle_clear_button_clicks,
+    update_character_counters,
+    animate_floating_labels,
+    handle_suggestion_keys,
+    handle_suggestion_clicks,
+    update_suggestions,
+    update_suggestion_highlight,
+};
 pub use types::{
     TextInputFilter,
     InputFilter,
+    RegexPattern,
     InputTransform,
     ClearButtonTarget,
+    CharacterCounter,
+    FloatingLabel,
+    SuggestionProvider,
+    TextInputSuggestions,
+    GhostSuggestion,
+    SuggestionMenu,
+    SuggestionItem,
     TextInputFocus,
     FocusGroupId,
 };
@@ -26,7 +42,15 @@ pub use native_input::{
     CursorVisual,
     CursorStyle,
     TextInputSettings,
+    InputHistory,
     TabBehavior,
+    SubmitKey,
+    VisualLines,
+    MultiClick,
+    ExtraCarets,
     TextInputSubmitEvent,
     TextInputChangeEvent,
+    TextInputSelectionChangedEvent,
+    TextInputFocusEvent,
+    SetTextInputValue,
 };
\ No newline at end of file
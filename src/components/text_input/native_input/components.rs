@@ -3,7 +3,25 @@
 use bevy::prelude::*;
 use std::collections::VecDeque;
 use super::helpers::{char_to_byte_index};
-use super::types::{CursorStyle, OperationType, TabBehavior};
+use super::types::{CursorStyle, OperationType, SubmitKey, TabBehavior};
+
+/// Default number of undo steps kept per text input
+pub const DEFAULT_UNDO_DEPTH: usize = 100;
+
+/// Default window, in seconds, in which typed characters merge into one undo step
+pub const DEFAULT_UNDO_COALESCE_WINDOW: f32 = 0.5;
+
+/// Default cursor blink rate (full on/off cycles per second)
+pub const DEFAULT_CURSOR_BLINK_RATE: f32 = 1.0;
+
+/// Default width in pixels of line cursors and extra carets
+pub const DEFAULT_CURSOR_WIDTH: f32 = 2.0;
+
+/// Mask character used by `TextInputBuilder::password()`
+pub const DEFAULT_MASK_CHAR: char = '*';
+
+/// Default number of submitted entries kept per input history
+pub const DEFAULT_INPUT_HISTORY_SIZE: usize = 50;
 
 /// The main text input component - this is all you need to spawn
 #[derive(Component, Default)]
@@ -90,10 +108,23 @@ pub struct TextInputVisual {
     pub placeholder: String,
     /// Placeholder color
     pub placeholder_color: Color,
-    /// Whether to mask input (for passwords)
+    /// Font for the placeholder, e.g. an italic face (`None` uses `font`)
+    pub placeholder_font: Option<Handle<Font>>,
+    /// Character displayed in place of each character of the content (for
+    /// passwords); the buffer keeps the real text
     pub mask_char: Option<char>,
 }
 
+impl TextInputVisual {
+    /// Font settings for the placeholder
+    pub fn placeholder_text_font(&self) -> TextFont {
+        TextFont {
+            font: self.placeholder_font.clone().unwrap_or_else(|| self.font.font.clone()),
+            ..self.font.clone()
+        }
+    }
+}
+
 impl Default for TextInputVisual {
     fn default() -> Self {
         Self {
@@ -103,6 +134,7 @@ impl Default for TextInputVisual {
             cursor_color: Color::WHITE,
             placeholder: String::new(),
             placeholder_color: Color::srgba(0.5, 0.5, 0.5, 0.5),
+            placeholder_font: None,
             mask_char: None,
         }
     }
@@ -121,6 +153,8 @@ pub struct CursorVisual {
     pub cursor_entity: Option<Entity>,
     /// Entities of selection overlays (for rendering selection highlights)
     pub selection_entities: Vec<Entity>,
+    /// Entities drawing the line cursor and the extra carets (see [`ExtraCarets`])
+    pub caret_entities: Vec<Entity>,
 }
 
 impl Default for CursorVisual {
@@ -131,6 +165,7 @@ impl Default for CursorVisual {
             style: CursorStyle::Line,
             cursor_entity: None,
             selection_entities: Vec::new(),
+            caret_entities: Vec::new(),
         }
     }
 }
@@ -138,12 +173,90 @@ impl Default for CursorVisual {
 /// Scroll state for overflow handling
 #[derive(Component, Default)]
 pub struct ScrollViewport {
-    /// Horizontal scroll offset
+    /// Horizontal scroll offset in logical pixels
     pub offset_x: f32,
-    /// Vertical scroll offset (for multiline)
+    /// Vertical scroll offset in logical pixels (for multiline)
     pub offset_y: f32,
 }
 
+/// Where each visual line of a multiline input starts, after word wrapping
+///
+/// Rebuilt from the text layout by `update_visual_lines`; Up/Down and
+/// Home/End navigate by these lines.
+#[derive(Component, Clone, Debug)]
+pub struct VisualLines {
+    /// Char index each line starts at, ascending and starting with 0
+    pub starts: Vec<usize>,
+    /// Column Up/Down aim for, and the position the last vertical move landed
+    /// on; any other cursor move drops it
+    pub goal: Option<(usize, usize)>,
+}
+
+impl Default for VisualLines {
+    fn default() -> Self {
+        Self {
+            starts: vec![0],
+            goal: None,
+        }
+    }
+}
+
+/// Longest gap between clicks, in seconds, for them to count as a double or
+/// triple click
+pub const MULTI_CLICK_INTERVAL: f64 = 0.4;
+
+/// Recent clicks on a text input, for double- and triple-click selection
+///
+/// A click on the same character as the last one, within
+/// [`MULTI_CLICK_INTERVAL`], bumps `count`: two clicks select a word, three
+/// the line. Dragging afterwards extends the selection by whole words or
+/// lines.
+#[derive(Component, Default, Debug)]
+pub struct MultiClick {
+    /// When the last click landed (real time, in seconds)
+    pub last_time: Option<f64>,
+    /// Char index the last click landed on
+    pub last_pos: usize,
+    /// Clicks in the current run, 1 to 3
+    pub count: u8,
+    /// The word or line the run selected, which drags extend from
+    pub unit: Option<(usize, usize)>,
+}
+
+/// Carets added next to the main cursor of a multiline input
+///
+/// Alt+Click adds a caret (or removes the one under the pointer). Typing,
+/// Backspace, Delete and Enter then apply at every caret as one undo step;
+/// any other key or a plain click goes back to the single main cursor.
+#[derive(Component, Default, Debug, Clone)]
+pub struct ExtraCarets {
+    /// Char positions of the carets besides `TextBuffer.cursor_pos`
+    pub positions: Vec<usize>,
+}
+
+impl ExtraCarets {
+    /// Add a caret at `pos`, or remove the one already there
+    ///
+    /// `cursor` is the main cursor, which never gets a second caret.
+    pub fn toggle(&mut self, pos: usize, cursor: usize) {
+        if let Some(index) = self.positions.iter().position(|&caret| caret == pos) {
+            self.positions.remove(index);
+        } else if pos != cursor {
+            self.positions.push(pos);
+        }
+    }
+
+    /// Whether there are carets besides the main cursor
+    pub fn is_active(&self) -> bool {
+        !self.positions.is_empty()
+    }
+
+    /// Go back to the main cursor only
+    pub fn clear(&mut self) {
+        self.positions.clear();
+    }
+}
+
 /// Undo/redo history
 #[derive(Component)]
 pub struct UndoHistory {
@@ -151,8 +264,14 @@ pub struct UndoHistory {
     pub undo_stack: VecDeque<EditOperation>,
     /// Stack of redo operations
     pub redo_stack: VecDeque<EditOperation>,
-    /// Maximum history size
+    /// Maximum history size (oldest operations are dropped beyond this)
     pub max_size: usize,
+    /// Seconds between typed characters for them to merge into one undo
+    /// step (0.0 makes every character its own step)
+    pub coalesce_window: f32,
+    /// When the newest undo step last grew from typing, or `None` once
+    /// anything else was recorded or undone
+    pub last_typed: Option<f64>,
 }
 
 impl Default for UndoHistory {
@@ -160,7 +279,58 @@ impl Default for UndoHistory {
         Self {
             undo_stack: VecDeque::new(),
             redo_stack: VecDeque::new(),
-            max_size: 100,
+            max_size: DEFAULT_UNDO_DEPTH,
+            coalesce_window: DEFAULT_UNDO_COALESCE_WINDOW,
+            last_typed: None,
+        }
+    }
+}
+
+impl UndoHistory {
+    /// Record a new edit operation.
+    ///
+    /// Clears the redo stack (a new edit invalidates the redo branch) and
+    /// drops the oldest operations once the history exceeds `max_size`.
+    pub fn push(&mut self, op: EditOperation) {
+        self.undo_stack.push_back(op);
+        self.redo_stack.clear();
+        self.last_typed = None;
+        self.enforce_limit();
+    }
+
+    /// Record a typed insertion, merging it into the newest undo step when
+    /// it continues that step's typing
+    ///
+    /// Typing merges while each character lands right after the previous one
+    /// within `coalesce_window` seconds, up to the next word: whitespace typed
+    /// after a word starts a new step. Typing at several carets merges the
+    /// same way. `now` is the current time in seconds.
+    pub fn push_typed(&mut self, op: EditOperation, now: f64) {
+        let in_window = self.coalesce_window > 0.0
+            && self.last_typed.is_some_and(|last| now - last <= f64::from(self.coalesce_window));
+        let merged = in_window && self.undo_stack.back_mut().is_some_and(|last| last.absorb(&op));
+
+        if merged {
+            self.redo_stack.clear();
+        } else {
+            self.push(op);
+        }
+        self.last_typed = Some(now);
+    }
+
+    /// Change the maximum history size, trimming both stacks if needed
+    pub fn set_max_size(&mut self, max_size: usize) {
+        self.max_size = max_size;
+        self.enforce_limit();
+    }
+
+    /// Drop the oldest operations from both stacks beyond `max_size`
+    fn enforce_limit(&mut self) {
+        while self.undo_stack.len() > self.max_size {
+            self.undo_stack.pop_front();
+        }
+        while self.redo_stack.len() > self.max_size {
+            self.redo_stack.pop_front();
         }
     }
 }
@@ -176,10 +346,142 @@ pub struct EditOperation {
     pub cursor_after: usize,
 }
 
+impl EditOperation {
+    /// Append `next` to this insertion if it was typed right after it,
+    /// without starting a new word
+    ///
+    /// Typing at several carets records the whole text each time; those
+    /// steps merge when `next` starts from the text this one left, judged
+    /// by the character typed at the main cursor.
+    fn absorb(&mut self, next: &EditOperation) -> bool {
+        if next.cursor_before != self.cursor_after {
+            return false;
+        }
+
+        match (&mut self.op_type, &next.op_type) {
+            (OperationType::Insert { pos, text }, OperationType::Insert { pos: next_pos, text: next_text }) => {
+                let contiguous = *next_pos == *pos + text.chars().count();
+                let word_break = next_text.starts_with(char::is_whitespace)
+                    && !text.ends_with(char::is_whitespace);
+                if !contiguous || word_break {
+                    return false;
+                }
+                text.push_str(next_text);
+            }
+            (
+                OperationType::Replace { pos: 0, new, .. },
+                OperationType::Replace { pos: 0, old: next_old, new: next_new },
+            ) => {
+                let is_space = |text: &str, cursor: usize| {
+                    cursor.checked_sub(1)
+                        .and_then(|index| text.chars().nth(index))
+                        .is_some_and(char::is_whitespace)
+                };
+                let word_break = is_space(next_new, next.cursor_after) && !is_space(new, self.cursor_after);
+                if *next_old != *new || word_break {
+                    return false;
+                }
+                new.clone_from(next_new);
+            }
+            _ => return false,
+        }
+
+        self.cursor_after = next.cursor_after;
+        true
+    }
+}
+
+/// Previously submitted entries, recalled with the Up and Down arrows
+///
+/// Added by `TextInputBuilder::with_history(true)`. Submitting pushes the
+/// text; Up steps back through older entries and Down forward again, ending
+/// at whatever was being typed before the history was browsed.
+#[derive(Component, Debug, Clone)]
+pub struct InputHistory {
+    /// Submitted entries, oldest first
+    pub entries: VecDeque<String>,
+    /// Maximum number of entries kept (the oldest are dropped beyond this)
+    pub max_size: usize,
+    /// Index of the entry being shown, or `None` while editing new text
+    pub position: Option<usize>,
+    /// Text that was being typed when browsing started
+    pub draft: String,
+}
+
+impl Default for InputHistory {
+    fn default() -> Self {
+        Self {
+            entries: VecDeque::new(),
+            max_size: DEFAULT_INPUT_HISTORY_SIZE,
+            position: None,
+            draft: String::new(),
+        }
+    }
+}
+
+impl InputHistory {
+    /// Record a submitted entry and stop browsing
+    ///
+    /// Empty entries and repeats of the latest entry are not recorded.
+    pub fn push(&mut self, entry: &str) {
+        self.position = None;
+        self.draft.clear();
+
+        if entry.is_empty() || self.entries.back().is_some_and(|last| last == entry) {
+            return;
+        }
+
+        self.entries.push_back(entry.to_string());
+        while self.entries.len() > self.max_size {
+            self.entries.pop_front();
+        }
+    }
+
+    /// Step to the next older entry, remembering `current` as the draft when
+    /// browsing starts
+    ///
+    /// Returns `None` when there is nothing older.
+    pub fn older(&mut self, current: &str) -> Option<&str> {
+        let index = match self.position {
+            None => {
+                let newest = self.entries.len().checked_sub(1)?;
+                self.draft = current.to_string();
+                newest
+            }
+            Some(index) => index.checked_sub(1)?,
+        };
+
+        self.position = Some(index);
+        self.entries.get(index).map(String::as_str)
+    }
+
+    /// Step to the next newer entry, or back to the draft after the newest
+    ///
+    /// Returns `None` when not browsing.
+    pub fn newer(&mut self) -> Option<String> {
+        let index = self.position?;
+
+        if index + 1 < self.entries.len() {
+            self.position = Some(index + 1);
+            self.entries.get(index + 1).cloned()
+        } else {
+            self.position = None;
+            Some(std::mem::take(&mut self.draft))
+        }
+    }
+}
+
 /// Inner text node marker
 #[derive(Component)]
 pub struct TextInputInner;
 
+/// Clips an input's scrolled text along with its selection and carets
+///
+/// Fills the input's content box and holds the [`TextInputInner`], so other
+/// children of the input, like a floating label, aren't clipped with the text.
+#[derive(Component)]
+pub struct TextInputClip;
+
 /// Marker component for the selection overlay
 #[derive(Component)]
 pub struct TextInputSelection {
@@ -192,7 +494,7 @@ pub struct TextInputSelection {
 pub struct TextInputSettings {
     /// Whether the input is multiline
     pub multiline: bool,
-    /// Maximum length in characters
+    /// Maximum length in graphemes (user-perceived characters)
     pub max_length: Option<usize>,
     /// Whether to retain text on submit
     pub retain_on_submit: bool,
@@ -200,6 +502,18 @@ pub struct TextInputSettings {
     pub read_only: bool,
     /// Tab behavior
     pub tab_behavior: TabBehavior,
+    /// Maximum number of undo steps kept (older steps are dropped)
+    pub max_undo_depth: usize,
+    /// Seconds between typed characters for them to merge into one undo
+    /// step (0.0 for per-character undo)
+    pub undo_coalesce_window: f32,
+    /// Cursor blinks per second, each one a visible and a hidden phase
+    /// (0.0 keeps the cursor solid)
+    pub cursor_blink_rate: f32,
+    /// Width in pixels of a [`CursorStyle::Line`] cursor and of extra carets
+    pub cursor_width: f32,
+    /// Which Enter press submits a multiline input
+    pub submit_key: SubmitKey,
 }
 
 impl Default for TextInputSettings {
@@ -210,6 +524,165 @@ impl Default for TextInputSettings {
             retain_on_submit: false,
             read_only: false,
             tab_behavior: TabBehavior::NextField,
+            max_undo_depth: DEFAULT_UNDO_DEPTH,
+            undo_coalesce_window: DEFAULT_UNDO_COALESCE_WINDOW,
+            cursor_blink_rate: DEFAULT_CURSOR_BLINK_RATE,
+            cursor_width: DEFAULT_CURSOR_WIDTH,
+            submit_key: SubmitKey::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn insert_op(pos: usize) -> EditOperation {
+        EditOperation {
+            op_type: OperationType::Insert { pos, text: "a".to_string() },
+            cursor_before: pos,
+            cursor_after: pos + 1,
+        }
+    }
+
+    #[test]
+    fn test_undo_history_is_bounded() {
+        let mut history = UndoHistory::default();
+        history.set_max_size(10);
+
+        for i in 0..1000 {
+            history.push(insert_op(i));
+        }
+
+        assert_eq!(history.undo_stack.len(), 10);
+        // Oldest operations are the ones dropped
+        assert_eq!(history.undo_stack.front().unwrap().cursor_before, 990);
+    }
+
+    #[test]
+    fn test_push_clears_redo_stack() {
+        let mut history = UndoHistory::default();
+        history.push(insert_op(0));
+        let op = history.undo_stack.pop_back().unwrap();
+        history.redo_stack.push_back(op);
+
+        history.push(insert_op(1));
+        assert!(history.redo_stack.is_empty());
+    }
+
+    fn typed(pos: usize, ch: char) -> EditOperation {
+        EditOperation {
+            op_type: OperationType::Insert { pos, text: ch.to_string() },
+            cursor_before: pos,
+            cursor_after: pos + 1,
+        }
+    }
+
+    fn inserted_steps(history: &UndoHistory) -> Vec<&str> {
+        history.undo_stack.iter()
+            .map(|op| match &op.op_type {
+                OperationType::Insert { text, .. } => text.as_str(),
+                _ => "",
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_typing_merges_into_one_step() {
+        let mut history = UndoHistory::default();
+        for (i, ch) in "hello".chars().enumerate() {
+            history.push_typed(typed(i, ch), i as f64 * 0.1);
+        }
+        assert_eq!(inserted_steps(&history), vec!["hello"]);
+        assert_eq!(history.undo_stack[0].cursor_after, 5);
+
+        // A pause longer than the window starts a new step
+        history.push_typed(typed(5, '!'), 2.0);
+        assert_eq!(inserted_steps(&history), vec!["hello", "!"]);
+
+        // So does a window of 0.0
+        let mut history = UndoHistory { coalesce_window: 0.0, ..default() };
+        history.push_typed(typed(0, 'h'), 0.0);
+        history.push_typed(typed(1, 'i'), 0.0);
+        assert_eq!(inserted_steps(&history), vec!["h", "i"]);
+    }
+
+    #[test]
+    fn test_space_after_a_word_breaks_the_step() {
+        let mut history = UndoHistory::default();
+        for (i, ch) in "hi  you".chars().enumerate() {
+            history.push_typed(typed(i, ch), 0.0);
         }
+        assert_eq!(inserted_steps(&history), vec!["hi", "  you"]);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_cursor_move_breaks_the_step() {
+        let mut history = UndoHistory::default();
+        history.push_typed(typed(0, 'a'), 0.0);
+        history.push_typed(typed(1, 'b'), 0.0);
+        // Cursor moved back to the start
+        history.push_typed(typed(0, 'c'), 0.0);
+        assert_eq!(inserted_steps(&history), vec!["ab", "c"]);
+
+        // Anything recorded in between breaks it too
+        history.push(insert_op(5));
+        history.push_typed(typed(6, 'd'), 0.0);
+        assert_eq!(history.undo_stack.len(), 4);
+    }
+
+    #[test]
+    fn test_typing_at_carets_merges_into_one_step() {
+        // The cursor after "x" and a caret after "y"
+        let replaced = |old: &str, new: &str, cursor_before: usize| EditOperation {
+            op_type: OperationType::Replace { pos: 0, old: old.to_string(), new: new.to_string() },
+            cursor_before,
+            cursor_after: cursor_before + 1,
+        };
+        let mut history = UndoHistory::default();
+        history.push_typed(replaced("x\ny", "xh\nyh", 1), 0.0);
+        history.push_typed(replaced("xh\nyh", "xhi\nyhi", 2), 0.1);
+        history.push_typed(replaced("xhi\nyhi", "xhi \nyhi ", 3), 0.2);
+
+        let steps: Vec<_> = history.undo_stack.iter()
+            .map(|op| match &op.op_type {
+                OperationType::Replace { old, new, .. } => (old.as_str(), new.as_str(), op.cursor_after),
+                _ => ("", "", 0),
+            })
+            .collect();
+        assert_eq!(steps, vec![("x\ny", "xhi\nyhi", 3), ("xhi\nyhi", "xhi \nyhi ", 4)]);
+    }
+
+    #[test]
+    fn test_input_history_recalls_entries_and_draft() {
+        let mut history = InputHistory::default();
+        history.push("help");
+        history.push("");
+        history.push("status");
+        history.push("status");
+        assert_eq!(history.entries.len(), 2);
+
+        assert_eq!(history.older("sta"), Some("status"));
+        assert_eq!(history.older("status"), Some("help"));
+        assert_eq!(history.older("help"), None);
+        assert_eq!(history.newer().as_deref(), Some("status"));
+        assert_eq!(history.newer().as_deref(), Some("sta"));
+        assert_eq!(history.newer(), None);
+    }
+
+    #[test]
+    fn test_shrinking_max_size_trims_both_stacks() {
+        let mut history = UndoHistory::default();
+        for i in 0..6 {
+            history.push(insert_op(i));
+        }
+        for _ in 0..3 {
+            let op = history.undo_stack.pop_back().unwrap();
+            history.redo_stack.push_back(op);
+        }
+
+        history.set_max_size(2);
+        assert_eq!(history.undo_stack.len(), 2);
+        assert_eq!(history.redo_stack.len(), 2);
+    }
+}
@@ -18,4 +18,70 @@ pub struct TextInputChangeEvent {
     pub entity: Entity,
     /// The new text content
     pub text: String,
-}
\ No newline at end of file
+}
+
+/// Event triggered when the selected text range of a text input changes
+///
+/// `start` and `end` are char positions. When the selection is cleared,
+/// `start == end` (the cursor position) and `selected_text` is empty.
+#[derive(Message)]
+pub struct TextInputSelectionChangedEvent {
+    /// Entity whose selection changed
+    pub entity: Entity,
+    /// Start of the selection (in chars)
+    pub start: usize,
+    /// End of the selection (in chars, exclusive)
+    pub end: usize,
+    /// The currently selected text (mask characters in a masked input)
+    pub selected_text: String,
+}
+
+/// Event triggered when a text input gains or loses keyboard focus
+///
+/// Sent once per transition, for clicks, clicking outside, Tab navigation and
+/// focus set through `TextInputFocus`. A focused input that is despawned
+/// sends a loss. When focus moves between inputs, the loss is sent before the
+/// gain.
+#[derive(Message)]
+pub struct TextInputFocusEvent {
+    /// Entity whose focus changed
+    pub entity: Entity,
+    /// Whether the input gained (`true`) or lost (`false`) focus
+    pub focused: bool,
+}
+
+/// Message replacing the text of a text input from game code
+///
+/// Use it for things like reset buttons or loading saved data into a form.
+/// The new text skips the input's filters and max length. The cursor stays
+/// where it was, clamped to the new text, the selection is cleared, and the
+/// replacement can be undone like any edit. A [`TextInputChangeEvent`] is
+/// sent if the text changed, unless the request is [`silent`](Self::silent).
+///
+/// Read the current text from the input's [`TextBuffer`](super::TextBuffer).
+#[derive(Message, Debug, Clone)]
+pub struct SetTextInputValue {
+    /// The text input entity
+    pub entity: Entity,
+    /// The new text
+    pub text: String,
+    /// Skip the change event
+    pub silent: bool,
+}
+
+impl SetTextInputValue {
+    /// Set the text of `entity` to `text`
+    pub fn new(entity: Entity, text: impl Into<String>) -> Self {
+        Self {
+            entity,
+            text: text.into(),
+            silent: false,
+        }
+    }
+
+    /// Don't send a [`TextInputChangeEvent`] for this change
+    pub fn silent(mut self) -> Self {
+        self.silent = true;
+        self
+    }
+}
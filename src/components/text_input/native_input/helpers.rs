@@ -1,7 +1,8 @@
 //! Helper functions for text manipulation and navigation
 
 use bevy::prelude::*;
-use super::components::{TextBuffer, SelectionState, EditOperation};
+use unicode_segmentation::UnicodeSegmentation;
+use super::components::{TextBuffer, SelectionState, EditOperation, VisualLines, CursorVisual};
 use super::types::{NavigationAction, EditAction, OperationType};
 
 /// Convert character index to byte index in a UTF-8 string
@@ -19,12 +20,54 @@ pub fn byte_to_char_index(text: &str, byte_index: usize) -> usize {
         .unwrap_or_else(|| text.chars().count())
 }
 
-/// Find the start of the previous word from the given position
-pub fn find_word_boundary_backward(text: &str, pos: usize) -> usize {
-    if pos == 0 {
-        return 0;
+/// Mask text for display, one `mask` per grapheme
+///
+/// An emoji or a letter with combining accents shows as a single mask
+/// character, however many chars it is made of.
+pub fn mask_text(text: &str, mask: char) -> String {
+    mask.to_string().repeat(text.graphemes(true).count())
+}
+
+/// Number of graphemes before char index `char_index`
+///
+/// Converts a cursor position in the text to one in its masked display. A
+/// grapheme the index falls inside counts as before it.
+pub fn char_to_grapheme_index(text: &str, char_index: usize) -> usize {
+    let byte_index = char_to_byte_index(text, char_index);
+    text.grapheme_indices(true)
+        .take_while(|(start, _)| *start < byte_index)
+        .count()
+}
+
+/// Char range `start..end` of `text` as positions in its display
+///
+/// Masked text shows one character per grapheme, so a masked range is
+/// converted to grapheme indices; a grapheme the end falls inside is covered.
+pub fn display_range(text: &str, (start, end): (usize, usize), masked: bool) -> (usize, usize) {
+    if masked {
+        (char_to_grapheme_index(text, start), char_to_grapheme_index(text, end))
+    } else {
+        (start, end)
     }
+}
 
+/// Char index where grapheme `grapheme_index` starts (the end of the text
+/// past the last one)
+///
+/// Converts a position in the masked display back to one in the text.
+pub fn grapheme_to_char_index(text: &str, grapheme_index: usize) -> usize {
+    let byte_index = text.grapheme_indices(true)
+        .nth(grapheme_index)
+        .map_or(text.len(), |(start, _)| start);
+    byte_to_char_index(text, byte_index)
+}
+
+/// Find the start of the previous word from the given position
+///
+/// Words are runs of non-whitespace, so punctuation stays with its word.
+/// Ctrl+Left, Ctrl+Backspace and double-click selection all split words
+/// here.
+pub fn find_word_boundary_backward(text: &str, pos: usize) -> usize {
     let chars: Vec<char> = text.chars().collect();
     let mut idx = pos.min(chars.len());
 
@@ -33,43 +76,26 @@ pub fn find_word_boundary_backward(text: &str, pos: usize) -> usize {
         idx -= 1;
     }
 
-    // Skip word characters until we hit whitespace or punctuation
-    while idx > 0 && !chars[idx - 1].is_whitespace() && chars[idx - 1].is_alphanumeric() {
+    // Skip word characters until we hit whitespace
+    while idx > 0 && !chars[idx - 1].is_whitespace() {
         idx -= 1;
     }
 
-    // If we're at punctuation, skip it as a separate word
-    if idx > 0 && !chars[idx - 1].is_whitespace() && !chars[idx - 1].is_alphanumeric() {
-        while idx > 0 && !chars[idx - 1].is_whitespace() && !chars[idx - 1].is_alphanumeric() {
-            idx -= 1;
-        }
-    }
-
     idx
 }
 
-/// Find the end of the next word from the given position
+/// Find the start of the next word from the given position
+///
+/// The counterpart of [`find_word_boundary_backward`], used by Ctrl+Right
+/// and Ctrl+Delete.
 pub fn find_word_boundary_forward(text: &str, pos: usize) -> usize {
     let chars: Vec<char> = text.chars().collect();
     let len = chars.len();
-
-    if pos >= len {
-        return len;
-    }
-
-    let mut idx = pos;
+    let mut idx = pos.min(len);
 
     // Skip current word characters
-    if idx < len && chars[idx].is_alphanumeric() {
-        while idx < len && chars[idx].is_alphanumeric() {
-            idx += 1;
-        }
-    }
-    // Skip punctuation as separate word
-    else if idx < len && !chars[idx].is_whitespace() {
-        while idx < len && !chars[idx].is_whitespace() && !chars[idx].is_alphanumeric() {
-            idx += 1;
-        }
+    while idx < len && !chars[idx].is_whitespace() {
+        idx += 1;
     }
 
     // Skip whitespace
@@ -104,6 +130,66 @@ pub fn find_line_end(text: &str, pos: usize) -> usize {
     }
 }
 
+/// Find the word containing the character at the given position
+///
+/// Words are split where Ctrl+Arrow navigation splits them (see
+/// [`find_word_boundary_backward`]), without the whitespace Ctrl+Right skips
+/// after a word; a position in a run of whitespace gets the whole run. At
+/// the end of the text the last character counts. Returns the `(start, end)`
+/// char range.
+pub fn word_range_at(text: &str, pos: usize) -> (usize, usize) {
+    let chars: Vec<char> = text.chars().collect();
+    if chars.is_empty() {
+        return (0, 0);
+    }
+
+    let idx = pos.min(chars.len() - 1);
+    let next_word = find_word_boundary_forward(text, idx);
+
+    if chars[idx].is_whitespace() {
+        let mut start = idx;
+        while start > 0 && chars[start - 1].is_whitespace() {
+            start -= 1;
+        }
+        return (start, next_word);
+    }
+
+    let mut end = next_word;
+    while end > idx + 1 && chars[end - 1].is_whitespace() {
+        end -= 1;
+    }
+
+    (find_word_boundary_backward(text, idx + 1), end)
+}
+
+/// Find the unit a run of clicks selects at the given position
+///
+/// Two clicks select the word there and three its line (the whole text for a
+/// single-line input).
+pub fn click_unit_at(text: &str, pos: usize, clicks: u8) -> (usize, usize) {
+    if clicks == 2 {
+        word_range_at(text, pos)
+    } else {
+        (find_line_start(text, pos), find_line_end(text, pos))
+    }
+}
+
+/// Extend a double- or triple-click selection to a dragged-to position
+///
+/// The selection keeps the clicked `unit` and grows by whole words or lines
+/// toward `pos`. Returns the new `(anchor, cursor)`.
+pub fn extend_by_unit(text: &str, unit: (usize, usize), clicks: u8, pos: usize) -> (usize, usize) {
+    let (start, end) = unit;
+    if pos < start {
+        (end, click_unit_at(text, pos, clicks).0)
+    } else if pos > end {
+        // The character before pos is the last one dragged over
+        (start, click_unit_at(text, pos - 1, clicks).1)
+    } else {
+        (start, end)
+    }
+}
+
 /// Apply a navigation action to cursor position
 pub fn apply_navigation(
     action: NavigationAction,
@@ -133,57 +219,133 @@ pub fn apply_navigation(
                 .unwrap_or_else(|| content.len());
             byte_to_char_index(content, line_end)
         }
-        NavigationAction::WordLeft => {
-            // Move to start of previous word
-            if cursor_pos == 0 {
-                return 0;
-            }
-
-            let mut pos = cursor_pos - 1;
-            let chars: Vec<char> = content.chars().collect();
-
-            // Skip whitespace
-            while pos > 0 && chars[pos].is_whitespace() {
-                pos -= 1;
-            }
+        NavigationAction::WordLeft => find_word_boundary_backward(content, cursor_pos),
+        NavigationAction::WordRight => find_word_boundary_forward(content, cursor_pos),
+        NavigationAction::DocumentStart => 0,
+        NavigationAction::DocumentEnd => char_count,
+        NavigationAction::LineUp | NavigationAction::LineDown => {
+            // Without layout information, lines are the hard (`\n`) lines
+            let down = matches!(action, NavigationAction::LineDown);
+            move_vertically(content, &hard_line_starts(content), cursor_pos, None, down)
+                .map(|(pos, _)| pos)
+                .unwrap_or(cursor_pos)
+        }
+    }
+}
 
-            // Skip word characters
-            while pos > 0 && !chars[pos - 1].is_whitespace() {
-                pos -= 1;
-            }
+/// Apply a navigation action over the visual lines of a wrapped input
+///
+/// LineUp/LineDown move between visual lines, aiming for the same column
+/// across consecutive moves even through shorter lines, and LineStart/LineEnd
+/// go to the ends of the current visual line. Other actions behave as in
+/// [`apply_navigation`]. Returns `None` for LineUp on the first line and
+/// LineDown on the last.
+pub fn apply_line_navigation(
+    action: NavigationAction,
+    cursor_pos: usize,
+    content: &str,
+    lines: &mut VisualLines,
+) -> Option<usize> {
+    // The goal column only survives while the cursor stays where the last
+    // vertical move put it
+    let goal = lines.goal
+        .filter(|(_, landed)| *landed == cursor_pos)
+        .map(|(column, _)| column);
+    let line = line_of(&lines.starts, cursor_pos);
 
-            pos
+    match action {
+        NavigationAction::LineUp | NavigationAction::LineDown => {
+            let down = matches!(action, NavigationAction::LineDown);
+            let (pos, column) = move_vertically(content, &lines.starts, cursor_pos, goal, down)?;
+            lines.goal = Some((column, pos));
+            Some(pos)
         }
-        NavigationAction::WordRight => {
-            // Move to end of next word
-            if cursor_pos >= char_count {
-                return char_count;
-            }
+        NavigationAction::LineStart => {
+            lines.goal = None;
+            Some(lines.starts[line])
+        }
+        NavigationAction::LineEnd => {
+            lines.goal = None;
+            Some(line_end(content, &lines.starts, line))
+        }
+        other => {
+            lines.goal = None;
+            Some(apply_navigation(other, cursor_pos, content))
+        }
+    }
+}
 
-            let mut pos = cursor_pos;
-            let chars: Vec<char> = content.chars().collect();
+/// Char index each hard line (separated by `\n`) starts at
+pub fn hard_line_starts(text: &str) -> Vec<usize> {
+    std::iter::once(0)
+        .chain(text.chars().enumerate().filter(|(_, c)| *c == '\n').map(|(i, _)| i + 1))
+        .collect()
+}
 
-            // Skip word characters
-            while pos < char_count && !chars[pos].is_whitespace() {
-                pos += 1;
+/// Char index each visual line starts at, from where the layout put glyphs
+///
+/// `glyphs` pairs the line number of each laid-out character with its char
+/// index, in order. A line starts at its first glyph; hard newlines also
+/// start lines, including empty ones the layout has no glyphs on.
+pub fn visual_line_starts(text: &str, glyphs: impl IntoIterator<Item = (usize, usize)>) -> Vec<usize> {
+    let mut starts = hard_line_starts(text);
+    let mut current_line = None;
+
+    for (line, index) in glyphs {
+        if current_line != Some(line) {
+            if current_line.is_some() {
+                starts.push(index);
             }
+            current_line = Some(line);
+        }
+    }
 
-            // Skip whitespace
-            while pos < char_count && chars[pos].is_whitespace() {
-                pos += 1;
-            }
+    starts.sort_unstable();
+    starts.dedup();
+    starts
+}
 
-            pos
-        }
-        NavigationAction::DocumentStart => 0,
-        NavigationAction::DocumentEnd => char_count,
-        NavigationAction::LineUp | NavigationAction::LineDown => {
-            // For multiline navigation (simplified for now)
-            cursor_pos
+/// Index of the line in `starts` that contains `pos`
+pub fn line_of(starts: &[usize], pos: usize) -> usize {
+    starts.partition_point(|start| *start <= pos).saturating_sub(1)
+}
+
+/// Position at the end of `line`
+///
+/// Sits before the newline or the space the line was wrapped at, so the
+/// cursor stays on that line.
+pub fn line_end(text: &str, starts: &[usize], line: usize) -> usize {
+    let char_count = text.chars().count();
+    match starts.get(line + 1) {
+        Some(&next) if next > 0 && next <= char_count => {
+            let wrapped_at_space = text.chars().nth(next - 1).is_some_and(char::is_whitespace);
+            if wrapped_at_space { next - 1 } else { next }
         }
+        _ => char_count,
     }
 }
 
+/// Cursor position one line up or down from `pos`, in the same column
+///
+/// `goal_column` overrides the current column, so a run of moves keeps
+/// aiming for where it started. Returns the new position and the column
+/// aimed for, or `None` when there is no line in that direction.
+pub fn move_vertically(
+    text: &str,
+    starts: &[usize],
+    pos: usize,
+    goal_column: Option<usize>,
+    down: bool,
+) -> Option<(usize, usize)> {
+    let line = line_of(starts, pos);
+    let column = goal_column.unwrap_or(pos - starts[line].min(pos));
+    let target = if down { line + 1 } else { line.checked_sub(1)? };
+    let start = *starts.get(target)?;
+
+    let end = line_end(text, starts, target);
+    Some(((start + column).min(end), column))
+}
+
 /// Get the selected text from the buffer
 pub fn get_selected_text(buffer: &TextBuffer, selection: &SelectionState) -> Option<String> {
     if let Some((start, end)) = selection.range() {
@@ -195,6 +357,49 @@ pub fn get_selected_text(buffer: &TextBuffer, selection: &SelectionState) -> Opt
     }
 }
 
+/// Focus an input, showing its cursor straight away
+pub fn focus_input(buffer: &mut TextBuffer, cursor_visual: &mut CursorVisual) {
+    buffer.is_focused = true;
+    cursor_visual.visible = true;
+    cursor_visual.blink_timer.reset();
+}
+
+/// Take the focus from an input, dropping its selection and cursor
+pub fn unfocus_input(buffer: &mut TextBuffer, selection: &mut SelectionState, cursor_visual: &mut CursorVisual) {
+    buffer.is_focused = false;
+    selection.clear();
+    cursor_visual.visible = false;
+}
+
+/// Replace the whole text as a single undoable edit
+///
+/// Keeps the cursor where it was, clamped to the new text, and clears the
+/// selection. Returns `None` when the text is unchanged.
+pub fn replace_content(
+    buffer: &mut TextBuffer,
+    selection: &mut SelectionState,
+    text: &str,
+) -> Option<EditOperation> {
+    let cursor_before = buffer.cursor_pos;
+    buffer.cursor_pos = buffer.cursor_pos.min(text.chars().count());
+    selection.clear();
+
+    if buffer.content == text {
+        return None;
+    }
+
+    let old = std::mem::replace(&mut buffer.content, text.to_string());
+    Some(EditOperation {
+        op_type: OperationType::Replace {
+            pos: 0,
+            old,
+            new: text.to_string(),
+        },
+        cursor_before,
+        cursor_after: buffer.cursor_pos,
+    })
+}
+
 /// Apply an edit action to the text buffer
 pub fn apply_edit(
     action: &EditAction,
@@ -203,7 +408,6 @@ pub fn apply_edit(
 ) -> Option<EditOperation> {
     match action {
         EditAction::InsertChar(ch) => {
-            info!("apply_edit InsertChar: '{}' at pos {}", ch, buffer.cursor_pos);
             // Delete selection first if exists
             if selection.has_selection() {
                 if let Some(_op) = apply_edit(&EditAction::DeleteSelection, buffer, selection) {
@@ -212,9 +416,7 @@ pub fn apply_edit(
             }
 
             let byte_pos = char_to_byte_index(&buffer.content, buffer.cursor_pos);
-            info!("Inserting '{}' at byte pos {}", ch, byte_pos);
             buffer.content.insert(byte_pos, *ch);
-            info!("Buffer after insert: '{}'", buffer.content);
 
             let op = EditOperation {
                 op_type: OperationType::Insert {
@@ -227,7 +429,6 @@ pub fn apply_edit(
 
             buffer.cursor_pos += 1;
             selection.clear();
-            info!("Returning Some(op) from apply_edit");
 
             Some(op)
         }
@@ -466,4 +667,233 @@ pub fn apply_edit(
             apply_edit(&EditAction::InsertString(text.clone()), buffer, selection)
         }
     }
-}
\ No newline at end of file
+}
+
+/// Apply an edit at the cursor and at each extra caret
+///
+/// Carets are edited from the start of the text, each shifted by the edits
+/// before it, and carets that end up in the same place merge. The whole
+/// change is returned as one `Replace` operation, so a single undo reverts
+/// it at every caret. Returns `None` when the text is unchanged.
+pub fn apply_edit_at_carets(
+    action: &EditAction,
+    buffer: &mut TextBuffer,
+    carets: &mut Vec<usize>,
+) -> Option<EditOperation> {
+    let cursor_before = buffer.cursor_pos;
+    let old = buffer.content.clone();
+    let len = old.chars().count();
+
+    let mut positions: Vec<usize> = carets.iter()
+        .chain([&cursor_before])
+        .map(|&pos| pos.min(len))
+        .collect();
+    positions.sort_unstable();
+    positions.dedup();
+
+    let mut selection = SelectionState::default();
+    let mut shift = 0isize;
+    let mut moved = Vec::with_capacity(positions.len());
+    for pos in positions {
+        let len_before = buffer.content.chars().count() as isize;
+        buffer.cursor_pos = pos.saturating_add_signed(shift);
+        apply_edit(action, buffer, &mut selection);
+        shift += buffer.content.chars().count() as isize - len_before;
+        moved.push((pos, buffer.cursor_pos));
+    }
+
+    let cursor_after = moved.iter()
+        .find(|(pos, _)| *pos == cursor_before.min(len))
+        .map_or(buffer.cursor_pos, |(_, after)| *after);
+    buffer.cursor_pos = cursor_after;
+    *carets = moved.into_iter()
+        .map(|(_, after)| after)
+        .filter(|&after| after != cursor_after)
+        .collect();
+    carets.dedup();
+
+    if buffer.content == old {
+        return None;
+    }
+
+    Some(EditOperation {
+        op_type: OperationType::Replace {
+            pos: 0,
+            old,
+            new: buffer.content.clone(),
+        },
+        cursor_before,
+        cursor_after,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks_cover_whole_graphemes() {
+        // "e" + combining acute, a family emoji joined with ZWJs, then "x"
+        let text = "e\u{301}\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}x";
+        assert_eq!(mask_text(text, '*'), "***");
+
+        // Char 2 starts the emoji, the second grapheme
+        assert_eq!(char_to_grapheme_index(text, 2), 1);
+        assert_eq!(grapheme_to_char_index(text, 1), 2);
+        assert_eq!(grapheme_to_char_index(text, 2), 7);
+        assert_eq!(grapheme_to_char_index(text, 3), text.chars().count());
+    }
+
+    #[test]
+    fn masked_selections_map_to_graphemes() {
+        let text = "e\u{301}\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}x";
+
+        // Selecting the emoji and the "x" covers the last two masks
+        assert_eq!(display_range(text, (2, 8), true), (1, 3));
+        assert_eq!(display_range(text, (2, 8), false), (2, 8));
+    }
+
+    #[test]
+    fn wrapped_lines_start_at_word_boundaries() {
+        // Laid out as "the quick " / "brown fox": the space stays on line 0
+        let text = "the quick brown fox";
+        let glyphs = text.chars().enumerate().map(|(index, _)| (usize::from(index >= 10), index));
+        assert_eq!(visual_line_starts(text, glyphs), vec![0, 10]);
+
+        // Home/End stay on the visual line, End before the wrapping space
+        let mut lines = VisualLines { starts: vec![0, 10], goal: None };
+        assert_eq!(apply_line_navigation(NavigationAction::LineEnd, 2, text, &mut lines), Some(9));
+        assert_eq!(apply_line_navigation(NavigationAction::LineStart, 14, text, &mut lines), Some(10));
+        assert_eq!(apply_line_navigation(NavigationAction::LineEnd, 14, text, &mut lines), Some(19));
+    }
+
+    #[test]
+    fn empty_hard_lines_get_their_own_start() {
+        // "ab", an empty line, then "cd" - the empty line has no glyphs
+        let text = "ab\n\ncd";
+        let glyphs = [(0, 0), (0, 1), (2, 4), (2, 5)];
+        assert_eq!(visual_line_starts(text, glyphs), vec![0, 3, 4]);
+    }
+
+    #[test]
+    fn vertical_moves_keep_the_goal_column() {
+        let text = "abcdef\nab\nabcdef";
+        let mut lines = VisualLines { starts: hard_line_starts(text), goal: None };
+        assert_eq!(lines.starts, vec![0, 7, 10]);
+
+        // Column 5 clamps to the end of the short middle line...
+        let middle = apply_line_navigation(NavigationAction::LineDown, 5, text, &mut lines);
+        assert_eq!(middle, Some(9));
+        // ...and comes back to column 5 on the next line
+        assert_eq!(apply_line_navigation(NavigationAction::LineDown, 9, text, &mut lines), Some(15));
+        assert_eq!(apply_line_navigation(NavigationAction::LineDown, 15, text, &mut lines), None);
+
+        // Moving the cursor some other way drops the goal
+        lines.goal = Some((5, 9));
+        assert_eq!(apply_line_navigation(NavigationAction::LineUp, 8, text, &mut lines), Some(1));
+    }
+
+    #[test]
+    fn words_split_on_whitespace_like_ctrl_arrows() {
+        let text = "hello, world  again";
+        assert_eq!(word_range_at(text, 2), (0, 6));
+        assert_eq!(word_range_at(text, 6), (6, 7));
+        assert_eq!(word_range_at(text, 12), (12, 14));
+        // Past the end, the last word
+        assert_eq!(word_range_at(text, 40), (14, 19));
+        assert_eq!(word_range_at("", 0), (0, 0));
+
+        // Ctrl+Right from a word's start lands on the next word's start
+        assert_eq!(apply_navigation(NavigationAction::WordRight, 0, text), word_range_at(text, 7).0);
+    }
+
+    #[test]
+    fn word_navigation_deletion_and_selection_agree() {
+        let text = "let x = foo.bar(1);  // done";
+        let (start, end) = word_range_at(text, 10);
+        assert_eq!((start, end), (8, 19));
+
+        // Ctrl+Left from inside the word and Ctrl+Backspace stop at its start
+        assert_eq!(apply_navigation(NavigationAction::WordLeft, 12, text), start);
+        let mut buffer = TextBuffer {
+            content: text.to_string(),
+            cursor_pos: end,
+            is_focused: true,
+        };
+        let mut selection = SelectionState::default();
+        apply_edit(&EditAction::DeleteWordBackward, &mut buffer, &mut selection);
+        assert_eq!(buffer.content, "let x =   // done");
+
+        // Ctrl+Right from its start goes past it and the whitespace after
+        assert_eq!(apply_navigation(NavigationAction::WordRight, start, text), 21);
+    }
+
+    #[test]
+    fn drags_extend_by_whole_units() {
+        let text = "one two three\nfour five";
+        let word = click_unit_at(text, 5, 2);
+        assert_eq!(word, (4, 7));
+
+        // Forward into "three" takes all of it, backward into "one" too
+        assert_eq!(extend_by_unit(text, word, 2, 9), (4, 13));
+        assert_eq!(extend_by_unit(text, word, 2, 1), (7, 0));
+        assert_eq!(extend_by_unit(text, word, 2, 6), (4, 7));
+
+        // Triple clicks select hard lines
+        let line = click_unit_at(text, 2, 3);
+        assert_eq!(line, (0, 13));
+        assert_eq!(extend_by_unit(text, line, 3, 16), (0, 23));
+    }
+
+    #[test]
+    fn replacing_content_clamps_the_cursor() {
+        let mut buffer = TextBuffer {
+            content: "Sir Reginald".to_string(),
+            cursor_pos: 12,
+            is_focused: true,
+        };
+        let mut selection = SelectionState::default();
+        selection.start_selection(0);
+        selection.update_selection(3);
+
+        let op = replace_content(&mut buffer, &mut selection, "Bob").expect("text changed");
+        assert_eq!((buffer.content.as_str(), buffer.cursor_pos), ("Bob", 3));
+        assert!(!selection.has_selection());
+        assert_eq!((op.cursor_before, op.cursor_after), (12, 3));
+        assert!(matches!(op.op_type, OperationType::Replace { pos: 0, ref old, .. } if old == "Sir Reginald"));
+
+        assert!(replace_content(&mut buffer, &mut selection, "Bob").is_none());
+    }
+
+    #[test]
+    fn edits_apply_at_every_caret() {
+        // Cursor at the end of the first line, a second caret at the end of the text
+        let mut buffer = TextBuffer {
+            content: "let a\nlet b".to_string(),
+            cursor_pos: 5,
+            is_focused: true,
+        };
+        let mut carets = vec![11];
+
+        apply_edit_at_carets(&EditAction::InsertChar('1'), &mut buffer, &mut carets);
+        let op = apply_edit_at_carets(&EditAction::InsertChar('2'), &mut buffer, &mut carets)
+            .expect("text changed");
+        assert_eq!(buffer.content, "let a12\nlet b12");
+        assert_eq!((buffer.cursor_pos, carets.as_slice()), (7, &[15][..]));
+        assert_eq!((op.cursor_before, op.cursor_after), (6, 7));
+
+        // Backspace at both, then carets that meet merge
+        apply_edit_at_carets(&EditAction::DeleteBackward, &mut buffer, &mut carets);
+        assert_eq!(buffer.content, "let a1\nlet b1");
+        let mut buffer = TextBuffer {
+            content: "ab".to_string(),
+            cursor_pos: 2,
+            is_focused: true,
+        };
+        let mut carets = vec![1];
+        apply_edit_at_carets(&EditAction::DeleteBackward, &mut buffer, &mut carets);
+        apply_edit_at_carets(&EditAction::DeleteBackward, &mut buffer, &mut carets);
+        assert_eq!((buffer.content.as_str(), buffer.cursor_pos), ("", 0));
+        assert!(carets.is_empty());
+    }
+}
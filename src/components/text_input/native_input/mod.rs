@@ -23,15 +23,28 @@ pub use components::{
     CursorVisual,
     TextInputSettings,
     TextInputInner,
+    TextInputClip,
     ScrollViewport,
+    VisualLines,
+    MultiClick,
+    ExtraCarets,
     UndoHistory,
+    InputHistory,
     EditOperation,
     TextInputSelection,
+    DEFAULT_UNDO_DEPTH,
+    DEFAULT_UNDO_COALESCE_WINDOW,
+    DEFAULT_CURSOR_BLINK_RATE,
+    DEFAULT_CURSOR_WIDTH,
+    DEFAULT_MASK_CHAR,
+    DEFAULT_INPUT_HISTORY_SIZE,
+    MULTI_CLICK_INTERVAL,
 };
 
 pub use types::{
     CursorStyle,
     TabBehavior,
+    SubmitKey,
     NavigationAction,
     EditAction,
     OperationType,
@@ -40,8 +53,14 @@ pub use types::{
 pub use events::{
     TextInputSubmitEvent,
     TextInputChangeEvent,
+    TextInputSelectionChangedEvent,
+    TextInputFocusEvent,
+    SetTextInputValue,
 };
 
+// Focus changes shared with widgets that embed an input
+pub(crate) use helpers::{focus_input, unfocus_input};
+
 // System exports for plugin registration
 pub use systems::{
     init_text_input,
@@ -54,13 +73,23 @@ pub use systems::{
     update_cursor_blink,
     render_text,
     render_selection,
+    render_carets,
+    scroll_text_inputs,
+    update_visual_lines,
+    handle_set_value,
     update_focus_visual,
+    emit_selection_changes,
+    emit_focus_changes,
 };
 
 // Helper function exports (if needed externally)
 pub use helpers::{
     char_to_byte_index,
     apply_navigation,
+    apply_line_navigation,
     apply_edit,
+    apply_edit_at_carets,
+    replace_content,
+    word_range_at,
     get_selected_text,
 };
\ No newline at end of file
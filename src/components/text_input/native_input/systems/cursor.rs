@@ -6,15 +6,27 @@ use super::super::components::*;
 
 /// Update cursor blinking
 pub fn update_cursor_blink(
-    mut text_inputs: Query<(&TextBuffer, &mut CursorVisual, Ref<TextBuffer>), With<NativeTextInput>>,
+    mut text_inputs: Query<(&TextBuffer, &mut CursorVisual, &TextInputSettings, Ref<TextBuffer>), With<NativeTextInput>>,
     time: Res<Time>,
 ) {
-    for (buffer, mut cursor, buffer_ref) in text_inputs.iter_mut() {
+    for (buffer, mut cursor, settings, buffer_ref) in text_inputs.iter_mut() {
         if !buffer.is_focused {
             cursor.visible = false;
             continue;
         }
 
+        // A non-positive rate keeps the cursor solid
+        if settings.cursor_blink_rate <= 0.0 {
+            cursor.visible = true;
+            continue;
+        }
+
+        // Each blink is one visible and one hidden phase
+        let phase = std::time::Duration::from_secs_f32(0.5 / settings.cursor_blink_rate);
+        if cursor.blink_timer.duration() != phase {
+            cursor.blink_timer.set_duration(phase);
+        }
+
         // If just gained focus, make cursor immediately visible
         if buffer_ref.is_changed() && buffer.is_focused {
             cursor.visible = true;
@@ -0,0 +1,46 @@
+//! Focus change notification system
+
+use std::collections::HashSet;
+use bevy::prelude::*;
+
+use super::super::components::*;
+use super::super::events::TextInputFocusEvent;
+
+/// Send a `TextInputFocusEvent` when an input gains or loses focus
+///
+/// Runs after the systems that move focus (clicks, clicking outside, Tab
+/// navigation) and compares against the focus it last reported, so each
+/// transition is sent exactly once. All losses of a frame are sent before its
+/// gains, so a reader sees focus leave the old field before it reaches the
+/// new one.
+pub fn emit_focus_changes(
+    inputs: Query<(Entity, &TextBuffer), (Changed<TextBuffer>, With<NativeTextInput>)>,
+    mut removed: RemovedComponents<TextBuffer>,
+    mut focused: Local<HashSet<Entity>>,
+    mut focus_events: MessageWriter<TextInputFocusEvent>,
+) {
+    for entity in removed.read() {
+        if focused.remove(&entity) {
+            focus_events.write(TextInputFocusEvent { entity, focused: false });
+        }
+    }
+
+    let mut gained = Vec::new();
+    for (entity, buffer) in inputs.iter() {
+        if buffer.is_focused == focused.contains(&entity) {
+            continue;
+        }
+
+        if buffer.is_focused {
+            focused.insert(entity);
+            gained.push(entity);
+        } else {
+            focused.remove(&entity);
+            focus_events.write(TextInputFocusEvent { entity, focused: false });
+        }
+    }
+
+    for entity in gained {
+        focus_events.write(TextInputFocusEvent { entity, focused: true });
+    }
+}
@@ -1,72 +1,133 @@
 //! Text input initialization systems
 
 use bevy::prelude::*;
+use bevy::text::TextBackgroundColor;
 use bevy::ui::{FocusPolicy, RelativeCursorPosition};
 
 use super::super::components::*;
-use super::super::types::CursorStyle;
 
 /// Initialize text input when spawned (observer for initial setup)
 pub fn init_text_input(
     trigger: On<Add, NativeTextInput>,
     mut commands: Commands,
+    settings: Query<&TextInputSettings>,
 ) {
     let entity = trigger.entity;
+    let multiline = settings.get(entity).is_ok_and(|settings| settings.multiline);
 
     // Add default components if not present
-    // Note: TextBuffer and TextInputVisual are set by builder, don't override
-    commands.entity(entity).try_insert((
+    // Note: TextBuffer, TextInputVisual and TextInputSettings are set by builder, don't override
+    commands.entity(entity).try_insert_if_new((
         SelectionState::default(),
         ScrollViewport::default(),
+        VisualLines::default(),
+        MultiClick::default(),
+        ExtraCarets::default(),
         UndoHistory::default(),
         TextInputSettings::default(),
         Interaction::default(),
         FocusPolicy::Block,
-        RelativeCursorPosition::default(),
     ));
 
     // Spawn text container with 3-span structure for embedded cursor
     // Initial content will be set by sync_initial_text_content system
-    commands.entity(entity).with_children(|parent| {
-        parent.spawn((
-            Text::default(),
-            TextInputInner,
-            Name::new("TextInputInner"),
-        ))
-        .with_children(|text_parent| {
-            // Pre-cursor text (will be populated by sync system)
-            text_parent.spawn((
-                TextSpan::new(""),
-                TextFont::default(),
-                TextColor(Color::WHITE),
-                Name::new("PreCursor"),
-            ));
-
-            // Cursor character
-            text_parent.spawn((
-                TextSpan::new(""),
-                TextFont::default(),
-                TextColor(Color::WHITE),
-                Name::new("Cursor"),
-            ));
-
-            // Post-cursor text
-            text_parent.spawn((
-                TextSpan::new(""),
-                TextFont::default(),
-                TextColor(Color::WHITE),
-                Name::new("PostCursor"),
-            ));
+    commands.entity(entity).with_children(|input| {
+        // The clip fills the input's content box. Single-line text is
+        // centered and only clipped horizontally; multiline text starts at
+        // the top and scrolls vertically, so it's clipped on both axes.
+        // Children of the input itself, like a floating label, stay unclipped
+        let (alignment, overflow) = if multiline {
+            (AlignItems::FlexStart, Overflow::clip())
+        } else {
+            (AlignItems::Center, Overflow::clip_x())
+        };
+        let mut clip = input.spawn((
+            Node {
+                flex_grow: 1.0,
+                flex_basis: Val::Px(0.0),
+                min_width: Val::Px(0.0),
+                align_self: AlignSelf::Stretch,
+                justify_content: JustifyContent::Start,
+                align_items: alignment,
+                overflow,
+                ..default()
+            },
+            TextInputClip,
+            Name::new("TextInputClip"),
+        ));
+
+        // Single-line text is laid out on one line at its full width;
+        // multiline text wraps at the input's width and grows downward.
+        // scroll_text_inputs offsets it to keep the cursor in view
+        let (layout, node) = if multiline {
+            (
+                TextLayout::new_with_linebreak(LineBreak::WordBoundary),
+                Node {
+                    width: Val::Percent(100.0),
+                    flex_shrink: 0.0,
+                    ..default()
+                },
+            )
+        } else {
+            (
+                TextLayout::new_with_no_wrap(),
+                Node {
+                    flex_shrink: 0.0,
+                    ..default()
+                },
+            )
+        };
+
+        clip.with_children(|parent| {
+            parent.spawn((
+                Text::default(),
+                layout,
+                node,
+                RelativeCursorPosition::default(),
+                TextInputInner,
+                Name::new("TextInputInner"),
+            ))
+            .with_children(|text_parent| {
+                // Pre-cursor text (will be populated by sync system)
+                text_parent.spawn((
+                    TextSpan::new(""),
+                    TextFont::default(),
+                    TextColor(Color::WHITE),
+                    Name::new("PreCursor"),
+                ));
+
+                // Cursor character (the background paints block cursors)
+                text_parent.spawn((
+                    TextSpan::new(""),
+                    TextFont::default(),
+                    TextColor(Color::WHITE),
+                    TextBackgroundColor(Color::NONE),
+                    Name::new("Cursor"),
+                ));
+
+                // Post-cursor text
+                text_parent.spawn((
+                    TextSpan::new(""),
+                    TextFont::default(),
+                    TextColor(Color::WHITE),
+                    Name::new("PostCursor"),
+                ));
+
+                // Rest of a ghost suggestion
+                text_parent.spawn((
+                    TextSpan::new(""),
+                    TextFont::default(),
+                    TextColor(Color::WHITE),
+                    Name::new("Ghost"),
+                ));
+            });
         });
     });
 
-    // Add CursorVisual component
-    commands.entity(entity).insert(CursorVisual {
-        cursor_entity: None,
+    // Add CursorVisual component (the builder may already have set a style)
+    commands.entity(entity).try_insert_if_new(CursorVisual {
         visible: false,
-        blink_timer: Timer::from_seconds(0.5, TimerMode::Repeating),
-        style: CursorStyle::Line,
-        selection_entities: Vec::new(),
+        ..default()
     });
 }
 
@@ -77,12 +138,16 @@ pub fn sync_initial_text_content(
         (&TextBuffer, &TextInputVisual, &Children),
         (Added<CursorVisual>, With<NativeTextInput>)
     >,
+    clips: Query<&Children, With<TextInputClip>>,
     text_inner_query: Query<&Children, With<TextInputInner>>,
     mut text_span_query: Query<(&mut TextSpan, &mut TextFont, &mut TextColor)>,
 ) {
     for (buffer, visual, children) in &text_inputs {
-        // Find TextInputInner entity
-        for child in children.iter() {
+        // Find TextInputInner entity, inside the clip
+        let Some(clip_children) = children.iter().find_map(|child| clips.get(child).ok()) else {
+            continue;
+        };
+        for child in clip_children.iter() {
             if let Ok(text_spans) = text_inner_query.get(child) {
                 let text_spans_vec: Vec<Entity> = text_spans.iter().collect();
 
@@ -90,12 +155,13 @@ pub fn sync_initial_text_content(
                     // Initialize pre-cursor span with initial content
                     if let Ok((mut span, mut font, mut color)) = text_span_query.get_mut(text_spans_vec[0]) {
                         *span = TextSpan::new(buffer.content.clone());
-                        *font = visual.font.clone();
-                        color.0 = if buffer.content.is_empty() {
-                            visual.placeholder_color
+                        if buffer.content.is_empty() {
+                            *font = visual.placeholder_text_font();
+                            color.0 = visual.placeholder_color;
                         } else {
-                            visual.text_color
-                        };
+                            *font = visual.font.clone();
+                            color.0 = visual.text_color;
+                        }
                     }
 
                     // Initialize cursor span with correct font
@@ -113,4 +179,34 @@ pub fn sync_initial_text_content(
             }
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_the_text_is_clipped() {
+        let mut app = App::new();
+        app.add_observer(init_text_input);
+
+        let input = app.world_mut().spawn((
+            Node::default(),
+            NativeTextInput,
+            TextInputSettings { multiline: true, ..default() },
+        )).id();
+        app.update();
+
+        let world = app.world_mut();
+        assert!(world.get::<Node>(input).unwrap().overflow.is_visible());
+
+        let (clip, clip_node, clip_parent) = world
+            .query_filtered::<(Entity, &Node, &ChildOf), With<TextInputClip>>()
+            .single(world)
+            .expect("one clip");
+        assert_eq!(clip_parent.parent(), input);
+        assert_eq!(clip_node.overflow, Overflow::clip());
+
+        let inner_parent = world.query_filtered::<&ChildOf, With<TextInputInner>>().single(world).unwrap();
+        assert_eq!(inner_parent.parent(), clip);
+    }
+}
@@ -0,0 +1,75 @@
+//! Editing at several carets at once (see `ExtraCarets`)
+
+use bevy::prelude::*;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use unicode_segmentation::UnicodeSegmentation;
+use super::super::super::super::components::{TextBuffer, ExtraCarets, UndoHistory, TextInputSettings};
+use super::super::super::super::helpers::apply_edit_at_carets;
+use super::super::super::super::types::{EditAction, OperationType};
+use crate::components::text_input::types::TextInputFilter;
+
+/// The edit a key makes at every caret, for the keys extra carets survive
+///
+/// `modified` is whether Ctrl, Alt or Cmd is held, and `newline` whether
+/// Enter inserts a newline rather than submitting.
+pub fn caret_edit(event: &KeyboardInput, modified: bool, shift: bool, newline: bool) -> Option<EditAction> {
+    if modified {
+        return None;
+    }
+
+    match (&event.logical_key, event.key_code) {
+        (_, KeyCode::Backspace) if !shift => Some(EditAction::DeleteBackward),
+        (_, KeyCode::Delete) if !shift => Some(EditAction::DeleteForward),
+        (_, KeyCode::Enter) if newline => Some(EditAction::InsertChar('\n')),
+        (Key::Space, _) => Some(EditAction::InsertChar(' ')),
+        (Key::Character(text), _) => Some(EditAction::InsertString(text.to_string())),
+        _ => None,
+    }
+}
+
+/// Whether a key is only a modifier, which leaves the carets alone
+pub fn is_modifier(key: &Key) -> bool {
+    matches!(
+        key,
+        Key::Shift | Key::Control | Key::Alt | Key::AltGraph | Key::Super | Key::Meta | Key::CapsLock
+    )
+}
+
+/// Apply an edit at the cursor and every extra caret, as one undo step
+///
+/// Like typing at a single cursor, insertions that would go past the max
+/// length or that the input's filter rejects are dropped, and the rest merge
+/// into one undo step (see `UndoHistory::push_typed`); `now` is the current
+/// time in seconds.
+pub fn handle_caret_edit(
+    action: &EditAction,
+    buffer: &mut TextBuffer,
+    carets: &mut ExtraCarets,
+    history: &mut UndoHistory,
+    settings: &TextInputSettings,
+    filter: Option<&TextInputFilter>,
+    now: f64,
+) {
+    let positions_before = carets.positions.clone();
+    let Some(op) = apply_edit_at_carets(action, buffer, &mut carets.positions) else {
+        return;
+    };
+
+    let inserting = matches!(action, EditAction::InsertChar(_) | EditAction::InsertString(_));
+    let too_long = settings.max_length.is_some_and(|max| buffer.content.graphemes(true).count() > max);
+    let rejected = filter.is_some_and(|filter| !filter.filter_type.is_valid_string(&buffer.content));
+    if inserting && (too_long || rejected) {
+        if let OperationType::Replace { old, .. } = op.op_type {
+            buffer.content = old;
+        }
+        buffer.cursor_pos = op.cursor_before;
+        carets.positions = positions_before;
+        return;
+    }
+
+    if inserting {
+        history.push_typed(op, now);
+    } else {
+        history.push(op);
+    }
+}
@@ -6,35 +6,9 @@
 use super::super::super::super::components::{TextBuffer, SelectionState, UndoHistory};
 use super::super::super::super::helpers::{apply_edit, get_selected_text};
 use super::super::super::super::types::EditAction;
-
-/// Copy text to system clipboard (if available)
-#[cfg(feature = "clipboard")]
-fn copy_to_clipboard(text: &str) {
-    if let Ok(mut clipboard) = arboard::Clipboard::new() {
-        let _ = clipboard.set_text(text);
-    }
-}
-
-/// Copy text to system clipboard (no-op when clipboard unavailable)
-#[cfg(not(feature = "clipboard"))]
-fn copy_to_clipboard(_text: &str) {
-    // Clipboard not available (WASM or feature disabled)
-}
-
-/// Get text from system clipboard (if available)
-#[cfg(feature = "clipboard")]
-fn get_from_clipboard() -> Option<String> {
-    arboard::Clipboard::new()
-        .ok()
-        .and_then(|mut cb| cb.get_text().ok())
-}
-
-/// Get text from system clipboard (returns None when clipboard unavailable)
-#[cfg(not(feature = "clipboard"))]
-fn get_from_clipboard() -> Option<String> {
-    // Clipboard not available (WASM or feature disabled)
-    None
-}
+use super::editing::filter_allows;
+use crate::components::text_input::types::TextInputFilter;
+use crate::utils::clipboard::{copy_to_clipboard, get_from_clipboard};
 
 /// Handle cut operation (Ctrl+X)
 pub fn handle_cut(
@@ -50,8 +24,7 @@ pub fn handle_cut(
 
         // Delete the selected text
         if let Some(op) = apply_edit(&EditAction::DeleteSelection, buffer, selection) {
-            history.undo_stack.push_back(op);
-            history.redo_stack.clear();
+            history.push(op);
         }
     }
 }
@@ -69,16 +42,22 @@ pub fn handle_copy(
 }
 
 /// Handle paste operation (Ctrl+V)
+///
+/// Pastes the input's filter rejects are dropped whole.
 pub fn handle_paste(
     buffer: &mut TextBuffer,
     selection: &mut SelectionState,
     history: &mut UndoHistory,
+    filter: Option<&TextInputFilter>,
 ) {
     if let Some(text) = get_from_clipboard() {
+        if !filter_allows(filter, buffer, selection, &text) {
+            return;
+        }
+
         // Apply paste operation
         if let Some(op) = apply_edit(&EditAction::PasteFromClipboard(text), buffer, selection) {
-            history.undo_stack.push_back(op);
-            history.redo_stack.clear();
+            history.push(op);
         }
     }
 }
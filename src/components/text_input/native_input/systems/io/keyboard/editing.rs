@@ -2,83 +2,107 @@
 
 use bevy::prelude::*;
 use bevy::input::keyboard::Key;
-use super::super::super::super::components::{TextBuffer, SelectionState, UndoHistory, TextInputSettings};
+use unicode_segmentation::UnicodeSegmentation;
+use super::super::super::super::components::{TextBuffer, SelectionState, UndoHistory, EditOperation, TextInputSettings};
 use super::super::super::super::helpers::apply_edit;
 use super::super::super::super::types::EditAction;
+use crate::components::text_input::types::TextInputFilter;
+
+/// Whether the input's filter lets `inserted` replace the selection (or go
+/// in at the cursor)
+pub fn filter_allows(
+    filter: Option<&TextInputFilter>,
+    buffer: &TextBuffer,
+    selection: &SelectionState,
+    inserted: &str,
+) -> bool {
+    let range = selection.range().unwrap_or((buffer.cursor_pos, buffer.cursor_pos));
+    filter.is_none_or(|filter| filter.filter_type.allows_insert(&buffer.content, range, inserted))
+}
+
+/// Whether `inserted` in place of the selection (or at the cursor) would take
+/// the input past its max length, counted in graphemes
+pub fn exceeds_max_length(
+    settings: &TextInputSettings,
+    buffer: &TextBuffer,
+    selection: &SelectionState,
+    inserted: &str,
+) -> bool {
+    let Some(max) = settings.max_length else {
+        return false;
+    };
+    let (start, end) = selection.range().unwrap_or((buffer.cursor_pos, buffer.cursor_pos));
+    let candidate: String = buffer.content.chars().take(start)
+        .chain(inserted.chars())
+        .chain(buffer.content.chars().skip(end))
+        .collect();
+    candidate.graphemes(true).count() > max
+}
 
 /// Handle character input
+///
+/// Characters the input's filter rejects at the cursor are dropped. Typed
+/// characters merge into one undo step (see `UndoHistory::push_typed`), except
+/// those typed over a selection; `now` is the current time in seconds.
 pub fn handle_character_input(
     key: &Key,
     buffer: &mut TextBuffer,
     selection: &mut SelectionState,
     history: &mut UndoHistory,
     settings: &TextInputSettings,
+    filter: Option<&TextInputFilter>,
+    now: f64,
 ) {
     match key {
         Key::Character(text) => {
-            info!("Received character input: '{}'", text);
-
             for ch in text.chars() {
-                // Check max length before each character insertion
-                if let Some(max) = settings.max_length {
-                    let current_len = buffer.content.chars().count();
-                    let selection_len = if selection.has_selection() {
-                        if let Some((start, end)) = selection.range() {
-                            end - start
-                        } else {
-                            0
-                        }
-                    } else {
-                        0
-                    };
-
-                    // Calculate what the new length would be after insertion
-                    let new_len = current_len - selection_len + 1;
-                    if new_len > max {
-                        info!("Max length {} reached, ignoring character", max);
-                        return;  // Don't insert if it would exceed max length
-                    }
+                // Check max length before each character insertion; combining
+                // marks and joiners extend the last grapheme and still fit
+                if exceeds_max_length(settings, buffer, selection, ch.encode_utf8(&mut [0; 4])) {
+                    info!("Max length reached, ignoring character");
+                    return;  // Don't insert if it would exceed max length
+                }
+
+                if !filter_allows(filter, buffer, selection, ch.encode_utf8(&mut [0; 4])) {
+                    continue;
                 }
 
-                info!("Processing character: '{}', buffer before: '{}', cursor: {}", ch, buffer.content, buffer.cursor_pos);
+                let replaces_selection = selection.has_selection();
                 if let Some(op) = apply_edit(&EditAction::InsertChar(ch), buffer, selection) {
-                    history.undo_stack.push_back(op);
-                    history.redo_stack.clear();
-                    info!("After edit - buffer: '{}', cursor: {}", buffer.content, buffer.cursor_pos);
+                    record_typed(history, op, replaces_selection, now);
                 }
             }
         }
         Key::Space => {
             // Check max length for space as well
-            if let Some(max) = settings.max_length {
-                let current_len = buffer.content.chars().count();
-                let selection_len = if selection.has_selection() {
-                    if let Some((start, end)) = selection.range() {
-                        end - start
-                    } else {
-                        0
-                    }
-                } else {
-                    0
-                };
-
-                let new_len = current_len - selection_len + 1;
-                if new_len > max {
-                    info!("Max length {} reached, ignoring space", max);
-                    return;
-                }
+            if exceeds_max_length(settings, buffer, selection, " ") {
+                info!("Max length reached, ignoring space");
+                return;
+            }
+
+            if !filter_allows(filter, buffer, selection, " ") {
+                return;
             }
 
             // Handle space separately since it's not a Character variant
+            let replaces_selection = selection.has_selection();
             if let Some(op) = apply_edit(&EditAction::InsertChar(' '), buffer, selection) {
-                history.undo_stack.push_back(op);
-                history.redo_stack.clear();
+                record_typed(history, op, replaces_selection, now);
             }
         }
         _ => {}
     }
 }
 
+/// Record a typed character, as its own undo step if it replaced a selection
+fn record_typed(history: &mut UndoHistory, op: EditOperation, replaces_selection: bool, now: f64) {
+    if replaces_selection {
+        history.push(op);
+    } else {
+        history.push_typed(op, now);
+    }
+}
+
 /// Handle backspace deletion
 pub fn handle_backspace(
     buffer: &mut TextBuffer,
@@ -86,8 +110,7 @@ pub fn handle_backspace(
     history: &mut UndoHistory,
 ) {
     if let Some(op) = apply_edit(&EditAction::DeleteBackward, buffer, selection) {
-        history.undo_stack.push_back(op);
-        history.redo_stack.clear();
+        history.push(op);
     }
 }
 
@@ -98,8 +121,7 @@ pub fn handle_delete(
     history: &mut UndoHistory,
 ) {
     if let Some(op) = apply_edit(&EditAction::DeleteForward, buffer, selection) {
-        history.undo_stack.push_back(op);
-        history.redo_stack.clear();
+        history.push(op);
     }
 }
 
@@ -110,8 +132,7 @@ pub fn handle_delete_word_backward(
     history: &mut UndoHistory,
 ) {
     if let Some(op) = apply_edit(&EditAction::DeleteWordBackward, buffer, selection) {
-        history.undo_stack.push_back(op);
-        history.redo_stack.clear();
+        history.push(op);
     }
 }
 
@@ -121,8 +142,7 @@ pub fn handle_delete_word_forward(
     history: &mut UndoHistory,
 ) {
     if let Some(op) = apply_edit(&EditAction::DeleteWordForward, buffer, selection) {
-        history.undo_stack.push_back(op);
-        history.redo_stack.clear();
+        history.push(op);
     }
 }
 
@@ -148,7 +168,7 @@ pub fn handle_delete_to_line_start(
         };
         buffer.cursor_pos = 0;
         selection.clear();
-        history.undo_stack.push_back(op);
+        history.push(op);
     }
 }
 
@@ -173,6 +193,6 @@ pub fn handle_delete_to_line_end(
             cursor_after: buffer.cursor_pos,
         };
         selection.clear();
-        history.undo_stack.push_back(op);
+        history.push(op);
     }
 }
\ No newline at end of file
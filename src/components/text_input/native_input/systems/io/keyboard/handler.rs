@@ -6,7 +6,7 @@ use bevy::input::keyboard::KeyboardInput;
 use super::super::super::super::components::*;
 use super::super::super::super::events::*;
 
-use super::navigation::{handle_arrow_left, handle_arrow_right, handle_home, handle_end};
+use super::navigation::{handle_arrow_left, handle_arrow_right, handle_home, handle_end, handle_line_navigation};
 use super::editing::{
     handle_character_input, handle_backspace, handle_delete,
     handle_delete_word_backward, handle_delete_word_forward,
@@ -15,8 +15,11 @@ use super::editing::{
 use super::selection::handle_select_all;
 use super::clipboard::{handle_cut, handle_copy, handle_paste};
 use super::undo_redo::{handle_undo, handle_redo};
-use super::special::handle_enter;
-use super::super::super::super::types::TabBehavior;
+use super::special::{handle_enter, ghost_completion};
+use super::history::handle_history_recall;
+use super::carets::{caret_edit, handle_caret_edit, is_modifier};
+use super::super::super::super::types::{NavigationAction, TabBehavior};
+use crate::components::text_input::types::{GhostSuggestion, TextInputFilter, TextInputSuggestions};
 
 /// Handle keyboard input for text editing
 pub fn handle_keyboard_input(
@@ -28,8 +31,16 @@ pub fn handle_keyboard_input(
         &mut CursorVisual,
         &mut UndoHistory,
         &TextInputSettings,
+        &TextInputVisual,
+        Option<&TextInputSuggestions>,
+        Option<&mut InputHistory>,
+        Option<&mut VisualLines>,
+        Option<&mut ExtraCarets>,
+        Option<&TextInputFilter>,
+        Option<&GhostSuggestion>,
     ), With<NativeTextInput>>,
     keyboard: Res<ButtonInput<KeyCode>>,
+    time: Res<Time<Real>>,
     mut submit_events: MessageWriter<TextInputSubmitEvent>,
     mut change_events: MessageWriter<TextInputChangeEvent>,
 ) {
@@ -39,30 +50,91 @@ pub fn handle_keyboard_input(
             continue;
         }
 
-        info!("Keyboard event received: {:?}", event.logical_key);
-
         // Check modifiers
         let ctrl = keyboard.pressed(KeyCode::ControlLeft) || keyboard.pressed(KeyCode::ControlRight);
         let shift = keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight);
         let alt = keyboard.pressed(KeyCode::AltLeft) || keyboard.pressed(KeyCode::AltRight);
         let cmd = keyboard.pressed(KeyCode::SuperLeft) || keyboard.pressed(KeyCode::SuperRight);
 
-        for (entity, mut buffer, mut selection, mut cursor_visual, mut history, settings) in text_inputs.iter_mut() {
+        for (entity, mut buffer, mut selection, mut cursor_visual, mut history, settings, visual, suggestions, mut input_history, mut visual_lines, mut carets, filter, ghost) in text_inputs.iter_mut() {
             // Skip if not focused or read-only
             if !buffer.is_focused || settings.read_only {
                 continue;
             }
 
+            // Keep the history bound in sync with the configured depth
+            if history.max_size != settings.max_undo_depth {
+                history.set_max_size(settings.max_undo_depth);
+            }
+            if history.coalesce_window != settings.undo_coalesce_window {
+                history.coalesce_window = settings.undo_coalesce_window;
+            }
+
             // Reset cursor blink on any input
             cursor_visual.blink_timer.reset();
             cursor_visual.visible = true;
 
+            // Extra carets take typing and deletion; any other key goes back
+            // to the main cursor
+            if let Some(carets) = carets.as_deref_mut().filter(|carets| carets.is_active()) {
+                let newline = settings.multiline && !settings.submit_key.submits(ctrl || cmd, shift, alt);
+                if let Some(action) = caret_edit(event, ctrl || alt || cmd, shift, newline) {
+                    handle_caret_edit(&action, &mut buffer, carets, &mut history, settings, filter, time.elapsed_secs_f64());
+                    change_events.write(TextInputChangeEvent {
+                        entity,
+                        text: buffer.content.clone(),
+                    });
+                    continue;
+                }
+                if !is_modifier(&event.logical_key) {
+                    carets.clear();
+                }
+            }
+
+            let ghost_shown = ghost_completion(ghost, &buffer, &selection, visual).is_some();
+
             // Handle navigation keys
             match event.key_code {
                 KeyCode::ArrowLeft => handle_arrow_left(&mut buffer, &mut selection, shift),
                 KeyCode::ArrowRight => handle_arrow_right(&mut buffer, &mut selection, shift),
+                // Multiline inputs navigate by wrapped line
+                KeyCode::Home | KeyCode::End if settings.multiline => {
+                    let action = if event.key_code == KeyCode::Home {
+                        NavigationAction::LineStart
+                    } else {
+                        NavigationAction::LineEnd
+                    };
+                    if let Some(lines) = visual_lines.as_deref_mut() {
+                        handle_line_navigation(action, &mut buffer, &mut selection, lines, shift);
+                    }
+                }
                 KeyCode::Home => handle_home(&mut buffer, &mut selection, shift),
                 KeyCode::End => handle_end(&mut buffer, &mut selection, shift),
+                // Up/Down move the suggestion highlight instead while the menu is open
+                KeyCode::ArrowUp | KeyCode::ArrowDown
+                    if settings.multiline && !suggestions.is_some_and(TextInputSuggestions::is_open) =>
+                {
+                    let older = event.key_code == KeyCode::ArrowUp;
+                    let action = if older { NavigationAction::LineUp } else { NavigationAction::LineDown };
+                    let moved = visual_lines.as_deref_mut().is_some_and(|lines| {
+                        handle_line_navigation(action, &mut buffer, &mut selection, lines, shift)
+                    });
+
+                    // Past the first or last line, Up/Down recall submitted entries
+                    if !moved && !shift {
+                        if let Some(input_history) = input_history.as_deref_mut() {
+                            handle_history_recall(older, &mut buffer, &mut selection, &mut history, input_history);
+                        }
+                    }
+                }
+                KeyCode::ArrowUp | KeyCode::ArrowDown
+                    if !shift && !suggestions.is_some_and(TextInputSuggestions::is_open) =>
+                {
+                    if let Some(input_history) = input_history.as_deref_mut() {
+                        let older = event.key_code == KeyCode::ArrowUp;
+                        handle_history_recall(older, &mut buffer, &mut selection, &mut history, input_history);
+                    }
+                }
                 KeyCode::Backspace if !ctrl && !shift => handle_backspace(&mut buffer, &mut selection, &mut history),
                 KeyCode::Delete if !ctrl && !shift => handle_delete(&mut buffer, &mut selection, &mut history),
                 KeyCode::Backspace if ctrl && !shift => handle_delete_word_backward(&mut buffer, &mut selection, &mut history),
@@ -73,20 +145,31 @@ pub fn handle_keyboard_input(
                 KeyCode::KeyZ if ctrl && !shift => handle_undo(&mut buffer, &mut selection, &mut history),
                 KeyCode::KeyY if ctrl => handle_redo(&mut buffer, &mut selection, &mut history),
                 KeyCode::KeyZ if ctrl && shift => handle_redo(&mut buffer, &mut selection, &mut history),
+                // Masked text never reaches the clipboard
+                KeyCode::KeyX | KeyCode::KeyC if ctrl && visual.mask_char.is_some() => {}
                 KeyCode::KeyX if ctrl => handle_cut(&mut buffer, &mut selection, &mut history),
                 KeyCode::KeyC if ctrl => handle_copy(&buffer, &selection),
-                KeyCode::KeyV if ctrl => handle_paste(&mut buffer, &mut selection, &mut history),
+                KeyCode::KeyV if ctrl => handle_paste(&mut buffer, &mut selection, &mut history, filter),
+                // Tab fills in a shown ghost suggestion instead (handle_tab_navigation)
+                KeyCode::Tab if !shift && ghost_shown && !suggestions.is_some_and(TextInputSuggestions::is_open) => {
+                    continue;
+                }
                 KeyCode::Tab if settings.tab_behavior == TabBehavior::NextField => {
                     // Tab navigation is handled in a separate system
                     continue;
                 }
+                // Enter accepts the highlighted suggestion instead (handle_suggestion_keys)
+                KeyCode::Enter if suggestions.is_some_and(|suggestions| suggestions.highlighted.is_some()) => {
+                    continue;
+                }
                 KeyCode::Enter => {
-                    handle_enter(entity, &mut buffer, &mut selection, settings, &mut history, &mut submit_events);
+                    let submits = settings.submit_key.submits(ctrl || cmd, shift, alt);
+                    handle_enter(entity, &mut buffer, &mut selection, settings, submits, &mut history, input_history.as_deref_mut(), &mut submit_events);
                 }
                 _ => {
                     // Handle regular character input
                     if !ctrl && !alt && !cmd {
-                        handle_character_input(&event.logical_key, &mut buffer, &mut selection, &mut history, settings);
+                        handle_character_input(&event.logical_key, &mut buffer, &mut selection, &mut history, settings, filter, time.elapsed_secs_f64());
                     }
                 }
             }
@@ -0,0 +1,49 @@
+//! Submitted-entry recall (Up/Down)
+
+use super::super::super::super::components::{TextBuffer, SelectionState, UndoHistory, EditOperation, InputHistory};
+use super::super::super::super::types::OperationType;
+
+/// Replace the text with an older (Up) or newer (Down) submitted entry
+///
+/// Only leaves the current line's bounds: in multiline text, Up recalls from
+/// the first line and Down from the last. The replacement can be undone.
+pub fn handle_history_recall(
+    older: bool,
+    buffer: &mut TextBuffer,
+    selection: &mut SelectionState,
+    history: &mut UndoHistory,
+    input_history: &mut InputHistory,
+) {
+    let mut chars = buffer.content.chars();
+    let before: String = chars.by_ref().take(buffer.cursor_pos).collect();
+    let crosses_line = if older { before.contains('\n') } else { chars.any(|c| c == '\n') };
+    if crosses_line {
+        return;
+    }
+
+    let recalled = if older {
+        input_history.older(&buffer.content).map(str::to_string)
+    } else {
+        input_history.newer()
+    };
+    let Some(text) = recalled else {
+        return;
+    };
+
+    let cursor_after = text.chars().count();
+    if text != buffer.content {
+        history.push(EditOperation {
+            op_type: OperationType::Replace {
+                pos: 0,
+                old: buffer.content.clone(),
+                new: text.clone(),
+            },
+            cursor_before: buffer.cursor_pos,
+            cursor_after,
+        });
+        buffer.content = text;
+    }
+
+    buffer.cursor_pos = cursor_after;
+    selection.clear();
+}
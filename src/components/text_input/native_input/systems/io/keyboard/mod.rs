@@ -6,8 +6,11 @@ mod selection;
 mod clipboard;
 mod undo_redo;
 mod special;
+mod history;
+mod carets;
 mod handler;
 
 // Re-export main keyboard handling function
 pub use handler::handle_keyboard_input;
-pub use special::handle_tab_navigation;
\ No newline at end of file
+pub use special::handle_tab_navigation;
+pub(crate) use special::ghost_completion;
\ No newline at end of file
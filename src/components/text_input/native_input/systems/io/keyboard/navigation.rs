@@ -1,6 +1,8 @@
 //! Navigation key handling (arrows, home, end)
 
-use super::super::super::super::components::{TextBuffer, SelectionState};
+use super::super::super::super::components::{TextBuffer, SelectionState, VisualLines};
+use super::super::super::super::helpers::apply_line_navigation;
+use super::super::super::super::types::NavigationAction;
 
 /// Handle arrow key navigation
 pub fn handle_arrow_left(
@@ -92,4 +94,32 @@ pub fn handle_end(
         buffer.cursor_pos = buffer.content.chars().count();
         selection.clear();
     }
-}
\ No newline at end of file
+}
+
+/// Handle Up/Down/Home/End over the visual lines of a multiline input
+///
+/// Returns `false` when Up/Down has no line to move to.
+pub fn handle_line_navigation(
+    action: NavigationAction,
+    buffer: &mut TextBuffer,
+    selection: &mut SelectionState,
+    lines: &mut VisualLines,
+    shift: bool,
+) -> bool {
+    let Some(target) = apply_line_navigation(action, buffer.cursor_pos, &buffer.content, lines) else {
+        return false;
+    };
+
+    if shift {
+        // Extend selection to the target
+        if selection.anchor.is_none() {
+            selection.start_selection(buffer.cursor_pos);
+        }
+        buffer.cursor_pos = target;
+        selection.update_selection(target);
+    } else {
+        buffer.cursor_pos = target;
+        selection.clear();
+    }
+    true
+}
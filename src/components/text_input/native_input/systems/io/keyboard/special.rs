@@ -1,28 +1,55 @@
 //! Special key handling (Tab, Enter)
 
 use bevy::prelude::*;
-use super::super::super::super::components::{TextBuffer, SelectionState, TextInputSettings, NativeTextInput, CursorVisual};
-use super::super::super::super::events::TextInputSubmitEvent;
-use super::super::super::super::helpers::apply_edit;
+use unicode_segmentation::UnicodeSegmentation;
+use super::super::super::super::components::{TextBuffer, SelectionState, TextInputSettings, TextInputVisual, NativeTextInput, CursorVisual, InputHistory, UndoHistory};
+use super::super::super::super::events::{TextInputSubmitEvent, TextInputChangeEvent};
+use super::super::super::super::helpers::{apply_edit, focus_input, replace_content, unfocus_input};
 use super::super::super::super::types::{TabBehavior, EditAction};
-use crate::components::text_input::types::TextInputFocus;
+use crate::components::text_input::types::{GhostSuggestion, TextInputFilter, TextInputFocus, TextInputSuggestions};
+
+/// The rest of an input's ghost suggestion, while it is shown
+///
+/// Ghosts follow a focused cursor resting at the end of plain (unmasked)
+/// text with nothing selected.
+pub(crate) fn ghost_completion<'a>(
+    ghost: Option<&'a GhostSuggestion>,
+    buffer: &TextBuffer,
+    selection: &SelectionState,
+    visual: &TextInputVisual,
+) -> Option<&'a str> {
+    let at_end = buffer.cursor_pos == buffer.content.chars().count();
+    ghost
+        .filter(|_| buffer.is_focused && at_end && visual.mask_char.is_none() && !selection.has_selection())
+        .and_then(|ghost| ghost.remainder(&buffer.content))
+}
 
 /// Handle Enter key
+///
+/// Multiline inputs only submit when `submits` (the press matches the
+/// settings' submit key); otherwise Enter inserts a newline.
+#[allow(clippy::too_many_arguments)]
 pub fn handle_enter(
     entity: Entity,
     buffer: &mut TextBuffer,
     selection: &mut SelectionState,
     settings: &TextInputSettings,
-    history: &mut super::super::super::super::components::UndoHistory,
+    submits: bool,
+    history: &mut UndoHistory,
+    input_history: Option<&mut InputHistory>,
     submit_events: &mut MessageWriter<TextInputSubmitEvent>,
 ) {
-    if !settings.multiline {
+    if !settings.multiline || submits {
         // Submit
         submit_events.write(TextInputSubmitEvent {
             entity,
             text: buffer.content.clone(),
         });
 
+        if let Some(input_history) = input_history {
+            input_history.push(&buffer.content);
+        }
+
         if !settings.retain_on_submit {
             buffer.content.clear();
             buffer.cursor_pos = 0;
@@ -31,24 +58,70 @@ pub fn handle_enter(
     } else {
         // Insert newline
         if let Some(op) = apply_edit(&EditAction::InsertChar('\n'), buffer, selection) {
-            history.undo_stack.push_back(op);
-            history.redo_stack.clear();
+            history.push(op);
         }
     }
 }
 
 /// Handle Tab key navigation between text inputs
+///
+/// While the focused input shows a ghost suggestion, Tab fills it in
+/// instead, whatever the input's [`TabBehavior`]. The text becomes the whole
+/// suggestion, so the typed part takes on its case, and it is held to the
+/// same max length and filter as typing; a suggestion they reject is left
+/// unfilled and Tab does nothing.
 pub fn handle_tab_navigation(
     keyboard: Res<ButtonInput<KeyCode>>,
     mut text_inputs: Query<(Entity, &mut TextBuffer, &mut SelectionState, &mut CursorVisual, &TextInputSettings, &TextInputFocus), With<NativeTextInput>>,
+    suggestions: Query<&TextInputSuggestions>,
+    mut ghosts: Query<(&GhostSuggestion, &TextInputVisual, &mut UndoHistory, Option<&TextInputFilter>)>,
+    mut change_events: MessageWriter<TextInputChangeEvent>,
 ) {
     // Check if Tab was just pressed
     if !keyboard.just_pressed(KeyCode::Tab) {
         return;
     }
 
+    // Tab accepts a suggestion while the focused input's menu is open
+    let accepting_suggestion = text_inputs.iter()
+        .filter(|(_, buffer, ..)| buffer.is_focused)
+        .any(|(entity, ..)| suggestions.get(entity).is_ok_and(TextInputSuggestions::is_open));
+    if accepting_suggestion {
+        return;
+    }
+
     let shift_held = keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight);
 
+    // Tab (not Shift+Tab) fills in a shown ghost suggestion
+    if !shift_held {
+        for (entity, mut buffer, mut selection, _, settings, _) in text_inputs.iter_mut() {
+            let Ok((ghost, visual, mut history, filter)) = ghosts.get_mut(entity) else {
+                continue;
+            };
+            if ghost_completion(Some(ghost), &buffer, &selection, visual).is_none() {
+                continue;
+            }
+
+            let suggestion = ghost.0.as_str();
+            let too_long = settings.max_length.is_some_and(|max| suggestion.graphemes(true).count() > max);
+            let rejected = filter.is_some_and(|filter| !filter.filter_type.is_valid_string(suggestion));
+            if too_long || rejected {
+                return;
+            }
+
+            if let Some(mut op) = replace_content(&mut buffer, &mut selection, suggestion) {
+                buffer.cursor_pos = suggestion.chars().count();
+                op.cursor_after = buffer.cursor_pos;
+                history.push(op);
+            }
+            change_events.write(TextInputChangeEvent {
+                entity,
+                text: buffer.content.clone(),
+            });
+            return;
+        }
+    }
+
     // Find currently focused input and its focus group
     let mut current_entity = None;
     let mut current_focus_group = None;
@@ -118,13 +191,9 @@ pub fn handle_tab_navigation(
             // Unfocus current and focus next
             for (entity, mut buffer, mut selection, mut cursor_visual, _, _) in text_inputs.iter_mut() {
                 if entity == current_entity {
-                    buffer.is_focused = false;
-                    selection.clear();
-                    cursor_visual.visible = false;
+                    unfocus_input(&mut buffer, &mut selection, &mut cursor_visual);
                 } else if entity == next_entity {
-                    buffer.is_focused = true;
-                    cursor_visual.visible = true;
-                    cursor_visual.blink_timer.reset();
+                    focus_input(&mut buffer, &mut cursor_visual);
                 }
             }
         }
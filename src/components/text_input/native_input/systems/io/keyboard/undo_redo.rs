@@ -29,11 +29,21 @@ pub fn handle_undo(
                 buffer.content = format!("{}{}{}", before, text, after);
                 buffer.cursor_pos = *position + text.chars().count();
             }
-            OperationType::Replace { .. } => {
-                // Handle replace if needed
+            OperationType::Replace { pos: position, old, new } => {
+                // Put the old text back in place of the new
+                let end_pos = *position + new.chars().count();
+                buffer.content = format!(
+                    "{}{}{}",
+                    buffer.content.chars().take(*position).collect::<String>(),
+                    old,
+                    buffer.content.chars().skip(end_pos).collect::<String>()
+                );
+                buffer.cursor_pos = op.cursor_before;
             }
         }
         history.redo_stack.push_back(op);
+        // Typing after an undo starts a new step
+        history.last_typed = None;
         selection.clear();
     }
 }
@@ -62,11 +72,45 @@ pub fn handle_redo(
                 );
                 buffer.cursor_pos = position;
             }
-            OperationType::Replace { .. } => {
-                // Handle replace if needed
+            OperationType::Replace { pos: position, ref old, ref new } => {
+                let end_pos = position + old.chars().count();
+                buffer.content = format!(
+                    "{}{}{}",
+                    buffer.content.chars().take(position).collect::<String>(),
+                    new,
+                    buffer.content.chars().skip(end_pos).collect::<String>()
+                );
+                buffer.cursor_pos = op.cursor_after;
             }
         }
         history.undo_stack.push_back(op);
+        history.last_typed = None;
         selection.clear();
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::super::super::super::helpers::replace_content;
+
+    #[test]
+    fn replaced_text_undoes_and_redoes() {
+        // What `SetTextInputValue` records
+        let mut buffer = TextBuffer {
+            content: "Sir Reginald".to_string(),
+            cursor_pos: 4,
+            is_focused: true,
+        };
+        let mut selection = SelectionState::default();
+        let mut history = UndoHistory::default();
+        history.push(replace_content(&mut buffer, &mut selection, "Bob").expect("text changed"));
+
+        handle_undo(&mut buffer, &mut selection, &mut history);
+        assert_eq!((buffer.content.as_str(), buffer.cursor_pos), ("Sir Reginald", 4));
+
+        handle_redo(&mut buffer, &mut selection, &mut history);
+        assert_eq!((buffer.content.as_str(), buffer.cursor_pos), ("Bob", 3));
+        assert!(history.redo_stack.is_empty());
+    }
+}
@@ -6,9 +6,14 @@ use bevy::text::TextLayoutInfo;
 use bevy::ui::RelativeCursorPosition;
 
 use super::super::super::super::components::*;
+use super::super::super::super::helpers::{click_unit_at, focus_input, unfocus_input};
 use super::selection::calculate_char_index_from_position;
 
 /// Handle mouse input for selection
+///
+/// A second click on the same character within `MULTI_CLICK_INTERVAL` selects
+/// the word under the pointer, and a third the whole line. Alt+Click in a
+/// multiline input adds a caret (see `ExtraCarets`).
 pub fn handle_mouse_input(
     mut param_set: ParamSet<(
         Query<(
@@ -16,15 +21,20 @@ pub fn handle_mouse_input(
             &mut TextBuffer,
             &mut SelectionState,
             &mut CursorVisual,
+            &mut MultiClick,
+            &mut ExtraCarets,
+            &TextInputSettings,
+            &TextInputVisual,
             &Interaction,
-            &RelativeCursorPosition,
             &Children,
         ), With<NativeTextInput>>,  // Removed Changed<Interaction> to check every frame
         Query<(Entity, &mut TextBuffer, &mut SelectionState, &mut CursorVisual), With<NativeTextInput>>,
     )>,
-    text_query: Query<&TextLayoutInfo, With<TextInputInner>>,
+    clips: Query<&Children, With<TextInputClip>>,
+    text_query: Query<(&TextLayoutInfo, &RelativeCursorPosition), With<TextInputInner>>,
     keyboard: Res<ButtonInput<KeyCode>>,
     mouse: Res<ButtonInput<MouseButton>>,
+    time: Res<Time<Real>>,
 ) {
     // Only process on actual mouse clicks
     if !mouse.just_pressed(MouseButton::Left) {
@@ -35,8 +45,8 @@ pub fn handle_mouse_input(
     let pressed_entities: Vec<Entity> = {
         let p0 = param_set.p0();
         p0.iter()
-            .filter(|(_, _, _, _, interaction, _, _)| **interaction == Interaction::Pressed)
-            .map(|(entity, _, _, _, _, _, _)| entity)
+            .filter(|(_, _, _, _, _, _, _, _, interaction, _)| **interaction == Interaction::Pressed)
+            .map(|(entity, ..)| entity)
             .collect()
     };
 
@@ -45,29 +55,26 @@ pub fn handle_mouse_input(
         let mut p1 = param_set.p1();
         for (entity, mut buffer, mut selection, mut cursor_visual) in p1.iter_mut() {
             if !pressed_entities.contains(&entity) {
-                buffer.is_focused = false;
-                selection.clear();
-                cursor_visual.visible = false;
+                unfocus_input(&mut buffer, &mut selection, &mut cursor_visual);
             }
         }
     }
 
     // Now handle the actual interaction
     let mut p0 = param_set.p0();
-    for (_entity, mut buffer, mut selection, mut cursor_visual, interaction, cursor_pos, children) in p0.iter_mut() {
+    for (_entity, mut buffer, mut selection, mut cursor_visual, mut clicks, mut carets, settings, visual, interaction, children) in p0.iter_mut() {
         if *interaction == Interaction::Pressed {
             // Check if already focused
             let was_already_focused = buffer.is_focused;
 
             // Focus the input (now exclusive)
             info!("Setting focus to entity {:?} (was_already_focused: {})", _entity, was_already_focused);
-            buffer.is_focused = true;
-            cursor_visual.visible = true;  // Make cursor immediately visible
-            cursor_visual.blink_timer.reset();  // Reset blink timer
+            focus_input(&mut buffer, &mut cursor_visual);
 
-            // Find the text inner entity (direct child)
+            // Find the text inner entity (a child of the clip)
             let mut text_entity = None;
-            for child in children.iter() {
+            let clip_children = children.iter().filter_map(|child| clips.get(child).ok()).flat_map(|clip| clip.iter());
+            for child in clip_children {
                 if text_query.get(child).is_ok() {
                     text_entity = Some(child);
                     break;
@@ -75,11 +82,12 @@ pub fn handle_mouse_input(
             }
 
             if let Some(text_entity) = text_entity {
-                if let Ok(text_layout) = text_query.get(text_entity) {
-                    if let Some(normalized_pos) = cursor_pos.normalized {
+                if let Ok((text_layout, cursor_pos)) = text_query.get(text_entity) {
+                    // Measured against the text itself so the scroll offset
+                    // is accounted for; normalized is center-based
+                    if let Some(normalized_pos) = cursor_pos.normalized.map(|pos| pos + Vec2::splat(0.5)) {
                         info!("Mouse click normalized position: {:?}", normalized_pos);
                         info!("Text layout glyphs count: {}", text_layout.glyphs.len());
-                        info!("Buffer content: '{}', length: {}", buffer.content, buffer.content.len());
 
                         // Only update cursor position if we have glyph data or if it wasn't focused before
                         // If no glyphs and already focused, keep cursor where it was
@@ -89,25 +97,54 @@ pub fn handle_mouse_input(
                                 normalized_pos,
                                 text_layout,
                                 &buffer.content,
+                                visual.mask_char.is_some(),
                             );
 
                             info!("Calculated char_index: {}", char_index);
 
                             let shift = keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight);
+                            let alt = keyboard.pressed(KeyCode::AltLeft) || keyboard.pressed(KeyCode::AltRight);
 
-                            if shift && mouse.just_pressed(MouseButton::Left) {
+                            if alt && settings.multiline && was_already_focused {
+                                // Add or remove a caret, keeping the main cursor
+                                carets.toggle(char_index, buffer.cursor_pos);
+                                selection.clear();
+                                *clicks = MultiClick::default();
+                            } else if shift && mouse.just_pressed(MouseButton::Left) {
+                                carets.clear();
                                 // Extend selection
                                 if selection.anchor.is_none() {
                                     selection.start_selection(buffer.cursor_pos);
                                 }
                                 selection.update_selection(char_index);
+                                *clicks = MultiClick::default();
                             } else if mouse.just_pressed(MouseButton::Left) {
-                                // Start new selection or just move cursor
-                                info!("Setting cursor position to: {} (was: {})", char_index, buffer.cursor_pos);
-                                buffer.cursor_pos = char_index;
-                                selection.clear();
-                                // Store anchor for potential drag selection
-                                selection.anchor = Some(char_index);
+                                // Count repeated clicks on the same spot
+                                let now = time.elapsed_secs_f64();
+                                let repeated = was_already_focused
+                                    && clicks.last_pos == char_index
+                                    && clicks.last_time.is_some_and(|last| now - last <= MULTI_CLICK_INTERVAL);
+                                clicks.count = if repeated { (clicks.count + 1).min(3) } else { 1 };
+                                clicks.last_time = Some(now);
+                                clicks.last_pos = char_index;
+                                carets.clear();
+
+                                if clicks.count >= 2 {
+                                    // Select the word or line under the pointer
+                                    let (start, end) = click_unit_at(&buffer.content, char_index, clicks.count);
+                                    buffer.cursor_pos = end;
+                                    selection.anchor = Some(start);
+                                    selection.cursor = end;
+                                    clicks.unit = Some((start, end));
+                                } else {
+                                    // Start new selection or just move cursor
+                                    info!("Setting cursor position to: {} (was: {})", char_index, buffer.cursor_pos);
+                                    buffer.cursor_pos = char_index;
+                                    selection.clear();
+                                    // Store anchor for potential drag selection
+                                    selection.anchor = Some(char_index);
+                                    clicks.unit = None;
+                                }
                             }
                         } else {
                             info!("Keeping cursor position at {} (no glyphs available yet)", buffer.cursor_pos);
@@ -5,17 +5,29 @@ use bevy::text::TextLayoutInfo;
 use bevy::ui::RelativeCursorPosition;
 
 use super::super::super::super::components::*;
+use super::super::super::super::helpers::extend_by_unit;
+use super::super::super::scroll::viewport_size;
 use super::selection::calculate_char_index_from_position;
 
 /// Handle mouse drag for text selection
+///
+/// The selection only extends over text that is in view; dragging past the
+/// edge of a long input lets the viewport scroll to reveal more, and the
+/// selection follows as it does. After a double or triple click the selection
+/// grows by whole words or lines.
 pub fn handle_mouse_drag(
     mut text_inputs: Query<(
         &mut TextBuffer,
         &mut SelectionState,
-        &RelativeCursorPosition,
+        &MultiClick,
+        &TextInputVisual,
+        &ScrollViewport,
+        &Node,
+        &ComputedNode,
         &Children,
     ), With<NativeTextInput>>,
-    text_query: Query<&TextLayoutInfo, With<TextInputInner>>,
+    clips: Query<&Children, With<TextInputClip>>,
+    text_query: Query<(&TextLayoutInfo, &RelativeCursorPosition, &ComputedNode), With<TextInputInner>>,
     mouse: Res<ButtonInput<MouseButton>>,
 ) {
     // Only process drag if mouse is held down
@@ -23,14 +35,15 @@ pub fn handle_mouse_drag(
         return;
     }
 
-    for (buffer, mut selection, cursor_pos, children) in text_inputs.iter_mut() {
+    for (buffer, mut selection, clicks, visual, viewport, node, computed, children) in text_inputs.iter_mut() {
         if !buffer.is_focused {
             continue;
         }
 
-        // Find the text inner entity
+        // Find the text inner entity, inside the clip
         let mut text_entity = None;
-        for child in children.iter() {
+        let clip_children = children.iter().filter_map(|child| clips.get(child).ok()).flat_map(|clip| clip.iter());
+        for child in clip_children {
             if text_query.get(child).is_ok() {
                 text_entity = Some(child);
                 break;
@@ -38,17 +51,33 @@ pub fn handle_mouse_drag(
         }
 
         if let Some(text_entity) = text_entity {
-            if let Ok(text_layout) = text_query.get(text_entity) {
+            if let Ok((text_layout, cursor_pos, text_computed)) = text_query.get(text_entity) {
                 if let Some(normalized_pos) = cursor_pos.normalized {
+                    // Corner-based position across the text, limited to the
+                    // part of the text that is scrolled into view
+                    let mut text_pos = normalized_pos + Vec2::splat(0.5);
+                    let text_width = text_computed.size().x * text_computed.inverse_scale_factor();
+                    if text_width > 0.0 {
+                        let visible_width = viewport_size(node, computed).x;
+                        let min = viewport.offset_x / text_width;
+                        let max = ((viewport.offset_x + visible_width) / text_width).max(min);
+                        text_pos.x = text_pos.x.clamp(min, max);
+                    }
+
                     // Calculate current mouse position in text
                     let char_index = calculate_char_index_from_position(
-                        normalized_pos,
+                        text_pos,
                         text_layout,
                         &buffer.content,
+                        visual.mask_char.is_some(),
                     );
 
-                    // If we have an anchor, update the selection
-                    if let Some(anchor) = selection.anchor {
+                    if let Some(unit) = clicks.unit {
+                        let (anchor, cursor) = extend_by_unit(&buffer.content, unit, clicks.count, char_index);
+                        selection.anchor = Some(anchor);
+                        selection.cursor = cursor;
+                    } else if let Some(anchor) = selection.anchor {
+                        // If we have an anchor, update the selection
                         if char_index != anchor {
                             selection.anchor = Some(anchor);
                             selection.cursor = char_index;
@@ -61,4 +90,4 @@ pub fn handle_mouse_drag(
             }
         }
     }
-}
\ No newline at end of file
+}
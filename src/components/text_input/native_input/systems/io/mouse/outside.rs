@@ -4,6 +4,7 @@ use bevy::prelude::*;
 use bevy::ecs::system::ParamSet;
 
 use super::super::super::super::components::*;
+use super::super::super::super::helpers::unfocus_input;
 use crate::components::text_input::types::TextInputFocus;
 
 /// Handle click outside to unfocus text inputs
@@ -31,9 +32,7 @@ pub fn handle_click_outside(
         for (mut buffer, mut selection, mut cursor_visual, _) in p1.iter_mut() {
             if buffer.is_focused {
                 info!("Unfocusing input - clicked outside");
-                buffer.is_focused = false;
-                selection.clear();
-                cursor_visual.visible = false;
+                unfocus_input(&mut buffer, &mut selection, &mut cursor_visual);
             }
         }
     }
@@ -3,22 +3,37 @@
 use bevy::prelude::*;
 use bevy::text::TextLayoutInfo;
 
+use super::super::super::super::helpers::grapheme_to_char_index;
+
 /// Calculate character index from normalized mouse position
+///
+/// In a `masked` input each glyph is the mask of a whole grapheme.
 pub fn calculate_char_index_from_position(
     normalized_pos: Vec2,
     text_layout: &TextLayoutInfo,
     content: &str,
+    masked: bool,
 ) -> usize {
     if text_layout.glyphs.is_empty() {
         // No glyphs available - position at end of text
         return content.chars().count();
     }
 
+    // In wrapped text, only the line under the mouse counts
+    let mouse_y = normalized_pos.y * text_layout.size.y;
+    let line = text_layout.glyphs.iter()
+        .min_by(|a, b| (a.position.y - mouse_y).abs().total_cmp(&(b.position.y - mouse_y).abs()))
+        .map(|glyph| glyph.line_index);
+
     // Find nearest character boundary
     let mut closest_index = 0;
     let mut closest_distance = f32::MAX;
 
     for (i, glyph) in text_layout.glyphs.iter().enumerate() {
+        if Some(glyph.line_index) != line {
+            continue;
+        }
+
         let glyph_x = glyph.position.x / text_layout.size.x;
         let distance = (normalized_pos.x - glyph_x).abs();
 
@@ -35,5 +50,8 @@ pub fn calculate_char_index_from_position(
         }
     }
 
+    if masked {
+        return grapheme_to_char_index(content, closest_index);
+    }
     closest_index.min(content.chars().count())
 }
\ No newline at end of file
@@ -0,0 +1,82 @@
+//! Visual line tracking for multiline inputs
+
+use bevy::prelude::*;
+use bevy::text::{PositionedGlyph, TextLayoutInfo};
+
+use super::super::components::*;
+use super::super::helpers::{byte_to_char_index, visual_line_starts};
+use super::super::types::CursorStyle;
+
+/// Record where each visual line of a multiline input starts
+///
+/// Reads the word-wrapped layout of the text as it was last rendered, so it
+/// runs before keyboard handling moves the cursor between these lines.
+pub fn update_visual_lines(
+    mut text_inputs: Query<(
+        &TextBuffer,
+        &TextInputSettings,
+        &CursorVisual,
+        &mut VisualLines,
+        &Children,
+    ), With<NativeTextInput>>,
+    clips: Query<&Children, With<TextInputClip>>,
+    text_query: Query<(&TextLayoutInfo, &Children), With<TextInputInner>>,
+    spans: Query<&TextSpan>,
+) {
+    for (buffer, settings, cursor_visual, mut lines, children) in text_inputs.iter_mut() {
+        if !settings.multiline {
+            continue;
+        }
+        let Some(clip_children) = children.iter().find_map(|child| clips.get(child).ok()) else {
+            continue;
+        };
+        let Some((layout, span_entities)) = clip_children.iter().find_map(|child| text_query.get(child).ok()) else {
+            continue;
+        };
+
+        let span_text = |index: usize| {
+            span_entities.get(index)
+                .and_then(|entity| spans.get(*entity).ok())
+                .map_or("", |span| span.0.as_str())
+        };
+        let (before, cursor, after) = (span_text(0), span_text(1), span_text(2));
+
+        let starts = if buffer.content.is_empty() {
+            // Only the placeholder is laid out
+            vec![0]
+        } else {
+            let block = cursor_visual.style == CursorStyle::Block;
+            let glyphs = glyph_chars(layout, (before, cursor, after), block)
+                .map(|(glyph, index)| (glyph.line_index, index));
+            visual_line_starts(&buffer.content, glyphs)
+        };
+
+        if lines.starts != starts {
+            lines.starts = starts;
+        }
+    }
+}
+
+/// Each laid-out glyph of the text with the char index it shows
+///
+/// `spans` is the text before the cursor, the cursor and the text after it.
+/// Glyph spans count from the root text: 1 is before the cursor, 2 the
+/// cursor and 3 after it. Only a block cursor covers a character of the text.
+pub(super) fn glyph_chars<'a>(
+    layout: &'a TextLayoutInfo,
+    (before, cursor, after): (&'a str, &'a str, &'a str),
+    block: bool,
+) -> impl Iterator<Item = (&'a PositionedGlyph, usize)> + 'a {
+    let before_chars = before.chars().count();
+    let after_offset = before_chars + usize::from(block && !cursor.is_empty());
+
+    layout.glyphs.iter().filter_map(move |glyph| {
+        let index = match glyph.span_index {
+            1 => byte_to_char_index(before, glyph.byte_index),
+            2 if block => before_chars,
+            3 => after_offset + byte_to_char_index(after, glyph.byte_index),
+            _ => return None,
+        };
+        Some((glyph, index))
+    })
+}
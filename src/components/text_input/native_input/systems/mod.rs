@@ -8,11 +8,21 @@ mod io;
 mod rendering;
 mod cursor;
 mod focus_visual;
+mod selection;
+mod focus_events;
+mod scroll;
+mod lines;
+mod value;
 
 // Re-export all public systems
 pub use initialization::{init_text_input, sync_initial_text_content};
 pub use io::keyboard::{handle_keyboard_input, handle_tab_navigation};
 pub use io::mouse::{handle_mouse_input, handle_mouse_drag, handle_click_outside};
-pub use rendering::{render_text, render_selection};
+pub use rendering::{render_text, render_selection, render_carets};
 pub use cursor::update_cursor_blink;
-pub use focus_visual::update_focus_visual;
\ No newline at end of file
+pub use focus_visual::update_focus_visual;
+pub use selection::emit_selection_changes;
+pub use focus_events::emit_focus_changes;
+pub use scroll::scroll_text_inputs;
+pub use lines::update_visual_lines;
+pub use value::handle_set_value;
\ No newline at end of file
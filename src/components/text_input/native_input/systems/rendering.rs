@@ -1,11 +1,18 @@
 //! Text and cursor rendering systems
 
 use bevy::prelude::*;
-use bevy::text::TextLayoutInfo;
+use bevy::color::Luminance;
+use bevy::text::{TextBackgroundColor, TextLayoutInfo};
 
+use crate::components::text_input::types::GhostSuggestion;
 use super::super::components::*;
+use super::super::helpers::{char_to_grapheme_index, display_range, line_of, mask_text};
+use super::super::types::CursorStyle;
+use super::lines::glyph_chars;
+use super::io::keyboard::ghost_completion;
 
-/// Render text with embedded cursor (split into 3 spans)
+/// Render text with embedded cursor (split into 3 spans, plus a 4th for
+/// the rest of a ghost suggestion)
 pub fn render_text(
     mut text_inputs: Query<(
         &TextBuffer,
@@ -13,17 +20,19 @@ pub fn render_text(
         &TextInputVisual,
         &CursorVisual,
         &Children,
+        Option<&GhostSuggestion>,
     ), With<NativeTextInput>>,
+    clips: Query<&Children, With<TextInputClip>>,
     text_inner_query: Query<Entity, With<TextInputInner>>,
     mut text_span_query: Query<(&mut TextSpan, &mut TextFont, &mut TextColor)>,
+    mut span_backgrounds: Query<&mut TextBackgroundColor>,
     children_query: Query<&Children>,
 ) {
-    for (buffer, _selection, visual, cursor_visual, children) in text_inputs.iter_mut() {
-        info!("render_text: buffer content = '{}', cursor_pos = {}", buffer.content, buffer.cursor_pos);
-
-        // Find the TextInputInner entity (direct child)
+    for (buffer, selection, visual, cursor_visual, children, ghost) in text_inputs.iter_mut() {
+        // Find the TextInputInner entity (a child of the clip)
         let mut text_inner_entity = None;
-        for child in children.iter() {
+        let clip_children = children.iter().filter_map(|child| clips.get(child).ok()).flat_map(|clip| clip.iter());
+        for child in clip_children {
             if text_inner_query.get(child).is_ok() {
                 text_inner_entity = Some(child);
                 break;
@@ -37,43 +46,82 @@ pub fn render_text(
 
                 if text_children_vec.len() >= 3 {
                     // Prepare the display text with optional masking
-                    let display_text = if buffer.content.is_empty() && !buffer.is_focused {
+                    let show_placeholder = buffer.content.is_empty() && !buffer.is_focused;
+                    let display_text = if show_placeholder {
                         // Show placeholder in first span
                         visual.placeholder.clone()
                     } else if let Some(mask) = visual.mask_char {
-                        mask.to_string().repeat(buffer.content.chars().count())
+                        mask_text(&buffer.content, mask)
                     } else {
                         buffer.content.clone()
                     };
 
-                    // Split text at cursor position
-                    let cursor_pos = buffer.cursor_pos.min(display_text.chars().count());
+                    // Split text at cursor position (masks stand for whole graphemes)
+                    let cursor_pos = match visual.mask_char {
+                        Some(_) if !show_placeholder => char_to_grapheme_index(&buffer.content, buffer.cursor_pos),
+                        _ => buffer.cursor_pos,
+                    };
+                    let cursor_pos = cursor_pos.min(display_text.chars().count());
                     let before_cursor: String = display_text.chars().take(cursor_pos).collect();
-                    let after_cursor: String = display_text.chars().skip(cursor_pos).collect();
+                    let mut after_cursor: String = display_text.chars().skip(cursor_pos).collect();
+                    let cursor_shown = buffer.is_focused && cursor_visual.visible;
+
+                    // Rest of the ghost suggestion, drawn after the cursor
+                    let ghost_text = ghost_completion(ghost, buffer, selection, visual).unwrap_or("");
+                    let mut ghost_rest = ghost_text.chars();
 
-                    // Determine cursor character
-                    let cursor_char = if buffer.is_focused && cursor_visual.visible {
-                        "|".to_string()
+                    // Determine cursor character. Block cursors take over the
+                    // character under them (or a space at the end of the text)
+                    // so they never shift the text while blinking.
+                    let cursor_char = if buffer.is_focused && cursor_visual.style == CursorStyle::Block {
+                        let mut rest = after_cursor.chars();
+                        // At the end, the block sits on the ghost's first character
+                        let under = rest.next().or_else(|| ghost_rest.next());
+                        let under = match under {
+                            Some(c) => c.to_string(),
+                            None if cursor_shown => " ".to_string(),
+                            None => String::new(),
+                        };
+                        after_cursor = rest.collect();
+                        under
+                    } else if cursor_shown {
+                        cursor_visual.style.glyph().to_string()
                     } else {
                         "".to_string()
                     };
+                    let block_shown = cursor_shown && cursor_visual.style == CursorStyle::Block;
+                    let ghost_under_block = !ghost_text.is_empty() && ghost_rest.as_str().len() < ghost_text.len();
 
                     // Update pre-cursor span (index 0)
                     if let Ok((mut text_span, mut font, mut color)) = text_span_query.get_mut(text_children_vec[0]) {
                         *text_span = TextSpan::new(before_cursor);
-                        *font = visual.font.clone();
-                        color.0 = if buffer.content.is_empty() && !buffer.is_focused {
-                            visual.placeholder_color
+                        if show_placeholder {
+                            *font = visual.placeholder_text_font();
+                            color.0 = visual.placeholder_color;
                         } else {
-                            visual.text_color
-                        };
+                            *font = visual.font.clone();
+                            color.0 = visual.text_color;
+                        }
                     }
 
                     // Update cursor span (index 1)
                     if let Ok((mut text_span, mut font, mut color)) = text_span_query.get_mut(text_children_vec[1]) {
                         *text_span = TextSpan::new(cursor_char);
                         *font = visual.font.clone();
-                        color.0 = visual.cursor_color;  // Always use cursor color
+                        color.0 = if block_shown {
+                            // Covered character contrasts with the block
+                            if visual.cursor_color.luminance() > 0.5 { Color::BLACK } else { Color::WHITE }
+                        } else if ghost_under_block {
+                            visual.placeholder_color
+                        } else if cursor_visual.style == CursorStyle::Block {
+                            visual.text_color  // Hidden block shows the plain character
+                        } else {
+                            visual.cursor_color
+                        };
+                    }
+
+                    if let Ok(mut background) = span_backgrounds.get_mut(text_children_vec[1]) {
+                        background.0 = if block_shown { visual.cursor_color } else { Color::NONE };
                     }
 
                     // Update post-cursor span (index 2)
@@ -82,6 +130,15 @@ pub fn render_text(
                         *font = visual.font.clone();
                         color.0 = visual.text_color;
                     }
+
+                    // Update ghost span (index 3)
+                    if let Some(ghost_span) = text_children_vec.get(3) {
+                        if let Ok((mut text_span, mut font, mut color)) = text_span_query.get_mut(*ghost_span) {
+                            *text_span = TextSpan::new(ghost_rest.as_str());
+                            *font = visual.placeholder_text_font();
+                            color.0 = visual.placeholder_color;
+                        }
+                    }
                 } else {
                     warn!("TextInputInner doesn't have 3 TextSpan children!");
                 }
@@ -94,127 +151,231 @@ pub fn render_text(
 
 // update_cursor_visual removed - cursor is now embedded in text spans
 
+/// Where laid-out text sits inside the input's [`TextInputClip`], in
+/// logical pixels
+struct TextGeometry {
+    /// Converts layout (physical) pixels to logical ones
+    scale: f32,
+    line_height: f32,
+    /// Top of the first visual line
+    text_top: f32,
+    /// Left edge of the text, scrolled
+    text_left: f32,
+}
+
+impl TextGeometry {
+    fn new(
+        layout: &TextLayoutInfo,
+        visual: &TextInputVisual,
+        settings: &TextInputSettings,
+        lines: &VisualLines,
+        viewport: &ScrollViewport,
+        clip: &ComputedNode,
+    ) -> Self {
+        // Lines share the laid-out height evenly; empty text has none yet
+        let scale = clip.inverse_scale_factor();
+        let line_height = match layout.size.y * scale / lines.starts.len() as f32 {
+            height if height > 0.0 => height,
+            _ => visual.font.font_size * 1.2,
+        };
+
+        // Multiline text starts at the top; single-line text is centered
+        let text_top = if settings.multiline {
+            -viewport.offset_y
+        } else {
+            (clip.size().y * scale - line_height) / 2.0
+        };
+
+        Self {
+            scale,
+            line_height,
+            text_top,
+            text_left: -viewport.offset_x,
+        }
+    }
+}
+
 /// Render selection highlight
+///
+/// Each visual line the selection touches gets its own rect, spanning the
+/// selected glyphs on that line.
 pub fn render_selection(
     mut commands: Commands,
-    mut text_inputs: Query<(Entity, &Children, &SelectionState, &mut CursorVisual), With<NativeTextInput>>,
-    text_inner_query: Query<(&TextLayoutInfo, &Children), With<TextInputInner>>,
-    text_span_query: Query<&TextSpan>,
-    primary_window: Query<&Window, With<bevy::window::PrimaryWindow>>,
+    mut text_inputs: Query<(
+        Entity,
+        &TextBuffer,
+        &SelectionState,
+        &TextInputVisual,
+        &TextInputSettings,
+        &VisualLines,
+        &ScrollViewport,
+        &Children,
+        &mut CursorVisual,
+    ), With<NativeTextInput>>,
+    clips: Query<(Entity, &ComputedNode, &Children), With<TextInputClip>>,
+    text_query: Query<(&TextLayoutInfo, &Children), With<TextInputInner>>,
+    spans: Query<&TextSpan>,
 ) {
-    let scale_factor = primary_window.iter().next().map(|w| w.scale_factor()).unwrap_or(1.0);
-
-    for (input_entity, children, selection, mut cursor_visual) in &mut text_inputs {
+    for (input_entity, buffer, selection, visual, settings, lines, viewport, children, mut cursor_visual) in &mut text_inputs {
         // Clean up existing selection entities
         for entity in cursor_visual.selection_entities.drain(..) {
             commands.entity(entity).despawn();
         }
 
-        if selection.has_selection() {
-            // Find text layout (direct child)
-            for child in children.iter() {
-                if let Ok((text_layout, text_spans)) = text_inner_query.get(child) {
-                    // Calculate selection bounds based on glyph positions
-                    if let Some((start, end)) = selection.range() {
-                        // We need to map character indices to glyph indices.
-                        // Because of the 3-span structure (Pre, Cursor, Post), and the cursor being a separate span/glyph,
-                        // the mapping is tricky.
-                        // However, TextLayoutInfo aggregates all glyphs.
-                        // If cursor is present (focused), we have an extra glyph at cursor_pos.
-
-                        // Check if cursor is actually present in the text layout
-                        // The cursor span is index 1.
-                        let cursor_present = if let Some(cursor_entity) = text_spans.get(1) {
-                             if let Ok(span) = text_span_query.get(*cursor_entity) {
-                                 !span.0.is_empty()
-                             } else {
-                                 false
-                             }
-                        } else {
-                            false
-                        };
+        let Some(range) = selection.range() else {
+            continue;
+        };
+        let Some((clip_entity, clip, clip_children)) = children.iter().find_map(|child| clips.get(child).ok()) else {
+            continue;
+        };
+        let Some((layout, span_entities)) = clip_children.iter().find_map(|child| text_query.get(child).ok()) else {
+            continue;
+        };
 
-                        let cursor_pos = selection.cursor; // This is the cursor position in the buffer
-
-                        // Map logical char index to glyph index
-                        // If cursor is present at `cursor_pos`, then:
-                        // Indices < cursor_pos map to same glyph index
-                        // Indices >= cursor_pos map to index + 1
-                        let map_index = |idx: usize, is_end: bool| -> usize {
-                            if cursor_present {
-                                if idx > cursor_pos || (idx == cursor_pos && !is_end) {
-                                    idx + 1
-                                } else {
-                                    idx
-                                }
-                            } else {
-                                idx
-                            }
-                        };
+        let span_text = |index: usize| {
+            span_entities.get(index)
+                .and_then(|entity| spans.get(*entity).ok())
+                .map_or("", |span| span.0.as_str())
+        };
+        let block = cursor_visual.style == CursorStyle::Block;
+        let geometry = TextGeometry::new(layout, visual, settings, lines, viewport, clip);
+        // Masks stand for whole graphemes
+        let (start, end) = display_range(&buffer.content, range, visual.mask_char.is_some());
 
-                        let start_glyph = map_index(start, false).min(text_layout.glyphs.len());
-                        let end_glyph = map_index(end, true).min(text_layout.glyphs.len());
+        // Horizontal extent of the selected glyphs on each visual line
+        // (glyph positions are centers)
+        let mut extents: Vec<(usize, f32, f32)> = Vec::new();
+        for (glyph, index) in glyph_chars(layout, (span_text(0), span_text(1), span_text(2)), block) {
+            if index < start || index >= end {
+                continue;
+            }
+            let left = glyph.position.x - glyph.size.x / 2.0;
+            let right = glyph.position.x + glyph.size.x / 2.0;
+            match extents.iter_mut().find(|(line, _, _)| *line == glyph.line_index) {
+                Some((_, min, max)) => {
+                    *min = min.min(left);
+                    *max = max.max(right);
+                }
+                None => extents.push((glyph.line_index, left, right)),
+            }
+        }
 
-                        // Calculate selection bounds
-                        let start_x = if start_glyph == 0 {
-                            0.0
-                        } else if start_glyph > 0 {
-                            text_layout.glyphs.get(start_glyph - 1)
-                                .map(|g| g.position.x + g.size.x)
-                                .unwrap_or(0.0)
-                        } else {
-                            0.0
-                        };
+        for (line, min, max) in extents {
+            let width = (max - min) * geometry.scale;
+            if width <= 0.0 {
+                continue;
+            }
 
-                        let end_x = if end_glyph == 0 {
-                            0.0
-                        } else if end_glyph > 0 && end_glyph <= text_layout.glyphs.len() {
-                            text_layout.glyphs.get(end_glyph - 1)
-                                .map(|g| g.position.x + g.size.x)
-                                .unwrap_or_else(|| {
-                                    text_layout.glyphs.last()
-                                        .map(|g| g.position.x + g.size.x)
-                                        .unwrap_or(0.0)
-                                })
-                        } else {
-                            text_layout.glyphs.last()
-                                .map(|g| g.position.x + g.size.x)
-                                .unwrap_or(0.0)
-                        };
+            let selection_entity = commands.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(geometry.text_left + min * geometry.scale),
+                    top: Val::Px(geometry.text_top + line as f32 * geometry.line_height),
+                    width: Val::Px(width),
+                    height: Val::Px(geometry.line_height),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.3, 0.5, 0.8, 0.3)), // Selection color
+                TextInputSelection { input_entity },
+                ZIndex(-1), // Render behind text
+            )).id();
 
-                        // Apply scale factor correction
-                        let start_x = start_x / scale_factor;
-                        let end_x = end_x / scale_factor;
-
-                        // Add padding offset to align with text
-                        let padding_offset = 10.0;
-
-                        let width = end_x - start_x;
-                        
-                        if width > 0.0 {
-                            let selection_entity = commands.spawn((
-                                Node {
-                                    position_type: PositionType::Absolute,
-                                    left: Val::Px(start_x + padding_offset),
-                                    top: Val::Px(padding_offset), // Assuming vertical padding is also 10
-                                    width: Val::Px(width),
-                                    height: Val::Px(20.0), // Approximate line height
-                                    ..default()
-                                },
-                                BackgroundColor(Color::srgba(0.3, 0.5, 0.8, 0.3)), // Selection color
-                                TextInputSelection { input_entity },
-                                ZIndex(-1), // Render behind text
-                            )).id();
-
-                            cursor_visual.selection_entities.push(selection_entity);
-                            
-                            // Parent the selection to the input container so it moves with it
-                            commands.entity(input_entity).add_child(selection_entity);
-                        }
-                    }
-                    break; // Found the inner text, stop looking
-                }
-            }
+            cursor_visual.selection_entities.push(selection_entity);
+
+            // Parent the selection to the clip so it moves and clips with the text
+            commands.entity(clip_entity).add_child(selection_entity);
+        }
+    }
+}
+
+/// Draw line cursors and the extra carets of multiline inputs
+///
+/// Like the selection highlight, carets are placed using the text layout
+/// rendered last frame, and are `cursor_width` wide. Extra carets blink
+/// along with the main cursor.
+pub fn render_carets(
+    mut commands: Commands,
+    mut text_inputs: Query<(
+        &TextBuffer,
+        &ExtraCarets,
+        &TextInputVisual,
+        &TextInputSettings,
+        &VisualLines,
+        &ScrollViewport,
+        &Children,
+        &mut CursorVisual,
+    ), With<NativeTextInput>>,
+    clips: Query<(Entity, &ComputedNode, &Children), With<TextInputClip>>,
+    text_query: Query<(&TextLayoutInfo, &Children), With<TextInputInner>>,
+    spans: Query<&TextSpan>,
+) {
+    for (buffer, carets, visual, settings, lines, viewport, children, mut cursor_visual) in &mut text_inputs {
+        for entity in cursor_visual.caret_entities.drain(..) {
+            commands.entity(entity).despawn();
+        }
+
+        if !buffer.is_focused || !cursor_visual.visible {
+            continue;
+        }
+
+        // The main cursor is drawn here only in the line style
+        let main = (cursor_visual.style == CursorStyle::Line).then_some(buffer.cursor_pos);
+        let positions: Vec<usize> = main.into_iter().chain(carets.positions.iter().copied()).collect();
+        if positions.is_empty() {
+            continue;
+        }
+        let Some((clip_entity, clip, clip_children)) = children.iter().find_map(|child| clips.get(child).ok()) else {
+            continue;
+        };
+        let Some((layout, span_entities)) = clip_children.iter().find_map(|child| text_query.get(child).ok()) else {
+            continue;
+        };
+
+        let span_text = |index: usize| {
+            span_entities.get(index)
+                .and_then(|entity| spans.get(*entity).ok())
+                .map_or("", |span| span.0.as_str())
+        };
+        let block = cursor_visual.style == CursorStyle::Block;
+        let glyphs: Vec<_> = glyph_chars(layout, (span_text(0), span_text(1), span_text(2)), block).collect();
+
+        let geometry = TextGeometry::new(layout, visual, settings, lines, viewport, clip);
+
+        for caret in positions {
+            let line = line_of(&lines.starts, caret);
+            // Masks stand for whole graphemes
+            let caret = match visual.mask_char {
+                Some(_) => char_to_grapheme_index(&buffer.content, caret),
+                None => caret,
+            };
+
+            // Left edge of the glyph at the caret, or the right edge of the
+            // one before it on the same line (glyph positions are centers)
+            let x = glyphs.iter()
+                .find(|(glyph, index)| *index == caret && glyph.line_index == line)
+                .map(|(glyph, _)| glyph.position.x - glyph.size.x / 2.0)
+                .or_else(|| {
+                    glyphs.iter()
+                        .find(|(glyph, index)| *index + 1 == caret && glyph.line_index == line)
+                        .map(|(glyph, _)| glyph.position.x + glyph.size.x / 2.0)
+                })
+                .unwrap_or(0.0);
+
+            let caret_entity = commands.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(geometry.text_left + x * geometry.scale),
+                    top: Val::Px(geometry.text_top + line as f32 * geometry.line_height),
+                    width: Val::Px(settings.cursor_width),
+                    height: Val::Px(geometry.line_height),
+                    ..default()
+                },
+                BackgroundColor(visual.cursor_color),
+                Pickable::IGNORE,
+            )).id();
+
+            cursor_visual.caret_entities.push(caret_entity);
+            commands.entity(clip_entity).add_child(caret_entity);
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,164 @@
+//! Viewport scrolling for text longer than the input
+
+use bevy::prelude::*;
+use bevy::text::TextLayoutInfo;
+use bevy::window::PrimaryWindow;
+
+use super::super::components::*;
+use super::super::helpers::line_of;
+use crate::utils::geometry::node_logical_rect;
+
+/// Room kept between the cursor and the viewport edge, in logical pixels
+const CURSOR_MARGIN: f32 = 2.0;
+
+/// Auto-scroll speed while dragging past an edge, in pixels per second for
+/// each pixel the mouse is beyond the edge
+const DRAG_SCROLL_SPEED: f32 = 12.0;
+
+/// Slowest auto-scroll speed, so a mouse just past the edge still scrolls
+const MIN_DRAG_SCROLL_SPEED: f32 = 60.0;
+
+/// Size of the area text is visible in, in logical pixels
+///
+/// The input's computed size less its padding and border.
+pub fn viewport_size(node: &Node, computed: &ComputedNode) -> Vec2 {
+    let px = px_or_zero;
+    let insets = Vec2::new(
+        px(node.padding.left) + px(node.padding.right) + px(node.border.left) + px(node.border.right),
+        px(node.padding.top) + px(node.padding.bottom) + px(node.border.top) + px(node.border.bottom),
+    );
+
+    (computed.size() * computed.inverse_scale_factor() - insets).max(Vec2::ZERO)
+}
+
+/// Scroll inputs so the cursor stays visible
+///
+/// Single-line inputs scroll horizontally. While a selection is being
+/// dragged past the left or right edge, the viewport keeps scrolling in that
+/// direction, faster the further the mouse is beyond the edge. Multiline
+/// inputs wrap instead, so they follow the cursor's line vertically and
+/// auto-scroll the same way past the top or bottom edge.
+pub fn scroll_text_inputs(
+    time: Res<Time>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut text_inputs: Query<(
+        &TextBuffer,
+        &SelectionState,
+        &TextInputSettings,
+        &mut ScrollViewport,
+        &Node,
+        &ComputedNode,
+        &GlobalTransform,
+        &Children,
+        Option<&VisualLines>,
+    ), With<NativeTextInput>>,
+    clips: Query<&Children, With<TextInputClip>>,
+    mut text_query: Query<(&TextLayoutInfo, &mut Node), (With<TextInputInner>, Without<NativeTextInput>)>,
+) {
+    let mouse_position = windows.single().ok().and_then(|window| window.cursor_position());
+    let delta = time.delta_secs();
+
+    for (buffer, selection, settings, mut viewport, node, computed, transform, children, lines) in text_inputs.iter_mut() {
+        let Some(text_entity) = children.iter()
+            .filter_map(|child| clips.get(child).ok())
+            .flat_map(|clip| clip.iter())
+            .find(|child| text_query.contains(*child))
+        else {
+            continue;
+        };
+        let Ok((text_layout, mut text_node)) = text_query.get_mut(text_entity) else {
+            continue;
+        };
+
+        let scale = computed.inverse_scale_factor();
+        let view = viewport_size(node, computed);
+        let text_size = text_layout.size * scale;
+
+        // Logical-pixel rect of the visible area, for comparing with the mouse
+        let inset_min = Vec2::new(
+            px_or_zero(node.padding.left) + px_or_zero(node.border.left),
+            px_or_zero(node.padding.top) + px_or_zero(node.border.top),
+        );
+        let view_min = node_logical_rect(computed, transform).min + inset_min;
+        let view_max = view_min + view;
+
+        let dragging = buffer.is_focused
+            && selection.anchor.is_some()
+            && mouse.pressed(MouseButton::Left)
+            && !mouse.just_pressed(MouseButton::Left);
+
+        // Follow the end of the selection that moves
+        let cursor = if selection.has_selection() {
+            selection.cursor
+        } else {
+            buffer.cursor_pos
+        };
+
+        if settings.multiline {
+            match (dragging, mouse_position, lines) {
+                (true, Some(mouse_position), _) => {
+                    viewport.offset_y += drag_scroll(mouse_position.y, view_min.y, view_max.y) * delta;
+                }
+                (_, _, Some(lines)) => {
+                    // Lines share the laid-out height evenly
+                    let line_height = text_size.y / lines.starts.len() as f32;
+                    let line_top = line_of(&lines.starts, cursor) as f32 * line_height;
+
+                    if line_top - CURSOR_MARGIN < viewport.offset_y {
+                        viewport.offset_y = line_top - CURSOR_MARGIN;
+                    } else if line_top + line_height + CURSOR_MARGIN > viewport.offset_y + view.y {
+                        viewport.offset_y = line_top + line_height + CURSOR_MARGIN - view.y;
+                    }
+                }
+                _ => {}
+            }
+            viewport.offset_y = viewport.offset_y.clamp(0.0, (text_size.y - view.y).max(0.0));
+        } else {
+            match (dragging, mouse_position) {
+                (true, Some(mouse_position)) => {
+                    viewport.offset_x += drag_scroll(mouse_position.x, view_min.x, view_max.x) * delta;
+                }
+                _ => {
+                    let cursor_x = cursor
+                        .checked_sub(1)
+                        .and_then(|index| text_layout.glyphs.get(index))
+                        .map(|glyph| (glyph.position.x + glyph.size.x) * scale)
+                        .unwrap_or(0.0);
+
+                    if cursor_x - CURSOR_MARGIN < viewport.offset_x {
+                        viewport.offset_x = cursor_x - CURSOR_MARGIN;
+                    } else if cursor_x + CURSOR_MARGIN > viewport.offset_x + view.x {
+                        viewport.offset_x = cursor_x + CURSOR_MARGIN - view.x;
+                    }
+                }
+            }
+            viewport.offset_x = viewport.offset_x.clamp(0.0, (text_size.x - view.x).max(0.0));
+        }
+
+        let left = Val::Px(-viewport.offset_x);
+        let top = Val::Px(-viewport.offset_y);
+        if text_node.left != left || text_node.top != top {
+            text_node.left = left;
+            text_node.top = top;
+        }
+    }
+}
+
+/// Signed scroll speed for a mouse coordinate against a visible range
+fn drag_scroll(position: f32, min: f32, max: f32) -> f32 {
+    let overshoot = if position < min {
+        position - min
+    } else if position > max {
+        position - max
+    } else {
+        return 0.0;
+    };
+
+    overshoot.signum() * (overshoot.abs() * DRAG_SCROLL_SPEED).max(MIN_DRAG_SCROLL_SPEED)
+}
+
+/// Pixel value of a `Val`, treating other units as zero
+fn px_or_zero(val: Val) -> f32 {
+    if let Val::Px(value) = val { value } else { 0.0 }
+}
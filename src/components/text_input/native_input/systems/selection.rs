@@ -0,0 +1,57 @@
+//! Selection change notification system
+
+use std::collections::HashMap;
+use bevy::prelude::*;
+
+use super::super::components::*;
+use super::super::events::TextInputSelectionChangedEvent;
+use super::super::helpers::{get_selected_text, mask_text};
+
+/// Send a `TextInputSelectionChangedEvent` whenever an input's selected range changes
+///
+/// Cursor moves without a selection don't count as changes, so toolbars only
+/// hear about selections appearing, changing and being cleared. Masked
+/// (password) inputs report the mask characters instead of the text.
+pub fn emit_selection_changes(
+    inputs: Query<(Entity, &TextBuffer, &SelectionState, &TextInputVisual), (Changed<SelectionState>, With<NativeTextInput>)>,
+    mut removed: RemovedComponents<SelectionState>,
+    mut reported: Local<HashMap<Entity, (usize, usize)>>,
+    mut events: MessageWriter<TextInputSelectionChangedEvent>,
+) {
+    for entity in removed.read() {
+        reported.remove(&entity);
+    }
+
+    for (entity, buffer, selection, visual) in inputs.iter() {
+        let range = selection.range().filter(|_| selection.has_selection());
+        if reported.get(&entity).copied() == range {
+            continue;
+        }
+
+        let (start, end) = match range {
+            Some(range) => {
+                reported.insert(entity, range);
+                range
+            }
+            None => {
+                reported.remove(&entity);
+                (selection.cursor, selection.cursor)
+            }
+        };
+
+        let selected_text = get_selected_text(buffer, selection)
+            .filter(|_| range.is_some())
+            .unwrap_or_default();
+        let selected_text = match visual.mask_char {
+            Some(mask) => mask_text(&selected_text, mask),
+            None => selected_text,
+        };
+
+        events.write(TextInputSelectionChangedEvent {
+            entity,
+            start,
+            end,
+            selected_text,
+        });
+    }
+}
@@ -0,0 +1,32 @@
+//! Programmatic text replacement
+
+use bevy::prelude::*;
+
+use super::super::components::*;
+use super::super::events::{SetTextInputValue, TextInputChangeEvent};
+use super::super::helpers::replace_content;
+
+/// Apply [`SetTextInputValue`] requests
+pub fn handle_set_value(
+    mut requests: MessageReader<SetTextInputValue>,
+    mut text_inputs: Query<(&mut TextBuffer, &mut SelectionState, &mut UndoHistory), With<NativeTextInput>>,
+    mut change_events: MessageWriter<TextInputChangeEvent>,
+) {
+    for request in requests.read() {
+        let Ok((mut buffer, mut selection, mut history)) = text_inputs.get_mut(request.entity) else {
+            continue;
+        };
+
+        let Some(op) = replace_content(&mut buffer, &mut selection, &request.text) else {
+            continue;
+        };
+        history.push(op);
+
+        if !request.silent {
+            change_events.write(TextInputChangeEvent {
+                entity: request.entity,
+                text: buffer.content.clone(),
+            });
+        }
+    }
+}
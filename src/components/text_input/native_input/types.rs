@@ -1,9 +1,10 @@
 //! Type definitions for native text input
 
 /// Cursor rendering style
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum CursorStyle {
     /// Vertical line cursor (default)
+    #[default]
     Line,
     /// Block cursor (covers character)
     Block,
@@ -11,6 +12,20 @@ pub enum CursorStyle {
     Underline,
 }
 
+impl CursorStyle {
+    /// Glyph drawn between the characters for inline styles
+    ///
+    /// Line and block cursors don't add a glyph: line cursors are drawn over
+    /// the text as a bar `cursor_width` wide, and block cursors paint the
+    /// background of the character under the cursor.
+    pub fn glyph(&self) -> &'static str {
+        match self {
+            Self::Line | Self::Block => "",
+            Self::Underline => "_",
+        }
+    }
+}
+
 /// Type of edit operation for undo/redo
 #[derive(Clone, Debug)]
 pub enum OperationType {
@@ -50,6 +65,38 @@ pub enum TabBehavior {
     InsertSpaces(usize),
 }
 
+/// Which Enter press submits a multiline input; any other Enter inserts a
+/// newline
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SubmitKey {
+    /// Ctrl+Enter (or Cmd+Enter) submits
+    #[default]
+    CtrlEnter,
+    /// Plain Enter submits and Shift+Enter inserts a newline, chat style
+    Enter,
+    /// Shift+Enter submits
+    ShiftEnter,
+    /// Alt+Enter submits
+    AltEnter,
+    /// Enter never submits; every Enter is a newline
+    Never,
+}
+
+impl SubmitKey {
+    /// Whether Enter with these modifiers held submits
+    ///
+    /// `ctrl` should include Cmd so the key works on macOS.
+    pub fn submits(self, ctrl: bool, shift: bool, alt: bool) -> bool {
+        match self {
+            Self::CtrlEnter => ctrl,
+            Self::Enter => !shift,
+            Self::ShiftEnter => shift,
+            Self::AltEnter => alt,
+            Self::Never => false,
+        }
+    }
+}
+
 /// Navigation actions for cursor movement
 #[derive(Debug, Clone, Copy)]
 pub enum NavigationAction {
@@ -102,4 +149,19 @@ pub enum EditAction {
     CopySelection,
     /// Paste text from clipboard
     PasteFromClipboard(String),
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enter_submit_key_keeps_shift_for_newlines() {
+        // (ctrl, shift, alt)
+        assert!(SubmitKey::Enter.submits(false, false, false));
+        assert!(!SubmitKey::Enter.submits(false, true, false));
+        assert!(!SubmitKey::CtrlEnter.submits(false, false, false));
+        assert!(SubmitKey::CtrlEnter.submits(true, false, false));
+        assert!(!SubmitKey::Never.submits(true, true, true));
+    }
+}
@@ -4,13 +4,24 @@
 use bevy::prelude::*;
 use bevy_plugin_builder::define_plugin;
 use super::native_input::*;
-use super::systems::handle_clear_button_clicks;
+use super::systems::{
+    handle_clear_button_clicks,
+    update_character_counters,
+    animate_floating_labels,
+    handle_suggestion_keys,
+    handle_suggestion_clicks,
+    update_suggestions,
+    update_suggestion_highlight,
+};
 
 /// Plugin that provides the complete text input system
 define_plugin!(TextInputPlugin {
     messages: [
         TextInputSubmitEvent,
-        TextInputChangeEvent
+        TextInputChangeEvent,
+        TextInputSelectionChangedEvent,
+        TextInputFocusEvent,
+        SetTextInputValue
     ],
     custom_init: |app: &mut App| {
         app.add_observer(init_text_input);
@@ -19,18 +30,72 @@ define_plugin!(TextInputPlugin {
         // Initial sync system - runs once when text input is fully initialized
         sync_initial_text_content,
 
+        // Wrapped line starts, from the layout rendered last frame
+        update_visual_lines
+            .before(handle_keyboard_input)
+            .before(render_text),
+
+        // Programmatic text changes land before this frame's typing
+        handle_set_value.before(handle_keyboard_input),
+
         // Native input systems
         handle_keyboard_input,
         handle_tab_navigation,
         handle_click_outside,  // Must run BEFORE handle_mouse_input to avoid race condition
         handle_mouse_input,     // This sets focus on clicked inputs
-        handle_mouse_drag,
+        handle_mouse_drag.after(handle_mouse_input),  // Extends this frame's double/triple click
         update_cursor_blink,
         update_focus_visual,    // Maintain focus border color
         render_text,
-        render_selection,
+
+        // Keep the cursor in view and auto-scroll drag selections
+        scroll_text_inputs
+            .after(handle_keyboard_input)
+            .after(handle_mouse_input)
+            .after(handle_mouse_drag),
+        render_selection.after(scroll_text_inputs),
+        render_carets.after(scroll_text_inputs),
+
+        // Report selection changes once this frame's input has been handled
+        emit_selection_changes
+            .after(handle_keyboard_input)
+            .after(handle_mouse_input)
+            .after(handle_mouse_drag)
+            .after(handle_click_outside),
+
+        // Report focus changes once this frame's clicks and Tab presses are
+        // in, including a suggestion click handing focus back to its input
+        emit_focus_changes
+            .after(handle_keyboard_input)
+            .after(handle_tab_navigation)
+            .after(handle_click_outside)
+            .after(handle_mouse_input)
+            .after(handle_suggestion_clicks),
 
         // Clear button functionality
-        handle_clear_button_clicks
+        handle_clear_button_clicks,
+
+        // Character counters follow this frame's edits
+        update_character_counters
+            .after(handle_keyboard_input)
+            .after(handle_set_value)
+            .after(handle_clear_button_clicks),
+
+        // Floating label rest/float animation
+        animate_floating_labels,
+
+        // Autocomplete menus - after the native handlers so Tab/Enter and
+        // clicks on a suggestion are resolved against the same frame's focus
+        (
+            handle_suggestion_keys,
+            handle_suggestion_clicks,
+            update_suggestions,
+            update_suggestion_highlight,
+        )
+            .chain()
+            .after(handle_keyboard_input)
+            .after(handle_tab_navigation)
+            .after(handle_click_outside)
+            .after(handle_mouse_input)
     ]
 });
\ No newline at end of file
@@ -1,7 +1,23 @@
 //! Text input interaction systems
 
 use bevy::prelude::*;
-use super::types::ClearButtonTarget;
+use crate::animation::Easing;
+use crate::styles::dimensions;
+use crate::systems::escape::{EscapeLayer, EscapeStack};
+use crate::systems::popup;
+use crate::theme::UiTheme;
+use crate::relationships::TextInputPart;
+use super::types::{ClearButtonTarget, CharacterCounter, FloatingLabel, TextInputSuggestions, SuggestionMenu, SuggestionItem};
+use super::native_input::{
+    NativeTextInput, TextBuffer, SelectionState, CursorVisual, TextInputVisual, TextInputChangeEvent,
+    TextInputSettings, UndoHistory, focus_input, replace_content,
+};
+
+/// Width of the text input border, which the floated label straddles
+const INPUT_BORDER_WIDTH: f32 = 2.0;
+
+/// Bevy's default line height relative to the font size
+const LINE_HEIGHT_FACTOR: f32 = 1.2;
 
 /// Handle clicks on clear buttons to clear their associated text input
 pub fn handle_clear_button_clicks(
@@ -16,4 +32,301 @@ pub fn handle_clear_button_clicks(
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// Refresh character counters when their input's text changes
+pub fn update_character_counters(
+    inputs: Query<(&TextBuffer, &TextInputSettings), Changed<TextBuffer>>,
+    mut counters: Query<(&CharacterCounter, &TextInputPart, &mut Text, &mut TextColor)>,
+) {
+    for (counter, part, mut text, mut color) in counters.iter_mut() {
+        let Ok((buffer, settings)) = inputs.get(part.0) else {
+            continue;
+        };
+
+        let (label, at_limit) = CharacterCounter::label(&buffer.content, settings.max_length);
+        if text.0 != label {
+            text.0 = label;
+        }
+        color.set_if_neq(TextColor(if at_limit { counter.limit_color } else { counter.color }));
+    }
+}
+
+/// Float labels above focused or filled inputs, and settle them back inside empty ones
+///
+/// With the theme's `reduce_motion` set, labels jump straight to their place.
+pub fn animate_floating_labels(
+    time: Res<Time>,
+    theme: Option<Res<UiTheme>>,
+    mut labels: Query<(
+        &mut FloatingLabel,
+        &ChildOf,
+        &mut Node,
+        &mut TextFont,
+        &mut TextColor,
+        &mut BackgroundColor,
+    )>,
+    inputs: Query<(&TextBuffer, &ComputedNode), With<NativeTextInput>>,
+) {
+    let reduce_motion = theme.is_some_and(|theme| theme.reduce_motion);
+
+    for (mut label, child_of, mut node, mut font, mut color, mut background) in labels.iter_mut() {
+        let Ok((buffer, computed)) = inputs.get(child_of.parent()) else {
+            continue;
+        };
+
+        let floated = buffer.is_focused || !buffer.content.is_empty();
+        let target = if floated { 1.0 } else { 0.0 };
+        if label.progress != target {
+            let step = if label.duration > 0.0 && !reduce_motion {
+                time.delta_secs() / label.duration
+            } else {
+                1.0
+            };
+            label.progress = if target > label.progress {
+                (label.progress + step).min(target)
+            } else {
+                (label.progress - step).max(target)
+            };
+        }
+
+        let t = Easing::CubicOut.apply(label.progress);
+        let font_size = label.rest_font_size + (label.float_font_size - label.rest_font_size) * t;
+
+        // Resting: vertically centered in the field. Floated: centered on the top border.
+        let input_height = computed.size().y * computed.inverse_scale_factor();
+        let rest_top = (input_height - INPUT_BORDER_WIDTH * 2.0 - label.rest_font_size * LINE_HEIGHT_FACTOR) / 2.0;
+        let float_top = -INPUT_BORDER_WIDTH - label.float_font_size * LINE_HEIGHT_FACTOR / 2.0;
+        node.top = Val::Px(rest_top + (float_top - rest_top) * t);
+
+        if font.font_size != font_size {
+            font.font_size = font_size;
+        }
+
+        let text_color = if buffer.is_focused { label.focus_color } else { label.rest_color };
+        color.set_if_neq(TextColor(text_color));
+        background.set_if_neq(BackgroundColor(label.background.with_alpha(label.background.alpha() * t)));
+    }
+}
+
+/// Move the suggestion highlight with Up/Down and accept it with Tab/Enter
+///
+/// Runs after the native keyboard handlers, which leave Tab and Enter alone
+/// while the focused input's suggestion menu is open. Escape closes the menu
+/// when it is the topmost [`EscapeStack`] layer.
+pub fn handle_suggestion_keys(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    escape: Res<EscapeStack>,
+    mut inputs: Query<(Entity, &mut TextInputSuggestions, &mut TextBuffer, &mut SelectionState, &mut UndoHistory), With<NativeTextInput>>,
+    mut change_events: MessageWriter<TextInputChangeEvent>,
+) {
+    for (entity, mut suggestions, mut buffer, mut selection, mut history) in inputs.iter_mut() {
+        if !buffer.is_focused || !suggestions.is_open() {
+            continue;
+        }
+
+        let count = suggestions.candidates.len();
+        let accept = if keyboard.just_pressed(KeyCode::ArrowDown) {
+            suggestions.highlighted = Some(suggestions.highlighted.map_or(0, |index| (index + 1) % count));
+            None
+        } else if keyboard.just_pressed(KeyCode::ArrowUp) {
+            suggestions.highlighted = Some(suggestions.highlighted.map_or(count - 1, |index| (index + count - 1) % count));
+            None
+        } else if keyboard.just_pressed(KeyCode::Tab) {
+            Some(suggestions.highlighted.unwrap_or(0))
+        } else if keyboard.just_pressed(KeyCode::Enter) {
+            suggestions.highlighted
+        } else if escape.closes(suggestions.menu) {
+            suggestions.close(&buffer.content);
+            None
+        } else {
+            None
+        };
+
+        if let Some(index) = accept {
+            accept_suggestion(entity, index, &mut suggestions, &mut buffer, &mut selection, &mut history, &mut change_events);
+        }
+    }
+}
+
+/// Highlight hovered suggestions and accept clicked ones
+pub fn handle_suggestion_clicks(
+    items: Query<(&SuggestionItem, &Interaction), Changed<Interaction>>,
+    mut inputs: Query<(
+        &mut TextInputSuggestions,
+        &mut TextBuffer,
+        &mut SelectionState,
+        &mut CursorVisual,
+        &mut UndoHistory,
+    ), With<NativeTextInput>>,
+    mut change_events: MessageWriter<TextInputChangeEvent>,
+) {
+    for (item, interaction) in items.iter() {
+        let Ok((mut suggestions, mut buffer, mut selection, mut cursor_visual, mut history)) = inputs.get_mut(item.input) else {
+            continue;
+        };
+
+        match interaction {
+            Interaction::Hovered => {
+                if suggestions.highlighted != Some(item.index) {
+                    suggestions.highlighted = Some(item.index);
+                }
+            }
+            Interaction::Pressed => {
+                accept_suggestion(item.input, item.index, &mut suggestions, &mut buffer, &mut selection, &mut history, &mut change_events);
+
+                // The click landed outside the input, which dropped its focus
+                focus_input(&mut buffer, &mut cursor_visual);
+            }
+            Interaction::None => {}
+        }
+    }
+}
+
+/// Replace the input's text with a candidate and close the menu
+///
+/// The replacement is a single undoable edit that leaves the cursor at the
+/// end of the candidate.
+fn accept_suggestion(
+    entity: Entity,
+    index: usize,
+    suggestions: &mut TextInputSuggestions,
+    buffer: &mut TextBuffer,
+    selection: &mut SelectionState,
+    history: &mut UndoHistory,
+    change_events: &mut MessageWriter<TextInputChangeEvent>,
+) {
+    let Some(candidate) = suggestions.candidates.get(index).cloned() else {
+        return;
+    };
+
+    if let Some(mut op) = replace_content(buffer, selection, &candidate) {
+        buffer.cursor_pos = candidate.chars().count();
+        op.cursor_after = buffer.cursor_pos;
+        history.push(op);
+    }
+    suggestions.close(&buffer.content);
+
+    change_events.write(TextInputChangeEvent {
+        entity,
+        text: buffer.content.clone(),
+    });
+}
+
+/// Ask providers for new candidates and rebuild the suggestion menus
+///
+/// Menus open while their input is focused and has candidates, and close
+/// when it loses focus.
+pub fn update_suggestions(
+    mut commands: Commands,
+    mut inputs: Query<(Entity, &mut TextInputSuggestions, &TextBuffer, &TextInputVisual), With<NativeTextInput>>,
+    mut menus: Query<&mut Node, With<SuggestionMenu>>,
+) {
+    for (entity, mut suggestions, buffer, visual) in inputs.iter_mut() {
+        if !buffer.is_focused {
+            if suggestions.query.is_some() || suggestions.is_open() {
+                suggestions.candidates.clear();
+                suggestions.highlighted = None;
+                suggestions.query = None;
+            }
+        } else if suggestions.query.as_deref() != Some(buffer.content.as_str()) {
+            let mut candidates = (suggestions.provider)(&buffer.content);
+            candidates.truncate(suggestions.max_items);
+            suggestions.candidates = candidates;
+            suggestions.highlighted = None;
+            suggestions.query = Some(buffer.content.clone());
+
+            let font = visual.font.clone();
+            let text_color = visual.text_color;
+            let candidates = suggestions.candidates.clone();
+            commands.entity(suggestions.menu)
+                .despawn_related::<Children>()
+                .with_children(|menu| {
+                    for (index, candidate) in candidates.into_iter().enumerate() {
+                        menu.spawn((
+                            popup::menu_item_node(Val::Auto),
+                            BackgroundColor(Color::NONE),
+                            Interaction::default(),
+                            SuggestionItem { input: entity, index },
+                        )).with_child((
+                            Text::new(candidate),
+                            font.clone(),
+                            TextColor(text_color),
+                            Pickable::IGNORE,
+                        ));
+                    }
+                });
+        }
+
+        if let Ok(mut node) = menus.get_mut(suggestions.menu) {
+            let display = if suggestions.is_open() { Display::Flex } else { Display::None };
+            if node.display != display {
+                node.display = display;
+                if suggestions.is_open() {
+                    commands.entity(suggestions.menu).insert(EscapeLayer::new(dimensions::Z_INDEX_POPUP));
+                } else {
+                    commands.entity(suggestions.menu).remove::<EscapeLayer>();
+                }
+            }
+        }
+    }
+}
+
+/// Tint the highlighted suggestion row
+pub fn update_suggestion_highlight(
+    inputs: Query<(&TextInputSuggestions, &TextInputVisual), Changed<TextInputSuggestions>>,
+    mut items: Query<(&SuggestionItem, &mut BackgroundColor)>,
+) {
+    for (item, mut background) in items.iter_mut() {
+        let Ok((suggestions, visual)) = inputs.get(item.input) else {
+            continue;
+        };
+
+        let color = if suggestions.highlighted == Some(item.index) {
+            visual.selection_color
+        } else {
+            Color::NONE
+        };
+        background.set_if_neq(BackgroundColor(color));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clicked_suggestion_is_one_undo_step() {
+        let mut app = App::new();
+        app.add_message::<TextInputChangeEvent>()
+            .add_systems(Update, handle_suggestion_clicks);
+
+        let input = app.world_mut().spawn((
+            TextInputSuggestions {
+                provider: |_| Vec::new(),
+                max_items: 8,
+                candidates: vec!["Berlin".to_string()],
+                highlighted: None,
+                menu: Entity::PLACEHOLDER,
+                query: None,
+            },
+            TextBuffer {
+                content: "Be".to_string(),
+                cursor_pos: 2,
+                is_focused: false,
+            },
+            SelectionState::default(),
+            CursorVisual::default(),
+            UndoHistory::default(),
+            NativeTextInput,
+        )).id();
+        app.world_mut().spawn((SuggestionItem { input, index: 0 }, Interaction::Pressed));
+        app.update();
+
+        let buffer = app.world().get::<TextBuffer>(input).unwrap();
+        assert_eq!((buffer.content.as_str(), buffer.cursor_pos, buffer.is_focused), ("Berlin", 6, true));
+        let history = app.world().get::<UndoHistory>(input).unwrap();
+        assert_eq!(history.undo_stack.len(), 1);
+        assert_eq!(history.undo_stack[0].cursor_before, 2);
+    }
+}
@@ -2,6 +2,7 @@
 
 use bevy::prelude::*;
 use bevy::color::Alpha;
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::theme::UiTheme;
 
@@ -33,8 +34,13 @@ pub enum InputFilter {
     Alphanumeric,
     /// Only allow hexadecimal characters (0-9, a-f, A-F)
     Hexadecimal,
-    /// Custom regex pattern
-    Regex(String),
+    /// Text matching a regex pattern (requires the `regex` feature)
+    ///
+    /// The pattern must match the whole text each edit would produce, so it
+    /// has to accept every partial entry on the way to a complete one:
+    /// `\d{0,3}(-\d{0,4})?` rather than `\d{3}-\d{4}`. Build it with
+    /// [`InputFilter::regex`].
+    Regex(RegexPattern),
     /// Custom filter function
     Custom(fn(&str) -> bool),
 }
@@ -49,7 +55,7 @@ impl PartialEq for InputFilter {
             (Self::Alphabetic, Self::Alphabetic) => true,
             (Self::Alphanumeric, Self::Alphanumeric) => true,
             (Self::Hexadecimal, Self::Hexadecimal) => true,
-            (Self::Regex(s1), Self::Regex(s2)) => s1 == s2,
+            (Self::Regex(p1), Self::Regex(p2)) => p1.as_str() == p2.as_str(),
             (Self::Custom(f1), Self::Custom(f2)) => {
                 // Function pointers can't be reliably compared, so we assume they are different
                 // unless they are literally the same pointer address (which is what standard PartialEq does but warns about)
@@ -62,6 +68,70 @@ impl PartialEq for InputFilter {
     }
 }
 
+/// A pattern for [`InputFilter::Regex`], compiled once when it's created
+///
+/// Without the `regex` feature, and for invalid patterns, nothing is
+/// filtered; both cases log a warning when the pattern is created.
+#[derive(Clone, Debug)]
+pub struct RegexPattern {
+    pattern: String,
+    #[cfg(feature = "regex")]
+    compiled: Option<regex::Regex>,
+}
+
+impl RegexPattern {
+    /// Compile `pattern`, anchored to match the whole text
+    pub fn new(pattern: impl Into<String>) -> Self {
+        let pattern = pattern.into();
+
+        #[cfg(feature = "regex")]
+        let compiled = match regex::Regex::new(&format!("^(?:{pattern})$")) {
+            Ok(regex) => Some(regex),
+            Err(error) => {
+                warn!("Invalid regex input filter {pattern:?}, nothing will be filtered: {error}");
+                None
+            }
+        };
+        #[cfg(not(feature = "regex"))]
+        warn!("Regex input filter {pattern:?} needs the `regex` feature, nothing will be filtered");
+
+        Self {
+            pattern,
+            #[cfg(feature = "regex")]
+            compiled,
+        }
+    }
+
+    /// The pattern as it was written
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Whether the pattern compiled and can filter anything
+    #[cfg(feature = "regex")]
+    pub fn is_valid(&self) -> bool {
+        self.compiled.is_some()
+    }
+
+    /// Whether the pattern compiled and can filter anything
+    #[cfg(not(feature = "regex"))]
+    pub fn is_valid(&self) -> bool {
+        false
+    }
+
+    /// Whether `text` matches as a whole (always true when nothing is filtered)
+    #[cfg(feature = "regex")]
+    pub fn is_match(&self, text: &str) -> bool {
+        self.compiled.as_ref().is_none_or(|regex| regex.is_match(text))
+    }
+
+    /// Whether `text` matches as a whole (always true when nothing is filtered)
+    #[cfg(not(feature = "regex"))]
+    pub fn is_match(&self, _text: &str) -> bool {
+        true
+    }
+}
+
 /// Text transformation options
 #[derive(Clone, Debug, PartialEq)]
 pub enum InputTransform {
@@ -86,6 +156,13 @@ impl Default for TextInputFilter {
 }
 
 impl InputFilter {
+    /// A regex filter, compiling `pattern` once up front
+    ///
+    /// See [`InputFilter::Regex`] for how the pattern is matched.
+    pub fn regex(pattern: impl Into<String>) -> Self {
+        Self::Regex(RegexPattern::new(pattern))
+    }
+
     /// Check if a character is valid for this filter
     pub fn is_valid_char(&self, ch: char, current_text: &str) -> bool {
         match self {
@@ -100,10 +177,11 @@ impl InputFilter {
             InputFilter::Alphabetic => ch.is_alphabetic(),
             InputFilter::Alphanumeric => ch.is_alphanumeric(),
             InputFilter::Hexadecimal => ch.is_ascii_hexdigit(),
-            InputFilter::Regex(_pattern) => {
-                // For regex, we'd need to check the entire string
-                // This is a simplified check
-                true // Will be validated in the full string check
+            InputFilter::Regex(_) => {
+                // Regexes only make sense against the whole text
+                let mut test_string = current_text.to_string();
+                test_string.push(ch);
+                self.is_valid_string(&test_string)
             }
             InputFilter::Custom(validator) => {
                 // Test if adding this character would be valid
@@ -155,14 +233,25 @@ impl InputFilter {
             InputFilter::Alphabetic => text.chars().all(|c| c.is_alphabetic()),
             InputFilter::Alphanumeric => text.chars().all(|c| c.is_alphanumeric()),
             InputFilter::Hexadecimal => text.chars().all(|c| c.is_ascii_hexdigit()),
-            InputFilter::Regex(_pattern) => {
-                // Would need regex crate for full support
-                true // Simplified for now
-            }
+            InputFilter::Regex(pattern) => pattern.is_match(text),
             InputFilter::Custom(validator) => validator(text),
         }
     }
 
+    /// Whether replacing the chars in `start..end` of `text` with `inserted`
+    /// leaves valid text
+    ///
+    /// This is how typing and pasting are filtered: the whole resulting text
+    /// is validated, not just the new characters, so the result depends on
+    /// where they go.
+    pub fn allows_insert(&self, text: &str, (start, end): (usize, usize), inserted: &str) -> bool {
+        let candidate: String = text.chars().take(start)
+            .chain(inserted.chars())
+            .chain(text.chars().skip(end))
+            .collect();
+        self.is_valid_string(&candidate)
+    }
+
     /// Filter out invalid characters from a string
     pub fn filter_string(&self, text: &str) -> String {
         let mut result = String::new();
@@ -179,6 +268,135 @@ impl InputFilter {
 #[derive(Component)]
 pub struct ClearButtonTarget(pub Entity);
 
+/// Small "12/20" label showing how much of an input's max length is used
+///
+/// Spawned by [`TextInputBuilder::show_counter`](super::TextInputBuilder::show_counter)
+/// as a child of the input, linked to it with a `TextInputPart`. Counts
+/// graphemes, the same unit `max_length` is enforced in, so a family emoji
+/// joined from several code points counts as one.
+#[derive(Component, Debug, Clone)]
+pub struct CharacterCounter {
+    /// Text color while under the limit
+    pub color: Color,
+    /// Text color once the limit is reached
+    pub limit_color: Color,
+}
+
+impl CharacterCounter {
+    /// Counter text for `text`, and whether it has reached `max_length`
+    pub fn label(text: &str, max_length: Option<usize>) -> (String, bool) {
+        let count = text.graphemes(true).count();
+        match max_length {
+            Some(max) => (format!("{count}/{max}"), count >= max),
+            None => (count.to_string(), false),
+        }
+    }
+}
+
+/// Material-style label that rests inside an empty input and floats above it
+/// when the input is focused or filled
+///
+/// Spawned as a child of the input by [`TextInputBuilder::floating_label`](super::TextInputBuilder::floating_label).
+#[derive(Component, Debug, Clone)]
+pub struct FloatingLabel {
+    /// Animation progress: 0.0 = resting inside the field, 1.0 = floated above it
+    pub progress: f32,
+    /// Seconds a full rest <-> float transition takes
+    pub duration: f32,
+    /// Font size while resting inside the field
+    pub rest_font_size: f32,
+    /// Font size while floated above the field
+    pub float_font_size: f32,
+    /// Text color while resting (and while floated but unfocused)
+    pub rest_color: Color,
+    /// Text color while floated above a focused input
+    pub focus_color: Color,
+    /// Background shown behind the floated label so it cuts the border line
+    pub background: Color,
+}
+
+/// Produces autocomplete candidates for the current text of an input
+pub type SuggestionProvider = fn(&str) -> Vec<String>;
+
+/// Autocomplete suggestions for a text input
+///
+/// Added by [`TextInputBuilder::suggestions`](super::TextInputBuilder::suggestions).
+/// While the input is focused, the provider is asked for candidates whenever
+/// the text changes and they are listed in a popup menu below the field.
+/// Up/Down move the highlight, Tab or Enter accept it, Escape closes the menu.
+#[derive(Component, Clone)]
+pub struct TextInputSuggestions {
+    /// Produces candidates for the current text
+    pub provider: SuggestionProvider,
+    /// Most candidates listed at once
+    pub max_items: usize,
+    /// Candidates currently listed (empty while the menu is closed)
+    pub candidates: Vec<String>,
+    /// Keyboard-highlighted candidate
+    pub highlighted: Option<usize>,
+    /// The popup menu listing the candidates
+    pub menu: Entity,
+    /// Text the candidates were last computed for
+    pub(crate) query: Option<String>,
+}
+
+impl TextInputSuggestions {
+    /// Whether the suggestion menu is showing candidates
+    pub fn is_open(&self) -> bool {
+        !self.candidates.is_empty()
+    }
+
+    /// Close the menu until the text changes again
+    pub fn close(&mut self, text: &str) {
+        self.candidates.clear();
+        self.highlighted = None;
+        self.query = Some(text.to_string());
+    }
+}
+
+/// Greyed-out completion drawn after the typed text, accepted with Tab
+///
+/// Added by [`TextInputBuilder::ghost_suggestion`](super::TextInputBuilder::ghost_suggestion).
+/// Unlike a placeholder it stays up while typing: as long as the focused
+/// input's text is the start of the suggestion (ignoring case) and the
+/// cursor is at the end, the rest of the suggestion is shown in the
+/// placeholder color, like a terminal's autosuggestion. Tab fills it in
+/// instead of moving to the next field. Change the suggestion at any time
+/// to follow what is being typed.
+#[derive(Component, Clone, Debug, PartialEq, Eq)]
+pub struct GhostSuggestion(pub String);
+
+impl GhostSuggestion {
+    /// The part of the suggestion still to type after `content`, if
+    /// `content` leads to it and anything is left
+    pub fn remainder(&self, content: &str) -> Option<&str> {
+        let mut rest = self.0.char_indices();
+        for typed in content.chars() {
+            let (_, expected) = rest.next()?;
+            if !typed.to_lowercase().eq(expected.to_lowercase()) {
+                return None;
+            }
+        }
+        rest.next().map(|(start, _)| &self.0[start..])
+    }
+}
+
+/// Popup menu listing a text input's suggestions
+#[derive(Component)]
+pub struct SuggestionMenu {
+    /// The text input the menu belongs to
+    pub input: Entity,
+}
+
+/// A row in a [`SuggestionMenu`]
+#[derive(Component, Clone, Copy, Debug)]
+pub struct SuggestionItem {
+    /// The text input the suggestion belongs to
+    pub input: Entity,
+    /// Index into [`TextInputSuggestions::candidates`]
+    pub index: usize,
+}
+
 /// Defines how a text input participates in focus management
 #[derive(Component, Clone, Debug)]
 pub enum TextInputFocus {
@@ -212,6 +430,7 @@ pub(crate) mod defaults {
     pub const TEXT_PRIMARY: Color = Color::srgb(0.95, 0.95, 0.95);
     pub const TEXT_MUTED: Color = Color::srgb(0.5, 0.5, 0.5);
     pub const PRIMARY: Color = Color::srgb(0.25, 0.46, 0.86);
+    pub const DANGER: Color = Color::srgb(0.86, 0.25, 0.25);
 }
 
 /// Resolved text input colors from theme
@@ -231,6 +450,8 @@ pub struct TextInputColors {
     pub placeholder: Color,
     /// Selection highlight color
     pub selection: Color,
+    /// Character counter color at the limit
+    pub danger: Color,
 }
 
 impl TextInputColors {
@@ -244,6 +465,7 @@ impl TextInputColors {
             text: theme.colors.text.primary,
             placeholder: theme.colors.text.muted,
             selection: theme.colors.primary.base.with_alpha(0.3),
+            danger: theme.colors.danger.base,
         }
     }
 
@@ -257,6 +479,72 @@ impl TextInputColors {
             text: defaults::TEXT_PRIMARY,
             placeholder: defaults::TEXT_MUTED,
             selection: defaults::PRIMARY.with_alpha(0.3),
+            danger: defaults::DANGER,
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insertions_are_checked_against_the_resulting_text() {
+        // Digits only, at most four of them
+        let filter = InputFilter::Custom(|text| text.len() <= 4 && text.chars().all(|c| c.is_ascii_digit()));
+
+        assert!(filter.allows_insert("123", (1, 1), "9"));
+        assert!(!filter.allows_insert("1234", (2, 2), "9"));
+        // Replacing a selection frees up room
+        assert!(filter.allows_insert("1234", (1, 3), "9"));
+        assert!(!filter.allows_insert("12", (1, 1), "x"));
+    }
+
+    #[test]
+    fn counter_counts_graphemes() {
+        assert_eq!(CharacterCounter::label("hello", Some(20)), ("5/20".to_string(), false));
+        // A single-codepoint emoji counts as one character
+        assert_eq!(CharacterCounter::label("party 🎉", Some(7)), ("7/7".to_string(), true));
+        assert_eq!(CharacterCounter::label("abc", None), ("3".to_string(), false));
+        // So does a ZWJ family emoji, seven code points in all
+        let family = "hi \u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}";
+        assert_eq!(family.chars().count(), 10);
+        assert_eq!(CharacterCounter::label(family, Some(4)), ("4/4".to_string(), true));
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn regex_filters_insertions_mid_string() {
+        // A partially typed phone number: up to 3 digits, a dash, up to 4 digits
+        let filter = InputFilter::regex(r"\d{0,3}(-\d{0,4})?");
+
+        assert!(filter.allows_insert("12-34", (2, 2), "3"));
+        assert!(filter.allows_insert("123", (3, 3), "-"));
+        assert!(!filter.allows_insert("123-45", (1, 1), "9"));
+        assert!(!filter.allows_insert("12-34", (3, 3), "-"));
+        assert!(!filter.allows_insert("12-34", (1, 1), "a"));
+        // The pattern must match the whole text, not just part of it
+        assert!(!filter.is_valid_string("1234"));
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn invalid_regex_filters_nothing() {
+        let pattern = RegexPattern::new(r"(\d");
+
+        assert!(!pattern.is_valid());
+        assert!(InputFilter::Regex(pattern).allows_insert("12", (2, 2), "x"));
+    }
+
+    #[test]
+    fn ghost_suggestion_completes_typed_prefix() {
+        let ghost = GhostSuggestion("assets/Textures/".to_string());
+
+        assert_eq!(ghost.remainder(""), Some("assets/Textures/"));
+        assert_eq!(ghost.remainder("assets/tex"), Some("tures/"));
+        // Nothing left to suggest, or the text went another way
+        assert_eq!(ghost.remainder("assets/Textures/"), None);
+        assert_eq!(ghost.remainder("assets/sounds"), None);
+        assert_eq!(ghost.remainder("assets/Textures/stone.png"), None);
+    }
+}
@@ -113,6 +113,34 @@ impl ToastBuilder {
         self
     }
 
+    /// Show a custom icon image instead of the variant's default icon
+    ///
+    /// Overrides the theme's icon for this variant
+    /// (`UiTheme::components.toast`).
+    pub fn icon(mut self, icon: Handle<Image>) -> Self {
+        self.toast.icon = Some(icon);
+        self
+    }
+
+    /// Collapse this toast with others in the same group
+    ///
+    /// While a toast from group `key` is on screen, later ones join it
+    /// instead of stacking up. With more than one, the toast shows a count
+    /// header built from the key and can be expanded to list each message.
+    ///
+    /// # Example
+    /// ```ignore
+    /// for file in failed_downloads {
+    ///     ToastBuilder::error(format!("{file} failed to download"))
+    ///         .group("download errors") // "3 download errors"
+    ///         .show(&mut toasts);
+    /// }
+    /// ```
+    pub fn group(mut self, key: impl Into<String>) -> Self {
+        self.toast.group = Some(key.into());
+        self
+    }
+
     /// Show the toast by adding it to the queue
     pub fn show(self, queue: &mut ToastQueue) {
         queue.push(self.toast);
@@ -7,8 +7,13 @@
 //! - Multiple toast variants (info, success, warning, error)
 //! - Configurable position and duration
 //! - Optional action buttons
+//! - Per-variant icons, overridable per toast or per theme
 //! - Dismissible toasts
-//! - Queue management for multiple toasts
+//! - Queue management for multiple toasts, with a cap on how many show at
+//!   once and an optional "+3 more" line for the rest
+//! - Grouping related toasts under one expandable header
+//! - Configurable enter/exit transitions via `ToastSettings`
+//! - Optional strip showing each toast's remaining time
 //!
 //! # Examples
 //!
@@ -31,6 +36,18 @@
 //!     show_success(&mut toasts, "Operation completed");
 //!     show_error(&mut toasts, "Something went wrong");
 //! }
+//!
+//! // Scale toasts in from the corner instead of fading
+//! fn configure_toasts(mut settings: ResMut<ToastSettings>) {
+//!     settings.enter_transition = Transition::scale_in(0.6, 0.25);
+//!     settings.exit_transition = Transition::scale_out(0.6, 0.2);
+//! }
+//!
+//! // Show at most three toasts, with a "+N more" line for the rest
+//! fn limit_toasts(mut settings: ResMut<ToastSettings>) {
+//!     settings.max_visible = 3;
+//!     settings.overflow_indicator = true;
+//! }
 //! ```
 
 mod builder;
@@ -42,5 +59,5 @@ pub use builder::{ToastBuilder, show_toast, show_success, show_error, show_warni
 pub use plugin::ToastPlugin;
 pub use types::{
     Toast, ToastVariant, ToastPosition, ToastQueue, ToastSettings,
-    ActiveToast, ToastContainer, ToastActionEvent, DismissToastEvent,
+    ActiveToast, ToastGroup, ToastContainer, ToastOverflowIndicator, ToastActionEvent, DismissToastEvent,
 };
@@ -16,9 +16,16 @@ define_plugin!(ToastPlugin {
         ensure_toast_container,
         spawn_toasts,
         update_toast_timers,
+        update_toast_progress.after(update_toast_timers),
         handle_toast_dismiss,
         handle_toast_action,
         handle_dismiss_events,
+        handle_toast_group_toggle,
+        refresh_toast_groups
+            .after(spawn_toasts)
+            .after(handle_toast_group_toggle),
+        sync_toast_overflow_indicator.after(spawn_toasts),
         despawn_exiting_toasts,
+        apply_toast_content_opacity,
     ]
 });
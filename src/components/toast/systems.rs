@@ -1,6 +1,9 @@
 //! Toast systems
 
 use bevy::prelude::*;
+use crate::animation::{EnterAnimating, ExitAnimating, Transition, UiAnimation};
+use crate::components::progress::{ProgressBar, ProgressBarBuilder, ProgressBarFill, ProgressBarStyle};
+use crate::traits::{LayoutBuilder, UiBuilder};
 use crate::styles::dimensions;
 use crate::theme::UiTheme;
 use super::types::*;
@@ -44,18 +47,40 @@ pub fn ensure_toast_container(
 }
 
 /// System to spawn new toasts from the queue
+///
+/// Only `max_visible` toasts are on screen at once (exiting ones don't
+/// count); the rest stay queued until one leaves. Grouped toasts whose group
+/// is already on screen join that toast instead of spawning (and don't count
+/// towards `max_visible`).
 pub fn spawn_toasts(
     mut commands: Commands,
     settings: Res<ToastSettings>,
     mut queue: ResMut<ToastQueue>,
     container_query: Query<Entity, With<ToastContainer>>,
-    active_toasts: Query<&ActiveToast>,
+    mut active_toasts: Query<(&mut ActiveToast, Option<&mut ToastGroup>)>,
     theme: Option<Res<UiTheme>>,
 ) {
     let Ok(container) = container_query.single() else {
         return;
     };
 
+    // Fold pending toasts into groups already on screen, keeping the group
+    // up for at least as long as its newest member would have been
+    for (mut active, group) in active_toasts.iter_mut() {
+        let Some(mut group) = group else {
+            continue;
+        };
+        if active.exiting {
+            continue;
+        }
+        let joining = queue.take_group(&group.key);
+        if !joining.is_empty() {
+            let longest = group.absorb(joining);
+            active.time_remaining = active.time_remaining.max(longest.as_secs_f32());
+            active.duration = active.duration.max(active.time_remaining);
+        }
+    }
+
     // Resolve colors from theme or use defaults
     let colors = if let Some(ref theme) = theme {
         ToastColors::from_theme(theme)
@@ -64,7 +89,7 @@ pub fn spawn_toasts(
     };
 
     // Count visible toasts
-    let visible_count = active_toasts.iter().filter(|t| !t.exiting).count();
+    let visible_count = active_toasts.iter().filter(|(t, _)| !t.exiting).count();
 
     // Spawn new toasts up to the limit
     while visible_count < settings.max_visible {
@@ -75,131 +100,384 @@ pub fn spawn_toasts(
         let bg_color = colors.background;
         let accent_color = colors.accent_for_variant(toast.variant);
 
-        let duration_secs = toast.duration.as_secs_f32();
-        let toast_clone = toast.clone();
+        // A new group takes every pending toast of the same group with it
+        let group = toast.group.clone().map(|key| {
+            let mut group = ToastGroup::new(key.clone(), toast.clone());
+            group.absorb(queue.take_group(&key));
+            group
+        });
+        let duration_secs = group.as_ref()
+            .map(|group| group.toasts.iter().map(|toast| toast.duration).max().unwrap_or_default())
+            .unwrap_or(toast.duration)
+            .as_secs_f32();
+
+        let icon = toast_icon(&toast, theme.as_deref());
 
         commands.entity(container).with_children(|parent| {
-            parent
-                .spawn((
+            let mut toast_entity = parent.spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    padding: UiRect::all(Val::Px(12.0)),
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(12.0),
+                    align_items: AlignItems::FlexStart,
+                    border: UiRect::left(Val::Px(4.0)),
+                    ..default()
+                },
+                BackgroundColor(bg_color),
+                BorderColor::all(accent_color),
+                BorderRadius::all(Val::Px(6.0)),
+                ActiveToast {
+                    toast: toast.clone(),
+                    time_remaining: duration_secs,
+                    duration: duration_secs,
+                    animation_progress: 0.0,
+                    exiting: false,
+                },
+                Interaction::default(),
+                Transform::default(),
+                UiAnimation::new()
+                    .with_enter(settings.enter_transition.clone())
+                    .with_exit(settings.exit_transition.clone()),
+                EnterAnimating,
+            ));
+            let entity = toast_entity.id();
+
+            if let Some(group) = &group {
+                toast_entity.insert(group.clone());
+            }
+            toast_entity.with_children(|toast_node| {
+                spawn_toast_contents(toast_node, entity, &toast, group.as_ref(), icon, &colors, settings.show_progress.then_some(1.0));
+            });
+        });
+
+        break; // Only spawn one per frame for smoother animation
+    }
+}
+
+/// Show, update or remove the "+N more" line for toasts still in the queue
+///
+/// The line is kept below the visible toasts as new ones are spawned.
+pub fn sync_toast_overflow_indicator(
+    mut commands: Commands,
+    settings: Res<ToastSettings>,
+    queue: Res<ToastQueue>,
+    containers: Query<(Entity, Option<&Children>), With<ToastContainer>>,
+    mut indicators: Query<(Entity, &mut ToastOverflowIndicator, &Children)>,
+    mut texts: Query<&mut Text>,
+    theme: Option<Res<UiTheme>>,
+) {
+    let Ok((container, children)) = containers.single() else {
+        return;
+    };
+    let waiting = if settings.overflow_indicator { queue.queue.len() } else { 0 };
+
+    let Ok((indicator, mut overflow, label)) = indicators.single_mut() else {
+        if waiting > 0 {
+            let colors = match theme.as_deref() {
+                Some(theme) => ToastColors::from_theme(theme),
+                None => ToastColors::default_colors(),
+            };
+            commands.entity(container).with_children(|parent| {
+                parent.spawn((
                     Node {
-                        width: Val::Percent(100.0),
-                        padding: UiRect::all(Val::Px(12.0)),
-                        flex_direction: FlexDirection::Row,
-                        column_gap: Val::Px(12.0),
-                        align_items: AlignItems::FlexStart,
-                        border: UiRect::left(Val::Px(4.0)),
+                        padding: UiRect::axes(Val::Px(12.0), Val::Px(4.0)),
                         ..default()
                     },
-                    BackgroundColor(bg_color),
-                    BorderColor::all(accent_color),
+                    BackgroundColor(colors.background),
                     BorderRadius::all(Val::Px(6.0)),
-                    ActiveToast {
-                        toast: toast_clone,
-                        time_remaining: duration_secs,
-                        animation_progress: 0.0,
-                        exiting: false,
+                    ToastOverflowIndicator { count: waiting },
+                )).with_child((
+                    Text::new(ToastOverflowIndicator::label(waiting)),
+                    TextFont {
+                        font_size: dimensions::FONT_SIZE_SMALL,
+                        ..default()
                     },
-                    Interaction::default(),
+                    TextColor(colors.text_secondary),
+                ));
+            });
+        }
+        return;
+    };
+
+    if waiting == 0 {
+        commands.entity(indicator).despawn();
+        return;
+    }
+
+    if overflow.count != waiting {
+        overflow.count = waiting;
+        for child in label.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                text.0 = ToastOverflowIndicator::label(waiting);
+            }
+        }
+    }
+
+    // New toasts spawn after the line. Moves the existing child back to the end
+    let children = children.map(|children| &children[..]).unwrap_or_default();
+    if children.last().is_some_and(|last| *last != indicator) {
+        commands.entity(container).insert_children(children.len() - 1, &[indicator]);
+    }
+}
+
+/// Icon for a toast: its own > the theme's for its variant > none (built-in glyph)
+fn toast_icon(toast: &Toast, theme: Option<&UiTheme>) -> Option<Handle<Image>> {
+    toast.icon.clone().or_else(|| theme.and_then(|theme| toast.variant.theme_icon(theme)))
+}
+
+/// Spawn the icon, text, and buttons inside a toast
+///
+/// Groups holding more than one toast show a count header and either the
+/// latest message or, when expanded, every message, instead of the toast's
+/// own title, message, and action. `progress` is the remaining time the
+/// progress strip starts at, if it's shown.
+fn spawn_toast_contents(
+    toast_node: &mut ChildSpawnerCommands,
+    toast_entity: Entity,
+    toast: &Toast,
+    group: Option<&ToastGroup>,
+    icon: Option<Handle<Image>>,
+    colors: &ToastColors,
+    progress: Option<f32>,
+) {
+    let accent_color = colors.accent_for_variant(toast.variant);
+    let group = group.filter(|group| group.is_collapsed());
+
+    // Icon slot (fixed size so text lines up across toasts)
+    let icon_node = Node {
+        width: Val::Px(TOAST_ICON_SIZE),
+        height: Val::Px(TOAST_ICON_SIZE),
+        flex_shrink: 0.0,
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    };
+    match icon {
+        Some(image) => {
+            toast_node.spawn((
+                icon_node,
+                ImageNode::new(image),
+                ToastIcon,
+            ));
+        }
+        None => {
+            toast_node
+                .spawn((
+                    icon_node,
+                    BackgroundColor(accent_color),
+                    BorderRadius::all(Val::Percent(50.0)),
+                    ToastIcon,
                 ))
-                .with_children(|toast_node| {
-                    // Content container
-                    toast_node
-                        .spawn((
-                            Node {
-                                flex_grow: 1.0,
-                                flex_direction: FlexDirection::Column,
-                                row_gap: Val::Px(4.0),
+                .with_children(|badge| {
+                    badge.spawn((
+                        Text::new(toast.variant.glyph()),
+                        TextFont {
+                            font_size: dimensions::FONT_SIZE_SMALL,
+                            ..default()
+                        },
+                        TextColor(colors.text_on_button),
+                    ));
+                });
+        }
+    }
+
+    // Content container
+    toast_node
+        .spawn((
+            Node {
+                flex_grow: 1.0,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+        ))
+        .with_children(|content| {
+            if let Some(group) = group {
+                spawn_group_contents(content, toast_entity, group, colors);
+                return;
+            }
+
+            // Title if present
+            if let Some(title) = &toast.title {
+                content.spawn((
+                    Text::new(title),
+                    TextFont {
+                        font_size: dimensions::FONT_SIZE_MEDIUM,
+                        ..default()
+                    },
+                    TextColor(colors.text_primary),
+                ));
+            }
+
+            // Message
+            content.spawn((
+                Text::new(&toast.message),
+                TextFont {
+                    font_size: dimensions::FONT_SIZE_SMALL,
+                    ..default()
+                },
+                TextColor(if toast.title.is_some() {
+                    colors.text_secondary
+                } else {
+                    colors.text_primary
+                }),
+            ));
+
+            // Action button if present
+            if let Some(action_text) = &toast.action {
+                content
+                    .spawn((
+                        Node {
+                            margin: UiRect::top(Val::Px(8.0)),
+                            padding: UiRect::new(
+                                Val::Px(12.0),
+                                Val::Px(12.0),
+                                Val::Px(6.0),
+                                Val::Px(6.0),
+                            ),
+                            ..default()
+                        },
+                        BackgroundColor(accent_color),
+                        BorderRadius::all(Val::Px(4.0)),
+                        Interaction::default(),
+                        ToastActionButton,
+                    ))
+                    .with_children(|btn| {
+                        btn.spawn((
+                            Text::new(action_text),
+                            TextFont {
+                                font_size: dimensions::FONT_SIZE_SMALL,
                                 ..default()
                             },
-                        ))
-                        .with_children(|content| {
-                            // Title if present
-                            if let Some(title) = &toast.title {
-                                content.spawn((
-                                    Text::new(title),
-                                    TextFont {
-                                        font_size: dimensions::FONT_SIZE_MEDIUM,
-                                        ..default()
-                                    },
-                                    TextColor(colors.text_primary),
-                                ));
-                            }
-
-                            // Message
-                            content.spawn((
-                                Text::new(&toast.message),
-                                TextFont {
-                                    font_size: dimensions::FONT_SIZE_SMALL,
-                                    ..default()
-                                },
-                                TextColor(if toast.title.is_some() {
-                                    colors.text_secondary
-                                } else {
-                                    colors.text_primary
-                                }),
-                            ));
-
-                            // Action button if present
-                            if let Some(action_text) = &toast.action {
-                                content
-                                    .spawn((
-                                        Node {
-                                            margin: UiRect::top(Val::Px(8.0)),
-                                            padding: UiRect::new(
-                                                Val::Px(12.0),
-                                                Val::Px(12.0),
-                                                Val::Px(6.0),
-                                                Val::Px(6.0),
-                                            ),
-                                            ..default()
-                                        },
-                                        BackgroundColor(accent_color),
-                                        BorderRadius::all(Val::Px(4.0)),
-                                        Interaction::default(),
-                                        ToastActionButton,
-                                    ))
-                                    .with_children(|btn| {
-                                        btn.spawn((
-                                            Text::new(action_text),
-                                            TextFont {
-                                                font_size: dimensions::FONT_SIZE_SMALL,
-                                                ..default()
-                                            },
-                                            TextColor(colors.text_on_button),
-                                        ));
-                                    });
-                            }
-                        });
-
-                    // Dismiss button if dismissible
-                    if toast.dismissible {
-                        toast_node
-                            .spawn((
-                                Node {
-                                    padding: UiRect::all(Val::Px(4.0)),
-                                    ..default()
-                                },
-                                Interaction::default(),
-                                ToastDismissButton,
-                            ))
-                            .with_children(|btn| {
-                                btn.spawn((
-                                    Text::new("x"),
-                                    TextFont {
-                                        font_size: dimensions::FONT_SIZE_SMALL,
-                                        ..default()
-                                    },
-                                    TextColor(colors.text_secondary),
-                                ));
-                            });
-                    }
-                });
+                            TextColor(colors.text_on_button),
+                        ));
+                    });
+            }
         });
 
-        break; // Only spawn one per frame for smoother animation
+    // Dismiss button if dismissible
+    if toast.dismissible {
+        toast_node
+            .spawn((
+                Node {
+                    padding: UiRect::all(Val::Px(4.0)),
+                    ..default()
+                },
+                Interaction::default(),
+                ToastDismissButton,
+            ))
+            .with_children(|btn| {
+                btn.spawn((
+                    Text::new("x"),
+                    TextFont {
+                        font_size: dimensions::FONT_SIZE_SMALL,
+                        ..default()
+                    },
+                    TextColor(colors.text_secondary),
+                ));
+            });
+    }
+
+    if let Some(progress) = progress {
+        spawn_toast_progress(toast_node, accent_color, progress);
     }
 }
 
+/// Spawn the strip along the bottom of a toast showing its remaining time
+///
+/// A regular thin [`ProgressBar`] kept in sync by [`update_toast_progress`].
+fn spawn_toast_progress(toast_node: &mut ChildSpawnerCommands, accent_color: Color, progress: f32) {
+    ProgressBarBuilder::new(progress)
+        .style(ProgressBarStyle::Thin)
+        .height(Val::Px(TOAST_PROGRESS_HEIGHT))
+        .fill_color(accent_color)
+        .track_color(Color::NONE)
+        .node(Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.0),
+            right: Val::Px(0.0),
+            bottom: Val::Px(0.0),
+            flex_direction: FlexDirection::Column,
+            ..default()
+        })
+        .insert(ToastProgressStrip)
+        .build(toast_node);
+}
+
+/// Spawn a group's count header, messages, and expand/collapse toggle
+fn spawn_group_contents(
+    content: &mut ChildSpawnerCommands,
+    toast_entity: Entity,
+    group: &ToastGroup,
+    colors: &ToastColors,
+) {
+    content.spawn((
+        Text::new(group.header()),
+        TextFont {
+            font_size: dimensions::FONT_SIZE_MEDIUM,
+            ..default()
+        },
+        TextColor(colors.text_primary),
+        ToastGroupCount,
+    ));
+
+    content
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            ToastGroupMessages,
+        ))
+        .with_children(|list| spawn_group_messages(list, group, colors));
+
+    content
+        .spawn((
+            Node {
+                margin: UiRect::top(Val::Px(4.0)),
+                ..default()
+            },
+            Interaction::default(),
+            ToastGroupToggle { toast: toast_entity },
+        ))
+        .with_children(|toggle| {
+            toggle.spawn((
+                Text::new(group_toggle_label(group)),
+                TextFont {
+                    font_size: dimensions::FONT_SIZE_SMALL,
+                    ..default()
+                },
+                TextColor(colors.accent_for_variant(group.toasts[0].variant)),
+            ));
+        });
+}
+
+/// Spawn the latest message of a group or, when expanded, every message
+fn spawn_group_messages(list: &mut ChildSpawnerCommands, group: &ToastGroup, colors: &ToastColors) {
+    let messages: Vec<&Toast> = if group.expanded {
+        group.toasts.iter().collect()
+    } else {
+        vec![group.latest()]
+    };
+    for toast in messages {
+        list.spawn((
+            Text::new(&toast.message),
+            TextFont {
+                font_size: dimensions::FONT_SIZE_SMALL,
+                ..default()
+            },
+            TextColor(colors.text_secondary),
+        ));
+    }
+}
+
+/// Text of a group's expand/collapse toggle
+fn group_toggle_label(group: &ToastGroup) -> &'static str {
+    if group.expanded { "Hide" } else { "Show all" }
+}
+
 /// Marker for toast dismiss button
 #[derive(Component)]
 pub struct ToastDismissButton;
@@ -208,19 +486,58 @@ pub struct ToastDismissButton;
 #[derive(Component)]
 pub struct ToastActionButton;
 
+/// Button that expands or collapses a toast group's message list
+#[derive(Component)]
+pub struct ToastGroupToggle {
+    /// The toast carrying the [`ToastGroup`]
+    pub toast: Entity,
+}
+
+/// Marker for the count header of a toast group, e.g. "3 download errors"
+#[derive(Component)]
+pub struct ToastGroupCount;
+
+/// Marker for the list of messages under a toast group's count header
+#[derive(Component)]
+pub struct ToastGroupMessages;
+
+/// Marker for the remaining-time strip along the bottom of a toast
+#[derive(Component, Clone)]
+pub struct ToastProgressStrip;
+
+/// Marker for the icon slot at the start of a toast
+#[derive(Component)]
+pub struct ToastIcon;
+
+/// Side length of the toast icon slot in pixels
+const TOAST_ICON_SIZE: f32 = 20.0;
+
+/// Height of the remaining-time strip in pixels
+const TOAST_PROGRESS_HEIGHT: f32 = 3.0;
+
 /// System to update toast timers and handle auto-dismiss
+///
+/// Expanded toast groups stay up until they are collapsed again. With the
+/// theme's `reduce_motion` set, toasts skip their entrance animation.
 pub fn update_toast_timers(
     time: Res<Time>,
-    mut toast_query: Query<&mut ActiveToast>,
+    theme: Option<Res<UiTheme>>,
+    mut toast_query: Query<(&mut ActiveToast, Option<&ToastGroup>)>,
 ) {
-    for mut toast in toast_query.iter_mut() {
-        if toast.exiting {
+    let reduce_motion = theme.is_some_and(|theme| theme.reduce_motion);
+
+    for (mut toast, group) in toast_query.iter_mut() {
+        if toast.exiting || group.is_some_and(|group| group.expanded) {
             continue;
         }
 
         // Update animation
         if toast.animation_progress < 1.0 {
-            toast.animation_progress = (toast.animation_progress + time.delta_secs() * 5.0).min(1.0);
+            toast.animation_progress = if reduce_motion {
+                1.0
+            } else {
+                (toast.animation_progress + time.delta_secs() * 5.0).min(1.0)
+            };
         }
 
         // Update timer
@@ -231,6 +548,26 @@ pub fn update_toast_timers(
     }
 }
 
+/// Empty each toast's progress strip along with its dismiss timer
+///
+/// The strip reads the same `time_remaining` that dismisses the toast, so
+/// whatever holds the timer (such as an expanded group) holds the strip too.
+pub fn update_toast_progress(
+    toasts: Query<(&ActiveToast, &Children), Changed<ActiveToast>>,
+    mut strips: Query<&mut ProgressBar, With<ToastProgressStrip>>,
+) {
+    for (active, children) in toasts.iter() {
+        let value = active.remaining_fraction();
+        for child in children.iter() {
+            if let Ok(mut bar) = strips.get_mut(child) {
+                if bar.value != value {
+                    bar.value = value;
+                }
+            }
+        }
+    }
+}
+
 /// System to handle toast dismiss button clicks
 pub fn handle_toast_dismiss(
     dismiss_query: Query<(&ChildOf, &Interaction), (With<ToastDismissButton>, Changed<Interaction>)>,
@@ -245,6 +582,81 @@ pub fn handle_toast_dismiss(
     }
 }
 
+/// Expand or collapse a toast group when its toggle is clicked
+pub fn handle_toast_group_toggle(
+    toggles: Query<(&ToastGroupToggle, &Interaction), Changed<Interaction>>,
+    mut groups: Query<&mut ToastGroup>,
+) {
+    for (toggle, interaction) in toggles.iter() {
+        if *interaction == Interaction::Pressed {
+            if let Ok(mut group) = groups.get_mut(toggle.toast) {
+                group.expanded = !group.expanded;
+            }
+        }
+    }
+}
+
+/// Update a grouped toast when toasts join it or it is expanded
+///
+/// A toast shown on its own switches to the group layout once a second
+/// toast joins; after that only the count header, the message list and the
+/// toggle label change.
+#[allow(clippy::too_many_arguments)]
+pub fn refresh_toast_groups(
+    mut commands: Commands,
+    settings: Res<ToastSettings>,
+    groups: Query<(Entity, &ActiveToast, Ref<ToastGroup>)>,
+    children: Query<&Children>,
+    mut counts: Query<&mut Text, With<ToastGroupCount>>,
+    lists: Query<(), With<ToastGroupMessages>>,
+    toggles: Query<&Children, With<ToastGroupToggle>>,
+    mut labels: Query<&mut Text, Without<ToastGroupCount>>,
+    theme: Option<Res<UiTheme>>,
+) {
+    let colors = match theme.as_deref() {
+        Some(theme) => ToastColors::from_theme(theme),
+        None => ToastColors::default_colors(),
+    };
+
+    for (entity, active, group) in groups.iter() {
+        // Freshly spawned toasts were built with their group already
+        if !group.is_changed() || group.is_added() || active.exiting {
+            continue;
+        }
+
+        let descendants: Vec<Entity> = children.iter_descendants(entity).collect();
+        let Some(count) = descendants.iter().copied().find(|descendant| counts.contains(*descendant)) else {
+            if group.is_collapsed() {
+                let icon = toast_icon(&active.toast, theme.as_deref());
+                commands.entity(entity)
+                    .despawn_related::<Children>()
+                    .with_children(|toast_node| {
+                        let progress = settings.show_progress.then(|| active.remaining_fraction());
+                        spawn_toast_contents(toast_node, entity, &active.toast, Some(&*group), icon, &colors, progress);
+                    });
+            }
+            continue;
+        };
+
+        if let Ok(mut text) = counts.get_mut(count) {
+            text.0 = group.header();
+        }
+        for descendant in descendants {
+            if lists.contains(descendant) {
+                commands.entity(descendant)
+                    .despawn_related::<Children>()
+                    .with_children(|list| spawn_group_messages(list, &group, &colors));
+            } else if let Ok(toggle) = toggles.get(descendant) {
+                for child in toggle.iter() {
+                    if let Ok(mut label) = labels.get_mut(child) {
+                        label.0 = group_toggle_label(&group).to_string();
+                    }
+                }
+            }
+        }
+    }
+}
+
 /// System to handle toast action button clicks
 pub fn handle_toast_action(
     dismiss_query: Query<(&ChildOf, &Interaction), (With<ToastActionButton>, Changed<Interaction>)>,
@@ -270,18 +682,58 @@ pub fn handle_toast_action(
     }
 }
 
-/// System to despawn exiting toasts
+/// System to start exit transitions for exiting toasts
+///
+/// Toasts without an exit transition are despawned immediately; otherwise
+/// the animation system despawns them once the transition completes.
 pub fn despawn_exiting_toasts(
     mut commands: Commands,
-    toast_query: Query<(Entity, &ActiveToast)>,
+    toast_query: Query<(Entity, &ActiveToast, Option<&UiAnimation>), Without<ExitAnimating>>,
 ) {
-    for (entity, toast) in toast_query.iter() {
-        if toast.exiting {
+    for (entity, toast, animation) in toast_query.iter() {
+        if !toast.exiting {
+            continue;
+        }
+
+        let has_exit = animation
+            .and_then(|animation| animation.exit.as_ref())
+            .is_some_and(|exit| !matches!(exit.transition, Transition::None));
+
+        if has_exit {
+            commands.entity(entity)
+                .remove::<EnterAnimating>()
+                .insert(ExitAnimating);
+        } else {
             commands.entity(entity).despawn();
         }
     }
 }
 
+/// Fade toast content along with the toast during fade transitions
+///
+/// The animation system only fades the toast's own background and border;
+/// this carries the opacity down to its text, action button and progress
+/// strip.
+pub fn apply_toast_content_opacity(
+    toasts: Query<(Entity, &UiAnimation), (With<ActiveToast>, Changed<UiAnimation>)>,
+    children: Query<&Children>,
+    mut texts: Query<&mut TextColor>,
+    mut action_buttons: Query<&mut BackgroundColor, Or<(With<ToastActionButton>, With<ProgressBarFill>)>>,
+) {
+    for (entity, animation) in toasts.iter() {
+        let opacity = animation.state.current_opacity;
+
+        for descendant in children.iter_descendants(entity) {
+            if let Ok(mut text_color) = texts.get_mut(descendant) {
+                text_color.0.set_alpha(opacity);
+            }
+            if let Ok(mut background) = action_buttons.get_mut(descendant) {
+                background.0.set_alpha(opacity);
+            }
+        }
+    }
+}
+
 /// System to handle dismiss events
 pub fn handle_dismiss_events(
     mut events: MessageReader<DismissToastEvent>,
@@ -293,3 +745,171 @@ pub fn handle_dismiss_events(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn toast_app(max_visible: usize) -> App {
+        let mut app = App::new();
+        app.insert_resource(ToastSettings {
+                max_visible,
+                exit_transition: Transition::None,
+                ..default()
+            })
+            .insert_resource(ToastQueue::new())
+            .init_resource::<Time>()
+            .add_message::<DismissToastEvent>()
+            .add_systems(Update, (
+                ensure_toast_container,
+                spawn_toasts,
+                update_toast_timers,
+                handle_dismiss_events,
+                despawn_exiting_toasts,
+            ).chain());
+        app
+    }
+
+    fn advance(app: &mut App, secs: f32) {
+        app.world_mut().resource_mut::<Time>().advance_by(Duration::from_secs_f32(secs));
+        app.update();
+        app.world_mut().resource_mut::<Time>().advance_by(Duration::ZERO);
+    }
+
+    fn shown(app: &mut App) -> Vec<(Entity, String)> {
+        let mut shown: Vec<_> = app.world_mut()
+            .query::<(Entity, &ActiveToast)>()
+            .iter(app.world())
+            .map(|(entity, active)| (entity, active.toast.message.clone()))
+            .collect();
+        shown.sort_by(|a, b| a.1.cmp(&b.1));
+        shown
+    }
+
+    #[test]
+    fn dismissing_a_toast_shows_the_next_queued_one() {
+        let mut app = toast_app(2);
+        {
+            let mut queue = app.world_mut().resource_mut::<ToastQueue>();
+            queue.push(Toast::new("a"));
+            queue.push(Toast::new("b"));
+            queue.push(Toast::new("c"));
+        }
+        for _ in 0..4 {
+            app.update();
+        }
+
+        let visible = shown(&mut app);
+        assert_eq!(visible.iter().map(|(_, m)| m.as_str()).collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(app.world().resource::<ToastQueue>().queue.len(), 1);
+
+        app.world_mut().write_message(DismissToastEvent { entity: visible[0].0 });
+        app.update();
+        app.update();
+
+        let visible = shown(&mut app);
+        assert_eq!(visible.iter().map(|(_, m)| m.as_str()).collect::<Vec<_>>(), ["b", "c"]);
+        assert!(!app.world().resource::<ToastQueue>().has_pending());
+    }
+
+    #[test]
+    fn queued_toasts_start_their_duration_once_shown() {
+        let mut app = toast_app(1);
+        {
+            let mut queue = app.world_mut().resource_mut::<ToastQueue>();
+            queue.push(Toast::new("first").duration_secs(1));
+            queue.push(Toast::new("second").duration_secs(3));
+        }
+        app.update();
+        app.update();
+
+        // "second" waits while "first" runs out
+        advance(&mut app, 1.5);
+        app.update();
+
+        let (entity, message) = shown(&mut app).remove(0);
+        assert_eq!(message, "second");
+        let active = app.world().get::<ActiveToast>(entity).unwrap();
+        assert_eq!(active.time_remaining, 3.0);
+    }
+
+    #[test]
+    fn reduce_motion_skips_the_entrance_animation() {
+        let mut app = toast_app(1);
+        app.insert_resource(UiTheme::default().with_reduce_motion(true));
+        app.world_mut().resource_mut::<ToastQueue>().push(Toast::new("saved"));
+        app.update();
+        app.update();
+
+        let (entity, _) = shown(&mut app).remove(0);
+        assert_eq!(app.world().get::<ActiveToast>(entity).unwrap().animation_progress, 1.0);
+    }
+
+    fn group_counts(app: &mut App) -> Vec<(Entity, String)> {
+        app.world_mut()
+            .query_filtered::<(Entity, &Text), With<ToastGroupCount>>()
+            .iter(app.world())
+            .map(|(entity, text)| (entity, text.0.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn joining_toasts_update_the_group_count_in_place() {
+        let mut app = toast_app(5);
+        app.add_systems(Update, refresh_toast_groups.after(spawn_toasts));
+        let push = |app: &mut App, message: &str| {
+            app.world_mut()
+                .resource_mut::<ToastQueue>()
+                .push(Toast::error(message).group("download errors"));
+        };
+
+        // A lone toast in a group shows like any other toast
+        push(&mut app, "model.bin failed");
+        app.update();
+        app.update();
+        assert!(group_counts(&mut app).is_empty());
+
+        // Later toasts merge into it and bring up the count header
+        push(&mut app, "texture.png failed");
+        push(&mut app, "audio.ogg failed");
+        app.update();
+        app.update();
+        assert_eq!(shown(&mut app).len(), 1);
+        let counts = group_counts(&mut app);
+        assert_eq!(counts.len(), 1);
+        assert_eq!(counts[0].1, "3 download errors");
+
+        // Once shown, the header is updated rather than rebuilt
+        push(&mut app, "video.mp4 failed");
+        app.update();
+        assert_eq!(group_counts(&mut app), vec![(counts[0].0, "4 download errors".to_string())]);
+    }
+
+    #[test]
+    fn rebuilt_group_keeps_its_progress() {
+        let mut app = toast_app(5);
+        app.add_systems(Update, refresh_toast_groups.after(spawn_toasts));
+        app.world_mut().resource_mut::<ToastSettings>().show_progress = true;
+
+        app.world_mut().resource_mut::<ToastQueue>().push(Toast::error("model.bin failed").group("download errors"));
+        app.update();
+        advance(&mut app, 4.0);
+
+        // A shorter toast joins without extending the group, which is rebuilt
+        // with the group layout
+        app.world_mut()
+            .resource_mut::<ToastQueue>()
+            .push(Toast::error("texture.png failed").duration(Duration::from_secs(2)).group("download errors"));
+        app.update();
+        app.update();
+        assert_eq!(group_counts(&mut app).len(), 1);
+
+        let progress: Vec<f32> = app.world_mut()
+            .query_filtered::<&ProgressBar, With<ToastProgressStrip>>()
+            .iter(app.world())
+            .map(|bar| bar.value)
+            .collect();
+        assert_eq!(progress, vec![0.5]);
+    }
+}
@@ -1,9 +1,11 @@
 //! Toast types and components
 
 use bevy::prelude::*;
+use crate::dimensions::ZLayer;
 use std::time::Duration;
 use std::collections::VecDeque;
 
+use crate::animation::Transition;
 use crate::theme::UiTheme;
 
 /// Toast notification variant (determines color/icon)
@@ -20,6 +22,30 @@ pub enum ToastVariant {
     Error,
 }
 
+impl ToastVariant {
+    /// Built-in icon glyph (ASCII for maximum compatibility), shown when
+    /// neither the toast nor the theme provides an icon image
+    pub fn glyph(&self) -> &'static str {
+        match self {
+            Self::Info => "i",
+            Self::Success => "+",
+            Self::Warning => "!",
+            Self::Error => "x",
+        }
+    }
+
+    /// The theme's icon image for this variant, if any
+    pub fn theme_icon(&self, theme: &UiTheme) -> Option<Handle<Image>> {
+        let icons = &theme.components.toast;
+        match self {
+            Self::Info => icons.info_icon.clone(),
+            Self::Success => icons.success_icon.clone(),
+            Self::Warning => icons.warning_icon.clone(),
+            Self::Error => icons.error_icon.clone(),
+        }
+    }
+}
+
 /// Position for toast notifications
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ToastPosition {
@@ -53,6 +79,10 @@ pub struct Toast {
     pub dismissible: bool,
     /// Optional action button text
     pub action: Option<String>,
+    /// Icon image replacing the variant's default icon
+    pub icon: Option<Handle<Image>>,
+    /// Group key; toasts sharing one collapse under a single header
+    pub(crate) group: Option<String>,
 }
 
 impl Toast {
@@ -65,6 +95,8 @@ impl Toast {
             duration: Duration::from_secs(5),
             dismissible: true,
             action: None,
+            icon: None,
+            group: None,
         }
     }
 
@@ -77,6 +109,8 @@ impl Toast {
             duration: Duration::from_secs(3),
             dismissible: true,
             action: None,
+            icon: None,
+            group: None,
         }
     }
 
@@ -89,6 +123,8 @@ impl Toast {
             duration: Duration::from_secs(8),
             dismissible: true,
             action: None,
+            icon: None,
+            group: None,
         }
     }
 
@@ -101,6 +137,8 @@ impl Toast {
             duration: Duration::from_secs(5),
             dismissible: true,
             action: None,
+            icon: None,
+            group: None,
         }
     }
 
@@ -133,6 +171,23 @@ impl Toast {
         self.action = Some(text.into());
         self
     }
+
+    /// Show a custom icon image instead of the variant's default icon
+    pub fn icon(mut self, icon: Handle<Image>) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Collapse this toast with others sharing the same group key
+    pub fn group(mut self, key: impl Into<String>) -> Self {
+        self.group = Some(key.into());
+        self
+    }
+
+    /// The group key set with [`group`](Self::group), if any
+    pub fn group_key(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
 }
 
 /// Queue of pending toasts to show
@@ -167,6 +222,15 @@ impl ToastQueue {
     pub fn has_pending(&self) -> bool {
         !self.queue.is_empty()
     }
+
+    /// Remove and return every pending toast in group `key`, oldest first
+    pub fn take_group(&mut self, key: &str) -> Vec<Toast> {
+        let (grouped, rest) = self.queue
+            .drain(..)
+            .partition(|toast| toast.group_key() == Some(key));
+        self.queue = rest;
+        grouped
+    }
 }
 
 /// Component marking an active toast entity
@@ -176,12 +240,95 @@ pub struct ActiveToast {
     pub toast: Toast,
     /// Time remaining until auto-dismiss
     pub time_remaining: f32,
+    /// Seconds `time_remaining` counts down from; grows when a group is
+    /// extended by new toasts
+    pub duration: f32,
     /// Animation progress (0.0 to 1.0 for enter, then stays at 1.0)
     pub animation_progress: f32,
     /// Whether the toast is exiting
     pub exiting: bool,
 }
 
+impl ActiveToast {
+    /// Share of the toast's time still left (1.0 when shown, 0.0 when it
+    /// times out)
+    pub fn remaining_fraction(&self) -> f32 {
+        if self.duration <= 0.0 {
+            return 0.0;
+        }
+        (self.time_remaining / self.duration).clamp(0.0, 1.0)
+    }
+}
+
+/// Toasts collapsed under one header, on the [`ActiveToast`] showing them
+///
+/// Added to toasts shown with a [`group`](Toast::group) key. Later toasts
+/// in the same group join it instead of stacking up; once it holds more
+/// than one, the toast shows a count header (e.g. "3 download errors")
+/// that can be expanded to list each message.
+#[derive(Component, Clone, Debug)]
+pub struct ToastGroup {
+    /// The shared group key, also used as the header label
+    pub key: String,
+    /// Every toast in the group, oldest first
+    pub toasts: Vec<Toast>,
+    /// Whether each message is listed (expanded toasts don't time out)
+    pub expanded: bool,
+}
+
+impl ToastGroup {
+    /// Start a group from its first toast
+    pub fn new(key: impl Into<String>, first: Toast) -> Self {
+        Self {
+            key: key.into(),
+            toasts: vec![first],
+            expanded: false,
+        }
+    }
+
+    /// Whether the group holds more than one toast and shows a header
+    pub fn is_collapsed(&self) -> bool {
+        self.toasts.len() > 1
+    }
+
+    /// Header label, e.g. "3 download errors"
+    pub fn header(&self) -> String {
+        format!("{} {}", self.toasts.len(), self.key)
+    }
+
+    /// The most recent toast in the group
+    pub fn latest(&self) -> &Toast {
+        self.toasts.last().expect("a toast group is never empty")
+    }
+
+    /// Add toasts to the group, returning the longest of their durations
+    pub fn absorb(&mut self, toasts: impl IntoIterator<Item = Toast>) -> Duration {
+        let mut longest = Duration::ZERO;
+        for toast in toasts {
+            longest = longest.max(toast.duration);
+            self.toasts.push(toast);
+        }
+        longest
+    }
+}
+
+/// The "+N more" line below the visible toasts
+///
+/// Shown while [`ToastSettings::overflow_indicator`] is set and toasts are
+/// waiting in the [`ToastQueue`].
+#[derive(Component, Clone, Debug)]
+pub struct ToastOverflowIndicator {
+    /// Number of waiting toasts shown in the label
+    pub count: usize,
+}
+
+impl ToastOverflowIndicator {
+    /// Label for `count` waiting toasts, e.g. "+3 more"
+    pub fn label(count: usize) -> String {
+        format!("+{count} more")
+    }
+}
+
 /// Component for the toast container
 #[derive(Component, Clone, Debug)]
 pub struct ToastContainer {
@@ -194,8 +341,17 @@ pub struct ToastContainer {
 pub struct ToastSettings {
     /// Default position for toasts
     pub position: ToastPosition,
-    /// Maximum number of visible toasts
+    /// Maximum number of toasts on screen at once
+    ///
+    /// Further toasts wait in the [`ToastQueue`] and appear one by one as
+    /// shown toasts are dismissed or time out. A toast's duration only
+    /// starts counting once it is on screen.
     pub max_visible: usize,
+    /// Show a "+3 more" line under the visible toasts while others wait
+    pub overflow_indicator: bool,
+    /// Draw a thin strip along the bottom of each toast that empties as its
+    /// time runs out
+    pub show_progress: bool,
     /// Gap between toasts
     pub gap: f32,
     /// Toast width
@@ -204,6 +360,14 @@ pub struct ToastSettings {
     pub z_index: i32,
     /// Animation duration
     pub animation_duration: f32,
+    /// Transition played when a toast appears
+    pub enter_transition: Transition,
+    /// Transition played when a toast is dismissed or times out.
+    ///
+    /// Exit-style transitions (`fade_out`, `scale_out`, ...) play forward;
+    /// enter-style ones play in reverse. `Transition::None` removes the
+    /// toast immediately.
+    pub exit_transition: Transition,
 }
 
 impl Default for ToastSettings {
@@ -211,10 +375,14 @@ impl Default for ToastSettings {
         Self {
             position: ToastPosition::TopRight,
             max_visible: 5,
+            overflow_indicator: false,
+            show_progress: false,
             gap: 8.0,
             width: 350.0,
-            z_index: 3000,
+            z_index: ZLayer::Toast.z_index(),
             animation_duration: 0.2,
+            enter_transition: Transition::fade_in(0.2),
+            exit_transition: Transition::fade_out(0.2),
         }
     }
 }
@@ -309,3 +477,50 @@ impl ToastColors {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_group_toasts_collapse_into_one() {
+        let mut queue = ToastQueue::new();
+        queue.push(Toast::error("model.bin failed").group("download errors"));
+        queue.push(Toast::success("Saved"));
+        queue.push(Toast::error("texture.png failed").group("download errors"));
+        queue.push(Toast::error("audio.ogg failed").group("download errors"));
+
+        let first = queue.pop().unwrap();
+        let key = first.group_key().unwrap().to_string();
+        let mut group = ToastGroup::new(key, first);
+        assert!(!group.is_collapsed());
+
+        group.absorb(queue.take_group("download errors"));
+        assert!(group.is_collapsed());
+        assert_eq!(group.header(), "3 download errors");
+        assert_eq!(group.latest().message, "audio.ogg failed");
+
+        // Other toasts keep their place in the queue
+        assert_eq!(queue.queue.len(), 1);
+        assert_eq!(queue.pop().unwrap().message, "Saved");
+    }
+
+    #[test]
+    fn remaining_fraction_follows_the_dismiss_timer() {
+        let mut active = ActiveToast {
+            toast: Toast::new("Saved"),
+            time_remaining: 4.0,
+            duration: 4.0,
+            animation_progress: 0.0,
+            exiting: false,
+        };
+        assert_eq!(active.remaining_fraction(), 1.0);
+
+        active.time_remaining = 1.0;
+        assert_eq!(active.remaining_fraction(), 0.25);
+
+        // Overshooting the timer never reads below empty
+        active.time_remaining = -0.1;
+        assert_eq!(active.remaining_fraction(), 0.0);
+    }
+}
@@ -97,6 +97,10 @@ impl TooltipBuilder {
     }
 
     /// Build and attach the tooltip to an existing entity
+    ///
+    /// Components added with `insert()` (and so `visible()` and `class()`)
+    /// belong to the entity spawned by `build()` and are not applied here,
+    /// so they never end up on `target`.
     pub fn build_for(self, commands: &mut Commands, target: Entity) {
         let delay = self.delay.unwrap_or(Duration::from_millis(500));
         let max_width = self.max_width.unwrap_or(300.0);
@@ -130,7 +134,7 @@ impl TooltipBuilder {
 }
 
 impl UiBuilder for TooltipBuilder {
-    fn build(self, parent: &mut ChildSpawnerCommands) -> Entity {
+    fn build(mut self, parent: &mut ChildSpawnerCommands) -> Entity {
         // When built standalone, create a placeholder entity with the tooltip
         let delay = self.delay.unwrap_or(Duration::from_millis(500));
         let max_width = self.max_width.unwrap_or(300.0);
@@ -148,6 +152,7 @@ impl UiBuilder for TooltipBuilder {
             ))
             .id();
 
+        self.base.apply(entity, &mut parent.commands());
         entity
     }
 
@@ -14,6 +14,7 @@ define_plugin!(TooltipPlugin {
         update_tooltip_hover_time,
         show_tooltips,
         hide_tooltips,
+        dismiss_tooltip_on_escape,
         cleanup_orphaned_tooltips,
     ]
 });
@@ -2,6 +2,7 @@
 
 use bevy::prelude::*;
 use crate::styles::dimensions;
+use crate::systems::popup::Popup;
 use crate::theme::UiTheme;
 use crate::traits::TooltipPosition;
 use super::types::*;
@@ -119,6 +120,7 @@ pub fn show_tooltips(
                 BorderColor::all(border_color),
                 BorderRadius::all(Val::Px(4.0)),
                 GlobalZIndex(settings.z_index),
+                Popup,
                 TooltipEntity { target: entity },
             ))
             .with_children(|parent| {
@@ -168,6 +170,30 @@ pub fn hide_tooltips(
     }
 }
 
+/// System to dismiss tooltips with Escape
+///
+/// Tooltips stay off the [`EscapeStack`](crate::systems::escape::EscapeStack),
+/// so the same press still closes the topmost popup or dialog. A dismissed
+/// tooltip stays away until its target is hovered again.
+pub fn dismiss_tooltip_on_escape(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut query: Query<&mut TooltipState, With<HasTooltip>>,
+) {
+    if !keyboard.just_pressed(KeyCode::Escape) {
+        return;
+    }
+
+    for mut state in query.iter_mut() {
+        if let Some(tooltip_entity) = state.tooltip_entity {
+            commands.entity(tooltip_entity).despawn();
+            // Still visible as far as show_tooltips is concerned, so it
+            // isn't shown again until the pointer leaves
+            state.tooltip_entity = None;
+        }
+    }
+}
+
 /// System to clean up tooltips when target entities are despawned
 pub fn cleanup_orphaned_tooltips(
     mut commands: Commands,
@@ -1,6 +1,7 @@
 //! Tooltip types and components
 
 use bevy::prelude::*;
+use crate::dimensions::ZLayer;
 use std::time::Duration;
 use crate::traits::TooltipPosition;
 
@@ -83,7 +84,7 @@ impl Default for TooltipSettings {
         Self {
             default_delay: Duration::from_millis(500),
             default_max_width: 300.0,
-            z_index: 2000,
+            z_index: ZLayer::Tooltip.z_index(),
             offset: 8.0,
         }
     }
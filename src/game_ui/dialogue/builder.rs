@@ -12,6 +12,7 @@ mod defaults {
     pub const TEXT_TITLE: Color = Color::srgb(1.0, 1.0, 1.0);
     pub const TEXT_PRIMARY: Color = Color::srgb(0.95, 0.95, 0.95);
     pub const TEXT_DISABLED: Color = Color::srgb(0.4, 0.4, 0.4);
+    pub const TEXT_HINT: Color = Color::srgb(0.75, 0.55, 0.3);
 }
 
 /// Builder for creating RPG-style dialogue boxes
@@ -36,9 +37,17 @@ mod defaults {
 ///     .choice("no", "Sorry, too dangerous.")
 ///     .typing_effect(true)
 ///     .build(parent);
+///
+/// // Each character's name in their own color
+/// DialogueBoxBuilder::new()
+///     .speaker("Mira")
+///     .speaker_color(Color::srgb(0.95, 0.6, 0.8))
+///     .text("You made it!")
+///     .build(parent);
 /// ```
 pub struct DialogueBoxBuilder {
     speaker: Option<String>,
+    speaker_color: Option<Color>,
     text: String,
     portrait: Option<Handle<Image>>,
     choices: Vec<DialogueChoiceConfig>,
@@ -54,6 +63,7 @@ impl DialogueBoxBuilder {
     pub fn new() -> Self {
         Self {
             speaker: None,
+            speaker_color: None,
             text: String::new(),
             portrait: None,
             choices: Vec::new(),
@@ -71,6 +81,12 @@ impl DialogueBoxBuilder {
         self
     }
 
+    /// Set the color of the speaker name, to tell characters apart
+    pub fn speaker_color(mut self, color: Color) -> Self {
+        self.speaker_color = Some(color);
+        self
+    }
+
     /// Set the dialogue text
     pub fn text(mut self, text: impl Into<String>) -> Self {
         self.text = text.into();
@@ -89,6 +105,25 @@ impl DialogueBoxBuilder {
         self
     }
 
+    /// Add a choice that is locked until `enabled` is true
+    ///
+    /// While locked, the choice is greyed out, cannot be selected and shows
+    /// a "(Requires `requirement`)" hint.
+    pub fn conditional_choice(
+        mut self,
+        id: impl Into<String>,
+        text: impl Into<String>,
+        enabled: bool,
+        requirement: impl Into<String>,
+    ) -> Self {
+        self.choices.push(
+            DialogueChoiceConfig::new(id, text)
+                .enabled(enabled)
+                .requires(requirement),
+        );
+        self
+    }
+
     /// Add a choice with full configuration
     pub fn choice_with_config(mut self, config: DialogueChoiceConfig) -> Self {
         self.choices.push(config);
@@ -181,15 +216,17 @@ impl UiBuilder for DialogueBoxBuilder {
 
                     // Speaker name
                     if let Some(speaker) = &self.speaker {
+                        let color = self.speaker_color.unwrap_or(defaults::TEXT_TITLE);
                         header.spawn((
                             Text::new(speaker),
                             TextFont {
                                 font_size: dimensions::FONT_SIZE_LARGE,
                                 ..default()
                             },
-                            TextColor(defaults::TEXT_TITLE),
+                            TextColor(color),
                             DialogueSpeaker {
                                 dialogue: dialogue_entity,
+                                color,
                             },
                         ));
                     }
@@ -242,6 +279,8 @@ impl UiBuilder for DialogueBoxBuilder {
                                     Val::Px(8.0),
                                     Val::Px(8.0),
                                 ),
+                                column_gap: Val::Px(8.0),
+                                align_items: AlignItems::Center,
                                 ..default()
                             },
                             BackgroundColor(Color::srgba(0.1, 0.1, 0.15, 0.8)),
@@ -250,12 +289,9 @@ impl UiBuilder for DialogueBoxBuilder {
                                 dialogue: dialogue_entity,
                                 index,
                                 choice_id: choice.id.clone(),
+                                disabled: choice.disabled,
                             },
-                            if choice.disabled {
-                                Interaction::None
-                            } else {
-                                Interaction::default()
-                            },
+                            Interaction::default(),
                         ))
                         .with_children(|choice_btn| {
                             choice_btn.spawn((
@@ -266,12 +302,29 @@ impl UiBuilder for DialogueBoxBuilder {
                                 },
                                 TextColor(text_color),
                             ));
+
+                            // "Requires ..." hint for locked choices
+                            if let Some(requirement) = choice.requirement.as_ref().filter(|_| choice.disabled) {
+                                choice_btn.spawn((
+                                    Text::new(format!("(Requires {})", requirement)),
+                                    TextFont {
+                                        font_size: dimensions::FONT_SIZE_SMALL,
+                                        ..default()
+                                    },
+                                    TextColor(defaults::TEXT_HINT),
+                                ));
+                            }
                         });
                     }
                 });
             }
         });
 
+        // Apply hooks
+        for hook in self.base.hooks {
+            hook(&mut parent.commands().entity(dialogue_entity));
+        }
+
         dialogue_entity
     }
 
@@ -15,6 +15,7 @@ define_plugin!(DialoguePlugin {
     update: [
         update_typing_effect,
         handle_skip_typing,
+        update_speaker_colors,
         handle_choice_hover,
         handle_choice_clicks,
     ]
@@ -71,11 +71,25 @@ pub fn handle_skip_typing(
     }
 }
 
+/// System to color speaker names with their speaker's color
+pub fn update_speaker_colors(
+    mut speakers: Query<(&DialogueSpeaker, &mut TextColor), Changed<DialogueSpeaker>>,
+) {
+    for (speaker, mut color) in speakers.iter_mut() {
+        color.set_if_neq(TextColor(speaker.color));
+    }
+}
+
 /// System to handle choice hover effects
 pub fn handle_choice_hover(
     mut choice_query: Query<(&DialogueChoice, &Interaction, &mut BackgroundColor), Changed<Interaction>>,
 ) {
     for (choice, interaction, mut bg_color) in choice_query.iter_mut() {
+        // Disabled choices never react to hover
+        if choice.disabled {
+            continue;
+        }
+
         *bg_color = match interaction {
             Interaction::Hovered => BackgroundColor(Color::srgba(0.2, 0.2, 0.3, 0.9)),
             Interaction::Pressed => BackgroundColor(Color::srgba(0.15, 0.15, 0.25, 0.9)),
@@ -90,7 +104,7 @@ pub fn handle_choice_clicks(
     mut choice_events: MessageWriter<DialogueChoiceEvent>,
 ) {
     for (choice, interaction) in choice_query.iter() {
-        if *interaction == Interaction::Pressed {
+        if *interaction == Interaction::Pressed && !choice.disabled {
             choice_events.write(DialogueChoiceEvent {
                 dialogue: choice.dialogue,
                 index: choice.index,
@@ -16,10 +16,14 @@ pub struct DialogueBox {
 }
 
 /// Component for the speaker name
+///
+/// The name text takes on `color`, so changing it recolors the name.
 #[derive(Component, Clone, Debug)]
 pub struct DialogueSpeaker {
     /// The dialogue box this belongs to
     pub dialogue: Entity,
+    /// Color of the speaker's name
+    pub color: Color,
 }
 
 /// Component for the portrait image
@@ -47,6 +51,8 @@ pub struct DialogueChoice {
     pub index: usize,
     /// Choice identifier (for game logic)
     pub choice_id: String,
+    /// Disabled choices are greyed out and cannot be selected
+    pub disabled: bool,
 }
 
 /// Style variants for dialogue boxes
@@ -72,6 +78,8 @@ pub struct DialogueChoiceConfig {
     pub id: String,
     /// Whether this choice is disabled
     pub disabled: bool,
+    /// Hint shown next to a disabled choice explaining what unlocks it
+    pub requirement: Option<String>,
 }
 
 impl DialogueChoiceConfig {
@@ -81,6 +89,7 @@ impl DialogueChoiceConfig {
             text: text.into(),
             id: id.into(),
             disabled: false,
+            requirement: None,
         }
     }
 
@@ -89,6 +98,27 @@ impl DialogueChoiceConfig {
         self.disabled = true;
         self
     }
+
+    /// Enable or disable the choice from a condition
+    ///
+    /// ```ignore
+    /// DialogueChoiceConfig::new("open", "Unlock the gate")
+    ///     .enabled(inventory.has("rusty_key"))
+    ///     .requires("Rusty Key")
+    /// ```
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.disabled = !enabled;
+        self
+    }
+
+    /// Set the "Requires ..." hint shown while the choice is disabled
+    ///
+    /// The hint is only rendered for disabled choices, so it can be set
+    /// unconditionally alongside [`enabled`](Self::enabled).
+    pub fn requires(mut self, requirement: impl Into<String>) -> Self {
+        self.requirement = Some(requirement.into());
+        self
+    }
 }
 
 /// Message emitted when dialogue advances
@@ -36,6 +36,7 @@ pub struct InventoryGridBuilder {
     slot_spacing: Val,
     drag_drop: bool,
     slot_style: SlotStyle,
+    hotbar: bool,
     base: BuilderBase,
 }
 
@@ -49,10 +50,24 @@ impl InventoryGridBuilder {
             slot_spacing: Val::Px(4.0),
             drag_drop: true,
             slot_style: SlotStyle::default(),
+            hotbar: false,
             base: BuilderBase::new(),
         }
     }
 
+    /// Create a single-row hotbar with `slots` quickslots
+    ///
+    /// Number keys 1-9 select the matching slot, emitting a
+    /// [`SlotClickEvent`] as if it had been left-clicked. The first slot
+    /// starts selected and the selection is highlighted with the slot
+    /// style's `selected_color`.
+    pub fn hotbar(slots: usize) -> Self {
+        let mut builder = Self::new(slots, 1);
+        builder.hotbar = true;
+        builder.slot_style.border_width = 2.0;
+        builder
+    }
+
     /// Set the slot size
     pub fn slot_size(mut self, size: Val) -> Self {
         self.slot_size = size;
@@ -113,6 +128,8 @@ impl UiBuilder for InventoryGridBuilder {
                 slot_count,
             },
         )).with_children(|grid| {
+            let hotbar = self.hotbar;
+
             // Spawn slots
             for index in 0..slot_count {
                 let row = index / self.columns;
@@ -128,7 +145,12 @@ impl UiBuilder for InventoryGridBuilder {
                         ..default()
                     },
                     BackgroundColor(self.slot_style.empty_color),
-                    BorderColor::all(self.slot_style.border_color),
+                    BorderColor::all(if hotbar && index == 0 {
+                        self.slot_style.selected_color
+                    } else {
+                        self.slot_style.border_color
+                    }),
+                    SlotBorderColor(self.slot_style.border_color),
                     BorderRadius::all(Val::Px(4.0)),
                     InventorySlot {
                         grid: grid_entity,
@@ -142,6 +164,19 @@ impl UiBuilder for InventoryGridBuilder {
             }
         });
 
+        if self.hotbar {
+            parent.commands().entity(grid_entity).insert(Hotbar {
+                selected: (slot_count > 0).then_some(0),
+                selected_color: self.slot_style.selected_color,
+                border_color: self.slot_style.border_color,
+            });
+        }
+
+        // Apply hooks
+        for hook in self.base.hooks {
+            hook(&mut parent.commands().entity(grid_entity));
+        }
+
         grid_entity
     }
 
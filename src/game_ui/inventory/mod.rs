@@ -9,6 +9,8 @@
 //! - Drag and drop support
 //! - Click events for slot interaction
 //! - Visual feedback for hover and selection
+//! - Hotbar variant with number key selection
+//! - Rarity-colored borders and glows for items
 //!
 //! # Examples
 //!
@@ -22,6 +24,10 @@
 //!     .drag_drop(true)
 //!     .build(parent);
 //!
+//! // Single-row hotbar; keys 1-9 select a slot
+//! InventoryGridBuilder::hotbar(9)
+//!     .build(parent);
+//!
 //! // Handle slot clicks
 //! fn handle_clicks(mut events: MessageReader<SlotClickEvent>) {
 //!     for event in events.read() {
@@ -38,7 +44,7 @@ mod types;
 pub use builder::{InventoryGridBuilder, inventory_grid};
 pub use plugin::InventoryPlugin;
 pub use types::{
-    InventoryGrid, InventorySlot, InventoryItem, SlotStyle,
+    InventoryGrid, Hotbar, InventorySlot, InventoryItem, ItemRarity, SlotStyle,
     SlotClickEvent, ItemDragStartEvent, ItemDropEvent,
     InventoryDragState, DragInfo, InventorySettings,
 };
@@ -15,7 +15,7 @@ define_plugin!(InventoryPlugin {
     },
     update: [
         handle_slot_hover,
-        handle_slot_clicks,
+        (handle_slot_clicks, handle_hotbar_keys, select_hotbar_slots, update_hotbar_highlight, update_slot_rarity).chain(),
         handle_drag_start,
         handle_drop,
         cancel_drag_on_right_click,
@@ -1,6 +1,8 @@
 //! Inventory systems
 
+use std::collections::HashSet;
 use bevy::prelude::*;
+use bevy::color::Alpha;
 use crate::theme::UiTheme;
 use super::types::*;
 
@@ -173,3 +175,144 @@ pub fn cancel_drag_on_right_click(
         drag_state.dragging = None;
     }
 }
+
+/// Number keys in slot order (1 selects the first slot)
+const HOTBAR_KEYS: [KeyCode; 9] = [
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+    KeyCode::Digit5,
+    KeyCode::Digit6,
+    KeyCode::Digit7,
+    KeyCode::Digit8,
+    KeyCode::Digit9,
+];
+
+/// System to select hotbar slots with number keys 1-9
+pub fn handle_hotbar_keys(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    hotbars: Query<Entity, With<Hotbar>>,
+    slot_query: Query<(Entity, &InventorySlot)>,
+    #[cfg(feature = "text_input")]
+    text_buffers: Query<&crate::components::text_input::TextBuffer>,
+    mut click_events: MessageWriter<SlotClickEvent>,
+) {
+    let Some(index) = HOTBAR_KEYS.iter().position(|key| keyboard.just_pressed(*key)) else {
+        return;
+    };
+
+    // Digits typed into a text field are not hotbar shortcuts
+    #[cfg(feature = "text_input")]
+    if text_buffers.iter().any(|buffer| buffer.is_focused) {
+        return;
+    }
+
+    for grid in hotbars.iter() {
+        let Some((entity, slot)) = slot_query.iter()
+            .find(|(_, slot)| slot.grid == grid && slot.index == index)
+        else {
+            continue;
+        };
+
+        click_events.write(SlotClickEvent {
+            grid,
+            slot: entity,
+            index: slot.index,
+            button: MouseButton::Left,
+        });
+    }
+}
+
+/// System to select the clicked slot of a hotbar
+pub fn select_hotbar_slots(
+    mut click_events: MessageReader<SlotClickEvent>,
+    mut hotbars: Query<&mut Hotbar>,
+) {
+    for event in click_events.read() {
+        if event.button != MouseButton::Left {
+            continue;
+        }
+
+        if let Ok(mut hotbar) = hotbars.get_mut(event.grid) {
+            if hotbar.selected != Some(event.index) {
+                hotbar.selected = Some(event.index);
+            }
+        }
+    }
+}
+
+/// System to highlight the selected hotbar slot
+///
+/// The other slots show their item's rarity color, if any.
+pub fn update_hotbar_highlight(
+    hotbars: Query<(Entity, &Hotbar), Changed<Hotbar>>,
+    items: Query<&InventoryItem>,
+    mut slot_query: Query<(&InventorySlot, &mut BorderColor)>,
+) {
+    for (grid, hotbar) in hotbars.iter() {
+        for (slot, mut border) in slot_query.iter_mut() {
+            if slot.grid != grid {
+                continue;
+            }
+
+            let color = if hotbar.selected == Some(slot.index) {
+                hotbar.selected_color
+            } else {
+                item_rarity(slot, &items).unwrap_or(hotbar.border_color)
+            };
+            *border = BorderColor::all(color);
+        }
+    }
+}
+
+/// Blur radius of the glow around slots holding a rare item
+const RARITY_GLOW_BLUR: f32 = 10.0;
+
+/// System to color slot borders and glows by the rarity of their item
+///
+/// Runs for slots whose item was placed, removed or changed. A selected
+/// hotbar slot keeps its selection border but still glows.
+pub fn update_slot_rarity(
+    mut commands: Commands,
+    changed_items: Query<&InventoryItem, Changed<InventoryItem>>,
+    items: Query<&InventoryItem>,
+    hotbars: Query<&Hotbar>,
+    mut slots: Query<(Entity, Ref<InventorySlot>, &SlotBorderColor, &mut BorderColor)>,
+) {
+    let touched: HashSet<Entity> = changed_items.iter().map(|item| item.slot).collect();
+
+    for (entity, slot, base, mut border) in slots.iter_mut() {
+        if !slot.is_changed() && !touched.contains(&entity) {
+            continue;
+        }
+
+        let rarity = item_rarity(&slot, &items);
+        let selected = hotbars.get(slot.grid).is_ok_and(|hotbar| hotbar.selected == Some(slot.index));
+        if !selected {
+            border.set_if_neq(BorderColor::all(rarity.unwrap_or(base.0)));
+        }
+
+        match rarity {
+            Some(color) => {
+                commands.entity(entity).insert(BoxShadow::new(
+                    color.with_alpha(0.6),
+                    Val::Px(0.0),
+                    Val::Px(0.0),
+                    Val::Px(1.0),
+                    Val::Px(RARITY_GLOW_BLUR),
+                ));
+            }
+            None => {
+                commands.entity(entity).remove::<BoxShadow>();
+            }
+        }
+    }
+}
+
+/// Rarity color of the item in `slot`, if it has one
+fn item_rarity(slot: &InventorySlot, items: &Query<&InventoryItem>) -> Option<Color> {
+    slot.item
+        .and_then(|item| items.get(item).ok())
+        .and_then(|item| item.rarity)
+}
@@ -13,6 +13,20 @@ pub struct InventoryGrid {
     pub slot_count: usize,
 }
 
+/// Component marking an inventory grid as a hotbar
+///
+/// Number keys 1-9 select the matching slot (emitting [`SlotClickEvent`])
+/// and the selected slot's border is highlighted.
+#[derive(Component, Clone, Debug)]
+pub struct Hotbar {
+    /// Index of the selected slot (if any)
+    pub selected: Option<usize>,
+    /// Border color of the selected slot
+    pub selected_color: Color,
+    /// Border color of the other slots
+    pub border_color: Color,
+}
+
 /// Component marking an inventory slot
 #[derive(Component, Clone, Debug)]
 pub struct InventorySlot {
@@ -39,8 +53,45 @@ pub struct InventoryItem {
     pub stack_size: u32,
     /// Maximum stack size
     pub max_stack: u32,
+    /// Rarity color; the item's slot gets a border and glow in this color
+    ///
+    /// Use [`ItemRarity::color`] for the usual tier colors.
+    pub rarity: Option<Color>,
+}
+
+/// Common item rarity tiers, for [`InventoryItem::rarity`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ItemRarity {
+    /// Grey
+    #[default]
+    Common,
+    /// Green
+    Uncommon,
+    /// Blue
+    Rare,
+    /// Purple
+    Epic,
+    /// Orange
+    Legendary,
+}
+
+impl ItemRarity {
+    /// Border and glow color of this tier
+    pub fn color(self) -> Color {
+        match self {
+            Self::Common => Color::srgb(0.62, 0.62, 0.62),
+            Self::Uncommon => Color::srgb(0.12, 0.8, 0.2),
+            Self::Rare => Color::srgb(0.0, 0.44, 0.87),
+            Self::Epic => Color::srgb(0.64, 0.21, 0.93),
+            Self::Legendary => Color::srgb(1.0, 0.5, 0.0),
+        }
+    }
 }
 
+/// Border color a slot returns to when it holds no rare item
+#[derive(Component, Clone, Copy, Debug)]
+pub(crate) struct SlotBorderColor(pub(crate) Color);
+
 /// Configuration for inventory slot appearance
 #[derive(Clone, Debug)]
 pub struct SlotStyle {
@@ -24,6 +24,13 @@ use super::types::*;
 ///     .rotation(MinimapRotation::FollowPlayer)
 ///     .zoom(1.5)
 ///     .build(parent);
+///
+/// // RTS minimap over a 2000x2000 world that starts fogged
+/// MinimapBuilder::new()
+///     .shape(MinimapShape::Square)
+///     .world_bounds(Rect::from_center_size(Vec2::ZERO, Vec2::splat(2000.0)))
+///     .fog_of_war(true)
+///     .build(parent);
 /// ```
 pub struct MinimapBuilder {
     size: f32,
@@ -34,6 +41,10 @@ pub struct MinimapBuilder {
     border_width: f32,
     background_color: Color,
     border_color: Color,
+    world_bounds: Option<Rect>,
+    fog_of_war: bool,
+    fog_color: Color,
+    fog_resolution: u32,
     base: BuilderBase,
 }
 
@@ -49,6 +60,10 @@ impl MinimapBuilder {
             border_width: 3.0,
             background_color: Color::srgba(0.1, 0.1, 0.12, 0.9),
             border_color: Color::srgb(0.4, 0.4, 0.4),
+            world_bounds: None,
+            fog_of_war: false,
+            fog_color: Color::srgba(0.0, 0.0, 0.0, 0.85),
+            fog_resolution: 128,
             base: BuilderBase::new(),
         }
     }
@@ -100,6 +115,32 @@ impl MinimapBuilder {
         self.border_color = color;
         self
     }
+
+    /// Set the area of the world the minimap shows
+    ///
+    /// Defaults to `size / zoom` world units square, centered on the origin.
+    pub fn world_bounds(mut self, bounds: Rect) -> Self {
+        self.world_bounds = Some(bounds);
+        self
+    }
+
+    /// Cover unexplored areas with fog until a `MinimapRevealEvent` clears them
+    pub fn fog_of_war(mut self, enabled: bool) -> Self {
+        self.fog_of_war = enabled;
+        self
+    }
+
+    /// Set the color of unexplored areas
+    pub fn fog_color(mut self, color: Color) -> Self {
+        self.fog_color = color;
+        self
+    }
+
+    /// Set the width and height of the fog mask in pixels
+    pub fn fog_resolution(mut self, resolution: u32) -> Self {
+        self.fog_resolution = resolution.max(1);
+        self
+    }
 }
 
 impl Default for MinimapBuilder {
@@ -116,6 +157,10 @@ impl UiBuilder for MinimapBuilder {
             MinimapShape::RoundedSquare => Val::Px(12.0),
         };
 
+        let world_bounds = self.world_bounds.unwrap_or_else(|| {
+            Rect::from_center_size(Vec2::ZERO, Vec2::splat(self.size / self.zoom.max(f32::EPSILON)))
+        });
+
         let minimap_entity = parent.spawn_empty().id();
 
         parent.commands().entity(minimap_entity).insert((
@@ -151,8 +196,33 @@ impl UiBuilder for MinimapBuilder {
                     minimap: minimap_entity,
                 },
             ));
+
+            // Fog above the content, markers included
+            if self.fog_of_war {
+                minimap.spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BorderRadius::all(border_radius),
+                    MinimapFog {
+                        minimap: minimap_entity,
+                        world_bounds,
+                        resolution: self.fog_resolution,
+                        color: self.fog_color,
+                        mask: Handle::default(),
+                    },
+                ));
+            }
         });
 
+        // Apply hooks
+        for hook in self.base.hooks {
+            hook(&mut parent.commands().entity(minimap_entity));
+        }
+
         minimap_entity
     }
 
@@ -8,6 +8,7 @@
 //! - Player rotation following
 //! - Zoom control
 //! - Marker support
+//! - Fog of war that clears as the world is explored
 //!
 //! # Examples
 //!
@@ -25,6 +26,7 @@
 
 mod builder;
 mod plugin;
+mod systems;
 mod types;
 
 pub use builder::{MinimapBuilder, minimap};
@@ -32,4 +34,5 @@ pub use plugin::MinimapPlugin;
 pub use types::{
     Minimap, MinimapShape, MinimapRotation, MinimapContent,
     MinimapMarker, MarkerType, MinimapSettings,
+    MinimapFog, MinimapRevealEvent,
 };
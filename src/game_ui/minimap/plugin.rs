@@ -3,9 +3,15 @@
 use bevy::prelude::*;
 use bevy_plugin_builder::define_plugin;
 use super::types::*;
+use super::systems::*;
 
 define_plugin!(MinimapPlugin {
     custom_init: |app: &mut App| {
-        app.insert_resource(MinimapSettings::default());
-    }
+        app.insert_resource(MinimapSettings::default())
+           .add_message::<MinimapRevealEvent>()
+           .add_observer(init_minimap_fog);
+    },
+    update: [
+        reveal_minimap_fog,
+    ]
 });
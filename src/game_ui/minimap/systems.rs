@@ -0,0 +1,92 @@
+//! Minimap systems
+
+use bevy::prelude::*;
+use bevy::asset::RenderAssetUsages;
+use bevy::color::Alpha;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use super::types::*;
+
+/// Share of the reveal radius over which the fog fades out
+const REVEAL_FEATHER: f32 = 0.2;
+
+/// Create the mask texture of new fog-of-war overlays, fully fogged
+pub fn init_minimap_fog(
+    trigger: On<Add, MinimapFog>,
+    mut commands: Commands,
+    mut fogs: Query<&mut MinimapFog>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let Ok(mut fog) = fogs.get_mut(trigger.entity) else {
+        return;
+    };
+
+    let mask = Image::new_fill(
+        Extent3d {
+            width: fog.resolution,
+            height: fog.resolution,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &fog.color.to_srgba().to_u8_array(),
+        TextureFormat::Rgba8UnormSrgb,
+        // Kept on the CPU too, so reveals can edit it
+        RenderAssetUsages::default(),
+    );
+    fog.mask = images.add(mask);
+    commands.entity(trigger.entity).insert(ImageNode::new(fog.mask.clone()));
+}
+
+/// Clear the fog of war around revealed positions
+pub fn reveal_minimap_fog(
+    mut reveals: MessageReader<MinimapRevealEvent>,
+    fogs: Query<&MinimapFog>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    if reveals.is_empty() {
+        return;
+    }
+    let reveals: Vec<MinimapRevealEvent> = reveals.read().copied().collect();
+
+    for fog in fogs.iter() {
+        let Some(mask) = images.get_mut(&fog.mask) else {
+            continue;
+        };
+
+        for reveal in &reveals {
+            clear_fog(mask, fog, reveal);
+        }
+    }
+}
+
+/// Fade the mask out within a reveal's radius, softly toward its edge
+fn clear_fog(mask: &mut Image, fog: &MinimapFog, reveal: &MinimapRevealEvent) {
+    if reveal.radius <= 0.0 {
+        return;
+    }
+
+    // Mask pixels per world unit on each axis
+    let scale = fog.resolution as f32 / fog.world_bounds.size();
+    let center = fog.world_to_mask(reveal.world_pos);
+    let reach = reveal.radius * scale;
+    let min = (center - reach).floor().max(Vec2::ZERO);
+    let max = (center + reach).ceil().min(Vec2::splat(fog.resolution as f32));
+
+    for y in min.y as u32..max.y as u32 {
+        for x in min.x as u32..max.x as u32 {
+            let pixel_center = Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+            let distance = ((pixel_center - center) / scale).length();
+            if distance >= reveal.radius {
+                continue;
+            }
+
+            let Ok(current) = mask.get_color_at(x, y) else {
+                continue;
+            };
+            let clear = ((reveal.radius - distance) / (reveal.radius * REVEAL_FEATHER)).min(1.0);
+            let alpha = current.alpha().min(fog.color.alpha() * (1.0 - clear));
+            if alpha < current.alpha() {
+                let _ = mask.set_color_at(x, y, current.with_alpha(alpha));
+            }
+        }
+    }
+}
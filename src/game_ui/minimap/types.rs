@@ -42,6 +42,51 @@ pub struct MinimapContent {
     pub minimap: Entity,
 }
 
+/// Fog-of-war overlay covering a minimap
+///
+/// Its `mask` texture spans `world_bounds` and is tinted `color` wherever the
+/// world is still unexplored. [`MinimapRevealEvent`]s clear it around
+/// explored positions. The mask is created when the overlay is spawned.
+#[derive(Component, Clone, Debug)]
+pub struct MinimapFog {
+    /// The minimap this overlay covers
+    pub minimap: Entity,
+    /// Area of the world the minimap shows
+    pub world_bounds: Rect,
+    /// Width and height of the mask in pixels
+    pub resolution: u32,
+    /// Color of unexplored areas
+    pub color: Color,
+    /// The mask texture
+    pub mask: Handle<Image>,
+}
+
+impl MinimapFog {
+    /// Mask pixel coordinates of a world position
+    ///
+    /// The world's +Y points up, toward the top row of the mask. Positions
+    /// outside `world_bounds` map outside the mask.
+    pub fn world_to_mask(&self, world_pos: Vec2) -> Vec2 {
+        let bounds = self.world_bounds;
+        let uv = Vec2::new(
+            (world_pos.x - bounds.min.x) / bounds.width(),
+            (bounds.max.y - world_pos.y) / bounds.height(),
+        );
+        uv * self.resolution as f32
+    }
+}
+
+/// Message clearing the fog of war around an explored world position
+///
+/// Applies to every minimap with fog of war.
+#[derive(Message, Clone, Copy, Debug)]
+pub struct MinimapRevealEvent {
+    /// Explored world position
+    pub world_pos: Vec2,
+    /// Radius to reveal around it, in world units
+    pub radius: f32,
+}
+
 /// Component for minimap markers/icons
 #[derive(Component, Clone, Debug)]
 pub struct MinimapMarker {
@@ -102,3 +147,24 @@ impl Default for MinimapSettings {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn world_positions_map_onto_the_mask() {
+        let fog = MinimapFog {
+            minimap: Entity::PLACEHOLDER,
+            world_bounds: Rect::new(-500.0, -500.0, 500.0, 500.0),
+            resolution: 100,
+            color: Color::BLACK,
+            mask: Handle::default(),
+        };
+
+        assert_eq!(fog.world_to_mask(Vec2::ZERO), Vec2::new(50.0, 50.0));
+        // World +Y is the top of the mask
+        assert_eq!(fog.world_to_mask(Vec2::new(-500.0, 500.0)), Vec2::ZERO);
+        assert_eq!(fog.world_to_mask(Vec2::new(250.0, -250.0)), Vec2::new(75.0, 75.0));
+    }
+}
@@ -4,6 +4,9 @@ use bevy::prelude::*;
 use crate::traits::{UiBuilder, LayoutBuilder, BuilderBase};
 use super::types::*;
 
+/// Width of threshold tick marks in pixels
+const TICK_THICKNESS: f32 = 2.0;
+
 /// Builder for creating resource bars (health, mana, stamina, etc.)
 ///
 /// # Examples
@@ -26,6 +29,12 @@ use super::types::*;
 ///     .height(16.0)
 ///     .animated(true)
 ///     .build(parent);
+///
+/// // Health bar that turns red below 25%, with a tick at the halfway mark
+/// health_bar()
+///     .threshold(0.5)
+///     .zone(0.25, Color::srgb(1.0, 0.1, 0.1))
+///     .build(parent);
 /// ```
 pub struct ResourceBarBuilder {
     value: f32,
@@ -71,6 +80,7 @@ impl ResourceBarBuilder {
         self.config.fill_color = style.fill_color();
         self.config.background_color = style.background_color();
         self.config.damage_color = style.damage_color();
+        self.config.overflow_color = style.overflow_color();
         self
     }
 
@@ -87,18 +97,63 @@ impl ResourceBarBuilder {
         self
     }
 
-    /// Set the bar height
+    /// Set the bar height (its thickness; the width for vertical bars)
     pub fn height(mut self, height: f32) -> Self {
         self.config.height = height;
         self
     }
 
-    /// Set the bar width
+    /// Set the bar width (its length; the height for vertical bars)
     pub fn bar_width(mut self, width: Val) -> Self {
         self.width = width;
         self
     }
 
+    /// Render the bar vertically, filling bottom-to-top
+    ///
+    /// Useful for mana/stamina gauges in side HUDs. The bar keeps its
+    /// thickness from [`height`](Self::height) and its length from
+    /// [`bar_width`](Self::bar_width).
+    pub fn vertical(mut self, vertical: bool) -> Self {
+        self.config.vertical = vertical;
+        self
+    }
+
+    /// Draw values above the maximum as an overflow layer
+    ///
+    /// The base fill stays capped at the maximum while the excess (e.g.
+    /// overheal or a shield above max health) is drawn over it from the
+    /// start of the bar in the overflow color.
+    pub fn allow_overflow(mut self, allow: bool) -> Self {
+        self.config.allow_overflow = allow;
+        self
+    }
+
+    /// Set custom overflow color
+    pub fn overflow_color(mut self, color: Color) -> Self {
+        self.config.overflow_color = color;
+        self
+    }
+
+    /// Draw a tick mark at a fraction of the maximum (0.0 to 1.0)
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.config.thresholds.push(threshold.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Turn the fill `color` while the value is at or below `threshold`
+    /// (a fraction of the maximum), and draw a tick mark there
+    ///
+    /// With several zones, the one with the lowest threshold the value is
+    /// in decides the color.
+    pub fn zone(mut self, threshold: f32, color: Color) -> Self {
+        self.config.zones.push(ResourceBarZone {
+            threshold: threshold.clamp(0.0, 1.0),
+            color,
+        });
+        self
+    }
+
     /// Enable or disable animation
     pub fn animated(mut self, animated: bool) -> Self {
         self.animated = animated;
@@ -144,35 +199,44 @@ impl UiBuilder for ResourceBarBuilder {
             0.0
         };
 
+        let vertical = self.config.vertical;
+        let thickness = Val::Px(self.config.height);
+        let (width, height) = if vertical {
+            (thickness, self.width)
+        } else {
+            (self.width, thickness)
+        };
+
+        let resource_bar = ResourceBar {
+            value: self.value,
+            max_value: self.max_value,
+            style: self.style,
+            animated: self.animated,
+            vertical,
+            allow_overflow: self.config.allow_overflow,
+            zones: self.config.zones.clone(),
+        };
+        let fill_color = resource_bar.zone().map_or(self.config.fill_color, |zone| zone.color);
+
         // Pre-spawn bar entity
         let bar_entity = parent.spawn_empty().id();
 
         parent.commands().entity(bar_entity).insert((
             Node {
-                width: self.width,
-                height: Val::Px(self.config.height),
+                width,
+                height,
                 border: UiRect::all(Val::Px(self.config.border_width)),
                 ..default()
             },
             BackgroundColor(self.config.background_color),
             BorderColor::all(self.config.border_color),
             BorderRadius::all(Val::Px(self.config.corner_radius)),
-            ResourceBar {
-                value: self.value,
-                max_value: self.max_value,
-                style: self.style,
-                animated: self.animated,
-            },
+            resource_bar,
         )).with_children(|bar| {
             // Damage indicator (behind the fill)
             if self.config.show_damage_indicator {
                 bar.spawn((
-                    Node {
-                        position_type: PositionType::Absolute,
-                        width: Val::Percent(percentage * 100.0),
-                        height: Val::Percent(100.0),
-                        ..default()
-                    },
+                    fill_node(vertical, percentage),
                     BackgroundColor(self.config.damage_color),
                     BorderRadius::all(Val::Px(self.config.corner_radius - self.config.border_width)),
                     ResourceBarDamageIndicator {
@@ -185,21 +249,49 @@ impl UiBuilder for ResourceBarBuilder {
 
             // Main fill
             bar.spawn((
-                Node {
-                    position_type: PositionType::Absolute,
-                    width: Val::Percent(percentage * 100.0),
-                    height: Val::Percent(100.0),
-                    ..default()
-                },
-                BackgroundColor(self.config.fill_color),
+                fill_node(vertical, percentage),
+                BackgroundColor(fill_color),
                 BorderRadius::all(Val::Px(self.config.corner_radius - self.config.border_width)),
                 ResourceBarFill {
                     bar: bar_entity,
                     target_percentage: percentage,
                     display_percentage: percentage,
+                    base_color: self.config.fill_color,
                 },
             ));
 
+            // Overflow layer (over the fill)
+            if self.config.allow_overflow {
+                let overflow = if self.max_value > 0.0 {
+                    ((self.value - self.max_value) / self.max_value).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+
+                bar.spawn((
+                    fill_node(vertical, overflow),
+                    BackgroundColor(self.config.overflow_color),
+                    BorderRadius::all(Val::Px(self.config.corner_radius - self.config.border_width)),
+                    ResourceBarOverflow {
+                        bar: bar_entity,
+                        display_percentage: overflow,
+                    },
+                ));
+            }
+
+            // Threshold tick marks (over the fills)
+            let zone_thresholds = self.config.zones.iter().map(|zone| zone.threshold);
+            for threshold in self.config.thresholds.iter().copied().chain(zone_thresholds) {
+                bar.spawn((
+                    tick_node(vertical, threshold),
+                    BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.5)),
+                    ResourceBarTick {
+                        bar: bar_entity,
+                        threshold,
+                    },
+                ));
+            }
+
             // Optional text overlay
             if self.show_text {
                 bar.spawn((
@@ -225,6 +317,11 @@ impl UiBuilder for ResourceBarBuilder {
             }
         });
 
+        // Apply hooks
+        for hook in self.base.hooks {
+            hook(&mut parent.commands().entity(bar_entity));
+        }
+
         bar_entity
     }
 
@@ -257,6 +354,49 @@ impl LayoutBuilder for ResourceBarBuilder {
     }
 }
 
+/// Node for a fill layer covering `percentage` of the bar.
+///
+/// Vertical fills are anchored to the bottom so they grow upwards.
+fn fill_node(vertical: bool, percentage: f32) -> Node {
+    if vertical {
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(0.0),
+            width: Val::Percent(100.0),
+            height: Val::Percent(percentage * 100.0),
+            ..default()
+        }
+    } else {
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(percentage * 100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        }
+    }
+}
+
+/// Node for a thin tick mark across the bar at `threshold`
+fn tick_node(vertical: bool, threshold: f32) -> Node {
+    if vertical {
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Percent(threshold * 100.0),
+            width: Val::Percent(100.0),
+            height: Val::Px(TICK_THICKNESS),
+            ..default()
+        }
+    } else {
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Percent(threshold * 100.0),
+            width: Val::Px(TICK_THICKNESS),
+            height: Val::Percent(100.0),
+            ..default()
+        }
+    }
+}
+
 /// Convenience function to create a health bar
 pub fn health_bar() -> ResourceBarBuilder {
     ResourceBarBuilder::new().style(ResourceBarStyle::Health)
@@ -9,6 +9,9 @@
 //! - Damage indicator that trails behind health loss
 //! - Customizable colors and dimensions
 //! - Optional text overlay
+//! - Horizontal or vertical (bottom-to-top) orientation
+//! - Overflow layer for values above the maximum (overheal/overshield)
+//! - Threshold tick marks and color zones (e.g. a red low-health zone)
 //!
 //! # Examples
 //!
@@ -41,6 +44,7 @@ pub use builder::{
 };
 pub use plugin::ResourceBarPlugin;
 pub use types::{
-    ResourceBar, ResourceBarFill, ResourceBarDamageIndicator,
+    ResourceBar, ResourceBarFill, ResourceBarOverflow, ResourceBarDamageIndicator,
     ResourceBarStyle, ResourceBarConfig, ResourceBarChanged, ResourceBarSettings,
+    ResourceBarZone, ResourceBarTick,
 };
@@ -12,6 +12,8 @@ define_plugin!(ResourceBarPlugin {
     },
     update: [
         animate_resource_bar_fill,
+        update_resource_bar_zone_colors,
+        animate_resource_bar_overflow,
         animate_damage_indicator,
     ]
 });
@@ -31,7 +31,52 @@ pub fn animate_resource_bar_fill(
             fill.display_percentage = target;
         }
 
-        node.width = Val::Percent(fill.display_percentage * 100.0);
+        set_fill_extent(&mut node, bar.vertical, fill.display_percentage);
+    }
+}
+
+/// System to recolor fills for the color zone their bar's value is in
+pub fn update_resource_bar_zone_colors(
+    bar_query: Query<&ResourceBar>,
+    mut fill_query: Query<(&ResourceBarFill, &mut BackgroundColor)>,
+) {
+    for (fill, mut background) in fill_query.iter_mut() {
+        let Ok(bar) = bar_query.get(fill.bar) else {
+            continue;
+        };
+
+        let color = bar.zone().map_or(fill.base_color, |zone| zone.color);
+        background.set_if_neq(BackgroundColor(color));
+    }
+}
+
+/// System to animate the overflow layer of bars that allow overflow
+pub fn animate_resource_bar_overflow(
+    time: Res<Time>,
+    settings: Res<ResourceBarSettings>,
+    bar_query: Query<&ResourceBar>,
+    mut overflow_query: Query<(&mut ResourceBarOverflow, &mut Node)>,
+) {
+    for (mut overflow, mut node) in overflow_query.iter_mut() {
+        let Ok(bar) = bar_query.get(overflow.bar) else {
+            continue;
+        };
+
+        let target = bar.overflow_percentage();
+
+        if bar.animated {
+            let diff = target - overflow.display_percentage;
+            if diff.abs() > 0.001 {
+                overflow.display_percentage += diff * settings.animation_speed * time.delta_secs();
+                overflow.display_percentage = overflow.display_percentage.clamp(0.0, 1.0);
+            } else {
+                overflow.display_percentage = target;
+            }
+        } else {
+            overflow.display_percentage = target;
+        }
+
+        set_fill_extent(&mut node, bar.vertical, overflow.display_percentage);
     }
 }
 
@@ -75,7 +120,16 @@ pub fn animate_damage_indicator(
             }
         }
 
-        node.width = Val::Percent(indicator.display_percentage * 100.0);
+        set_fill_extent(&mut node, bar.vertical, indicator.display_percentage);
+    }
+}
+
+/// Size a fill node along the bar's axis
+fn set_fill_extent(node: &mut Node, vertical: bool, percentage: f32) {
+    if vertical {
+        node.height = Val::Percent(percentage * 100.0);
+    } else {
+        node.width = Val::Percent(percentage * 100.0);
     }
 }
 
@@ -13,6 +13,12 @@ pub struct ResourceBar {
     pub style: ResourceBarStyle,
     /// Whether to animate changes
     pub animated: bool,
+    /// Whether the bar fills bottom-to-top instead of left-to-right
+    pub vertical: bool,
+    /// Whether values above `max_value` are drawn as an overflow layer
+    pub allow_overflow: bool,
+    /// Color zones the fill changes color in
+    pub zones: Vec<ResourceBarZone>,
 }
 
 impl ResourceBar {
@@ -24,6 +30,49 @@ impl ResourceBar {
             (self.value / self.max_value).clamp(0.0, 1.0)
         }
     }
+
+    /// Get the portion above `max_value` as a percentage of the bar (0.0 to 1.0)
+    ///
+    /// Always 0.0 unless `allow_overflow` is set. Overflow beyond twice the
+    /// maximum fills the whole bar.
+    pub fn overflow_percentage(&self) -> f32 {
+        if !self.allow_overflow || self.max_value <= 0.0 {
+            0.0
+        } else {
+            ((self.value - self.max_value) / self.max_value).clamp(0.0, 1.0)
+        }
+    }
+
+    /// The color zone the current value is in
+    ///
+    /// When zones overlap, the one with the lowest threshold wins, so a
+    /// "danger" zone at 25% takes over from a "warning" zone at 50%.
+    pub fn zone(&self) -> Option<&ResourceBarZone> {
+        let percentage = self.percentage();
+        self.zones
+            .iter()
+            .filter(|zone| percentage <= zone.threshold)
+            .min_by(|a, b| a.threshold.total_cmp(&b.threshold))
+    }
+}
+
+/// Part of a resource bar, from empty up to `threshold`, where the fill
+/// turns `color` (e.g. a low-health warning)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ResourceBarZone {
+    /// Upper end of the zone as a fraction of the maximum (0.0 to 1.0)
+    pub threshold: f32,
+    /// Fill color while the value is in the zone
+    pub color: Color,
+}
+
+/// Tick mark drawn across a resource bar at a threshold
+#[derive(Component, Clone, Debug)]
+pub struct ResourceBarTick {
+    /// The bar this tick belongs to
+    pub bar: Entity,
+    /// Position along the bar as a fraction of the maximum
+    pub threshold: f32,
 }
 
 /// Component for the fill portion of the bar
@@ -35,6 +84,17 @@ pub struct ResourceBarFill {
     pub target_percentage: f32,
     /// Current display percentage
     pub display_percentage: f32,
+    /// Fill color outside every color zone
+    pub base_color: Color,
+}
+
+/// Component for the overflow layer drawn over the fill (overheal/overshield)
+#[derive(Component, Clone, Debug)]
+pub struct ResourceBarOverflow {
+    /// The bar this overflow layer belongs to
+    pub bar: Entity,
+    /// Current display percentage
+    pub display_percentage: f32,
 }
 
 /// Component for animated "damage" indicator
@@ -102,6 +162,18 @@ impl ResourceBarStyle {
             Self::Custom => Color::WHITE,
         }
     }
+
+    /// Get the overflow (overheal/overshield) color
+    pub fn overflow_color(&self) -> Color {
+        match self {
+            Self::Health => Color::srgb(1.0, 0.8, 0.3),
+            Self::Mana => Color::srgb(0.6, 0.8, 1.0),
+            Self::Stamina => Color::srgb(0.6, 1.0, 0.4),
+            Self::Experience => Color::srgb(1.0, 0.6, 0.9),
+            Self::Shield => Color::srgb(0.85, 1.0, 1.0),
+            Self::Custom => Color::srgb(1.0, 0.85, 0.3),
+        }
+    }
 }
 
 /// Configuration for resource bar appearance
@@ -115,7 +187,9 @@ pub struct ResourceBarConfig {
     pub border_color: Color,
     /// Damage indicator color
     pub damage_color: Color,
-    /// Bar height
+    /// Color of the portion above the maximum
+    pub overflow_color: Color,
+    /// Bar thickness (height, or width for vertical bars)
     pub height: f32,
     /// Border width
     pub border_width: f32,
@@ -125,6 +199,14 @@ pub struct ResourceBarConfig {
     pub show_damage_indicator: bool,
     /// Animation speed
     pub animation_speed: f32,
+    /// Render the bar vertically, filling bottom-to-top
+    pub vertical: bool,
+    /// Draw values above the maximum as an overflow layer
+    pub allow_overflow: bool,
+    /// Fractions of the maximum (0.0 to 1.0) to draw tick marks at
+    pub thresholds: Vec<f32>,
+    /// Color zones, each also drawn as a tick mark
+    pub zones: Vec<ResourceBarZone>,
 }
 
 impl Default for ResourceBarConfig {
@@ -134,11 +216,16 @@ impl Default for ResourceBarConfig {
             background_color: ResourceBarStyle::Health.background_color(),
             border_color: Color::srgb(0.3, 0.3, 0.3),
             damage_color: ResourceBarStyle::Health.damage_color(),
+            overflow_color: ResourceBarStyle::Health.overflow_color(),
             height: 24.0,
             border_width: 2.0,
             corner_radius: 4.0,
             show_damage_indicator: true,
             animation_speed: 3.0,
+            vertical: false,
+            allow_overflow: false,
+            thresholds: Vec::new(),
+            zones: Vec::new(),
         }
     }
 }
@@ -173,3 +260,34 @@ impl Default for ResourceBarSettings {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lowest_matching_zone_wins() {
+        let red = Color::srgb(1.0, 0.0, 0.0);
+        let orange = Color::srgb(1.0, 0.5, 0.0);
+        let mut bar = ResourceBar {
+            value: 100.0,
+            max_value: 100.0,
+            style: ResourceBarStyle::Health,
+            animated: false,
+            vertical: false,
+            allow_overflow: false,
+            zones: vec![
+                ResourceBarZone { threshold: 0.5, color: orange },
+                ResourceBarZone { threshold: 0.25, color: red },
+            ],
+        };
+
+        assert!(bar.zone().is_none());
+        bar.value = 40.0;
+        assert_eq!(bar.zone().map(|zone| zone.color), Some(orange));
+        bar.value = 25.0;
+        assert_eq!(bar.zone().map(|zone| zone.color), Some(red));
+        bar.value = 0.0;
+        assert_eq!(bar.zone().map(|zone| zone.color), Some(red));
+    }
+}
@@ -3,6 +3,8 @@
 //! Provides convenient builders for common layout patterns.
 
 use bevy::prelude::*;
+use crate::components::scroll_view::ScrollViewBuilder;
+use crate::theme::UiTheme;
 use crate::traits::{UiBuilder, LayoutBuilder, BuilderBase};
 
 // ============================================================================
@@ -482,14 +484,23 @@ pub fn spacer() -> SpacerBuilder {
     SpacerBuilder::new()
 }
 
+/// Create a header / scrolling body / footer page layout
+pub fn scaffold() -> ScaffoldBuilder {
+    ScaffoldBuilder::new()
+}
+
 /// Builder for flexible spacers
 pub struct SpacerBuilder {
     grow: f32,
+    base: BuilderBase,
 }
 
 impl SpacerBuilder {
     pub fn new() -> Self {
-        Self { grow: 1.0 }
+        Self {
+            grow: 1.0,
+            base: BuilderBase::new(),
+        }
     }
 
     /// Set how much this spacer should grow relative to others
@@ -506,17 +517,23 @@ impl Default for SpacerBuilder {
 }
 
 impl UiBuilder for SpacerBuilder {
-    fn build(self, parent: &mut ChildSpawnerCommands) -> Entity {
+    fn build(mut self, parent: &mut ChildSpawnerCommands) -> Entity {
         // Include Transform to prevent B0004 warnings when using with_children
-        parent
+        let entity = parent
             .spawn(UiContainer::new(Node {
                 flex_grow: self.grow,
                 ..default()
             }))
-            .id()
+            .id();
+
+        self.base.apply(entity, &mut parent.commands());
+        entity
     }
 
-    fn insert(self, _bundle: impl Bundle + Clone) -> Self {
+    fn insert(mut self, bundle: impl Bundle + Clone) -> Self {
+        self.base.hooks.push(Box::new(move |cmds| {
+            cmds.insert(bundle.clone());
+        }));
         self
     }
 
@@ -524,3 +541,250 @@ impl UiBuilder for SpacerBuilder {
         self
     }
 }
+
+// ============================================================================
+// Scaffold - fixed header, scrolling body, fixed footer
+// ============================================================================
+
+/// Marker for the fixed header region of a scaffold
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ScaffoldHeader;
+
+/// Marker for the scrolling body region of a scaffold (the scroll view)
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ScaffoldBody;
+
+/// Marker for the fixed footer region of a scaffold
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ScaffoldFooter;
+
+type RegionSpawner = Box<dyn FnOnce(&mut ChildSpawnerCommands)>;
+
+/// Builder for a common page layout: fixed header, scrollable body, fixed footer.
+///
+/// The scaffold fills its parent by default. Header and footer keep their
+/// natural height while the body takes the remaining space and scrolls when
+/// its content overflows.
+///
+/// # Example
+/// ```ignore
+/// scaffold()
+///     .header(|header| { label("Toolbar").build(header); })
+///     .body(|body| {
+///         for i in 0..50 {
+///             label(format!("Row {}", i)).build(body);
+///         }
+///     })
+///     .footer(|footer| { primary_button("Save").build(footer); })
+///     .build(parent);
+/// ```
+pub struct ScaffoldBuilder {
+    header: Option<RegionSpawner>,
+    body: Option<RegionSpawner>,
+    footer: Option<RegionSpawner>,
+    header_background: Option<Color>,
+    body_background: Option<Color>,
+    footer_background: Option<Color>,
+    region_padding: UiRect,
+    body_gap: Val,
+    base: BuilderBase,
+}
+
+impl ScaffoldBuilder {
+    /// Create a new scaffold builder filling its parent
+    pub fn new() -> Self {
+        let mut base = BuilderBase::new();
+        base.node.width = Val::Percent(100.0);
+        base.node.height = Val::Percent(100.0);
+        base.node.flex_direction = FlexDirection::Column;
+
+        Self {
+            header: None,
+            body: None,
+            footer: None,
+            header_background: None,
+            body_background: None,
+            footer_background: None,
+            region_padding: UiRect::all(Val::Px(12.0)),
+            body_gap: Val::Px(8.0),
+            base,
+        }
+    }
+
+    /// Apply theme colors to the header, body and footer backgrounds
+    pub fn themed(mut self, theme: &UiTheme) -> Self {
+        self.header_background = Some(theme.colors.surface.secondary);
+        self.body_background = Some(theme.colors.surface.primary);
+        self.footer_background = Some(theme.colors.surface.secondary);
+        self
+    }
+
+    /// Spawn the fixed header content (laid out as a row)
+    pub fn header(mut self, spawn: impl FnOnce(&mut ChildSpawnerCommands) + 'static) -> Self {
+        self.header = Some(Box::new(spawn));
+        self
+    }
+
+    /// Spawn the scrolling body content (laid out as a column)
+    pub fn body(mut self, spawn: impl FnOnce(&mut ChildSpawnerCommands) + 'static) -> Self {
+        self.body = Some(Box::new(spawn));
+        self
+    }
+
+    /// Spawn the fixed footer content (laid out as a row)
+    pub fn footer(mut self, spawn: impl FnOnce(&mut ChildSpawnerCommands) + 'static) -> Self {
+        self.footer = Some(Box::new(spawn));
+        self
+    }
+
+    /// Set the header background color
+    pub fn header_background(mut self, color: Color) -> Self {
+        self.header_background = Some(color);
+        self
+    }
+
+    /// Set the body background color
+    pub fn body_background(mut self, color: Color) -> Self {
+        self.body_background = Some(color);
+        self
+    }
+
+    /// Set the footer background color
+    pub fn footer_background(mut self, color: Color) -> Self {
+        self.footer_background = Some(color);
+        self
+    }
+
+    /// Set the padding applied inside each region
+    pub fn region_padding(mut self, padding: UiRect) -> Self {
+        self.region_padding = padding;
+        self
+    }
+
+    /// Set the gap between items in the body
+    pub fn body_gap(mut self, gap: Val) -> Self {
+        self.body_gap = gap;
+        self
+    }
+
+    /// Build the scaffold (proxy to UiBuilder::build)
+    pub fn build(self, parent: &mut ChildSpawnerCommands) -> Entity {
+        UiBuilder::build(self, parent)
+    }
+}
+
+impl Default for ScaffoldBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UiBuilder for ScaffoldBuilder {
+    fn build(mut self, parent: &mut ChildSpawnerCommands) -> Entity {
+        let region_padding = self.region_padding;
+        let body_gap = self.body_gap;
+        let (header, body, footer) = (self.header.take(), self.body.take(), self.footer.take());
+        let (header_bg, body_bg, footer_bg) = (
+            self.header_background.unwrap_or(Color::NONE),
+            self.body_background.unwrap_or(Color::NONE),
+            self.footer_background.unwrap_or(Color::NONE),
+        );
+
+        // Fixed regions never shrink, so the body absorbs all overflow
+        let fixed_region = || Node {
+            width: Val::Percent(100.0),
+            flex_shrink: 0.0,
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(8.0),
+            padding: region_padding,
+            ..default()
+        };
+
+        let entity = parent
+            .spawn(UiContainer::new(self.base.node.clone()))
+            .with_children(|root| {
+                if let Some(header) = header {
+                    root.spawn((
+                        UiContainer::new(fixed_region()),
+                        BackgroundColor(header_bg),
+                        ScaffoldHeader,
+                    )).with_children(header);
+                }
+
+                // Body wrapper takes the remaining height; min_height 0 lets it
+                // shrink below its content so the scroll view can overflow
+                root.spawn((
+                    UiContainer::new(Node {
+                        width: Val::Percent(100.0),
+                        flex_grow: 1.0,
+                        flex_basis: Val::Px(0.0),
+                        min_height: Val::Px(0.0),
+                        ..default()
+                    }),
+                    BackgroundColor(body_bg),
+                )).with_children(|wrapper| {
+                    let scroll = ScrollViewBuilder::new()
+                        .node(Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Percent(100.0),
+                            padding: region_padding,
+                            ..default()
+                        })
+                        .gap(body_gap)
+                        .insert(ScaffoldBody);
+
+                    match body {
+                        Some(body) => scroll.build_with_children(wrapper, body),
+                        None => scroll.build(wrapper),
+                    };
+                });
+
+                if let Some(footer) = footer {
+                    root.spawn((
+                        UiContainer::new(fixed_region()),
+                        BackgroundColor(footer_bg),
+                        ScaffoldFooter,
+                    )).with_children(footer);
+                }
+            })
+            .id();
+
+        self.base.apply(entity, &mut parent.commands());
+        entity
+    }
+
+    fn insert(mut self, bundle: impl Bundle + Clone) -> Self {
+        self.base.hooks.push(Box::new(move |cmds| {
+            cmds.insert(bundle.clone());
+        }));
+        self
+    }
+
+    fn id(mut self, id: Entity) -> Self {
+        self.base.entity = Some(id);
+        self
+    }
+}
+
+impl LayoutBuilder for ScaffoldBuilder {
+    fn width(mut self, width: Val) -> Self {
+        self.base.node.width = width;
+        self
+    }
+
+    fn height(mut self, height: Val) -> Self {
+        self.base.node.height = height;
+        self
+    }
+
+    fn margin(mut self, margin: UiRect) -> Self {
+        self.base.node.margin = margin;
+        self
+    }
+
+    fn padding(mut self, padding: UiRect) -> Self {
+        self.base.node.padding = padding;
+        self
+    }
+}
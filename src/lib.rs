@@ -26,6 +26,9 @@ pub mod validation;
 pub mod traits;
 pub mod theme;
 pub mod animation;
+pub mod accessibility;
+#[cfg(feature = "audio")]
+pub mod audio;
 
 // Components module (contains all UI component builders)
 pub mod components;
@@ -38,12 +41,12 @@ pub mod layout;
 pub mod game_ui;
 
 // Public exports - Styles
-pub use styles::{ButtonStyle, ButtonSize, colors, dimensions};
+pub use styles::{ButtonStyle, ButtonSize, ZLayer, colors, dimensions};
 
 // Public exports - Theme
 pub use theme::{
     UiTheme, ThemeColors, ColorScale, ThemePlugin, ThemeChanged,
-    ThemeSpacing, ThemeTypography, ThemeBorders, ThemeAnimation,
+    ThemeSpacing, ThemeTypography, ThemeFonts, FontRole, ThemeBorders, ThemeAnimation,
     SurfaceColors, TextColors, BorderColors,
     // Semantic variant system
     SemanticVariant, ResolvedColors, resolve_colors,
@@ -53,11 +56,28 @@ pub use theme::{
 pub use animation::{
     UiAnimation, AnimationPreset, AnimationPlugin, HoverEffect,
     Transition, Direction, Easing,
-    AnimationCategory, DisableAutoAnimation,
+    AnimationCategory, DisableAutoAnimation, ThemeTransition, TransformOrigin,
 };
+#[cfg(feature = "debug")]
+pub use animation::{AnimationDebug, AnimationDebugLabel, AnimationDebugPlugin};
+
+// Public exports - Accessibility
+pub use accessibility::AccessibleName;
+
+// Public exports - Audio
+#[cfg(feature = "audio")]
+pub use audio::{UiSounds, NoUiSounds, UiSoundsPlugin};
 
 // Public exports - Systems
 pub use systems::cleanup::{despawn_entities, despawn_ui_entities};
+pub use systems::class::{UiClass, entities_with_class, UiClassPlugin};
+pub use systems::escape::{EscapeLayer, EscapeStack, EscapeStackPlugin};
+pub use systems::interaction::{InteractionState, is_hovered, is_pressed, UiInteractionPlugin};
+pub use systems::mnemonic::{Mnemonic, MnemonicPressed, ParsedMnemonic, parse_mnemonic, UiMnemonicPlugin};
+pub use systems::visibility::{UiHidden, SetVisible, UiVisibilityPlugin};
+pub use systems::popup::{PopupLayer, Popup, PopupAnchor, PopupSide, PopupWidth, PopupLayerPlugin};
+#[cfg(feature = "debug")]
+pub use systems::layout_debug::{UiDebug, UiDebugOutline, UiDebugPlugin};
 
 // Public exports - Relationships
 pub use relationships::{
@@ -69,11 +89,15 @@ pub use relationships::{
     TextInputPart, TextInputParts,
     ProgressBarPart, ProgressBarParts,
     BelongsToDropdown, DropdownElements,
+    BadgeOf, WidgetBadges,
     UIRelationshipsPlugin,
 };
 
 // Public exports - Validation
-pub use validation::{Validated, ValidationState, ValidationPlugin};
+pub use validation::{
+    Validated, ValidationState, ValidationPlugin, AsyncValidated, ValidationIndicator,
+    ValidationRequestEvent, ValidationResolvedEvent, spawn_validation_indicator,
+};
 
 // Public exports - ScrollView (always available)
 pub use components::scroll_view::{
@@ -83,19 +107,28 @@ pub use components::scroll_view::{
 // Builder exports based on features
 #[cfg(feature = "button")]
 pub use components::button::{
-    ButtonBuilder, StyledButton,
-    primary_button, secondary_button, success_button, danger_button, ghost_button,
+    ButtonBuilder, StyledButton, LinkButton, SegmentedButtonGroup, ButtonCooldown, TriggerCooldown,
+    primary_button, secondary_button, success_button, danger_button, ghost_button, link_button,
 };
 
 #[cfg(feature = "slider")]
-pub use components::slider::{SliderBuilder, Slider, SliderHandle, SliderTrack, ValueFormat};
+pub use components::slider::{
+    SliderBuilder, Slider, SliderRef, SliderChanged, SliderThrottle, SliderHandle, SliderTrack, SliderDefault, SliderTick, SliderFocused, SliderDisplayOnly, ValueFormat, SliderBinding, SliderBindingPlugin,
+    AccessibleValue,
+    Pad2DBuilder, Pad2D, Pad2DChanged,
+    RangeSliderBuilder, RangeSlider, RangeHandle, RangeSliderChanged,
+};
+
+#[cfg(all(feature = "slider", feature = "number_input"))]
+pub use components::slider::{SliderWithInputBuilder, SliderInputPair, slider_with_input};
 
 #[cfg(feature = "form")]
-pub use components::form::{FormBuilder, FieldType, ValidationRule};
+pub use components::form::{FormBuilder, FieldType, ValidationRule, FormSubmitEvent, FormData};
 
 #[cfg(feature = "dialog")]
 pub use components::dialog::{
     DialogBuilder, DialogButtonEvent, DialogType, DialogOverlay, DialogButtonMarker,
+    DialogQueue, DialogIcon, DialogIconPosition, DialogResponsiveWidth, DialogButtonStacking,
     // Standard button markers for dialog buttons
     ConfirmButton, CancelButton, SaveButton, DiscardButton,
     OkButton, YesButton, NoButton,
@@ -105,31 +138,31 @@ pub use components::dialog::{
 };
 
 #[cfg(feature = "text_input")]
-pub use components::text_input::{TextInputBuilder, InputFilter, InputTransform, FocusGroupId, text_input};
+pub use components::text_input::{TextInputBuilder, InputFilter, RegexPattern, InputTransform, FocusGroupId, SubmitKey, SetTextInputValue, CharacterCounter, text_input};
 
 #[cfg(feature = "progress")]
-pub use components::progress::{ProgressBarBuilder, ProgressBar, ProgressBarStyle, progress};
+pub use components::progress::{ProgressBarBuilder, ProgressBar, ProgressBarStyle, ProgressLabelPosition, ProgressCompleteEvent, progress};
 
 #[cfg(feature = "label")]
-pub use components::label::{LabelBuilder, Label, LabelSize, label};
+pub use components::label::{LabelBuilder, Label, LabelCopyButton, LabelSize, label};
 // Deprecated re-export for backwards compatibility
 #[allow(deprecated)]
 pub use components::label::LabelStyle;
 
 #[cfg(feature = "panel")]
-pub use components::panel::{PanelBuilder, Panel, PanelStyle, panel};
+pub use components::panel::{PanelBuilder, Panel, PanelStyle, GradientDirection, panel};
 
 #[cfg(feature = "separator")]
 pub use components::separator::{SeparatorBuilder, Separator, SeparatorStyle, Orientation, separator};
 
 #[cfg(feature = "checkbox")]
-pub use components::checkbox::{CheckboxBuilder, Checkbox, CheckboxState, CheckboxStyle};
+pub use components::checkbox::{CheckboxBuilder, Checkbox, CheckboxState, CheckboxStyle, CheckIcon, CheckboxIcon, RadioCheckbox, CheckboxFocused, CheckboxFocusRing, CheckboxRow};
 
 #[cfg(feature = "number_input")]
-pub use components::number_input::{NumberInputBuilder, NumberInput, NumberInputConfig};
+pub use components::number_input::{NumberInputBuilder, NumberInput, NumberInputConfig, NumberInputChangedEvent};
 
 #[cfg(feature = "dropdown")]
-pub use components::dropdown::{DropdownBuilder, Dropdown, DropdownState, DropdownData};
+pub use components::dropdown::{DropdownBuilder, DropdownSubmenuBuilder, Dropdown, DropdownState, DropdownData, DropdownNavigation, DropdownSearch, DropdownOption, DropdownFaceRenderFn, DropdownSubmenu, DropdownSubmenuItem, DropdownGroupHeader};
 
 #[cfg(feature = "tooltip")]
 pub use components::tooltip::{
@@ -139,13 +172,13 @@ pub use components::tooltip::{
 #[cfg(feature = "tabs")]
 pub use components::tabs::{
     TabViewBuilder, TabView, TabButton, TabContent, TabPosition, TabStyle,
-    TabSelectedEvent, TabConfig, NoTabs, HasTabs, tabs
+    TabSelectedEvent, TabReorderedEvent, TabConfig, NoTabs, HasTabs, tabs
 };
 
 #[cfg(feature = "toast")]
 pub use components::toast::{
     ToastBuilder, Toast, ToastVariant, ToastPosition, ToastQueue, ToastSettings,
-    ActiveToast, ToastContainer, ToastActionEvent, DismissToastEvent,
+    ActiveToast, ToastGroup, ToastContainer, ToastOverflowIndicator, ToastActionEvent, DismissToastEvent,
     show_toast, show_success, show_error, show_warning
 };
 
@@ -156,22 +189,37 @@ pub use components::context_menu::{
     ContextMenuSettings, OpenContextMenu, context_menu
 };
 
+#[cfg(feature = "reorderable_list")]
+pub use components::reorderable_list::{
+    ReorderableListBuilder, ReorderableList, ReorderableItem, ListReorderedEvent,
+    ReorderDragState, reorderable_list
+};
+
+#[cfg(feature = "split_pane")]
+pub use components::split_pane::{
+    SplitPaneBuilder, SplitPane, SplitDivider, SplitPaneFirst, SplitPaneSecond,
+    SplitResizedEvent, split_pane
+};
+
+#[cfg(feature = "badge")]
+pub use components::badge::{BadgeBuilder, Badge, BadgeCorner, BadgeText};
+
 // Game UI exports
 #[cfg(feature = "inventory")]
 pub use game_ui::inventory::{
-    InventoryGridBuilder, InventoryGrid, InventorySlot, InventoryItem,
+    InventoryGridBuilder, InventoryGrid, Hotbar, InventorySlot, InventoryItem, ItemRarity,
     SlotClickEvent, ItemDragStartEvent, ItemDropEvent, inventory_grid
 };
 
 #[cfg(feature = "resource_bar")]
 pub use game_ui::resource_bar::{
-    ResourceBarBuilder, ResourceBar, ResourceBarStyle, ResourceBarFill,
+    ResourceBarBuilder, ResourceBar, ResourceBarStyle, ResourceBarFill, ResourceBarZone,
     health_bar, mana_bar, stamina_bar, experience_bar
 };
 
 #[cfg(feature = "minimap")]
 pub use game_ui::minimap::{
-    MinimapBuilder, Minimap, MinimapShape, MinimapRotation, minimap
+    MinimapBuilder, Minimap, MinimapShape, MinimapRotation, MinimapFog, MinimapRevealEvent, minimap
 };
 
 #[cfg(feature = "dialogue")]
@@ -202,40 +250,52 @@ pub mod prelude {
     // Validation
     pub use crate::{Validated, ValidationState, ValidationRule};
 
+    // Show/hide and stacking
+    pub use crate::{UiHidden, SetVisible, ZLayer};
+
+    // Interaction readback
+    pub use crate::{InteractionState, is_hovered, is_pressed};
+    pub use crate::{Mnemonic, parse_mnemonic};
+    pub use crate::{EscapeLayer, EscapeStack};
+    pub use crate::{UiClass, entities_with_class};
+
     #[cfg(feature = "button")]
     pub use crate::{ButtonBuilder, ButtonStyle, ButtonSize};
 
     #[cfg(feature = "slider")]
-    pub use crate::{SliderBuilder, ValueFormat};
+    pub use crate::{SliderBuilder, SliderRef, SliderChanged, RangeSliderBuilder, RangeSliderChanged, ValueFormat};
+
+    #[cfg(all(feature = "slider", feature = "number_input"))]
+    pub use crate::SliderWithInputBuilder;
 
     #[cfg(feature = "form")]
-    pub use crate::{FormBuilder, FieldType};
+    pub use crate::{FormBuilder, FieldType, FormSubmitEvent};
 
     #[cfg(feature = "dialog")]
     pub use crate::{
-        DialogBuilder, DialogType, DialogButtonMarker,
+        DialogBuilder, DialogType, DialogButtonMarker, DialogIconPosition, DialogQueue,
         // Standard button markers
         ConfirmButton, CancelButton, SaveButton, DiscardButton,
         OkButton, YesButton, NoButton,
     };
 
     #[cfg(feature = "text_input")]
-    pub use crate::{TextInputBuilder, InputFilter};
+    pub use crate::{TextInputBuilder, InputFilter, SetTextInputValue};
 
     #[cfg(feature = "progress")]
-    pub use crate::{ProgressBarBuilder, ProgressBarStyle};
+    pub use crate::{ProgressBarBuilder, ProgressBarStyle, ProgressLabelPosition};
 
     #[cfg(feature = "label")]
     pub use crate::{LabelBuilder, LabelSize};
 
     #[cfg(feature = "panel")]
-    pub use crate::{PanelBuilder, PanelStyle};
+    pub use crate::{PanelBuilder, PanelStyle, GradientDirection};
 
     #[cfg(feature = "separator")]
     pub use crate::{SeparatorBuilder, Orientation};
 
     #[cfg(feature = "checkbox")]
-    pub use crate::{CheckboxBuilder, CheckboxState, CheckboxStyle};
+    pub use crate::{CheckboxBuilder, CheckboxState, CheckboxStyle, CheckIcon};
 
     #[cfg(feature = "number_input")]
     pub use crate::{NumberInputBuilder};
@@ -247,7 +307,7 @@ pub mod prelude {
     pub use crate::{TooltipBuilder, tooltip};
 
     #[cfg(feature = "tabs")]
-    pub use crate::{TabViewBuilder, TabPosition, TabStyle, TabConfig, tabs};
+    pub use crate::{TabViewBuilder, TabPosition, TabStyle, TabConfig, TabReorderedEvent, tabs};
 
     #[cfg(feature = "toast")]
     pub use crate::{ToastBuilder, ToastVariant, ToastPosition, ToastQueue, show_toast, show_success, show_error, show_warning};
@@ -255,6 +315,15 @@ pub mod prelude {
     #[cfg(feature = "context_menu")]
     pub use crate::{ContextMenuBuilder, MenuItem, ContextMenuActionEvent, context_menu};
 
+    #[cfg(feature = "reorderable_list")]
+    pub use crate::{ReorderableListBuilder, ListReorderedEvent, reorderable_list};
+
+    #[cfg(feature = "split_pane")]
+    pub use crate::{SplitPaneBuilder, SplitResizedEvent, split_pane};
+
+    #[cfg(feature = "badge")]
+    pub use crate::{BadgeBuilder, Badge, BadgeCorner};
+
     // Game UI
     #[cfg(feature = "inventory")]
     pub use crate::{InventoryGridBuilder, SlotClickEvent, inventory_grid};
@@ -263,7 +332,7 @@ pub mod prelude {
     pub use crate::{ResourceBarBuilder, ResourceBarStyle, health_bar, mana_bar};
 
     #[cfg(feature = "minimap")]
-    pub use crate::{MinimapBuilder, MinimapShape, MinimapRotation, minimap};
+    pub use crate::{MinimapBuilder, MinimapShape, MinimapRotation, MinimapRevealEvent, minimap};
 
     #[cfg(feature = "dialogue")]
     pub use crate::{DialogueBoxBuilder, DialogueChoiceEvent, dialogue_box};
@@ -277,28 +346,32 @@ pub mod prelude {
     // Layout helpers
     pub use crate::layout::{
         UiContainer, UiContainerBuilder,
-        RowBuilder, ColumnBuilder, SpacerBuilder,
-        row, column, centered, spacer,
+        RowBuilder, ColumnBuilder, SpacerBuilder, ScaffoldBuilder,
+        row, column, centered, spacer, scaffold,
     };
 
     // Convenience functions from components
     #[cfg(feature = "button")]
     pub use crate::components::button::{
         button, primary_button, secondary_button, success_button, danger_button, ghost_button,
+        link_button,
     };
 
     #[cfg(feature = "label")]
     pub use crate::components::label::{label, heading, title, secondary_text};
 
     #[cfg(feature = "slider")]
-    pub use crate::components::slider::{slider, percentage_slider, normalized_slider};
+    pub use crate::components::slider::{slider, percentage_slider, normalized_slider, pad_2d, range_slider};
+
+    #[cfg(all(feature = "slider", feature = "number_input"))]
+    pub use crate::components::slider::slider_with_input;
 
     #[cfg(feature = "text_input")]
     pub use crate::components::text_input::text_input;
 }
 
 define_plugin!(UiBuilderPlugin {
-    plugins: [ThemePlugin, AnimationPlugin, UIRelationshipsPlugin, ValidationPlugin],
+    plugins: [ThemePlugin, AnimationPlugin, UIRelationshipsPlugin, ValidationPlugin, UiVisibilityPlugin, UiInteractionPlugin, UiMnemonicPlugin, PopupLayerPlugin, EscapeStackPlugin, UiClassPlugin],
     custom_init: |app: &mut App| {
         // Bevy 0.17 requires picking plugins for Interaction component updates
         // Only add if not already present (DefaultPlugins includes them)
@@ -309,6 +382,12 @@ define_plugin!(UiBuilderPlugin {
         // ScrollView plugin (always available)
         app.add_plugins(components::scroll_view::ScrollViewPlugin);
 
+        #[cfg(feature = "debug")]
+        app.add_plugins((animation::AnimationDebugPlugin, systems::layout_debug::UiDebugPlugin));
+
+        #[cfg(feature = "audio")]
+        app.add_plugins(audio::UiSoundsPlugin);
+
         #[cfg(feature = "button")]
         app.add_plugins(components::button::ButtonPlugin);
 
@@ -318,9 +397,18 @@ define_plugin!(UiBuilderPlugin {
         #[cfg(feature = "dialog")]
         app.add_plugins(components::dialog::DialogPlugin);
 
+        #[cfg(feature = "form")]
+        app.add_plugins(components::form::FormPlugin);
+
         #[cfg(feature = "text_input")]
         app.add_plugins(components::text_input::TextInputPlugin);
 
+        #[cfg(feature = "number_input")]
+        app.add_plugins(components::number_input::NumberInputPlugin);
+
+        #[cfg(feature = "label")]
+        app.add_plugins(components::label::LabelPlugin);
+
         #[cfg(feature = "progress")]
         app.add_plugins(components::progress::ProgressBarPlugin);
 
@@ -342,6 +430,15 @@ define_plugin!(UiBuilderPlugin {
         #[cfg(feature = "context_menu")]
         app.add_plugins(components::context_menu::ContextMenuPlugin);
 
+        #[cfg(feature = "reorderable_list")]
+        app.add_plugins(components::reorderable_list::ReorderableListPlugin);
+
+        #[cfg(feature = "split_pane")]
+        app.add_plugins(components::split_pane::SplitPanePlugin);
+
+        #[cfg(feature = "badge")]
+        app.add_plugins(components::badge::BadgePlugin);
+
         // Game UI plugins
         #[cfg(feature = "inventory")]
         app.add_plugins(game_ui::inventory::InventoryPlugin);
@@ -56,7 +56,7 @@ impl SliderParts {
 // ============================================================================
 
 /// Marks an entity as a field belonging to a form.
-#[derive(Component)]
+#[derive(Component, Clone)]
 #[relationship(relationship_target = FormFields)]
 pub struct BelongsToForm(pub Entity);
 
@@ -198,6 +198,28 @@ impl DropdownElements {
     }
 }
 
+// ============================================================================
+// Badge Relationships
+// ============================================================================
+
+/// Marks an entity as a badge decorating a widget.
+#[derive(Component)]
+#[relationship(relationship_target = WidgetBadges)]
+pub struct BadgeOf(pub Entity);
+
+/// Contains all badges attached to this widget.
+/// The linked_spawn attribute ensures badges are removed with the widget.
+#[derive(Component)]
+#[relationship_target(relationship = BadgeOf, linked_spawn)]
+pub struct WidgetBadges(Vec<Entity>);
+
+impl WidgetBadges {
+    /// Get an iterator over the badges on this widget
+    pub fn iter(&self) -> impl Iterator<Item = &Entity> {
+        self.0.iter()
+    }
+}
+
 // ============================================================================
 // Temporary Components (should be moved to appropriate modules)
 // ============================================================================
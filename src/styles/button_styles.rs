@@ -41,6 +41,8 @@ mod defaults {
     pub const TEXT_SECONDARY: Color = Color::srgb(0.7, 0.7, 0.7);
 
     pub const BORDER_DEFAULT: Color = Color::srgb(0.3, 0.3, 0.3);
+
+    pub const LINK: Color = Color::srgb(0.35, 0.56, 0.96);
 }
 
 /// Button style variants for consistent theming
@@ -58,6 +60,8 @@ pub enum ButtonStyle {
     Warning,
     /// Transparent with border only
     Ghost,
+    /// Text only in the link color, underlined on hover
+    Link,
 }
 
 /// Button size variants
@@ -82,7 +86,7 @@ impl ButtonStyle {
             ButtonStyle::Danger => defaults::DANGER,
             ButtonStyle::Success => defaults::SUCCESS,
             ButtonStyle::Warning => defaults::WARNING,
-            ButtonStyle::Ghost => Color::NONE,
+            ButtonStyle::Ghost | ButtonStyle::Link => Color::NONE,
         }
     }
 
@@ -95,6 +99,7 @@ impl ButtonStyle {
             ButtonStyle::Success => defaults::SUCCESS_HOVER,
             ButtonStyle::Warning => defaults::WARNING_HOVER,
             ButtonStyle::Ghost => defaults::GHOST_HOVER,
+            ButtonStyle::Link => Color::NONE,
         }
     }
 
@@ -107,6 +112,7 @@ impl ButtonStyle {
             ButtonStyle::Success => defaults::SUCCESS_PRESSED,
             ButtonStyle::Warning => defaults::WARNING_PRESSED,
             ButtonStyle::Ghost => defaults::GHOST_PRESSED,
+            ButtonStyle::Link => Color::NONE,
         }
     }
 
@@ -114,6 +120,7 @@ impl ButtonStyle {
     pub fn text_color(&self) -> Color {
         match self {
             ButtonStyle::Ghost => defaults::TEXT_SECONDARY,
+            ButtonStyle::Link => defaults::LINK,
             _ => Color::WHITE,
         }
     }
@@ -127,6 +134,7 @@ impl ButtonStyle {
             ButtonStyle::Success => defaults::SUCCESS_DARK,
             ButtonStyle::Warning => defaults::WARNING_PRESSED,
             ButtonStyle::Ghost => defaults::BORDER_DEFAULT,
+            ButtonStyle::Link => Color::NONE,
         }
     }
     /// Get the colors for this button style (bg, text, border)
@@ -138,6 +146,7 @@ impl ButtonStyle {
             ButtonStyle::Danger => (defaults::DANGER, defaults::TEXT_ON_DANGER, defaults::DANGER_DARK),
             ButtonStyle::Warning => (defaults::WARNING, Color::BLACK, defaults::WARNING_PRESSED),
             ButtonStyle::Ghost => (Color::NONE, defaults::TEXT_SECONDARY, defaults::BORDER_DEFAULT),
+            ButtonStyle::Link => (Color::NONE, defaults::LINK, Color::NONE),
         }
     }
 
@@ -178,6 +187,11 @@ impl ButtonStyle {
                 theme.colors.text.secondary,
                 theme.colors.border.default,
             ),
+            ButtonStyle::Link => (
+                Color::NONE,
+                theme.colors.text.link,
+                Color::NONE,
+            ),
         }
     }
 
@@ -190,6 +204,7 @@ impl ButtonStyle {
             ButtonStyle::Success => theme.colors.success.hover,
             ButtonStyle::Warning => theme.colors.warning.hover,
             ButtonStyle::Ghost => theme.colors.ghost.hover,
+            ButtonStyle::Link => Color::NONE,
         }
     }
 
@@ -202,6 +217,7 @@ impl ButtonStyle {
             ButtonStyle::Success => theme.colors.success.pressed,
             ButtonStyle::Warning => theme.colors.warning.pressed,
             ButtonStyle::Ghost => theme.colors.ghost.pressed,
+            ButtonStyle::Link => Color::NONE,
         }
     }
 }
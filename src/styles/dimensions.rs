@@ -74,11 +74,54 @@ pub const PROGRESS_WIDTH_DEFAULT: f32 = 200.0;
 
 // Z-index layers
 pub const Z_INDEX_BASE: i32 = 0;
+#[deprecated(note = "dropdown menus sit on the popup layer; use ZLayer::Dropdown or Z_INDEX_POPUP")]
 pub const Z_INDEX_DROPDOWN: i32 = 100;
 pub const Z_INDEX_MODAL: i32 = 1000;
+pub const Z_INDEX_POPUP: i32 = 1500;
 pub const Z_INDEX_TOOLTIP: i32 = 2000;
 pub const Z_INDEX_NOTIFICATION: i32 = 3000;
 
+/// Named stacking layers for overlays, lowest first
+///
+/// Each layer's [`z_index`](Self::z_index) is the `GlobalZIndex` the library
+/// gives that kind of overlay. Pass one to [`UiBuilder::z_layer`] to put a
+/// widget on a layer, or add to it to go just above, e.g.
+/// `.z_layer(ZLayer::Toast.z_index() + 1)`.
+///
+/// [`UiBuilder::z_layer`]: crate::traits::UiBuilder::z_layer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ZLayer {
+    /// Regular UI
+    Base,
+    /// Modal dialogs and their backdrops
+    Modal,
+    /// Dropdown, context and autocomplete menus (the popup layer)
+    Dropdown,
+    /// Tooltips
+    Tooltip,
+    /// Toast notifications
+    Toast,
+}
+
+impl ZLayer {
+    /// The z-index of this layer
+    pub const fn z_index(self) -> i32 {
+        match self {
+            ZLayer::Base => Z_INDEX_BASE,
+            ZLayer::Modal => Z_INDEX_MODAL,
+            ZLayer::Dropdown => Z_INDEX_POPUP,
+            ZLayer::Tooltip => Z_INDEX_TOOLTIP,
+            ZLayer::Toast => Z_INDEX_NOTIFICATION,
+        }
+    }
+}
+
+impl From<ZLayer> for i32 {
+    fn from(layer: ZLayer) -> Self {
+        layer.z_index()
+    }
+}
+
 // Animation durations (in seconds)
 pub const ANIMATION_FAST: f32 = 0.15;
 pub const ANIMATION_NORMAL: f32 = 0.3;
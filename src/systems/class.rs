@@ -0,0 +1,122 @@
+//! Class names for tagging groups of widgets
+//!
+//! Any builder can tag the widget it builds with one or more class names,
+//! CSS style. Game code then finds the whole group at once - to restyle a
+//! subset of widgets, toggle them together, or look them up in tests -
+//! without a marker component per group:
+//!
+//! ```ignore
+//! ButtonBuilder::new("Delete").class("danger").build(parent);
+//!
+//! fn lock_danger_buttons(mut commands: Commands, classes: Query<(Entity, &UiClass)>) {
+//!     for button in entities_with_class("danger", &classes) {
+//!         commands.entity(button).insert(Disabled);
+//!     }
+//! }
+//! ```
+
+use bevy::ecs::query::QueryFilter;
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+use smallvec::SmallVec;
+
+/// Class names a widget was tagged with
+///
+/// Added by [`UiBuilder::class`](crate::traits::UiBuilder::class); each call
+/// adds to the names already there.
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq)]
+pub struct UiClass(pub SmallVec<[String; 2]>);
+
+impl UiClass {
+    /// Classes from whitespace-separated names, e.g. `"danger large"`
+    pub fn new(names: &str) -> Self {
+        let mut class = Self::default();
+        class.add(names);
+        class
+    }
+
+    /// Add whitespace-separated names, skipping ones already present
+    pub fn add(&mut self, names: &str) {
+        for name in names.split_whitespace() {
+            if !self.contains(name) {
+                self.0.push(name.to_string());
+            }
+        }
+    }
+
+    /// Remove a class name
+    pub fn remove(&mut self, name: &str) {
+        self.0.retain(|class| class != name);
+    }
+
+    /// Whether the widget has the class `name`
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.iter().any(|class| class == name)
+    }
+
+    /// The class names, in the order they were added
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(String::as_str)
+    }
+}
+
+/// Names queued by a builder's `.class()`, merged into [`UiClass`]
+///
+/// Inserting `UiClass` directly would replace the names from an earlier
+/// `.class()` call on the same builder.
+#[derive(Component, Debug, Clone)]
+pub(crate) struct AddUiClass(pub(crate) String);
+
+/// Every entity in `query` with the class `name`
+pub fn entities_with_class<F: QueryFilter>(name: &str, query: &Query<(Entity, &UiClass), F>) -> Vec<Entity> {
+    query
+        .iter()
+        .filter(|(_, class)| class.contains(name))
+        .map(|(entity, _)| entity)
+        .collect()
+}
+
+/// Merge names queued by `.class()` into the entity's [`UiClass`]
+///
+/// Both steps run as commands, so several `.class()` calls on one builder
+/// add up even before the first `UiClass` exists.
+pub(crate) fn merge_added_class(
+    trigger: On<Insert, AddUiClass>,
+    mut commands: Commands,
+    added: Query<&AddUiClass>,
+) {
+    let entity = trigger.entity;
+    let Ok(AddUiClass(names)) = added.get(entity) else {
+        return;
+    };
+
+    let added_names = names.clone();
+    commands.entity(entity)
+        .remove::<AddUiClass>()
+        .entry::<UiClass>()
+        .and_modify(move |mut class| class.add(&added_names))
+        .or_insert(UiClass::new(names));
+}
+
+define_plugin!(UiClassPlugin {
+    custom_init: |app: &mut App| {
+        app.add_observer(merge_added_class);
+    }
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn class_names_split_and_skip_duplicates() {
+        let mut class = UiClass::new("danger  large");
+        class.add("large outline");
+        assert_eq!(class.iter().collect::<Vec<_>>(), ["danger", "large", "outline"]);
+        assert!(class.contains("outline"));
+
+        class.remove("large");
+        assert!(!class.contains("large"));
+        assert!(!class.contains("dang"));
+    }
+}
@@ -0,0 +1,145 @@
+//! Escape closes the topmost open surface
+//!
+//! Dialogs, dropdown menus, context menus and suggestion menus carry an
+//! [`EscapeLayer`] while they are open, and the [`EscapeStack`] keeps track
+//! of them. Each Escape press closes only the topmost layer - the one with
+//! the highest z-index, or the most recently opened among equals - so a
+//! dropdown open inside a dialog closes on the first press and the dialog
+//! on the second. Layer z-indices are on the same scale as the
+//! [`GlobalZIndex`] the surfaces are drawn with. Tooltips hide on any
+//! Escape press without taking a layer.
+//!
+//! Custom surfaces join the stack the same way:
+//!
+//! ```ignore
+//! commands.spawn((panel_node, MyPanel, EscapeLayer::new(dimensions::Z_INDEX_MODAL)));
+//!
+//! fn close_panels(mut commands: Commands, escape: Res<EscapeStack>, panels: Query<Entity, With<MyPanel>>) {
+//!     for panel in panels.iter().filter(|panel| escape.closes(*panel)) {
+//!         commands.entity(panel).despawn();
+//!     }
+//! }
+//! ```
+
+use bevy::prelude::*;
+use bevy::input::InputSystems;
+use bevy_plugin_builder::define_plugin;
+
+/// Marks an open surface that Escape can close
+///
+/// The layer leaves the stack when the component is removed or the entity
+/// is despawned.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EscapeLayer {
+    /// Stacking order; higher layers close first
+    pub z_index: i32,
+}
+
+impl EscapeLayer {
+    /// Create a layer at the given z-index
+    pub fn new(z_index: i32) -> Self {
+        Self { z_index }
+    }
+}
+
+/// Open [`EscapeLayer`]s, and the one this frame's Escape press closes
+#[derive(Resource, Debug, Default)]
+pub struct EscapeStack {
+    /// Open layers in the order they opened
+    layers: Vec<(Entity, i32)>,
+    /// Layer that was on top when Escape was pressed this frame
+    closing: Option<Entity>,
+}
+
+impl EscapeStack {
+    /// The topmost open layer: the highest z-index, most recent among equals
+    pub fn top(&self) -> Option<Entity> {
+        // max_by_key keeps the last of equal maximums
+        self.layers.iter().max_by_key(|(_, z_index)| *z_index).map(|(entity, _)| *entity)
+    }
+
+    /// Whether Escape was pressed this frame with `entity` on top
+    pub fn closes(&self, entity: Entity) -> bool {
+        self.closing == Some(entity)
+    }
+
+    /// Number of open layers
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Whether no layer is open
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    fn push(&mut self, entity: Entity, z_index: i32) {
+        self.remove(entity);
+        self.layers.push((entity, z_index));
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        self.layers.retain(|(layer, _)| *layer != entity);
+    }
+}
+
+/// Put a newly opened layer on the stack
+pub fn push_escape_layer(
+    trigger: On<Add, EscapeLayer>,
+    layers: Query<&EscapeLayer>,
+    mut stack: ResMut<EscapeStack>,
+) {
+    if let Ok(layer) = layers.get(trigger.entity) {
+        stack.push(trigger.entity, layer.z_index);
+    }
+}
+
+/// Take a closed (or despawned) layer off the stack
+pub fn remove_escape_layer(trigger: On<Remove, EscapeLayer>, mut stack: ResMut<EscapeStack>) {
+    stack.remove(trigger.entity);
+}
+
+/// Pick the layer an Escape press closes, before any widget reacts to it
+pub fn resolve_escape(keyboard: Res<ButtonInput<KeyCode>>, mut stack: ResMut<EscapeStack>) {
+    let closing = keyboard.just_pressed(KeyCode::Escape).then(|| stack.top()).flatten();
+    if stack.closing != closing {
+        stack.closing = closing;
+    }
+}
+
+define_plugin!(EscapeStackPlugin {
+    custom_init: |app: &mut App| {
+        app.init_resource::<EscapeStack>()
+           .add_observer(push_escape_layer)
+           .add_observer(remove_escape_layer)
+           .add_systems(PreUpdate, resolve_escape.after(InputSystems));
+    }
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn top_prefers_z_index_then_most_recent() {
+        let mut world = World::new();
+        let dialog = world.spawn_empty().id();
+        let dropdown = world.spawn_empty().id();
+        let other_dialog = world.spawn_empty().id();
+
+        let mut stack = EscapeStack::default();
+        assert_eq!(stack.top(), None);
+
+        stack.push(dialog, 1000);
+        stack.push(dropdown, 1500);
+        stack.push(other_dialog, 1000);
+        // The dropdown sorts above both dialogs
+        assert_eq!(stack.top(), Some(dropdown));
+
+        // Then the dialog opened last
+        stack.remove(dropdown);
+        assert_eq!(stack.top(), Some(other_dialog));
+        stack.remove(other_dialog);
+        assert_eq!(stack.top(), Some(dialog));
+    }
+}
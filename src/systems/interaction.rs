@@ -1,5 +1,119 @@
-//! Generic interaction handling for UI elements
+//! Poll widget interaction from game logic
 //!
-//! This module is reserved for future interaction utilities.
+//! Every entity with an [`Interaction`] gets an [`InteractionState`] that
+//! records whether it is hovered or pressed, and whether that changed this
+//! frame. Game systems can read it - or use [`is_hovered`] and [`is_pressed`]
+//! on a plain `Interaction` query - instead of matching on `Interaction`
+//! themselves:
+//!
+//! ```ignore
+//! fn show_tooltip(shop: Single<&InteractionState, With<ShopButton>>) {
+//!     if shop.just_hovered {
+//!         info!("Open the shop");
+//!     }
+//! }
+//! ```
+
+use bevy::ecs::query::QueryFilter;
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+/// Readback of an interactive entity's hover and press state
+///
+/// Added automatically to every entity with an [`Interaction`] and updated
+/// each frame in `PreUpdate`, after Bevy's UI focus system.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InteractionState {
+    /// The pointer is over the entity (or pressing it)
+    pub hovered: bool,
+    /// The entity is being pressed
+    pub pressed: bool,
+    /// The pointer moved onto the entity this frame
+    pub just_hovered: bool,
+    /// The pointer moved off the entity this frame
+    pub just_unhovered: bool,
+    /// The entity started being pressed this frame
+    pub just_pressed: bool,
+    /// The entity stopped being pressed this frame
+    pub just_released: bool,
+}
+
+impl InteractionState {
+    /// State for `interaction`, given the state from the previous frame
+    pub fn next(self, interaction: Interaction) -> Self {
+        let hovered = interaction != Interaction::None;
+        let pressed = interaction == Interaction::Pressed;
+
+        Self {
+            hovered,
+            pressed,
+            just_hovered: hovered && !self.hovered,
+            just_unhovered: !hovered && self.hovered,
+            just_pressed: pressed && !self.pressed,
+            just_released: !pressed && self.pressed,
+        }
+    }
+}
+
+/// Whether `entity` is hovered (or being pressed)
+///
+/// Returns `false` if the entity isn't in `query`.
+pub fn is_hovered<F: QueryFilter>(entity: Entity, query: &Query<&Interaction, F>) -> bool {
+    query
+        .get(entity)
+        .is_ok_and(|interaction| *interaction != Interaction::None)
+}
+
+/// Whether `entity` is being pressed
+///
+/// Returns `false` if the entity isn't in `query`.
+pub fn is_pressed<F: QueryFilter>(entity: Entity, query: &Query<&Interaction, F>) -> bool {
+    query
+        .get(entity)
+        .is_ok_and(|interaction| *interaction == Interaction::Pressed)
+}
+
+/// Give new interactive entities an [`InteractionState`]
+pub fn add_interaction_state(trigger: On<Add, Interaction>, mut commands: Commands) {
+    commands.entity(trigger.entity).try_insert_if_new(InteractionState::default());
+}
+
+/// Refresh [`InteractionState`] from each entity's [`Interaction`]
+pub fn update_interaction_states(mut states: Query<(&Interaction, &mut InteractionState)>) {
+    for (interaction, mut state) in states.iter_mut() {
+        let next = state.next(*interaction);
+        state.set_if_neq(next);
+    }
+}
+
+define_plugin!(UiInteractionPlugin {
+    custom_init: |app: &mut App| {
+        app.add_observer(add_interaction_state)
+           .add_systems(
+               PreUpdate,
+               update_interaction_states.after(bevy::ui::UiSystems::Focus),
+           );
+    }
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_reports_edges_once() {
+        let hovered = InteractionState::default().next(Interaction::Hovered);
+        assert!(hovered.hovered && hovered.just_hovered && !hovered.pressed);
+
+        let pressed = hovered.next(Interaction::Pressed);
+        assert!(pressed.pressed && pressed.just_pressed && !pressed.just_hovered);
+
+        let released = pressed.next(Interaction::Hovered);
+        assert!(released.just_released && released.hovered && !released.just_unhovered);
+
+        let left = released.next(Interaction::None);
+        assert!(left.just_unhovered && !left.hovered);
 
-// Currently empty - interaction handling is done within individual builder modules
\ No newline at end of file
+        assert_eq!(left.next(Interaction::None), InteractionState::default());
+    }
+}
@@ -0,0 +1,105 @@
+//! Layout debug outlines (requires the `debug` feature).
+//!
+//! When [`UiDebug::enabled`] is set, every UI container (each `Node` that
+//! isn't text) is outlined, colored by how deeply it is nested, so the boxes
+//! a layout is made of and their padding, gaps and overflow can be seen at a
+//! glance. Widgets that draw their own outline, such as focus rings, keep it.
+//!
+//! ```ignore
+//! fn setup(mut debug: ResMut<UiDebug>) {
+//!     debug.enabled = true;
+//! }
+//! ```
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+/// Outline colors by nesting depth, repeating for deeper nodes
+const DEPTH_COLORS: [Color; 6] = [
+    Color::srgb(1.0, 0.3, 0.3),
+    Color::srgb(1.0, 0.65, 0.2),
+    Color::srgb(0.95, 0.95, 0.3),
+    Color::srgb(0.35, 0.9, 0.4),
+    Color::srgb(0.3, 0.75, 1.0),
+    Color::srgb(0.8, 0.45, 1.0),
+];
+
+/// Controls the layout debug outlines
+#[derive(Resource, Debug, Clone)]
+pub struct UiDebug {
+    /// Whether the outlines are currently shown
+    pub enabled: bool,
+    /// Key that toggles the outlines (None to disable the shortcut)
+    pub toggle_key: Option<KeyCode>,
+    /// Outline width in logical pixels
+    pub outline_width: f32,
+}
+
+impl Default for UiDebug {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            toggle_key: Some(KeyCode::F10),
+            outline_width: 1.0,
+        }
+    }
+}
+
+/// Marks an outline added by [`UiDebug`], so it can be removed again
+#[derive(Component, Debug, Clone, Copy)]
+pub struct UiDebugOutline;
+
+/// Toggle the outlines with the configured key
+pub fn toggle_ui_debug(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut debug: ResMut<UiDebug>,
+) {
+    if let Some(key) = debug.toggle_key {
+        if keyboard.just_pressed(key) {
+            debug.enabled = !debug.enabled;
+        }
+    }
+}
+
+/// Outline containers while enabled, including ones spawned later, and
+/// remove the outlines once disabled
+pub fn sync_ui_debug_outlines(
+    mut commands: Commands,
+    debug: Res<UiDebug>,
+    containers: Query<Entity, (With<Node>, Without<Text>, Without<Outline>)>,
+    outlined: Query<Entity, With<UiDebugOutline>>,
+    parents: Query<&ChildOf>,
+) {
+    if !debug.enabled {
+        if debug.is_changed() {
+            for entity in outlined.iter() {
+                commands.entity(entity).remove::<(Outline, UiDebugOutline)>();
+            }
+        }
+        return;
+    }
+
+    // New containers, plus every outlined one when the settings change
+    let redraw = debug.is_changed();
+    for entity in containers.iter().chain(outlined.iter().filter(|_| redraw)) {
+        let depth = parents.iter_ancestors(entity).count();
+        commands.entity(entity).insert((
+            Outline::new(
+                Val::Px(debug.outline_width),
+                Val::ZERO,
+                DEPTH_COLORS[depth % DEPTH_COLORS.len()],
+            ),
+            UiDebugOutline,
+        ));
+    }
+}
+
+define_plugin!(UiDebugPlugin {
+    custom_init: |app: &mut App| {
+        app.init_resource::<UiDebug>();
+    },
+    update: [
+        toggle_ui_debug,
+        sync_ui_debug_outlines.after(toggle_ui_debug),
+    ]
+});
@@ -0,0 +1,236 @@
+//! Keyboard mnemonics (underlined access keys)
+//!
+//! Buttons built with `mnemonic_label` (and labels built with
+//! `mnemonic_for`) read an access key from their text: an `&` before a
+//! letter or digit marks that character, so `"&Save"` is drawn as "Save"
+//! with the S underlined, and Alt+S presses the button. Write `&&` for a
+//! literal ampersand; an `&` before anything else (as in "Save & Quit") is
+//! kept as-is. Other text is drawn exactly as written.
+//!
+//! A mnemonic press sets the widget's [`Interaction`] to `Pressed` for one
+//! frame, so code that reacts to clicks reacts to the access key as well.
+//! Access keys are ignored while a text input has focus, so Alt+key
+//! combinations (such as word navigation) keep reaching the input:
+//!
+//! ```ignore
+//! ButtonBuilder::new("&Save").mnemonic_label().build(parent); // Alt+S clicks it
+//! ```
+
+use bevy::prelude::*;
+use bevy::ui::UiSystems;
+use bevy_plugin_builder::define_plugin;
+
+use crate::traits::Disabled;
+use super::interaction::update_interaction_states;
+
+/// Access key that presses this widget while Alt is held
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mnemonic(pub KeyCode);
+
+/// Marks a widget pressed by its mnemonic this frame
+#[derive(Component)]
+pub struct MnemonicPressed;
+
+/// Display text with the access key markers removed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedMnemonic {
+    /// Text to draw
+    pub text: String,
+    /// Byte offset of the access key character in `text`
+    pub index: Option<usize>,
+}
+
+impl ParsedMnemonic {
+    /// The access key character, if the text had one
+    pub fn char(&self) -> Option<char> {
+        self.index.and_then(|index| self.text[index..].chars().next())
+    }
+
+    /// Key that triggers the mnemonic
+    pub fn key(&self) -> Option<KeyCode> {
+        self.char().and_then(key_code)
+    }
+
+    /// Text before the access key, and the access key with the text after it
+    ///
+    /// Used to draw the access key as its own underlined span.
+    pub(crate) fn spans(&self) -> (String, Option<(String, String)>) {
+        let Some((index, key)) = self.index.zip(self.char()) else {
+            return (self.text.clone(), None);
+        };
+        let rest = index + key.len_utf8();
+
+        (
+            self.text[..index].to_string(),
+            Some((key.to_string(), self.text[rest..].to_string())),
+        )
+    }
+}
+
+/// Split `&`-marked text into display text and the access key position
+///
+/// Only the first marked character becomes the access key; later markers
+/// are dropped from the text.
+pub fn parse_mnemonic(source: &str) -> ParsedMnemonic {
+    let mut text = String::with_capacity(source.len());
+    let mut index = None;
+    let mut chars = source.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '&' {
+            match chars.peek() {
+                Some('&') => {
+                    chars.next();
+                    text.push('&');
+                    continue;
+                }
+                Some(next) if next.is_ascii_alphanumeric() => {
+                    index.get_or_insert(text.len());
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        text.push(c);
+    }
+
+    ParsedMnemonic { text, index }
+}
+
+/// Add the access key and trailing text as spans of a text entity
+///
+/// The entity's own [`Text`] should hold the text before the access key.
+pub(crate) fn with_mnemonic_spans(
+    entity: &mut EntityCommands,
+    spans: Option<(String, String)>,
+    font: &TextFont,
+    color: TextColor,
+) {
+    let Some((key, rest)) = spans else {
+        return;
+    };
+
+    let font = font.clone();
+    entity.with_children(|text| {
+        text.spawn((TextSpan::new(key), font.clone(), color, Underline));
+        text.spawn((TextSpan::new(rest), font, color));
+    });
+}
+
+/// Key code for a letter or digit
+fn key_code(c: char) -> Option<KeyCode> {
+    Some(match c.to_ascii_uppercase() {
+        'A' => KeyCode::KeyA,
+        'B' => KeyCode::KeyB,
+        'C' => KeyCode::KeyC,
+        'D' => KeyCode::KeyD,
+        'E' => KeyCode::KeyE,
+        'F' => KeyCode::KeyF,
+        'G' => KeyCode::KeyG,
+        'H' => KeyCode::KeyH,
+        'I' => KeyCode::KeyI,
+        'J' => KeyCode::KeyJ,
+        'K' => KeyCode::KeyK,
+        'L' => KeyCode::KeyL,
+        'M' => KeyCode::KeyM,
+        'N' => KeyCode::KeyN,
+        'O' => KeyCode::KeyO,
+        'P' => KeyCode::KeyP,
+        'Q' => KeyCode::KeyQ,
+        'R' => KeyCode::KeyR,
+        'S' => KeyCode::KeyS,
+        'T' => KeyCode::KeyT,
+        'U' => KeyCode::KeyU,
+        'V' => KeyCode::KeyV,
+        'W' => KeyCode::KeyW,
+        'X' => KeyCode::KeyX,
+        'Y' => KeyCode::KeyY,
+        'Z' => KeyCode::KeyZ,
+        '0' => KeyCode::Digit0,
+        '1' => KeyCode::Digit1,
+        '2' => KeyCode::Digit2,
+        '3' => KeyCode::Digit3,
+        '4' => KeyCode::Digit4,
+        '5' => KeyCode::Digit5,
+        '6' => KeyCode::Digit6,
+        '7' => KeyCode::Digit7,
+        '8' => KeyCode::Digit8,
+        '9' => KeyCode::Digit9,
+        _ => return None,
+    })
+}
+
+/// Press the visible, enabled widget whose access key was hit with Alt held
+pub fn press_mnemonics(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut widgets: Query<(Entity, &Mnemonic, &mut Interaction, &InheritedVisibility), Without<Disabled>>,
+    #[cfg(feature = "text_input")]
+    text_buffers: Query<&crate::components::text_input::TextBuffer>,
+) {
+    if !keyboard.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]) {
+        return;
+    }
+
+    // A focused text input owns Alt+key
+    #[cfg(feature = "text_input")]
+    if text_buffers.iter().any(|buffer| buffer.is_focused) {
+        return;
+    }
+
+    for key in keyboard.get_just_pressed() {
+        let widget = widgets
+            .iter_mut()
+            .find(|(_, mnemonic, _, visibility)| mnemonic.0 == *key && visibility.get());
+        if let Some((entity, _, mut interaction, _)) = widget {
+            *interaction = Interaction::Pressed;
+            commands.entity(entity).insert(MnemonicPressed);
+        }
+    }
+}
+
+/// Release widgets pressed by their mnemonic on the previous frame
+pub fn release_mnemonics(
+    mut commands: Commands,
+    mut pressed: Query<(Entity, &mut Interaction), With<MnemonicPressed>>,
+) {
+    for (entity, mut interaction) in pressed.iter_mut() {
+        interaction.set_if_neq(Interaction::None);
+        commands.entity(entity).remove::<MnemonicPressed>();
+    }
+}
+
+define_plugin!(UiMnemonicPlugin {
+    custom_init: |app: &mut App| {
+        app.add_systems(
+            PreUpdate,
+            (
+                release_mnemonics.before(UiSystems::Focus),
+                press_mnemonics
+                    .after(UiSystems::Focus)
+                    .before(update_interaction_states),
+            ),
+        );
+    }
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_marks_first_access_key() {
+        let save = parse_mnemonic("&Save");
+        assert_eq!(save.text, "Save");
+        assert_eq!(save.key(), Some(KeyCode::KeyS));
+        assert_eq!(save.spans(), (String::new(), Some(("S".into(), "ave".into()))));
+
+        let exit = parse_mnemonic("E&xit");
+        assert_eq!(exit.char(), Some('x'));
+        assert_eq!(exit.spans(), ("E".into(), Some(("x".into(), "it".into()))));
+
+        let literal = parse_mnemonic("Save & Quit && R&&D");
+        assert_eq!(literal.text, "Save & Quit & R&D");
+        assert_eq!(literal.key(), None);
+    }
+}
@@ -1,9 +1,31 @@
 //! Shared systems for all UI builders
 
+pub mod class;
 pub mod cleanup;
+pub mod escape;
 pub mod interaction;
+#[cfg(feature = "debug")]
+pub mod layout_debug;
+pub mod mnemonic;
+pub mod popup;
+pub mod visibility;
 
 // Gateway exports - external code MUST access through here!
 // These exports are used by lib.rs to provide the public API.
 #[allow(unused_imports)]
-pub use cleanup::{despawn_entities, despawn_ui_entities};
\ No newline at end of file
+pub use class::{UiClass, entities_with_class, UiClassPlugin};
+#[allow(unused_imports)]
+pub use cleanup::{despawn_entities, despawn_ui_entities};
+#[allow(unused_imports)]
+pub use escape::{EscapeLayer, EscapeStack, EscapeStackPlugin};
+#[allow(unused_imports)]
+pub use interaction::{InteractionState, is_hovered, is_pressed, UiInteractionPlugin};
+#[allow(unused_imports)]
+pub use mnemonic::{Mnemonic, MnemonicPressed, ParsedMnemonic, parse_mnemonic, UiMnemonicPlugin};
+#[cfg(feature = "debug")]
+#[allow(unused_imports)]
+pub use layout_debug::{UiDebug, UiDebugOutline, UiDebugPlugin};
+#[allow(unused_imports)]
+pub use visibility::{UiHidden, SetVisible, UiVisibilityPlugin};
+#[allow(unused_imports)]
+pub use popup::{PopupLayer, Popup, PopupAnchor, PopupSide, PopupWidth, PopupLayerPlugin};
\ No newline at end of file
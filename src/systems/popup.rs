@@ -0,0 +1,344 @@
+//! Shared top layer for popups
+//!
+//! Dropdown menus, context menus and tooltips are tagged with [`Popup`] and
+//! moved under a single full-screen [`PopupLayer`] root. Living outside the
+//! widget tree means a popup is never clipped by a scrolling or
+//! `Overflow::clip` ancestor, and never sorts behind a sibling panel with a
+//! higher z-index.
+//!
+//! Popups that belong to a widget carry a [`PopupAnchor`], which keeps them
+//! glued below the anchor every frame:
+//!
+//! ```ignore
+//! commands.spawn((my_menu_node, PopupAnchor::below(button_entity)));
+//! ```
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+use crate::styles::dimensions;
+use crate::utils::geometry::node_logical_rect;
+
+/// Root node that hosts every popup above normal UI content
+///
+/// Spawned on demand the first time a [`Popup`] appears.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct PopupLayer;
+
+/// Marks content that should render in the [`PopupLayer`]
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct Popup;
+
+/// Positions a popup directly below (or beside) another UI node
+#[derive(Component, Debug, Clone, Copy)]
+#[require(Popup)]
+pub struct PopupAnchor {
+    /// The node the popup hangs from
+    pub target: Entity,
+    /// Which side of the anchor the popup opens on
+    pub side: PopupSide,
+    /// Gap between the anchor's edge and the popup
+    pub gap: f32,
+    /// How the popup's width follows the anchor
+    pub width: PopupWidth,
+}
+
+/// Which side of its anchor a popup opens on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PopupSide {
+    /// Below the anchor, aligned to its left edge (dropdown menus)
+    #[default]
+    Below,
+    /// To the right of the anchor, aligned to its top edge (cascading submenus)
+    Right,
+}
+
+/// How an anchored popup is sized relative to its anchor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PopupWidth {
+    /// Exactly the anchor's width
+    #[default]
+    Match,
+    /// At least the anchor's width, growing to fit wider content
+    AtLeast,
+    /// Leave the popup's own width alone
+    Free,
+}
+
+impl PopupAnchor {
+    /// Anchor below `target`, matching its width
+    pub fn below(target: Entity) -> Self {
+        Self {
+            target,
+            side: PopupSide::Below,
+            gap: 4.0,
+            width: PopupWidth::Match,
+        }
+    }
+
+    /// Anchor to the right of `target`, top edges aligned, keeping the
+    /// popup's own width
+    pub fn beside(target: Entity) -> Self {
+        Self {
+            target,
+            side: PopupSide::Right,
+            gap: 2.0,
+            width: PopupWidth::Free,
+        }
+    }
+
+    /// Set the gap between the anchor and the popup
+    pub fn with_gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Set how the popup's width follows the anchor
+    pub fn with_width(mut self, width: PopupWidth) -> Self {
+        self.width = width;
+        self
+    }
+}
+
+/// Layout shared by popup menus (dropdown menus, text input suggestions)
+///
+/// The menu starts hidden; set `display` to `Display::Flex` to open it.
+pub(crate) fn menu_node(max_height: Val) -> Node {
+    Node {
+        max_height,
+        position_type: PositionType::Absolute,
+        flex_direction: FlexDirection::Column,
+        display: Display::None, // Hidden by default
+        overflow: Overflow::scroll_y(),
+        border: UiRect::all(Val::Px(2.0)),
+        ..default()
+    }
+}
+
+/// Layout of a single row in a popup menu
+pub(crate) fn menu_item_node(height: Val) -> Node {
+    Node {
+        width: Val::Percent(100.0),
+        height,
+        flex_shrink: 0.0, // Keep full height inside a scrolling menu
+        padding: UiRect::all(Val::Px(dimensions::PADDING_SMALL)),
+        align_items: AlignItems::Center,
+        column_gap: Val::Px(dimensions::SPACING_SMALL),
+        ..default()
+    }
+}
+
+/// Submenu hover tracking for one open menu (root or submenu)
+///
+/// Shared by context menus and dropdowns so cascading menus behave the same
+/// everywhere: a submenu opens after its item has been hovered for the open
+/// delay, and an open submenu stays up while the pointer is inside it or
+/// travelling toward it across other items, closing the close delay after
+/// the pointer moves on.
+#[derive(Component, Default, Debug)]
+pub(crate) struct SubmenuHover {
+    /// The submenu item whose submenu is open, and the submenu
+    pub(crate) open: Option<(Entity, Entity)>,
+    /// Item hovered last frame
+    pub(crate) hovered: Option<Entity>,
+    /// Seconds the current hover has lasted without aiming at the open submenu
+    pub(crate) timer: f32,
+    /// Cursor position last frame (logical pixels)
+    pub(crate) last_cursor: Option<Vec2>,
+}
+
+/// Submenu changes decided by [`SubmenuHover::update`]
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct SubmenuSwitch {
+    /// Open submenu to close, with its item
+    pub(crate) close: Option<(Entity, Entity)>,
+    /// Item whose submenu should open; the caller opens it and records it in
+    /// [`SubmenuHover::open`]
+    pub(crate) open: Option<Entity>,
+}
+
+impl SubmenuHover {
+    /// Advance hover tracking by one frame
+    ///
+    /// `hovered` is the item under the pointer and whether it has a submenu;
+    /// `in_submenu` is whether the pointer is inside the open submenu (or one
+    /// of its own submenus), and `open_rect` the open submenu's bounds.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn update(
+        &mut self,
+        hovered: Option<(Entity, bool)>,
+        in_submenu: bool,
+        open_rect: Option<Rect>,
+        cursor: Option<Vec2>,
+        delta: f32,
+        open_delay: f32,
+        close_delay: f32,
+    ) -> Option<SubmenuSwitch> {
+        let hovered_entity = hovered.map(|(entity, _)| entity);
+        if self.hovered != hovered_entity {
+            self.hovered = hovered_entity;
+            self.timer = 0.0;
+        }
+
+        let keep_open = in_submenu || self.open.is_some_and(|(item, _)| Some(item) == hovered_entity);
+        let target = hovered.filter(|(_, has_submenu)| *has_submenu).map(|(entity, _)| entity);
+
+        let mut switch = None;
+        if keep_open || (self.open.is_none() && target.is_none()) {
+            self.timer = 0.0;
+        } else {
+            // Heading for the open submenu across other items holds it open
+            let aiming = match (self.last_cursor, cursor, open_rect) {
+                (Some(from), Some(to), Some(rect)) => {
+                    let edge_x = if rect.center().x >= from.x { rect.min.x } else { rect.max.x };
+                    aiming_at_submenu(from, to, Vec2::new(edge_x, rect.min.y), Vec2::new(edge_x, rect.max.y))
+                }
+                _ => false,
+            };
+
+            if aiming {
+                self.timer = 0.0;
+            } else {
+                self.timer += delta;
+            }
+
+            let delay = if self.open.is_some() { close_delay } else { open_delay };
+            if self.timer >= delay {
+                self.timer = 0.0;
+                switch = Some(SubmenuSwitch {
+                    close: self.open.take(),
+                    open: target,
+                });
+            }
+        }
+
+        self.last_cursor = cursor;
+        switch
+    }
+}
+
+/// Whether the pointer moving from `from` to `to` is heading into the
+/// submenu whose near edge runs from `edge_top` to `edge_bottom`
+///
+/// Checks that `to` lies inside the triangle between `from` and the edge, so
+/// a diagonal path across other items toward the submenu doesn't close it.
+pub(crate) fn aiming_at_submenu(from: Vec2, to: Vec2, edge_top: Vec2, edge_bottom: Vec2) -> bool {
+    if from == to {
+        return false;
+    }
+
+    let side = |a: Vec2, b: Vec2, p: Vec2| (b - a).perp_dot(p - a);
+    let d1 = side(from, edge_top, to);
+    let d2 = side(edge_top, edge_bottom, to);
+    let d3 = side(edge_bottom, from, to);
+
+    let has_negative = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+    let has_positive = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+    !(has_negative && has_positive)
+}
+
+/// Move newly spawned popups under the popup layer
+pub fn raise_popups(
+    mut commands: Commands,
+    popups: Query<(Entity, Option<&ChildOf>), Added<Popup>>,
+    layers: Query<Entity, With<PopupLayer>>,
+) {
+    if popups.is_empty() {
+        return;
+    }
+
+    let layer = layers.single().unwrap_or_else(|_| {
+        commands.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                top: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            GlobalZIndex(dimensions::Z_INDEX_POPUP),
+            Pickable::IGNORE, // Clicks go through to the UI below
+            PopupLayer,
+            Name::new("PopupLayer"),
+        )).id()
+    });
+
+    for (entity, child_of) in popups.iter() {
+        if child_of.map(ChildOf::parent) != Some(layer) {
+            commands.entity(layer).add_child(entity);
+        }
+    }
+}
+
+/// Keep anchored popups next to their anchors
+pub fn position_anchored_popups(
+    mut popups: Query<(&PopupAnchor, &mut Node)>,
+    anchors: Query<(&GlobalTransform, &ComputedNode)>,
+) {
+    for (anchor, mut node) in popups.iter_mut() {
+        // Closed popups don't need to follow
+        if node.display == Display::None {
+            continue;
+        }
+
+        let Ok((transform, computed)) = anchors.get(anchor.target) else {
+            continue;
+        };
+
+        let rect = node_logical_rect(computed, transform);
+        let (left, top) = match anchor.side {
+            PopupSide::Below => (rect.min.x, rect.max.y + anchor.gap),
+            PopupSide::Right => (rect.max.x + anchor.gap, rect.min.y),
+        };
+        let (left, top) = (Val::Px(left), Val::Px(top));
+        if node.left != left {
+            node.left = left;
+        }
+        if node.top != top {
+            node.top = top;
+        }
+
+        // The anchor is measured while the popup is open, so a button that
+        // was resized since the last time is picked up on the next open
+        let anchor_width = Val::Px(rect.width());
+        match anchor.width {
+            PopupWidth::Match => {
+                if node.width != anchor_width {
+                    node.width = anchor_width;
+                }
+            }
+            PopupWidth::AtLeast => {
+                if node.min_width != anchor_width {
+                    node.min_width = anchor_width;
+                }
+            }
+            PopupWidth::Free => {}
+        }
+    }
+}
+
+define_plugin!(PopupLayerPlugin {
+    update: [
+        raise_popups,
+        position_anchored_popups,
+    ]
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diagonal_move_toward_submenu_is_aiming() {
+        // Submenu to the right, near edge at x = 200 from y = 0 to y = 200
+        let top = Vec2::new(200.0, 0.0);
+        let bottom = Vec2::new(200.0, 200.0);
+        let from = Vec2::new(100.0, 20.0);
+
+        assert!(aiming_at_submenu(from, Vec2::new(120.0, 40.0), top, bottom));
+        // Straight down, away from the submenu's edge
+        assert!(!aiming_at_submenu(from, Vec2::new(95.0, 60.0), top, bottom));
+        // Standing still isn't aiming
+        assert!(!aiming_at_submenu(from, from, top, bottom));
+    }
+}
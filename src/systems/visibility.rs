@@ -0,0 +1,201 @@
+//! Show/hide widgets without despawning them
+//!
+//! A widget carrying [`UiHidden`] is collapsed out of the layout
+//! (`Display::None`) and made invisible, so neither it nor its children
+//! receive pointer interaction. Popups the widget owns in the popup layer
+//! (dropdown menus and submenus, suggestion menus) are closed along with it.
+//! Removing the component restores the display mode the widget had before it
+//! was hidden.
+//!
+//! Hide widgets at build time with `.visible(false)` on any builder, and
+//! toggle them at runtime by writing [`SetVisible`]:
+//!
+//! ```ignore
+//! fn toggle(mut visibility: MessageWriter<SetVisible>, panel: Single<(Entity, Has<UiHidden>), With<AdvancedOptions>>) {
+//!     let (entity, hidden) = *panel;
+//!     visibility.write(SetVisible::new(entity, hidden));
+//! }
+//! ```
+
+use bevy::prelude::*;
+use bevy_plugin_builder::define_plugin;
+
+#[cfg(feature = "dropdown")]
+use crate::components::dropdown::{DropdownMenu, DropdownState, DropdownSubmenu};
+#[cfg(feature = "dropdown")]
+use crate::relationships::BelongsToDropdown;
+#[cfg(feature = "text_input")]
+use crate::components::text_input::TextInputSuggestions;
+#[cfg(feature = "text_input")]
+use crate::systems::escape::EscapeLayer;
+#[cfg(any(feature = "text_input", feature = "dropdown"))]
+use crate::systems::popup::Popup;
+
+/// Marks a widget as hidden.
+///
+/// Stores the display mode to restore once the widget is shown again.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct UiHidden {
+    /// Display mode the widget had before it was hidden
+    pub display: Display,
+}
+
+impl Default for UiHidden {
+    fn default() -> Self {
+        Self {
+            display: Display::Flex,
+        }
+    }
+}
+
+/// Message requesting that a widget be shown or hidden
+#[derive(Message, Debug, Clone, Copy)]
+pub struct SetVisible {
+    /// The widget's root entity
+    pub entity: Entity,
+    /// Whether the widget should be shown
+    pub visible: bool,
+}
+
+impl SetVisible {
+    /// Show (`true`) or hide (`false`) `entity`
+    pub fn new(entity: Entity, visible: bool) -> Self {
+        Self { entity, visible }
+    }
+}
+
+/// Apply [`SetVisible`] requests
+pub fn handle_set_visible(
+    mut commands: Commands,
+    mut requests: MessageReader<SetVisible>,
+    hidden: Query<Has<UiHidden>>,
+) {
+    for request in requests.read() {
+        let Ok(is_hidden) = hidden.get(request.entity) else {
+            continue;
+        };
+
+        if request.visible && is_hidden {
+            commands.entity(request.entity).remove::<UiHidden>();
+        } else if !request.visible && !is_hidden {
+            commands.entity(request.entity).insert(UiHidden::default());
+        }
+    }
+}
+
+/// Collapse a widget when it becomes hidden
+///
+/// Popups live outside the widget tree, so the ones owned by the widget or
+/// its descendants are closed here rather than hidden with it.
+#[allow(clippy::too_many_arguments)]
+pub fn hide_widget(
+    trigger: On<Add, UiHidden>,
+    #[cfg(feature = "text_input")]
+    mut commands: Commands,
+    mut widgets: Query<(&mut Node, &mut Visibility, &mut UiHidden)>,
+    children: Query<&Children>,
+    mut interactions: Query<&mut Interaction>,
+    #[cfg(feature = "text_input")]
+    mut text_buffers: Query<&mut crate::components::text_input::TextBuffer>,
+    #[cfg(feature = "text_input")]
+    mut suggestions: Query<&mut TextInputSuggestions>,
+    #[cfg(feature = "dropdown")]
+    mut dropdowns: Query<&mut DropdownState>,
+    #[cfg(feature = "dropdown")]
+    dropdown_menus: Query<(Entity, &BelongsToDropdown), Or<(With<DropdownMenu>, With<DropdownSubmenu>)>>,
+    #[cfg(any(feature = "text_input", feature = "dropdown"))]
+    mut popups: Query<&mut Node, (With<Popup>, Without<UiHidden>)>,
+) {
+    let entity = trigger.entity;
+    let Ok((mut node, mut visibility, mut hidden)) = widgets.get_mut(entity) else {
+        return;
+    };
+
+    hidden.display = node.display;
+    node.display = Display::None;
+    *visibility = Visibility::Hidden;
+
+    // Hidden widgets must not keep a stale hover/press state or keyboard focus
+    for descendant in std::iter::once(entity).chain(children.iter_descendants(entity)) {
+        if let Ok(mut interaction) = interactions.get_mut(descendant) {
+            interaction.set_if_neq(Interaction::None);
+        }
+
+        #[cfg(feature = "text_input")]
+        if let Ok(mut buffer) = text_buffers.get_mut(descendant) {
+            if buffer.is_focused {
+                buffer.is_focused = false;
+            }
+        }
+
+        #[cfg(feature = "text_input")]
+        if let Ok(mut suggestions) = suggestions.get_mut(descendant) {
+            suggestions.candidates.clear();
+            suggestions.highlighted = None;
+            suggestions.query = None;
+            if let Ok(mut menu) = popups.get_mut(suggestions.menu) {
+                menu.display = Display::None;
+            }
+            commands.entity(suggestions.menu).try_remove::<EscapeLayer>();
+        }
+
+        #[cfg(feature = "dropdown")]
+        if let Ok(mut state) = dropdowns.get_mut(descendant) {
+            state.set_if_neq(DropdownState::Closed);
+            for (menu, belongs_to) in dropdown_menus.iter() {
+                if belongs_to.0 == descendant {
+                    if let Ok(mut menu_node) = popups.get_mut(menu) {
+                        menu_node.display = Display::None;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Restore a widget's layout when it is shown again
+pub fn show_widget(
+    trigger: On<Remove, UiHidden>,
+    mut widgets: Query<(&mut Node, &mut Visibility, &UiHidden)>,
+) {
+    if let Ok((mut node, mut visibility, hidden)) = widgets.get_mut(trigger.entity) {
+        node.display = hidden.display;
+        *visibility = Visibility::Inherited;
+    }
+}
+
+define_plugin!(UiVisibilityPlugin {
+    messages: [SetVisible],
+    custom_init: |app: &mut App| {
+        app.add_observer(hide_widget)
+           .add_observer(show_widget);
+    },
+    update: [
+        handle_set_visible,
+    ]
+});
+
+#[cfg(all(test, feature = "dropdown"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hiding_a_dropdown_closes_its_menu() {
+        let mut app = App::new();
+        app.add_observer(hide_widget);
+
+        let dropdown = app.world_mut().spawn((Node::default(), DropdownState::Open)).id();
+        let menu = app.world_mut().spawn((
+            Node::default(),
+            Popup,
+            DropdownMenu,
+            BelongsToDropdown(dropdown),
+        )).id();
+
+        app.world_mut().entity_mut(dropdown).insert(UiHidden::default());
+        app.world_mut().flush();
+
+        assert_eq!(*app.world().get::<DropdownState>(dropdown).unwrap(), DropdownState::Closed);
+        assert_eq!(app.world().get::<Node>(menu).unwrap().display, Display::None);
+    }
+}
@@ -3,6 +3,8 @@
 //! These allow fine-grained control over individual component appearances
 //! beyond what the base theme provides.
 
+use bevy::prelude::{Handle, Image};
+
 /// Component-specific style overrides.
 ///
 /// These settings allow customization of individual component types
@@ -25,6 +27,8 @@ pub struct ComponentStyles {
     pub checkbox: CheckboxComponentStyle,
     /// Dropdown styles
     pub dropdown: DropdownComponentStyle,
+    /// Toast styles
+    pub toast: ToastComponentStyle,
 }
 
 /// Button component styles
@@ -71,9 +75,10 @@ pub struct TextInputComponentStyle {
     pub default_width: f32,
     /// Border radius
     pub border_radius: f32,
-    /// Cursor blink rate (in seconds)
+    /// Cursor blinks per second (0.0 keeps the cursor solid), used by
+    /// themed text inputs
     pub cursor_blink_rate: f32,
-    /// Cursor width
+    /// Width in pixels of line cursors, used by themed text inputs
     pub cursor_width: f32,
 }
 
@@ -84,7 +89,7 @@ impl Default for TextInputComponentStyle {
             min_width: 200.0,
             default_width: 300.0,
             border_radius: 6.0,
-            cursor_blink_rate: 0.53,
+            cursor_blink_rate: 1.0,
             cursor_width: 2.0,
         }
     }
@@ -239,3 +244,19 @@ impl Default for DropdownComponentStyle {
         }
     }
 }
+
+/// Toast component styles
+///
+/// Icons replace the built-in glyph shown for each toast variant. A toast's
+/// own `ToastBuilder::icon` takes precedence.
+#[derive(Clone, Debug, Default)]
+pub struct ToastComponentStyle {
+    /// Icon for info toasts
+    pub info_icon: Option<Handle<Image>>,
+    /// Icon for success toasts
+    pub success_icon: Option<Handle<Image>>,
+    /// Icon for warning toasts
+    pub warning_icon: Option<Handle<Image>>,
+    /// Icon for error toasts
+    pub error_icon: Option<Handle<Image>>,
+}
@@ -25,7 +25,7 @@
 //!
 //! The theme is organized into:
 //! - **Colors**: Semantic colors (primary, danger, success, etc.) with interaction states
-//! - **Typography**: Font sizes and line heights
+//! - **Typography**: Font sizes, line heights and per-role fonts
 //! - **Spacing**: Consistent spacing scale
 //! - **Borders**: Border widths and radii
 //! - **Animation**: Duration and easing presets
@@ -50,11 +50,11 @@ pub use borders::{BorderRadii, BorderWidths, ThemeBorders};
 pub use components::{
     ButtonComponentStyle, CheckboxComponentStyle, ComponentStyles, DialogComponentStyle,
     DropdownComponentStyle, PanelComponentStyle, ProgressComponentStyle, SliderComponentStyle,
-    TextInputComponentStyle,
+    TextInputComponentStyle, ToastComponentStyle,
 };
 pub use plugin::{detect_theme_changes, ThemeChanged, ThemePlugin};
 pub use spacing::{ComponentSpacing, SpacingLevel, SpacingScale, ThemeSpacing};
-pub use typography::{LineHeights, ThemeTypography, TypeScale};
+pub use typography::{FontRole, LineHeights, ThemeFonts, ThemeTypography, TypeScale};
 pub use types::{
     contrast_color, darken, desaturate, lighten, BorderColors, ColorScale,
     GhostColors, SurfaceColors, TextColors, ThemeColors, UiTheme,
@@ -88,6 +88,8 @@ impl UiTheme {
             borders: ThemeBorders::default(),
             animation: ThemeAnimation::default(),
             components: ComponentStyles::default(),
+            reduce_motion: false,
+            reduce_transparency: false,
         }
     }
 
@@ -167,6 +169,8 @@ impl UiTheme {
             borders: ThemeBorders::default(),
             animation: ThemeAnimation::default(),
             components: ComponentStyles::default(),
+            reduce_motion: false,
+            reduce_transparency: false,
         }
     }
 
@@ -241,6 +245,8 @@ impl UiTheme {
             borders: ThemeBorders::default(),
             animation: ThemeAnimation::default(),
             components: ComponentStyles::default(),
+            reduce_motion: false,
+            reduce_transparency: false,
         }
     }
 }
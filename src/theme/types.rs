@@ -10,7 +10,7 @@ use bevy::prelude::*;
 use super::borders::ThemeBorders;
 use super::components::ComponentStyles;
 use super::spacing::ThemeSpacing;
-use super::typography::ThemeTypography;
+use super::typography::{FontRole, ThemeTypography};
 use super::animation::ThemeAnimation;
 
 /// The main UI theme resource.
@@ -44,6 +44,12 @@ pub struct UiTheme {
     pub animation: ThemeAnimation,
     /// Component-specific style overrides
     pub components: ComponentStyles,
+    /// Skip UI animations: hover effects, enter/exit transitions and tweens
+    /// jump straight to their final state (mirrors the OS "reduce motion" setting)
+    pub reduce_motion: bool,
+    /// Draw backdrops and overlays fully opaque instead of see-through
+    /// (mirrors the OS "reduce transparency" setting)
+    pub reduce_transparency: bool,
 }
 
 impl UiTheme {
@@ -56,6 +62,8 @@ impl UiTheme {
             borders: ThemeBorders::default(),
             animation: ThemeAnimation::default(),
             components: ComponentStyles::default(),
+            reduce_motion: false,
+            reduce_transparency: false,
         }
     }
 
@@ -89,6 +97,12 @@ impl UiTheme {
         self
     }
 
+    /// Set the font used for a text role (heading, body, monospace, ...)
+    pub fn with_font(mut self, role: FontRole, font: Handle<Font>) -> Self {
+        self.typography = self.typography.with_font(role, font);
+        self
+    }
+
     /// Scale all typography by a factor
     pub fn with_font_scale(mut self, scale: f32) -> Self {
         self.typography = self.typography.scaled(scale);
@@ -100,6 +114,29 @@ impl UiTheme {
         self.spacing = self.spacing.scaled(scale);
         self
     }
+
+    /// Skip UI animations (see [`reduce_motion`](Self::reduce_motion))
+    pub fn with_reduce_motion(mut self, enabled: bool) -> Self {
+        self.reduce_motion = enabled;
+        self
+    }
+
+    /// Make backdrops opaque (see [`reduce_transparency`](Self::reduce_transparency))
+    pub fn with_reduce_transparency(mut self, enabled: bool) -> Self {
+        self.reduce_transparency = enabled;
+        self
+    }
+
+    /// Backdrop/overlay color, made fully opaque when `reduce_transparency` is set
+    ///
+    /// Fully transparent colors are left alone so "no background" stays invisible.
+    pub fn backdrop_color(&self, color: Color) -> Color {
+        if self.reduce_transparency && color.alpha() > 0.0 {
+            color.with_alpha(1.0)
+        } else {
+            color
+        }
+    }
 }
 
 /// Semantic color palette for the theme.
@@ -130,6 +167,73 @@ pub struct ThemeColors {
     pub overlay: Color,
 }
 
+impl ThemeColors {
+    /// The five semantic color scales, in a fixed order
+    fn scales(&self) -> [&ColorScale; 5] {
+        [&self.primary, &self.secondary, &self.success, &self.warning, &self.danger]
+    }
+
+    /// Every color of the palette in a fixed role order
+    ///
+    /// The same index names the same role in any two palettes. Surfaces, text
+    /// and borders come first so that a color shared by several roles maps
+    /// through its most general one.
+    pub fn palette(&self) -> Vec<Color> {
+        let surface = &self.surface;
+        let text = &self.text;
+        let border = &self.border;
+
+        let mut colors = vec![
+            surface.background, surface.primary, surface.secondary, surface.tertiary,
+            surface.dark, surface.medium, surface.light,
+            text.primary, text.secondary, text.disabled, text.title, text.muted, text.link,
+            border.default, border.focus, border.error, border.success, border.light,
+            self.ghost.hover, self.ghost.pressed,
+            self.overlay,
+        ];
+        for scale in self.scales() {
+            colors.extend([scale.base, scale.hover, scale.pressed, scale.disabled, scale.on_color]);
+        }
+        colors
+    }
+
+    /// Find the color playing the same role in `to` as `color` plays in this palette
+    ///
+    /// Returns `None` for colors that aren't part of this palette.
+    pub fn remap(&self, to: &ThemeColors, color: Color) -> Option<Color> {
+        let index = self.palette().iter().position(|c| same_color(*c, color))?;
+        to.palette().get(index).copied()
+    }
+
+    /// Like [`remap`](Self::remap), for text drawn on `background`
+    ///
+    /// Text on a semantic color (a primary button's label, say) maps to that
+    /// scale's `on_color` rather than to a general text role.
+    pub fn remap_text(&self, to: &ThemeColors, color: Color, background: Option<Color>) -> Option<Color> {
+        if let Some(background) = background {
+            let on_scale = self.scales().into_iter().zip(to.scales()).find(|(scale, _)| {
+                same_color(scale.on_color, color)
+                    && [scale.base, scale.hover, scale.pressed, scale.disabled]
+                        .into_iter()
+                        .any(|c| same_color(c, background))
+            });
+            if let Some((_, target)) = on_scale {
+                return Some(target.on_color);
+            }
+        }
+        self.remap(to, color)
+    }
+}
+
+/// Whether two colors are equal up to float rounding
+fn same_color(a: Color, b: Color) -> bool {
+    let (a, b) = (a.to_linear(), b.to_linear());
+    (a.red - b.red).abs() < 1e-4
+        && (a.green - b.green).abs() < 1e-4
+        && (a.blue - b.blue).abs() < 1e-4
+        && (a.alpha - b.alpha).abs() < 1e-4
+}
+
 /// Colors for ghost/transparent style elements
 #[derive(Clone, Debug)]
 pub struct GhostColors {
@@ -308,3 +412,30 @@ pub fn contrast_color(background: Color) -> Color {
 }
 
 // Note: Use bevy::color::Alpha trait for with_alpha() functionality
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remap_follows_color_roles() {
+        let dark = UiTheme::dark().colors;
+        let light = UiTheme::light().colors;
+
+        assert_eq!(dark.remap(&light, dark.surface.primary), Some(light.surface.primary));
+        assert_eq!(dark.remap(&light, dark.text.primary), Some(light.text.primary));
+        assert_eq!(dark.remap(&light, Color::srgb(0.42, 0.1, 0.9)), None);
+    }
+
+    #[test]
+    fn remap_text_prefers_on_color_over_its_background() {
+        let dark = UiTheme::dark().colors;
+        let light = UiTheme::light().colors;
+
+        // White is both the title color and the text color on primary buttons
+        let label = dark.remap_text(&light, Color::WHITE, Some(dark.primary.base));
+        assert_eq!(label, Some(light.primary.on_color));
+        let title = dark.remap_text(&light, Color::WHITE, Some(dark.surface.primary));
+        assert_eq!(title, Some(light.text.title));
+    }
+}
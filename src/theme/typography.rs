@@ -1,5 +1,7 @@
 //! Typography settings for text rendering.
 
+use bevy::prelude::{Font, Handle};
+
 /// Typography settings for the theme.
 #[derive(Clone, Debug)]
 pub struct ThemeTypography {
@@ -7,6 +9,8 @@ pub struct ThemeTypography {
     pub scale: TypeScale,
     /// Line height multipliers
     pub line_height: LineHeights,
+    /// Font handles per text role
+    pub fonts: ThemeFonts,
 }
 
 impl ThemeTypography {
@@ -15,6 +19,7 @@ impl ThemeTypography {
         Self {
             scale: TypeScale::from_base(base),
             line_height: LineHeights::default(),
+            fonts: ThemeFonts::default(),
         }
     }
 
@@ -23,8 +28,20 @@ impl ThemeTypography {
         Self {
             scale: self.scale.scaled(factor),
             line_height: self.line_height,
+            fonts: self.fonts,
         }
     }
+
+    /// Set the font used for a text role
+    pub fn with_font(mut self, role: FontRole, font: Handle<Font>) -> Self {
+        self.fonts.set(role, font);
+        self
+    }
+
+    /// Font to use for a text role (see [`ThemeFonts::font_for`])
+    pub fn font_for(&self, role: FontRole) -> Handle<Font> {
+        self.fonts.font_for(role)
+    }
 }
 
 impl Default for ThemeTypography {
@@ -32,10 +49,75 @@ impl Default for ThemeTypography {
         Self {
             scale: TypeScale::default(),
             line_height: LineHeights::default(),
+            fonts: ThemeFonts::default(),
         }
     }
 }
 
+/// What a piece of text is for, used to pick its font
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum FontRole {
+    /// Large display text (titles)
+    Display,
+    /// Section headings
+    Heading,
+    /// Normal body text and input contents
+    #[default]
+    Body,
+    /// Code, numbers and other fixed-width text
+    Monospace,
+    /// Italic body text, such as placeholders
+    Italic,
+}
+
+/// Font handles per text role
+///
+/// Unset roles fall back along the chain display → heading → body → Bevy's
+/// default font. Monospace falls back straight to the default font, since
+/// the body font is unlikely to be fixed-width either. Italic falls back to
+/// the upright body font, as Bevy can't slant text itself.
+#[derive(Clone, Debug, Default)]
+pub struct ThemeFonts {
+    /// Font for display/title text
+    pub display: Option<Handle<Font>>,
+    /// Font for headings
+    pub heading: Option<Handle<Font>>,
+    /// Font for body text
+    pub body: Option<Handle<Font>>,
+    /// Fixed-width font
+    pub monospace: Option<Handle<Font>>,
+    /// Italic face of the body font
+    pub italic: Option<Handle<Font>>,
+}
+
+impl ThemeFonts {
+    /// Set the font for a role
+    pub fn set(&mut self, role: FontRole, font: Handle<Font>) {
+        let slot = match role {
+            FontRole::Display => &mut self.display,
+            FontRole::Heading => &mut self.heading,
+            FontRole::Body => &mut self.body,
+            FontRole::Monospace => &mut self.monospace,
+            FontRole::Italic => &mut self.italic,
+        };
+        *slot = Some(font);
+    }
+
+    /// Font to use for a role, following the fallback chain
+    pub fn font_for(&self, role: FontRole) -> Handle<Font> {
+        let font = match role {
+            FontRole::Display => self.display.as_ref()
+                .or(self.heading.as_ref())
+                .or(self.body.as_ref()),
+            FontRole::Heading => self.heading.as_ref().or(self.body.as_ref()),
+            FontRole::Body => self.body.as_ref(),
+            FontRole::Monospace => self.monospace.as_ref(),
+            FontRole::Italic => self.italic.as_ref().or(self.body.as_ref()),
+        };
+        font.cloned().unwrap_or_default()
+    }
+}
+
 /// Font size scale
 #[derive(Clone, Debug)]
 pub struct TypeScale {
@@ -1,5 +1,8 @@
 use bevy::prelude::*;
 
+use crate::systems::class::AddUiClass;
+use crate::systems::visibility::UiHidden;
+
 /// Core functionality for all UI builders.
 ///
 /// This is the base trait that all builders must implement.
@@ -36,6 +39,37 @@ pub trait UiBuilder: Sized {
     fn id(self, _id: Entity) -> Self {
         self
     }
+
+    /// Start the widget shown (`true`, the default) or hidden.
+    ///
+    /// Hidden widgets keep their state but are collapsed out of the layout
+    /// and receive no interaction. Show them again with
+    /// [`SetVisible`](crate::SetVisible).
+    fn visible(self, visible: bool) -> Self {
+        if visible {
+            self
+        } else {
+            self.insert(UiHidden::default())
+        }
+    }
+
+    /// Stack the widget at the given global z-index, above everything with
+    /// a lower one regardless of where it sits in the hierarchy.
+    ///
+    /// Takes a raw `i32` or a [`ZLayer`](crate::dimensions::ZLayer), e.g.
+    /// `.z_layer(ZLayer::Modal)`.
+    fn z_layer(self, z: impl Into<i32>) -> Self {
+        self.insert(GlobalZIndex(z.into()))
+    }
+
+    /// Tag the widget with a class name (or several, separated by spaces).
+    ///
+    /// Calls add up; the names end up in the widget's
+    /// [`UiClass`](crate::UiClass), so groups of widgets can be found with
+    /// [`entities_with_class`](crate::entities_with_class).
+    fn class(self, name: &str) -> Self {
+        self.insert(AddUiClass(name.to_string()))
+    }
 }
 
 /// Functionality for builders that have physical layout properties.
@@ -102,7 +136,7 @@ pub trait LayoutBuilder: UiBuilder {
 pub struct BuilderBase {
     pub entity: Option<Entity>,
     pub node: Node,
-    pub hooks: Vec<Box<dyn FnOnce(&mut EntityCommands)>>,
+    pub hooks: Vec<Box<dyn FnOnce(&mut EntityCommands) + Send + Sync>>,
 }
 
 impl BuilderBase {
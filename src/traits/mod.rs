@@ -2,7 +2,7 @@
 //!
 //! This module defines the trait hierarchy for all UI builders:
 //!
-//! - `UiBuilder` - Base trait with `build()`, `insert()`, `with_entity()`
+//! - `UiBuilder` - Base trait with `build()`, `insert()`, `with_entity()`, `visible()`, `class()`
 //! - `LayoutBuilder` - Layout properties (width, height, margin, padding, etc.)
 //! - `StyleBuilder` - Visual style (variant, colors)
 //! - `SizeableBuilder` - Size presets (small, medium, large)
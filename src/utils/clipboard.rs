@@ -0,0 +1,35 @@
+//! System clipboard access shared by components
+//!
+//! Clipboard support requires the `clipboard` feature and is not available on WASM targets.
+//! When clipboard is unavailable, these operations are no-ops.
+
+/// Copy text to system clipboard (if available)
+#[cfg(feature = "clipboard")]
+pub(crate) fn copy_to_clipboard(text: &str) {
+    if let Ok(mut clipboard) = arboard::Clipboard::new() {
+        let _ = clipboard.set_text(text);
+    }
+}
+
+/// Copy text to system clipboard (no-op when clipboard unavailable)
+#[cfg(not(feature = "clipboard"))]
+pub(crate) fn copy_to_clipboard(_text: &str) {
+    // Clipboard not available (WASM or feature disabled)
+}
+
+/// Get text from system clipboard (if available)
+#[cfg(feature = "clipboard")]
+#[cfg_attr(not(feature = "text_input"), allow(dead_code))]
+pub(crate) fn get_from_clipboard() -> Option<String> {
+    arboard::Clipboard::new()
+        .ok()
+        .and_then(|mut cb| cb.get_text().ok())
+}
+
+/// Get text from system clipboard (returns None when clipboard unavailable)
+#[cfg(not(feature = "clipboard"))]
+#[cfg_attr(not(feature = "text_input"), allow(dead_code))]
+pub(crate) fn get_from_clipboard() -> Option<String> {
+    // Clipboard not available (WASM or feature disabled)
+    None
+}
@@ -0,0 +1,15 @@
+//! Screen-space geometry helpers for laid-out UI nodes
+
+use bevy::prelude::*;
+
+/// Logical-pixel bounds of a UI node
+///
+/// Global transforms and computed sizes are physical pixels, centered on the
+/// node; this scales both into the same space as the window cursor.
+pub(crate) fn node_logical_rect(computed: &ComputedNode, transform: &GlobalTransform) -> Rect {
+    let scale = computed.inverse_scale_factor();
+    Rect::from_center_size(
+        transform.translation().truncate() * scale,
+        computed.size() * scale,
+    )
+}
@@ -1,6 +1,11 @@
 //! Utility functions for UI builders
 
 pub mod intrinsic;
+pub(crate) mod geometry;
+#[cfg(any(feature = "text_input", feature = "label"))]
+pub(crate) mod clipboard;
+#[cfg(any(feature = "tabs", feature = "reorderable_list"))]
+pub(crate) mod reorder;
 
 // Gateway exports - external code MUST access through here!
 // These exports are used by lib.rs to provide the public API.
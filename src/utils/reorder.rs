@@ -0,0 +1,51 @@
+//! Index math shared by drag-to-reorder widgets (reorderable lists, tabs)
+
+/// Item index a drop into gap `slot` moves an item picked up at `from` to
+///
+/// Gaps are numbered 0 (before the first item) to the item count (after the
+/// last). Returns `None` when the drop would leave the item where it was.
+pub fn reorder_target(from: usize, slot: usize) -> Option<usize> {
+    let to = if slot > from { slot - 1 } else { slot };
+    (to != from).then_some(to)
+}
+
+/// New index of the item at `index` after moving the item at `from` to `to`
+pub fn reordered_index(index: usize, from: usize, to: usize) -> usize {
+    if index == from {
+        to
+    } else if from < to && index > from && index <= to {
+        index - 1
+    } else if to < from && index >= to && index < from {
+        index + 1
+    } else {
+        index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reorder_target_accounts_for_removed_item() {
+        // Gaps on either side of the dragged item are no-ops
+        assert_eq!(reorder_target(2, 2), None);
+        assert_eq!(reorder_target(2, 3), None);
+        // Moving up lands at the gap index
+        assert_eq!(reorder_target(2, 0), Some(0));
+        // Moving down lands one before the gap (the item left a hole above it)
+        assert_eq!(reorder_target(0, 3), Some(2));
+        assert_eq!(reorder_target(1, 4), Some(3));
+    }
+
+    #[test]
+    fn reordered_index_shifts_items_between_from_and_to() {
+        // [a, b, c, d] moving a to 2 gives [b, c, a, d]
+        let moved: Vec<usize> = (0..4).map(|index| reordered_index(index, 0, 2)).collect();
+        assert_eq!(moved, vec![2, 0, 1, 3]);
+
+        // [a, b, c, d] moving d to 1 gives [a, d, b, c]
+        let moved: Vec<usize> = (0..4).map(|index| reordered_index(index, 3, 1)).collect();
+        assert_eq!(moved, vec![0, 2, 3, 1]);
+    }
+}
@@ -1,10 +1,15 @@
 //! Universal input validation system
 //!
-//! Provides composable validation rules that work with any input component.
+//! Provides composable validation rules that work with any input component,
+//! plus debounced async checks (`AsyncValidated`) answered by the app.
 
 mod types;
 mod systems;
 mod plugin;
 
-pub use types::{Validated, ValidationState};
+pub use types::{
+    Validated, ValidationState, AsyncValidated, ValidationIndicator,
+    ValidationRequestEvent, ValidationResolvedEvent,
+};
+pub use systems::spawn_validation_indicator;
 pub use plugin::ValidationPlugin;
@@ -2,9 +2,14 @@
 
 use bevy_plugin_builder::define_plugin;
 use super::systems::*;
+use super::types::{ValidationRequestEvent, ValidationResolvedEvent};
 
 define_plugin!(ValidationPlugin {
+    messages: [ValidationRequestEvent, ValidationResolvedEvent],
     update: [
         validate_text_inputs,
+        request_async_validation.after(validate_text_inputs),
+        resolve_async_validation.after(request_async_validation),
+        update_validation_indicators.after(resolve_async_validation),
     ]
 });
@@ -10,8 +10,13 @@ mod defaults {
     use bevy::prelude::Color;
     pub const BORDER_DEFAULT: Color = Color::srgb(0.3, 0.3, 0.3);
     pub const BORDER_ERROR: Color = Color::srgb(0.86, 0.25, 0.25);
+    pub const PENDING: Color = Color::srgb(0.25, 0.46, 0.86);
+    pub const VALID: Color = Color::srgb(0.25, 0.76, 0.25);
 }
 
+/// Spinner turns per second while a check is pending
+const SPINNER_SPEED: f32 = 1.5;
+
 /// Validate text inputs when their buffer changes
 pub fn validate_text_inputs(
     mut inputs: Query<
@@ -35,9 +40,10 @@ pub fn validate_text_inputs(
     for (validated, mut state, mut border, buffer) in inputs.iter_mut() {
         let value = &buffer.content;
 
-        // Run all validation rules
+        // Run all validation rules (an allowed-empty value skips them)
         let mut errors = Vec::new();
-        for rule in &validated.rules {
+        let skip_rules = validated.allow_empty && value.trim().is_empty();
+        for rule in validated.rules.iter().filter(|_| !skip_rules) {
             if let Err(msg) = rule.validate(value) {
                 errors.push(msg);
                 break; // Show only first error
@@ -54,3 +60,252 @@ pub fn validate_text_inputs(
         }
     }
 }
+
+/// Debounce [`AsyncValidated`] inputs and request a check once their value settles
+///
+/// Runs after `validate_text_inputs`, so a changed value has already been
+/// through the input's own rules.
+pub fn request_async_validation(
+    time: Res<Time>,
+    mut inputs: Query<(
+        Entity,
+        &mut AsyncValidated,
+        &mut ValidationState,
+        Ref<TextBuffer>,
+        Has<Validated>,
+        Option<&mut BorderColor>,
+    )>,
+    mut requests: MessageWriter<ValidationRequestEvent>,
+    theme: Option<Res<UiTheme>>,
+) {
+    let border_default = theme
+        .as_ref()
+        .map_or(defaults::BORDER_DEFAULT, |theme| theme.colors.border.default);
+
+    for (entity, mut validated, mut state, buffer, has_rules, border) in inputs.iter_mut() {
+        if buffer.is_changed() && !buffer.is_added() {
+            // Without sync rules nothing else clears the old value's result
+            if validated.resolved && !has_rules {
+                *state = ValidationState::valid();
+                if let Some(mut border) = border {
+                    *border = BorderColor::all(border_default);
+                }
+            }
+            validated.restart();
+            // A result for the old value no longer applies
+            if state.pending {
+                state.pending = false;
+            }
+        }
+
+        if !validated.tick(time.delta()) {
+            continue;
+        }
+        if !state.is_valid || buffer.content.trim().is_empty() {
+            continue;
+        }
+
+        state.pending = true;
+        requests.write(ValidationRequestEvent {
+            field: entity,
+            value: buffer.content.clone(),
+            request: validated.next_request(),
+        });
+    }
+}
+
+/// Apply finished async checks to their inputs
+///
+/// Only the answer to an input's latest request counts; answers for values
+/// it has since moved on from are dropped.
+pub fn resolve_async_validation(
+    mut results: MessageReader<ValidationResolvedEvent>,
+    mut inputs: Query<(&mut AsyncValidated, &mut ValidationState, Option<&mut BorderColor>)>,
+    theme: Option<Res<UiTheme>>,
+) {
+    let (border_default, border_error) = if let Some(ref theme) = theme {
+        (theme.colors.border.default, theme.colors.danger.base)
+    } else {
+        (defaults::BORDER_DEFAULT, defaults::BORDER_ERROR)
+    };
+
+    for result in results.read() {
+        let Ok((mut validated, mut state, border)) = inputs.get_mut(result.field) else {
+            continue;
+        };
+        if !state.pending || result.request != validated.request {
+            continue;
+        }
+
+        validated.resolved = true;
+        if result.valid {
+            *state = ValidationState::valid();
+        } else {
+            let message = result.message.clone().unwrap_or_else(|| "Invalid value".to_string());
+            *state = ValidationState::invalid(message);
+        }
+
+        if let Some(mut border) = border {
+            *border = BorderColor::all(if result.valid { border_default } else { border_error });
+        }
+    }
+}
+
+/// Show a spinner on [`ValidationIndicator`]s while their input's check is
+/// pending, then a check (✓) or cross (✗) badge once it resolves
+pub fn update_validation_indicators(
+    time: Res<Time>,
+    mut indicators: Query<(
+        &ValidationIndicator,
+        &Children,
+        &mut Visibility,
+        &mut BorderColor,
+        &mut BackgroundColor,
+        &mut Transform,
+    )>,
+    inputs: Query<(&ValidationState, &AsyncValidated)>,
+    mut texts: Query<&mut Text>,
+    theme: Option<Res<UiTheme>>,
+) {
+    let (pending_color, valid_color, invalid_color) = if let Some(ref theme) = theme {
+        (theme.colors.primary.base, theme.colors.success.base, theme.colors.danger.base)
+    } else {
+        (defaults::PENDING, defaults::VALID, defaults::BORDER_ERROR)
+    };
+
+    for (indicator, children, mut visibility, mut border, mut background, mut transform) in indicators.iter_mut() {
+        let Ok((state, validated)) = inputs.get(indicator.input) else {
+            continue;
+        };
+
+        let (glyph, badge) = if state.pending {
+            // Only the top edge of the ring is drawn; turning it makes the spinner
+            border.set_if_neq(BorderColor {
+                top: pending_color,
+                ..BorderColor::all(Color::NONE)
+            });
+            background.set_if_neq(BackgroundColor(Color::NONE));
+            transform.rotate_z(-std::f32::consts::TAU * SPINNER_SPEED * time.delta_secs());
+            visibility.set_if_neq(Visibility::Inherited);
+            ("", None)
+        } else if validated.resolved {
+            border.set_if_neq(BorderColor::all(Color::NONE));
+            transform.rotation = Quat::IDENTITY;
+            visibility.set_if_neq(Visibility::Inherited);
+            if state.is_valid { ("✓", Some(valid_color)) } else { ("✗", Some(invalid_color)) }
+        } else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+
+        if let Some(color) = badge {
+            background.set_if_neq(BackgroundColor(color));
+        }
+        for child in children.iter() {
+            if let Ok(mut text) = texts.get_mut(child) {
+                if text.0 != glyph {
+                    text.0 = glyph.to_string();
+                }
+            }
+        }
+    }
+}
+
+/// Side length of a [`ValidationIndicator`] in pixels
+const INDICATOR_SIZE: f32 = 16.0;
+
+/// Spawn a [`ValidationIndicator`] for `input`, hidden until a check starts
+pub fn spawn_validation_indicator(parent: &mut ChildSpawnerCommands, input: Entity) -> Entity {
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(INDICATOR_SIZE),
+                height: Val::Px(INDICATOR_SIZE),
+                flex_shrink: 0.0,
+                border: UiRect::all(Val::Px(2.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BorderColor::all(Color::NONE),
+            BackgroundColor(Color::NONE),
+            BorderRadius::all(Val::Percent(50.0)),
+            Transform::default(),
+            Visibility::Hidden,
+            ValidationIndicator { input },
+        ))
+        .with_children(|badge| {
+            badge.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: INDICATOR_SIZE - 6.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        })
+        .id()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[derive(Resource, Default)]
+    struct Sent(Vec<ValidationRequestEvent>);
+
+    fn record_requests(mut requests: MessageReader<ValidationRequestEvent>, mut sent: ResMut<Sent>) {
+        sent.0.extend(requests.read().cloned());
+    }
+
+    fn set_value(app: &mut App, input: Entity, value: &str) {
+        app.world_mut().get_mut::<TextBuffer>(input).unwrap().content = value.to_string();
+        app.update();
+    }
+
+    #[test]
+    fn answers_for_an_older_value_are_ignored() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<Sent>()
+            .add_message::<ValidationRequestEvent>()
+            .add_message::<ValidationResolvedEvent>()
+            .add_systems(Update, (
+                request_async_validation,
+                record_requests,
+                resolve_async_validation,
+            ).chain());
+
+        let input = app.world_mut().spawn((
+            TextBuffer {
+                content: String::new(),
+                cursor_pos: 0,
+                is_focused: true,
+            },
+            AsyncValidated::new(Duration::ZERO),
+            ValidationState::valid(),
+        )).id();
+        app.update();
+
+        // "admin" is sent, then the user types on and "adminx" is sent too
+        set_value(&mut app, input, "admin");
+        set_value(&mut app, input, "adminx");
+        let sent = app.world().resource::<Sent>().0.clone();
+        assert_eq!(sent.iter().map(|r| r.value.as_str()).collect::<Vec<_>>(), ["admin", "adminx"]);
+
+        // The slow "taken" answer for "admin" arrives while "adminx" is pending
+        app.world_mut().write_message(ValidationResolvedEvent::invalid(&sent[0], "Username is taken"));
+        app.update();
+        let state = app.world().get::<ValidationState>(input).unwrap();
+        assert!(state.is_pending());
+        assert!(state.is_valid);
+
+        app.world_mut().write_message(ValidationResolvedEvent::valid(&sent[1]));
+        app.update();
+        let state = app.world().get::<ValidationState>(input).unwrap();
+        assert!(!state.is_pending());
+        assert!(state.is_valid);
+        assert!(app.world().get::<AsyncValidated>(input).unwrap().resolved);
+    }
+}
@@ -1,5 +1,6 @@
 //! Validation component types
 
+use std::time::Duration;
 use bevy::prelude::*;
 use crate::ValidationRule;
 
@@ -8,12 +9,23 @@ use crate::ValidationRule;
 pub struct Validated {
     /// Validation rules to apply
     pub rules: Vec<ValidationRule>,
+    /// Treat an empty value as valid without running the rules (optional fields)
+    pub allow_empty: bool,
 }
 
 impl Validated {
     /// Create a new validated component with rules
     pub fn new(rules: Vec<ValidationRule>) -> Self {
-        Self { rules }
+        Self {
+            rules,
+            allow_empty: false,
+        }
+    }
+
+    /// Accept an empty value as valid, so the rules only apply once something is entered
+    pub fn allow_empty(mut self) -> Self {
+        self.allow_empty = true;
+        self
     }
 }
 
@@ -24,6 +36,8 @@ pub struct ValidationState {
     pub is_valid: bool,
     /// Error message if invalid
     pub error_message: Option<String>,
+    /// Whether an async check of the current value is still running
+    pub(crate) pending: bool,
 }
 
 impl Default for ValidationState {
@@ -31,6 +45,7 @@ impl Default for ValidationState {
         Self {
             is_valid: true,
             error_message: None,
+            pending: false,
         }
     }
 }
@@ -41,6 +56,7 @@ impl ValidationState {
         Self {
             is_valid: true,
             error_message: None,
+            pending: false,
         }
     }
 
@@ -49,6 +65,167 @@ impl ValidationState {
         Self {
             is_valid: false,
             error_message: Some(message),
+            pending: false,
+        }
+    }
+
+    /// Create a state waiting on an async check (valid until it resolves)
+    pub fn pending() -> Self {
+        Self {
+            is_valid: true,
+            error_message: None,
+            pending: true,
+        }
+    }
+
+    /// Whether an async check of the current value is still running
+    pub fn is_pending(&self) -> bool {
+        self.pending
+    }
+}
+
+/// Async validation for a text input, such as a server-side username check
+///
+/// Once the value has stopped changing for `debounce`, a
+/// [`ValidationRequestEvent`] is sent and the input's [`ValidationState`]
+/// turns pending. Whatever runs the check answers with a
+/// [`ValidationResolvedEvent`]. Values already failing the input's
+/// [`Validated`] rules, and empty values, are not sent.
+#[derive(Component, Debug, Clone)]
+pub struct AsyncValidated {
+    /// How long the value must stay unchanged before a check is requested
+    pub debounce: Duration,
+    /// Whether the current value has been checked
+    pub resolved: bool,
+    /// Time since the value last changed, while waiting to request a check
+    pub(crate) settling: Option<Duration>,
+    /// Id of the latest request; only its result is applied
+    pub(crate) request: u64,
+}
+
+impl AsyncValidated {
+    /// Request a check once the value has been unchanged for `debounce`
+    pub fn new(debounce: Duration) -> Self {
+        Self {
+            debounce,
+            resolved: false,
+            settling: None,
+            request: 0,
+        }
+    }
+
+    /// Note a value change, restarting the debounce
+    pub(crate) fn restart(&mut self) {
+        self.settling = Some(Duration::ZERO);
+        self.resolved = false;
+    }
+
+    /// Advance the debounce, returning true once a check is due
+    pub(crate) fn tick(&mut self, delta: Duration) -> bool {
+        let Some(elapsed) = self.settling.as_mut() else {
+            return false;
+        };
+        *elapsed += delta;
+        if *elapsed < self.debounce {
+            return false;
         }
+        self.settling = None;
+        true
+    }
+
+    /// Start a new request, returning its id
+    pub(crate) fn next_request(&mut self) -> u64 {
+        self.request += 1;
+        self.request
+    }
+}
+
+/// Sent when an [`AsyncValidated`] input's value needs checking
+#[derive(Message, Debug, Clone)]
+pub struct ValidationRequestEvent {
+    /// The input being validated
+    pub field: Entity,
+    /// The value to check
+    pub value: String,
+    /// Id of this request, echoed back by [`ValidationResolvedEvent`]
+    pub request: u64,
+}
+
+/// Send this when an async check finishes
+///
+/// Build it from the [`ValidationRequestEvent`] being answered. Results for
+/// anything but an input's latest request are ignored, so a slow answer for
+/// an older value never lands on a newer one.
+///
+/// # Example
+/// ```ignore
+/// fn finish_check(mut results: MessageWriter<ValidationResolvedEvent>, ...) {
+///     results.write(ValidationResolvedEvent::invalid(&request, "Username is taken"));
+/// }
+/// ```
+#[derive(Message, Debug, Clone)]
+pub struct ValidationResolvedEvent {
+    /// The input that was checked
+    pub field: Entity,
+    /// Id of the request being answered
+    pub request: u64,
+    /// Whether the value passed
+    pub valid: bool,
+    /// Error shown when the value failed
+    pub message: Option<String>,
+}
+
+impl ValidationResolvedEvent {
+    /// The requested value passed the check
+    pub fn valid(request: &ValidationRequestEvent) -> Self {
+        Self {
+            field: request.field,
+            request: request.request,
+            valid: true,
+            message: None,
+        }
+    }
+
+    /// The requested value failed the check with `message`
+    pub fn invalid(request: &ValidationRequestEvent, message: impl Into<String>) -> Self {
+        Self {
+            field: request.field,
+            request: request.request,
+            valid: false,
+            message: Some(message.into()),
+        }
+    }
+}
+
+/// Shows the state of an [`AsyncValidated`] input: a spinner while its
+/// check is pending, then a check or cross mark
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ValidationIndicator {
+    /// The input whose state is shown
+    pub input: Entity,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn async_check_waits_for_value_to_settle() {
+        let frame = Duration::from_millis(100);
+        let mut validated = AsyncValidated::new(Duration::from_millis(250));
+        assert!(!validated.tick(frame));
+
+        validated.restart();
+        assert!(!validated.tick(frame));
+        assert!(!validated.tick(frame));
+
+        // Typing again restarts the wait
+        validated.restart();
+        assert!(!validated.tick(frame));
+        assert!(!validated.tick(frame));
+        assert!(validated.tick(frame));
+
+        // Only one request per change
+        assert!(!validated.tick(frame));
     }
 }